@@ -0,0 +1,91 @@
+use core::hint::black_box;
+
+use benches::bench;
+use bevy_app::{App, Plugin, Update};
+use bevy_ecs::prelude::*;
+use bevy_platform::sync::Arc;
+use criterion::{criterion_group, Criterion};
+
+criterion_group!(benches, fork);
+
+/// The number of [`SystemsPlugin`]s in the benchmarked apps.
+const PLUGINS: usize = 50;
+
+#[derive(Component)]
+struct Health(u32);
+
+#[derive(Component)]
+struct Regen(u32);
+
+fn regen(mut query: Query<(&mut Health, &Regen)>) {
+    for (mut health, regen) in &mut query {
+        health.0 += regen.0;
+    }
+}
+
+fn clamp(mut query: Query<&mut Health>) {
+    for mut health in &mut query {
+        health.0 = health.0.min(100);
+    }
+}
+
+/// A pure-ECS plugin, only adding systems.
+struct SystemsPlugin;
+
+impl Plugin for SystemsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, (regen, clamp).chain());
+    }
+
+    fn is_unique(&self) -> bool {
+        false
+    }
+}
+
+/// A resource that is expensive to create, shared with the forks.
+#[derive(Resource, Clone)]
+struct Lookup(Arc<[u64]>);
+
+struct LookupPlugin;
+
+impl Plugin for LookupPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.world().contains_resource::<Lookup>() {
+            let table = (0..100_000u64)
+                .map(|i| black_box(i.wrapping_mul(i) % 7919))
+                .collect();
+            app.insert_resource(Lookup(table));
+        }
+        app.add_systems(Update, |lookup: Res<Lookup>| {
+            black_box(lookup.0[0]);
+        });
+    }
+
+    fn share(&self, src: &App, dst: &mut App) {
+        dst.insert_resource(src.world().resource::<Lookup>().clone());
+    }
+}
+
+fn build_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(LookupPlugin);
+    for _ in 0..PLUGINS {
+        app.add_plugins(SystemsPlugin);
+    }
+    app
+}
+
+fn fork(c: &mut Criterion) {
+    let mut group = c.benchmark_group(bench!("build"));
+
+    group.bench_function("cold", |b| {
+        b.iter(|| black_box(build_app()));
+    });
+
+    let app = build_app();
+    group.bench_function("fork", |b| {
+        b.iter(|| black_box(app.fork()));
+    });
+
+    group.finish();
+}
@@ -0,0 +1,5 @@
+use criterion::criterion_main;
+
+mod fork;
+
+criterion_main!(fork::benches);
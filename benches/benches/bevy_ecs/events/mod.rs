@@ -1,9 +1,10 @@
 mod iter;
+mod par_iter;
 mod write;
 
 use criterion::{criterion_group, Criterion};
 
-criterion_group!(benches, send, iter);
+criterion_group!(benches, send, iter, par_iter);
 
 fn send(c: &mut Criterion) {
     let mut group = c.benchmark_group("events_send");
@@ -54,3 +55,20 @@ fn iter(c: &mut Criterion) {
     }
     group.finish();
 }
+
+fn par_iter(c: &mut Criterion) {
+    let mut group = c.benchmark_group("events_par_iter");
+    group.warm_up_time(core::time::Duration::from_millis(500));
+    group.measurement_time(core::time::Duration::from_secs(4));
+    for count in [1_000, 10_000, 100_000] {
+        group.bench_function(format!("sequential_{count}"), |b| {
+            let mut bench = par_iter::Benchmark::new(count);
+            b.iter(move || bench.run_sequential());
+        });
+        group.bench_function(format!("parallel_{count}"), |b| {
+            let mut bench = par_iter::Benchmark::new(count);
+            b.iter(move || bench.run_parallel());
+        });
+    }
+    group.finish();
+}
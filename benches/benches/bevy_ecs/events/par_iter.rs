@@ -0,0 +1,49 @@
+use bevy_ecs::prelude::*;
+use bevy_tasks::{ComputeTaskPool, TaskPool};
+
+/// A synthetic collision between two bodies, like a physics engine would write thousands of.
+#[derive(BufferedEvent)]
+struct Collision {
+    normal: [f32; 3],
+    depth: f32,
+}
+
+pub struct Benchmark(Events<Collision>);
+
+impl Benchmark {
+    pub fn new(count: usize) -> Self {
+        ComputeTaskPool::get_or_init(TaskPool::default);
+
+        let mut events = Events::default();
+        events.write_batch((0..count).map(|i| Collision {
+            normal: [i as f32, 1.0, 0.5],
+            depth: 0.01,
+        }));
+
+        Self(events)
+    }
+
+    pub fn run_sequential(&mut self) {
+        let mut reader = self.0.get_cursor();
+        for collision in reader.read(&self.0) {
+            core::hint::black_box(resolve(collision));
+        }
+    }
+
+    pub fn run_parallel(&mut self) {
+        let mut reader = self.0.get_cursor();
+        reader.par_read(&self.0).for_each(|collision| {
+            core::hint::black_box(resolve(collision));
+        });
+    }
+}
+
+/// Some work per event, so that the cost of reading them doesn't dominate.
+fn resolve(collision: &Collision) -> f32 {
+    let [x, y, z] = collision.normal;
+    let mut impulse = collision.depth;
+    for _ in 0..50 {
+        impulse = (impulse * x + y).sin() * z + impulse.sqrt();
+    }
+    impulse
+}
@@ -1,8 +1,10 @@
 use crate::{
-    First, Main, MainSchedulePlugin, PlaceholderPlugin, Plugin, Plugins, PluginsState, SubApp,
-    SubApps,
+    plugin::shared_plugin, plugin_rebuild::RegistrationSnapshot, shutdown::start_shutdown,
+    system_error::flush_system_errors, AppShutdown, First, FrameNumber, Main, MainSchedulePlugin, OnAppExit, PlaceholderPlugin,
+    Plugin, Plugins, PluginsState, SubApp, SubAppOrder, SubApps,
 };
 use alloc::{
+    borrow::Cow,
     boxed::Box,
     string::{String, ToString},
     vec::Vec,
@@ -11,19 +13,24 @@ pub use bevy_derive::AppLabel;
 use bevy_ecs::{
     component::RequiredComponentsError,
     error::{DefaultErrorHandler, ErrorHandler},
-    event::{event_update_system, EventCursor},
+    event::{event_update_system, EventCursor, EventSettings},
     intern::Interned,
     prelude::*,
-    schedule::{InternedSystemSet, ScheduleBuildSettings, ScheduleLabel},
+    schedule::{
+        ExplainError, FrameExplanation, InternedSystemSet, ScheduleBuildSettings, ScheduleLabel,
+    },
     system::{IntoObserverSystem, ScheduleSystem, SystemId, SystemInput},
+    world::AsyncCommandQueue,
 };
-use bevy_platform::collections::HashMap;
+use bevy_platform::sync::Arc;
 use core::{fmt::Debug, num::NonZero, panic::AssertUnwindSafe};
 use log::debug;
 
 #[cfg(feature = "trace")]
 use tracing::info_span;
 
+#[cfg(feature = "std")]
+use core::any::Any;
 #[cfg(feature = "std")]
 use std::{
     panic::{catch_unwind, resume_unwind},
@@ -86,7 +93,13 @@ pub struct App {
     /// [`WinitPlugin`]: https://docs.rs/bevy/latest/bevy/winit/struct.WinitPlugin.html
     /// [`ScheduleRunnerPlugin`]: https://docs.rs/bevy/latest/bevy/app/struct.ScheduleRunnerPlugin.html
     pub(crate) runner: RunnerFn,
+    /// The wrappers added with [`App::wrap_runner`], in registration order.
+    runner_wrappers: Vec<Box<dyn FnOnce(RunnerFn) -> RunnerFn>>,
     default_error_handler: Option<ErrorHandler>,
+    /// Whether the panics of the plugins are turned into [`AppRunError::Plugin`], see
+    /// [`App::run_returning`].
+    #[cfg(feature = "std")]
+    pub(crate) report_plugin_failures: bool,
 }
 
 impl Debug for App {
@@ -101,6 +114,9 @@ impl Debug for App {
 
 impl Default for App {
     fn default() -> Self {
+        #[cfg(feature = "early_logs")]
+        crate::early_logs::buffer_early_logs();
+
         let mut app = App::empty();
         app.sub_apps.main.update_schedule = Some(Main.intern());
 
@@ -123,7 +139,19 @@ impl Default for App {
                 .in_set(bevy_ecs::event::EventUpdateSystems)
                 .run_if(bevy_ecs::event::event_update_condition),
         );
-        app.add_event::<AppExit>();
+        app.add_systems(
+            First,
+            World::apply_async_commands
+                .after(bevy_ecs::event::EventUpdateSystems)
+                .run_if(resource_exists::<AsyncCommandQueue>),
+        );
+        app.add_event::<AppExit>()
+            .init_resource::<AppShutdown>()
+            .add_systems(Main, start_shutdown.after(Main::run_main));
+        app.init_resource::<FrameNumber>();
+
+        #[cfg(feature = "trace")]
+        app.init_resource::<bevy_ecs::schedule::TraceSpanConfig>();
 
         app
     }
@@ -141,12 +169,12 @@ impl App {
     /// Use this constructor if you want to customize scheduling, exit handling, cleanup, etc.
     pub fn empty() -> App {
         Self {
-            sub_apps: SubApps {
-                main: SubApp::new(),
-                sub_apps: HashMap::default(),
-            },
+            sub_apps: SubApps::new(SubApp::new()),
             runner: Box::new(run_once),
+            runner_wrappers: Vec::new(),
             default_error_handler: None,
+            #[cfg(feature = "std")]
+            report_plugin_failures: false,
         }
     }
 
@@ -157,12 +185,14 @@ impl App {
         }
 
         self.sub_apps.update();
+        self.apply_plugin_rebuilds();
     }
 
     /// Runs the [`App`] by calling its [runner](Self::set_runner).
     ///
     /// This will (re)build the [`App`] first. For general usage, see the example on the item
     /// level documentation.
+    /// To get a [`Result`] instead, use [`App::run_returning`].
     ///
     /// # Caveats
     ///
@@ -186,10 +216,94 @@ impl App {
         }
 
         let runner = core::mem::replace(&mut self.runner, Box::new(run_once));
+        let runner = core::mem::take(&mut self.runner_wrappers)
+            .into_iter()
+            .fold(runner, |runner, wrap| wrap(runner));
         let app = core::mem::replace(self, App::empty());
         (runner)(app)
     }
 
+    /// Runs the [`App`] like [`App::run`], and returns how it ended as a [`Result`], for
+    /// programs that embed an app and need to react to its failure.
+    ///
+    /// The [`AppExit`] returned by the [runner](Self::set_runner) is mapped to `Ok(())` on
+    /// success, or to [`AppRunError::Exit`] with its exit code. If a plugin panics while it is
+    /// built, finished or cleaned up by the runner, the panic is caught and returned as an
+    /// [`AppRunError::Plugin`]. Other panics are propagated. Without the `std` feature, panics
+    /// can't be caught, so only [`AppRunError::Exit`] is returned.
+    ///
+    /// # Returning from `main`
+    ///
+    /// [`AppRunError`] implements [`Error`](core::error::Error), so `?` works in a `main` function
+    /// returning a [`Result`]. The error is then printed and the process exits with the code 1.
+    /// To exit with the code of the [`AppExit`] instead, convert the error back:
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_app::AppRunError;
+    /// fn run_game() -> Result<(), AppRunError> {
+    ///     App::new().run_returning()?;
+    ///     // Save the settings...
+    ///     Ok(())
+    /// }
+    ///
+    /// fn main() -> AppExit {
+    ///     match run_game() {
+    ///         Ok(()) => AppExit::Success,
+    ///         Err(error) => {
+    ///             eprintln!("{error}");
+    ///             error.into()
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Caveats
+    ///
+    /// Like [`App::run`], this never returns on iOS and Web, and may not return for windowed apps.
+    ///
+    /// # Panics
+    ///
+    /// Panics if not all plugins have been built.
+    pub fn run_returning(&mut self) -> Result<(), AppRunError> {
+        #[cfg(feature = "std")]
+        let exit = {
+            self.report_plugin_failures = true;
+            match catch_unwind(AssertUnwindSafe(|| self.run())) {
+                Ok(exit) => exit,
+                Err(payload) => match payload.downcast::<AppRunError>() {
+                    Ok(error) => return Err(*error),
+                    Err(payload) => resume_unwind(payload),
+                },
+            }
+        };
+
+        #[cfg(not(feature = "std"))]
+        let exit = self.run();
+
+        match exit {
+            AppExit::Success => Ok(()),
+            AppExit::Error(code) => Err(AppRunError::Exit(code)),
+        }
+    }
+
+    /// Runs a hook of the plugin named `plugin`, and turns its panic into an
+    /// [`AppRunError::Plugin`] when the app is run by [`App::run_returning`].
+    fn run_plugin_hook<R>(&mut self, plugin: &str, hook: impl FnOnce(&mut Self) -> R) -> R {
+        #[cfg(feature = "std")]
+        if self.report_plugin_failures {
+            return match catch_unwind(AssertUnwindSafe(|| hook(self))) {
+                Ok(result) => result,
+                Err(payload) => resume_unwind(plugin_failure(plugin, payload)),
+            };
+        }
+
+        #[cfg(not(feature = "std"))]
+        let _ = plugin;
+
+        hook(self)
+    }
+
     /// Sets the function that will be called when the app is run.
     ///
     /// The runner function `f` is called only once by [`App::run`]. If the
@@ -199,6 +313,10 @@ impl App {
     /// The runner function is usually not set manually, but by Bevy integrated plugins
     /// (e.g. `WinitPlugin`).
     ///
+    /// The [`AppExit`] returned by the runner is returned by [`App::run`], and mapped to a
+    /// [`Result`] by [`App::run_returning`], so runners report failures by returning an
+    /// [`AppExit::Error`].
+    ///
     /// # Examples
     ///
     /// ```
@@ -222,6 +340,36 @@ impl App {
         self
     }
 
+    /// Wraps the runner of the app, without replacing it.
+    ///
+    /// When the app is [run](App::run), `f` is given the installed runner and returns the runner
+    /// to use instead, which usually calls the given one. This lets plugins decorate whichever
+    /// runner ends up installed, for example to set up a profiler before the app runs and flush
+    /// it once it exits.
+    ///
+    /// Wrappers are applied in registration order, so the first one wraps the runner directly and
+    /// the last one runs first. They are applied when the app is run, so they also wrap a runner
+    /// installed later with [`App::set_runner`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// #
+    /// App::new().wrap_runner(|runner| {
+    ///     Box::new(|app| {
+    ///         println!("Starting");
+    ///         let exit = runner(app);
+    ///         println!("Exited with {exit:?}");
+    ///         exit
+    ///     })
+    /// });
+    /// ```
+    pub fn wrap_runner(&mut self, f: impl FnOnce(RunnerFn) -> RunnerFn + 'static) -> &mut Self {
+        self.runner_wrappers.push(Box::new(f));
+        self
+    }
+
     /// Returns the state of all plugins. This is usually called by the event loop, but can be
     /// useful for situations where you want to use [`App::update`].
     // TODO: &mut self -> &self
@@ -229,6 +377,7 @@ impl App {
     pub fn plugins_state(&mut self) -> PluginsState {
         let mut overall_plugins_state = match self.main_mut().plugins_state {
             PluginsState::Adding => {
+                self.build_deferred_plugins();
                 let mut state = PluginsState::Ready;
                 let plugins = core::mem::take(&mut self.main_mut().plugin_registry);
                 for plugin in &plugins {
@@ -254,13 +403,22 @@ impl App {
 
     /// Runs [`Plugin::finish`] for each plugin. This is usually called by the event loop once all
     /// plugins are ready, but can be useful for situations where you want to use [`App::update`].
+    ///
+    /// Before that, schedules that have systems but are never run are reported according to the
+    /// [`MissingScheduleBehavior`](crate::MissingScheduleBehavior).
     pub fn finish(&mut self) {
+        self.build_deferred_plugins();
+        self.main().assert_no_deferred_plugins();
+        self.check_missing_schedules();
+        // Once all the plugins are built, so that the events they added keep their settings.
+        #[cfg(feature = "auto_register_events")]
+        bevy_ecs::event::EventRegistry::register_derived_events(self.world_mut());
         // plugins installed to main should see all sub-apps
-        // do hokey pokey with a boxed zst plugin (doesn't allocate)
-        let mut hokeypokey: Box<dyn Plugin> = Box::new(HokeyPokey);
+        // do hokey pokey with a zst plugin (allocates only the reference counts)
+        let mut hokeypokey = shared_plugin(HokeyPokey);
         for i in 0..self.main().plugin_registry.len() {
             core::mem::swap(&mut self.main_mut().plugin_registry[i], &mut hokeypokey);
-            hokeypokey.finish(self);
+            self.run_plugin_hook(hokeypokey.name(), |app| hokeypokey.finish(app));
             core::mem::swap(&mut self.main_mut().plugin_registry[i], &mut hokeypokey);
         }
         self.main_mut().plugins_state = PluginsState::Finished;
@@ -271,17 +429,44 @@ impl App {
     /// [`App::finish`], but can be useful for situations where you want to use [`App::update`].
     pub fn cleanup(&mut self) {
         // plugins installed to main should see all sub-apps
-        // do hokey pokey with a boxed zst plugin (doesn't allocate)
-        let mut hokeypokey: Box<dyn Plugin> = Box::new(HokeyPokey);
+        // do hokey pokey with a zst plugin (allocates only the reference counts)
+        let mut hokeypokey = shared_plugin(HokeyPokey);
         for i in 0..self.main().plugin_registry.len() {
             core::mem::swap(&mut self.main_mut().plugin_registry[i], &mut hokeypokey);
-            hokeypokey.cleanup(self);
+            self.run_plugin_hook(hokeypokey.name(), |app| hokeypokey.cleanup(app));
             core::mem::swap(&mut self.main_mut().plugin_registry[i], &mut hokeypokey);
         }
         self.main_mut().plugins_state = PluginsState::Cleaned;
         self.sub_apps.iter_mut().skip(1).for_each(SubApp::cleanup);
     }
 
+    /// Runs the [`OnAppExit`] schedule, then [`Plugin::on_exit`] for each plugin, including the
+    /// plugins of the sub-apps.
+    ///
+    /// Runners call this once they stopped updating the app, just before returning its
+    /// [`AppExit`]. Only the first call does something, so it is safe to call it from several
+    /// places of a runner.
+    pub fn run_exit_hooks(&mut self) {
+        let mut shutdown = self.world_mut().get_resource_or_init::<AppShutdown>();
+        if core::mem::replace(&mut shutdown.exit_hooks_run, true) {
+            return;
+        }
+
+        let _ = self.world_mut().try_run_schedule(OnAppExit);
+        flush_system_errors(self.world_mut());
+        // do hokey pokey with a zst plugin (allocates only the reference counts)
+        let mut hokeypokey = shared_plugin(HokeyPokey);
+        for i in 0..self.main().plugin_registry.len() {
+            core::mem::swap(&mut self.main_mut().plugin_registry[i], &mut hokeypokey);
+            self.run_plugin_hook(hokeypokey.name(), |app| hokeypokey.on_exit(app));
+            core::mem::swap(&mut self.main_mut().plugin_registry[i], &mut hokeypokey);
+        }
+        self.sub_apps
+            .iter_mut()
+            .skip(1)
+            .for_each(SubApp::run_exit_hooks);
+    }
+
     /// Returns `true` if any of the sub-apps are building plugins.
     pub(crate) fn is_building_plugins(&self) -> bool {
         self.sub_apps.iter().any(SubApp::is_building_plugins)
@@ -344,11 +529,59 @@ impl App {
         self
     }
 
+    /// Describes a schedule for tools such as schedule inspectors, see
+    /// [`ScheduleDescriptions`](bevy_ecs::schedule::ScheduleDescriptions).
+    ///
+    /// Plugins usually describe the schedules they add in [`Plugin::build`]. If the schedule was
+    /// already described differently, the new description replaces the previous one and a warning
+    /// names the plugins that gave them.
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::schedule::{ScheduleDescriptions, ScheduleLabel};
+    /// #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+    /// struct PhysicsStep;
+    ///
+    /// let mut app = App::new();
+    /// app.describe_schedule(PhysicsStep, "Moves the bodies of the physics simulation.");
+    /// assert_eq!(
+    ///     app.world().resource::<ScheduleDescriptions>().schedule(PhysicsStep),
+    ///     Some("Moves the bodies of the physics simulation.")
+    /// );
+    /// ```
+    pub fn describe_schedule(
+        &mut self,
+        label: impl ScheduleLabel,
+        text: impl Into<Cow<'static, str>>,
+    ) -> &mut Self {
+        self.main_mut().describe_schedule(label, text);
+        self
+    }
+
+    /// Describes a system set for tools such as schedule inspectors, see
+    /// [`ScheduleDescriptions`](bevy_ecs::schedule::ScheduleDescriptions).
+    ///
+    /// Like [`describe_schedule`](Self::describe_schedule), the last description wins.
+    pub fn describe_set(
+        &mut self,
+        set: impl SystemSet,
+        text: impl Into<Cow<'static, str>>,
+    ) -> &mut Self {
+        self.main_mut().describe_set(set, text);
+        self
+    }
+
     /// Initializes [`BufferedEvent`] handling for `T` by inserting an event queue resource ([`Events::<T>`])
     /// and scheduling an [`event_update_system`] in [`First`].
     ///
     /// See [`Events`] for information on how to define events.
     ///
+    /// With the `auto_register_events` feature, the events derived with
+    /// `#[buffered_event(auto_register)]` are added by [`App::finish`] instead, unless they were
+    /// added before. As [`App::run`] finishes the app, this only matters for apps updated
+    /// manually. See `EventRegistry::register_derived_events` for the platforms this is
+    /// supported on.
+    ///
     /// # Examples
     ///
     /// ```
@@ -369,6 +602,33 @@ impl App {
         self
     }
 
+    /// Initializes [`BufferedEvent`] handling for `T` like [`add_event`](Self::add_event), with
+    /// [`EventSettings`] bounding the number of stored events.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::{event::{EventSettings, Overflow}, prelude::*};
+    /// #
+    /// # #[derive(BufferedEvent)]
+    /// # struct LogLine(String);
+    /// # let mut app = App::new();
+    /// #
+    /// app.add_event_with::<LogLine>(EventSettings {
+    ///     capacity: 10_000,
+    ///     overflow: Overflow::DropOldest,
+    ///     ..Default::default()
+    /// });
+    /// ```
+    pub fn add_event_with<T>(&mut self, settings: EventSettings) -> &mut Self
+    where
+        T: BufferedEvent,
+    {
+        self.main_mut().add_event_with::<T>(settings);
+        self
+    }
+
     /// Inserts the [`Resource`] into the app, overwriting any existing resource of the same type.
     ///
     /// There is also an [`init_resource`](Self::init_resource) for resources that have
@@ -464,22 +724,62 @@ impl App {
     pub(crate) fn add_boxed_plugin(
         &mut self,
         plugin: Box<dyn Plugin>,
+    ) -> Result<&mut Self, AppError> {
+        self.add_shared_plugin(Arc::from(plugin))
+    }
+
+    /// Adds a plugin that may also be in the registry of other apps, see [`App::fork`].
+    pub(crate) fn add_shared_plugin(
+        &mut self,
+        plugin: Arc<dyn Plugin>,
     ) -> Result<&mut Self, AppError> {
         debug!("added plugin: {}", plugin.name());
-        if plugin.is_unique() && self.main_mut().plugin_names.contains(plugin.name()) {
+        if plugin.is_unique()
+            && (self.main().plugin_names.contains(plugin.name())
+                || self
+                    .main()
+                    .deferred_plugins
+                    .iter()
+                    .any(|deferred| deferred.name() == plugin.name()))
+        {
             Err(AppError::DuplicatePlugin {
                 plugin_name: plugin.name().to_string(),
             })?;
         }
 
+        if !plugin
+            .required_resources()
+            .iter()
+            .all(|resource| resource.exists(self.world()))
+        {
+            debug!(
+                "deferred building plugin {} until its required resources exist",
+                plugin.name()
+            );
+            self.main_mut().deferred_plugins.push(plugin);
+            return Ok(self);
+        }
+
         // Reserve position in the plugin registry. If the plugin adds more plugins,
         // they'll all end up in insertion order.
         let index = self.main().plugin_registry.len();
+        let nested = self.main().is_building_plugins();
         self.main_mut()
             .plugin_registry
-            .push(Box::new(PlaceholderPlugin));
+            .push(shared_plugin(PlaceholderPlugin));
+        self.main_mut().nested_plugins.push(nested);
+        let snapshot = self
+            .main()
+            .plugin_records
+            .is_some()
+            .then(|| RegistrationSnapshot::take(self.world()));
+        if let Some(records) = &mut self.main_mut().plugin_records {
+            records.push(None);
+        }
 
-        self.main_mut().plugin_build_depth += 1;
+        self.main_mut()
+            .building_plugins
+            .push(plugin.name().to_string());
 
         let f = AssertUnwindSafe(|| plugin.build(self));
 
@@ -492,17 +792,48 @@ impl App {
         self.main_mut()
             .plugin_names
             .insert(plugin.name().to_string());
-        self.main_mut().plugin_build_depth -= 1;
+        self.main_mut().building_plugins.pop();
 
         #[cfg(feature = "std")]
         if let Err(payload) = result {
+            if self.report_plugin_failures {
+                resume_unwind(plugin_failure(plugin.name(), payload));
+            }
             resume_unwind(payload);
         }
 
+        if let Some(snapshot) = snapshot {
+            let main = self.main();
+            let record = snapshot.record(
+                main.world(),
+                &main.plugin_records.as_ref().unwrap()[index + 1..],
+            );
+            self.main_mut().plugin_records.as_mut().unwrap()[index] = Some(record);
+        }
         self.main_mut().plugin_registry[index] = plugin;
+        self.build_deferred_plugins();
         Ok(self)
     }
 
+    /// Builds the deferred plugins whose required resources now exist, in the order they were
+    /// added.
+    pub(crate) fn build_deferred_plugins(&mut self) {
+        while let Some(index) = self.main().deferred_plugins.iter().position(|plugin| {
+            plugin
+                .required_resources()
+                .iter()
+                .all(|resource| resource.exists(self.world()))
+        }) {
+            let plugin = self.main_mut().deferred_plugins.remove(index);
+            // Duplicates were rejected when the plugin was deferred.
+            if let Err(AppError::DuplicatePlugin { plugin_name }) = self.add_shared_plugin(plugin) {
+                panic!(
+                    "Error adding plugin {plugin_name}: plugin was already added in application"
+                );
+            }
+        }
+    }
+
     /// Returns `true` if the [`Plugin`] has already been added.
     pub fn is_plugin_added<T>(&self) -> bool
     where
@@ -537,6 +868,51 @@ impl App {
         self.main().get_added_plugins::<T>()
     }
 
+    /// Returns an iterator over the plugins that have been added, in insertion order.
+    ///
+    /// Plugins that are in the middle of being built, or whose build is deferred until their
+    /// [required resources](Plugin::required_resources) exist, are not listed.
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # fn my_plugin(_: &mut App) {}
+    /// let mut app = App::new();
+    /// app.add_plugins(my_plugin);
+    /// for plugin in app.iter_plugins() {
+    ///     println!("{} (unique: {})", plugin.name(), plugin.is_unique());
+    /// }
+    /// ```
+    pub fn iter_plugins(&self) -> impl Iterator<Item = &dyn Plugin> {
+        self.main().iter_plugins()
+    }
+
+    /// Returns an iterator over the plugins of type `T` that have been added, in insertion order.
+    ///
+    /// This is the lazy version of [`App::get_added_plugins`].
+    pub fn iter_plugins_of<T>(&self) -> impl Iterator<Item = &T>
+    where
+        T: Plugin,
+    {
+        self.main().iter_plugins_of::<T>()
+    }
+
+    /// Returns a mutable iterator over the plugins that have been added, in insertion order.
+    ///
+    /// This allows changing the settings of a plugin before its [`Plugin::finish`] and
+    /// [`Plugin::cleanup`] run. See [`App::iter_plugins`] for which plugins are listed.
+    ///
+    /// The plugins shared with a [fork](App::fork), or with the app this one was forked from, are
+    /// skipped while the other app exists, since they can't be modified: a plugin listed by
+    /// [`App::iter_plugins`] but not by this method is shared. Dropping the other app makes them
+    /// modifiable again.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the plugins are already [finished](PluginsState::Finished).
+    pub fn iter_plugins_mut(&mut self) -> impl Iterator<Item = &mut dyn Plugin> {
+        self.main_mut().iter_plugins_mut()
+    }
+
     /// Installs a [`Plugin`] collection.
     ///
     /// Bevy prioritizes modularity as a core principle. **All** engine features are implemented
@@ -589,6 +965,48 @@ impl App {
         self
     }
 
+    /// Installs a [`Plugin`] collection, skipping the plugins that were already added.
+    ///
+    /// This behaves like [`add_plugins`](Self::add_plugins), except that unique plugins which are
+    /// already present in the [`App`] are skipped with a debug log instead of causing a panic.
+    /// This works the same way for single plugins, [`PluginGroup`]s and tuples, so a tuple can
+    /// be added even if only some of its members are new.
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # struct AudioPlugin;
+    /// # impl Plugin for AudioPlugin {
+    /// #     fn build(&self, app: &mut App) {}
+    /// # }
+    /// # struct InputPlugin;
+    /// # impl Plugin for InputPlugin {
+    /// #     fn build(&self, app: &mut App) {}
+    /// # }
+    /// let mut app = App::new();
+    /// app.add_plugins(AudioPlugin);
+    /// // `AudioPlugin` is skipped, `InputPlugin` is added.
+    /// app.add_plugins_if_new((AudioPlugin, InputPlugin));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if called after [`App::finish`] or [`App::cleanup`].
+    ///
+    /// [`PluginGroup`]: super::PluginGroup
+    #[track_caller]
+    pub fn add_plugins_if_new<M>(&mut self, plugins: impl Plugins<M>) -> &mut Self {
+        if matches!(
+            self.plugins_state(),
+            PluginsState::Cleaned | PluginsState::Finished
+        ) {
+            panic!(
+                "Plugins cannot be added after App::cleanup() or App::finish() has been called."
+            );
+        }
+        plugins.add_to_app_if_new(self);
+        self
+    }
+
     /// Registers the type `T` in the [`AppTypeRegistry`] resource,
     /// adding reflect data as specified in the [`Reflect`](bevy_reflect::Reflect) derive:
     /// ```ignore (No serde "derive" feature)
@@ -775,7 +1193,7 @@ impl App {
     #[cfg(feature = "reflect_functions")]
     pub fn register_function_with_name<F, Marker>(
         &mut self,
-        name: impl Into<alloc::borrow::Cow<'static, str>>,
+        name: impl Into<Cow<'static, str>>,
         function: F,
     ) -> &mut Self
     where
@@ -1127,12 +1545,41 @@ impl App {
                 .world_mut()
                 .get_resource_or_insert_with(|| DefaultErrorHandler(handler));
         }
-        self.sub_apps.sub_apps.insert(label.intern(), sub_app);
+        self.sub_apps.insert(label.intern(), sub_app);
     }
 
     /// Removes the [`SubApp`] with the given label, if it exists.
     pub fn remove_sub_app(&mut self, label: impl AppLabel) -> Option<SubApp> {
-        self.sub_apps.sub_apps.remove(&label.intern())
+        self.sub_apps.remove(label.intern())
+    }
+
+    /// Orders the extraction and update of two sub-apps.
+    ///
+    /// After the main app is updated, each sub-app is [extracted](SubApp::extract) from the main
+    /// world and then updated, one sub-app after the other. By default they run in the order
+    /// they were inserted in; this adds a constraint to that order, which applies even if the
+    /// sub-apps are inserted later.
+    ///
+    /// ```
+    /// # use bevy_app::{App, AppLabel, SubApp, SubAppOrderExt};
+    /// #[derive(AppLabel, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    /// struct SimulationApp;
+    ///
+    /// #[derive(AppLabel, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    /// struct RenderApp;
+    ///
+    /// let mut app = App::new();
+    /// app.insert_sub_app(RenderApp, SubApp::new());
+    /// app.insert_sub_app(SimulationApp, SubApp::new());
+    /// app.configure_sub_app_order(SimulationApp.before(RenderApp));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the constraint would make the order cyclic.
+    pub fn configure_sub_app_order(&mut self, order: SubAppOrder) -> &mut Self {
+        self.sub_apps.configure_order(order);
+        self
     }
 
     /// Extract data from the main world into the [`SubApp`] with the given label and perform an update if it exists.
@@ -1165,6 +1612,40 @@ impl App {
         self.main_mut().get_schedule_mut(label)
     }
 
+    /// Explains which systems of the [`Schedule`] with the provided `label` would run if it ran
+    /// now, without running them. See [`Schedule::explain`].
+    ///
+    /// This is useful to debug run conditions: the explanation tells which condition skips each
+    /// system, grouped by system set. It can also be printed from a dev console with
+    /// [`World::run_explain_command`], for example `explain Update`.
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// # #[derive(Resource)]
+    /// # struct Paused(bool);
+    /// fn move_player() {}
+    ///
+    /// let mut app = App::new();
+    /// app.insert_resource(Paused(true)).add_systems(
+    ///     Update,
+    ///     move_player.run_if(|paused: Res<Paused>| !paused.0),
+    /// );
+    /// let explanation = app.explain_frame(Update).unwrap();
+    /// assert_eq!(explanation.would_run().count(), 0);
+    /// println!("{explanation}");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the schedule doesn't exist or can't be built.
+    pub fn explain_frame(
+        &mut self,
+        label: impl ScheduleLabel,
+    ) -> Result<FrameExplanation, ExplainError> {
+        self.main_mut().world_mut().explain_schedule(label)
+    }
+
     /// Runs function `f` with the [`Schedule`] associated with `label`.
     ///
     /// **Note:** This will create the schedule if it does not already exist.
@@ -1289,6 +1770,8 @@ impl App {
     /// Will attempt to return the first [`Error`](AppExit::Error) it encounters.
     /// This should be called after every [`update()`](App::update) otherwise you risk
     /// dropping possible [`AppExit`] events.
+    ///
+    /// Use [`App::exit_status`] to also know why the app is exiting.
     pub fn should_exit(&self) -> Option<AppExit> {
         let mut reader = EventCursor::default();
 
@@ -1348,6 +1831,41 @@ impl App {
         self
     }
 
+    /// Spawns an [`Observer`] entity like [`App::add_observer`], with the given `priority`
+    /// relative to the other observers of the same event.
+    ///
+    /// Observers with a higher priority run first, see [`ObserverOrder`](bevy_ecs::observer::ObserverOrder)
+    /// for details. To run an observer before or after specific observers instead, spawn an
+    /// [`Observer`] configured with [`Observer::before`] or [`Observer::after`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// #
+    /// # let mut app = App::new();
+    /// #
+    /// # #[derive(Event)]
+    /// # struct Purchase;
+    /// #
+    /// app.add_observer(|_: On<Purchase>| {
+    ///     // Apply the purchase, once it was validated.
+    /// });
+    /// app.add_observer_with_priority(|_: On<Purchase>| {
+    ///     // Validate the purchase.
+    /// }, 100);
+    /// ```
+    pub fn add_observer_with_priority<E: Event, B: Bundle, M>(
+        &mut self,
+        observer: impl IntoObserverSystem<E, B, M>,
+        priority: i32,
+    ) -> &mut Self {
+        self.world_mut()
+            .add_observer_with_priority(observer, priority);
+        self
+    }
+
     /// Gets the error handler to set for new supapps.
     ///
     /// Note that the error handler of existing subapps may differ.
@@ -1398,7 +1916,9 @@ impl Plugin for HokeyPokey {
     fn build(&self, _: &mut App) {}
 }
 
-type RunnerFn = Box<dyn FnOnce(App) -> AppExit>;
+/// A [runner](App::set_runner) of an [`App`], as given to the wrappers added with
+/// [`App::wrap_runner`].
+pub type RunnerFn = Box<dyn FnOnce(App) -> AppExit>;
 
 fn run_once(mut app: App) -> AppExit {
     while app.plugins_state() == PluginsState::Adding {
@@ -1409,6 +1929,7 @@ fn run_once(mut app: App) -> AppExit {
     app.cleanup();
 
     app.update();
+    app.run_exit_hooks();
 
     app.should_exit().unwrap_or(AppExit::Success)
 }
@@ -1471,6 +1992,12 @@ impl From<u8> for AppExit {
     }
 }
 
+impl From<AppRunError> for AppExit {
+    fn from(error: AppRunError) -> Self {
+        AppExit::Error(error.exit_code())
+    }
+}
+
 #[cfg(feature = "std")]
 impl Termination for AppExit {
     fn report(self) -> ExitCode {
@@ -1482,25 +2009,85 @@ impl Termination for AppExit {
     }
 }
 
+/// An error returned by [`App::run_returning`] when the app didn't exit successfully.
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum AppRunError {
+    /// The runner returned an [`AppExit::Error`].
+    #[error("the app exited with the code {0}")]
+    Exit(NonZero<u8>),
+    /// A plugin panicked while it was built, finished or cleaned up.
+    #[error("the plugin {plugin} failed: {message}")]
+    Plugin {
+        /// The name of the plugin.
+        plugin: String,
+        /// The message of the panic.
+        message: String,
+    },
+}
+
+impl AppRunError {
+    /// Returns the exit code matching this error, which is 1 for plugin failures.
+    #[must_use]
+    pub const fn exit_code(&self) -> NonZero<u8> {
+        match self {
+            AppRunError::Exit(code) => *code,
+            AppRunError::Plugin { .. } => NonZero::<u8>::MIN,
+        }
+    }
+}
+
+/// Wraps the panic `payload` of the plugin named `plugin` in an [`AppRunError::Plugin`], unless
+/// it comes from a plugin added by that plugin.
+#[cfg(feature = "std")]
+fn plugin_failure(plugin: &str, payload: Box<dyn Any + Send>) -> Box<dyn Any + Send> {
+    if payload.is::<AppRunError>() {
+        return payload;
+    }
+    let message = if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        String::from("unknown panic")
+    };
+    Box::new(AppRunError::Plugin {
+        plugin: plugin.to_string(),
+        message,
+    })
+}
+
 #[cfg(test)]
 mod tests {
-    use core::marker::PhantomData;
+    use alloc::{
+        boxed::Box,
+        format,
+        string::{String, ToString},
+        vec,
+        vec::Vec,
+    };
+    use core::{marker::PhantomData, num::NonZero};
     use std::sync::Mutex;
 
     use bevy_ecs::{
         change_detection::{DetectChanges, ResMut},
         component::Component,
         entity::Entity,
-        event::{BufferedEvent, EventWriter, Events},
+        event::{BufferedEvent, EventReader, EventWriter, Events},
         lifecycle::RemovedComponents,
         query::With,
         resource::Resource,
-        schedule::{IntoScheduleConfigs, ScheduleLabel},
-        system::{Commands, Query},
+        schedule::{
+            common_conditions::not, IntoScheduleConfigs, Schedule, ScheduleDescriptions,
+            ScheduleLabel, SystemSet,
+        },
+        system::{Commands, Query, Res},
         world::{FromWorld, World},
     };
 
-    use crate::{App, AppExit, Plugin, SubApp, Update};
+    use crate::{
+        App, AppExit, AppRunError, Main, Plugin, PluginGroup, PluginGroupBuilder, PluginsState,
+        RequiredResource, SubApp, Update,
+    };
 
     struct PluginA;
     impl Plugin for PluginA {
@@ -1587,6 +2174,131 @@ mod tests {
         App::new().add_plugins((PluginD, PluginD));
     }
 
+    #[derive(Resource)]
+    struct ApiKey;
+
+    struct ProvidesApiKey;
+    impl Plugin for ProvidesApiKey {
+        fn build(&self, app: &mut App) {
+            app.insert_resource(ApiKey);
+        }
+    }
+
+    struct NeedsApiKey;
+    impl Plugin for NeedsApiKey {
+        fn build(&self, app: &mut App) {
+            assert!(app.world().contains_resource::<ApiKey>());
+            app.init_resource::<BuildOrder>();
+            app.world_mut()
+                .resource_mut::<BuildOrder>()
+                .0
+                .push("NeedsApiKey");
+        }
+
+        fn required_resources(&self) -> Vec<RequiredResource> {
+            vec![RequiredResource::of::<ApiKey>()]
+        }
+    }
+
+    #[derive(Resource, Default)]
+    struct BuildOrder(Vec<&'static str>);
+
+    #[test]
+    fn plugin_build_is_deferred_until_required_resources_exist() {
+        let mut app = App::new();
+        app.add_plugins(NeedsApiKey);
+        assert!(!app.is_plugin_added::<NeedsApiKey>());
+
+        // Inserting the resource in a later plugin's `build` builds the deferred plugin.
+        app.add_plugins(ProvidesApiKey);
+        assert!(app.is_plugin_added::<NeedsApiKey>());
+        assert_eq!(app.world().resource::<BuildOrder>().0, ["NeedsApiKey"]);
+        assert_eq!(app.plugins_state(), PluginsState::Ready);
+    }
+
+    #[test]
+    fn deferred_plugins_compose_with_groups() {
+        struct TestGroup;
+        impl PluginGroup for TestGroup {
+            fn build(self) -> PluginGroupBuilder {
+                PluginGroupBuilder::start::<Self>()
+                    .add(NeedsApiKey)
+                    .add(PluginA)
+                    .add(ProvidesApiKey)
+            }
+        }
+
+        let mut app = App::new();
+        app.add_plugins(TestGroup);
+        assert!(app.is_plugin_added::<NeedsApiKey>());
+        // The deferred plugin is registered after the plugin that unblocked it.
+        let names = app
+            .main()
+            .plugin_registry
+            .iter()
+            .map(|plugin| plugin.name())
+            .collect::<Vec<_>>();
+        let position = |name: &str| names.iter().position(|n| n.ends_with(name)).unwrap();
+        assert!(position("ProvidesApiKey") < position("NeedsApiKey"));
+    }
+
+    #[test]
+    fn deferred_plugins_are_built_once_resources_are_inserted() {
+        let mut app = App::new();
+        app.add_plugins(NeedsApiKey);
+        app.insert_resource(ApiKey);
+        assert_eq!(app.plugins_state(), PluginsState::Ready);
+        assert!(app.is_plugin_added::<NeedsApiKey>());
+    }
+
+    #[test]
+    #[should_panic(expected = "NeedsApiKey (missing bevy_app::app::tests::ApiKey)")]
+    fn missing_required_resources_panic_when_ready() {
+        let mut app = App::new();
+        app.add_plugins(NeedsApiKey);
+        assert_eq!(app.plugins_state(), PluginsState::Ready);
+        app.finish();
+    }
+
+    #[test]
+    #[should_panic]
+    fn deferred_plugins_are_still_unique() {
+        App::new().add_plugins((NeedsApiKey, NeedsApiKey));
+    }
+
+    #[test]
+    fn add_plugins_if_new_skips_existing_members() {
+        let mut app = App::new();
+        app.add_plugins(PluginA);
+        app.add_plugins_if_new((PluginA, PluginB, PluginD));
+        app.add_plugins_if_new((PluginB, PluginD));
+
+        assert_eq!(app.get_added_plugins::<PluginA>().len(), 1);
+        assert_eq!(app.get_added_plugins::<PluginB>().len(), 1);
+        // Non-unique plugins are never considered duplicates.
+        assert_eq!(app.get_added_plugins::<PluginD>().len(), 2);
+    }
+
+    #[test]
+    fn add_plugins_if_new_skips_existing_group_members() {
+        struct TestGroup;
+        impl PluginGroup for TestGroup {
+            fn build(self) -> PluginGroupBuilder {
+                PluginGroupBuilder::start::<Self>()
+                    .add(PluginA)
+                    .add(PluginB)
+            }
+        }
+
+        let mut app = App::new();
+        app.add_plugins(PluginB);
+        app.add_plugins_if_new(TestGroup);
+        app.add_plugins_if_new(TestGroup);
+
+        assert_eq!(app.get_added_plugins::<PluginA>().len(), 1);
+        assert_eq!(app.get_added_plugins::<PluginB>().len(), 1);
+    }
+
     #[test]
     #[should_panic]
     fn cant_call_app_run_from_plugin_build() {
@@ -1668,6 +2380,69 @@ mod tests {
         );
     }
 
+    #[test]
+    fn iter_plugins_skips_plugins_being_built() {
+        struct ListingPlugin;
+        impl Plugin for ListingPlugin {
+            fn build(&self, app: &mut App) {
+                assert!(app
+                    .iter_plugins()
+                    .all(|plugin| plugin.name() != self.name()));
+                assert_eq!(app.iter_plugins().count(), 2);
+            }
+        }
+
+        let mut app = App::new();
+        app.add_plugins((PluginA, ListingPlugin, PluginD, PluginD));
+        let names = app.iter_plugins().map(Plugin::name).collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            [
+                "bevy_app::main_schedule::MainSchedulePlugin",
+                "bevy_app::app::tests::PluginA",
+                "bevy_app::app::tests::iter_plugins_skips_plugins_being_built::ListingPlugin",
+                "bevy_app::app::tests::PluginD",
+                "bevy_app::app::tests::PluginD",
+            ]
+        );
+        assert_eq!(app.iter_plugins_of::<PluginD>().count(), 2);
+        assert_eq!(app.iter_plugins_of::<PluginB>().count(), 0);
+    }
+
+    struct Speed(u32);
+    impl Plugin for Speed {
+        fn build(&self, _app: &mut App) {}
+
+        fn finish(&self, app: &mut App) {
+            app.insert_resource(AppliedSpeed(self.0));
+        }
+    }
+
+    #[derive(Resource)]
+    struct AppliedSpeed(u32);
+
+    #[test]
+    fn iter_plugins_mut_changes_settings_before_finish() {
+        let mut app = App::new();
+        app.add_plugins((PluginA, Speed(1)));
+        for plugin in app.iter_plugins_mut() {
+            if let Some(speed) = plugin.downcast_mut::<Speed>() {
+                speed.0 = 3;
+            }
+        }
+        app.finish();
+        assert_eq!(app.world().resource::<AppliedSpeed>().0, 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "plugins can only be modified before the app starts")]
+    fn iter_plugins_mut_panics_once_finished() {
+        let mut app = App::new();
+        app.add_plugins(PluginA);
+        app.finish();
+        let _ = app.iter_plugins_mut();
+    }
+
     #[test]
     fn test_derive_app_label() {
         use super::AppLabel;
@@ -1825,6 +2600,151 @@ mod tests {
         app.update();
     }
 
+    #[test]
+    fn sub_apps_are_ordered() {
+        use super::AppLabel;
+        use crate::SubAppOrderExt;
+
+        #[derive(AppLabel, Clone, Copy, Hash, PartialEq, Eq, Debug)]
+        enum Sub {
+            A,
+            B,
+            C,
+        }
+
+        #[derive(Resource, Default)]
+        struct Order(Vec<Sub>);
+
+        let mut app = App::new();
+        app.init_resource::<Order>();
+        // Constraints can be added before the sub-apps.
+        app.configure_sub_app_order(Sub::C.before(Sub::A));
+        for label in [Sub::A, Sub::B, Sub::C] {
+            let mut sub_app = SubApp::new();
+            sub_app.set_extract(move |main_world, _| {
+                main_world.resource_mut::<Order>().0.push(label);
+            });
+            app.insert_sub_app(label, sub_app);
+        }
+        app.configure_sub_app_order(Sub::B.after(Sub::A));
+
+        app.update();
+        assert_eq!(
+            app.world().resource::<Order>().0,
+            vec![Sub::C, Sub::A, Sub::B]
+        );
+        assert_eq!(
+            app.sub_apps().order(),
+            vec![Sub::C.intern(), Sub::A.intern(), Sub::B.intern()]
+        );
+
+        // Removed sub-apps are skipped, and without `A` nothing orders `B` and `C` anymore.
+        app.remove_sub_app(Sub::A);
+        app.world_mut().resource_mut::<Order>().0.clear();
+        app.update();
+        assert_eq!(app.world().resource::<Order>().0, vec![Sub::B, Sub::C]);
+    }
+
+    #[test]
+    #[should_panic(expected = "creates a cycle")]
+    fn cyclic_sub_app_order_panics() {
+        use super::AppLabel;
+        use crate::SubAppOrderExt;
+
+        #[derive(AppLabel, Clone, Copy, Hash, PartialEq, Eq, Debug)]
+        enum Sub {
+            A,
+            B,
+        }
+
+        App::new()
+            .configure_sub_app_order(Sub::A.before(Sub::B))
+            .configure_sub_app_order(Sub::A.after(Sub::B));
+    }
+
+    #[test]
+    fn sub_app_plugins_follow_the_main_lifecycle() {
+        use super::AppLabel;
+        use core::sync::atomic::{AtomicBool, Ordering};
+
+        #[derive(AppLabel, Clone, Copy, Hash, PartialEq, Eq, Debug)]
+        struct MySubApp;
+
+        static READY: AtomicBool = AtomicBool::new(false);
+
+        #[derive(Resource, Default)]
+        struct Lifecycle(Vec<&'static str>);
+
+        struct SubAppPlugin;
+        impl Plugin for SubAppPlugin {
+            fn build(&self, app: &mut App) {
+                app.init_resource::<Lifecycle>();
+            }
+            fn ready(&self, _: &App) -> bool {
+                READY.load(Ordering::Relaxed)
+            }
+            fn finish(&self, app: &mut App) {
+                app.world_mut().resource_mut::<Lifecycle>().0.push("finish");
+            }
+            fn cleanup(&self, app: &mut App) {
+                app.world_mut()
+                    .resource_mut::<Lifecycle>()
+                    .0
+                    .push("cleanup");
+            }
+        }
+
+        let mut app = App::new();
+        let mut sub_app = SubApp::new();
+        sub_app.add_plugins(SubAppPlugin);
+        app.insert_sub_app(MySubApp, sub_app);
+
+        assert_eq!(app.plugins_state(), PluginsState::Adding);
+        READY.store(true, Ordering::Relaxed);
+        assert_eq!(app.plugins_state(), PluginsState::Ready);
+        app.finish();
+        app.cleanup();
+        assert_eq!(app.plugins_state(), PluginsState::Cleaned);
+        assert_eq!(
+            app.sub_app(MySubApp).world().resource::<Lifecycle>().0,
+            vec!["finish", "cleanup"]
+        );
+    }
+
+    #[test]
+    fn extract_runs_between_main_and_sub_app_updates() {
+        use super::AppLabel;
+
+        #[derive(AppLabel, Clone, Copy, Hash, PartialEq, Eq, Debug)]
+        struct MySubApp;
+
+        #[derive(Resource, Default)]
+        struct Steps(Vec<&'static str>);
+
+        let mut app = App::new();
+        app.init_resource::<Steps>()
+            .add_systems(Update, |mut steps: ResMut<Steps>| steps.0.push("main"));
+
+        let mut sub_app = SubApp::new();
+        sub_app.update_schedule = Some(Main.intern());
+        sub_app.init_resource::<Steps>();
+        sub_app.add_systems(Main, |mut steps: ResMut<Steps>| steps.0.push("sub app"));
+        sub_app.set_extract(|main_world, sub_world| {
+            // Both worlds can be mutated.
+            main_world.resource_mut::<Steps>().0.push("extract");
+            let main_steps = main_world.resource::<Steps>().0.clone();
+            sub_world.resource_mut::<Steps>().0.extend(main_steps);
+        });
+        app.insert_sub_app(MySubApp, sub_app);
+
+        app.update();
+        assert_eq!(app.world().resource::<Steps>().0, vec!["main", "extract"]);
+        assert_eq!(
+            app.sub_app(MySubApp).world().resource::<Steps>().0,
+            vec!["main", "extract", "sub app"]
+        );
+    }
+
     #[test]
     fn runner_returns_correct_exit_code() {
         fn raise_exits(mut exits: EventWriter<AppExit>) {
@@ -1840,6 +2760,122 @@ mod tests {
         assert_eq!(exit, AppExit::from_code(4));
     }
 
+    #[test]
+    fn run_returning_maps_exit_codes() {
+        assert_eq!(App::new().run_returning(), Ok(()));
+
+        let error = App::new()
+            .set_runner(|_| AppExit::from_code(3))
+            .run_returning()
+            .unwrap_err();
+        assert_eq!(error, AppRunError::Exit(NonZero::new(3).unwrap()));
+        assert_eq!(AppExit::from(error), AppExit::from_code(3));
+    }
+
+    #[test]
+    fn run_returning_reports_plugin_failures() {
+        struct FailingFinish;
+        impl Plugin for FailingFinish {
+            fn build(&self, _: &mut App) {}
+            fn finish(&self, _: &mut App) {
+                panic!("no GPU found");
+            }
+        }
+
+        struct FailingBuild;
+        impl Plugin for FailingBuild {
+            fn build(&self, _: &mut App) {
+                panic!("invalid settings");
+            }
+        }
+
+        // Adds `FailingBuild`, which is reported instead of this plugin.
+        struct Outer;
+        impl Plugin for Outer {
+            fn build(&self, app: &mut App) {
+                app.add_plugins(FailingBuild);
+            }
+        }
+
+        let error = App::new()
+            .add_plugins((PluginA, FailingFinish))
+            .run_returning()
+            .unwrap_err();
+        assert_eq!(
+            error,
+            AppRunError::Plugin {
+                plugin: FailingFinish.name().to_string(),
+                message: "no GPU found".to_string(),
+            }
+        );
+        assert_eq!(AppExit::from(error), AppExit::error());
+
+        let error = App::new()
+            .set_runner(|mut app| {
+                app.add_plugins(Outer);
+                AppExit::Success
+            })
+            .run_returning()
+            .unwrap_err();
+        assert_eq!(
+            error,
+            AppRunError::Plugin {
+                plugin: FailingBuild.name().to_string(),
+                message: "invalid settings".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "runner failed")]
+    fn run_returning_propagates_other_panics() {
+        let _ = App::new()
+            .set_runner(|_| panic!("runner failed"))
+            .run_returning();
+    }
+
+    #[test]
+    fn runner_wrappers_run_in_order() {
+        use alloc::sync::Arc;
+
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let wrapper = |name: &'static str, calls: Arc<Mutex<Vec<String>>>| {
+            move |runner: super::RunnerFn| -> super::RunnerFn {
+                Box::new(move |app| {
+                    calls.lock().unwrap().push(format!("{name} before"));
+                    let exit = runner(app);
+                    calls.lock().unwrap().push(format!("{name} after {exit:?}"));
+                    exit
+                })
+            }
+        };
+
+        let exit = App::new()
+            .wrap_runner(wrapper("inner", calls.clone()))
+            .wrap_runner(wrapper("outer", calls.clone()))
+            // The runner is wrapped even though it is set after the wrappers.
+            .set_runner({
+                let calls = calls.clone();
+                move |_| {
+                    calls.lock().unwrap().push("runner".to_string());
+                    AppExit::from_code(3)
+                }
+            })
+            .run();
+
+        assert_eq!(exit, AppExit::from_code(3));
+        assert_eq!(
+            *calls.lock().unwrap(),
+            vec![
+                "outer before",
+                "inner before",
+                "runner",
+                "inner after Error(3)",
+                "outer after Error(3)",
+            ]
+        );
+    }
+
     /// Custom runners should be in charge of when `app::update` gets called as they may need to
     /// coordinate some state.
     /// bug: <https://github.com/bevyengine/bevy/issues/10385>
@@ -1959,4 +2995,161 @@ mod tests {
         assert_eq!(test_events.len(), 2); // Events are double-buffered, so we see 2 + 0 = 2
         assert_eq!(test_events.iter_current_update_events().count(), 0);
     }
+
+    #[cfg(feature = "auto_register_events")]
+    #[test]
+    fn derived_events_are_added_when_finished() {
+        use bevy_ecs::event::EventSettings;
+
+        #[derive(BufferedEvent)]
+        #[buffered_event(auto_register)]
+        struct LevelUp(u32);
+
+        #[derive(BufferedEvent)]
+        #[buffered_event(auto_register)]
+        struct ItemDropped;
+
+        #[derive(Resource, Default)]
+        struct Levels(u32);
+
+        let mut app = App::new();
+        app.add_event_with::<ItemDropped>(EventSettings {
+            capacity: 8,
+            ..Default::default()
+        })
+        .init_resource::<Levels>()
+        .add_systems(
+            Update,
+            (
+                |mut level_ups: EventWriter<LevelUp>| {
+                    level_ups.write(LevelUp(1));
+                },
+                |mut level_ups: EventReader<LevelUp>, mut levels: ResMut<Levels>| {
+                    levels.0 += level_ups.read().map(|level_up| level_up.0).sum::<u32>();
+                },
+            )
+                .chain(),
+        );
+        assert!(!app.world().contains_resource::<Events<LevelUp>>());
+
+        app.finish();
+        app.update();
+        app.update();
+        assert_eq!(app.world().resource::<Levels>().0, 2);
+        // The events added explicitly keep their settings.
+        let dropped = app.world().resource::<Events<ItemDropped>>();
+        assert_eq!(
+            dropped.settings().map(|settings| settings.capacity),
+            Some(8)
+        );
+    }
+
+    #[test]
+    fn async_commands_are_applied_next_frame() {
+        #[derive(BufferedEvent)]
+        struct Loaded(u32);
+
+        #[derive(Resource, Default)]
+        struct Received(Vec<u32>);
+
+        let mut app = App::new();
+        app.add_event::<Loaded>()
+            .init_resource::<Received>()
+            .add_systems(
+                Update,
+                |mut events: EventReader<Loaded>, mut received: ResMut<Received>| {
+                    received.0.extend(events.read().map(|event| event.0));
+                },
+            );
+        app.update();
+
+        let commands = app.world_mut().async_commands();
+        std::thread::spawn(move || commands.write_event(Loaded(1)).unwrap())
+            .join()
+            .unwrap();
+        assert!(app.world().resource::<Received>().0.is_empty());
+        app.update();
+        assert_eq!(app.world().resource::<Received>().0, vec![1]);
+    }
+
+    #[test]
+    fn explain_frame() {
+        #[derive(Resource)]
+        struct Paused(bool);
+
+        fn paused(paused: Res<Paused>) -> bool {
+            paused.0
+        }
+
+        #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+        struct Render;
+
+        fn simulate() {}
+
+        let mut app = App::new();
+        app.insert_resource(Paused(false))
+            .add_systems(Update, simulate.run_if(not(paused)));
+
+        let explanation = app.explain_frame(Update).unwrap();
+        assert_eq!(explanation.would_run().count(), 1);
+        app.world_mut().resource_mut::<Paused>().0 = true;
+        let explanation = app.explain_frame(Update).unwrap();
+        assert_eq!(explanation.would_run().count(), 0);
+        assert_eq!(
+            app.world_mut()
+                .run_explain_command("explain Update")
+                .unwrap(),
+            explanation.to_string()
+        );
+
+        assert!(app.explain_frame(Render).is_err());
+    }
+
+    #[test]
+    fn describe_schedules_and_sets() {
+        #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+        #[schedule_doc = "Runs the physics."]
+        struct Physics;
+
+        #[derive(SystemSet, Clone, Debug, PartialEq, Eq, Hash)]
+        struct Movement;
+
+        struct PhysicsPlugin;
+        impl Plugin for PhysicsPlugin {
+            fn build(&self, app: &mut App) {
+                app.describe_schedule(Physics, "Steps the simulation.")
+                    .describe_set(Movement, "Moves the bodies.");
+            }
+        }
+
+        struct CharacterPlugin;
+        impl Plugin for CharacterPlugin {
+            fn build(&self, app: &mut App) {
+                app.describe_set(Movement, "Moves the characters.");
+            }
+        }
+
+        let mut app = App::new();
+        app.add_schedule(Schedule::new(Physics));
+        assert_eq!(
+            app.world_mut()
+                .get_resource_or_init::<ScheduleDescriptions>()
+                .schedule(Physics),
+            Some("Runs the physics.")
+        );
+
+        // The last description wins.
+        app.add_plugins((PhysicsPlugin, CharacterPlugin));
+        let descriptions = app.world().resource::<ScheduleDescriptions>();
+        assert_eq!(
+            descriptions.schedule(Physics),
+            Some("Steps the simulation.")
+        );
+        assert_eq!(descriptions.set(Movement), Some("Moves the characters."));
+        let (_, movement) = descriptions.iter_sets().next().unwrap();
+        assert_eq!(
+            movement.described_by.as_deref(),
+            Some(core::any::type_name::<CharacterPlugin>())
+        );
+    }
 }
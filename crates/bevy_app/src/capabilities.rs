@@ -0,0 +1,423 @@
+use crate::{App, SubApp};
+use alloc::{
+    borrow::ToOwned,
+    string::{String, ToString},
+};
+use bevy_platform::collections::HashMap;
+use core::{fmt, num::ParseIntError, str::FromStr};
+use thiserror::Error;
+
+/// The capability declared by [`App::declare_capability`].
+pub const CAPABILITY_CAPABILITIES: &str = "bevy_app.capabilities";
+/// Plugins can require resources before they are built, see
+/// [`Plugin::required_resources`](crate::Plugin::required_resources).
+pub const CAPABILITY_REQUIRED_RESOURCES: &str = "bevy_app.required_resources";
+/// Plugins can be added only if they weren't already, see [`App::add_plugins_if_new`].
+pub const CAPABILITY_ADD_PLUGINS_IF_NEW: &str = "bevy_app.add_plugins_if_new";
+/// Plugins can be rebuilt while the app runs, see [`App::rebuild_plugin`].
+pub const CAPABILITY_PLUGIN_REBUILD: &str = "bevy_app.plugin_rebuild";
+/// The plugins of an app can be listed, see [`App::iter_plugins`].
+pub const CAPABILITY_ITER_PLUGINS: &str = "bevy_app.iter_plugins";
+/// Observers of the same event can be ordered, see [`App::add_observer_with_priority`].
+pub const CAPABILITY_OBSERVER_ORDERING: &str = "bevy_ecs.observer_ordering";
+
+/// The version of a capability declared with [`App::declare_capability`].
+///
+/// Versions follow semantic versioning: a capability satisfies a requirement if they have the same
+/// major version and the capability is at least as recent as the requirement.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CapabilityVersion {
+    /// Incremented for incompatible changes.
+    pub major: u32,
+    /// Incremented for backward compatible additions.
+    pub minor: u32,
+    /// Incremented for backward compatible fixes.
+    pub patch: u32,
+}
+
+impl CapabilityVersion {
+    /// Creates a version from its components.
+    pub const fn new(major: u32, minor: u32, patch: u32) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+        }
+    }
+
+    /// Returns `true` if this version can be used where `required` is required.
+    pub fn satisfies(&self, required: CapabilityVersion) -> bool {
+        self.major == required.major && *self >= required
+    }
+}
+
+impl fmt::Display for CapabilityVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+impl FromStr for CapabilityVersion {
+    type Err = ParseIntError;
+
+    /// Parses `major.minor.patch`, where `minor` and `patch` default to 0 if they are omitted.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.splitn(3, '.');
+        let mut next = || parts.next().map_or(Ok(0), str::parse);
+        Ok(Self::new(next()?, next()?, next()?))
+    }
+}
+
+/// A capability declared by a plugin.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Capability {
+    /// The version of the capability.
+    pub version: CapabilityVersion,
+    /// The name of the plugin that declared the capability, or `None` if it was declared outside
+    /// of a plugin build.
+    pub declared_by: Option<String>,
+}
+
+/// The capabilities declared in a [`SubApp`], see [`App::declare_capability`].
+#[derive(Clone, Debug, Default)]
+pub struct Capabilities {
+    declared: HashMap<String, Capability>,
+}
+
+impl Capabilities {
+    /// Returns the capability declared with this name.
+    pub fn get(&self, name: &str) -> Option<&Capability> {
+        self.declared.get(name)
+    }
+
+    /// Returns an iterator over the declared capabilities and their names.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Capability)> {
+        self.declared
+            .iter()
+            .map(|(name, capability)| (name.as_str(), capability))
+    }
+}
+
+/// An error returned by [`App::require_capability`].
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum CapabilityError {
+    /// No plugin declared the capability.
+    #[error("{} requires the capability `{capability}` {required}, but no plugin declared it", by_plugin(.required_by))]
+    Missing {
+        /// The name of the capability.
+        capability: String,
+        /// The version that was required.
+        required: CapabilityVersion,
+        /// The plugin that required the capability.
+        required_by: Option<String>,
+    },
+    /// The declared version of the capability doesn't satisfy the requirement.
+    #[error("{} requires the capability `{capability}` {required}, but {} declared version {declared}", by_plugin(.required_by), by_plugin(.declared_by))]
+    Incompatible {
+        /// The name of the capability.
+        capability: String,
+        /// The version that was required.
+        required: CapabilityVersion,
+        /// The plugin that required the capability.
+        required_by: Option<String>,
+        /// The version that was declared.
+        declared: CapabilityVersion,
+        /// The plugin that declared the capability.
+        declared_by: Option<String>,
+    },
+}
+
+pub(crate) fn by_plugin(plugin: &Option<String>) -> String {
+    match plugin {
+        Some(plugin) => alloc::format!("plugin `{plugin}`"),
+        None => "the app".to_string(),
+    }
+}
+
+/// Declares the capabilities provided by this crate, in [`MainSchedulePlugin`](crate::MainSchedulePlugin).
+pub(crate) fn declare_core_capabilities(app: &mut App) {
+    for name in [
+        CAPABILITY_CAPABILITIES,
+        CAPABILITY_REQUIRED_RESOURCES,
+        CAPABILITY_ADD_PLUGINS_IF_NEW,
+        CAPABILITY_PLUGIN_REBUILD,
+        CAPABILITY_ITER_PLUGINS,
+        CAPABILITY_OBSERVER_ORDERING,
+    ] {
+        app.declare_capability(name, CapabilityVersion::new(1, 0, 0));
+    }
+}
+
+impl SubApp {
+    /// See [`App::capabilities`].
+    pub fn capabilities(&self) -> &Capabilities {
+        &self.capabilities
+    }
+
+    /// See [`App::declare_capability`].
+    pub fn declare_capability(
+        &mut self,
+        name: impl Into<String>,
+        version: CapabilityVersion,
+    ) -> &mut Self {
+        let name = name.into();
+        let declared_by = self.building_plugins.last().cloned();
+        if let Some(existing) = self.capabilities.declared.get(&name) {
+            assert!(
+                existing.version == version,
+                "{} declared the capability `{name}` {version}, but {} already declared version {}",
+                by_plugin(&declared_by),
+                by_plugin(&existing.declared_by),
+                existing.version,
+            );
+            return self;
+        }
+        self.capabilities.declared.insert(
+            name,
+            Capability {
+                version,
+                declared_by,
+            },
+        );
+        self
+    }
+
+    /// See [`App::has_capability`].
+    pub fn has_capability(&self, name: &str) -> Option<CapabilityVersion> {
+        self.capabilities
+            .get(name)
+            .map(|capability| capability.version)
+    }
+
+    /// See [`App::require_capability`].
+    pub fn require_capability(
+        &self,
+        name: &str,
+        required: CapabilityVersion,
+    ) -> Result<CapabilityVersion, CapabilityError> {
+        let required_by = self.building_plugins.last().cloned();
+        let Some(capability) = self.capabilities.get(name) else {
+            return Err(CapabilityError::Missing {
+                capability: name.to_owned(),
+                required,
+                required_by,
+            });
+        };
+        if !capability.version.satisfies(required) {
+            return Err(CapabilityError::Incompatible {
+                capability: name.to_owned(),
+                required,
+                required_by,
+                declared: capability.version,
+                declared_by: capability.declared_by.clone(),
+            });
+        }
+        Ok(capability.version)
+    }
+}
+
+impl App {
+    /// Returns the capabilities declared in the main [`SubApp`].
+    pub fn capabilities(&self) -> &Capabilities {
+        self.main().capabilities()
+    }
+
+    /// Declares that the app supports the capability `name`, with the given `version`.
+    ///
+    /// Capabilities let plugins detect optional behavior provided by other plugins, for example
+    /// the extensions of a forked plugin, without depending on their types. They are usually
+    /// declared in [`Plugin::build`](crate::Plugin::build), and the declaring plugin is recorded
+    /// to explain errors. Capabilities can only be detected by plugins built afterwards.
+    ///
+    /// Declaring a capability again with the same version does nothing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the capability was already declared with a different version.
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_app::CapabilityVersion;
+    /// fn fast_path_plugin(app: &mut App) {
+    ///     app.declare_capability("my_crate.fast_path", CapabilityVersion::new(1, 2, 0));
+    /// }
+    ///
+    /// fn integration_plugin(app: &mut App) {
+    ///     if app
+    ///         .require_capability("my_crate.fast_path", CapabilityVersion::new(1, 0, 0))
+    ///         .is_ok()
+    ///     {
+    ///         // Enable the optional integration.
+    ///     }
+    /// }
+    ///
+    /// App::new().add_plugins((fast_path_plugin, integration_plugin));
+    /// ```
+    pub fn declare_capability(
+        &mut self,
+        name: impl Into<String>,
+        version: CapabilityVersion,
+    ) -> &mut Self {
+        self.main_mut().declare_capability(name, version);
+        self
+    }
+
+    /// Returns the version of the capability `name`, if it was declared.
+    pub fn has_capability(&self, name: &str) -> Option<CapabilityVersion> {
+        self.main().has_capability(name)
+    }
+
+    /// Returns the version of the capability `name` if it satisfies the `required` version.
+    ///
+    /// See [`CapabilityVersion`] for when a version satisfies a requirement.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error naming the capability and the plugin being built if the capability wasn't
+    /// declared, or if its version doesn't satisfy the requirement.
+    pub fn require_capability(
+        &self,
+        name: &str,
+        required: CapabilityVersion,
+    ) -> Result<CapabilityVersion, CapabilityError> {
+        self.main().require_capability(name, required)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{App, Plugin};
+
+    const FAST_PATH: &str = "test.fast_path";
+
+    struct FastPathPlugin(CapabilityVersion);
+
+    impl Plugin for FastPathPlugin {
+        fn build(&self, app: &mut App) {
+            app.declare_capability(FAST_PATH, self.0);
+        }
+
+        fn is_unique(&self) -> bool {
+            false
+        }
+    }
+
+    struct IntegrationPlugin;
+
+    impl Plugin for IntegrationPlugin {
+        fn build(&self, app: &mut App) {
+            let result = app.require_capability(FAST_PATH, CapabilityVersion::new(1, 2, 0));
+            app.insert_resource(Integration(result));
+        }
+    }
+
+    #[derive(bevy_ecs::resource::Resource)]
+    struct Integration(Result<CapabilityVersion, CapabilityError>);
+
+    fn integration(
+        version: Option<CapabilityVersion>,
+    ) -> Result<CapabilityVersion, CapabilityError> {
+        let mut app = App::new();
+        if let Some(version) = version {
+            app.add_plugins(FastPathPlugin(version));
+        }
+        app.add_plugins(IntegrationPlugin);
+        app.world().resource::<Integration>().0.clone()
+    }
+
+    #[test]
+    fn declare_and_require() {
+        assert_eq!(
+            integration(Some(CapabilityVersion::new(1, 3, 1))),
+            Ok(CapabilityVersion::new(1, 3, 1))
+        );
+
+        let mut app = App::new();
+        app.add_plugins(FastPathPlugin(CapabilityVersion::new(1, 2, 0)));
+        assert_eq!(
+            app.has_capability(FAST_PATH),
+            Some(CapabilityVersion::new(1, 2, 0))
+        );
+        assert_eq!(
+            app.capabilities()
+                .get(FAST_PATH)
+                .unwrap()
+                .declared_by
+                .as_deref(),
+            Some("bevy_app::capabilities::tests::FastPathPlugin")
+        );
+        assert_eq!(app.has_capability("test.other"), None);
+        assert_eq!(
+            app.has_capability(CAPABILITY_REQUIRED_RESOURCES),
+            Some(CapabilityVersion::new(1, 0, 0))
+        );
+    }
+
+    #[test]
+    fn version_requirement_failure() {
+        for declared in [
+            CapabilityVersion::new(1, 1, 9),
+            CapabilityVersion::new(2, 0, 0),
+        ] {
+            let error = integration(Some(declared)).unwrap_err();
+            assert_eq!(
+                error.to_string(),
+                alloc::format!(
+                    "plugin `bevy_app::capabilities::tests::IntegrationPlugin` requires the \
+                    capability `test.fast_path` 1.2.0, but plugin \
+                    `bevy_app::capabilities::tests::FastPathPlugin` declared version {declared}"
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn missing_capability() {
+        let error = integration(None).unwrap_err();
+        assert_eq!(
+            error,
+            CapabilityError::Missing {
+                capability: FAST_PATH.to_string(),
+                required: CapabilityVersion::new(1, 2, 0),
+                required_by: Some("bevy_app::capabilities::tests::IntegrationPlugin".to_string()),
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "plugin `bevy_app::capabilities::tests::IntegrationPlugin` requires the capability \
+            `test.fast_path` 1.2.0, but no plugin declared it"
+        );
+    }
+
+    #[test]
+    fn same_version_declared_twice() {
+        let mut app = App::new();
+        app.add_plugins((
+            FastPathPlugin(CapabilityVersion::new(1, 2, 0)),
+            FastPathPlugin(CapabilityVersion::new(1, 2, 0)),
+        ));
+        assert_eq!(
+            app.has_capability(FAST_PATH),
+            Some(CapabilityVersion::new(1, 2, 0))
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "plugin `bevy_app::capabilities::tests::FastPathPlugin` declared the capability `test.fast_path` 2.0.0, but plugin `bevy_app::capabilities::tests::FastPathPlugin` already declared version 1.2.0"
+    )]
+    fn conflicting_versions() {
+        App::new().add_plugins((
+            FastPathPlugin(CapabilityVersion::new(1, 2, 0)),
+            FastPathPlugin(CapabilityVersion::new(2, 0, 0)),
+        ));
+    }
+
+    #[test]
+    fn parse_version() {
+        assert_eq!("1.2.3".parse(), Ok(CapabilityVersion::new(1, 2, 3)));
+        assert_eq!("4".parse(), Ok(CapabilityVersion::new(4, 0, 0)));
+        assert!("1.x".parse::<CapabilityVersion>().is_err());
+    }
+}
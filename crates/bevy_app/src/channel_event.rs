@@ -0,0 +1,183 @@
+use crate::{App, Plugin};
+use bevy_ecs::{
+    event::{BufferedEvent, EventSender, EventSenderClosedError, Events},
+    resource::Resource,
+};
+use core::marker::PhantomData;
+
+/// Adds the events of type `T`, to be sent from other threads, such as audio callbacks, network
+/// threads or file watchers, into the app.
+///
+/// The plugin inserts a [`ChannelSender<T>`] resource, which can be cloned and moved to any
+/// thread. The events sent through it are written in the order they were sent, to be read with
+/// an [`EventReader`](bevy_ecs::event::EventReader).
+///
+/// The events are written during the event update, at the start of each frame in
+/// [`First`](crate::First), rather than by a system of their own, so there is no schedule to
+/// choose: the systems of every later schedule, like [`PreUpdate`](crate::PreUpdate) or
+/// [`Update`](crate::Update), read them in the same frame. On top of [`App::add_event`], the
+/// plugin can limit the number of sent events written each frame, see
+/// [`max_per_frame`](Self::max_per_frame).
+///
+/// ```
+/// # use bevy_app::{prelude::*, ChannelEventPlugin, ChannelSender};
+/// # use bevy_ecs::prelude::*;
+/// # use std::thread;
+/// #[derive(BufferedEvent)]
+/// struct PacketReceived(Vec<u8>);
+///
+/// let mut app = App::new();
+/// app.add_plugins(ChannelEventPlugin::<PacketReceived>::default().with_max_per_frame(64));
+///
+/// let sender = app.world().resource::<ChannelSender<PacketReceived>>().clone();
+/// thread::spawn(move || {
+///     for packet in [vec![1, 2, 3], vec![4, 5]] {
+///         // Sending only fails once the app is dropped.
+///         if sender.send(PacketReceived(packet)).is_err() {
+///             break;
+///         }
+///     }
+/// });
+/// ```
+pub struct ChannelEventPlugin<T> {
+    /// The maximum number of sent events written per frame, or `None` for no limit. The other
+    /// events are kept for the next frames.
+    ///
+    /// This keeps a thread sending events faster than the app can handle them from making the
+    /// frames longer and longer.
+    pub max_per_frame: Option<usize>,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T> ChannelEventPlugin<T> {
+    /// Writes at most `max_per_frame` sent events per frame.
+    pub fn with_max_per_frame(mut self, max_per_frame: usize) -> Self {
+        self.max_per_frame = Some(max_per_frame);
+        self
+    }
+}
+
+impl<T> Default for ChannelEventPlugin<T> {
+    fn default() -> Self {
+        Self {
+            max_per_frame: None,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T: BufferedEvent> Plugin for ChannelEventPlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.add_event::<T>();
+        let mut events = app.world_mut().resource_mut::<Events<T>>();
+        events.set_max_received(self.max_per_frame);
+        let sender = events.sender();
+        app.insert_resource(ChannelSender(sender));
+    }
+}
+
+/// Sends `T` events into the app from any thread, see [`ChannelEventPlugin`].
+///
+/// This is the [`EventSender`] of the events, inserted as a resource by the plugin.
+#[derive(Resource)]
+pub struct ChannelSender<T: BufferedEvent>(pub EventSender<T>);
+
+impl<T: BufferedEvent> ChannelSender<T> {
+    /// Sends `event`, to be written in the app during the next frame.
+    ///
+    /// Returns the event in an error if the app was dropped, for example after it exited, so
+    /// that the thread sending the events can stop.
+    pub fn send(&self, event: T) -> Result<(), EventSenderClosedError<T>> {
+        self.0.send(event)
+    }
+}
+
+impl<T: BufferedEvent> Clone for ChannelSender<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::{App, ChannelEventPlugin, ChannelSender, First, Update};
+    use alloc::vec::Vec;
+    use bevy_ecs::{event::EventUpdateSystems, prelude::*};
+    use std::thread;
+
+    #[derive(BufferedEvent, Debug, PartialEq, Eq)]
+    struct Packet(u32);
+
+    #[derive(Resource, Default)]
+    struct Received {
+        packets: Vec<u32>,
+        max_per_frame: usize,
+    }
+
+    #[test]
+    fn events_arrive_in_order() {
+        let mut app = App::new();
+        app.add_plugins(ChannelEventPlugin::<Packet>::default().with_max_per_frame(64))
+            .init_resource::<Received>()
+            .add_systems(
+                Update,
+                |mut packets: EventReader<Packet>, mut received: ResMut<Received>| {
+                    let count = packets.len();
+                    received
+                        .packets
+                        .extend(packets.read().map(|packet| packet.0));
+                    received.max_per_frame = received.max_per_frame.max(count);
+                },
+            );
+
+        let sender = app.world().resource::<ChannelSender<Packet>>().clone();
+        let thread = thread::spawn(move || {
+            for i in 0..300 {
+                sender.send(Packet(i)).unwrap();
+            }
+        });
+        let received = |app: &App| app.world().resource::<Received>().packets.len();
+        for _ in 0..100_000 {
+            if received(&app) == 300 {
+                break;
+            }
+            app.update();
+        }
+        thread.join().unwrap();
+
+        let received = app.world().resource::<Received>();
+        assert_eq!(received.packets, (0..300).collect::<Vec<_>>());
+        assert!(received.max_per_frame <= 64);
+    }
+
+    #[test]
+    fn events_are_readable_from_first() {
+        let mut app = App::new();
+        app.add_plugins(ChannelEventPlugin::<Packet>::default())
+            .init_resource::<Received>()
+            .add_systems(
+                First,
+                (|mut packets: EventReader<Packet>, mut received: ResMut<Received>| {
+                    received
+                        .packets
+                        .extend(packets.read().map(|packet| packet.0));
+                })
+                .after(EventUpdateSystems),
+            );
+        app.world_mut()
+            .event_sender::<Packet>()
+            .send(Packet(1))
+            .unwrap();
+        app.update();
+        assert_eq!(app.world().resource::<Received>().packets, [1]);
+    }
+
+    #[test]
+    fn sending_after_the_app_is_dropped_fails() {
+        let mut app = App::new();
+        app.add_plugins(ChannelEventPlugin::<Packet>::default());
+        let sender = app.world().resource::<ChannelSender<Packet>>().clone();
+        drop(app);
+        assert_eq!(sender.send(Packet(1)).unwrap_err().0, Packet(1));
+    }
+}
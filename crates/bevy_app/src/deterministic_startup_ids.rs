@@ -0,0 +1,196 @@
+use crate::{App, MainScheduleOrder, Plugin, PostStartup, Startup};
+use bevy_ecs::{schedule::ScheduleLabel, world::World};
+use log::warn;
+
+/// Opt-in mode that gives entities spawned during [`Startup`] and [`PostStartup`] ids that do not
+/// depend on plugin or system order.
+///
+/// Save files and network handshakes sometimes embed the ids of entities created at startup.
+/// Normally those ids come from a single counter, so adding, removing or reordering an unrelated
+/// plugin shifts them. With this plugin, a block of [`capacity`](Self::capacity) rows starting at
+/// [`first_index`](Self::first_index) is set aside right before [`Startup`], and every entity spawned through [`Commands::spawn`] or
+/// [`Commands::spawn_empty`] until the end of [`PostStartup`] takes its id from that block. The
+/// row is derived from the spawning system's name and from how many entities that system has
+/// spawned so far, so a system that spawns the same entities in the same order always gets the
+/// same ids.
+///
+/// After [`PostStartup`], rows that were never claimed are returned to the regular allocator,
+/// which is used for every entity spawned later on. Rows below `first_index` that are still
+/// unused when the block is set aside are handed to the regular allocator right away.
+///
+/// `first_index` must be above the number of entities that exist before [`Startup`], such as
+/// observers and entities spawned in [`PreStartup`]. Otherwise the block starts after the last
+/// existing entity and a warning is logged, as the ids are no longer independent of earlier
+/// allocations.
+///
+/// Keep `capacity` well above the number of entities spawned at startup: two spawns that map to
+/// the same row are resolved by probing the following rows, which depends on which one happens
+/// first. Once the block is full, spawns fall back to the regular allocator. System names are
+/// only available with the `debug` feature of `bevy_utils`; without it, every system shares the
+/// same name and only single-threaded, in-order spawning is reproducible.
+///
+/// ```
+/// # use bevy_app::{App, DeterministicStartupIds, Startup};
+/// # use bevy_ecs::prelude::*;
+/// fn spawn_level(mut commands: Commands) {
+///     // Gets the same id on every run, whatever other plugins do at startup.
+///     commands.spawn_empty();
+/// }
+///
+/// App::new()
+///     .add_plugins(DeterministicStartupIds::default())
+///     .add_systems(Startup, spawn_level);
+/// ```
+///
+/// [`Commands::spawn`]: bevy_ecs::system::Commands::spawn
+/// [`Commands::spawn_empty`]: bevy_ecs::system::Commands::spawn_empty
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeterministicStartupIds {
+    /// The index of the first entity row set aside for startup spawns.
+    pub first_index: u32,
+    /// The number of entity rows set aside for startup spawns.
+    pub capacity: u32,
+}
+
+impl DeterministicStartupIds {
+    /// Creates the mode with room for `capacity` entities, starting at row `first_index`.
+    pub const fn new(first_index: u32, capacity: u32) -> Self {
+        Self {
+            first_index,
+            capacity,
+        }
+    }
+}
+
+impl Default for DeterministicStartupIds {
+    fn default() -> Self {
+        Self::new(4096, 4096)
+    }
+}
+
+/// Opens the reserved entity range right before [`Startup`].
+#[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+struct OpenStartupIdRange;
+
+/// Closes the reserved entity range right after [`PostStartup`].
+#[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+struct CloseStartupIdRange;
+
+impl Plugin for DeterministicStartupIds {
+    fn build(&self, app: &mut App) {
+        let Self {
+            first_index,
+            capacity,
+        } = *self;
+        app.add_systems(OpenStartupIdRange, move |world: &mut World| {
+            let range = world.begin_reserved_entity_range(first_index, capacity);
+            if range.start != first_index {
+                warn!(
+                    "{} entities exist before `Startup`, startup entity ids will not be deterministic. \
+                    Consider raising `DeterministicStartupIds::first_index` above {first_index}.",
+                    range.start
+                );
+            }
+        })
+        .add_systems(CloseStartupIdRange, |world: &mut World| {
+            world.end_reserved_entity_range();
+        });
+
+        let mut order = app.world_mut().resource_mut::<MainScheduleOrder>();
+        order.insert_startup_before(Startup, OpenStartupIdRange);
+        order.insert_startup_after(PostStartup, CloseStartupIdRange);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{PreStartup, Update};
+    use alloc::vec::Vec;
+    use bevy_ecs::prelude::*;
+
+    #[derive(Component)]
+    struct Gameplay(u32);
+
+    fn spawn_gameplay(mut commands: Commands) {
+        for i in 0..8 {
+            commands.spawn(Gameplay(i));
+        }
+    }
+
+    fn spawn_unrelated_a(mut commands: Commands) {
+        for _ in 0..5 {
+            commands.spawn_empty();
+        }
+    }
+
+    fn spawn_unrelated_b(world: &mut World) {
+        world.spawn_batch((0..3).map(|_| ()));
+    }
+
+    fn gameplay_ids(app: &mut App) -> Vec<(u32, Entity)> {
+        let mut ids = app
+            .world_mut()
+            .query::<(Entity, &Gameplay)>()
+            .iter(app.world())
+            .map(|(entity, gameplay)| (gameplay.0, entity))
+            .collect::<Vec<_>>();
+        ids.sort_unstable_by_key(|(i, _)| *i);
+        ids
+    }
+
+    #[test]
+    fn ids_are_stable_across_unrelated_plugin_order() {
+        let mut first = App::new();
+        first
+            .add_plugins(DeterministicStartupIds::default())
+            .add_systems(PreStartup, spawn_unrelated_b)
+            .add_systems(Startup, (spawn_unrelated_a, spawn_gameplay).chain());
+        first.update();
+
+        let mut second = App::new();
+        second
+            .add_systems(PreStartup, (spawn_unrelated_b, spawn_unrelated_b))
+            .add_systems(Startup, (spawn_gameplay, spawn_unrelated_a).chain())
+            .add_plugins(DeterministicStartupIds::default());
+        second.update();
+
+        let ids = gameplay_ids(&mut first);
+        assert_eq!(ids.len(), 8);
+        assert_eq!(ids, gameplay_ids(&mut second));
+    }
+
+    #[test]
+    fn later_spawns_do_not_collide() {
+        let mut app = App::new();
+        app.add_plugins(DeterministicStartupIds::new(64, 16))
+            .add_systems(Startup, spawn_gameplay)
+            .add_systems(Update, spawn_gameplay);
+        app.update();
+        app.update();
+        app.update();
+
+        let ids = gameplay_ids(&mut app);
+        assert_eq!(ids.len(), 32);
+        let mut entities = ids.iter().map(|(_, entity)| *entity).collect::<Vec<_>>();
+        entities.sort_unstable();
+        entities.dedup();
+        assert_eq!(entities.len(), 32);
+        assert!(app.world().entities().reserved_range().is_none());
+    }
+
+    #[test]
+    fn default_allocation_is_unchanged() {
+        let mut app = App::new();
+        app.add_systems(Startup, spawn_gameplay);
+        app.update();
+
+        // Without the mode, startup spawns use consecutive ids from the regular allocator.
+        let ids = gameplay_ids(&mut app);
+        let first = ids[0].1.index();
+        assert!(ids
+            .iter()
+            .enumerate()
+            .all(|(i, (_, entity))| entity.index() == first + i as u32));
+    }
+}
@@ -0,0 +1,317 @@
+use crate::LogMessageVisitor;
+use alloc::{boxed::Box, collections::VecDeque, string::String};
+use bevy_platform::{collections::HashMap, time::Instant};
+use core::{
+    ops::DerefMut,
+    sync::atomic::{AtomicBool, Ordering},
+    time::Duration,
+};
+use std::sync::{Mutex, Once, OnceLock, PoisonError};
+use tracing::{
+    callsite::{self, Callsite, Identifier},
+    dispatcher,
+    field::{FieldSet, Value},
+    level_filters::LevelFilter,
+    metadata::Kind,
+    span::{Attributes, Id, Record},
+    subscriber::Interest,
+    Dispatch, Event, Level, Metadata, Subscriber,
+};
+use tracing_core::span::Current;
+
+/// The maximum number of logs buffered before the `LogPlugin` is added. The oldest logs are
+/// dropped past that.
+const MAX_LOGS: usize = 1024;
+
+/// How long the logs are buffered if the `LogPlugin` isn't added.
+const MAX_AGE: Duration = Duration::from_secs(30);
+
+/// The least important level of the buffered logs, the default level of the `LogPlugin`.
+const MAX_LEVEL: Level = Level::INFO;
+
+static EARLY_LOGS: EarlyLogs = EarlyLogs {
+    installed: AtomicBool::new(false),
+    buffering: AtomicBool::new(false),
+    buffer: Mutex::new(None),
+    subscriber: OnceLock::new(),
+};
+
+/// Sets the global tracing subscriber to one buffering the logs until the `LogPlugin` of
+/// `bevy_log` sets up logging, the first time an [`App`](crate::App) is created.
+///
+/// Nothing is buffered if a global subscriber was already set.
+pub(crate) fn buffer_early_logs() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        *EARLY_LOGS.lock_buffer() = Some(Buffer {
+            logs: VecDeque::new(),
+            dropped: 0,
+            since: Instant::now(),
+        });
+        EARLY_LOGS.buffering.store(true, Ordering::Release);
+        if dispatcher::set_global_default(Dispatch::new(EarlyLogsSubscriber)).is_ok() {
+            EARLY_LOGS.installed.store(true, Ordering::Release);
+        } else {
+            EARLY_LOGS.buffering.store(false, Ordering::Release);
+            *EARLY_LOGS.lock_buffer() = None;
+        }
+    });
+}
+
+/// Forwards the logs to `subscriber` from now on, after replaying the logs buffered since the
+/// first [`App`](crate::App) was created.
+///
+/// This is used by the `LogPlugin` of `bevy_log` instead of setting the global tracing
+/// subscriber, which is already set to the one buffering the logs. The replayed logs keep their
+/// level and target, have their fields formatted after their message, and have a
+/// `replayed = true` field.
+///
+/// Returns `subscriber` back if the global subscriber isn't the one buffering the logs, because
+/// another one was set before the first [`App`](crate::App) was created, or if the logs are
+/// already forwarded to another subscriber.
+///
+/// The subscriber can't be downcast through the global [`Dispatch`], so
+/// [`Dispatch::downcast_ref`] and the `SpanTrace` of `tracing-error` don't find it.
+pub fn forward_early_logs(
+    subscriber: impl Subscriber + Send + Sync,
+) -> Result<(), Box<dyn Subscriber + Send + Sync>> {
+    let mut subscriber: Option<Box<dyn Subscriber + Send + Sync>> = Some(Box::new(subscriber));
+    if EARLY_LOGS.installed.load(Ordering::Acquire) {
+        EARLY_LOGS
+            .subscriber
+            .get_or_init(|| subscriber.take().unwrap());
+    }
+    if let Some(subscriber) = subscriber {
+        return Err(subscriber);
+    }
+    let subscriber = EARLY_LOGS.subscriber.get().unwrap();
+    let buffer = EARLY_LOGS.lock_buffer().take();
+    EARLY_LOGS.buffering.store(false, Ordering::Release);
+    // The interests of the callsites were cached while buffering.
+    callsite::rebuild_interest_cache();
+
+    // The logs were dropped if they were buffered for too long.
+    let Some(buffer) = buffer else {
+        return Ok(());
+    };
+    let mut replay_callsites = HashMap::<Identifier, &'static ReplayCallsite>::default();
+    for log in buffer.logs {
+        let callsite = *replay_callsites
+            .entry(log.metadata.callsite())
+            .or_insert_with(|| ReplayCallsite::new(log.metadata));
+        let metadata = callsite.metadata();
+        if !subscriber.enabled(metadata) {
+            continue;
+        }
+        let fields = metadata.fields();
+        let message = fields.field("message").unwrap();
+        let replayed = fields.field("replayed").unwrap();
+        let values = [
+            (&message, Some(&log.message.as_str() as &dyn Value)),
+            (&replayed, Some(&true as &dyn Value)),
+        ];
+        let values = fields.value_set(&values);
+        let event = Event::new(metadata, &values);
+        if subscriber.event_enabled(&event) {
+            subscriber.event(&event);
+        }
+    }
+    if buffer.dropped > 0 {
+        tracing::warn!(
+            "{} logs written before the LogPlugin was added were dropped, only the last {MAX_LOGS} \
+            were kept",
+            buffer.dropped
+        );
+    }
+    Ok(())
+}
+
+struct EarlyLogs {
+    /// Whether the global subscriber is the one buffering the logs.
+    installed: AtomicBool,
+    /// Whether the logs are buffered, checked without locking the buffer.
+    buffering: AtomicBool,
+    buffer: Mutex<Option<Buffer>>,
+    /// The subscriber of the `LogPlugin`, once it is added.
+    subscriber: OnceLock<Box<dyn Subscriber + Send + Sync>>,
+}
+
+impl EarlyLogs {
+    fn lock_buffer(&self) -> impl DerefMut<Target = Option<Buffer>> + '_ {
+        self.buffer.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+struct Buffer {
+    logs: VecDeque<EarlyLog>,
+    /// The number of logs dropped because the buffer was full.
+    dropped: usize,
+    /// When the logs started being buffered.
+    since: Instant,
+}
+
+struct EarlyLog {
+    metadata: &'static Metadata<'static>,
+    /// The message of the log, followed by its other fields.
+    message: String,
+}
+
+/// The global tracing subscriber, buffering the logs until the `LogPlugin` is added, then
+/// forwarding everything to its subscriber.
+///
+/// Spans and the logs less important than [`MAX_LEVEL`] are disabled while buffering. Once the
+/// logs are forwarded, the interests of the callsites are rebuilt, so the filter of the
+/// `LogPlugin` applies instead.
+struct EarlyLogsSubscriber;
+
+impl EarlyLogsSubscriber {
+    fn forward<T>(&self, f: impl FnOnce(&(dyn Subscriber + Send + Sync)) -> T) -> Option<T> {
+        EARLY_LOGS
+            .subscriber
+            .get()
+            .map(|subscriber| f(&**subscriber))
+    }
+}
+
+impl Subscriber for EarlyLogsSubscriber {
+    fn register_callsite(&self, metadata: &'static Metadata<'static>) -> Interest {
+        self.forward(|subscriber| subscriber.register_callsite(metadata))
+            .unwrap_or_else(Interest::sometimes)
+    }
+
+    fn max_level_hint(&self) -> Option<LevelFilter> {
+        self.forward(Subscriber::max_level_hint)
+            .unwrap_or(Some(LevelFilter::from_level(MAX_LEVEL)))
+    }
+
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        self.forward(|subscriber| subscriber.enabled(metadata))
+            .unwrap_or_else(|| {
+                metadata.is_event()
+                    && *metadata.level() <= MAX_LEVEL
+                    && EARLY_LOGS.buffering.load(Ordering::Acquire)
+            })
+    }
+
+    fn new_span(&self, span: &Attributes<'_>) -> Id {
+        // Spans are disabled while buffering, so a subscriber is always set here.
+        self.forward(|subscriber| subscriber.new_span(span))
+            .unwrap_or_else(|| Id::from_u64(u64::MAX))
+    }
+
+    fn record(&self, span: &Id, values: &Record<'_>) {
+        self.forward(|subscriber| subscriber.record(span, values));
+    }
+
+    fn record_follows_from(&self, span: &Id, follows: &Id) {
+        self.forward(|subscriber| subscriber.record_follows_from(span, follows));
+    }
+
+    fn event_enabled(&self, event: &Event<'_>) -> bool {
+        self.forward(|subscriber| subscriber.event_enabled(event))
+            .unwrap_or(true)
+    }
+
+    fn event(&self, event: &Event<'_>) {
+        if self.forward(|subscriber| subscriber.event(event)).is_some() {
+            return;
+        }
+        let mut buffer = EARLY_LOGS.lock_buffer();
+        let Some(logs) = buffer.as_mut() else {
+            return;
+        };
+        if logs.since.elapsed() > MAX_AGE {
+            drop_logs(&mut buffer);
+            return;
+        }
+        if logs.logs.len() == MAX_LOGS {
+            logs.logs.pop_front();
+            logs.dropped += 1;
+        }
+        let mut visitor = LogMessageVisitor::default();
+        event.record(&mut visitor);
+        logs.logs.push_back(EarlyLog {
+            metadata: event.metadata(),
+            message: visitor.message,
+        });
+    }
+
+    fn enter(&self, span: &Id) {
+        self.forward(|subscriber| subscriber.enter(span));
+    }
+
+    fn exit(&self, span: &Id) {
+        self.forward(|subscriber| subscriber.exit(span));
+    }
+
+    fn clone_span(&self, id: &Id) -> Id {
+        self.forward(|subscriber| subscriber.clone_span(id))
+            .unwrap_or_else(|| id.clone())
+    }
+
+    fn try_close(&self, id: Id) -> bool {
+        self.forward(|subscriber| subscriber.try_close(id))
+            .unwrap_or(false)
+    }
+
+    fn current_span(&self) -> Current {
+        self.forward(Subscriber::current_span)
+            .unwrap_or_else(Current::none)
+    }
+}
+
+/// Drops the buffered logs when the `LogPlugin` wasn't added in time.
+#[expect(
+    clippy::print_stderr,
+    reason = "The logs can't be used to report that they were dropped"
+)]
+fn drop_logs(buffer: &mut Option<Buffer>) {
+    if let Some(logs) = buffer.take() {
+        EARLY_LOGS.buffering.store(false, Ordering::Release);
+        std::eprintln!(
+            "{} logs were dropped, as no LogPlugin was added within {}s of creating the App",
+            logs.logs.len() + logs.dropped,
+            MAX_AGE.as_secs()
+        );
+    }
+}
+
+/// The callsite of the replayed logs of a callsite, with the same metadata, but with only a
+/// `message` and a `replayed` field.
+struct ReplayCallsite {
+    metadata: OnceLock<Metadata<'static>>,
+}
+
+impl ReplayCallsite {
+    /// Creates the callsite for the logs of `original`, leaked as there is one per callsite that
+    /// logged before the `LogPlugin` was added.
+    fn new(original: &'static Metadata<'static>) -> &'static Self {
+        let callsite: &'static Self = Box::leak(Box::new(Self {
+            metadata: OnceLock::new(),
+        }));
+        let _ = callsite.metadata.set(Metadata::new(
+            original.name(),
+            original.target(),
+            *original.level(),
+            original.file(),
+            original.line(),
+            original.module_path(),
+            FieldSet::new(&["message", "replayed"], Identifier(callsite)),
+            Kind::EVENT,
+        ));
+        callsite::register(callsite);
+        callsite
+    }
+
+    fn metadata(&self) -> &Metadata<'static> {
+        self.metadata.get().unwrap()
+    }
+}
+
+impl Callsite for ReplayCallsite {
+    fn set_interest(&self, _interest: Interest) {}
+
+    fn metadata(&self) -> &Metadata<'_> {
+        ReplayCallsite::metadata(self)
+    }
+}
@@ -0,0 +1,525 @@
+use crate::{App, Plugin};
+use alloc::{
+    collections::BTreeMap,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use bevy_ecs::prelude::*;
+use bevy_reflect::{
+    DynamicEnum, DynamicVariant, PartialReflect, Reflect, ReflectMut, ReflectRef, TypeInfo,
+    VariantInfo,
+};
+use core::marker::PhantomData;
+use log::{debug, warn};
+use thiserror::Error;
+
+/// The settings of [`App::env_resource`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EnvResourceSettings {
+    /// The prefix of the environment variables, without the trailing `_`.
+    pub prefix: String,
+    /// If `true`, the environment variables override the values set by code. By default, the
+    /// values set by code take precedence.
+    pub override_code: bool,
+}
+
+impl From<&str> for EnvResourceSettings {
+    fn from(prefix: &str) -> Self {
+        Self {
+            prefix: prefix.to_string(),
+            override_code: false,
+        }
+    }
+}
+
+/// Reports how the environment variables were applied by [`App::env_resource`].
+///
+/// Invalid values and unknown variables don't stop the app, they are logged and listed here.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq)]
+pub struct EnvConfigReport {
+    /// The variables whose values were applied.
+    pub applied: Vec<String>,
+    /// The variables that were ignored because the field was set by code.
+    pub overridden: Vec<String>,
+    /// The variables whose values couldn't be parsed.
+    pub errors: Vec<EnvVarError>,
+    /// The variables starting with a prefix that don't match any field.
+    pub unknown: Vec<UnknownEnvVar>,
+}
+
+/// The value of an environment variable couldn't be parsed, see [`EnvConfigReport`].
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("invalid value {value:?} for {variable}, expected {expected}")]
+pub struct EnvVarError {
+    /// The name of the variable.
+    pub variable: String,
+    /// The value of the variable.
+    pub value: String,
+    /// A description of the expected values.
+    pub expected: String,
+}
+
+/// An environment variable with the prefix of a resource that doesn't match any of its fields,
+/// see [`EnvConfigReport`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownEnvVar {
+    /// The name of the variable.
+    pub variable: String,
+    /// The closest variable matching a field, if any is close enough to be a typo.
+    pub suggestion: Option<String>,
+}
+
+/// Applies the environment variables to the resource `R` once the plugins are built.
+struct EnvResourcePlugin<R> {
+    settings: EnvResourceSettings,
+    /// The variables to use instead of the environment of the process.
+    vars: Option<BTreeMap<String, String>>,
+    marker: PhantomData<fn() -> R>,
+}
+
+impl<R: Resource + Default + Reflect> Plugin for EnvResourcePlugin<R> {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<EnvConfigReport>();
+    }
+
+    fn finish(&self, app: &mut App) {
+        let vars = match &self.vars {
+            Some(vars) => vars.clone(),
+            None => std::env::vars_os()
+                .filter_map(|(name, value)| {
+                    Some((name.into_string().ok()?, value.into_string().ok()?))
+                })
+                .collect(),
+        };
+        apply_env::<R>(app.world_mut(), &self.settings, &vars);
+    }
+
+    fn is_unique(&self) -> bool {
+        false
+    }
+}
+
+fn apply_env<R: Resource + Default + Reflect>(
+    world: &mut World,
+    settings: &EnvResourceSettings,
+    vars: &BTreeMap<String, String>,
+) {
+    let var_prefix = format!("{}_", settings.prefix);
+    let mut report = EnvConfigReport::default();
+    let mut known = Vec::new();
+
+    let default = R::default();
+    if !world.contains_resource::<R>() {
+        world.insert_resource(R::default());
+    }
+    let mut resource = world.resource_mut::<R>();
+    visit_fields(
+        resource.bypass_change_detection().as_partial_reflect_mut(),
+        default.as_partial_reflect(),
+        &var_prefix,
+        &mut |variable, field, default| {
+            if let Some(value) = vars.get(&variable) {
+                if !settings.override_code && field.reflect_partial_eq(default) != Some(true) {
+                    report.overridden.push(variable.clone());
+                } else {
+                    match parse_into(field, value) {
+                        Ok(()) => report.applied.push(variable.clone()),
+                        Err(expected) => report.errors.push(EnvVarError {
+                            variable: variable.clone(),
+                            value: value.clone(),
+                            expected,
+                        }),
+                    }
+                }
+            }
+            known.push(variable);
+        },
+    );
+    if !report.applied.is_empty() {
+        resource.set_changed();
+    }
+
+    for variable in vars.keys() {
+        if variable.starts_with(&var_prefix) && !known.contains(variable) {
+            let suggestion = known
+                .iter()
+                .map(|name| (edit_distance(name, variable), name))
+                .filter(|(distance, _)| *distance <= 3)
+                .min_by_key(|(distance, _)| *distance)
+                .map(|(_, name)| name.clone());
+            report.unknown.push(UnknownEnvVar {
+                variable: variable.clone(),
+                suggestion,
+            });
+        }
+    }
+
+    for variable in &report.applied {
+        debug!("applied the environment variable {variable}");
+    }
+    for variable in &report.overridden {
+        debug!("ignored the environment variable {variable}, the value was set by code");
+    }
+    for error in &report.errors {
+        warn!("{error}");
+    }
+    for unknown in &report.unknown {
+        match &unknown.suggestion {
+            Some(suggestion) => warn!(
+                "unknown environment variable {}, did you mean {suggestion}?",
+                unknown.variable
+            ),
+            None => warn!("unknown environment variable {}", unknown.variable),
+        }
+    }
+
+    let mut total = world.get_resource_or_init::<EnvConfigReport>();
+    total.applied.append(&mut report.applied);
+    total.overridden.append(&mut report.overridden);
+    total.errors.append(&mut report.errors);
+    total.unknown.append(&mut report.unknown);
+}
+
+/// Calls `visit` with the variable name, the value and the default value of each field of the
+/// struct `value`, recursing into the fields that are structs.
+fn visit_fields(
+    value: &mut dyn PartialReflect,
+    default: &dyn PartialReflect,
+    var_prefix: &str,
+    visit: &mut dyn FnMut(String, &mut dyn PartialReflect, &dyn PartialReflect),
+) {
+    let (ReflectMut::Struct(value), ReflectRef::Struct(default)) =
+        (value.reflect_mut(), default.reflect_ref())
+    else {
+        return;
+    };
+    for index in 0..value.field_len() {
+        let Some(name) = value.name_at(index) else {
+            continue;
+        };
+        let variable = format!("{var_prefix}{}", name.to_uppercase());
+        let (Some(field), Some(default)) = (value.field_at_mut(index), default.field_at(index))
+        else {
+            continue;
+        };
+        if matches!(field.reflect_ref(), ReflectRef::Struct(_)) {
+            visit_fields(field, default, &format!("{variable}__"), visit);
+        } else {
+            visit(variable, field, default);
+        }
+    }
+}
+
+/// Parses `value` into `field`, or returns a description of the expected values.
+fn parse_into(field: &mut dyn PartialReflect, value: &str) -> Result<(), String> {
+    let value = value.trim();
+    if let Some(field) = field.try_downcast_mut::<String>() {
+        *field = value.to_string();
+        return Ok(());
+    }
+    if let Some(field) = field.try_downcast_mut::<bool>() {
+        *field = match value.to_ascii_lowercase().as_str() {
+            "true" | "1" => true,
+            "false" | "0" => false,
+            _ => return Err(String::from("`true`, `false`, `1` or `0`")),
+        };
+        return Ok(());
+    }
+
+    macro_rules! parse_numbers {
+        ($($ty:ty),*) => {$(
+            if let Some(field) = field.try_downcast_mut::<$ty>() {
+                *field = value
+                    .parse()
+                    .map_err(|_| String::from(concat!("a `", stringify!($ty), "`")))?;
+                return Ok(());
+            }
+        )*};
+    }
+    parse_numbers!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+    if let Some(TypeInfo::Enum(info)) = field.get_represented_type_info() {
+        let variants = info
+            .iter()
+            .filter(|variant| matches!(variant, VariantInfo::Unit(_)))
+            .map(VariantInfo::name);
+        if let Some(variant) = variants
+            .clone()
+            .find(|variant| variant.eq_ignore_ascii_case(value))
+        {
+            return field
+                .try_apply(&DynamicEnum::new(variant, DynamicVariant::Unit))
+                .map_err(|error| error.to_string());
+        }
+        let variants = variants
+            .map(|variant| format!("`{variant}`"))
+            .collect::<Vec<_>>();
+        return Err(format!("one of {}", variants.join(", ")));
+    }
+
+    Err(String::from(
+        "a field of a supported type: a bool, a number, a string or an enum",
+    ))
+}
+
+/// Returns the number of single character edits to turn `a` into `b`.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut previous = (0..=b.len()).collect::<Vec<_>>();
+    for (i, a) in a.chars().enumerate() {
+        let mut current = Vec::with_capacity(b.len() + 1);
+        current.push(i + 1);
+        for (j, b) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a != *b);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+impl App {
+    /// Sets the fields of the resource `R` from the environment variables, to configure an app
+    /// without recompiling it.
+    ///
+    /// A field is set by the variable named after the prefix and the field in upper case,
+    /// separated by `_`, such as `GAME_MAX_PLAYERS` for the field `max_players` with the prefix
+    /// `GAME`. The fields of nested structs are separated by `__`, such as `GAME_WINDOW__WIDTH`.
+    /// Booleans, numbers, strings and the unit variants of enums, by name, are supported.
+    ///
+    /// The variables are applied once the plugins are [finished](Plugin::finish), with this
+    /// precedence:
+    /// 1. The [`Default`] value of `R`, inserted if the resource doesn't exist.
+    /// 2. The environment variables.
+    /// 3. The values set by code, with [`App::insert_resource`] or by a plugin. A field that
+    ///    differs from its default value is considered set by code, and keeps its value.
+    ///
+    /// Set [`override_code`](EnvResourceSettings::override_code) to apply the environment
+    /// variables over the values set by code.
+    ///
+    /// Invalid values and variables with the prefix that don't match a field are logged and
+    /// reported in the [`EnvConfigReport`] resource, they don't stop the app.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_app::{EnvConfigReport, EnvResourceSettings};
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_reflect::Reflect;
+    /// #[derive(Resource, Reflect, Default)]
+    /// struct ServerConfig {
+    ///     port: u16,
+    ///     name: String,
+    /// }
+    ///
+    /// App::new()
+    ///     // Reads `SERVER_PORT` and `SERVER_NAME`.
+    ///     .env_resource::<ServerConfig>("SERVER")
+    ///     .env_resource::<ServerConfig>(EnvResourceSettings {
+    ///         prefix: "OVERRIDE".into(),
+    ///         override_code: true,
+    ///     });
+    /// ```
+    pub fn env_resource<R: Resource + Default + Reflect>(
+        &mut self,
+        settings: impl Into<EnvResourceSettings>,
+    ) -> &mut Self {
+        self.add_plugins(EnvResourcePlugin::<R> {
+            settings: settings.into(),
+            vars: None,
+            marker: PhantomData,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Resource, Reflect, Default, Debug, PartialEq)]
+    struct Config {
+        max_players: u32,
+        name: String,
+        verbose: bool,
+        quality: Quality,
+        window: Window,
+    }
+
+    #[derive(Reflect, Default, Debug, PartialEq)]
+    struct Window {
+        width: f32,
+        fullscreen: bool,
+    }
+
+    #[derive(Reflect, Default, Debug, PartialEq)]
+    enum Quality {
+        #[default]
+        Low,
+        High,
+    }
+
+    fn app(settings: impl Into<EnvResourceSettings>, vars: &[(&str, &str)]) -> App {
+        let mut app = App::new();
+        app.add_plugins(EnvResourcePlugin::<Config> {
+            settings: settings.into(),
+            vars: Some(
+                vars.iter()
+                    .map(|(name, value)| (name.to_string(), value.to_string()))
+                    .collect(),
+            ),
+            marker: PhantomData,
+        });
+        app
+    }
+
+    fn finish(mut app: App) -> App {
+        app.finish();
+        app
+    }
+
+    #[test]
+    fn nested_fields() {
+        let app = finish(app(
+            "GAME",
+            &[
+                ("GAME_MAX_PLAYERS", "8"),
+                ("GAME_NAME", "Arena"),
+                ("GAME_VERBOSE", "true"),
+                ("GAME_WINDOW__WIDTH", "1280.5"),
+                ("GAME_WINDOW__FULLSCREEN", "1"),
+                ("OTHER_NAME", "ignored"),
+            ],
+        ));
+
+        assert_eq!(
+            app.world().resource::<Config>(),
+            &Config {
+                max_players: 8,
+                name: "Arena".into(),
+                verbose: true,
+                quality: Quality::Low,
+                window: Window {
+                    width: 1280.5,
+                    fullscreen: true,
+                },
+            }
+        );
+        let report = app.world().resource::<EnvConfigReport>();
+        assert_eq!(report.applied.len(), 5);
+        assert!(report.errors.is_empty() && report.unknown.is_empty());
+    }
+
+    #[test]
+    fn code_takes_precedence() {
+        let vars = [("GAME_MAX_PLAYERS", "8"), ("GAME_NAME", "Arena")];
+        let mut app = app("GAME", &vars);
+        app.insert_resource(Config {
+            max_players: 4,
+            ..Default::default()
+        });
+        let app = finish(app);
+
+        // Only the field set by code keeps its value.
+        let config = app.world().resource::<Config>();
+        assert_eq!((config.max_players, config.name.as_str()), (4, "Arena"));
+        let report = app.world().resource::<EnvConfigReport>();
+        assert_eq!(report.overridden, ["GAME_MAX_PLAYERS"]);
+        assert_eq!(report.applied, ["GAME_NAME"]);
+    }
+
+    #[test]
+    fn env_overrides_code() {
+        let settings = EnvResourceSettings {
+            prefix: "GAME".into(),
+            override_code: true,
+        };
+        let mut app = app(settings, &[("GAME_MAX_PLAYERS", "8")]);
+        app.insert_resource(Config {
+            max_players: 4,
+            ..Default::default()
+        });
+        let app = finish(app);
+
+        assert_eq!(app.world().resource::<Config>().max_players, 8);
+    }
+
+    #[test]
+    fn enum_variants() {
+        let high = finish(app("GAME", &[("GAME_QUALITY", "high")]));
+        assert_eq!(high.world().resource::<Config>().quality, Quality::High);
+
+        let ultra = finish(app("GAME", &[("GAME_QUALITY", "Ultra")]));
+        let report = ultra.world().resource::<EnvConfigReport>();
+        assert_eq!(report.errors[0].expected, "one of `Low`, `High`");
+    }
+
+    #[test]
+    fn parse_errors_are_collected() {
+        let app = finish(app(
+            "GAME",
+            &[
+                ("GAME_MAX_PLAYERS", "-3"),
+                ("GAME_VERBOSE", "maybe"),
+                ("GAME_NAME", "Arena"),
+            ],
+        ));
+
+        // The valid variables are still applied.
+        let config = app.world().resource::<Config>();
+        assert_eq!((config.max_players, config.name.as_str()), (0, "Arena"));
+        let report = app.world().resource::<EnvConfigReport>();
+        assert_eq!(
+            report.errors,
+            [
+                EnvVarError {
+                    variable: "GAME_MAX_PLAYERS".into(),
+                    value: "-3".into(),
+                    expected: "a `u32`".into(),
+                },
+                EnvVarError {
+                    variable: "GAME_VERBOSE".into(),
+                    value: "maybe".into(),
+                    expected: "`true`, `false`, `1` or `0`".into(),
+                },
+            ]
+        );
+        assert_eq!(
+            report.errors[0].to_string(),
+            "invalid value \"-3\" for GAME_MAX_PLAYERS, expected a `u32`"
+        );
+    }
+
+    #[test]
+    fn unknown_variables_are_reported() {
+        let app = finish(app(
+            "GAME",
+            &[
+                ("GAME_MAX_PLAYER", "8"),
+                ("GAME_WINDOW__WIDHT", "720"),
+                ("GAME_SEED", "1"),
+            ],
+        ));
+
+        let report = app.world().resource::<EnvConfigReport>();
+        assert_eq!(
+            report.unknown,
+            [
+                UnknownEnvVar {
+                    variable: "GAME_MAX_PLAYER".into(),
+                    suggestion: Some("GAME_MAX_PLAYERS".into()),
+                },
+                UnknownEnvVar {
+                    variable: "GAME_SEED".into(),
+                    suggestion: None,
+                },
+                UnknownEnvVar {
+                    variable: "GAME_WINDOW__WIDHT".into(),
+                    suggestion: Some("GAME_WINDOW__WIDTH".into()),
+                },
+            ]
+        );
+        assert_eq!(app.world().resource::<Config>().max_players, 0);
+    }
+}
@@ -0,0 +1,283 @@
+use crate::App;
+use bevy_ecs::{event::BufferedEvent, resource::Resource};
+
+#[cfg(debug_assertions)]
+use {
+    crate::First,
+    alloc::{
+        format,
+        string::{String, ToString},
+        vec::Vec,
+    },
+    bevy_ecs::{
+        change_detection::DetectChangesMut,
+        event::{EventUpdateSystems, Events, UnconsumedEvents},
+        schedule::IntoScheduleConfigs,
+        system::{Res, ResMut},
+    },
+    log::error,
+};
+
+/// Configures the checks added by [`App::assert_event_consumed_same_frame`].
+#[derive(Resource, Debug, Default, Clone, PartialEq, Eq)]
+pub struct SameFrameEventSettings {
+    /// Whether to panic when an event isn't read in the frame it was written, instead of logging
+    /// an error.
+    pub strict: bool,
+}
+
+impl App {
+    /// Asserts that the events of type `E` are read in the frame they are written.
+    ///
+    /// Some events must be handled in the frame they are written, like input that must be
+    /// applied before rendering. Since events are kept for two frames, a reader running after
+    /// the events are updated in [`First`] still receives them, but one frame late. After each
+    /// update of the events, this checks whether a reader read every event written since the
+    /// previous update, and logs an error naming the event type and the number of late events
+    /// otherwise. With [`SameFrameEventSettings::strict`], it panics instead. If the
+    /// `track_location` feature of `bevy_ecs` is enabled, the error also lists where the events
+    /// were written.
+    ///
+    /// The checks only exist in debug builds: in release builds, this only
+    /// [adds](App::add_event) the event.
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// #[derive(BufferedEvent)]
+    /// struct ApplyInput;
+    ///
+    /// fn poll_input(mut events: EventWriter<ApplyInput>) {
+    ///     events.write(ApplyInput);
+    /// }
+    ///
+    /// fn apply_input(mut events: EventReader<ApplyInput>) {
+    ///     for _ in events.read() {}
+    /// }
+    ///
+    /// App::new()
+    ///     .assert_event_consumed_same_frame::<ApplyInput>()
+    ///     // Moving `apply_input` to `PreUpdate` would log an error.
+    ///     .add_systems(Update, (poll_input, apply_input).chain());
+    /// ```
+    pub fn assert_event_consumed_same_frame<E: BufferedEvent>(&mut self) -> &mut Self {
+        self.add_event::<E>();
+
+        #[cfg(debug_assertions)]
+        {
+            self.world_mut()
+                .resource_mut::<Events<E>>()
+                .track_consumption();
+            self.add_systems(
+                First,
+                check_consumed_same_frame::<E>.after(EventUpdateSystems),
+            );
+        }
+
+        self
+    }
+}
+
+/// Reports the events of type `E` that were not read before the last update of the events.
+#[cfg(debug_assertions)]
+fn check_consumed_same_frame<E: BufferedEvent>(
+    mut events: ResMut<Events<E>>,
+    settings: Option<Res<SameFrameEventSettings>>,
+) {
+    let Some(unconsumed) = events.bypass_change_detection().take_unconsumed() else {
+        return;
+    };
+    let message = unconsumed_message::<E>(&unconsumed);
+    if settings.is_some_and(|settings| settings.strict) {
+        panic!("{message}");
+    }
+    error!("{message}");
+}
+
+#[cfg(debug_assertions)]
+fn unconsumed_message<E: BufferedEvent>(unconsumed: &UnconsumedEvents) -> String {
+    let mut message = format!(
+        "{} `{}` event(s) were not read in the frame they were written, their readers probably \
+        run after the events are updated in `First`",
+        unconsumed.count,
+        core::any::type_name::<E>(),
+    );
+    if !unconsumed.writers.is_empty() {
+        let writers = unconsumed
+            .writers
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        message.push_str(&format!(" (written at {})", writers.join(", ")));
+    }
+    message
+}
+
+#[cfg(all(test, debug_assertions))]
+mod tests {
+    use super::unconsumed_message;
+    use crate::{App, PreUpdate, SameFrameEventSettings, Update};
+    use alloc::{
+        string::{String, ToString},
+        vec,
+        vec::Vec,
+    };
+    use bevy_ecs::{
+        change_detection::MaybeLocation,
+        event::{BufferedEvent, EventReader, EventWriter, Events, UnconsumedEvents},
+        schedule::IntoScheduleConfigs,
+    };
+    use core::panic::Location;
+    use std::sync::{Mutex, Once};
+
+    #[derive(BufferedEvent)]
+    struct ApplyInput;
+
+    fn write_input(mut events: EventWriter<ApplyInput>) {
+        events.write(ApplyInput);
+    }
+
+    fn read_input(mut events: EventReader<ApplyInput>) {
+        for _ in events.read() {}
+    }
+
+    /// The errors logged by the tests, see [`logged_errors`].
+    static ERRORS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+    struct ErrorLogger;
+
+    impl log::Log for ErrorLogger {
+        fn enabled(&self, metadata: &log::Metadata) -> bool {
+            metadata.level() == log::Level::Error
+        }
+
+        fn log(&self, record: &log::Record) {
+            if self.enabled(record.metadata()) {
+                ERRORS.lock().unwrap().push(record.args().to_string());
+            }
+        }
+
+        fn flush(&self) {}
+    }
+
+    /// Returns the errors logged about the events of type `E` since the start of the tests.
+    fn logged_errors<E: BufferedEvent>() -> Vec<String> {
+        static INSTALL: Once = Once::new();
+        INSTALL.call_once(|| {
+            log::set_logger(&ErrorLogger).unwrap();
+            log::set_max_level(log::LevelFilter::Error);
+        });
+        let name = core::any::type_name::<E>();
+        ERRORS
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|error| error.contains(name))
+            .cloned()
+            .collect()
+    }
+
+    fn take_unconsumed(app: &mut App) -> Option<UnconsumedEvents> {
+        app.world_mut()
+            .resource_mut::<Events<ApplyInput>>()
+            .take_unconsumed()
+    }
+
+    #[test]
+    fn logs_late_consumers() {
+        #[derive(BufferedEvent)]
+        struct LateInput;
+
+        assert!(logged_errors::<LateInput>().is_empty());
+        let mut app = App::new();
+        app.assert_event_consumed_same_frame::<LateInput>()
+            .add_systems(Update, |mut events: EventWriter<LateInput>| {
+                events.write(LateInput);
+            })
+            .add_systems(
+                PreUpdate,
+                |mut events: EventReader<LateInput>| {
+                    for _ in events.read() {}
+                },
+            );
+
+        for _ in 0..3 {
+            app.update();
+        }
+        // The check took the report of each frame.
+        assert_eq!(
+            app.world_mut()
+                .resource_mut::<Events<LateInput>>()
+                .take_unconsumed(),
+            None
+        );
+        // The events of the first two frames were read one frame late.
+        let errors = logged_errors::<LateInput>();
+        assert_eq!(errors.len(), 2);
+        for error in errors {
+            assert!(error.starts_with(
+                "1 `bevy_app::event_consumption::tests::logs_late_consumers::LateInput` event(s)"
+            ));
+            if MaybeLocation::caller().into_option().is_some() {
+                assert!(error.contains(&alloc::format!("(written at {}:", file!())));
+            }
+        }
+    }
+
+    #[test]
+    fn no_false_positives() {
+        let mut app = App::new();
+        app.insert_resource(SameFrameEventSettings { strict: true })
+            .assert_event_consumed_same_frame::<ApplyInput>()
+            .add_systems(Update, (write_input, read_input).chain());
+
+        for _ in 0..3 {
+            app.update();
+        }
+        assert_eq!(take_unconsumed(&mut app), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "1 `bevy_app::event_consumption::tests::ApplyInput` event(s)")]
+    fn iterators_reading_nothing_dont_consume() {
+        let mut app = App::new();
+        app.insert_resource(SameFrameEventSettings { strict: true })
+            .assert_event_consumed_same_frame::<ApplyInput>()
+            .add_systems(
+                Update,
+                (write_input, |mut events: EventReader<ApplyInput>| {
+                    // Creates the iterator, but reads nothing.
+                    let _ = events.read();
+                })
+                    .chain(),
+            );
+
+        app.update();
+        app.update();
+    }
+
+    #[test]
+    #[should_panic(expected = "1 `bevy_app::event_consumption::tests::ApplyInput` event(s)")]
+    fn strict_mode_panics_on_late_consumers() {
+        let mut app = App::new();
+        app.insert_resource(SameFrameEventSettings { strict: true })
+            .assert_event_consumed_same_frame::<ApplyInput>()
+            .add_systems(Update, write_input)
+            .add_systems(PreUpdate, read_input);
+
+        app.update();
+        // The event of the first frame is read in `PreUpdate` of the second one.
+        app.update();
+    }
+
+    #[test]
+    fn message_names_the_writers() {
+        let writer = Location::caller();
+        let message = unconsumed_message::<ApplyInput>(&UnconsumedEvents {
+            count: 2,
+            writers: vec![writer],
+        });
+        assert!(message.starts_with("2 `bevy_app::event_consumption::tests::ApplyInput` event(s)"));
+        assert!(message.ends_with(&alloc::format!("(written at {writer})")));
+    }
+}
@@ -0,0 +1,388 @@
+use crate::{App, First, FrameNumber, Last, OnAppExit, Plugin};
+use alloc::{collections::VecDeque, vec::Vec};
+use bevy_ecs::{
+    event::{BufferedEvent, EventReader, EventUpdateSystems, Events},
+    resource::Resource,
+    schedule::IntoScheduleConfigs,
+    system::{Res, ResMut},
+};
+use core::marker::PhantomData;
+use log::error;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+use thiserror::Error;
+
+/// Records the events of type `T` written while the app runs, with the [frame](FrameNumber) they
+/// were written in, to replay them later with an [`EventReplayPlugin`], for example to reproduce
+/// a bug that only happens after a precise sequence of inputs.
+///
+/// The events are recorded in [`Last`] into the [`EventRecorder<T>`] resource, in a compact
+/// binary format. When a [`path`](Self::path) is set, the recording is saved to it when the app
+/// exits, in [`OnAppExit`].
+///
+/// ```no_run
+/// # use bevy_app::{prelude::*, EventRecorderPlugin};
+/// # use bevy_ecs::prelude::*;
+/// # use serde::{Deserialize, Serialize};
+/// #[derive(BufferedEvent, Serialize, Deserialize)]
+/// struct KeyPressed(char);
+///
+/// App::new()
+///     .add_plugins(EventRecorderPlugin::<KeyPressed>::new("inputs.rec"))
+///     .run();
+/// ```
+pub struct EventRecorderPlugin<T> {
+    /// The file the recording is saved to when the app exits, or `None` to only keep it in the
+    /// [`EventRecorder<T>`].
+    pub path: Option<PathBuf>,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T> EventRecorderPlugin<T> {
+    /// Records the events, and saves them to `path` when the app exits.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: Some(path.into()),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Default for EventRecorderPlugin<T> {
+    fn default() -> Self {
+        Self {
+            path: None,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T: BufferedEvent + Serialize> Plugin for EventRecorderPlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.add_event::<T>()
+            .insert_resource(EventRecorder::<T> {
+                bytes: Vec::new(),
+                path: self.path.clone(),
+                marker: PhantomData,
+            })
+            .add_systems(Last, record_events::<T>)
+            .add_systems(OnAppExit, save_recording::<T>);
+    }
+}
+
+/// The events of type `T` recorded by the [`EventRecorderPlugin<T>`].
+#[derive(Resource)]
+pub struct EventRecorder<T: BufferedEvent> {
+    bytes: Vec<u8>,
+    path: Option<PathBuf>,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T: BufferedEvent> EventRecorder<T> {
+    /// Returns the encoded recording, as saved to a file.
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Decodes the recording.
+    pub fn recording(&self) -> Result<EventRecording<T>, EventRecordingError>
+    where
+        T: DeserializeOwned,
+    {
+        EventRecording::from_bytes(&self.bytes)
+    }
+
+    /// Saves the recording to `path`, which can be replayed with [`EventReplayPlugin::load`].
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        fs::write(path, &self.bytes)
+    }
+}
+
+/// Appends the events written during the frame to the recording.
+fn record_events<T: BufferedEvent + Serialize>(
+    mut events: EventReader<T>,
+    frame: Res<FrameNumber>,
+    mut recorder: ResMut<EventRecorder<T>>,
+) {
+    if events.is_empty() {
+        return;
+    }
+    let frame = RecordedFrame {
+        frame: frame.get(),
+        events: events.read().collect(),
+    };
+    match postcard::to_allocvec(&frame) {
+        Ok(bytes) => recorder.bytes.extend(bytes),
+        Err(err) => error!(
+            "Failed to record the `{}` events of frame {}: {err}",
+            core::any::type_name::<T>(),
+            frame.frame
+        ),
+    }
+}
+
+/// Saves the recording to the path of the plugin, if any.
+fn save_recording<T: BufferedEvent>(recorder: Res<EventRecorder<T>>) {
+    if let Some(path) = &recorder.path
+        && let Err(err) = recorder.save(path)
+    {
+        error!(
+            "Failed to save the `{}` events to {}: {err}",
+            core::any::type_name::<T>(),
+            path.display()
+        );
+    }
+}
+
+/// Replays the events of type `T` recorded by an [`EventRecorderPlugin<T>`], at the
+/// [frames](FrameNumber) they were recorded in.
+///
+/// While the replay runs, the events written by the app itself are discarded, see
+/// [`Events::set_writes_blocked`], so that the readers only see the recorded ones. The events of
+/// each recorded frame are written in [`First`], right after the events are updated, so the
+/// readers of that frame read them. Once all the recorded frames were replayed, the app writes
+/// its own events again. The [`EventReplay<T>`] resource tells when the replay is finished.
+///
+/// ```no_run
+/// # use bevy_app::{prelude::*, EventReplayPlugin};
+/// # use bevy_ecs::prelude::*;
+/// # use serde::{Deserialize, Serialize};
+/// # #[derive(BufferedEvent, Serialize, Deserialize)]
+/// # struct KeyPressed(char);
+/// let replay = EventReplayPlugin::<KeyPressed>::load("inputs.rec").unwrap();
+/// App::new().add_plugins(replay).run();
+/// ```
+pub struct EventReplayPlugin<T> {
+    bytes: Vec<u8>,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T: DeserializeOwned> EventReplayPlugin<T> {
+    /// Replays the recording saved to `path`.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, EventRecordingError> {
+        Self::from_bytes(fs::read(path)?)
+    }
+
+    /// Replays the recording encoded in `bytes`, such as the [`EventRecorder::bytes`].
+    pub fn from_bytes(bytes: Vec<u8>) -> Result<Self, EventRecordingError> {
+        // Check the recording now, so that building the plugin can't fail.
+        EventRecording::<T>::from_bytes(&bytes)?;
+        Ok(Self {
+            bytes,
+            marker: PhantomData,
+        })
+    }
+}
+
+impl<T: BufferedEvent + DeserializeOwned> Plugin for EventReplayPlugin<T> {
+    fn build(&self, app: &mut App) {
+        let recording = EventRecording::<T>::from_bytes(&self.bytes)
+            .expect("the recording is checked when the plugin is created");
+        let finished = recording.frames.is_empty();
+        app.add_event::<T>()
+            .insert_resource(EventReplay {
+                frames: recording.frames.into(),
+            })
+            .add_systems(First, replay_events::<T>.after(EventUpdateSystems));
+        app.world_mut()
+            .resource_mut::<Events<T>>()
+            .set_writes_blocked(!finished);
+    }
+}
+
+/// The events of type `T` left to replay by the [`EventReplayPlugin<T>`].
+#[derive(Resource)]
+pub struct EventReplay<T: BufferedEvent> {
+    frames: VecDeque<RecordedFrame<T>>,
+}
+
+impl<T: BufferedEvent> EventReplay<T> {
+    /// Returns `true` once all the recorded events were replayed.
+    pub fn is_finished(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// Returns the number of recorded frames with events left to replay.
+    pub fn remaining_frames(&self) -> usize {
+        self.frames.len()
+    }
+}
+
+/// Writes the events recorded during the current frame.
+fn replay_events<T: BufferedEvent>(
+    frame: Res<FrameNumber>,
+    mut replay: ResMut<EventReplay<T>>,
+    mut events: ResMut<Events<T>>,
+) {
+    // The frames before the current one can't be replayed anymore, for example if the plugin was
+    // added while the app was running.
+    let mut replayed = false;
+    while let Some(recorded) = replay.frames.front()
+        && recorded.frame <= frame.get()
+    {
+        let recorded = replay.frames.pop_front().unwrap();
+        if recorded.frame == frame.get() {
+            events.set_writes_blocked(false);
+            events.write_batch(recorded.events);
+            replayed = true;
+        }
+    }
+    // The app writes its own events again from the frame after the last replayed one.
+    events.set_writes_blocked(replayed || !replay.is_finished());
+}
+
+/// The events of type `T` recorded by an [`EventRecorderPlugin<T>`], decoded.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EventRecording<T> {
+    /// The frames with recorded events, in increasing order.
+    pub frames: Vec<RecordedFrame<T>>,
+}
+
+impl<T: DeserializeOwned> EventRecording<T> {
+    /// Loads the recording saved to `path`.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, EventRecordingError> {
+        Self::from_bytes(&fs::read(path)?)
+    }
+
+    /// Decodes the recording encoded in `bytes`.
+    pub fn from_bytes(mut bytes: &[u8]) -> Result<Self, EventRecordingError> {
+        let mut frames = Vec::new();
+        while !bytes.is_empty() {
+            let (frame, rest) = postcard::take_from_bytes(bytes)?;
+            frames.push(frame);
+            bytes = rest;
+        }
+        Ok(Self { frames })
+    }
+}
+
+impl<T: Serialize> EventRecording<T> {
+    /// Encodes the recording, for example after editing it, to be saved or replayed with
+    /// [`EventReplayPlugin::from_bytes`].
+    pub fn to_bytes(&self) -> Result<Vec<u8>, EventRecordingError> {
+        let mut bytes = Vec::new();
+        for frame in &self.frames {
+            bytes.extend(postcard::to_allocvec(frame)?);
+        }
+        Ok(bytes)
+    }
+}
+
+/// The events recorded during a frame, see [`EventRecording`].
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct RecordedFrame<T> {
+    /// The [number](FrameNumber) of the frame.
+    pub frame: u64,
+    /// The events written during the frame, in the order they were written.
+    pub events: Vec<T>,
+}
+
+/// An error that occurs when loading an [`EventRecording`].
+#[derive(Error, Debug)]
+pub enum EventRecordingError {
+    /// The recording couldn't be read.
+    #[error("failed to read the recording: {0}")]
+    Io(#[from] io::Error),
+    /// The recording isn't valid, or doesn't hold events of this type.
+    #[error("invalid recording: {0}")]
+    Format(#[from] postcard::Error),
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        App, EventRecorder, EventRecorderPlugin, EventRecording, EventReplay, EventReplayPlugin,
+        RecordedFrame, Update,
+    };
+    use alloc::{vec, vec::Vec};
+    use bevy_ecs::prelude::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(BufferedEvent, Serialize, Deserialize, Debug, Clone, PartialEq)]
+    struct Input(u32);
+
+    #[derive(Resource, Default)]
+    struct Inputs(Vec<(u64, u32)>);
+
+    /// Writes live inputs on the even frames, and keeps all the inputs read.
+    fn app(live: u32) -> App {
+        let mut app = App::new();
+        app.add_event::<Input>()
+            .init_resource::<Inputs>()
+            .add_systems(
+                Update,
+                (
+                    move |frame: Res<crate::FrameNumber>, mut inputs: EventWriter<Input>| {
+                        if frame.get().is_multiple_of(2) {
+                            inputs.write(Input(live + frame.get() as u32));
+                        }
+                    },
+                    |frame: Res<crate::FrameNumber>,
+                     mut inputs: EventReader<Input>,
+                     mut read: ResMut<Inputs>| {
+                        read.0
+                            .extend(inputs.read().map(|input| (frame.get(), input.0)));
+                    },
+                )
+                    .chain(),
+            );
+        app
+    }
+
+    #[test]
+    fn recorded_events_are_replayed_at_the_same_frames() {
+        let mut recorder = app(100);
+        recorder.add_plugins(EventRecorderPlugin::<Input>::default());
+        for _ in 0..5 {
+            recorder.update();
+        }
+        let recorded = recorder.world().resource::<Inputs>().0.clone();
+        assert_eq!(recorded, [(0, 100), (2, 102), (4, 104)]);
+        let bytes = recorder
+            .world()
+            .resource::<EventRecorder<Input>>()
+            .bytes()
+            .to_vec();
+
+        // The live inputs of the replaying app are discarded while the replay runs.
+        let mut replayer = app(200);
+        replayer.add_plugins(EventReplayPlugin::<Input>::from_bytes(bytes).unwrap());
+        for _ in 0..5 {
+            replayer.update();
+        }
+        assert_eq!(replayer.world().resource::<Inputs>().0, recorded);
+        assert!(replayer
+            .world()
+            .resource::<EventReplay<Input>>()
+            .is_finished());
+
+        // Once the replay is finished, the live inputs are written again.
+        replayer.update();
+        replayer.update();
+        assert_eq!(replayer.world().resource::<Inputs>().0[3..], [(6, 206)]);
+    }
+
+    #[test]
+    fn recordings_round_trip() {
+        let recording = EventRecording {
+            frames: vec![
+                RecordedFrame {
+                    frame: 3,
+                    events: vec![Input(1), Input(2)],
+                },
+                RecordedFrame {
+                    frame: 70_000,
+                    events: vec![Input(u32::MAX)],
+                },
+            ],
+        };
+        let bytes = recording.to_bytes().unwrap();
+        // Each frame takes a few bytes, plus the events.
+        assert!(bytes.len() < 20);
+        assert_eq!(EventRecording::from_bytes(&bytes).unwrap(), recording);
+        assert!(EventRecording::<Input>::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+    }
+}
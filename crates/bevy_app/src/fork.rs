@@ -0,0 +1,230 @@
+use crate::{App, PlaceholderPlugin, SystemErrorHandler};
+
+impl App {
+    /// Creates a new app with the same plugins as this one, built again on a fresh world.
+    ///
+    /// The plugins themselves are not cloned but shared: the fork keeps a reference to each of
+    /// them, so their settings are not parsed again, and [`App::get_added_plugins`] returns the
+    /// same instances on both apps. Before the plugins are built in the fork, each plugin's
+    /// [`Plugin::share`](crate::Plugin::share) copies what it opted into sharing, such as
+    /// resources that are expensive to create. This makes forking cheaper than building the same
+    /// plugins from scratch, for example to give each test of a suite its own app.
+    ///
+    /// The fork starts from [`App::new`]:
+    ///
+    /// - Only the plugins added directly to this app are added to the fork. The plugins they add
+    ///   in their [`Plugin::build`](crate::Plugin::build) are added again by that build.
+    ///   Non-unique plugins are added as many times as they were added to this app.
+    /// - Nothing else is copied: not the entities, resources, systems or sub-apps that were added
+    ///   outside of plugins, nor the runner. The default error handler and
+    ///   the [system error handler](App::set_system_error_handler) are kept.
+    /// - Non-send resources are never shared, since the fork may be moved to another thread.
+    ///   Plugins have to create them again in their `build`.
+    /// - The fork's plugins start [being added](crate::PluginsState::Adding), whatever the state
+    ///   of this app's plugins. Since its plugins are shared, they can't be modified through
+    ///   [`App::iter_plugins_mut`] on either app.
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// #[derive(Resource, Clone)]
+    /// struct NavMesh(Vec<u32>);
+    ///
+    /// struct NavigationPlugin;
+    ///
+    /// impl Plugin for NavigationPlugin {
+    ///     fn build(&self, app: &mut App) {
+    ///         if !app.world().contains_resource::<NavMesh>() {
+    ///             // Expensive to compute.
+    ///             app.insert_resource(NavMesh(vec![1, 2, 3]));
+    ///         }
+    ///     }
+    ///
+    ///     fn share(&self, src: &App, dst: &mut App) {
+    ///         dst.insert_resource(src.world().resource::<NavMesh>().clone());
+    ///     }
+    /// }
+    ///
+    /// let mut app = App::new();
+    /// app.add_plugins(NavigationPlugin);
+    ///
+    /// let fork = app.fork();
+    /// assert!(fork.world().contains_resource::<NavMesh>());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if called while a plugin is building.
+    pub fn fork(&self) -> App {
+        if self.is_building_plugins() {
+            panic!("App::fork() was called while a plugin was building.");
+        }
+
+        let mut fork = App::new();
+        if let Some(handler) = self.get_error_handler() {
+            fork.set_error_handler(handler);
+        }
+        if let Some(handler) = self.world().get_resource::<SystemErrorHandler>() {
+            fork.set_system_error_handler(handler.0);
+        }
+        #[cfg(feature = "std")]
+        {
+            fork.report_plugin_failures = self.report_plugin_failures;
+        }
+
+        for plugin in self.iter_plugins() {
+            plugin.share(self, &mut fork);
+        }
+
+        let main = self.main();
+        let registered = main
+            .plugin_registry
+            .iter()
+            .zip(&main.nested_plugins)
+            .filter(|(plugin, nested)| !**nested && !plugin.is::<PlaceholderPlugin>())
+            .map(|(plugin, _)| plugin);
+        for plugin in registered.chain(&main.deferred_plugins) {
+            // Unique plugins already added by `App::new`, or by a plugin added before them, are
+            // skipped.
+            let _ = fork.add_shared_plugin(plugin.clone());
+        }
+        fork
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{App, Plugin, Update};
+    use alloc::{vec, vec::Vec};
+    use bevy_ecs::prelude::*;
+    use bevy_platform::sync::Arc;
+    use core::sync::atomic::{AtomicU32, Ordering};
+
+    #[derive(Resource, Default)]
+    struct Builds(Vec<&'static str>);
+
+    struct Outer;
+
+    impl Plugin for Outer {
+        fn build(&self, app: &mut App) {
+            app.world_mut()
+                .get_resource_or_init::<Builds>()
+                .0
+                .push("outer");
+            app.add_plugins(Inner);
+        }
+
+        fn finish(&self, app: &mut App) {
+            app.world_mut().resource_mut::<Builds>().0.push("finish");
+        }
+    }
+
+    struct Inner;
+
+    impl Plugin for Inner {
+        fn build(&self, app: &mut App) {
+            app.world_mut()
+                .get_resource_or_init::<Builds>()
+                .0
+                .push("inner");
+        }
+    }
+
+    struct Repeated;
+
+    impl Plugin for Repeated {
+        fn build(&self, app: &mut App) {
+            app.world_mut()
+                .get_resource_or_init::<Builds>()
+                .0
+                .push("repeated");
+        }
+
+        fn is_unique(&self) -> bool {
+            false
+        }
+    }
+
+    #[test]
+    fn fork_builds_the_same_plugins() {
+        let mut app = App::new();
+        app.add_plugins((Outer, Repeated, Repeated))
+            .add_systems(Update, || {});
+
+        let mut fork = app.fork();
+        let builds = &fork.world().resource::<Builds>().0;
+        assert_eq!(builds, &["outer", "inner", "repeated", "repeated"]);
+        assert_eq!(
+            fork.iter_plugins().count(),
+            app.iter_plugins().count(),
+            "{:?}",
+            fork.iter_plugins().map(Plugin::name).collect::<Vec<_>>()
+        );
+        assert_eq!(fork.get_added_plugins::<Repeated>().len(), 2);
+        assert!(core::ptr::eq(
+            app.get_added_plugins::<Outer>()[0],
+            fork.get_added_plugins::<Outer>()[0]
+        ));
+        // Only the plugins are forked.
+        assert!(fork.get_schedule(Update).is_none());
+
+        // The shared plugins still run their other hooks on the fork.
+        fork.finish();
+        assert_eq!(fork.world().resource::<Builds>().0.last(), Some(&"finish"));
+        assert_eq!(app.world().resource::<Builds>().0.len(), 4);
+    }
+
+    #[test]
+    fn shared_resources_are_not_created_again() {
+        static CREATED: AtomicU32 = AtomicU32::new(0);
+
+        #[derive(Resource, Clone)]
+        struct Expensive(Arc<Vec<u32>>);
+
+        struct ExpensivePlugin;
+
+        impl Plugin for ExpensivePlugin {
+            fn build(&self, app: &mut App) {
+                if !app.world().contains_resource::<Expensive>() {
+                    CREATED.fetch_add(1, Ordering::Relaxed);
+                    app.insert_resource(Expensive(Arc::new(vec![1, 2, 3])));
+                }
+            }
+
+            fn share(&self, src: &App, dst: &mut App) {
+                dst.insert_resource(src.world().resource::<Expensive>().clone());
+            }
+        }
+
+        let mut app = App::new();
+        app.add_plugins(ExpensivePlugin);
+        let forks = [app.fork(), app.fork()];
+
+        assert_eq!(CREATED.load(Ordering::Relaxed), 1);
+        for fork in &forks {
+            assert!(Arc::ptr_eq(
+                &fork.world().resource::<Expensive>().0,
+                &app.world().resource::<Expensive>().0
+            ));
+        }
+    }
+
+    #[test]
+    fn shared_plugins_cannot_be_modified() {
+        fn repeated_mut(app: &mut App) -> usize {
+            app.iter_plugins_mut()
+                .filter(|plugin| plugin.is::<Repeated>())
+                .count()
+        }
+
+        let mut app = App::new();
+        app.add_plugins(Repeated);
+        assert_eq!(repeated_mut(&mut app), 1);
+
+        let mut fork = app.fork();
+        assert_eq!(repeated_mut(&mut app), 0);
+        assert_eq!(repeated_mut(&mut fork), 0);
+        drop(fork);
+        assert_eq!(repeated_mut(&mut app), 1);
+    }
+}
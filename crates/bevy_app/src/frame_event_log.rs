@@ -0,0 +1,194 @@
+use crate::{App, Last};
+use bevy_ecs::{
+    event::{BufferedEvent, EventSequenceCounter, Events, FrameEventLog},
+    resource::Resource,
+    schedule::{IntoScheduleConfigs, SystemSet},
+    system::ResMut,
+};
+use bevy_platform::collections::HashSet;
+use core::any::TypeId;
+use serde::Serialize;
+
+/// The systems that gather the events logged to the [`FrameEventLog`], in [`Last`].
+///
+/// Systems draining the log should run after this set.
+#[derive(Debug, Hash, PartialEq, Eq, Copy, Clone, SystemSet)]
+pub struct FrameEventLogSystems;
+
+impl App {
+    /// Logs the events of type `E` to the [`FrameEventLog`].
+    ///
+    /// Every event of the logged types is stamped with the world's sequence number when it is
+    /// written, see [`World::stamp_event_sequence`], so the log orders the events of all the logged types by the order in which they
+    /// were written, no matter which system wrote them. The events written during a frame are
+    /// gathered in [`Last`], by the [`FrameEventLogSystems`], and should be drained by a system
+    /// running after them. Events that aren't drained are dropped on the next frame.
+    ///
+    /// This also [adds](App::add_event) the event if needed.
+    ///
+    /// ```
+    /// # use bevy_app::{prelude::*, FrameEventLogSystems};
+    /// # use bevy_ecs::{event::FrameEventLog, prelude::*};
+    /// # use serde::Serialize;
+    /// #[derive(BufferedEvent, Serialize, Clone)]
+    /// struct PlayerMoved(u32);
+    ///
+    /// #[derive(BufferedEvent, Serialize, Clone)]
+    /// struct PlayerFired;
+    ///
+    /// fn send_batch(mut log: ResMut<FrameEventLog>) {
+    ///     let batch = log.drain();
+    ///     // Serialize `batch` and send it.
+    /// }
+    ///
+    /// App::new()
+    ///     .log_events_to_frame_log::<PlayerMoved>()
+    ///     .log_events_to_frame_log::<PlayerFired>()
+    ///     .add_systems(Last, send_batch.after(FrameEventLogSystems));
+    /// ```
+    ///
+    /// [`World::stamp_event_sequence`]: bevy_ecs::world::World::stamp_event_sequence
+    pub fn log_events_to_frame_log<E>(&mut self) -> &mut Self
+    where
+        E: BufferedEvent + Clone + Serialize,
+    {
+        self.add_event::<E>();
+        if !self.world().contains_resource::<FrameEventLog>() {
+            let world = self.world_mut();
+            let counter = world.get_resource_or_init::<EventSequenceCounter>().clone();
+            world.insert_resource(FrameEventLog::new(counter));
+            world.init_resource::<LoggedEventTypes>();
+            self.add_systems(Last, clear_frame_event_log.before(FrameEventLogSystems));
+        }
+        let world = self.world_mut();
+        if !world
+            .resource_mut::<LoggedEventTypes>()
+            .0
+            .insert(TypeId::of::<E>())
+        {
+            return self;
+        }
+
+        world.stamp_event_sequence::<E>();
+        self.add_systems(Last, collect_frame_events::<E>.in_set(FrameEventLogSystems))
+    }
+}
+
+/// The event types logged to the [`FrameEventLog`].
+#[derive(Resource, Default)]
+struct LoggedEventTypes(HashSet<TypeId>);
+
+/// Drops the events of the previous frame that weren't drained.
+fn clear_frame_event_log(mut log: ResMut<FrameEventLog>) {
+    log.clear();
+}
+
+fn collect_frame_events<E: BufferedEvent + Clone + Serialize>(
+    mut log: ResMut<FrameEventLog>,
+    mut events: ResMut<Events<E>>,
+) {
+    log.collect(&mut events);
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+
+    use bevy_ecs::{
+        event::{BufferedEvent, EventWriter, FrameEventLog},
+        resource::Resource,
+        schedule::IntoScheduleConfigs,
+        system::ResMut,
+    };
+    use serde::Serialize;
+
+    use crate::{App, FrameEventLogSystems, Last, Update};
+
+    #[derive(BufferedEvent, Serialize, Clone, Debug, PartialEq)]
+    struct Moved(u32);
+
+    #[derive(BufferedEvent, Serialize, Clone, Debug, PartialEq)]
+    struct Fired(u32);
+
+    /// The batches drained in each frame, as `(sequence, is_moved, value)`.
+    #[derive(Resource, Default)]
+    struct Sent(Vec<Vec<(u64, bool, u32)>>);
+
+    fn send(mut log: ResMut<FrameEventLog>, mut sent: ResMut<Sent>) {
+        let batch = log
+            .drain()
+            .iter()
+            .map(|entry| match entry.downcast_ref::<Moved>() {
+                Some(moved) => (entry.sequence, true, moved.0),
+                None => (
+                    entry.sequence,
+                    false,
+                    entry.downcast_ref::<Fired>().unwrap().0,
+                ),
+            })
+            .collect();
+        sent.0.push(batch);
+    }
+
+    fn app() -> App {
+        let mut app = App::new();
+        app.log_events_to_frame_log::<Moved>()
+            .log_events_to_frame_log::<Fired>()
+            // Logging twice does nothing.
+            .log_events_to_frame_log::<Moved>()
+            .init_resource::<Sent>()
+            .add_systems(Last, send.after(FrameEventLogSystems));
+        app
+    }
+
+    #[test]
+    fn cross_type_order_matches_write_order() {
+        fn move_first(mut moved: EventWriter<Moved>) {
+            moved.write(Moved(1));
+        }
+        fn fire(mut fired: EventWriter<Fired>) {
+            fired.write_batch([Fired(2), Fired(3)]);
+        }
+        fn move_last(mut moved: EventWriter<Moved>) {
+            moved.write(Moved(4));
+        }
+
+        let mut app = app();
+        // `fire` doesn't conflict with the other systems, the ordering makes the test
+        // deterministic.
+        app.add_systems(Update, (move_first, fire, move_last).chain());
+        app.update();
+        app.update();
+
+        let sent = &app.world().resource::<Sent>().0;
+        assert_eq!(
+            sent[0],
+            [(0, true, 1), (1, false, 2), (2, false, 3), (3, true, 4)]
+        );
+        assert_eq!(
+            sent[1],
+            [(4, true, 1), (5, false, 2), (6, false, 3), (7, true, 4)]
+        );
+    }
+
+    #[test]
+    fn drained_and_cleared_each_frame() {
+        let mut app = app();
+        app.world_mut().write_event(Fired(1));
+        app.update();
+        app.update();
+        assert_eq!(
+            app.world().resource::<Sent>().0,
+            vec![vec![(0, false, 1)], vec![]]
+        );
+
+        // Events that aren't drained are dropped on the next frame.
+        let mut app = App::new();
+        app.log_events_to_frame_log::<Moved>();
+        app.world_mut().write_event(Moved(1));
+        app.update();
+        assert_eq!(app.world().resource::<FrameEventLog>().len(), 1);
+        app.update();
+        assert!(app.world().resource::<FrameEventLog>().is_empty());
+    }
+}
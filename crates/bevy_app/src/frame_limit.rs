@@ -0,0 +1,87 @@
+use crate::{App, AppExit, AppExitWriter, FrameNumber, Last, Plugin, ShutdownReason};
+use alloc::format;
+use bevy_ecs::system::Res;
+
+/// Exits the app with [`AppExit::Success`] once it ran a given number of frames.
+///
+/// This caps headless examples and tests that would otherwise loop forever. The [`AppExit`] is
+/// written in [`Last`], during the last frame, with the [`ShutdownReason::Requested`] reason.
+/// Defaults to a single frame.
+///
+/// ```
+/// # use bevy_app::{prelude::*, FrameLimitPlugin, ScheduleRunnerPlugin};
+/// # use bevy_ecs::prelude::*;
+/// #[derive(Resource, Default)]
+/// struct Frames(u32);
+///
+/// let mut app = App::new();
+/// app.add_plugins((ScheduleRunnerPlugin::default(), FrameLimitPlugin::new(3)))
+///     .init_resource::<Frames>()
+///     .add_systems(Update, |mut frames: ResMut<Frames>| frames.0 += 1);
+///
+/// assert_eq!(app.run_frames(10), Ok(Some(AppExit::Success)));
+/// assert_eq!(app.world().resource::<Frames>().0, 3);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FrameLimitPlugin {
+    /// The number of frames to run before exiting. At least one frame is run.
+    pub frames: u32,
+}
+
+impl FrameLimitPlugin {
+    /// Exits the app after `frames` frames.
+    pub fn new(frames: u32) -> Self {
+        Self { frames }
+    }
+}
+
+impl Default for FrameLimitPlugin {
+    fn default() -> Self {
+        Self::new(1)
+    }
+}
+
+impl Plugin for FrameLimitPlugin {
+    fn build(&self, app: &mut App) {
+        let frames = self.frames.max(1);
+        app.add_systems(
+            Last,
+            move |frame: Res<FrameNumber>, mut exit: AppExitWriter| {
+                // The frame number is advanced after `Last`.
+                if frame.get() + 1 == u64::from(frames) {
+                    exit.write_with_message(
+                        AppExit::Success,
+                        ShutdownReason::Requested,
+                        format!("Reached the frame limit: {frames}"),
+                    );
+                }
+            },
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{App, AppExit, FrameLimitPlugin, FrameNumber, ShutdownReason};
+
+    #[test]
+    fn exits_after_the_frame_limit() {
+        let mut app = App::new();
+        app.add_plugins(FrameLimitPlugin::new(5));
+
+        assert_eq!(app.run_frames(4), Ok(None));
+        assert_eq!(app.run_frames(4), Ok(Some(AppExit::Success)));
+        assert_eq!(app.world().resource::<FrameNumber>().get(), 5);
+        let status = app.exit_status().unwrap();
+        assert_eq!(status.reason, ShutdownReason::Requested);
+    }
+
+    #[test]
+    fn runs_at_least_one_frame() {
+        let mut app = App::new();
+        app.add_plugins(FrameLimitPlugin::new(0));
+
+        assert_eq!(app.run_frames(2), Ok(Some(AppExit::Success)));
+        assert_eq!(app.world().resource::<FrameNumber>().get(), 1);
+    }
+}
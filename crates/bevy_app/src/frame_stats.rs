@@ -0,0 +1,210 @@
+use crate::App;
+use alloc::vec::Vec;
+use bevy_ecs::{
+    resource::Resource,
+    schedule::{InternedScheduleLabel, Schedules},
+    world::World,
+};
+use bevy_platform::time::Instant;
+use core::time::Duration;
+
+/// The number of the current frame of the [`Main`](crate::Main) schedule, starting at 0.
+///
+/// It is advanced after the schedules run by [`Main`](crate::Main), and never wraps. It is the
+/// counter reported by [`FrameStats::frame`], and `FrameCount` in `bevy_diagnostic` is derived
+/// from it so that both agree.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FrameNumber(u64);
+
+impl FrameNumber {
+    /// Returns the number of the current frame.
+    pub fn get(&self) -> u64 {
+        self.0
+    }
+
+    /// Advances the frame number, at the end of [`Main::run_main`](crate::Main::run_main).
+    pub(crate) fn advance(world: &mut World) {
+        if let Some(mut frame) = world.get_resource_mut::<FrameNumber>() {
+            frame.0 += 1;
+        }
+    }
+}
+
+/// Configures the [`FrameStats`] returned by [`App::update_with_stats`].
+#[derive(Resource, Debug, Default, Clone, PartialEq, Eq)]
+pub struct FrameStatsSettings {
+    /// Whether to time each schedule run by [`Main`](crate::Main), see [`FrameStats::schedules`].
+    ///
+    /// Disabled by default, as it adds a measurement around every schedule.
+    pub per_schedule_timing: bool,
+}
+
+/// Statistics about a frame, returned by [`App::update_with_stats`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FrameStats {
+    /// The [number](FrameNumber) of the frame.
+    pub frame: u64,
+    /// The time spent in [`App::update`], including the sub-apps.
+    pub schedule_time: Duration,
+    /// The number of systems in the schedules of the main world.
+    pub system_count: usize,
+    /// The time spent in each schedule run by [`Main`](crate::Main), in the order they ran.
+    ///
+    /// Only filled when [`FrameStatsSettings::per_schedule_timing`] is enabled.
+    pub schedules: Vec<ScheduleTime>,
+}
+
+/// The time spent in a schedule, see [`FrameStats::schedules`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScheduleTime {
+    /// The label of the schedule.
+    pub label: InternedScheduleLabel,
+    /// The time spent running the schedule.
+    pub time: Duration,
+}
+
+/// Collects the [`ScheduleTime`]s of the current frame while [`Main`](crate::Main) runs.
+#[derive(Resource, Default)]
+pub(crate) struct ScheduleTimes(pub(crate) Vec<ScheduleTime>);
+
+impl ScheduleTimes {
+    /// Runs the schedule `label`, timing it if per-schedule timing is enabled for this frame.
+    pub(crate) fn run(world: &mut World, label: InternedScheduleLabel) {
+        if !world.contains_resource::<ScheduleTimes>() {
+            let _ = world.try_run_schedule(label);
+            return;
+        }
+
+        let start = Instant::now();
+        if world.try_run_schedule(label).is_err() {
+            return;
+        }
+        let time = start.elapsed();
+        if let Some(mut times) = world.get_resource_mut::<ScheduleTimes>() {
+            times.0.push(ScheduleTime { label, time });
+        }
+    }
+}
+
+impl App {
+    /// Runs [`App::update`], and returns statistics about the frame.
+    ///
+    /// This is meant for profiling harnesses that need cheap numbers without adding the
+    /// diagnostics plugins. Gathering them costs a clock read and a count of the systems, unless
+    /// [`FrameStatsSettings::per_schedule_timing`] is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_app::FrameStatsSettings;
+    /// # use bevy_ecs::schedule::ScheduleLabel;
+    /// let mut app = App::new();
+    /// app.add_systems(Update, || {})
+    ///     .insert_resource(FrameStatsSettings {
+    ///         per_schedule_timing: true,
+    ///     });
+    ///
+    /// for expected in 0..3 {
+    ///     let stats = app.update_with_stats();
+    ///     assert_eq!(stats.frame, expected);
+    ///     assert!(stats.schedules.iter().any(|schedule| schedule.label == Update.intern()));
+    /// }
+    /// ```
+    pub fn update_with_stats(&mut self) -> FrameStats {
+        let per_schedule_timing = self
+            .world()
+            .get_resource::<FrameStatsSettings>()
+            .is_some_and(|settings| settings.per_schedule_timing);
+        if per_schedule_timing {
+            self.world_mut().init_resource::<ScheduleTimes>();
+        }
+        let frame = self
+            .world()
+            .get_resource::<FrameNumber>()
+            .map_or(0, FrameNumber::get);
+
+        let start = Instant::now();
+        self.update();
+        let schedule_time = start.elapsed();
+
+        let schedules = self
+            .world_mut()
+            .remove_resource::<ScheduleTimes>()
+            .map(|times| times.0)
+            .unwrap_or_default();
+        let system_count = self
+            .world()
+            .get_resource::<Schedules>()
+            .map_or(0, |schedules| {
+                schedules
+                    .iter()
+                    .map(|(_, schedule)| schedule.systems_len())
+                    .sum()
+            });
+
+        FrameStats {
+            frame,
+            schedule_time,
+            system_count,
+            schedules,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{App, FixedMain, FrameNumber, FrameStatsSettings, PostUpdate, Startup, Update};
+    use alloc::vec::Vec;
+    use bevy_ecs::schedule::ScheduleLabel;
+
+    #[test]
+    fn frames_are_numbered_across_updates() {
+        let mut app = App::new();
+        app.update();
+        assert_eq!(app.world().resource::<FrameNumber>().get(), 1);
+
+        let stats = app.update_with_stats();
+        assert_eq!(stats.frame, 1);
+        app.update();
+        assert_eq!(app.update_with_stats().frame, 3);
+
+        // Fixed updates are not frames.
+        app.world_mut().run_schedule(FixedMain);
+        assert_eq!(app.world().resource::<FrameNumber>().get(), 4);
+    }
+
+    #[test]
+    fn counts_systems() {
+        let mut app = App::new();
+        let base = app.update_with_stats().system_count;
+        app.add_systems(Update, (|| {}, || {}));
+        assert_eq!(app.update_with_stats().system_count, base + 2);
+    }
+
+    #[test]
+    fn per_schedule_timing_is_opt_in() {
+        let mut app = App::new();
+        app.add_systems(Startup, || {})
+            .add_systems(Update, || {})
+            .add_systems(PostUpdate, || {});
+        assert!(app.update_with_stats().schedules.is_empty());
+
+        app.insert_resource(FrameStatsSettings {
+            per_schedule_timing: true,
+        });
+        let labels = app
+            .update_with_stats()
+            .schedules
+            .iter()
+            .map(|schedule| schedule.label)
+            .collect::<Vec<_>>();
+        // Startup schedules only ran on the first frame.
+        assert!(!labels.contains(&Startup.intern()));
+        let update = labels.iter().position(|&label| label == Update.intern());
+        let post_update = labels
+            .iter()
+            .position(|&label| label == PostUpdate.intern());
+        assert!(update.unwrap() < post_update.unwrap());
+    }
+}
@@ -24,31 +24,105 @@ extern crate alloc;
 extern crate self as bevy_app;
 
 mod app;
+mod capabilities;
+mod channel_event;
+mod deterministic_startup_ids;
+#[cfg(feature = "early_logs")]
+mod early_logs;
+#[cfg(all(feature = "std", feature = "bevy_reflect"))]
+mod env_config;
+mod event_consumption;
+#[cfg(feature = "event_recording")]
+mod event_recording;
+mod fork;
+#[cfg(feature = "serialize")]
+mod frame_event_log;
+mod frame_limit;
+mod frame_stats;
+#[cfg(feature = "log_message")]
+mod log_message;
 mod main_schedule;
+mod missing_schedules;
+mod observer_bridge;
 mod panic_handler;
+mod pause;
 mod plugin;
+#[cfg(feature = "plugin_config")]
+mod plugin_config;
 mod plugin_group;
+mod plugin_rebuild;
 mod propagate;
+#[cfg(feature = "bevy_reflect")]
+mod replication;
+mod run_frames;
 mod schedule_runner;
+mod shutdown;
+#[cfg(feature = "bevy_reflect")]
+mod snapshot;
 mod sub_app;
+mod system_error;
 mod task_pool_plugin;
-#[cfg(all(any(all(unix, not(target_os = "horizon")), windows), feature = "std"))]
+#[cfg(all(
+    any(
+        all(unix, not(target_os = "horizon")),
+        windows,
+        all(target_arch = "wasm32", feature = "web")
+    ),
+    feature = "std"
+))]
 mod terminal_ctrl_c_handler;
+mod warm_up;
 
 #[cfg(feature = "hotpatching")]
 pub mod hotpatch;
 
 pub use app::*;
+pub use capabilities::*;
+pub use channel_event::*;
+pub use deterministic_startup_ids::*;
+#[cfg(feature = "early_logs")]
+pub use early_logs::*;
+#[cfg(all(feature = "std", feature = "bevy_reflect"))]
+pub use env_config::*;
+pub use event_consumption::*;
+#[cfg(feature = "event_recording")]
+pub use event_recording::*;
+#[cfg(feature = "serialize")]
+pub use frame_event_log::*;
+pub use frame_limit::*;
+pub use frame_stats::*;
+#[cfg(feature = "log_message")]
+pub use log_message::*;
 pub use main_schedule::*;
+pub use missing_schedules::*;
 pub use panic_handler::*;
+pub use pause::*;
 pub use plugin::*;
+#[cfg(feature = "plugin_config")]
+pub use plugin_config::*;
 pub use plugin_group::*;
+pub use plugin_rebuild::*;
 pub use propagate::*;
+#[cfg(feature = "bevy_reflect")]
+pub use replication::*;
+pub use run_frames::*;
 pub use schedule_runner::*;
+pub use shutdown::*;
+#[cfg(feature = "bevy_reflect")]
+pub use snapshot::*;
 pub use sub_app::*;
+pub use system_error::*;
 pub use task_pool_plugin::*;
-#[cfg(all(any(all(unix, not(target_os = "horizon")), windows), feature = "std"))]
+#[cfg(all(
+    any(
+        all(unix, not(target_os = "horizon")),
+        windows,
+        all(target_arch = "wasm32", feature = "web")
+    ),
+    feature = "std"
+))]
 pub use terminal_ctrl_c_handler::*;
+pub use warm_up::*;
 
 /// The app prelude.
 ///
@@ -0,0 +1,48 @@
+use alloc::{format, string::String};
+use core::fmt::{self, Write};
+use tracing::field::{Field, Visit};
+
+/// Formats the message of a log, followed by its other fields, such as `Loaded level=3`.
+///
+/// The metadata fields of the logs of the `log` crate, which start with `log.`, are skipped.
+/// This is shared by the logs buffered before the `LogPlugin` of `bevy_log` is built and by its
+/// log history, so that they are formatted the same way.
+///
+/// ```
+/// # use bevy_app::LogMessageVisitor;
+/// # use tracing::{Event, Subscriber};
+/// fn message(event: &Event<'_>) -> String {
+///     let mut visitor = LogMessageVisitor::default();
+///     event.record(&mut visitor);
+///     visitor.message
+/// }
+/// ```
+#[derive(Default, Debug)]
+pub struct LogMessageVisitor {
+    /// The message, followed by the fields recorded so far.
+    pub message: String,
+}
+
+impl Visit for LogMessageVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        match field.name() {
+            "message" => self.message.insert_str(0, value),
+            // The metadata of the logs of the `log` crate, already normalized.
+            name if name.starts_with("log.") => {}
+            name => {
+                let _ = write!(self.message, " {name}={value}");
+            }
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        match field.name() {
+            // The message comes before the other fields.
+            "message" => self.message.insert_str(0, &format!("{value:?}")),
+            name if name.starts_with("log.") => {}
+            name => {
+                let _ = write!(self.message, " {name}={value:?}");
+            }
+        }
+    }
+}
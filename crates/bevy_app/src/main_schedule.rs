@@ -1,14 +1,15 @@
-use crate::{App, Plugin};
+use crate::{frame_stats::ScheduleTimes, App, FrameNumber, MainSchedulePause, Plugin};
 use alloc::{vec, vec::Vec};
 use bevy_ecs::{
     resource::Resource,
     schedule::{
         ExecutorKind, InternedScheduleLabel, IntoScheduleConfigs, Schedule, ScheduleLabel,
-        SystemSet,
+        Schedules, SystemSet,
     },
     system::Local,
     world::{Mut, World},
 };
+use thiserror::Error;
 
 /// The schedule that contains the app logic that is evaluated each tick of [`App::update()`].
 ///
@@ -27,6 +28,7 @@ use bevy_ecs::{
 /// * [`PostStartup`]
 ///
 /// Then it will run:
+/// * the [`LateStartup`] schedules, if systems were added to the startup schedules after they ran
 /// * [`First`]
 /// * [`PreUpdate`]
 /// * [`StateTransition`] [^1]
@@ -36,6 +38,9 @@ use bevy_ecs::{
 /// * [`PostUpdate`]
 /// * [`Last`]
 ///
+/// While the app is paused with [`MainSchedulePause`], [`RunFixedMainLoop`] and [`Update`] only run
+/// the system sets exempted from the pause.
+///
 /// # Rendering
 ///
 /// Note rendering is not executed in the main schedule by default.
@@ -73,6 +78,49 @@ pub struct Startup;
 #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash, Default)]
 pub struct PostStartup;
 
+/// The systems added to a startup schedule, such as [`Startup`], after the startup schedules ran.
+///
+/// Plugins added while the app is running, for example when loading them dynamically, still get
+/// their startup systems run: systems added to one of the
+/// [startup schedules](MainScheduleOrder::startup_labels) once they ran are added to
+/// `LateStartup(label)` instead. At the beginning of the next frame, before [`First`], these
+/// schedules run once, in the order of the startup schedules, and are then removed so that their
+/// systems never run twice. The system sets configured on the original startup schedule don't
+/// apply to them.
+#[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct LateStartup(pub InternedScheduleLabel);
+
+impl LateStartup {
+    /// Returns the schedule that `label` stands for in `world`: its [`LateStartup`] schedule if it
+    /// is a startup schedule that already ran, or `label` itself.
+    pub(crate) fn redirect(world: &World, label: InternedScheduleLabel) -> InternedScheduleLabel {
+        if world.contains_resource::<StartupRan>()
+            && world
+                .get_resource::<MainScheduleOrder>()
+                .is_some_and(|order| order.startup_labels.contains(&label))
+        {
+            LateStartup(label).intern()
+        } else {
+            label
+        }
+    }
+
+    /// Runs, then removes, the [`LateStartup`] schedules that have systems.
+    fn run_all(world: &mut World, order: &MainScheduleOrder) {
+        for &label in &order.startup_labels {
+            let late = LateStartup(label);
+            let Some(mut schedule) = world.resource_mut::<Schedules>().remove(late) else {
+                continue;
+            };
+            schedule.run(world);
+        }
+    }
+}
+
+/// Marks that the startup schedules ran, see [`LateStartup`].
+#[derive(Resource)]
+pub(crate) struct StartupRan;
+
 /// Runs first in the schedule.
 ///
 /// See the [`Main`] schedule for some details about how schedules are run.
@@ -250,6 +298,26 @@ impl MainScheduleOrder {
         self.labels.insert(index, schedule.intern());
     }
 
+    /// Adds the given `schedule` after the `after` schedule in the main list of schedules, or
+    /// returns an error if `after` isn't in the list or `schedule` already is.
+    pub fn try_insert_after(
+        &mut self,
+        after: impl ScheduleLabel,
+        schedule: impl ScheduleLabel,
+    ) -> Result<(), ScheduleOrderError> {
+        insert_label(&mut self.labels, after.intern(), schedule.intern(), 1)
+    }
+
+    /// Adds the given `schedule` before the `before` schedule in the main list of schedules, or
+    /// returns an error if `before` isn't in the list or `schedule` already is.
+    pub fn try_insert_before(
+        &mut self,
+        before: impl ScheduleLabel,
+        schedule: impl ScheduleLabel,
+    ) -> Result<(), ScheduleOrderError> {
+        insert_label(&mut self.labels, before.intern(), schedule.intern(), 0)
+    }
+
     /// Adds the given `schedule` after the `after` schedule in the list of startup schedules.
     pub fn insert_startup_after(
         &mut self,
@@ -285,17 +353,25 @@ impl Main {
         if !*run_at_least_once {
             world.resource_scope(|world, order: Mut<MainScheduleOrder>| {
                 for &label in &order.startup_labels {
-                    let _ = world.try_run_schedule(label);
+                    ScheduleTimes::run(world, label);
                 }
             });
+            world.insert_resource(StartupRan);
             *run_at_least_once = true;
+        } else {
+            world.resource_scope(|world, order: Mut<MainScheduleOrder>| {
+                LateStartup::run_all(world, &order);
+            });
         }
 
         world.resource_scope(|world, order: Mut<MainScheduleOrder>| {
             for &label in &order.labels {
-                let _ = world.try_run_schedule(label);
+                if !MainSchedulePause::run_exempt_sets(world, label) {
+                    ScheduleTimes::run(world, label);
+                }
             }
         });
+        FrameNumber::advance(world);
     }
 }
 
@@ -317,6 +393,7 @@ impl Plugin for MainSchedulePlugin {
             .add_schedule(fixed_main_loop_schedule)
             .init_resource::<MainScheduleOrder>()
             .init_resource::<FixedMainScheduleOrder>()
+            .init_resource::<MainSchedulePause>()
             .add_systems(Main, Main::run_main)
             .add_systems(FixedMain, FixedMain::run_fixed_main)
             .configure_sets(
@@ -329,6 +406,8 @@ impl Plugin for MainSchedulePlugin {
                     .chain(),
             );
 
+        crate::capabilities::declare_core_capabilities(app);
+
         #[cfg(feature = "bevy_debug_stepping")]
         {
             use bevy_ecs::schedule::{IntoScheduleConfigs, Stepping};
@@ -379,6 +458,132 @@ impl FixedMainScheduleOrder {
             .unwrap_or_else(|| panic!("Expected {before:?} to exist"));
         self.labels.insert(index, schedule.intern());
     }
+
+    /// Adds the given `schedule` after the `after` schedule, or returns an error if `after` isn't
+    /// in the list or `schedule` already is.
+    pub fn try_insert_after(
+        &mut self,
+        after: impl ScheduleLabel,
+        schedule: impl ScheduleLabel,
+    ) -> Result<(), ScheduleOrderError> {
+        insert_label(&mut self.labels, after.intern(), schedule.intern(), 1)
+    }
+
+    /// Adds the given `schedule` before the `before` schedule, or returns an error if `before`
+    /// isn't in the list or `schedule` already is.
+    pub fn try_insert_before(
+        &mut self,
+        before: impl ScheduleLabel,
+        schedule: impl ScheduleLabel,
+    ) -> Result<(), ScheduleOrderError> {
+        insert_label(&mut self.labels, before.intern(), schedule.intern(), 0)
+    }
+}
+
+/// An error that occurs when inserting a schedule in the [`MainScheduleOrder`] or the
+/// [`FixedMainScheduleOrder`].
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ScheduleOrderError {
+    /// The schedule to insert the new one next to isn't in the order.
+    #[error("the schedule {0:?} is not in the order")]
+    AnchorNotFound(InternedScheduleLabel),
+    /// The schedule to insert is already in the order.
+    #[error("the schedule {0:?} is already in the order")]
+    AlreadyInserted(InternedScheduleLabel),
+}
+
+/// Inserts `schedule` in `labels` at `offset` from `anchor`.
+fn insert_label(
+    labels: &mut Vec<InternedScheduleLabel>,
+    anchor: InternedScheduleLabel,
+    schedule: InternedScheduleLabel,
+    offset: usize,
+) -> Result<(), ScheduleOrderError> {
+    if labels.contains(&schedule) {
+        return Err(ScheduleOrderError::AlreadyInserted(schedule));
+    }
+    let index = labels
+        .iter()
+        .position(|label| *label == anchor)
+        .ok_or(ScheduleOrderError::AnchorNotFound(anchor))?;
+    labels.insert(index + offset, schedule);
+    Ok(())
+}
+
+impl App {
+    /// Runs `schedule` right after `after` in the [`Main`] schedule, each frame.
+    ///
+    /// `after` must be one of the schedules of the [`MainScheduleOrder`], such as [`Update`].
+    /// Returns an error if it isn't, or if `schedule` already is. The schedule is created if it
+    /// doesn't exist yet.
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::schedule::ScheduleLabel;
+    /// #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+    /// struct LateUpdate;
+    ///
+    /// let mut app = App::new();
+    /// app.insert_schedule_after(Update, LateUpdate).unwrap();
+    /// ```
+    pub fn insert_schedule_after(
+        &mut self,
+        after: impl ScheduleLabel,
+        schedule: impl ScheduleLabel,
+    ) -> Result<&mut Self, ScheduleOrderError> {
+        let schedule = schedule.intern();
+        self.world_mut()
+            .get_resource_or_init::<MainScheduleOrder>()
+            .try_insert_after(after, schedule)?;
+        Ok(self.init_schedule(schedule))
+    }
+
+    /// Runs `schedule` right before `before` in the [`Main`] schedule, each frame.
+    ///
+    /// See [`App::insert_schedule_after`].
+    pub fn insert_schedule_before(
+        &mut self,
+        before: impl ScheduleLabel,
+        schedule: impl ScheduleLabel,
+    ) -> Result<&mut Self, ScheduleOrderError> {
+        let schedule = schedule.intern();
+        self.world_mut()
+            .get_resource_or_init::<MainScheduleOrder>()
+            .try_insert_before(before, schedule)?;
+        Ok(self.init_schedule(schedule))
+    }
+
+    /// Runs `schedule` right after `after` in the [`FixedMain`] schedule, each fixed timestep.
+    ///
+    /// `after` must be one of the schedules of the [`FixedMainScheduleOrder`], such as
+    /// [`FixedUpdate`]. Returns an error if it isn't, or if `schedule` already is. The schedule
+    /// is created if it doesn't exist yet.
+    pub fn insert_fixed_schedule_after(
+        &mut self,
+        after: impl ScheduleLabel,
+        schedule: impl ScheduleLabel,
+    ) -> Result<&mut Self, ScheduleOrderError> {
+        let schedule = schedule.intern();
+        self.world_mut()
+            .get_resource_or_init::<FixedMainScheduleOrder>()
+            .try_insert_after(after, schedule)?;
+        Ok(self.init_schedule(schedule))
+    }
+
+    /// Runs `schedule` right before `before` in the [`FixedMain`] schedule, each fixed timestep.
+    ///
+    /// See [`App::insert_fixed_schedule_after`].
+    pub fn insert_fixed_schedule_before(
+        &mut self,
+        before: impl ScheduleLabel,
+        schedule: impl ScheduleLabel,
+    ) -> Result<&mut Self, ScheduleOrderError> {
+        let schedule = schedule.intern();
+        self.world_mut()
+            .get_resource_or_init::<FixedMainScheduleOrder>()
+            .try_insert_before(before, schedule)?;
+        Ok(self.init_schedule(schedule))
+    }
 }
 
 impl FixedMain {
@@ -386,7 +591,7 @@ impl FixedMain {
     pub fn run_fixed_main(world: &mut World) {
         world.resource_scope(|world, order: Mut<FixedMainScheduleOrder>| {
             for &label in &order.labels {
-                let _ = world.try_run_schedule(label);
+                ScheduleTimes::run(world, label);
             }
         });
     }
@@ -488,3 +693,114 @@ pub enum RunFixedMainLoopSystems {
 /// Deprecated alias for [`RunFixedMainLoopSystems`].
 #[deprecated(since = "0.17.0", note = "Renamed to `RunFixedMainLoopSystems`.")]
 pub type RunFixedMainLoopSystem = RunFixedMainLoopSystems;
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        App, FixedMain, FixedPostUpdate, FixedUpdate, LateStartup, PostStartup, PostUpdate,
+        ScheduleOrderError, Startup, Update,
+    };
+    use alloc::{vec, vec::Vec};
+    use bevy_ecs::{prelude::*, schedule::ScheduleLabel};
+
+    #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+    struct Custom;
+
+    #[derive(Resource, Default)]
+    struct Order(Vec<&'static str>);
+
+    fn push(name: &'static str) -> impl FnMut(ResMut<Order>) {
+        move |mut order| order.0.push(name)
+    }
+
+    #[test]
+    fn inserted_schedule_runs_in_order() {
+        let mut app = App::new();
+        app.init_resource::<Order>()
+            .insert_schedule_after(Update, Custom)
+            .unwrap()
+            .add_systems(Update, push("update"))
+            .add_systems(Custom, push("custom"))
+            .add_systems(PostUpdate, push("post_update"));
+
+        app.update();
+        assert_eq!(
+            app.world().resource::<Order>().0,
+            vec!["update", "custom", "post_update"]
+        );
+    }
+
+    #[test]
+    fn inserted_fixed_schedule_runs_in_order() {
+        let mut app = App::new();
+        app.init_resource::<Order>()
+            .insert_fixed_schedule_before(FixedPostUpdate, Custom)
+            .unwrap()
+            .add_systems(FixedUpdate, push("fixed_update"))
+            .add_systems(Custom, push("custom"))
+            .add_systems(FixedPostUpdate, push("fixed_post_update"));
+
+        app.world_mut().run_schedule(FixedMain);
+        assert_eq!(
+            app.world().resource::<Order>().0,
+            vec!["fixed_update", "custom", "fixed_post_update"]
+        );
+    }
+
+    #[test]
+    fn invalid_insertions_are_errors() {
+        #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+        struct Missing;
+
+        let mut app = App::new();
+        assert_eq!(
+            app.insert_schedule_after(Missing, Custom).unwrap_err(),
+            ScheduleOrderError::AnchorNotFound(Missing.intern())
+        );
+        // `FixedUpdate` is only in the fixed order.
+        assert_eq!(
+            app.insert_schedule_before(FixedUpdate, Custom).unwrap_err(),
+            ScheduleOrderError::AnchorNotFound(FixedUpdate.intern())
+        );
+        app.insert_schedule_before(PostUpdate, Custom).unwrap();
+        assert_eq!(
+            app.insert_schedule_after(Update, Custom).unwrap_err(),
+            ScheduleOrderError::AlreadyInserted(Custom.intern())
+        );
+        assert_eq!(
+            app.insert_fixed_schedule_after(FixedUpdate, FixedPostUpdate)
+                .unwrap_err(),
+            ScheduleOrderError::AlreadyInserted(FixedPostUpdate.intern())
+        );
+    }
+
+    #[test]
+    fn late_startup_systems_run_once() {
+        let mut app = App::new();
+        app.init_resource::<Order>()
+            .add_systems(Startup, push("startup"));
+        app.update();
+
+        // A plugin added while the app is running.
+        app.add_plugins(|app: &mut App| {
+            app.add_systems(PostStartup, push("late_post_startup"))
+                .add_systems(Startup, push("late_startup"));
+        });
+        assert!(app.get_schedule(LateStartup(Startup.intern())).is_some());
+        app.add_systems(Update, push("update"));
+        app.update();
+        app.update();
+
+        assert_eq!(
+            app.world().resource::<Order>().0,
+            vec![
+                "startup",
+                "late_startup",
+                "late_post_startup",
+                "update",
+                "update"
+            ]
+        );
+        assert!(app.get_schedule(LateStartup(Startup.intern())).is_none());
+    }
+}
@@ -0,0 +1,216 @@
+use crate::{warm_up::warm_up_labels, App, LateStartup, OnAppExit, OnShutdown};
+use alloc::{string::String, vec::Vec};
+use bevy_ecs::{
+    resource::Resource,
+    schedule::{InternedScheduleLabel, Schedule, ScheduleLabel, Schedules},
+};
+use core::any::{Any, TypeId};
+use log::warn;
+
+/// What [`App::finish`] does about schedules that have systems but are never run.
+///
+/// Systems added to a schedule that isn't run by the [`Main`](crate::Main) schedule, by the app
+/// or by something registered in [`RunSchedules`] silently never run, for example because of a
+/// typo in a custom label or because the schedule was never added to the
+/// [`MainScheduleOrder`](crate::MainScheduleOrder).
+///
+/// Only the schedules of the main world are checked.
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MissingScheduleBehavior {
+    /// Creates the schedules when systems are added to them, without checking that they run.
+    #[default]
+    Create,
+    /// Logs a warning for each schedule that has systems but is never run.
+    Warn,
+    /// Panics in [`App::finish`] if a schedule has systems but is never run.
+    Error,
+}
+
+/// The schedules run by something other than the [`Main`](crate::Main) schedule or the app, for
+/// example by a system calling [`World::run_schedule`](bevy_ecs::world::World::run_schedule).
+///
+/// Schedules listed here are not reported by the [`MissingScheduleBehavior`] checks.
+#[derive(Resource, Debug, Default)]
+pub struct RunSchedules {
+    labels: Vec<InternedScheduleLabel>,
+    label_types: Vec<TypeId>,
+    warned: Vec<InternedScheduleLabel>,
+}
+
+impl RunSchedules {
+    /// Registers a schedule as being run.
+    pub fn insert(&mut self, label: impl ScheduleLabel) {
+        let label = label.intern();
+        if !self.labels.contains(&label) {
+            self.labels.push(label);
+        }
+    }
+
+    /// Registers all the schedules whose label is of type `L` as being run, for labels with
+    /// fields such as `OnEnter(state)`.
+    pub fn insert_type<L: ScheduleLabel>(&mut self) {
+        let type_id = TypeId::of::<L>();
+        if !self.label_types.contains(&type_id) {
+            self.label_types.push(type_id);
+        }
+    }
+
+    /// Returns `true` if the schedule was registered as being run, by label or by type.
+    pub fn contains(&self, label: InternedScheduleLabel) -> bool {
+        self.labels.contains(&label) || self.label_types.contains(&(&*label as &dyn Any).type_id())
+    }
+}
+
+/// Returns `true` if `label` is a [`LateStartup`] schedule, which the [`Main`](crate::Main)
+/// schedule runs on the frame after it was created.
+fn is_late_startup(label: InternedScheduleLabel) -> bool {
+    (&*label as &dyn Any).type_id() == TypeId::of::<LateStartup>()
+}
+
+impl App {
+    /// Sets what [`App::finish`] does about schedules that have systems but are never run.
+    ///
+    /// Defaults to [`MissingScheduleBehavior::Create`].
+    pub fn set_missing_schedule_behavior(
+        &mut self,
+        behavior: MissingScheduleBehavior,
+    ) -> &mut Self {
+        self.insert_resource(behavior)
+    }
+
+    /// Registers a schedule as being run by something other than the [`Main`](crate::Main)
+    /// schedule, so that it isn't reported by the [`MissingScheduleBehavior`] checks.
+    pub fn register_run_schedule(&mut self, label: impl ScheduleLabel) -> &mut Self {
+        self.world_mut()
+            .get_resource_or_init::<RunSchedules>()
+            .insert(label);
+        self
+    }
+
+    /// Returns the labels of the schedules of the main world that have systems but are never
+    /// run.
+    ///
+    /// A schedule is run if it is the update schedule of the main world, one of the schedules run
+    /// by the [`Main`](crate::Main) and [`FixedMain`](crate::FixedMain) schedules, or
+    /// [`OnShutdown`], [`OnAppExit`] or a [`LateStartup`] schedule, or if it is registered in
+    /// [`RunSchedules`].
+    pub fn unrun_schedules(&self) -> Vec<InternedScheduleLabel> {
+        let world = self.world();
+        let Some(schedules) = world.get_resource::<Schedules>() else {
+            return Vec::new();
+        };
+        let mut run = warm_up_labels(world);
+        run.extend(self.main().update_schedule);
+        run.extend([OnShutdown.intern(), OnAppExit.intern()]);
+        let registered = world.get_resource::<RunSchedules>();
+
+        let mut unrun = schedules
+            .iter()
+            .map(|(_, schedule)| schedule)
+            .filter(|schedule| schedule.systems_len() > 0)
+            .map(Schedule::label)
+            .filter(|label| !run.contains(label) && !is_late_startup(*label))
+            .filter(|label| !registered.is_some_and(|registered| registered.contains(*label)))
+            .collect::<Vec<_>>();
+        // `Schedules` is a hash map, sort the labels to report them in a stable order.
+        unrun.sort_by_cached_key(|label| alloc::format!("{label:?}"));
+        unrun
+    }
+
+    /// Applies the [`MissingScheduleBehavior`], called when the plugins are finished.
+    pub(crate) fn check_missing_schedules(&mut self) {
+        let behavior = self
+            .world()
+            .get_resource::<MissingScheduleBehavior>()
+            .copied()
+            .unwrap_or_default();
+        if behavior == MissingScheduleBehavior::Create {
+            return;
+        }
+
+        let unrun = self.unrun_schedules();
+        if unrun.is_empty() {
+            return;
+        }
+        match behavior {
+            MissingScheduleBehavior::Create => {}
+            MissingScheduleBehavior::Warn => {
+                let mut run_schedules = self.world_mut().get_resource_or_init::<RunSchedules>();
+                for label in unrun {
+                    if run_schedules.warned.contains(&label) {
+                        continue;
+                    }
+                    run_schedules.warned.push(label);
+                    warn!(
+                        "The schedule {label:?} has systems but is never run. Add it to the `MainScheduleOrder` or register it with `App::register_run_schedule`."
+                    );
+                }
+            }
+            MissingScheduleBehavior::Error => {
+                let labels = unrun
+                    .iter()
+                    .map(|label| alloc::format!("{label:?}"))
+                    .collect::<Vec<String>>();
+                panic!(
+                    "Some schedules have systems but are never run: {}. Add them to the `MainScheduleOrder` or register them with `App::register_run_schedule`.",
+                    labels.join(", ")
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{App, MissingScheduleBehavior, RunSchedules, Update};
+    use bevy_ecs::schedule::ScheduleLabel;
+
+    #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+    struct Upadte;
+
+    #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+    struct OnLevel(u32);
+
+    fn app(behavior: MissingScheduleBehavior) -> App {
+        let mut app = App::new();
+        app.set_missing_schedule_behavior(behavior)
+            .add_systems(Update, || {})
+            .add_systems(Upadte, || {});
+        app
+    }
+
+    #[test]
+    fn create_creates_the_schedule() {
+        let mut app = app(MissingScheduleBehavior::Create);
+        app.finish();
+        assert!(app.get_schedule(Upadte).is_some());
+    }
+
+    #[test]
+    fn warn_reports_the_schedule() {
+        let mut app = app(MissingScheduleBehavior::Warn);
+        assert_eq!(app.unrun_schedules(), [Upadte.intern()]);
+        app.finish();
+        assert!(app.get_schedule(Upadte).is_some());
+    }
+
+    #[test]
+    #[should_panic(expected = "Upadte")]
+    fn error_panics_on_finish() {
+        let mut app = app(MissingScheduleBehavior::Error);
+        app.finish();
+    }
+
+    #[test]
+    fn registered_schedules_are_not_reported() {
+        let mut app = app(MissingScheduleBehavior::Error);
+        app.add_systems(OnLevel(1), || {})
+            .add_systems(OnLevel(2), || {})
+            .register_run_schedule(Upadte);
+        app.world_mut()
+            .resource_mut::<RunSchedules>()
+            .insert_type::<OnLevel>();
+        assert!(app.unrun_schedules().is_empty());
+        app.finish();
+    }
+}
@@ -0,0 +1,161 @@
+use crate::{App, First};
+use alloc::vec::Vec;
+use bevy_ecs::{
+    event::{BufferedEvent, Event, EventCursor, EventUpdateSystems, Events},
+    resource::Resource,
+    schedule::IntoScheduleConfigs,
+    world::{Mut, World},
+};
+
+impl App {
+    /// Adds the event `E`, like [`App::add_event`], and triggers each written `E` for the
+    /// observers, so that they can handle the buffered events with [`On<E>`] without a system
+    /// reading them.
+    ///
+    /// The events are triggered by value: each one is cloned, and the original is kept for the
+    /// [`EventReader`]s. They are triggered in the order they were written, at the start of the
+    /// frame following the one they were written in, right after the events are updated in
+    /// [`First`].
+    ///
+    /// The events written by the observers themselves are triggered at the start of the next
+    /// frame, rather than right away, so that an observer writing the event it observes doesn't
+    /// loop forever.
+    ///
+    /// The bridge reads the events like any other reader: once triggered, they count as read for
+    /// [`Events::clear_consumed`], since the observers handled them.
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// #[derive(BufferedEvent, Event, Clone)]
+    /// struct ScoreChanged(u32);
+    ///
+    /// App::new()
+    ///     .add_event_with_observer_bridge::<ScoreChanged>()
+    ///     .add_observer(|score: On<ScoreChanged>| {
+    ///         println!("score: {}", score.0);
+    ///     });
+    /// ```
+    ///
+    /// [`On<E>`]: bevy_ecs::observer::On
+    /// [`EventReader`]: bevy_ecs::event::EventReader
+    /// [`Events::clear_consumed`]: bevy_ecs::event::Events::clear_consumed
+    pub fn add_event_with_observer_bridge<E>(&mut self) -> &mut Self
+    where
+        E: BufferedEvent + Event + Clone,
+    {
+        self.add_event::<E>();
+        if self.world().contains_resource::<ObserverBridge<E>>() {
+            return self;
+        }
+        self.insert_resource(ObserverBridge::<E>(EventCursor::default()))
+            .add_systems(First, trigger_bridged_events::<E>.after(EventUpdateSystems))
+    }
+}
+
+/// The events of type `E` already triggered by [`App::add_event_with_observer_bridge`].
+#[derive(Resource)]
+struct ObserverBridge<E: BufferedEvent>(EventCursor<E>);
+
+/// Triggers the events of type `E` written since the last run.
+fn trigger_bridged_events<E: BufferedEvent + Event + Clone>(world: &mut World) {
+    // The events are collected first: the ones the observers write are left for the next run.
+    let events = world.resource_scope(|world, mut bridge: Mut<ObserverBridge<E>>| {
+        let Some(events) = world.get_resource::<Events<E>>() else {
+            return Vec::new();
+        };
+        bridge.0.read(events).cloned().collect::<Vec<_>>()
+    });
+    for event in events {
+        world.trigger(event);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{App, Update};
+    use alloc::{vec, vec::Vec};
+    use bevy_ecs::{
+        event::{BufferedEvent, Event, EventReader, EventWriter},
+        observer::On,
+        resource::Resource,
+        system::ResMut,
+    };
+
+    #[derive(BufferedEvent, Event, Clone)]
+    struct Scored(u32);
+
+    #[derive(Resource, Default)]
+    struct Observed(Vec<u32>);
+
+    fn app() -> App {
+        let mut app = App::new();
+        app.add_event_with_observer_bridge::<Scored>()
+            // Bridging twice does nothing.
+            .add_event_with_observer_bridge::<Scored>()
+            .init_resource::<Observed>()
+            .add_observer(|scored: On<Scored>, mut observed: ResMut<Observed>| {
+                observed.0.push(scored.0);
+            });
+        app
+    }
+
+    #[test]
+    fn each_event_triggers_one_observer_run_in_order() {
+        let mut app = app();
+        app.add_systems(Update, |mut scored: EventWriter<Scored>| {
+            scored.write_batch([Scored(1), Scored(2)]);
+            scored.write(Scored(3));
+        });
+        app.world_mut().write_event(Scored(0));
+
+        app.update();
+        // The events of `Update` are triggered on the next frame.
+        assert_eq!(app.world().resource::<Observed>().0, [0]);
+        app.update();
+        assert_eq!(app.world().resource::<Observed>().0, [0, 1, 2, 3]);
+        app.update();
+        assert_eq!(app.world().resource::<Observed>().0, [0, 1, 2, 3, 1, 2, 3]);
+    }
+
+    #[test]
+    fn events_are_still_readable() {
+        #[derive(Resource, Default)]
+        struct Read(usize);
+
+        let mut app = app();
+        app.init_resource::<Read>().add_systems(
+            Update,
+            |mut scored: EventReader<Scored>, mut read: ResMut<Read>| {
+                read.0 += scored.read().count();
+            },
+        );
+        app.world_mut().write_event(Scored(0));
+        app.update();
+        assert_eq!(app.world().resource::<Observed>().0, [0]);
+        assert_eq!(app.world().resource::<Read>().0, 1);
+    }
+
+    #[test]
+    fn events_written_by_observers_are_deferred() {
+        let mut app = App::new();
+        app.add_event_with_observer_bridge::<Scored>()
+            .init_resource::<Observed>()
+            // An observer writing the event it observes, which would never stop if the events
+            // were triggered right away.
+            .add_observer(
+                |scored: On<Scored>,
+                 mut observed: ResMut<Observed>,
+                 mut writer: EventWriter<Scored>| {
+                    observed.0.push(scored.0);
+                    writer.write(Scored(scored.0 + 1));
+                },
+            );
+        app.world_mut().write_event(Scored(0));
+
+        for _ in 0..3 {
+            app.update();
+        }
+        assert_eq!(app.world().resource::<Observed>().0, vec![0, 1, 2]);
+    }
+}
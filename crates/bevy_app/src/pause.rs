@@ -0,0 +1,188 @@
+use crate::{App, RunFixedMainLoop, Update};
+use alloc::vec::Vec;
+use bevy_ecs::{
+    resource::Resource,
+    schedule::{InternedScheduleLabel, InternedSystemSet, ScheduleLabel, SystemSet},
+    world::World,
+};
+
+/// Pauses the gameplay schedules of [`Main`](crate::Main), while the rest of the app keeps
+/// running.
+///
+/// While paused, [`Update`] and [`RunFixedMainLoop`] only run the system sets that were exempted
+/// with [`App::configure_pause_exempt`], and their other systems are skipped. The other schedules,
+/// like [`PreUpdate`](crate::PreUpdate) and [`PostUpdate`](crate::PostUpdate), keep running
+/// entirely, so input, UI and rendering stay responsive. This is meant for editors and debug
+/// overlays that freeze the game while keeping their own systems running.
+///
+/// As the fixed timestep is accumulated by a system in [`RunFixedMainLoop`], no time is
+/// accumulated while paused: resuming doesn't run [`FixedMain`](crate::FixedMain) for all the
+/// time spent paused.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_app::{prelude::*, MainSchedulePause};
+/// # use bevy_ecs::prelude::*;
+/// #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+/// struct PauseMenu;
+///
+/// fn toggle_pause(mut pause: ResMut<MainSchedulePause>) {
+///     # let pressed_escape = true;
+///     if pressed_escape {
+///         pause.toggle();
+///     }
+/// }
+///
+/// App::new()
+///     .configure_pause_exempt(PauseMenu)
+///     .add_systems(Update, toggle_pause.in_set(PauseMenu));
+/// ```
+#[derive(Resource, Debug, Default, Clone)]
+pub struct MainSchedulePause {
+    paused: bool,
+    exempt_sets: Vec<InternedSystemSet>,
+}
+
+impl MainSchedulePause {
+    /// Pauses the gameplay schedules.
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    /// Resumes the gameplay schedules.
+    pub fn resume(&mut self) {
+        self.paused = false;
+    }
+
+    /// Pauses the gameplay schedules if they are running, and resumes them otherwise.
+    pub fn toggle(&mut self) {
+        self.paused = !self.paused;
+    }
+
+    /// Returns whether the gameplay schedules are paused.
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Returns the system sets that keep running while paused.
+    pub fn exempt_sets(&self) -> &[InternedSystemSet] {
+        &self.exempt_sets
+    }
+
+    /// Runs the exempt sets of the schedule `label` if it is paused, and returns whether it was.
+    pub(crate) fn run_exempt_sets(world: &mut World, label: InternedScheduleLabel) -> bool {
+        let Some(pause) = world.get_resource::<MainSchedulePause>() else {
+            return false;
+        };
+        if !pause.paused || (label != Update.intern() && label != RunFixedMainLoop.intern()) {
+            return false;
+        }
+
+        if !pause.exempt_sets.is_empty() {
+            let sets = pause.exempt_sets.clone();
+            let _ = world.try_schedule_scope(label, |world, schedule| {
+                schedule.run_sets(world, &sets);
+            });
+        }
+        true
+    }
+}
+
+impl App {
+    /// Keeps the systems in `set` running while the [`Main`](crate::Main) schedule is paused with
+    /// [`MainSchedulePause`].
+    ///
+    /// Plugins use this for the systems that must keep running while the game is paused, like
+    /// the system that resumes it.
+    pub fn configure_pause_exempt(&mut self, set: impl SystemSet) -> &mut Self {
+        let set = set.intern();
+        let mut pause = self.world_mut().get_resource_or_init::<MainSchedulePause>();
+        if !pause.exempt_sets.contains(&set) {
+            pause.exempt_sets.push(set);
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        App, FixedUpdate, MainSchedulePause, PostUpdate, PreUpdate, RunFixedMainLoop, Update,
+    };
+    use bevy_ecs::prelude::*;
+
+    #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+    struct Editor;
+
+    #[derive(Resource, Default, Debug, PartialEq, Eq)]
+    struct Runs {
+        pre_update: u32,
+        update: u32,
+        editor: u32,
+        fixed: u32,
+        post_update: u32,
+    }
+
+    fn app() -> App {
+        let mut app = App::new();
+        app.init_resource::<Runs>()
+            .configure_pause_exempt(Editor)
+            .add_systems(PreUpdate, |mut runs: ResMut<Runs>| runs.pre_update += 1)
+            .add_systems(Update, |mut runs: ResMut<Runs>| runs.update += 1)
+            .add_systems(
+                Update,
+                (|mut runs: ResMut<Runs>| runs.editor += 1).in_set(Editor),
+            )
+            // Stands in for the fixed timestep accumulator of `bevy_time`.
+            .add_systems(RunFixedMainLoop, |world: &mut World| {
+                world.run_schedule(FixedUpdate);
+            })
+            .add_systems(FixedUpdate, |mut runs: ResMut<Runs>| runs.fixed += 1)
+            .add_systems(PostUpdate, |mut runs: ResMut<Runs>| runs.post_update += 1);
+        app
+    }
+
+    #[test]
+    fn pausing_skips_gameplay_schedules() {
+        let mut app = app();
+        app.update();
+        app.world_mut().resource_mut::<MainSchedulePause>().pause();
+        app.update();
+        app.update();
+
+        assert_eq!(
+            *app.world().resource::<Runs>(),
+            Runs {
+                pre_update: 3,
+                update: 1,
+                editor: 3,
+                fixed: 1,
+                post_update: 3,
+            }
+        );
+
+        app.world_mut().resource_mut::<MainSchedulePause>().resume();
+        app.update();
+        let runs = app.world().resource::<Runs>();
+        assert_eq!((runs.update, runs.fixed), (2, 2));
+    }
+
+    #[test]
+    fn exempt_systems_can_resume() {
+        let mut app = app();
+        app.add_systems(
+            Update,
+            (|mut pause: ResMut<MainSchedulePause>| pause.toggle()).in_set(Editor),
+        );
+
+        // Pauses.
+        app.update();
+        assert!(app.world().resource::<MainSchedulePause>().is_paused());
+        // Resumes, the other systems of `Update` will run on the next frame.
+        app.update();
+        assert!(!app.world().resource::<MainSchedulePause>().is_paused());
+        app.update();
+        assert_eq!(app.world().resource::<Runs>().update, 2);
+    }
+}
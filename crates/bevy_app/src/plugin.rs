@@ -1,5 +1,8 @@
 use crate::App;
-use core::any::Any;
+use alloc::{boxed::Box, vec::Vec};
+use bevy_ecs::{resource::Resource, world::World};
+use bevy_platform::sync::Arc;
+use core::any::{Any, TypeId};
 use downcast_rs::{impl_downcast, Downcast};
 
 /// A collection of Bevy app logic and configuration.
@@ -16,7 +19,8 @@ use downcast_rs::{impl_downcast, Downcast};
 /// ## Lifecycle of a plugin
 ///
 /// When adding a plugin to an [`App`]:
-/// * the app calls [`Plugin::build`] immediately, and register the plugin
+/// * the app calls [`Plugin::build`] immediately, and register the plugin, unless some of its
+///   [`Plugin::required_resources`] are missing, in which case `build` is deferred until they exist
 /// * once the app started, it will wait for all registered [`Plugin::ready`] to return `true`
 /// * it will then call all registered [`Plugin::finish`]
 /// * and call all registered [`Plugin::cleanup`]
@@ -78,6 +82,23 @@ pub trait Plugin: Downcast + Any + Send + Sync {
         // do nothing
     }
 
+    /// Runs once when the app exits, after its last update and [`OnAppExit`](crate::OnAppExit),
+    /// for example to flush data the plugin buffered. Runners call it through
+    /// [`App::run_exit_hooks`].
+    fn on_exit(&self, _app: &mut App) {
+        // do nothing
+    }
+
+    /// Copies what this plugin shares between an app and its forks from `src` to `dst`, when
+    /// [`App::fork`] creates `dst`.
+    ///
+    /// This runs before [`build`](Plugin::build) is called on `dst`, so that resources that are
+    /// expensive to create can be cloned from `src` instead, and skipped by `build` when they
+    /// already exist.
+    fn share(&self, _src: &App, _dst: &mut App) {
+        // do nothing
+    }
+
     /// Configures a name for the [`Plugin`] which is primarily used for checking plugin
     /// uniqueness and debugging.
     fn name(&self) -> &str {
@@ -89,6 +110,46 @@ pub trait Plugin: Downcast + Any + Send + Sync {
     fn is_unique(&self) -> bool {
         true
     }
+
+    /// Resources that must exist before [`build`](Plugin::build) is called.
+    ///
+    /// If any of them is missing when the plugin is added, the plugin is not built right away.
+    /// It is built as soon as they all exist instead, for example once a plugin added later
+    /// inserted them in its own `build`. If they still don't exist once all plugins are
+    /// [ready](PluginsState::Ready), the app panics in [`App::finish`].
+    ///
+    /// ```
+    /// # use bevy_app::*;
+    /// # use bevy_ecs::prelude::*;
+    /// #[derive(Resource)]
+    /// struct ApiKey(String);
+    ///
+    /// struct TelemetryPlugin;
+    ///
+    /// impl Plugin for TelemetryPlugin {
+    ///     fn build(&self, app: &mut App) {
+    ///         let key = &app.world().resource::<ApiKey>().0;
+    ///         // ...
+    ///     }
+    ///
+    ///     fn required_resources(&self) -> Vec<RequiredResource> {
+    ///         vec![RequiredResource::of::<ApiKey>()]
+    ///     }
+    /// }
+    /// ```
+    fn required_resources(&self) -> Vec<RequiredResource> {
+        Vec::new()
+    }
+
+    /// Returns this plugin as a [`ConfigurablePlugin`](crate::ConfigurablePlugin), if it can be
+    /// configured by a [`PluginGroupConfig`](crate::PluginGroupConfig).
+    ///
+    /// Plugins implementing [`ConfigurablePlugin`](crate::ConfigurablePlugin) should override
+    /// this method to return `Some(self)`.
+    #[cfg(feature = "plugin_config")]
+    fn as_configurable(&mut self) -> Option<&mut dyn crate::ConfigurablePlugin> {
+        None
+    }
 }
 
 impl_downcast!(Plugin);
@@ -99,6 +160,38 @@ impl<T: Fn(&mut App) + Send + Sync + 'static> Plugin for T {
     }
 }
 
+/// A [`Resource`] that must exist before a [`Plugin`] is built.
+///
+/// See [`Plugin::required_resources`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RequiredResource {
+    type_id: TypeId,
+    name: &'static str,
+}
+
+impl RequiredResource {
+    /// Requires the resource `R`.
+    pub fn of<R: Resource>() -> Self {
+        Self {
+            type_id: TypeId::of::<R>(),
+            name: core::any::type_name::<R>(),
+        }
+    }
+
+    /// Returns the type name of the resource.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns `true` if the resource exists in `world`.
+    pub fn exists(&self, world: &World) -> bool {
+        world
+            .components()
+            .get_resource_id(self.type_id)
+            .is_some_and(|id| world.contains_resource_by_id(id))
+    }
+}
+
 /// Plugins state in the application
 #[derive(PartialEq, Eq, Debug, Clone, Copy, PartialOrd, Ord)]
 pub enum PluginsState {
@@ -119,6 +212,12 @@ impl Plugin for PlaceholderPlugin {
     fn build(&self, _app: &mut App) {}
 }
 
+/// Moves `plugin` to an [`Arc`], the way plugins are stored in a plugin registry.
+pub(crate) fn shared_plugin(plugin: impl Plugin) -> Arc<dyn Plugin> {
+    let plugin: Box<dyn Plugin> = Box::new(plugin);
+    Arc::from(plugin)
+}
+
 /// Types that represent a set of [`Plugin`]s.
 ///
 /// This is implemented for all types which implement [`Plugin`],
@@ -129,12 +228,15 @@ impl<Marker, T> Plugins<Marker> for T where T: sealed::Plugins<Marker> {}
 
 mod sealed {
     use alloc::boxed::Box;
+    use core::panic::Location;
+    use log::debug;
     use variadics_please::all_tuples;
 
     use crate::{App, AppError, Plugin, PluginGroup};
 
     pub trait Plugins<Marker> {
         fn add_to_app(self, app: &mut App);
+        fn add_to_app_if_new(self, app: &mut App);
     }
 
     pub struct PluginMarker;
@@ -152,6 +254,18 @@ mod sealed {
                 )
             }
         }
+
+        #[track_caller]
+        fn add_to_app_if_new(self, app: &mut App) {
+            if let Err(AppError::DuplicatePlugin { plugin_name }) =
+                app.add_boxed_plugin(Box::new(self))
+            {
+                debug!(
+                    "Skipped plugin {plugin_name} added at {}: plugin was already added in application",
+                    Location::caller()
+                );
+            }
+        }
     }
 
     impl<P: PluginGroup> Plugins<PluginGroupMarker> for P {
@@ -159,6 +273,11 @@ mod sealed {
         fn add_to_app(self, app: &mut App) {
             self.build().finish(app);
         }
+
+        #[track_caller]
+        fn add_to_app_if_new(self, app: &mut App) {
+            self.build().finish_if_new(app);
+        }
     }
 
     macro_rules! impl_plugins_tuples {
@@ -179,6 +298,18 @@ mod sealed {
                     let ($($plugins,)*) = self;
                     $($plugins.add_to_app(app);)*
                 }
+
+                #[expect(
+                    clippy::allow_attributes,
+                    reason = "This is inside a macro, and as such, may not trigger in all cases."
+                )]
+                #[allow(non_snake_case, reason = "`all_tuples!()` generates non-snake-case variable names.")]
+                #[allow(unused_variables, reason = "`app` is unused when implemented for the unit type `()`.")]
+                #[track_caller]
+                fn add_to_app_if_new(self, app: &mut App) {
+                    let ($($plugins,)*) = self;
+                    $($plugins.add_to_app_if_new(app);)*
+                }
             }
         }
     }
@@ -0,0 +1,242 @@
+use crate::{Plugin, PluginGroupBuilder};
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec::Vec,
+};
+use log::warn;
+use serde::{Deserialize, Serialize};
+
+pub use ron;
+
+/// Configures the plugins of a [`PluginGroup`](crate::PluginGroup) from data, so the same binary can run with
+/// differently tuned plugins, for example as a dedicated server or in CI.
+///
+/// Plugins are identified by their [`name`](Plugin::name), which defaults to their type name.
+/// The configuration can be deserialized from any format supported by `serde`. In RON:
+///
+/// ```ron
+/// (
+///     disabled: ["bevy_audio::AudioPlugin"],
+///     settings: {
+///         "bevy_log::LogPlugin": (filter: "wgpu=error,bevy_render=warn"),
+///     },
+/// )
+/// ```
+///
+/// See [`PluginGroup::with_config`](crate::PluginGroup::with_config) to apply it.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(default)]
+pub struct PluginGroupConfig {
+    /// The names of the plugins to disable.
+    pub disabled: Vec<String>,
+    /// The settings of the plugins, by name, for plugins implementing [`ConfigurablePlugin`].
+    pub settings: BTreeMap<String, ron::Value>,
+}
+
+impl PluginGroupConfig {
+    /// Parses a configuration from RON.
+    pub fn from_ron(ron: &str) -> Result<Self, ron::error::SpannedError> {
+        ron::from_str(ron)
+    }
+
+    /// Serializes the configuration to RON.
+    pub fn to_ron(&self) -> Result<String, ron::Error> {
+        ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+    }
+}
+
+/// A [`Plugin`] whose settings can be set by a [`PluginGroupConfig`].
+///
+/// To opt in, a plugin implements this trait and returns itself from
+/// [`Plugin::as_configurable`]:
+///
+/// ```
+/// # use bevy_app::{prelude::*, ConfigurablePlugin, ron};
+/// # use serde::Deserialize;
+/// #[derive(Deserialize)]
+/// struct NetworkPlugin {
+///     port: u16,
+/// }
+///
+/// impl Plugin for NetworkPlugin {
+///     fn build(&self, app: &mut App) {}
+///
+///     fn as_configurable(&mut self) -> Option<&mut dyn ConfigurablePlugin> {
+///         Some(self)
+///     }
+/// }
+///
+/// impl ConfigurablePlugin for NetworkPlugin {
+///     fn apply_config(&mut self, value: &ron::Value) -> Result<(), ron::Error> {
+///         *self = value.clone().into_rust()?;
+///         Ok(())
+///     }
+/// }
+/// ```
+pub trait ConfigurablePlugin: Plugin {
+    /// Applies the settings from a [`PluginGroupConfig`].
+    ///
+    /// Settings that aren't in `value` should be left unchanged. Since [`ron::Value`] doesn't
+    /// keep track of optional fields, this is best done by looking them up in its
+    /// [`Map`](ron::Value::Map). Errors are logged as warnings, and the plugin is still added.
+    fn apply_config(&mut self, value: &ron::Value) -> Result<(), ron::Error>;
+}
+
+impl PluginGroupBuilder {
+    /// Applies a [`PluginGroupConfig`] to the plugins of this group, see
+    /// [`PluginGroup::with_config`](crate::PluginGroup::with_config).
+    pub fn apply_config(mut self, config: &PluginGroupConfig) -> Self {
+        for name in &config.disabled {
+            match self.entry_named_mut(name) {
+                Some(entry) => {
+                    entry.enabled = false;
+                    entry.history.push("disabled by config".to_string());
+                }
+                None => warn!(
+                    "Cannot disable unknown plugin `{name}` in {}",
+                    self.group_name()
+                ),
+            }
+        }
+
+        for (name, value) in &config.settings {
+            let Some(entry) = self.entry_named_mut(name) else {
+                warn!(
+                    "Cannot configure unknown plugin `{name}` in {}",
+                    self.group_name()
+                );
+                continue;
+            };
+            let Some(plugin) = entry.plugin.as_configurable() else {
+                warn!(
+                    "Cannot configure plugin `{name}`: it doesn't implement `ConfigurablePlugin`"
+                );
+                continue;
+            };
+            match plugin.apply_config(value) {
+                Ok(()) => entry.history.push("configured by config".to_string()),
+                Err(error) => warn!("Cannot configure plugin `{name}`: {error}"),
+            }
+        }
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ron, ConfigurablePlugin, PluginGroupConfig};
+    use crate::{App, NoopPluginGroup, Plugin, PluginGroup, PluginGroupBuilder};
+    use alloc::{
+        string::{String, ToString},
+        vec,
+    };
+
+    #[derive(Debug, PartialEq)]
+    struct LogPlugin {
+        filter: String,
+        level: u8,
+    }
+
+    impl Plugin for LogPlugin {
+        fn build(&self, _: &mut App) {}
+
+        fn as_configurable(&mut self) -> Option<&mut dyn ConfigurablePlugin> {
+            Some(self)
+        }
+    }
+
+    impl ConfigurablePlugin for LogPlugin {
+        fn apply_config(&mut self, value: &ron::Value) -> Result<(), ron::Error> {
+            let ron::Value::Map(settings) = value else {
+                return Err(ron::Error::ExpectedMap);
+            };
+            if let Some(filter) = settings.get(&ron::Value::String("filter".to_string())) {
+                self.filter = filter.clone().into_rust()?;
+            }
+            if let Some(level) = settings.get(&ron::Value::String("level".to_string())) {
+                self.level = level.clone().into_rust()?;
+            }
+            Ok(())
+        }
+    }
+
+    struct AudioPlugin;
+    impl Plugin for AudioPlugin {
+        fn build(&self, _: &mut App) {}
+    }
+
+    struct TestPlugins;
+    impl PluginGroup for TestPlugins {
+        fn build(self) -> PluginGroupBuilder {
+            PluginGroupBuilder::start::<NoopPluginGroup>()
+                .add(LogPlugin {
+                    filter: "info".to_string(),
+                    level: 2,
+                })
+                .add(AudioPlugin)
+        }
+    }
+
+    const CONFIG: &str = r#"(
+        disabled: ["bevy_app::plugin_config::tests::AudioPlugin", "bevy_audio::AudioPlugin"],
+        settings: {
+            "bevy_app::plugin_config::tests::LogPlugin": (filter: "wgpu=error"),
+            "bevy_app::plugin_config::tests::AudioPlugin": (volume: 0.5),
+            "bevy_app::plugin_config::tests::Unknown": (),
+        },
+    )"#;
+
+    #[test]
+    fn round_trip() {
+        let config = PluginGroupConfig::from_ron(CONFIG).unwrap();
+        assert_eq!(config.disabled.len(), 2);
+        assert_eq!(config.settings.len(), 3);
+        let serialized = config.to_ron().unwrap();
+        assert_eq!(PluginGroupConfig::from_ron(&serialized).unwrap(), config);
+
+        assert_eq!(
+            PluginGroupConfig::from_ron("()").unwrap(),
+            PluginGroupConfig::default()
+        );
+    }
+
+    #[test]
+    fn apply_config() {
+        let config = PluginGroupConfig::from_ron(CONFIG).unwrap();
+        // Unknown plugins and plugins that can't be configured only log warnings.
+        let group = TestPlugins.with_config(&config);
+
+        assert!(!group.enabled::<AudioPlugin>());
+        assert!(group.enabled::<LogPlugin>());
+        let log = group
+            .iter()
+            .find_map(|entry| entry.plugin().as_any().downcast_ref::<LogPlugin>())
+            .unwrap();
+        // Settings missing from the config are left unchanged.
+        assert_eq!(
+            log,
+            &LogPlugin {
+                filter: "wgpu=error".to_string(),
+                level: 2,
+            }
+        );
+
+        // Invalid settings are ignored.
+        let config = PluginGroupConfig {
+            settings: [(
+                "bevy_app::plugin_config::tests::LogPlugin".to_string(),
+                ron::from_str("(level: \"high\")").unwrap(),
+            )]
+            .into(),
+            disabled: vec![],
+        };
+        let group = TestPlugins.with_config(&config);
+        let log = group
+            .iter()
+            .find_map(|entry| entry.plugin().as_any().downcast_ref::<LogPlugin>())
+            .unwrap();
+        assert_eq!(log.level, 2);
+    }
+}
@@ -1,13 +1,19 @@
 use crate::{App, AppError, Plugin};
 use alloc::{
     boxed::Box,
+    format,
     string::{String, ToString},
+    vec,
     vec::Vec,
 };
 use bevy_platform::collections::hash_map::Entry;
 use bevy_utils::TypeIdMap;
-use core::any::TypeId;
+use core::{
+    any::TypeId,
+    fmt::{self, Debug},
+};
 use log::{debug, warn};
+use variadics_please::all_tuples;
 
 /// A macro for generating a well-documented [`PluginGroup`] from a list of [`Plugin`] paths.
 ///
@@ -212,11 +218,52 @@ pub trait PluginGroup: Sized {
     fn set<T: Plugin>(self, plugin: T) -> PluginGroupBuilder {
         self.build().set(plugin)
     }
+    /// Builds the group and applies a [`PluginGroupConfig`](crate::PluginGroupConfig) to its
+    /// plugins.
+    ///
+    /// The plugins listed in [`disabled`](crate::PluginGroupConfig::disabled) are disabled, and the
+    /// [`settings`](crate::PluginGroupConfig::settings) are applied to the plugins implementing
+    /// [`ConfigurablePlugin`](crate::ConfigurablePlugin). Unknown plugin names and invalid
+    /// settings are logged as warnings.
+    ///
+    /// ```no_run
+    /// # use bevy_app::{prelude::*, PluginGroupConfig, NoopPluginGroup as DefaultPlugins};
+    /// let config = std::fs::read_to_string("plugins.ron").unwrap();
+    /// let config = PluginGroupConfig::from_ron(&config).unwrap();
+    /// App::new().add_plugins(DefaultPlugins.with_config(&config)).run();
+    /// ```
+    #[cfg(feature = "plugin_config")]
+    fn with_config(self, config: &crate::PluginGroupConfig) -> PluginGroupBuilder {
+        self.build().apply_config(config)
+    }
+    /// Sets the value of the given [`Plugin`] if it exists, or adds it at the end otherwise.
+    ///
+    /// See [`PluginGroupBuilder::set_or_add`].
+    fn set_or_add<T: Plugin>(self, plugin: T) -> PluginGroupBuilder {
+        self.build().set_or_add(plugin)
+    }
+    /// Lets environment variables disable the [`Plugin`]s of the group.
+    ///
+    /// See [`PluginGroupBuilder::allow_env_overrides`].
+    fn allow_env_overrides(self) -> PluginGroupBuilder {
+        self.build().allow_env_overrides()
+    }
 }
 
-struct PluginEntry {
-    plugin: Box<dyn Plugin>,
-    enabled: bool,
+pub(crate) struct PluginEntry {
+    pub(crate) plugin: Box<dyn Plugin>,
+    pub(crate) enabled: bool,
+    pending_order: Option<PendingOrder>,
+    /// The calls that affected the plugin, for [`PluginGroupBuilder::describe`].
+    pub(crate) history: Vec<String>,
+}
+
+/// An ordering constraint whose target isn't in the group yet, see
+/// [`PluginGroupBuilder::add_before_deferred`].
+#[derive(Clone, Copy)]
+struct PendingOrder {
+    target: TypeId,
+    after: bool,
 }
 
 impl PluginGroup for PluginGroupBuilder {
@@ -225,24 +272,105 @@ impl PluginGroup for PluginGroupBuilder {
     }
 }
 
+/// A [`Plugin`] of a [`PluginGroupBuilder`], returned by [`PluginGroupBuilder::iter`].
+#[derive(Clone, Copy)]
+pub struct PluginGroupEntry<'a> {
+    plugin: &'a dyn Plugin,
+    enabled: bool,
+    index: usize,
+    history: &'a [String],
+}
+
+impl<'a> PluginGroupEntry<'a> {
+    /// Returns the plugin.
+    pub fn plugin(&self) -> &'a dyn Plugin {
+        self.plugin
+    }
+
+    /// Returns the [`name`](Plugin::name) of the plugin.
+    pub fn name(&self) -> &'a str {
+        self.plugin.name()
+    }
+
+    /// Returns `true` if the plugin is enabled, meaning it will be added to the [`App`].
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Returns the position of the plugin in the group.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the calls that affected the plugin, in the order they were made, see
+    /// [`PluginGroupBuilder::describe`].
+    pub fn history(&self) -> &'a [String] {
+        self.history
+    }
+}
+
+impl Debug for PluginGroupEntry<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PluginGroupEntry")
+            .field("name", &self.name())
+            .field("enabled", &self.enabled)
+            .field("index", &self.index)
+            .field("history", &self.history)
+            .finish()
+    }
+}
+
+/// An error returned by the methods of a [`PluginGroupBuilder`].
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum PluginGroupError {
+    /// The plugin is not in the group.
+    #[error("{plugin} is not in the group, which contains: {}", .plugins.join(", "))]
+    NotInGroup {
+        /// The name of the plugin.
+        plugin: &'static str,
+        /// The names of the plugins in the group, in order.
+        plugins: Vec<String>,
+    },
+}
+
 /// Facilitates the creation and configuration of a [`PluginGroup`].
 ///
 /// Provides a build ordering to ensure that [`Plugin`]s which produce/require a [`Resource`](bevy_ecs::resource::Resource)
 /// are built before/after dependent/depending [`Plugin`]s. [`Plugin`]s inside the group
 /// can be disabled, enabled or reordered.
 pub struct PluginGroupBuilder {
-    group_name: String,
+    group_name: Box<str>,
     plugins: TypeIdMap<PluginEntry>,
     order: Vec<TypeId>,
+    finish_options: Option<Box<FinishOptions>>,
+}
+
+impl Debug for PluginGroupBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.describe())
+    }
+}
+
+/// What to do when a [`PluginGroupBuilder`] is [finished](PluginGroupBuilder::finish).
+///
+/// Boxed in the builder, which keeps it small enough to be returned in the `Err` of the `try_*`
+/// methods.
+#[derive(Default)]
+struct FinishOptions {
+    /// The closures given to [`PluginGroupBuilder::build_with`], composed.
+    wrapper: Option<Box<dyn FnMut(Box<dyn Plugin>) -> Box<dyn Plugin> + Send + Sync>>,
+    /// Whether [`PluginGroupBuilder::allow_env_overrides`] was called.
+    env_overrides: bool,
 }
 
 impl PluginGroupBuilder {
     /// Start a new builder for the [`PluginGroup`].
     pub fn start<PG: PluginGroup>() -> Self {
         Self {
-            group_name: PG::name(),
+            group_name: PG::name().into(),
             plugins: Default::default(),
             order: Default::default(),
+            finish_options: None,
         }
     }
 
@@ -258,6 +386,104 @@ impl PluginGroupBuilder {
             .is_some_and(|e| e.enabled)
     }
 
+    /// Checks if the [`PluginGroupBuilder`] contains a [`Plugin`] whose [`name`](Plugin::name) is
+    /// `name`.
+    pub fn contains_named(&self, name: &str) -> bool {
+        self.index_of_named(name).is_some()
+    }
+
+    /// Returns the number of [`Plugin`]s in the [`PluginGroupBuilder`], including the disabled
+    /// ones.
+    pub fn len(&self) -> usize {
+        self.order.len()
+    }
+
+    /// Returns `true` if the [`PluginGroupBuilder`] doesn't contain any [`Plugin`].
+    pub fn is_empty(&self) -> bool {
+        self.order.is_empty()
+    }
+
+    /// Iterates over the [`Plugin`]s of the [`PluginGroupBuilder`], including the disabled ones,
+    /// in the order they will be added to the [`App`].
+    ///
+    /// ```
+    /// # use bevy_app::{prelude::*, PluginGroupBuilder, NoopPluginGroup};
+    /// # struct AudioPlugin;
+    /// # impl Plugin for AudioPlugin { fn build(&self, _: &mut App) {} }
+    /// let group = PluginGroupBuilder::start::<NoopPluginGroup>()
+    ///     .add(AudioPlugin)
+    ///     .disable::<AudioPlugin>();
+    /// for entry in group.iter() {
+    ///     let checkbox = if entry.enabled() { "[x]" } else { "[ ]" };
+    ///     println!("{checkbox} {}. {}", entry.index() + 1, entry.name());
+    /// }
+    /// ```
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = PluginGroupEntry<'_>> {
+        self.order.iter().enumerate().map(|(index, ty)| {
+            let entry = &self.plugins[ty];
+            PluginGroupEntry {
+                plugin: entry.plugin.as_ref(),
+                enabled: entry.enabled,
+                index,
+                history: &entry.history,
+            }
+        })
+    }
+
+    /// Describes the [`Plugin`]s of the [`PluginGroupBuilder`] in the order they will be added to
+    /// the [`App`], with whether they are enabled and the calls that affected them.
+    ///
+    /// This is useful to debug the order of a group, including the plugins added from other
+    /// groups with [`add_group`](Self::add_group) or [`merge`](Self::merge). The description is
+    /// also the [`Debug`] output of the builder, and it's logged at the `debug` level when the
+    /// group is added to an [`App`] if the `BEVY_DESCRIBE_PLUGIN_GROUPS` environment variable is
+    /// set.
+    ///
+    /// ```
+    /// # use bevy_app::{prelude::*, PluginGroupBuilder, NoopPluginGroup};
+    /// # struct TimePlugin;
+    /// # impl Plugin for TimePlugin { fn build(&self, _: &mut App) {} fn name(&self) -> &str { "TimePlugin" } }
+    /// # struct AudioPlugin;
+    /// # impl Plugin for AudioPlugin { fn build(&self, _: &mut App) {} fn name(&self) -> &str { "AudioPlugin" } }
+    /// let group = PluginGroupBuilder::start::<NoopPluginGroup>()
+    ///     .add(TimePlugin)
+    ///     .add_before::<TimePlugin>(AudioPlugin)
+    ///     .disable::<AudioPlugin>();
+    /// assert_eq!(
+    ///     group.describe(),
+    ///     "bevy_app::plugin_group::NoopPluginGroup:
+    ///   1. [disabled] AudioPlugin (add_before(TimePlugin), disable)
+    ///   2. [enabled] TimePlugin (add)
+    /// "
+    /// );
+    /// ```
+    pub fn describe(&self) -> String {
+        let mut description = format!("{}:\n", self.group_name);
+        for entry in self.iter() {
+            let state = if entry.enabled() {
+                "enabled"
+            } else {
+                "disabled"
+            };
+            description += &format!(
+                "  {}. [{state}] {} ({})\n",
+                entry.index() + 1,
+                entry.name(),
+                entry.history().join(", ")
+            );
+        }
+        description
+    }
+
+    /// Logs the [description](Self::describe) of the group if the `BEVY_DESCRIBE_PLUGIN_GROUPS`
+    /// environment variable is set.
+    fn log_description(&self) {
+        #[cfg(feature = "std")]
+        if std::env::var_os("BEVY_DESCRIBE_PLUGIN_GROUPS").is_some() {
+            debug!("{}", self.describe());
+        }
+    }
+
     /// Finds the index of a target [`Plugin`].
     fn index_of<Target: Plugin>(&self) -> Option<usize> {
         self.order
@@ -273,6 +499,8 @@ impl PluginGroupBuilder {
             PluginEntry {
                 plugin: Box::new(plugin),
                 enabled: true,
+                pending_order: None,
+                history: Vec::new(),
             },
             added_at_index,
         );
@@ -286,7 +514,7 @@ impl PluginGroupBuilder {
         plugin: PluginEntry,
         added_at_index: usize,
     ) {
-        if let Some(entry) = self.plugins.insert(key, plugin) {
+        if let Some(mut entry) = self.plugins.insert(key, plugin) {
             if entry.enabled {
                 warn!(
                     "You are replacing plugin '{}' that was not disabled.",
@@ -302,6 +530,17 @@ impl PluginGroupBuilder {
             {
                 self.order.remove(to_remove);
             }
+            // Keep the history of the replaced plugin, as it's the same entry for the user.
+            let new_entry = self.plugins.get_mut(&key).unwrap();
+            entry.history.append(&mut new_entry.history);
+            new_entry.history = entry.history;
+        }
+    }
+
+    /// Records that `note` affected the plugin `ty`, for [`describe`](Self::describe).
+    fn note(&mut self, ty: TypeId, note: impl Into<String>) {
+        if let Some(entry) = self.plugins.get_mut(&ty) {
+            entry.history.push(note.into());
         }
     }
 
@@ -325,7 +564,9 @@ impl PluginGroupBuilder {
     pub fn try_set<T: Plugin>(mut self, plugin: T) -> Result<Self, (Self, T)> {
         match self.plugins.entry(TypeId::of::<T>()) {
             Entry::Occupied(mut entry) => {
-                entry.get_mut().plugin = Box::new(plugin);
+                let entry = entry.get_mut();
+                entry.plugin = Box::new(plugin);
+                entry.history.push("set".to_string());
 
                 Ok(self)
             }
@@ -333,6 +574,26 @@ impl PluginGroupBuilder {
         }
     }
 
+    /// Sets the value of the given [`Plugin`] if it exists, keeping its place and whether it is
+    /// enabled. Otherwise, adds it at the end of this [`PluginGroupBuilder`].
+    ///
+    /// This is useful to configure a plugin that may have been removed from the group by a cargo
+    /// feature, without needing to mirror the feature in your own code:
+    ///
+    /// ```
+    /// # use bevy_app::{prelude::*, NoopPluginGroup as DefaultPlugins};
+    /// # #[derive(Default)]
+    /// # struct LogPlugin { filter: String }
+    /// # impl Plugin for LogPlugin { fn build(&self, _: &mut App) {} }
+    /// App::new().add_plugins(DefaultPlugins.set_or_add(LogPlugin {
+    ///     filter: "wgpu=error".to_string(),
+    /// }));
+    /// ```
+    pub fn set_or_add<T: Plugin>(self, plugin: T) -> Self {
+        self.try_set(plugin)
+            .unwrap_or_else(|(group, plugin)| group.add(plugin))
+    }
+
     /// Adds the plugin [`Plugin`] at the end of this [`PluginGroupBuilder`]. If the plugin was
     /// already in the group, it is removed from its previous place.
     // This is not confusing, clippy!
@@ -344,6 +605,8 @@ impl PluginGroupBuilder {
         let target_index = self.order.len();
         self.order.push(TypeId::of::<T>());
         self.upsert_plugin_state(plugin, target_index);
+        self.note(TypeId::of::<T>(), "add");
+        self.resolve_pending_orders();
         self
     }
 
@@ -360,9 +623,48 @@ impl PluginGroupBuilder {
 
     /// Adds a [`PluginGroup`] at the end of this [`PluginGroupBuilder`]. If the plugin was
     /// already in the group, it is removed from its previous place.
+    ///
+    /// The plugins of `group` are expanded into this group, keeping their order and whether they
+    /// are enabled. They can then be configured like the other plugins of this group: the ordering
+    /// methods can target them, and [`set`](Self::set), [`enable`](Self::enable) and
+    /// [`disable`](Self::disable) apply to them. Plugins of different types with the same
+    /// [name](Plugin::name) are kept, and [`finish`](Self::finish) panics on the second one like
+    /// for any other duplicate.
+    ///
+    /// ```
+    /// # use bevy_app::{prelude::*, PluginGroupBuilder};
+    /// # struct WindowPlugin;
+    /// # impl Plugin for WindowPlugin { fn build(&self, _: &mut App) {} }
+    /// # struct InputPlugin;
+    /// # impl Plugin for InputPlugin { fn build(&self, _: &mut App) {} }
+    /// # struct AudioPlugin;
+    /// # impl Plugin for AudioPlugin { fn build(&self, _: &mut App) {} }
+    /// struct PlatformPlugins;
+    ///
+    /// impl PluginGroup for PlatformPlugins {
+    ///     fn build(self) -> PluginGroupBuilder {
+    ///         PluginGroupBuilder::start::<Self>()
+    ///             .add(WindowPlugin)
+    ///             .add(InputPlugin)
+    ///     }
+    /// }
+    ///
+    /// struct GamePlugins;
+    ///
+    /// impl PluginGroup for GamePlugins {
+    ///     fn build(self) -> PluginGroupBuilder {
+    ///         PluginGroupBuilder::start::<Self>()
+    ///             .add_group(PlatformPlugins)
+    ///             .add_before::<InputPlugin>(AudioPlugin)
+    ///     }
+    /// }
+    /// ```
     pub fn add_group(mut self, group: impl PluginGroup) -> Self {
         let Self {
-            mut plugins, order, ..
+            group_name,
+            mut plugins,
+            order,
+            ..
         } = group.build();
 
         for plugin_id in order {
@@ -373,8 +675,68 @@ impl PluginGroupBuilder {
             );
 
             self.order.push(plugin_id);
+            self.note(plugin_id, format!("add_group({group_name})"));
+        }
+
+        self.resolve_pending_orders();
+        self
+    }
+
+    /// Merges another [`PluginGroupBuilder`] into this one.
+    ///
+    /// Unlike [`add_group`](Self::add_group), the plugins of this group keep their place: the
+    /// plugins that are only in `other` are added at the end, in the order of `other`, and for
+    /// the plugins that are in both groups, the instance configured in `other` replaces the one
+    /// of this group, in the position of this group. The pending constraints of both groups, added
+    /// with [`add_before_deferred`](Self::add_before_deferred) or
+    /// [`add_after_deferred`](Self::add_after_deferred), are resolved if their target is now in
+    /// the group.
+    ///
+    /// A plugin disabled in either group stays disabled, even if it is enabled in the other.
+    /// It can still be [re-enabled](Self::enable) after merging.
+    ///
+    /// ```
+    /// # use bevy_app::{prelude::*, PluginGroupBuilder, NoopPluginGroup as DefaultPlugins};
+    /// # struct TelemetryPlugin;
+    /// # impl Plugin for TelemetryPlugin { fn build(&self, _: &mut App) {} }
+    /// struct CompanyPlugins;
+    ///
+    /// impl PluginGroup for CompanyPlugins {
+    ///     fn build(self) -> PluginGroupBuilder {
+    ///         PluginGroupBuilder::start::<Self>().add(TelemetryPlugin)
+    ///     }
+    /// }
+    ///
+    /// App::new().add_plugins(DefaultPlugins.build().merge(CompanyPlugins.build()));
+    /// ```
+    pub fn merge(mut self, other: PluginGroupBuilder) -> Self {
+        let Self {
+            group_name,
+            mut plugins,
+            order,
+            ..
+        } = other;
+
+        for plugin_id in order {
+            let mut entry = plugins.remove(&plugin_id).unwrap();
+            match self.plugins.get_mut(&plugin_id) {
+                Some(existing) => {
+                    existing.plugin = entry.plugin;
+                    existing.enabled &= entry.enabled;
+                    existing.pending_order = entry.pending_order.or(existing.pending_order);
+                    existing
+                        .history
+                        .push(format!("replaced by merge({group_name})"));
+                }
+                None => {
+                    entry.history.push(format!("merge({group_name})"));
+                    self.plugins.insert(plugin_id, entry);
+                    self.order.push(plugin_id);
+                }
+            }
         }
 
+        self.resolve_pending_orders();
         self
     }
 
@@ -416,16 +778,15 @@ impl PluginGroupBuilder {
     /// If there isn't a plugin of type `Target` in the group the plugin we're trying to insert
     /// is returned.
     pub fn try_add_before_overwrite<Target: Plugin, Insert: Plugin>(
-        mut self,
+        self,
         plugin: Insert,
     ) -> Result<Self, (Self, Insert)> {
         let Some(target_index) = self.index_of::<Target>() else {
             return Err((self, plugin));
         };
 
-        self.order.insert(target_index, TypeId::of::<Insert>());
-        self.upsert_plugin_state(plugin, target_index);
-        Ok(self)
+        let note = format!("add_before({})", self.name_at(target_index));
+        Ok(self.insert_at(target_index, plugin, note))
     }
 
     /// Adds a [`Plugin`] in this [`PluginGroupBuilder`] after the plugin of type `Target`.
@@ -466,48 +827,483 @@ impl PluginGroupBuilder {
     /// If there isn't a plugin of type `Target` in the group the plugin we're trying to insert
     /// is returned.
     pub fn try_add_after_overwrite<Target: Plugin, Insert: Plugin>(
-        mut self,
+        self,
         plugin: Insert,
     ) -> Result<Self, (Self, Insert)> {
         let Some(target_index) = self.index_of::<Target>() else {
             return Err((self, plugin));
         };
 
-        let target_index = target_index + 1;
+        let note = format!("add_after({})", self.name_at(target_index));
+        Ok(self.insert_at(target_index + 1, plugin, note))
+    }
 
-        self.order.insert(target_index, TypeId::of::<Insert>());
-        self.upsert_plugin_state(plugin, target_index);
-        Ok(self)
+    /// Adds a [`Plugin`] in this [`PluginGroupBuilder`] before the plugin whose
+    /// [`name`](Plugin::name) is `target`.
+    ///
+    /// This is useful when the type of the target plugin can't be named, for example because it
+    /// is private or behind a feature you don't depend on. If the plugin was already in the group,
+    /// it is removed from its previous place. If no plugin of the group is named `target`, the
+    /// plugin we're trying to insert is returned, so it can be [added](Self::add) at the end
+    /// instead.
+    ///
+    /// The name must match exactly. Note that the default name of a plugin is its
+    /// [type name](core::any::type_name), whose exact contents aren't guaranteed and may change
+    /// between versions of Rust or when the plugin is moved to another module.
+    ///
+    /// ```
+    /// # use bevy_app::{prelude::*, PluginGroupBuilder, NoopPluginGroup};
+    /// # struct MyPlugin;
+    /// # impl Plugin for MyPlugin { fn build(&self, _: &mut App) {} }
+    /// let group = PluginGroupBuilder::start::<NoopPluginGroup>()
+    ///     .add_before_named("bevy_render::RenderPlugin", MyPlugin)
+    ///     .unwrap_or_else(|(group, plugin)| group.add(plugin));
+    /// ```
+    pub fn add_before_named<Insert: Plugin>(
+        self,
+        target: &str,
+        plugin: Insert,
+    ) -> Result<Self, (Self, Insert)> {
+        let Some(target_index) = self.index_of_named(target) else {
+            return Err((self, plugin));
+        };
+        Ok(self.insert_at(target_index, plugin, format!("add_before_named({target})")))
+    }
+
+    /// Adds a [`Plugin`] in this [`PluginGroupBuilder`] after the plugin whose
+    /// [`name`](Plugin::name) is `target`.
+    ///
+    /// See [`add_before_named`](Self::add_before_named) for more details.
+    pub fn add_after_named<Insert: Plugin>(
+        self,
+        target: &str,
+        plugin: Insert,
+    ) -> Result<Self, (Self, Insert)> {
+        let Some(target_index) = self.index_of_named(target) else {
+            return Err((self, plugin));
+        };
+        Ok(self.insert_at(
+            target_index + 1,
+            plugin,
+            format!("add_after_named({target})"),
+        ))
+    }
+
+    /// Adds a [`Plugin`] in this [`PluginGroupBuilder`] before the plugin of type `Target`, even
+    /// if `Target` isn't in the group yet.
+    ///
+    /// If `Target` is in the group, this is the same as [`add_before`](Self::add_before).
+    /// Otherwise, the plugin is added at the end and moved before `Target` once it is added to
+    /// the group, for example by [merging](Self::merge) another group. This lets a group order
+    /// its plugins relative to plugins of a group it is meant to be merged with. If `Target` is
+    /// never added, the plugin stays where it was added.
+    pub fn add_before_deferred<Target: Plugin, Insert: Plugin>(self, plugin: Insert) -> Self {
+        self.add_deferred::<Target, Insert>(plugin, false)
+    }
+
+    /// Adds a [`Plugin`] in this [`PluginGroupBuilder`] after the plugin of type `Target`, even
+    /// if `Target` isn't in the group yet.
+    ///
+    /// See [`add_before_deferred`](Self::add_before_deferred) for more details.
+    pub fn add_after_deferred<Target: Plugin, Insert: Plugin>(self, plugin: Insert) -> Self {
+        self.add_deferred::<Target, Insert>(plugin, true)
+    }
+
+    fn add_deferred<Target: Plugin, Insert: Plugin>(self, plugin: Insert, after: bool) -> Self {
+        let call = if after {
+            "add_after_deferred"
+        } else {
+            "add_before_deferred"
+        };
+        if let Some(target_index) = self.index_of::<Target>() {
+            let note = format!("{call}({})", self.name_at(target_index));
+            return self.insert_at(target_index + usize::from(after), plugin, note);
+        }
+        let mut group = self.add(plugin);
+        let entry = group.plugins.get_mut(&TypeId::of::<Insert>()).unwrap();
+        entry.pending_order = Some(PendingOrder {
+            target: TypeId::of::<Target>(),
+            after,
+        });
+        entry.history.push(format!(
+            "{call}({}, pending)",
+            core::any::type_name::<Target>()
+        ));
+        group
+    }
+
+    /// Applies the pending ordering constraints whose target is now in the group.
+    fn resolve_pending_orders(&mut self) {
+        for plugin_id in self.order.clone() {
+            let entry = self.plugins.get_mut(&plugin_id).unwrap();
+            let Some(pending) = entry.pending_order else {
+                continue;
+            };
+            if !self.order.contains(&pending.target) {
+                continue;
+            }
+            entry.pending_order = None;
+            let index = self.order.iter().position(|&ty| ty == plugin_id).unwrap();
+            self.order.remove(index);
+            let target_index = self
+                .order
+                .iter()
+                .position(|&ty| ty == pending.target)
+                .unwrap();
+            self.order
+                .insert(target_index + usize::from(pending.after), plugin_id);
+            let note = format!(
+                "moved {} {} (deferred)",
+                if pending.after { "after" } else { "before" },
+                self.plugins[&pending.target].plugin.name()
+            );
+            self.note(plugin_id, note);
+        }
+    }
+
+    /// Returns the name of the group.
+    pub(crate) fn group_name(&self) -> &str {
+        &self.group_name
+    }
+
+    /// Returns the entry of the [`Plugin`] named `name`.
+    #[cfg_attr(
+        not(feature = "std"),
+        expect(
+            dead_code,
+            reason = "only used to apply plugin configs and env overrides"
+        )
+    )]
+    pub(crate) fn entry_named_mut(&mut self, name: &str) -> Option<&mut PluginEntry> {
+        let index = self.index_of_named(name)?;
+        self.plugins.get_mut(&self.order[index])
+    }
+
+    /// Finds the index of the [`Plugin`] named `target`.
+    fn index_of_named(&self, target: &str) -> Option<usize> {
+        self.order
+            .iter()
+            .position(|ty| self.plugins[ty].plugin.name() == target)
+    }
+
+    /// Returns the [`name`](Plugin::name) of the plugin at `index`.
+    fn name_at(&self, index: usize) -> &str {
+        self.plugins[&self.order[index]].plugin.name()
+    }
+
+    fn insert_at<Insert: Plugin>(mut self, index: usize, plugin: Insert, note: String) -> Self {
+        self.order.insert(index, TypeId::of::<Insert>());
+        self.upsert_plugin_state(plugin, index);
+        self.note(TypeId::of::<Insert>(), note);
+        self
     }
 
     /// Enables a [`Plugin`].
     ///
     /// [`Plugin`]s within a [`PluginGroup`] are enabled by default. This function is used to
-    /// opt back in to a [`Plugin`] after [disabling](Self::disable) it. If there are no plugins
-    /// of type `T` in this group, it will panic.
-    pub fn enable<T: Plugin>(mut self) -> Self {
-        let plugin_entry = self
-            .plugins
-            .get_mut(&TypeId::of::<T>())
-            .expect("Cannot enable a plugin that does not exist.");
-        plugin_entry.enabled = true;
-        self
+    /// opt back in to a [`Plugin`] after [disabling](Self::disable) it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no plugins of type `T` in this group. See
+    /// [`try_enable`](Self::try_enable) for a non-panicking version.
+    pub fn enable<T: Plugin>(self) -> Self {
+        self.try_enable::<T>().unwrap_or_else(|(group, error)| {
+            panic!("Cannot enable a plugin in {}: {error}", group.group_name)
+        })
+    }
+
+    /// Tries to enable a [`Plugin`], see [`enable`](Self::enable).
+    ///
+    /// If there are no plugins of type `T` in this group, returns self and an error listing the
+    /// plugins of the group.
+    pub fn try_enable<T: Plugin>(self) -> Result<Self, (Self, PluginGroupError)> {
+        self.set_enabled::<T>(true)
     }
 
     /// Disables a [`Plugin`], preventing it from being added to the [`App`] with the rest of the
     /// [`PluginGroup`]. The disabled [`Plugin`] keeps its place in the [`PluginGroup`], so it can
     /// still be used for ordering with [`add_before`](Self::add_before) or
-    /// [`add_after`](Self::add_after), or it can be [re-enabled](Self::enable). If there are no
-    /// plugins of type `T` in this group, it will panic.
-    pub fn disable<T: Plugin>(mut self) -> Self {
-        let plugin_entry = self
-            .plugins
-            .get_mut(&TypeId::of::<T>())
-            .expect("Cannot disable a plugin that does not exist.");
-        plugin_entry.enabled = false;
+    /// [`add_after`](Self::add_after), or it can be [re-enabled](Self::enable).
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no plugins of type `T` in this group. See
+    /// [`try_disable`](Self::try_disable) for a non-panicking version.
+    pub fn disable<T: Plugin>(self) -> Self {
+        self.try_disable::<T>().unwrap_or_else(|(group, error)| {
+            panic!("Cannot disable a plugin in {}: {error}", group.group_name)
+        })
+    }
+
+    /// Tries to disable a [`Plugin`], see [`disable`](Self::disable).
+    ///
+    /// If there are no plugins of type `T` in this group, returns self and an error listing the
+    /// plugins of the group. This is useful when the plugin may have been removed from the group
+    /// by a cargo feature:
+    ///
+    /// ```
+    /// # use bevy_app::{prelude::*, NoopPluginGroup as DefaultPlugins};
+    /// # struct AudioPlugin;
+    /// # impl Plugin for AudioPlugin { fn build(&self, _: &mut App) {} }
+    /// let group = DefaultPlugins
+    ///     .build()
+    ///     .try_disable::<AudioPlugin>()
+    ///     .unwrap_or_else(|(group, _)| group);
+    /// ```
+    pub fn try_disable<T: Plugin>(self) -> Result<Self, (Self, PluginGroupError)> {
+        self.set_enabled::<T>(false)
+    }
+
+    /// Disables every [`Plugin`] of the group, see [`disable`](Self::disable).
+    ///
+    /// [`Plugin`]s added to the group afterwards are enabled, as usual. To keep only a few
+    /// plugins of a group, see [`enable_only`](Self::enable_only).
+    pub fn disable_all(mut self) -> Self {
+        for entry in self.plugins.values_mut() {
+            entry.enabled = false;
+            entry.history.push("disable_all".to_string());
+        }
+        self
+    }
+
+    /// Disables every [`Plugin`] of the group except the ones in the tuple `T`, which are
+    /// enabled. The plugins keep their order in the group.
+    ///
+    /// This is useful to pick a few plugins out of a large group, without having to disable the
+    /// plugins that may be added to the group later:
+    ///
+    /// ```
+    /// # use bevy_app::{prelude::*, PluginGroupBuilder, NoopPluginGroup};
+    /// # struct TimePlugin;
+    /// # impl Plugin for TimePlugin { fn build(&self, _: &mut App) {} }
+    /// # struct WindowPlugin;
+    /// # impl Plugin for WindowPlugin { fn build(&self, _: &mut App) {} }
+    /// # struct AudioPlugin;
+    /// # impl Plugin for AudioPlugin { fn build(&self, _: &mut App) {} }
+    /// # struct DefaultPlugins;
+    /// # impl PluginGroup for DefaultPlugins {
+    /// #     fn build(self) -> PluginGroupBuilder {
+    /// #         PluginGroupBuilder::start::<NoopPluginGroup>()
+    /// #             .add(TimePlugin)
+    /// #             .add(WindowPlugin)
+    /// #             .add(AudioPlugin)
+    /// #     }
+    /// # }
+    /// let group = DefaultPlugins.build().enable_only::<(TimePlugin, AudioPlugin)>();
+    /// assert!(group.enabled::<TimePlugin>());
+    /// assert!(!group.enabled::<WindowPlugin>());
+    /// assert!(group.enabled::<AudioPlugin>());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if one of the plugins is not in this group. See
+    /// [`try_enable_only`](Self::try_enable_only) for a non-panicking version.
+    pub fn enable_only<T: PluginTypes>(self) -> Self {
+        self.try_enable_only::<T>()
+            .unwrap_or_else(|(group, error)| {
+                panic!("Cannot enable a plugin in {}: {error}", group.group_name)
+            })
+    }
+
+    /// Tries to enable only the [`Plugin`]s in the tuple `T`, see
+    /// [`enable_only`](Self::enable_only).
+    ///
+    /// If one of the plugins is not in this group, returns self unchanged and an error listing
+    /// the plugins of the group.
+    pub fn try_enable_only<T: PluginTypes>(mut self) -> Result<Self, (Self, PluginGroupError)> {
+        let plugins = T::plugin_types();
+        if let Some((_, name)) = plugins
+            .iter()
+            .find(|(ty, _)| !self.plugins.contains_key(ty))
+        {
+            let error = self.not_in_group(name);
+            return Err((self, error));
+        }
+        for (ty, entry) in &mut self.plugins {
+            entry.enabled = plugins.iter().any(|(enabled, _)| enabled == ty);
+            entry.history.push("enable_only".to_string());
+        }
+        Ok(self)
+    }
+
+    fn set_enabled<T: Plugin>(mut self, enabled: bool) -> Result<Self, (Self, PluginGroupError)> {
+        let Some(plugin_entry) = self.plugins.get_mut(&TypeId::of::<T>()) else {
+            let error = self.not_in_group(core::any::type_name::<T>());
+            return Err((self, error));
+        };
+        plugin_entry.enabled = enabled;
+        let note = if enabled { "enable" } else { "disable" };
+        plugin_entry.history.push(note.to_string());
+        Ok(self)
+    }
+
+    fn not_in_group(&self, plugin: &'static str) -> PluginGroupError {
+        PluginGroupError::NotInGroup {
+            plugin,
+            plugins: self
+                .order
+                .iter()
+                .map(|ty| self.plugins[ty].plugin.name().to_string())
+                .collect(),
+        }
+    }
+
+    /// Passes each enabled [`Plugin`] through `wrapper` when the group is
+    /// [finished](Self::finish), for example to instrument every plugin of the group without
+    /// naming them.
+    ///
+    /// `wrapper` receives the plugins in their final order, as configured by the calls to
+    /// [`set`](Self::set) made before or after this one, and the app adds the plugins it returns.
+    /// When called several times, the wrappers are applied in the order they were given.
+    ///
+    /// The uniqueness of the plugins is checked with the [name](Plugin::name) of the returned
+    /// plugins, so a wrapper usually forwards the name of the plugin it wraps. Wrappers of a group
+    /// added to this one with [`add_group`](Self::add_group) or [`merge`](Self::merge) are
+    /// ignored, only the wrappers of the group added to the app are applied.
+    ///
+    /// ```
+    /// # use bevy_app::{prelude::*, NoopPluginGroup as DefaultPlugins};
+    /// struct Timed(Box<dyn Plugin>);
+    ///
+    /// impl Plugin for Timed {
+    ///     fn build(&self, app: &mut App) {
+    ///         // Start a timer...
+    ///         self.0.build(app);
+    ///         // ... and log how long the plugin took to build.
+    ///     }
+    ///
+    ///     fn name(&self) -> &str {
+    ///         self.0.name()
+    ///     }
+    /// }
+    ///
+    /// App::new().add_plugins(
+    ///     DefaultPlugins
+    ///         .build()
+    ///         .build_with(|plugin| Box::new(Timed(plugin))),
+    /// );
+    /// ```
+    pub fn build_with(
+        mut self,
+        mut wrapper: impl FnMut(Box<dyn Plugin>) -> Box<dyn Plugin> + Send + Sync + 'static,
+    ) -> Self {
+        let options = self.finish_options.get_or_insert_default();
+        options.wrapper = Some(match options.wrapper.take() {
+            Some(mut previous) => Box::new(move |plugin| wrapper(previous(plugin))),
+            None => Box::new(wrapper),
+        });
+        self
+    }
+
+    /// Lets the `BEVY_DISABLE_PLUGINS` and `BEVY_ONLY_PLUGINS` environment variables disable
+    /// [`Plugin`]s of the group when it is [finished](Self::finish), for example to disable the
+    /// audio of a build without rebuilding it.
+    ///
+    /// Both variables are comma-separated lists of plugin [names](Plugin::name), such as
+    /// `BEVY_DISABLE_PLUGINS=bevy_audio::AudioPlugin,bevy_gilrs::GilrsPlugin`. The plugins listed
+    /// in `BEVY_DISABLE_PLUGINS` are disabled, and if `BEVY_ONLY_PLUGINS` is set, the plugins it
+    /// doesn't list are disabled. Each disabled plugin is logged, and the names that don't match a
+    /// plugin of the group are warned about.
+    ///
+    /// Like the [`build_with`](Self::build_with) wrappers, this only applies to the group added to
+    /// the app, not to the groups added to it with [`add_group`](Self::add_group) or
+    /// [`merge`](Self::merge). The variables are ignored without the `std` feature.
+    ///
+    /// ```
+    /// # use bevy_app::{prelude::*, NoopPluginGroup as DefaultPlugins};
+    /// App::new().add_plugins(DefaultPlugins.allow_env_overrides());
+    /// ```
+    pub fn allow_env_overrides(mut self) -> Self {
+        self.finish_options.get_or_insert_default().env_overrides = true;
         self
     }
 
+    /// Disables the plugins named in the environment variables, if
+    /// [`allow_env_overrides`](Self::allow_env_overrides) was called.
+    fn apply_env_overrides(&mut self) {
+        #[cfg(feature = "std")]
+        if self
+            .finish_options
+            .as_ref()
+            .is_some_and(|options| options.env_overrides)
+        {
+            self.override_enabled(|name| std::env::var(name).ok());
+        }
+    }
+
+    /// Disables the plugins listed in the `BEVY_DISABLE_PLUGINS` variable, and the plugins not
+    /// listed in the `BEVY_ONLY_PLUGINS` variable if it's set, reading the variables with `var`.
+    #[cfg(feature = "std")]
+    fn override_enabled(&mut self, var: impl Fn(&str) -> Option<String>) {
+        fn names(list: &str) -> impl Iterator<Item = &str> {
+            list.split(',')
+                .map(str::trim)
+                .filter(|name| !name.is_empty())
+        }
+
+        let disabled = var("BEVY_DISABLE_PLUGINS");
+        for name in disabled.as_deref().into_iter().flat_map(names) {
+            let group_name = self.group_name.clone();
+            match self.entry_named_mut(name) {
+                Some(entry) => {
+                    if entry.enabled {
+                        log::info!(
+                            "Disabled plugin {name} in {group_name} with BEVY_DISABLE_PLUGINS"
+                        );
+                    }
+                    entry.enabled = false;
+                    entry.history.push("disabled by BEVY_DISABLE_PLUGINS".to_string());
+                }
+                None => warn!(
+                    "Cannot disable unknown plugin `{name}` in {group_name} with BEVY_DISABLE_PLUGINS"
+                ),
+            }
+        }
+
+        let Some(only) = var("BEVY_ONLY_PLUGINS") else {
+            return;
+        };
+        let only = only.as_str();
+        for name in names(only) {
+            if !self.contains_named(name) {
+                warn!(
+                    "Cannot keep unknown plugin `{name}` in {} with BEVY_ONLY_PLUGINS",
+                    self.group_name
+                );
+            }
+        }
+        for entry in self.plugins.values_mut() {
+            let name = entry.plugin.name();
+            if names(only).any(|only| only == name) {
+                continue;
+            }
+            if entry.enabled {
+                log::info!(
+                    "Disabled plugin {name} in {} with BEVY_ONLY_PLUGINS",
+                    self.group_name
+                );
+            }
+            entry.enabled = false;
+            entry
+                .history
+                .push("disabled by BEVY_ONLY_PLUGINS".to_string());
+        }
+    }
+
+    /// Returns the plugin of `entry` to add to the app, after the [`build_with`](Self::build_with)
+    /// wrappers.
+    fn wrap(options: &mut Option<Box<FinishOptions>>, entry: PluginEntry) -> Box<dyn Plugin> {
+        match options
+            .as_mut()
+            .and_then(|options| options.wrapper.as_mut())
+        {
+            Some(wrapper) => wrapper(entry.plugin),
+            None => entry.plugin,
+        }
+    }
+
     /// Consumes the [`PluginGroupBuilder`] and [builds](Plugin::build) the contained [`Plugin`]s
     /// in the order specified.
     ///
@@ -516,13 +1312,15 @@ impl PluginGroupBuilder {
     /// Panics if one of the plugin in the group was already added to the application.
     #[track_caller]
     pub fn finish(mut self, app: &mut App) {
+        self.apply_env_overrides();
+        self.log_description();
         for ty in &self.order {
             if let Some(entry) = self.plugins.remove(ty)
                 && entry.enabled
             {
-                debug!("added plugin: {}", entry.plugin.name());
-                if let Err(AppError::DuplicatePlugin { plugin_name }) =
-                    app.add_boxed_plugin(entry.plugin)
+                let plugin = Self::wrap(&mut self.finish_options, entry);
+                debug!("added plugin: {}", plugin.name());
+                if let Err(AppError::DuplicatePlugin { plugin_name }) = app.add_boxed_plugin(plugin)
                 {
                     panic!(
                         "Error adding plugin {} in group {}: plugin was already added in application",
@@ -533,8 +1331,55 @@ impl PluginGroupBuilder {
             }
         }
     }
+
+    /// Consumes the [`PluginGroupBuilder`] and [builds](Plugin::build) the contained [`Plugin`]s
+    /// in the order specified, skipping the ones that were already added to the application.
+    #[track_caller]
+    pub(crate) fn finish_if_new(mut self, app: &mut App) {
+        let caller = core::panic::Location::caller();
+        self.apply_env_overrides();
+        self.log_description();
+        for ty in &self.order {
+            if let Some(entry) = self.plugins.remove(ty)
+                && entry.enabled
+                && let Err(AppError::DuplicatePlugin { plugin_name }) =
+                    app.add_boxed_plugin(Self::wrap(&mut self.finish_options, entry))
+            {
+                debug!(
+                    "Skipped plugin {} in group {} added at {}: plugin was already added in application",
+                    plugin_name, self.group_name, caller
+                );
+            }
+        }
+    }
+}
+
+/// A tuple of [`Plugin`] types, to name several plugins at once, for example with
+/// [`PluginGroupBuilder::enable_only`].
+pub trait PluginTypes {
+    /// Returns the [`TypeId`] and the type name of each plugin.
+    fn plugin_types() -> Vec<(TypeId, &'static str)>;
 }
 
+macro_rules! impl_plugin_types {
+    ($(#[$meta:meta])* $($plugin: ident),*) => {
+        $(#[$meta])*
+        impl<$($plugin: Plugin),*> PluginTypes for ($($plugin,)*) {
+            fn plugin_types() -> Vec<(TypeId, &'static str)> {
+                vec![$((TypeId::of::<$plugin>(), core::any::type_name::<$plugin>())),*]
+            }
+        }
+    };
+}
+
+all_tuples!(
+    #[doc(fake_variadic)]
+    impl_plugin_types,
+    0,
+    15,
+    P
+);
+
 /// A plugin group which doesn't do anything. Useful for examples:
 /// ```
 /// # use bevy_app::prelude::*;
@@ -555,11 +1400,16 @@ impl PluginGroup for NoopPluginGroup {
 
 #[cfg(test)]
 mod tests {
-    use alloc::vec;
+    use alloc::{
+        format,
+        string::{String, ToString},
+        vec,
+        vec::Vec,
+    };
     use core::{any::TypeId, fmt::Debug};
 
-    use super::PluginGroupBuilder;
-    use crate::{App, NoopPluginGroup, Plugin};
+    use super::{PluginGroupBuilder, PluginGroupEntry, PluginGroupError};
+    use crate::{App, NoopPluginGroup, Plugin, PluginGroup};
 
     struct PluginA;
     impl Plugin for PluginA {
@@ -873,4 +1723,635 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn configure_subgroup_members() {
+        let inner = PluginGroupBuilder::start::<NoopPluginGroup>()
+            .add(PluginA)
+            .add(PluginWithData(0))
+            .disable::<PluginA>();
+
+        let group = PluginGroupBuilder::start::<NoopPluginGroup>()
+            .add(PluginB)
+            .add_group(inner)
+            .add_after::<PluginB>(PluginC)
+            .set(PluginWithData(3))
+            .enable::<PluginA>()
+            .disable::<PluginB>();
+
+        assert_eq!(
+            group.order,
+            vec![
+                TypeId::of::<PluginB>(),
+                TypeId::of::<PluginC>(),
+                TypeId::of::<PluginA>(),
+                TypeId::of::<PluginWithData>(),
+            ]
+        );
+        assert_eq!(
+            get_plugin::<PluginWithData>(&group, TypeId::of::<PluginWithData>()),
+            &PluginWithData(3)
+        );
+        assert!(group.enabled::<PluginA>());
+        assert!(!group.enabled::<PluginB>());
+
+        // Disabled plugins of the inner group stay disabled.
+        let inner = PluginGroupBuilder::start::<NoopPluginGroup>()
+            .add(PluginA)
+            .disable::<PluginA>();
+        let group = PluginGroupBuilder::start::<NoopPluginGroup>().add_group(inner);
+        assert!(!group.enabled::<PluginA>());
+    }
+
+    #[test]
+    #[should_panic(expected = "plugin was already added in application")]
+    fn subgroup_duplicate_names() {
+        struct Named(&'static str);
+        impl Plugin for Named {
+            fn build(&self, _: &mut App) {}
+            fn name(&self) -> &str {
+                self.0
+            }
+        }
+        struct OtherNamed(&'static str);
+        impl Plugin for OtherNamed {
+            fn build(&self, _: &mut App) {}
+            fn name(&self) -> &str {
+                self.0
+            }
+        }
+
+        let inner = PluginGroupBuilder::start::<NoopPluginGroup>().add(OtherNamed("shared"));
+        let group = PluginGroupBuilder::start::<NoopPluginGroup>()
+            .add(Named("shared"))
+            .add_group(inner);
+        App::new().add_plugins(group);
+    }
+
+    /// A group where `PluginB` is excluded when a cargo feature is disabled.
+    fn feature_gated_group(feature_enabled: bool) -> PluginGroupBuilder {
+        let group = PluginGroupBuilder::start::<NoopPluginGroup>().add(PluginA);
+        let group = if feature_enabled {
+            group.add(PluginB)
+        } else {
+            group
+        };
+        group.add(PluginC)
+    }
+
+    #[test]
+    fn try_disable_and_enable() {
+        for feature_enabled in [true, false] {
+            let group = feature_gated_group(feature_enabled)
+                .try_disable::<PluginB>()
+                .unwrap_or_else(|(group, _)| group);
+            assert!(!group.enabled::<PluginB>());
+            assert!(group.enabled::<PluginA>());
+
+            let group = match group.try_enable::<PluginB>() {
+                Ok(group) => {
+                    assert!(feature_enabled);
+                    group
+                }
+                Err((group, error)) => {
+                    assert!(!feature_enabled);
+                    assert_eq!(
+                        error,
+                        PluginGroupError::NotInGroup {
+                            plugin: "bevy_app::plugin_group::tests::PluginB",
+                            plugins: vec![
+                                "bevy_app::plugin_group::tests::PluginA".to_string(),
+                                "bevy_app::plugin_group::tests::PluginC".to_string(),
+                            ],
+                        }
+                    );
+                    group
+                }
+            };
+            assert_eq!(group.enabled::<PluginB>(), feature_enabled);
+        }
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Cannot disable a plugin in bevy_app::plugin_group::NoopPluginGroup: bevy_app::plugin_group::tests::PluginB is not in the group, which contains: bevy_app::plugin_group::tests::PluginA, bevy_app::plugin_group::tests::PluginC"
+    )]
+    fn disable_nonexistent() {
+        feature_gated_group(false).disable::<PluginB>();
+    }
+
+    #[test]
+    fn disable_all_then_add() {
+        let group = feature_gated_group(true)
+            .disable_all()
+            .add(PluginWithData(0))
+            .add_before::<PluginA>(PluginWithData(1));
+
+        assert!(!group.enabled::<PluginA>());
+        assert!(!group.enabled::<PluginB>());
+        assert!(!group.enabled::<PluginC>());
+        // Plugins added after `disable_all` are enabled.
+        assert!(group.enabled::<PluginWithData>());
+    }
+
+    #[test]
+    fn enable_only() {
+        let group = feature_gated_group(true)
+            .add(PluginWithData(0))
+            .enable_only::<(PluginWithData, PluginA)>();
+
+        let enabled = group
+            .iter()
+            .filter(PluginGroupEntry::enabled)
+            .map(|entry| entry.index())
+            .collect::<Vec<_>>();
+        // The order of the group is kept, not the order of the tuple.
+        assert_eq!(enabled, vec![0, 3]);
+        assert_eq!(group.order[3], TypeId::of::<PluginWithData>());
+
+        let group = group.enable_only::<()>();
+        assert_eq!(group.iter().filter(PluginGroupEntry::enabled).count(), 0);
+        let group = group.enable_only::<(PluginB,)>().add(PluginWithData(1));
+        assert!(group.enabled::<PluginB>());
+        assert!(group.enabled::<PluginWithData>());
+    }
+
+    #[test]
+    fn try_enable_only_nonexistent() {
+        let Err((group, error)) = feature_gated_group(false)
+            .disable::<PluginC>()
+            .try_enable_only::<(PluginA, PluginB)>()
+        else {
+            panic!("PluginB isn't in the group");
+        };
+        assert_eq!(
+            error,
+            PluginGroupError::NotInGroup {
+                plugin: "bevy_app::plugin_group::tests::PluginB",
+                plugins: vec![
+                    "bevy_app::plugin_group::tests::PluginA".to_string(),
+                    "bevy_app::plugin_group::tests::PluginC".to_string(),
+                ],
+            }
+        );
+        // The group is left unchanged.
+        assert!(group.enabled::<PluginA>());
+        assert!(!group.enabled::<PluginC>());
+    }
+
+    #[test]
+    fn add_named() {
+        let group = PluginGroupBuilder::start::<NoopPluginGroup>()
+            .add(PluginA)
+            .add(PluginB);
+
+        let Ok(group) = group.add_before_named("bevy_app::plugin_group::tests::PluginB", PluginC)
+        else {
+            panic!("PluginB wasn't in group");
+        };
+        assert_eq!(
+            group.order,
+            vec![
+                TypeId::of::<PluginA>(),
+                TypeId::of::<PluginC>(),
+                TypeId::of::<PluginB>(),
+            ]
+        );
+
+        // Moving a plugin keeps a single entry for it.
+        let Ok(group) = group.add_after_named("bevy_app::plugin_group::tests::PluginB", PluginA)
+        else {
+            panic!("PluginB wasn't in group");
+        };
+        assert_eq!(
+            group.order,
+            vec![
+                TypeId::of::<PluginC>(),
+                TypeId::of::<PluginB>(),
+                TypeId::of::<PluginA>(),
+            ]
+        );
+    }
+
+    #[test]
+    fn add_named_nonexistent() {
+        let group = PluginGroupBuilder::start::<NoopPluginGroup>().add(PluginA);
+
+        // Names must match exactly.
+        let Err((group, plugin)) = group.add_before_named("PluginA", PluginWithData(1)) else {
+            panic!("PluginA shouldn't match its full type name");
+        };
+        let Err((group, plugin)) = group.add_after_named("bevy_render::RenderPlugin", plugin)
+        else {
+            panic!("RenderPlugin isn't in group");
+        };
+
+        let group = group.add(plugin);
+        assert_eq!(
+            group.order,
+            vec![TypeId::of::<PluginA>(), TypeId::of::<PluginWithData>()]
+        );
+        assert_eq!(
+            get_plugin::<PluginWithData>(&group, TypeId::of::<PluginWithData>()),
+            &PluginWithData(1)
+        );
+
+        let mut app = App::new();
+        group.finish(&mut app);
+        assert_eq!(
+            app.iter_plugins().map(Plugin::name).collect::<Vec<_>>(),
+            [
+                "bevy_app::main_schedule::MainSchedulePlugin",
+                "bevy_app::plugin_group::tests::PluginA",
+                "bevy_app::plugin_group::tests::PluginWithData",
+            ]
+        );
+    }
+
+    #[test]
+    fn iter_reflects_final_order() {
+        let group = PluginGroupBuilder::start::<NoopPluginGroup>()
+            .add(PluginA)
+            .add(PluginB)
+            .add_before::<PluginA>(PluginC)
+            .add_after::<PluginB>(PluginWithData(0))
+            .set(PluginWithData(1))
+            .disable::<PluginB>()
+            // Re-adding moves the plugin to the end.
+            .add(PluginA);
+
+        let entries = group
+            .iter()
+            .map(|entry| (entry.index(), entry.name(), entry.enabled()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            entries,
+            [
+                (0, "bevy_app::plugin_group::tests::PluginC", true),
+                (1, "bevy_app::plugin_group::tests::PluginB", false),
+                (2, "bevy_app::plugin_group::tests::PluginWithData", true),
+                (3, "bevy_app::plugin_group::tests::PluginA", true),
+            ]
+        );
+        let entry = group.iter().nth(2).unwrap();
+        assert_eq!(
+            entry.plugin().as_any().downcast_ref::<PluginWithData>(),
+            Some(&PluginWithData(1))
+        );
+
+        assert_eq!(group.len(), 4);
+        assert!(!group.is_empty());
+        assert!(PluginGroupBuilder::start::<NoopPluginGroup>().is_empty());
+        assert!(group.contains_named("bevy_app::plugin_group::tests::PluginB"));
+        assert!(!group.contains_named("PluginB"));
+    }
+
+    #[test]
+    fn merge() {
+        let base = PluginGroupBuilder::start::<NoopPluginGroup>()
+            .add(PluginA)
+            .add(PluginWithData(0))
+            .add(PluginB)
+            .disable::<PluginB>();
+        let other = PluginGroupBuilder::start::<NoopPluginGroup>()
+            .add(PluginC)
+            .add(PluginWithData(1))
+            .add(PluginB);
+
+        let group = base.merge(other);
+        assert_eq!(
+            group.order,
+            vec![
+                TypeId::of::<PluginA>(),
+                TypeId::of::<PluginWithData>(),
+                TypeId::of::<PluginB>(),
+                TypeId::of::<PluginC>(),
+            ]
+        );
+        // The later group's instance wins, in the earlier group's position.
+        assert_eq!(
+            get_plugin::<PluginWithData>(&group, TypeId::of::<PluginWithData>()),
+            &PluginWithData(1)
+        );
+        // A plugin disabled in either group stays disabled.
+        assert!(!group.enabled::<PluginB>());
+
+        let group = PluginGroupBuilder::start::<NoopPluginGroup>()
+            .add(PluginA)
+            .merge(
+                PluginGroupBuilder::start::<NoopPluginGroup>()
+                    .add(PluginA)
+                    .add(PluginC)
+                    .disable::<PluginA>(),
+            );
+        assert!(!group.enabled::<PluginA>());
+        assert!(group.enabled::<PluginC>());
+    }
+
+    #[test]
+    fn merge_resolves_deferred_orders() {
+        // `PluginWithData` wants to run before `PluginC`, which is only in the other group.
+        let base = PluginGroupBuilder::start::<NoopPluginGroup>()
+            .add(PluginA)
+            .add_before_deferred::<PluginC, _>(PluginWithData(0));
+        assert_eq!(
+            base.order,
+            vec![TypeId::of::<PluginA>(), TypeId::of::<PluginWithData>()]
+        );
+        // `PluginB` wants to run after `PluginA`, which is only in the first group.
+        let other = PluginGroupBuilder::start::<NoopPluginGroup>()
+            .add_after_deferred::<PluginA, _>(PluginB)
+            .add(PluginC);
+
+        let group = base.merge(other);
+        assert_eq!(
+            group.order,
+            vec![
+                TypeId::of::<PluginA>(),
+                TypeId::of::<PluginB>(),
+                TypeId::of::<PluginWithData>(),
+                TypeId::of::<PluginC>(),
+            ]
+        );
+        assert!(group
+            .plugins
+            .values()
+            .all(|entry| entry.pending_order.is_none()));
+
+        // Deferred orders whose target is already in the group apply immediately, and the ones
+        // whose target never shows up leave the plugin where it was added.
+        let group = PluginGroupBuilder::start::<NoopPluginGroup>()
+            .add(PluginA)
+            .add_before_deferred::<PluginA, _>(PluginB)
+            .add_after_deferred::<PluginWithData, _>(PluginC)
+            .merge(PluginGroupBuilder::start::<NoopPluginGroup>());
+        assert_eq!(
+            group.order,
+            vec![
+                TypeId::of::<PluginB>(),
+                TypeId::of::<PluginA>(),
+                TypeId::of::<PluginC>(),
+            ]
+        );
+    }
+
+    #[test]
+    fn set_or_add() {
+        // Replaces the existing plugin in place.
+        let group = PluginGroupBuilder::start::<NoopPluginGroup>()
+            .add(PluginA)
+            .add(PluginWithData(0))
+            .add(PluginB)
+            .set_or_add(PluginWithData(1));
+        assert_eq!(
+            group.order,
+            vec![
+                TypeId::of::<PluginA>(),
+                TypeId::of::<PluginWithData>(),
+                TypeId::of::<PluginB>(),
+            ]
+        );
+        assert_eq!(
+            get_plugin::<PluginWithData>(&group, TypeId::of::<PluginWithData>()),
+            &PluginWithData(1)
+        );
+
+        // Keeps the plugin disabled.
+        let group = group
+            .disable::<PluginWithData>()
+            .set_or_add(PluginWithData(2));
+        assert!(!group.enabled::<PluginWithData>());
+        assert_eq!(
+            get_plugin::<PluginWithData>(&group, TypeId::of::<PluginWithData>()),
+            &PluginWithData(2)
+        );
+
+        // Adds the missing plugin at the end.
+        let group = group.set_or_add(PluginC);
+        assert_eq!(group.order.last(), Some(&TypeId::of::<PluginC>()));
+        assert!(group.enabled::<PluginC>());
+        assert_eq!(group.len(), 4);
+    }
+
+    #[test]
+    fn try_set() {
+        let group = PluginGroupBuilder::start::<NoopPluginGroup>().add(PluginWithData(0));
+        let Ok(group) = group.try_set(PluginWithData(1)) else {
+            panic!("PluginWithData is in the group");
+        };
+        assert_eq!(
+            get_plugin::<PluginWithData>(&group, TypeId::of::<PluginWithData>()),
+            &PluginWithData(1)
+        );
+
+        let Err((group, plugin)) = group.try_set(PluginA) else {
+            panic!("PluginA isn't in the group");
+        };
+        assert!(!group.contains::<PluginA>());
+        let group = group.add(plugin);
+        assert!(group.contains::<PluginA>());
+    }
+
+    #[test]
+    fn build_with() {
+        use alloc::{boxed::Box, string::String};
+        use bevy_ecs::resource::Resource;
+        use bevy_platform::sync::{Arc, Mutex};
+
+        #[derive(Resource, Default)]
+        struct Built(Vec<String>);
+
+        struct Instrumented(Box<dyn Plugin>);
+        impl Plugin for Instrumented {
+            fn build(&self, app: &mut App) {
+                app.world_mut()
+                    .get_resource_or_init::<Built>()
+                    .0
+                    .push(self.0.name().to_string());
+                self.0.build(app);
+            }
+
+            fn name(&self) -> &str {
+                self.0.name()
+            }
+        }
+
+        let data = Arc::new(Mutex::new(None));
+        let seen = data.clone();
+        let group = PluginGroupBuilder::start::<NoopPluginGroup>()
+            .add(PluginA)
+            .add(PluginWithData(0))
+            .add(PluginB)
+            .add(PluginC)
+            .build_with(move |plugin| {
+                if let Some(plugin) = plugin.as_any().downcast_ref::<PluginWithData>() {
+                    *seen.lock().unwrap() = Some(plugin.0);
+                }
+                Box::new(Instrumented(plugin))
+            })
+            .build_with(|plugin| plugin)
+            // The wrapper receives the final configuration.
+            .set(PluginWithData(7))
+            .disable::<PluginB>()
+            .add_before::<PluginA>(PluginC);
+
+        fn assert_send_sync<T: Send + Sync>(_: &T) {}
+        assert_send_sync(&group);
+
+        let mut app = App::new();
+        app.add_plugins(group);
+        assert_eq!(*data.lock().unwrap(), Some(7));
+        assert_eq!(
+            app.world().resource::<Built>().0,
+            [
+                core::any::type_name::<PluginC>(),
+                core::any::type_name::<PluginA>(),
+                core::any::type_name::<PluginWithData>(),
+            ]
+        );
+        assert!(app.is_plugin_added::<PluginWithData>());
+    }
+
+    struct InnerPlugins;
+    impl PluginGroup for InnerPlugins {
+        fn build(self) -> PluginGroupBuilder {
+            PluginGroupBuilder::start::<Self>()
+                .add(PluginA)
+                .add_after_deferred::<PluginC, _>(PluginB)
+        }
+    }
+
+    fn history<T: Plugin>(group: &PluginGroupBuilder) -> Vec<&str> {
+        group.plugins[&TypeId::of::<T>()]
+            .history
+            .iter()
+            .map(String::as_str)
+            .collect()
+    }
+
+    #[test]
+    fn describe() {
+        let group = PluginGroupBuilder::start::<NoopPluginGroup>()
+            .add(PluginA)
+            .add(PluginB)
+            .add_before::<PluginA>(PluginC)
+            .set(PluginB)
+            .disable::<PluginA>();
+
+        let a = core::any::type_name::<PluginA>();
+        let b = core::any::type_name::<PluginB>();
+        let c = core::any::type_name::<PluginC>();
+        let description = format!(
+            "bevy_app::plugin_group::NoopPluginGroup:
+  1. [enabled] {c} (add_before({a}))
+  2. [disabled] {a} (add, disable)
+  3. [enabled] {b} (add, set)
+"
+        );
+        assert_eq!(group.describe(), description);
+        assert_eq!(format!("{group:?}"), description);
+        assert_eq!(
+            group
+                .iter()
+                .map(|entry| entry.history().len())
+                .collect::<Vec<_>>(),
+            [1, 2, 2]
+        );
+    }
+
+    #[test]
+    fn describe_nested_and_merged() {
+        let inner = core::any::type_name::<InnerPlugins>();
+        let group = PluginGroupBuilder::start::<NoopPluginGroup>()
+            .add(PluginWithData(0))
+            .add_group(InnerPlugins)
+            // Re-adding a plugin keeps its history.
+            .add(PluginA);
+        assert_eq!(
+            history::<PluginA>(&group),
+            ["add", &format!("add_group({inner})"), "add"]
+        );
+        assert_eq!(
+            history::<PluginB>(&group),
+            [
+                "add",
+                &format!(
+                    "add_after_deferred({}, pending)",
+                    core::any::type_name::<PluginC>()
+                ),
+                &format!("add_group({inner})"),
+            ]
+        );
+
+        let other = PluginGroupBuilder::start::<InnerPlugins>()
+            .add(PluginC)
+            .add(PluginWithData(1));
+        let group = group.merge(other);
+        assert_eq!(
+            history::<PluginC>(&group),
+            ["add", &format!("merge({inner})")]
+        );
+        assert_eq!(
+            history::<PluginWithData>(&group),
+            ["add", &format!("replaced by merge({inner})")]
+        );
+        assert_eq!(
+            history::<PluginB>(&group)[3..],
+            [format!("moved after {}", core::any::type_name::<PluginC>()) + " (deferred)"]
+        );
+
+        let group = group.enable_only::<(PluginC,)>().disable_all();
+        assert_eq!(
+            history::<PluginC>(&group)[2..],
+            ["enable_only", "disable_all"]
+        );
+    }
+
+    /// Returns a fake environment with the given `BEVY_DISABLE_PLUGINS` and `BEVY_ONLY_PLUGINS`.
+    fn env(disabled: Option<String>, only: Option<String>) -> impl Fn(&str) -> Option<String> {
+        move |name| match name {
+            "BEVY_DISABLE_PLUGINS" => disabled.clone(),
+            "BEVY_ONLY_PLUGINS" => only.clone(),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn env_overrides() {
+        let a = core::any::type_name::<PluginA>();
+        let b = core::any::type_name::<PluginB>();
+        let group = || {
+            PluginGroupBuilder::start::<NoopPluginGroup>()
+                .add(PluginA)
+                .add(PluginB)
+                .add(PluginC)
+        };
+
+        let mut disabled = group();
+        disabled.override_enabled(env(Some(format!(" {a},unknown::Plugin,, {b}")), None));
+        assert_eq!(
+            history::<PluginA>(&disabled),
+            ["add", "disabled by BEVY_DISABLE_PLUGINS"]
+        );
+        let mut app = App::new();
+        app.add_plugins(disabled);
+        assert!(!app.is_plugin_added::<PluginA>());
+        assert!(!app.is_plugin_added::<PluginB>());
+        assert!(app.is_plugin_added::<PluginC>());
+
+        let mut only = group();
+        only.override_enabled(env(Some(b.to_string()), Some(format!("{a},{b}"))));
+        let mut app = App::new();
+        app.add_plugins(only);
+        assert!(app.is_plugin_added::<PluginA>());
+        assert!(!app.is_plugin_added::<PluginB>());
+        assert!(!app.is_plugin_added::<PluginC>());
+
+        let mut unchanged = group();
+        unchanged.override_enabled(env(None, None));
+        assert_eq!(
+            unchanged.iter().filter(PluginGroupEntry::enabled).count(),
+            3
+        );
+    }
 }
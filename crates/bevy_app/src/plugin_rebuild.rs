@@ -0,0 +1,457 @@
+use crate::{plugin::shared_plugin, App, PlaceholderPlugin, Plugin, PluginsState};
+use alloc::{boxed::Box, vec::Vec};
+use bevy_ecs::{
+    component::{ComponentId, Tick},
+    event::EventRegistry,
+    resource::Resource,
+    schedule::{InternedScheduleLabel, Schedules, SystemKey},
+    world::World,
+};
+use bevy_platform::collections::HashSet;
+use core::{any::type_name, ops::Range};
+use log::error;
+use thiserror::Error;
+
+/// What a plugin registered during its [`Plugin::build`].
+///
+/// Only recorded once [`App::enable_plugin_rebuilds`] has been called.
+pub(crate) struct PluginRecord {
+    /// Systems added to the schedules of the main world.
+    systems: Vec<(InternedScheduleLabel, SystemKey)>,
+    /// Resources inserted in the main world.
+    resources: Vec<ComponentId>,
+    /// The change tick of the main world once the plugin was built.
+    built_at: Tick,
+    /// The number of plugins added while building this one. They directly follow it in the
+    /// plugin registry.
+    nested: usize,
+}
+
+/// The systems and resources of a world, taken right before building a plugin.
+pub(crate) struct RegistrationSnapshot {
+    systems: HashSet<(InternedScheduleLabel, SystemKey)>,
+    resources: HashSet<ComponentId>,
+}
+
+impl RegistrationSnapshot {
+    pub(crate) fn take(world: &World) -> Self {
+        Self {
+            systems: systems(world).collect(),
+            resources: resources(world).collect(),
+        }
+    }
+
+    /// Records what was registered since the snapshot was taken, except what is already
+    /// recorded for the `nested` plugins.
+    pub(crate) fn record(self, world: &World, nested: &[Option<PluginRecord>]) -> PluginRecord {
+        let nested_systems = nested
+            .iter()
+            .flatten()
+            .flat_map(|record| &record.systems)
+            .collect::<HashSet<_>>();
+        let nested_resources = nested
+            .iter()
+            .flatten()
+            .flat_map(|record| &record.resources)
+            .collect::<HashSet<_>>();
+        PluginRecord {
+            systems: systems(world)
+                .filter(|system| !self.systems.contains(system) && !nested_systems.contains(system))
+                .collect(),
+            resources: resources(world)
+                .filter(|id| !self.resources.contains(id) && !nested_resources.contains(id))
+                .collect(),
+            built_at: world.read_change_tick(),
+            nested: nested.len(),
+        }
+    }
+}
+
+fn systems(world: &World) -> impl Iterator<Item = (InternedScheduleLabel, SystemKey)> + '_ {
+    world
+        .get_resource::<Schedules>()
+        .into_iter()
+        .flat_map(Schedules::iter)
+        .flat_map(|(_, schedule)| {
+            let label = schedule.label();
+            schedule.graph().systems.keys().map(move |key| (label, key))
+        })
+}
+
+fn resources(world: &World) -> impl Iterator<Item = ComponentId> + '_ {
+    world
+        .storages()
+        .resources
+        .iter()
+        .filter(|(_, data)| data.is_present())
+        .map(|(id, _)| id)
+}
+
+impl PluginRecord {
+    /// Removes what was recorded from `world`.
+    fn undo(&self, world: &mut World) {
+        if let Some(mut schedules) = world.get_resource_mut::<Schedules>() {
+            for &(label, key) in &self.systems {
+                if let Some(schedule) = schedules.get_mut(label) {
+                    schedule.remove_system(key);
+                }
+            }
+        }
+
+        let this_run = world.read_change_tick();
+        for &id in &self.resources {
+            if EventRegistry::deregister_events_by_id(world, id) {
+                continue;
+            }
+            let changed_since = world
+                .get_resource_change_ticks_by_id(id)
+                .is_some_and(|ticks| ticks.is_changed(self.built_at, this_run));
+            if !changed_since {
+                world.remove_resource_by_id(id);
+            }
+        }
+    }
+}
+
+/// An error that occurs when rebuilding a plugin with [`App::rebuild_plugin`].
+#[derive(Error, Debug)]
+pub enum PluginRebuildError {
+    /// The plugin was never added to the app.
+    #[error("plugin {0} was not added to the app")]
+    NotAdded(&'static str),
+    /// The plugin was added before [`App::enable_plugin_rebuilds`] was called, so what it
+    /// registered is unknown.
+    #[error("plugin {0} was added before `App::enable_plugin_rebuilds` was called")]
+    NotRecorded(&'static str),
+    /// One of the [required resources](Plugin::required_resources) of the new instance is missing.
+    #[error("plugin {plugin} requires the resource {resource}, which does not exist")]
+    MissingRequiredResource {
+        /// The plugin that was rebuilt.
+        plugin: &'static str,
+        /// The resource that does not exist.
+        resource: &'static str,
+    },
+}
+
+/// Plugins to rebuild at the end of the next [`App::update`].
+///
+/// This lets systems, for example one reacting to a key press, swap a plugin for a new instance
+/// with [`App::rebuild_plugin`]. Errors are logged. This resource is inserted by
+/// [`App::enable_plugin_rebuilds`].
+#[derive(Resource, Default)]
+pub struct PluginRebuilds {
+    #[expect(clippy::type_complexity, reason = "Only used in this module.")]
+    queue: Vec<Box<dyn FnOnce(&mut App) -> Result<(), PluginRebuildError> + Send + Sync>>,
+}
+
+impl PluginRebuilds {
+    /// Queues a rebuild of the plugin of type `P`, replacing it with `plugin`.
+    pub fn rebuild<P: Plugin>(&mut self, plugin: P) {
+        self.queue
+            .push(Box::new(move |app| app.rebuild_plugin(plugin).map(|_| ())));
+    }
+}
+
+impl App {
+    /// Starts recording what each plugin registers during its [`Plugin::build`], so it can later
+    /// be replaced with [`App::rebuild_plugin`].
+    ///
+    /// This is meant for development: it makes adding plugins slower. Call it before adding the
+    /// plugins you want to rebuild, since plugins that are already added are not recorded.
+    pub fn enable_plugin_rebuilds(&mut self) -> &mut Self {
+        let main = self.main_mut();
+        if main.plugin_records.is_none() {
+            main.plugin_records = Some(main.plugin_registry.iter().map(|_| None).collect());
+        }
+        self.init_resource::<PluginRebuilds>()
+    }
+
+    /// Replaces the plugin of type `P` with `plugin`, without restarting the app.
+    ///
+    /// Everything the old instance registered in the main world during its [`Plugin::build`] is
+    /// removed, along with the plugins it added, before the new instance is built in its place.
+    /// If the app already [finished](App::finish) or [cleaned up](App::cleanup) its plugins,
+    /// the new instance is finished and cleaned up as well.
+    ///
+    /// What can be removed is:
+    /// - systems added to the schedules of the main world,
+    /// - resources inserted in the main world that did not exist before, unless they changed
+    ///   since the plugin was built,
+    /// - events registered with [`App::add_event`].
+    ///
+    /// Everything else is kept, notably schedules, system sets, observers, entities, one-shot
+    /// systems, type registrations and anything added to sub-apps. Ordering constraints between
+    /// the removed systems and other systems are dropped.
+    ///
+    /// If several instances of `P` were added, the first one is rebuilt. Requires
+    /// [`App::enable_plugin_rebuilds`] to be called before `P` was added. Inside systems, use
+    /// [`PluginRebuilds`] instead.
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// #[derive(Resource)]
+    /// struct Gravity(f32);
+    ///
+    /// struct PhysicsPlugin {
+    ///     gravity: f32,
+    /// }
+    ///
+    /// impl Plugin for PhysicsPlugin {
+    ///     fn build(&self, app: &mut App) {
+    ///         app.insert_resource(Gravity(self.gravity));
+    ///     }
+    /// }
+    ///
+    /// let mut app = App::new();
+    /// app.enable_plugin_rebuilds()
+    ///     .add_plugins(PhysicsPlugin { gravity: 9.8 });
+    /// app.update();
+    ///
+    /// app.rebuild_plugin(PhysicsPlugin { gravity: 1.6 }).unwrap();
+    /// assert_eq!(app.world().resource::<Gravity>().0, 1.6);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if called while a plugin is building.
+    pub fn rebuild_plugin<P: Plugin>(
+        &mut self,
+        plugin: P,
+    ) -> Result<&mut Self, PluginRebuildError> {
+        if self.is_building_plugins() {
+            panic!("App::rebuild_plugin() was called while a plugin was building.");
+        }
+
+        let name = type_name::<P>();
+        let index = self
+            .main()
+            .plugin_registry
+            .iter()
+            .position(|plugin| plugin.downcast_ref::<P>().is_some())
+            .ok_or(PluginRebuildError::NotAdded(name))?;
+        let nested = self
+            .main()
+            .plugin_records
+            .as_ref()
+            .and_then(|records| records[index].as_ref())
+            .ok_or(PluginRebuildError::NotRecorded(name))?
+            .nested;
+        if let Some(resource) = plugin
+            .required_resources()
+            .into_iter()
+            .find(|resource| !resource.exists(self.world()))
+        {
+            return Err(PluginRebuildError::MissingRequiredResource {
+                plugin: name,
+                resource: resource.name(),
+            });
+        }
+
+        // Take the old plugin and the ones it added out of the registry, so the new ones are
+        // built at the same position.
+        let main = self.main_mut();
+        let end = index + 1 + nested;
+        let registry_tail = main.plugin_registry.split_off(end);
+        let old_plugins = main.plugin_registry.split_off(index);
+        let nested_tail = main.nested_plugins.split_off(end);
+        let nested = main.nested_plugins[index];
+        main.nested_plugins.truncate(index);
+        let records = main.plugin_records.as_mut().unwrap();
+        let records_tail = records.split_off(end);
+        let old_records = records.split_off(index);
+
+        for record in old_records.iter().rev().flatten() {
+            record.undo(main.world_mut());
+        }
+        for old_plugin in &old_plugins {
+            main.plugin_names.remove(old_plugin.name());
+        }
+
+        // Nested plugins can only be added while the plugins are being added.
+        let state = main.plugins_state;
+        main.plugins_state = PluginsState::Adding;
+        if let Err(error) = self.add_boxed_plugin(Box::new(plugin)) {
+            panic!("Error rebuilding plugin {name}: {error}");
+        }
+
+        let main = self.main_mut();
+        let new_end = main.plugin_registry.len();
+        main.plugin_registry.extend(registry_tail);
+        main.nested_plugins[index] = nested;
+        main.nested_plugins.extend(nested_tail);
+        let records = main.plugin_records.as_mut().unwrap();
+        records.extend(records_tail);
+        // Plugins that added the old plugin now contain the new ones instead.
+        for (i, record) in records[..index].iter_mut().enumerate() {
+            if let Some(record) = record
+                && i + record.nested >= index
+            {
+                record.nested = record.nested + new_end - end;
+            }
+        }
+
+        if state >= PluginsState::Finished {
+            self.run_plugins(index..new_end, Plugin::finish);
+        }
+        if state >= PluginsState::Cleaned {
+            self.run_plugins(index..new_end, Plugin::cleanup);
+        }
+        self.main_mut().plugins_state = state;
+        Ok(self)
+    }
+
+    /// Runs `f` on the plugins of the main registry in `range`.
+    fn run_plugins(&mut self, range: Range<usize>, f: fn(&dyn Plugin, &mut App)) {
+        for i in range {
+            let plugin = core::mem::replace(
+                &mut self.main_mut().plugin_registry[i],
+                shared_plugin(PlaceholderPlugin),
+            );
+            f(&*plugin, self);
+            self.main_mut().plugin_registry[i] = plugin;
+        }
+    }
+
+    /// Applies the rebuilds queued in [`PluginRebuilds`].
+    pub(crate) fn apply_plugin_rebuilds(&mut self) {
+        if self.main().plugin_records.is_none() {
+            return;
+        }
+        let Some(mut rebuilds) = self.world_mut().get_resource_mut::<PluginRebuilds>() else {
+            return;
+        };
+        for rebuild in core::mem::take(&mut rebuilds.queue) {
+            if let Err(error) = rebuild(self) {
+                error!("Failed to rebuild plugin: {error}");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Update;
+    use bevy_ecs::{
+        event::{BufferedEvent, Events},
+        prelude::*,
+    };
+
+    #[derive(Resource, Default)]
+    struct Total(u32);
+
+    #[derive(Resource)]
+    struct Step(u32);
+
+    #[derive(Resource, Default)]
+    struct Finished(u32);
+
+    #[derive(Resource, Default)]
+    struct Saved(u32);
+
+    #[derive(Event, BufferedEvent)]
+    struct Tick;
+
+    struct CounterPlugin {
+        step: u32,
+    }
+
+    impl Plugin for CounterPlugin {
+        fn build(&self, app: &mut App) {
+            app.insert_resource(Step(self.step))
+                .init_resource::<Saved>()
+                .add_event::<Tick>()
+                .add_plugins(NestedPlugin)
+                .add_systems(Update, |step: Res<Step>, mut total: ResMut<Total>| {
+                    total.0 += step.0;
+                });
+        }
+
+        fn finish(&self, app: &mut App) {
+            app.world_mut().resource_mut::<Finished>().0 += 1;
+        }
+    }
+
+    struct NestedPlugin;
+
+    impl Plugin for NestedPlugin {
+        fn build(&self, app: &mut App) {
+            app.add_systems(Update, |mut total: ResMut<Total>| total.0 += 1000);
+        }
+    }
+
+    fn app() -> App {
+        let mut app = App::new();
+        app.init_resource::<Total>()
+            .init_resource::<Finished>()
+            .enable_plugin_rebuilds()
+            .add_plugins(CounterPlugin { step: 1 });
+        app
+    }
+
+    #[test]
+    fn rebuild_replaces_registrations() {
+        let mut app = app();
+        app.update();
+        assert_eq!(app.world().resource::<Total>().0, 1001);
+
+        app.rebuild_plugin(CounterPlugin { step: 10 }).unwrap();
+        app.update();
+        assert_eq!(app.world().resource::<Total>().0, 2011);
+        assert_eq!(app.main().plugin_registry.len(), app_plugin_count() + 2);
+        assert!(app.is_plugin_added::<NestedPlugin>());
+
+        // The event is registered once, so it is kept for two updates.
+        app.world_mut().resource_mut::<Events<Tick>>().write(Tick);
+        app.update();
+        assert_eq!(app.world().resource::<Events<Tick>>().len(), 1);
+        app.update();
+        assert!(app.world().resource::<Events<Tick>>().is_empty());
+    }
+
+    fn app_plugin_count() -> usize {
+        App::new().main().plugin_registry.len()
+    }
+
+    #[test]
+    fn changed_resources_are_kept() {
+        let mut app = app();
+        app.update();
+        app.world_mut().resource_mut::<Saved>().0 = 5;
+        app.rebuild_plugin(CounterPlugin { step: 10 }).unwrap();
+        // `init_resource` doesn't replace the resource that was kept.
+        assert_eq!(app.world().resource::<Saved>().0, 5);
+        assert_eq!(app.world().resource::<Step>().0, 10);
+    }
+
+    #[test]
+    fn queued_rebuilds_finish_the_new_plugin() {
+        let mut app = app();
+        app.finish();
+        app.cleanup();
+        app.add_systems(Update, |mut rebuilds: ResMut<PluginRebuilds>| {
+            rebuilds.rebuild(CounterPlugin { step: 10 });
+        });
+        app.update();
+        assert_eq!(app.world().resource::<Finished>().0, 2);
+        assert_eq!(app.world().resource::<Step>().0, 10);
+        assert_eq!(app.main().plugins_state, PluginsState::Cleaned);
+    }
+
+    #[test]
+    fn rebuild_requires_recording() {
+        let mut app = App::new();
+        app.add_plugins(NestedPlugin);
+        assert!(matches!(
+            app.rebuild_plugin(NestedPlugin),
+            Err(PluginRebuildError::NotRecorded(_))
+        ));
+
+        app.enable_plugin_rebuilds();
+        assert!(matches!(
+            app.rebuild_plugin(CounterPlugin { step: 1 }),
+            Err(PluginRebuildError::NotAdded(_))
+        ));
+    }
+}
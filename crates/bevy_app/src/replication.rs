@@ -0,0 +1,792 @@
+use crate::{App, PostUpdate};
+use alloc::{
+    boxed::Box,
+    collections::VecDeque,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use bevy_ecs::{
+    change_detection::DetectChanges,
+    prelude::*,
+    reflect::{AppTypeRegistry, ReflectResource},
+};
+use bevy_platform::collections::HashMap;
+use bevy_reflect::{
+    GetTypeRegistration, PartialReflect, Reflect, ReflectPath, ReflectRef, TypePath,
+};
+use thiserror::Error;
+
+/// An opaque handle identifying a remote peer that receives replicated resources.
+///
+/// The handle is chosen by the transport layer; replication only uses it to keep track of which
+/// changes each peer has acknowledged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ReplicationPeer(pub u64);
+
+/// A single changed field of a replicated resource.
+#[derive(Debug)]
+pub struct FieldChange {
+    /// The [reflection path](bevy_reflect::GetPath) of the changed field, relative to the resource.
+    ///
+    /// An empty path means the whole resource value is replaced.
+    pub path: String,
+    /// The new value of the field.
+    ///
+    /// Transports can serialize this using the type registry, for example with
+    /// `bevy_reflect::serde::ReflectSerializer`.
+    pub value: Box<dyn PartialReflect>,
+}
+
+/// The field-level changes of one replicated resource, produced for a single peer.
+#[derive(Debug)]
+pub struct ResourceDiff {
+    /// The [type path](TypePath::type_path) of the resource the changes belong to.
+    pub type_path: String,
+    /// A monotonically increasing sequence number, used by the receiver to
+    /// [acknowledge](ResourceReplication::ack) the diff.
+    pub sequence: u64,
+    /// The changed fields, in field declaration order.
+    pub changes: Vec<FieldChange>,
+}
+
+/// A [`ResourceDiff`] addressed to a specific [`ReplicationPeer`].
+#[derive(Debug)]
+pub struct ReplicationMessage {
+    /// The peer that should receive the diff.
+    pub peer: ReplicationPeer,
+    /// The changes to send.
+    pub diff: ResourceDiff,
+}
+
+/// The queue of [`ReplicationMessage`]s produced by the [`ReplicationSystems`] each frame.
+///
+/// The transport layer is expected to [`drain`](Self::drain) this resource after
+/// [`PostUpdate`] and send the messages to their peers.
+#[derive(Resource, Debug, Default)]
+pub struct ReplicationOutbox {
+    messages: Vec<ReplicationMessage>,
+}
+
+impl ReplicationOutbox {
+    /// Removes and returns all queued messages.
+    pub fn drain(&mut self) -> impl Iterator<Item = ReplicationMessage> + '_ {
+        self.messages.drain(..)
+    }
+
+    /// Returns the queued messages without removing them.
+    pub fn messages(&self) -> &[ReplicationMessage] {
+        &self.messages
+    }
+
+    /// Returns the number of queued messages.
+    pub fn len(&self) -> usize {
+        self.messages.len()
+    }
+
+    /// Returns `true` if there are no queued messages.
+    pub fn is_empty(&self) -> bool {
+        self.messages.is_empty()
+    }
+}
+
+/// Per-peer bookkeeping of a single replicated resource.
+#[derive(Default)]
+struct PeerSnapshots {
+    /// The value the peer is known to have, if it acknowledged anything yet.
+    acked: Option<Box<dyn PartialReflect>>,
+    /// Snapshots that were sent but not acknowledged yet, oldest first.
+    in_flight: VecDeque<(u64, Box<dyn PartialReflect>)>,
+}
+
+/// Tracks the connected [`ReplicationPeer`]s and what each of them has acknowledged.
+///
+/// Resources are registered for replication with [`App::replicate_resource`]. Every frame in
+/// which a replicated resource changed, a [`ResourceDiff`] is queued in the [`ReplicationOutbox`]
+/// for each peer. It contains every field that differs from the last snapshot the peer
+/// acknowledged or from any snapshot sent since, since the peer may have applied those already.
+/// So a lost message is corrected by the next one, and a field set back to an earlier value is
+/// sent again.
+#[derive(Resource)]
+pub struct ResourceReplication {
+    peers: Vec<ReplicationPeer>,
+    resources: HashMap<&'static str, HashMap<ReplicationPeer, PeerSnapshots>>,
+    next_sequence: u64,
+    max_in_flight: usize,
+}
+
+impl Default for ResourceReplication {
+    fn default() -> Self {
+        Self {
+            peers: Vec::new(),
+            resources: HashMap::default(),
+            next_sequence: 0,
+            max_in_flight: 64,
+        }
+    }
+}
+
+impl ResourceReplication {
+    /// Starts replicating to `peer`. The next replication pass sends it the full value of
+    /// every replicated resource.
+    pub fn add_peer(&mut self, peer: ReplicationPeer) {
+        if !self.peers.contains(&peer) {
+            self.peers.push(peer);
+        }
+    }
+
+    /// Stops replicating to `peer` and forgets everything it acknowledged.
+    pub fn remove_peer(&mut self, peer: ReplicationPeer) {
+        self.peers.retain(|p| *p != peer);
+        for snapshots in self.resources.values_mut() {
+            snapshots.remove(&peer);
+        }
+    }
+
+    /// Returns the peers replicated to, in the order they were added.
+    pub fn peers(&self) -> &[ReplicationPeer] {
+        &self.peers
+    }
+
+    /// Records that `peer` received every diff with a sequence number up to and including
+    /// `sequence`. Later diffs for that peer no longer contain fields that only differ from the
+    /// snapshots sent before.
+    pub fn ack(&mut self, peer: ReplicationPeer, sequence: u64) {
+        for snapshots in self.resources.values_mut() {
+            let Some(snapshots) = snapshots.get_mut(&peer) else {
+                continue;
+            };
+            while snapshots
+                .in_flight
+                .front()
+                .is_some_and(|(s, _)| *s <= sequence)
+            {
+                let (_, snapshot) = snapshots.in_flight.pop_front().unwrap();
+                snapshots.acked = Some(snapshot);
+            }
+        }
+    }
+
+    /// Returns the number of diffs sent to `peer` that were not acknowledged yet, across all
+    /// replicated resources.
+    pub fn in_flight(&self, peer: ReplicationPeer) -> usize {
+        self.resources
+            .values()
+            .filter_map(|snapshots| snapshots.get(&peer))
+            .map(|snapshots| snapshots.in_flight.len())
+            .sum()
+    }
+
+    /// Sets how many unacknowledged snapshots are kept per peer and resource. Once the window is
+    /// full, the oldest snapshot is forgotten and acknowledging it becomes a no-op. Since the
+    /// peer may have applied it, the next diffs then contain the full value until the peer
+    /// acknowledges one of them.
+    ///
+    /// Defaults to 64.
+    pub fn set_max_in_flight(&mut self, max_in_flight: usize) {
+        self.max_in_flight = max_in_flight.max(1);
+    }
+
+    fn replicate(
+        &mut self,
+        type_path: &'static str,
+        value: &dyn PartialReflect,
+        changed: bool,
+        outbox: &mut ReplicationOutbox,
+    ) {
+        let resource = self.resources.entry(type_path).or_default();
+        for &peer in &self.peers {
+            let is_new = !resource.contains_key(&peer);
+            if !changed && !is_new {
+                continue;
+            }
+            let snapshots = resource.entry(peer).or_default();
+
+            let mut changes = Vec::new();
+            match &snapshots.acked {
+                Some(acked) => {
+                    // The peer has the acknowledged value, updated by any diff still in flight.
+                    let baselines: Vec<_> = core::iter::once(acked.as_ref())
+                        .chain(snapshots.in_flight.iter().map(|(_, sent)| sent.as_ref()))
+                        .collect();
+                    diff_fields(value, &baselines, &mut String::new(), &mut changes);
+                }
+                None => changes.push(FieldChange {
+                    path: String::new(),
+                    value: value.to_dynamic(),
+                }),
+            }
+            if changes.is_empty() {
+                continue;
+            }
+
+            let sequence = self.next_sequence;
+            self.next_sequence += 1;
+            if snapshots.in_flight.len() >= self.max_in_flight {
+                // The forgotten snapshot may have been applied, so the baseline is unknown.
+                snapshots.in_flight.pop_front();
+                snapshots.acked = None;
+            }
+            snapshots
+                .in_flight
+                .push_back((sequence, value.to_dynamic()));
+
+            outbox.messages.push(ReplicationMessage {
+                peer,
+                diff: ResourceDiff {
+                    type_path: type_path.to_string(),
+                    sequence,
+                    changes,
+                },
+            });
+        }
+    }
+}
+
+/// Recursively compares `current` with each of the `previous` values, pushing a [`FieldChange`]
+/// for every leaf that differs from at least one of them. Structs and tuples are descended into;
+/// any other kind of value is compared as a whole.
+fn diff_fields(
+    current: &dyn PartialReflect,
+    previous: &[&dyn PartialReflect],
+    path: &mut String,
+    changes: &mut Vec<FieldChange>,
+) {
+    let len = path.len();
+    match current.reflect_ref() {
+        ReflectRef::Struct(current) => {
+            let previous: Option<Vec<_>> = previous
+                .iter()
+                .map(|previous| match previous.reflect_ref() {
+                    ReflectRef::Struct(previous) => Some(previous),
+                    _ => None,
+                })
+                .collect();
+            if let Some(previous) = previous {
+                for index in 0..current.field_len() {
+                    let name = current.name_at(index).unwrap();
+                    path.push('.');
+                    path.push_str(name);
+                    diff_field(
+                        current.field_at(index).unwrap(),
+                        previous.iter().map(|previous| previous.field(name)),
+                        path,
+                        changes,
+                    );
+                    path.truncate(len);
+                }
+                return;
+            }
+        }
+        ReflectRef::TupleStruct(current) => {
+            let previous: Option<Vec<_>> = previous
+                .iter()
+                .map(|previous| match previous.reflect_ref() {
+                    ReflectRef::TupleStruct(previous) => Some(previous),
+                    _ => None,
+                })
+                .collect();
+            if let Some(previous) = previous {
+                for index in 0..current.field_len() {
+                    path.push_str(&format!(".{index}"));
+                    diff_field(
+                        current.field(index).unwrap(),
+                        previous.iter().map(|previous| previous.field(index)),
+                        path,
+                        changes,
+                    );
+                    path.truncate(len);
+                }
+                return;
+            }
+        }
+        ReflectRef::Tuple(current) => {
+            let previous: Option<Vec<_>> = previous
+                .iter()
+                .map(|previous| match previous.reflect_ref() {
+                    ReflectRef::Tuple(previous) => Some(previous),
+                    _ => None,
+                })
+                .collect();
+            if let Some(previous) = previous {
+                for index in 0..current.field_len() {
+                    path.push_str(&format!(".{index}"));
+                    diff_field(
+                        current.field(index).unwrap(),
+                        previous.iter().map(|previous| previous.field(index)),
+                        path,
+                        changes,
+                    );
+                    path.truncate(len);
+                }
+                return;
+            }
+        }
+        _ => {}
+    }
+    if previous
+        .iter()
+        .any(|previous| current.reflect_partial_eq(*previous) != Some(true))
+    {
+        changes.push(FieldChange {
+            path: path.clone(),
+            value: current.to_dynamic(),
+        });
+    }
+}
+
+/// Compares the field at `path` with the same field of the previous values, sending it whole if
+/// one of them doesn't have it.
+fn diff_field<'a>(
+    current: &dyn PartialReflect,
+    previous: impl Iterator<Item = Option<&'a dyn PartialReflect>>,
+    path: &mut String,
+    changes: &mut Vec<FieldChange>,
+) {
+    match previous.collect::<Option<Vec<_>>>() {
+        Some(previous) => diff_fields(current, &previous, path, changes),
+        None => changes.push(FieldChange {
+            path: path.clone(),
+            value: current.to_dynamic(),
+        }),
+    }
+}
+
+/// The system set in [`PostUpdate`] that queues [`ResourceDiff`]s into the [`ReplicationOutbox`].
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ReplicationSystems;
+
+fn replicate_resource<R: Resource + Reflect + TypePath>(
+    resource: Res<R>,
+    mut replication: ResMut<ResourceReplication>,
+    mut outbox: ResMut<ReplicationOutbox>,
+) {
+    let changed = resource.is_changed();
+    replication.replicate(
+        R::type_path(),
+        resource.as_partial_reflect(),
+        changed,
+        &mut outbox,
+    );
+}
+
+/// An error returned by [`apply_resource_diff`].
+#[derive(Debug, Error)]
+pub enum ReplicationError {
+    /// The resource type is not registered in the [`AppTypeRegistry`].
+    #[error("the resource type `{0}` is not registered")]
+    UnregisteredType(String),
+    /// The resource type is registered, but without [`ReflectResource`] type data.
+    #[error(
+        "the type `{0}` does not have `ReflectResource` type data, add `#[reflect(Resource)]`"
+    )]
+    MissingReflectResource(String),
+    /// The resource does not exist in the receiving world.
+    #[error("the resource `{0}` does not exist in the world")]
+    MissingResource(String),
+    /// A field path of the diff could not be resolved on the resource.
+    #[error("invalid field path `{path}` for resource `{type_path}`: {reason}")]
+    InvalidPath {
+        /// The type path of the resource.
+        type_path: String,
+        /// The path that failed to resolve.
+        path: String,
+        /// Why the path failed to resolve.
+        reason: String,
+    },
+    /// A new value could not be applied to the field it targets.
+    #[error("could not apply value at `{path}` for resource `{type_path}`: {reason}")]
+    Apply {
+        /// The type path of the resource.
+        type_path: String,
+        /// The path of the field the value was applied to.
+        path: String,
+        /// Why the value could not be applied.
+        reason: String,
+    },
+}
+
+/// Applies a [`ResourceDiff`] produced by a remote [`ReplicationOutbox`] to the matching resource
+/// in `world`.
+///
+/// The resource type must be registered in the world's [`AppTypeRegistry`] with
+/// [`ReflectResource`] type data, and the resource must already exist.
+pub fn apply_resource_diff(world: &mut World, diff: &ResourceDiff) -> Result<(), ReplicationError> {
+    let reflect_resource = {
+        let registry = world.resource::<AppTypeRegistry>().read();
+        let registration = registry
+            .get_with_type_path(&diff.type_path)
+            .ok_or_else(|| ReplicationError::UnregisteredType(diff.type_path.clone()))?;
+        registration
+            .data::<ReflectResource>()
+            .cloned()
+            .ok_or_else(|| ReplicationError::MissingReflectResource(diff.type_path.clone()))?
+    };
+
+    let mut resource = reflect_resource
+        .reflect_mut(world)
+        .map_err(|_| ReplicationError::MissingResource(diff.type_path.clone()))?;
+    let root = resource.as_partial_reflect_mut();
+
+    for change in &diff.changes {
+        let field = if change.path.is_empty() {
+            &mut *root
+        } else {
+            change
+                .path
+                .as_str()
+                .reflect_element_mut(&mut *root)
+                .map_err(|err| ReplicationError::InvalidPath {
+                    type_path: diff.type_path.clone(),
+                    path: change.path.clone(),
+                    reason: err.to_string(),
+                })?
+        };
+        field
+            .try_apply(change.value.as_ref())
+            .map_err(|err| ReplicationError::Apply {
+                type_path: diff.type_path.clone(),
+                path: change.path.clone(),
+                reason: err.to_string(),
+            })?;
+    }
+
+    Ok(())
+}
+
+impl App {
+    /// Replicates the resource `R` to every [`ReplicationPeer`] added to [`ResourceReplication`].
+    ///
+    /// Each frame in which `R` changed, a [`ResourceDiff`] containing only the changed fields is
+    /// queued in the [`ReplicationOutbox`] for every peer during [`PostUpdate`]. The receiving
+    /// side applies it with [`apply_resource_diff`].
+    ///
+    /// `R` is registered in the [`AppTypeRegistry`]; it should reflect `Resource` so the
+    /// receiver can look it up.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_app::{ReplicationOutbox, ReplicationPeer, ResourceReplication};
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_reflect::Reflect;
+    /// #[derive(Resource, Reflect, Default)]
+    /// #[reflect(Resource)]
+    /// struct Score {
+    ///     red: u32,
+    ///     blue: u32,
+    /// }
+    ///
+    /// let mut app = App::new();
+    /// app.init_resource::<Score>().replicate_resource::<Score>();
+    /// app.world_mut()
+    ///     .resource_mut::<ResourceReplication>()
+    ///     .add_peer(ReplicationPeer(1));
+    /// app.update();
+    ///
+    /// for message in app.world_mut().resource_mut::<ReplicationOutbox>().drain() {
+    ///     // Send `message.diff` to `message.peer`.
+    /// }
+    /// ```
+    pub fn replicate_resource<R: Resource + Reflect + TypePath + GetTypeRegistration>(
+        &mut self,
+    ) -> &mut Self {
+        self.register_type::<R>()
+            .init_resource::<ResourceReplication>()
+            .init_resource::<ReplicationOutbox>()
+            .add_systems(
+                PostUpdate,
+                replicate_resource::<R>.in_set(ReplicationSystems),
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[derive(Resource, Reflect, Default, Debug, PartialEq, Clone)]
+    #[reflect(Resource)]
+    struct Settings {
+        volume: f32,
+        name: String,
+        window: Window,
+    }
+
+    #[derive(Reflect, Default, Debug, PartialEq, Clone)]
+    struct Window {
+        width: u32,
+        height: u32,
+    }
+
+    const PEER_A: ReplicationPeer = ReplicationPeer(1);
+    const PEER_B: ReplicationPeer = ReplicationPeer(2);
+
+    fn app_with_peers(peers: &[ReplicationPeer]) -> App {
+        let mut app = App::new();
+        app.init_resource::<Settings>()
+            .replicate_resource::<Settings>();
+        let mut replication = app.world_mut().resource_mut::<ResourceReplication>();
+        for &peer in peers {
+            replication.add_peer(peer);
+        }
+        app
+    }
+
+    fn drain(app: &mut App) -> Vec<ReplicationMessage> {
+        app.world_mut()
+            .resource_mut::<ReplicationOutbox>()
+            .drain()
+            .collect()
+    }
+
+    fn paths(message: &ReplicationMessage) -> Vec<&str> {
+        message
+            .diff
+            .changes
+            .iter()
+            .map(|change| change.path.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn only_changed_fields_are_sent() {
+        let mut app = app_with_peers(&[PEER_A]);
+        app.update();
+
+        let messages = drain(&mut app);
+        assert_eq!(messages.len(), 1);
+        assert_eq!(paths(&messages[0]), vec![""]);
+        let sequence = messages[0].diff.sequence;
+        app.world_mut()
+            .resource_mut::<ResourceReplication>()
+            .ack(PEER_A, sequence);
+
+        // Nothing changed, nothing is sent.
+        app.update();
+        assert!(drain(&mut app).is_empty());
+
+        app.world_mut().resource_mut::<Settings>().volume = 0.5;
+        app.update();
+        let messages = drain(&mut app);
+        assert_eq!(messages.len(), 1);
+        assert_eq!(paths(&messages[0]), vec![".volume"]);
+        assert_eq!(
+            messages[0].diff.changes[0].value.try_downcast_ref::<f32>(),
+            Some(&0.5)
+        );
+    }
+
+    #[test]
+    fn nested_struct_paths() {
+        let mut app = app_with_peers(&[PEER_A]);
+        app.update();
+        let sequence = drain(&mut app)[0].diff.sequence;
+        app.world_mut()
+            .resource_mut::<ResourceReplication>()
+            .ack(PEER_A, sequence);
+
+        app.world_mut().resource_mut::<Settings>().window.height = 720;
+        app.update();
+        let messages = drain(&mut app);
+        assert_eq!(paths(&messages[0]), vec![".window.height"]);
+    }
+
+    #[test]
+    fn per_peer_ack_windows() {
+        let mut app = app_with_peers(&[PEER_A, PEER_B]);
+        app.update();
+        let messages = drain(&mut app);
+        assert_eq!(messages.len(), 2);
+
+        // Only peer A acknowledges the initial state.
+        let sequence = messages
+            .iter()
+            .find(|message| message.peer == PEER_A)
+            .unwrap()
+            .diff
+            .sequence;
+        app.world_mut()
+            .resource_mut::<ResourceReplication>()
+            .ack(PEER_A, sequence);
+
+        app.world_mut().resource_mut::<Settings>().name = "bevy".into();
+        app.update();
+        let messages = drain(&mut app);
+        let for_a = messages.iter().find(|m| m.peer == PEER_A).unwrap();
+        let for_b = messages.iter().find(|m| m.peer == PEER_B).unwrap();
+        assert_eq!(paths(for_a), vec![".name"]);
+        // Peer B never acknowledged anything, so it still gets the full value.
+        assert_eq!(paths(for_b), vec![""]);
+
+        let replication = app.world().resource::<ResourceReplication>();
+        assert_eq!(replication.in_flight(PEER_A), 1);
+        assert_eq!(replication.in_flight(PEER_B), 2);
+    }
+
+    #[test]
+    fn ack_window_is_bounded() {
+        let mut app = app_with_peers(&[PEER_A]);
+        app.world_mut()
+            .resource_mut::<ResourceReplication>()
+            .set_max_in_flight(2);
+        for volume in 0..5 {
+            app.world_mut().resource_mut::<Settings>().volume = volume as f32;
+            app.update();
+        }
+        assert_eq!(
+            app.world()
+                .resource::<ResourceReplication>()
+                .in_flight(PEER_A),
+            2
+        );
+    }
+
+    #[test]
+    fn reverted_fields_are_sent_again() {
+        let mut app = app_with_peers(&[PEER_A]);
+        app.update();
+        let sequence = drain(&mut app)[0].diff.sequence;
+        app.world_mut()
+            .resource_mut::<ResourceReplication>()
+            .ack(PEER_A, sequence);
+
+        // The peer may apply this diff even if it isn't acknowledged yet.
+        app.world_mut().resource_mut::<Settings>().volume = 0.5;
+        app.update();
+        assert_eq!(paths(&drain(&mut app)[0]), vec![".volume"]);
+
+        app.world_mut().resource_mut::<Settings>().volume = 0.0;
+        app.update();
+        let messages = drain(&mut app);
+        assert_eq!(messages.len(), 1);
+        assert_eq!(paths(&messages[0]), vec![".volume"]);
+        assert_eq!(
+            messages[0].diff.changes[0].value.try_downcast_ref::<f32>(),
+            Some(&0.0)
+        );
+    }
+
+    #[test]
+    fn unacknowledged_diffs_are_applied_correctly() {
+        let mut app = app_with_peers(&[PEER_A]);
+        let mut receiver = App::new();
+        receiver
+            .register_type::<Settings>()
+            .init_resource::<Settings>();
+        app.update();
+        let message = drain(&mut app).remove(0);
+        apply_resource_diff(receiver.world_mut(), &message.diff).unwrap();
+        app.world_mut()
+            .resource_mut::<ResourceReplication>()
+            .ack(PEER_A, message.diff.sequence);
+
+        // Neither diff is acknowledged, but both are applied in order.
+        app.world_mut().resource_mut::<Settings>().window.width = 1;
+        app.update();
+        let mut settings = app.world_mut().resource_mut::<Settings>();
+        settings.window.width = 0;
+        settings.window.height = 1;
+        app.update();
+        for message in drain(&mut app) {
+            apply_resource_diff(receiver.world_mut(), &message.diff).unwrap();
+        }
+        assert_eq!(
+            app.world().resource::<Settings>(),
+            receiver.world().resource::<Settings>()
+        );
+    }
+
+    #[test]
+    fn forgetting_a_snapshot_sends_the_full_value() {
+        let mut app = app_with_peers(&[PEER_A]);
+        app.world_mut()
+            .resource_mut::<ResourceReplication>()
+            .set_max_in_flight(1);
+        app.update();
+        let sequence = drain(&mut app)[0].diff.sequence;
+        app.world_mut()
+            .resource_mut::<ResourceReplication>()
+            .ack(PEER_A, sequence);
+
+        app.world_mut().resource_mut::<Settings>().volume = 0.5;
+        app.update();
+        assert_eq!(paths(&drain(&mut app)[0]), vec![".volume"]);
+        app.world_mut().resource_mut::<Settings>().volume = 1.0;
+        app.update();
+        assert_eq!(paths(&drain(&mut app)[0]), vec![".volume"]);
+
+        // The first `.volume` diff was forgotten, so the baseline is unknown.
+        app.world_mut().resource_mut::<Settings>().name = "bevy".into();
+        app.update();
+        assert_eq!(paths(&drain(&mut app)[0]), vec![""]);
+    }
+
+    #[test]
+    fn apply_reconstructs_value() {
+        let mut app = app_with_peers(&[PEER_A]);
+
+        let mut receiver = App::new();
+        receiver
+            .register_type::<Settings>()
+            .init_resource::<Settings>();
+
+        let send = |app: &mut App, receiver: &mut App| {
+            app.update();
+            for message in drain(app) {
+                apply_resource_diff(receiver.world_mut(), &message.diff).unwrap();
+                app.world_mut()
+                    .resource_mut::<ResourceReplication>()
+                    .ack(message.peer, message.diff.sequence);
+            }
+        };
+
+        *app.world_mut().resource_mut::<Settings>() = Settings {
+            volume: 0.25,
+            name: "first".into(),
+            window: Window {
+                width: 1280,
+                height: 720,
+            },
+        };
+        send(&mut app, &mut receiver);
+        assert_eq!(
+            app.world().resource::<Settings>(),
+            receiver.world().resource::<Settings>()
+        );
+
+        app.world_mut().resource_mut::<Settings>().window.width = 1920;
+        app.world_mut().resource_mut::<Settings>().name = "second".into();
+        send(&mut app, &mut receiver);
+        assert_eq!(
+            app.world().resource::<Settings>(),
+            receiver.world().resource::<Settings>()
+        );
+    }
+
+    #[test]
+    fn apply_reports_invalid_paths() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Settings>();
+        world.init_resource::<Settings>();
+
+        let diff = ResourceDiff {
+            type_path: Settings::type_path().to_string(),
+            sequence: 0,
+            changes: vec![FieldChange {
+                path: ".missing".into(),
+                value: Box::new(1.0f32),
+            }],
+        };
+        assert!(matches!(
+            apply_resource_diff(&mut world, &diff),
+            Err(ReplicationError::InvalidPath { .. })
+        ));
+    }
+}
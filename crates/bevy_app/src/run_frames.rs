@@ -0,0 +1,258 @@
+use crate::{App, AppExit, PluginsState};
+use alloc::{string::String, vec::Vec};
+use bevy_platform::time::Instant;
+use core::time::Duration;
+use thiserror::Error;
+
+/// How long [`App::run_frames`] waits for the plugins to be [ready](crate::Plugin::ready).
+const PLUGINS_READY_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// An error returned by [`App::run_frames`] and [`App::run_for`] when the plugins never became
+/// [ready](crate::Plugin::ready).
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("plugins were not ready after {waited:?}: {}", .plugins.join(", "))]
+pub struct PluginsNotReady {
+    /// The names of the plugins that were not ready, in the order they were added.
+    pub plugins: Vec<String>,
+    /// How long the app waited for the plugins.
+    pub waited: Duration,
+}
+
+impl App {
+    /// Runs the [`App`] for `frames` updates, without using its [runner](App::set_runner).
+    ///
+    /// This is meant for tests and benchmarks. The plugins are first driven through their
+    /// lifecycle like a runner would: the app waits for them to be [ready](crate::Plugin::ready),
+    /// then calls [`App::finish`] and [`App::cleanup`]. Plugins that are not ready after 10
+    /// seconds make this return a [`PluginsNotReady`] error instead of waiting forever. The steps
+    /// that already happened are skipped, so this can be called repeatedly.
+    ///
+    /// Returns the [`AppExit`] of the first update that sent one, in which case the remaining
+    /// updates are not run, or `None` if the app never asked to exit.
+    ///
+    /// The runner isn't used nor consumed, so this works without a window, and [`App::run`]
+    /// can still be called afterwards.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// #[derive(Resource, Default)]
+    /// struct Frames(u32);
+    ///
+    /// let mut app = App::new();
+    /// app.init_resource::<Frames>()
+    ///     .add_systems(Update, |mut frames: ResMut<Frames>| frames.0 += 1);
+    ///
+    /// assert_eq!(app.run_frames(3), Ok(None));
+    /// assert_eq!(app.world().resource::<Frames>().0, 3);
+    /// ```
+    pub fn run_frames(&mut self, frames: u32) -> Result<Option<AppExit>, PluginsNotReady> {
+        self.complete_plugins_lifecycle(Instant::now() + PLUGINS_READY_TIMEOUT)?;
+        for _ in 0..frames {
+            if let Some(exit) = self.update_until_exit() {
+                return Ok(Some(exit));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Runs the [`App`] until `duration` has elapsed, without using its
+    /// [runner](App::set_runner).
+    ///
+    /// This behaves like [`App::run_frames`], except that the app is updated as often as possible
+    /// until the deadline, and that waiting for the plugins to be [ready](crate::Plugin::ready)
+    /// counts towards `duration`: if they are not ready by the deadline, a [`PluginsNotReady`]
+    /// error is returned.
+    pub fn run_for(&mut self, duration: Duration) -> Result<Option<AppExit>, PluginsNotReady> {
+        let deadline = Instant::now() + duration;
+        self.complete_plugins_lifecycle(deadline)?;
+        while Instant::now() < deadline {
+            if let Some(exit) = self.update_until_exit() {
+                return Ok(Some(exit));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Updates the app once, and returns the [`AppExit`] it sent, if any.
+    fn update_until_exit(&mut self) -> Option<AppExit> {
+        self.update();
+        self.should_exit()
+    }
+
+    /// Waits for the plugins to be ready, then finishes and cleans them up, unless they already
+    /// were.
+    fn complete_plugins_lifecycle(&mut self, deadline: Instant) -> Result<(), PluginsNotReady> {
+        let start = Instant::now();
+        loop {
+            match self.plugins_state() {
+                PluginsState::Adding if Instant::now() >= deadline => {
+                    return Err(PluginsNotReady {
+                        plugins: self.unready_plugins(),
+                        waited: start.elapsed(),
+                    });
+                }
+                PluginsState::Adding => {
+                    #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+                    bevy_tasks::tick_global_task_pools_on_main_thread();
+                }
+                PluginsState::Ready => {
+                    self.finish();
+                    self.cleanup();
+                    return Ok(());
+                }
+                PluginsState::Finished => {
+                    self.cleanup();
+                    return Ok(());
+                }
+                PluginsState::Cleaned => return Ok(()),
+            }
+        }
+    }
+
+    /// Returns the names of the plugins of all sub-apps that are not ready yet.
+    fn unready_plugins(&mut self) -> Vec<String> {
+        // plugins installed to main need to see all sub-apps
+        let plugins = core::mem::take(&mut self.main_mut().plugin_registry);
+        let mut names: Vec<String> = plugins
+            .iter()
+            .filter(|plugin| !plugin.ready(self))
+            .map(|plugin| plugin.name().into())
+            .collect();
+        self.main_mut().plugin_registry = plugins;
+
+        for sub_app in self.sub_apps.iter_mut().skip(1) {
+            names.extend(sub_app.unready_plugins());
+        }
+        names
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{App, AppExit, Plugin, PluginsNotReady, PluginsState, TaskPoolPlugin, Update};
+    use alloc::{vec, vec::Vec};
+    use bevy_ecs::prelude::*;
+    use core::{
+        sync::atomic::{AtomicU32, Ordering},
+        time::Duration,
+    };
+
+    #[derive(Resource, Default)]
+    struct Frames(u32);
+
+    fn count_frames(mut frames: ResMut<Frames>) {
+        frames.0 += 1;
+    }
+
+    #[test]
+    fn drives_the_plugins_lifecycle() {
+        #[derive(Resource, Default)]
+        struct Lifecycle(Vec<&'static str>);
+
+        // Becomes ready after being polled a few times.
+        #[derive(Default)]
+        struct SlowPlugin(AtomicU32);
+        impl Plugin for SlowPlugin {
+            fn build(&self, app: &mut App) {
+                app.init_resource::<Lifecycle>();
+            }
+            fn ready(&self, _: &App) -> bool {
+                self.0.fetch_add(1, Ordering::Relaxed) >= 3
+            }
+            fn finish(&self, app: &mut App) {
+                app.world_mut().resource_mut::<Lifecycle>().0.push("finish");
+            }
+            fn cleanup(&self, app: &mut App) {
+                app.world_mut()
+                    .resource_mut::<Lifecycle>()
+                    .0
+                    .push("cleanup");
+            }
+        }
+
+        let mut app = App::new();
+        // Ticking the task pools while waiting needs them.
+        app.add_plugins((TaskPoolPlugin::default(), SlowPlugin::default()))
+            .init_resource::<Frames>()
+            .add_systems(Update, count_frames);
+
+        assert_eq!(app.run_frames(2), Ok(None));
+        assert_eq!(app.plugins_state(), PluginsState::Cleaned);
+        assert_eq!(app.world().resource::<Frames>().0, 2);
+
+        // The lifecycle is only completed once.
+        assert_eq!(app.run_frames(3), Ok(None));
+        assert_eq!(app.world().resource::<Frames>().0, 5);
+        assert_eq!(
+            app.world().resource::<Lifecycle>().0,
+            vec!["finish", "cleanup"]
+        );
+    }
+
+    #[test]
+    fn stops_at_early_exit() {
+        fn exit_on_third_frame(frames: Res<Frames>, mut exit: EventWriter<AppExit>) {
+            if frames.0 == 3 {
+                exit.write(AppExit::from_code(4));
+            }
+        }
+
+        let mut app = App::new();
+        app.init_resource::<Frames>()
+            .add_systems(Update, (count_frames, exit_on_third_frame).chain());
+
+        assert_eq!(app.run_frames(10), Ok(Some(AppExit::from_code(4))));
+        assert_eq!(app.world().resource::<Frames>().0, 3);
+
+        let mut app = App::new();
+        app.init_resource::<Frames>()
+            .add_systems(Update, (count_frames, exit_on_third_frame).chain());
+
+        assert_eq!(
+            app.run_for(Duration::from_secs(10)),
+            Ok(Some(AppExit::from_code(4)))
+        );
+        assert_eq!(app.world().resource::<Frames>().0, 3);
+    }
+
+    #[test]
+    fn never_ready_plugin_errors() {
+        struct NeverReady;
+        impl Plugin for NeverReady {
+            fn build(&self, _: &mut App) {}
+            fn ready(&self, _: &App) -> bool {
+                false
+            }
+        }
+
+        let mut app = App::new();
+        app.add_plugins((TaskPoolPlugin::default(), NeverReady))
+            .init_resource::<Frames>()
+            .add_systems(Update, count_frames);
+
+        let PluginsNotReady { plugins, waited } =
+            app.run_for(Duration::from_millis(20)).unwrap_err();
+        assert_eq!(plugins.len(), 1);
+        assert!(plugins[0].ends_with("NeverReady"));
+        assert!(waited >= Duration::from_millis(20));
+        assert_eq!(app.world().resource::<Frames>().0, 0);
+    }
+
+    #[test]
+    fn keeps_the_runner() {
+        let mut app = App::new();
+        app.init_resource::<Frames>()
+            .add_systems(Update, count_frames)
+            .set_runner(|mut app| {
+                app.update();
+                AppExit::from(app.world().resource::<Frames>().0 as u8)
+            });
+
+        assert_eq!(app.run_for(Duration::ZERO), Ok(None));
+        assert_eq!(app.run_frames(2), Ok(None));
+        assert_eq!(app.run(), AppExit::from_code(3));
+    }
+}
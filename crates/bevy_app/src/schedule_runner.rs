@@ -3,6 +3,7 @@ use crate::{
     plugin::Plugin,
     PluginsState,
 };
+use bevy_ecs::resource::Resource;
 use bevy_platform::time::Instant;
 use core::time::Duration;
 
@@ -34,6 +35,31 @@ impl Default for RunMode {
     }
 }
 
+/// Overrides the `wait` of [`RunMode::Loop`] while this resource exists.
+///
+/// This lets plugins change the frame rate of an app run by the [`ScheduleRunnerPlugin`] at
+/// runtime, for example to lower it while the app is idle. The override is read after each
+/// update, so it applies to the wait that follows the update that inserted it.
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LoopWaitOverride(pub Option<Duration>);
+
+/// The rate at which the [`ScheduleRunnerPlugin`] updates the app in [`RunMode::Loop`].
+///
+/// It is updated by the runner before each update, so systems see the values of the current
+/// update.
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq)]
+pub struct ScheduleRunnerTickRate {
+    /// The number of updates started so far, including the current one.
+    pub ticks: u64,
+    /// The time between the start of the first update and the start of the current one.
+    pub elapsed: Duration,
+    /// The number of updates per second, smoothed over the last updates.
+    pub ticks_per_second: f64,
+    /// How late the current update started compared to when it should have, following the
+    /// `wait` of [`RunMode::Loop`]. Zero without a wait.
+    pub lateness: Duration,
+}
+
 /// Configures an [`App`] to run its [`Schedule`](bevy_ecs::schedule::Schedule) according to a given
 /// [`RunMode`].
 ///
@@ -50,6 +76,15 @@ impl Default for RunMode {
 pub struct ScheduleRunnerPlugin {
     /// Determines whether the [`Schedule`](bevy_ecs::schedule::Schedule) is run once or repeatedly.
     pub run_mode: RunMode,
+    /// Whether to call [`App::warm_up`] before the first update.
+    pub warm_up: bool,
+    /// How long before the start of the next update the runner stops sleeping and spins instead,
+    /// in [`RunMode::Loop`] with a wait.
+    ///
+    /// Sleeping usually lasts a bit longer than requested. Spinning for the end of the wait makes
+    /// the updates start on time, at the cost of keeping a CPU core busy. Defaults to zero, which
+    /// never spins. Ignored on the web.
+    pub sleep_slack: Duration,
 }
 
 impl ScheduleRunnerPlugin {
@@ -57,6 +92,7 @@ impl ScheduleRunnerPlugin {
     pub fn run_once() -> Self {
         ScheduleRunnerPlugin {
             run_mode: RunMode::Once,
+            ..Default::default()
         }
     }
 
@@ -66,13 +102,28 @@ impl ScheduleRunnerPlugin {
             run_mode: RunMode::Loop {
                 wait: Some(wait_duration),
             },
+            ..Default::default()
         }
     }
+
+    /// Sets whether to call [`App::warm_up`] before the first update.
+    pub fn with_warm_up(mut self, warm_up: bool) -> Self {
+        self.warm_up = warm_up;
+        self
+    }
+
+    /// Sets the [`sleep_slack`](Self::sleep_slack).
+    pub fn with_sleep_slack(mut self, sleep_slack: Duration) -> Self {
+        self.sleep_slack = sleep_slack;
+        self
+    }
 }
 
 impl Plugin for ScheduleRunnerPlugin {
     fn build(&self, app: &mut App) {
         let run_mode = self.run_mode;
+        let warm_up = self.warm_up;
+        let sleep_slack = self.sleep_slack;
         app.set_runner(move |mut app: App| {
             let plugins_state = app.plugins_state();
             if plugins_state != PluginsState::Cleaned {
@@ -83,10 +134,14 @@ impl Plugin for ScheduleRunnerPlugin {
                 app.finish();
                 app.cleanup();
             }
+            if warm_up {
+                app.warm_up();
+            }
 
             match run_mode {
                 RunMode::Once => {
                     app.update();
+                    app.run_exit_hooks();
 
                     if let Some(exit) = app.should_exit() {
                         return exit;
@@ -95,27 +150,26 @@ impl Plugin for ScheduleRunnerPlugin {
                     AppExit::Success
                 }
                 RunMode::Loop { wait } => {
-                    let tick = move |app: &mut App,
-                                     _wait: Option<Duration>|
-                          -> Result<Option<Duration>, AppExit> {
+                    let mut pacer = FramePacer::default();
+                    let mut tick = move |app: &mut App,
+                                         _wait: Option<Duration>|
+                          -> Result<Option<Instant>, AppExit> {
                         let start_time = Instant::now();
+                        let tick_rate = pacer.start_tick(start_time);
+                        app.insert_resource(tick_rate);
 
                         app.update();
 
                         if let Some(exit) = app.should_exit() {
+                            app.run_exit_hooks();
                             return Err(exit);
                         };
 
-                        let end_time = Instant::now();
-
-                        if let Some(wait) = _wait {
-                            let exe_time = end_time - start_time;
-                            if exe_time < wait {
-                                return Ok(Some(wait - exe_time));
-                            }
-                        }
-
-                        Ok(None)
+                        let _wait = app
+                            .world()
+                            .get_resource::<LoopWaitOverride>()
+                            .map_or(_wait, |wait_override| wait_override.0);
+                        Ok(pacer.next_deadline(start_time, _wait))
                     };
 
                     cfg_if::cfg_if! {
@@ -130,6 +184,8 @@ impl Plugin for ScheduleRunnerPlugin {
                                     .expect("Should register `setTimeout`.");
                             }
                             let asap = Duration::from_millis(1);
+                            // Spinning would block the browser.
+                            let _ = sleep_slack;
 
                             let exit = Rc::new(RefCell::new(AppExit::Success));
                             let closure_exit = exit.clone();
@@ -140,11 +196,16 @@ impl Plugin for ScheduleRunnerPlugin {
 
                             let tick_app = move || {
                                 let app = Rc::get_mut(&mut app).unwrap();
-                                let delay = tick(app, wait);
-                                match delay {
-                                    Ok(delay) => set_timeout(
+                                let deadline = tick(app, wait);
+                                match deadline {
+                                    Ok(deadline) => set_timeout(
                                         moved_tick_closure.borrow().as_ref().unwrap(),
-                                        delay.unwrap_or(asap),
+                                        deadline
+                                            .map(|deadline| {
+                                                deadline.saturating_duration_since(Instant::now())
+                                            })
+                                            .filter(|delay| *delay > Duration::ZERO)
+                                            .unwrap_or(asap),
                                     ),
                                     Err(code) => {
                                         closure_exit.replace(code);
@@ -159,9 +220,7 @@ impl Plugin for ScheduleRunnerPlugin {
                         } else {
                             loop {
                                 match tick(&mut app, wait) {
-                                    Ok(Some(delay)) => {
-                                        bevy_platform::thread::sleep(delay);
-                                    }
+                                    Ok(Some(deadline)) => sleep_until(deadline, sleep_slack),
                                     Ok(None) => continue,
                                     Err(exit) => return exit,
                                 }
@@ -173,3 +232,157 @@ impl Plugin for ScheduleRunnerPlugin {
         });
     }
 }
+
+/// Schedules the updates of [`RunMode::Loop`] at fixed deadlines, and measures the achieved
+/// [`ScheduleRunnerTickRate`].
+#[derive(Default)]
+struct FramePacer {
+    first_tick: Option<Instant>,
+    last_tick: Option<Instant>,
+    deadline: Option<Instant>,
+    tick_rate: ScheduleRunnerTickRate,
+}
+
+impl FramePacer {
+    /// How much each update weighs in the smoothed [`ScheduleRunnerTickRate::ticks_per_second`].
+    const SMOOTHING: f64 = 0.1;
+
+    /// Records the start of an update.
+    fn start_tick(&mut self, now: Instant) -> ScheduleRunnerTickRate {
+        let first_tick = *self.first_tick.get_or_insert(now);
+        if let Some(last_tick) = self.last_tick.replace(now) {
+            let ticks_per_second = 1.0 / (now - last_tick).as_secs_f64().max(f64::EPSILON);
+            self.tick_rate.ticks_per_second = if self.tick_rate.ticks == 1 {
+                ticks_per_second
+            } else {
+                self.tick_rate.ticks_per_second
+                    + (ticks_per_second - self.tick_rate.ticks_per_second) * Self::SMOOTHING
+            };
+        }
+        self.tick_rate.ticks += 1;
+        self.tick_rate.elapsed = now - first_tick;
+        self.tick_rate.lateness = self.deadline.map_or(Duration::ZERO, |deadline| {
+            now.saturating_duration_since(deadline)
+        });
+        self.tick_rate
+    }
+
+    /// Returns when the next update should start, given the start of the current one.
+    ///
+    /// The deadlines follow each other by `wait`, so that an update starting late, for example
+    /// because the thread overslept, is compensated by a shorter wait before the next one instead
+    /// of delaying all of the following updates. An update that started more than `wait` late
+    /// doesn't get caught up with: the deadlines start again from it.
+    fn next_deadline(&mut self, tick_start: Instant, wait: Option<Duration>) -> Option<Instant> {
+        let Some(wait) = wait else {
+            self.deadline = None;
+            return None;
+        };
+        let deadline = match self.deadline {
+            Some(deadline) if tick_start.saturating_duration_since(deadline) < wait => {
+                deadline + wait
+            }
+            _ => tick_start + wait,
+        };
+        self.deadline = Some(deadline);
+        Some(deadline)
+    }
+}
+
+/// Sleeps until `slack` before `deadline`, then spins until `deadline`.
+#[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+fn sleep_until(deadline: Instant, slack: Duration) {
+    let remaining = deadline.saturating_duration_since(Instant::now());
+    if remaining > slack {
+        bevy_platform::thread::sleep(remaining - slack);
+    }
+    while Instant::now() < deadline {
+        core::hint::spin_loop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FramePacer;
+    use crate::{App, AppExit, Last, ScheduleRunnerPlugin, ScheduleRunnerTickRate};
+    use alloc::sync::Arc;
+    use bevy_ecs::prelude::*;
+    use bevy_platform::time::Instant;
+    use core::time::Duration;
+    use std::sync::Mutex;
+
+    const WAIT: Duration = Duration::from_millis(10);
+
+    #[test]
+    fn late_ticks_are_compensated() {
+        let start = Instant::now();
+        let mut pacer = FramePacer::default();
+        pacer.start_tick(start);
+        assert_eq!(pacer.next_deadline(start, Some(WAIT)), Some(start + WAIT));
+
+        // The thread overslept by 3ms, the next wait is shorter.
+        let late = start + WAIT + Duration::from_millis(3);
+        let tick_rate = pacer.start_tick(late);
+        assert_eq!(tick_rate.lateness, Duration::from_millis(3));
+        assert_eq!(
+            pacer.next_deadline(late, Some(WAIT)),
+            Some(start + WAIT * 2)
+        );
+
+        // Too late to catch up, the deadlines start again from this tick.
+        let very_late = start + WAIT * 4;
+        pacer.start_tick(very_late);
+        assert_eq!(
+            pacer.next_deadline(very_late, Some(WAIT)),
+            Some(very_late + WAIT)
+        );
+
+        // Without a wait, there is no deadline to be late for.
+        assert_eq!(pacer.next_deadline(very_late, None), None);
+        let tick_rate = pacer.start_tick(very_late + WAIT * 2);
+        assert_eq!(tick_rate.lateness, Duration::ZERO);
+        assert_eq!(tick_rate.ticks, 4);
+        assert_eq!(tick_rate.elapsed, WAIT * 6);
+    }
+
+    #[test]
+    fn measures_the_tick_rate() {
+        let start = Instant::now();
+        let mut pacer = FramePacer::default();
+        for tick in 0..100 {
+            pacer.start_tick(start + WAIT * tick);
+        }
+        let tick_rate = pacer.start_tick(start + WAIT * 100);
+        assert_eq!(tick_rate.ticks, 101);
+        assert!((tick_rate.ticks_per_second - 100.0).abs() < 0.01);
+    }
+
+    #[test]
+    #[ignore = "timing-sensitive"]
+    fn drift_stays_bounded() {
+        const TICKS: u64 = 300;
+        const WAIT: Duration = Duration::from_millis(2);
+
+        let result = Arc::new(Mutex::new(None));
+        let system_result = result.clone();
+        let mut app = App::new();
+        app.add_plugins(
+            ScheduleRunnerPlugin::run_loop(WAIT).with_sleep_slack(Duration::from_micros(500)),
+        )
+        .add_systems(
+            Last,
+            move |tick_rate: Res<ScheduleRunnerTickRate>, mut exit: EventWriter<AppExit>| {
+                if tick_rate.ticks == TICKS {
+                    *system_result.lock().unwrap() = Some(*tick_rate);
+                    exit.write(AppExit::Success);
+                }
+            },
+        );
+        app.run();
+
+        let tick_rate = result.lock().unwrap().unwrap();
+        let expected = WAIT * (TICKS - 1) as u32;
+        let drift = tick_rate.elapsed.abs_diff(expected);
+        assert!(drift < WAIT * 2, "drifted by {drift:?}");
+    }
+}
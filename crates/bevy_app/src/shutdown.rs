@@ -0,0 +1,570 @@
+use crate::{system_error::flush_system_errors, App, AppExit};
+use alloc::string::String;
+use bevy_ecs::{
+    event::{EventCursor, EventWriter, Events},
+    resource::Resource,
+    schedule::ScheduleLabel,
+    system::{Local, ResMut, SystemParam},
+    world::World,
+};
+use core::fmt;
+use log::{error, info};
+
+/// The schedule that runs once when the app starts shutting down, at the end of the update in
+/// which the first [`AppExit`] was written.
+///
+/// The [`AppShutdown`] resource tells the systems of this schedule why the app is exiting, for
+/// example to decide whether to save.
+#[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub struct OnShutdown;
+
+/// The schedule that runs once when the app exits, after its last update and just before its
+/// runner returns.
+///
+/// Unlike [`OnShutdown`], which runs during the update in which the [`AppExit`] was written,
+/// every system of the last update has run by then, so this is the place to flush saves or
+/// notify clients. It is run by [`App::run_exit_hooks`], before [`Plugin::on_exit`].
+///
+/// [`Plugin::on_exit`]: crate::Plugin::on_exit
+#[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub struct OnAppExit;
+
+/// Why the app is exiting.
+///
+/// The variants are ordered by increasing severity: when several reasons are given in the same
+/// frame, the most severe one is kept.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ShutdownReason {
+    /// The app logic asked to exit, without a more specific reason.
+    ///
+    /// This is the reason of an [`AppExit::Success`] written without a reason.
+    #[default]
+    Requested,
+    /// The user asked to quit, for example by closing the last window.
+    UserQuit,
+    /// The host of the app asked it to exit, for example the operating system or the application
+    /// embedding it.
+    HostRequest,
+    /// A test harness ended the app, once the test succeeded or failed.
+    TestHarness,
+    /// The process received a signal, for example `Ctrl+C` on the terminal.
+    Signal,
+    /// A watchdog found the app unresponsive.
+    Watchdog,
+    /// A plugin failed, for example because its renderer or a thread it started crashed.
+    PluginFailure,
+    /// The app experienced an unhandleable error.
+    ///
+    /// This is the reason of an [`AppExit::Error`] written without a reason.
+    FatalError,
+}
+
+impl fmt::Display for ShutdownReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ShutdownReason::Requested => "requested",
+            ShutdownReason::UserQuit => "user quit",
+            ShutdownReason::HostRequest => "host request",
+            ShutdownReason::TestHarness => "test harness",
+            ShutdownReason::Signal => "signal",
+            ShutdownReason::Watchdog => "watchdog",
+            ShutdownReason::PluginFailure => "plugin failure",
+            ShutdownReason::FatalError => "fatal error",
+        })
+    }
+}
+
+impl ShutdownReason {
+    /// The reason of an [`AppExit`] written without a reason.
+    fn of_exit(exit: &AppExit) -> Self {
+        if exit.is_error() {
+            ShutdownReason::FatalError
+        } else {
+            ShutdownReason::Requested
+        }
+    }
+}
+
+/// Tracks why the app is exiting.
+///
+/// Reasons are given by [`AppExitWriter::write_with_reason`] or [`request`](Self::request), and
+/// the most severe one is kept, along with its message if it has one. When the app starts shutting down, the reason is settled, from
+/// the [`AppExit`] if none was given, the exit is logged and [`OnShutdown`] runs.
+#[derive(Resource, Debug, Default)]
+pub struct AppShutdown {
+    reason: Option<ShutdownReason>,
+    message: Option<String>,
+    exit: Option<AppExit>,
+    /// Whether [`App::run_exit_hooks`] was called.
+    pub(crate) exit_hooks_run: bool,
+}
+
+impl AppShutdown {
+    /// Returns why the app is exiting, if a reason was given or the app is shutting down.
+    pub fn reason(&self) -> Option<ShutdownReason> {
+        self.reason
+    }
+
+    /// Returns the message given with the [reason](Self::reason), if any.
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+
+    /// Returns the [`AppExit`] the app is exiting with, once it is shutting down.
+    pub fn exit(&self) -> Option<&AppExit> {
+        self.exit.as_ref()
+    }
+
+    /// Returns `true` once the app is shutting down, from the start of [`OnShutdown`].
+    pub fn is_shutting_down(&self) -> bool {
+        self.exit.is_some()
+    }
+
+    /// Gives a reason for the app to exit.
+    ///
+    /// This doesn't make the app exit by itself, an [`AppExit`] must be written too, see
+    /// [`AppExitWriter::write_with_reason`]. If a more severe reason was already given, it is
+    /// kept and `reason` is logged.
+    pub fn request(&mut self, reason: ShutdownReason) {
+        self.request_inner(reason, None);
+    }
+
+    /// Gives a reason for the app to exit, with a message describing it, like
+    /// [`request`](Self::request).
+    ///
+    /// The message is kept with the reason, logged when the app starts shutting down and
+    /// available to the host of the app through [`App::exit_status`].
+    pub fn request_with_message(&mut self, reason: ShutdownReason, message: impl Into<String>) {
+        self.request_inner(reason, Some(message.into()));
+    }
+
+    fn request_inner(&mut self, reason: ShutdownReason, message: Option<String>) {
+        match self.reason {
+            Some(current) if current >= reason => {
+                if current != reason {
+                    info!(
+                        "Ignoring shutdown reason `{reason}`, keeping the more severe `{current}`"
+                    );
+                }
+            }
+            current => {
+                if let Some(current) = current {
+                    info!(
+                        "Ignoring shutdown reason `{current}`, keeping the more severe `{reason}`"
+                    );
+                }
+                self.reason = Some(reason);
+                self.message = message;
+            }
+        }
+    }
+}
+
+/// Logged when the app starts shutting down.
+impl fmt::Display for AppShutdown {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let reason = self.reason.unwrap_or_default();
+        match self.exit {
+            Some(AppExit::Error(code)) => write!(f, "App exiting ({reason}) with code {code}")?,
+            _ => write!(f, "App exiting ({reason})")?,
+        }
+        match &self.message {
+            Some(message) => write!(f, ": {message}"),
+            None => Ok(()),
+        }
+    }
+}
+
+/// A [`SystemParam`] that writes [`AppExit`] events along with a [`ShutdownReason`].
+///
+/// The reasons are given to the [`AppShutdown`] resource, which [`App::new`] adds. Without it,
+/// for example in an app built from [`App::empty`], the reasons are dropped and only the
+/// [`AppExit`] events are written.
+///
+/// ```
+/// # use bevy_app::{prelude::*, AppExitWriter, ShutdownReason};
+/// fn quit_button(mut exit: AppExitWriter) {
+///     // When the button is pressed:
+///     exit.write_with_reason(AppExit::Success, ShutdownReason::UserQuit);
+/// }
+/// # App::new().add_systems(Update, quit_button);
+/// ```
+#[derive(SystemParam)]
+pub struct AppExitWriter<'w> {
+    exits: EventWriter<'w, AppExit>,
+    shutdown: Option<ResMut<'w, AppShutdown>>,
+}
+
+impl AppExitWriter<'_> {
+    /// Writes an [`AppExit`], without a [`ShutdownReason`].
+    ///
+    /// Unless another reason is given, the reason is [`ShutdownReason::Requested`] for
+    /// [`AppExit::Success`] and [`ShutdownReason::FatalError`] for [`AppExit::Error`].
+    pub fn write(&mut self, exit: AppExit) {
+        self.exits.write(exit);
+    }
+
+    /// Writes an [`AppExit`] and [requests](AppShutdown::request) the given [`ShutdownReason`].
+    pub fn write_with_reason(&mut self, exit: AppExit, reason: ShutdownReason) {
+        if let Some(shutdown) = &mut self.shutdown {
+            shutdown.request(reason);
+        }
+        self.exits.write(exit);
+    }
+
+    /// Writes an [`AppExit`] and [requests](AppShutdown::request_with_message) the given
+    /// [`ShutdownReason`], with a message describing it.
+    pub fn write_with_message(
+        &mut self,
+        exit: AppExit,
+        reason: ShutdownReason,
+        message: impl Into<String>,
+    ) {
+        if let Some(shutdown) = &mut self.shutdown {
+            shutdown.request_with_message(reason, message);
+        }
+        self.exits.write(exit);
+    }
+}
+
+/// Starts shutting down once an [`AppExit`] is written, running [`OnShutdown`].
+pub(crate) fn start_shutdown(world: &mut World, mut cursor: Local<EventCursor<AppExit>>) {
+    let Some(exits) = world.get_resource::<Events<AppExit>>() else {
+        return;
+    };
+    let exits = cursor.read(exits);
+    if exits.len() == 0 {
+        return;
+    }
+    // Same precedence as `App::should_exit`: the first error, or success.
+    let exit = exits.fold(AppExit::Success, |exit, next| {
+        if exit.is_error() || next.is_success() {
+            exit
+        } else {
+            next.clone()
+        }
+    });
+
+    let Some(mut shutdown) = world.get_resource_mut::<AppShutdown>() else {
+        return;
+    };
+    if shutdown.is_shutting_down() {
+        return;
+    }
+    let reason = shutdown
+        .reason
+        .unwrap_or_else(|| ShutdownReason::of_exit(&exit));
+    shutdown.reason = Some(reason);
+    let is_error = exit.is_error();
+    shutdown.exit = Some(exit);
+    if is_error {
+        error!("{}", *shutdown);
+    } else {
+        info!("{}", *shutdown);
+    }
+
+    let _ = world.try_run_schedule(OnShutdown);
+    flush_system_errors(world);
+}
+
+/// An [`AppExit`] along with why the app is exiting, returned by [`App::exit_status`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AppExitStatus {
+    /// The [`AppExit`] the app is exiting with, mapped to the exit code of the process.
+    pub exit: AppExit,
+    /// Why the app is exiting.
+    pub reason: ShutdownReason,
+    /// The message given with the reason, if any.
+    pub message: Option<String>,
+}
+
+impl App {
+    /// Returns the [`AppExit`] of the app like [`App::should_exit`], along with why it is exiting.
+    ///
+    /// This lets the host of the app, like an editor, show why the app exited. Without an
+    /// explicit reason, it is [`ShutdownReason::Requested`] for [`AppExit::Success`] and
+    /// [`ShutdownReason::FatalError`] for [`AppExit::Error`].
+    pub fn exit_status(&self) -> Option<AppExitStatus> {
+        let exit = self.should_exit()?;
+        let shutdown = self.world().get_resource::<AppShutdown>();
+        Some(AppExitStatus {
+            reason: shutdown
+                .and_then(AppShutdown::reason)
+                .unwrap_or_else(|| ShutdownReason::of_exit(&exit)),
+            message: shutdown.and_then(|shutdown| shutdown.message.clone()),
+            exit,
+        })
+    }
+
+    /// Returns why the app is exiting, see [`AppShutdown::reason`].
+    pub fn shutdown_reason(&self) -> Option<ShutdownReason> {
+        self.world()
+            .get_resource::<AppShutdown>()
+            .and_then(AppShutdown::reason)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{string::ToString, vec, vec::Vec};
+    use bevy_ecs::{
+        event::EventWriter,
+        resource::Resource,
+        system::{Res, ResMut},
+    };
+
+    use super::{AppExitStatus, AppExitWriter, AppShutdown, OnShutdown, ShutdownReason};
+    use crate::{App, AppExit, Update};
+
+    fn reason_after_update(app: &mut App) -> Option<ShutdownReason> {
+        app.update();
+        app.shutdown_reason()
+    }
+
+    #[test]
+    fn each_origin_sets_its_reason() {
+        let mut app = App::new();
+        app.add_systems(Update, |mut exit: AppExitWriter| {
+            exit.write_with_reason(AppExit::Success, ShutdownReason::UserQuit);
+        });
+        assert_eq!(
+            reason_after_update(&mut app),
+            Some(ShutdownReason::UserQuit)
+        );
+
+        let mut app = App::new();
+        app.add_systems(Update, |mut exit: EventWriter<AppExit>| {
+            exit.write(AppExit::Success);
+        });
+        assert_eq!(
+            reason_after_update(&mut app),
+            Some(ShutdownReason::Requested)
+        );
+
+        let mut app = App::new();
+        app.add_systems(Update, |mut exit: AppExitWriter| {
+            exit.write(AppExit::error());
+        });
+        assert_eq!(
+            reason_after_update(&mut app),
+            Some(ShutdownReason::FatalError)
+        );
+
+        let mut app = App::new();
+        app.update();
+        assert_eq!(app.shutdown_reason(), None);
+        app.world_mut()
+            .resource_mut::<AppShutdown>()
+            .request(ShutdownReason::HostRequest);
+        app.world_mut().write_event(AppExit::Success);
+        assert_eq!(
+            reason_after_update(&mut app),
+            Some(ShutdownReason::HostRequest)
+        );
+    }
+
+    #[test]
+    fn writer_works_without_shutdown_resource() {
+        let mut app = App::new();
+        app.world_mut().remove_resource::<AppShutdown>();
+        app.add_systems(Update, |mut exit: AppExitWriter| {
+            exit.write_with_reason(AppExit::error(), ShutdownReason::UserQuit);
+        });
+        assert_eq!(reason_after_update(&mut app), None);
+        assert_eq!(app.should_exit(), Some(AppExit::error()));
+    }
+
+    #[cfg(all(any(all(unix, not(target_os = "horizon")), windows), feature = "std"))]
+    #[test]
+    fn ctrl_c_sets_signal_reason() {
+        use crate::TerminalCtrlCHandlerPlugin;
+
+        let mut app = App::new();
+        app.add_systems(Update, TerminalCtrlCHandlerPlugin::exit_on_flag);
+        TerminalCtrlCHandlerPlugin::gracefully_exit();
+        let reason = reason_after_update(&mut app);
+        TerminalCtrlCHandlerPlugin::reset_exit_flag();
+        assert_eq!(reason, Some(ShutdownReason::Signal));
+        assert_eq!(app.should_exit(), Some(AppExit::from_code(130)));
+    }
+
+    #[test]
+    fn exit_hooks_run_once_after_the_last_update() {
+        use crate::{OnAppExit, Plugin, ScheduleRunnerPlugin};
+        use alloc::sync::Arc;
+        use bevy_platform::sync::Mutex;
+        use core::time::Duration;
+
+        type Calls = Arc<Mutex<Vec<&'static str>>>;
+
+        struct ExitPlugin(Calls);
+        impl Plugin for ExitPlugin {
+            fn build(&self, _app: &mut App) {}
+            fn on_exit(&self, _app: &mut App) {
+                self.0.lock().unwrap().push("plugin");
+            }
+        }
+
+        let calls = Calls::default();
+        let exit = {
+            let (update, on_app_exit) = (calls.clone(), calls.clone());
+            App::new()
+                .add_plugins((
+                    ScheduleRunnerPlugin::run_loop(Duration::ZERO),
+                    ExitPlugin(calls.clone()),
+                ))
+                .add_systems(Update, move |mut exit: AppExitWriter| {
+                    let mut calls = update.lock().unwrap();
+                    calls.push("update");
+                    if calls.len() == 2 {
+                        exit.write(AppExit::from_code(4));
+                    }
+                })
+                .add_systems(OnAppExit, move || {
+                    on_app_exit.lock().unwrap().push("schedule");
+                })
+                .run()
+        };
+
+        assert_eq!(exit, AppExit::from_code(4));
+        assert_eq!(
+            *calls.lock().unwrap(),
+            vec!["update", "update", "schedule", "plugin"]
+        );
+    }
+
+    #[test]
+    fn exit_hooks_only_run_once() {
+        #[derive(Resource, Default)]
+        struct Runs(u32);
+
+        let mut app = App::new();
+        app.init_resource::<Runs>()
+            .add_systems(crate::OnAppExit, |mut runs: ResMut<Runs>| runs.0 += 1);
+        app.run_exit_hooks();
+        app.run_exit_hooks();
+        assert_eq!(app.world().resource::<Runs>().0, 1);
+    }
+
+    #[test]
+    fn most_severe_reason_wins() {
+        let mut app = App::new();
+        app.add_systems(Update, |mut exit: AppExitWriter| {
+            exit.write_with_reason(AppExit::Success, ShutdownReason::UserQuit);
+            exit.write_with_reason(AppExit::from_code(2), ShutdownReason::FatalError);
+            exit.write_with_reason(AppExit::Success, ShutdownReason::Signal);
+        });
+        assert_eq!(
+            reason_after_update(&mut app),
+            Some(ShutdownReason::FatalError)
+        );
+
+        let mut shutdown = AppShutdown::default();
+        shutdown.request(ShutdownReason::HostRequest);
+        shutdown.request(ShutdownReason::Requested);
+        assert_eq!(shutdown.reason(), Some(ShutdownReason::HostRequest));
+        shutdown.request(ShutdownReason::Signal);
+        assert_eq!(shutdown.reason(), Some(ShutdownReason::Signal));
+    }
+
+    #[test]
+    fn available_during_shutdown_systems() {
+        #[derive(Resource, Default)]
+        struct Seen(Vec<(Option<ShutdownReason>, Option<AppExit>)>);
+
+        let mut app = App::new();
+        app.init_resource::<Seen>()
+            .add_systems(
+                OnShutdown,
+                |shutdown: Res<AppShutdown>, mut seen: ResMut<Seen>| {
+                    seen.0.push((shutdown.reason(), shutdown.exit().cloned()));
+                },
+            )
+            .add_systems(Update, |mut exit: AppExitWriter| {
+                exit.write_with_reason(AppExit::from_code(3), ShutdownReason::HostRequest);
+            });
+
+        // `OnShutdown` only runs once, even if the app keeps updating.
+        app.update();
+        app.update();
+        assert_eq!(
+            app.world().resource::<Seen>().0,
+            vec![(
+                Some(ShutdownReason::HostRequest),
+                Some(AppExit::from_code(3))
+            )]
+        );
+    }
+
+    #[test]
+    fn exit_status_has_the_message() {
+        let mut app = App::new();
+        app.update();
+        assert_eq!(app.exit_status(), None);
+
+        app.add_systems(Update, |mut exit: AppExitWriter| {
+            exit.write_with_message(
+                AppExit::from_code(2),
+                ShutdownReason::TestHarness,
+                "3 errors were logged",
+            );
+        });
+        app.update();
+        assert_eq!(
+            app.exit_status(),
+            Some(AppExitStatus {
+                exit: AppExit::from_code(2),
+                reason: ShutdownReason::TestHarness,
+                message: Some("3 errors were logged".to_string()),
+            })
+        );
+        assert_eq!(
+            app.world().resource::<AppShutdown>().to_string(),
+            "App exiting (test harness) with code 2: 3 errors were logged"
+        );
+
+        // Without a reason, it is deduced from the exit.
+        let mut app = App::new();
+        app.world_mut().write_event(AppExit::error());
+        assert_eq!(
+            app.exit_status(),
+            Some(AppExitStatus {
+                exit: AppExit::error(),
+                reason: ShutdownReason::FatalError,
+                message: None,
+            })
+        );
+    }
+
+    #[test]
+    fn message_follows_the_kept_reason() {
+        let mut shutdown = AppShutdown::default();
+        shutdown.request_with_message(ShutdownReason::UserQuit, "quit button");
+        shutdown.request_with_message(ShutdownReason::Requested, "ignored");
+        assert_eq!(shutdown.message(), Some("quit button"));
+        shutdown.request(ShutdownReason::Signal);
+        assert_eq!(shutdown.message(), None);
+    }
+
+    #[test]
+    fn log_line() {
+        let mut app = App::new();
+        app.add_systems(Update, |mut exit: AppExitWriter| {
+            exit.write_with_reason(AppExit::from_code(3), ShutdownReason::Signal);
+            exit.write(AppExit::Success);
+        });
+        app.update();
+        assert_eq!(
+            app.world().resource::<AppShutdown>().to_string(),
+            "App exiting (signal) with code 3"
+        );
+
+        let mut app = App::new();
+        app.world_mut().write_event(AppExit::Success);
+        app.update();
+        assert_eq!(
+            app.world().resource::<AppShutdown>().to_string(),
+            "App exiting (requested)"
+        );
+    }
+}
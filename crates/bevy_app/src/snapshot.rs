@@ -0,0 +1,364 @@
+use crate::App;
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec::Vec,
+};
+use bevy_ecs::{
+    archetype::ArchetypeEntity,
+    change_detection::Mut,
+    entity::Entity,
+    entity_disabling::Internal,
+    event::EventRegistry,
+    observer::ObservedBy,
+    reflect::{AppTypeRegistry, ReflectComponent, ReflectResource},
+    relationship::RelationshipHookMode,
+    world::World,
+};
+use bevy_reflect::Reflect;
+use core::any::TypeId;
+use thiserror::Error;
+
+/// A copy of the entities and resources of a [`World`], taken with [`App::snapshot`] and
+/// restored with [`App::restore`].
+///
+/// This lets tests run the same scenario repeatedly from a known state. Only the types
+/// registered in the [`AppTypeRegistry`] can be copied:
+///
+/// - Every component of every entity must be registered with `#[reflect(Component)]`, or taking
+///   the snapshot fails with [`SnapshotError::NotSnapshotable`]. Internal entities, like
+///   observers and systems, are not part of the snapshot.
+/// - The resources registered with `#[reflect(Resource)]` are copied. The other resources are
+///   considered part of the app rather than of its state, and are left untouched.
+///
+/// Values are copied with [`PartialReflect::reflect_clone`](bevy_reflect::PartialReflect::reflect_clone),
+/// so types that can't be cloned through reflection can't be snapshotted either. Entities
+/// watched by [entity observers](bevy_ecs::observer::Observer::with_entity) can't be
+/// snapshotted, as their observers would not be restored with them.
+///
+/// Restoring despawns the entities and spawns them again with their exact ids, so that stored
+/// [`Entity`] references stay valid. Hooks and observers run as usual, and change detection sees
+/// every restored component and resource as added. The events in the [`EventRegistry`] are
+/// cleared.
+///
+/// ```
+/// # use bevy_app::App;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_reflect::Reflect;
+/// #[derive(Component, Reflect)]
+/// #[reflect(Component)]
+/// struct Health(f32);
+///
+/// let mut app = App::new();
+/// app.register_type::<Health>();
+/// let player = app.world_mut().spawn(Health(10.0)).id();
+///
+/// let snapshot = app.snapshot().unwrap();
+/// app.world_mut().entity_mut(player).despawn();
+/// app.restore(&snapshot).unwrap();
+///
+/// assert_eq!(app.world().get::<Health>(player).unwrap().0, 10.0);
+/// ```
+pub struct WorldSnapshot {
+    entities: Vec<(Entity, Vec<(ReflectComponent, Box<dyn Reflect>)>)>,
+    resources: Vec<(TypeId, Box<dyn Reflect>)>,
+}
+
+/// An error that occurs when taking or restoring a [`WorldSnapshot`].
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum SnapshotError {
+    /// The world contains types that can't be snapshotted, listed by name.
+    #[error(
+        "the world can't be snapshotted, as these types aren't registered as reflected components or resources, or can't be cloned through reflection: {}",
+        .0.join(", ")
+    )]
+    NotSnapshotable(Vec<String>),
+    /// The id of a snapshotted entity is used by an internal entity spawned after the snapshot.
+    #[error(
+        "entity {0} can't be restored, as its id is used by an internal entity spawned after the snapshot"
+    )]
+    EntityInUse(Entity),
+}
+
+impl WorldSnapshot {
+    /// Copies the entities and resources of `world`.
+    pub fn take(world: &World) -> Result<Self, SnapshotError> {
+        let registry = world
+            .get_resource::<AppTypeRegistry>()
+            .cloned()
+            .unwrap_or_default();
+        let registry = registry.read();
+        let internal = world.component_id::<Internal>();
+        let observed_by = world.component_id::<ObservedBy>();
+
+        let mut not_snapshotable = Vec::new();
+        let mut entities = Vec::new();
+        for archetype in world.archetypes().iter() {
+            if archetype.is_empty() || internal.is_some_and(|id| archetype.contains(id)) {
+                continue;
+            }
+
+            let mut reflect_components = Vec::new();
+            for id in archetype.components() {
+                let Some(info) = world.components().get_info(id) else {
+                    continue;
+                };
+                match info
+                    .type_id()
+                    .filter(|_| Some(id) != observed_by)
+                    .and_then(|type_id| registry.get_type_data::<ReflectComponent>(type_id))
+                {
+                    Some(reflect_component) => reflect_components.push(reflect_component),
+                    None => not_snapshotable.push(info.name().to_string()),
+                }
+            }
+
+            for entity in archetype.entities().iter().map(ArchetypeEntity::id) {
+                let entity_ref = world.entity(entity);
+                let mut components = Vec::with_capacity(reflect_components.len());
+                for reflect_component in &reflect_components {
+                    let Some(value) = reflect_component.reflect(entity_ref) else {
+                        continue;
+                    };
+                    match value.reflect_clone() {
+                        Ok(value) => components.push(((*reflect_component).clone(), value)),
+                        Err(_) => not_snapshotable.push(value.reflect_type_path().to_string()),
+                    }
+                }
+                entities.push((entity, components));
+            }
+        }
+
+        let mut resources = Vec::new();
+        for (registration, reflect_resource) in registry.iter_with_data::<ReflectResource>() {
+            let Ok(value) = reflect_resource.reflect(world) else {
+                continue;
+            };
+            match value.reflect_clone() {
+                Ok(value) => resources.push((registration.type_id(), value)),
+                Err(_) => not_snapshotable.push(value.reflect_type_path().to_string()),
+            }
+        }
+
+        if !not_snapshotable.is_empty() {
+            not_snapshotable.sort_unstable();
+            not_snapshotable.dedup();
+            return Err(SnapshotError::NotSnapshotable(not_snapshotable));
+        }
+        Ok(Self {
+            entities,
+            resources,
+        })
+    }
+
+    /// Restores the entities and resources of `world` to the ones of the snapshot.
+    ///
+    /// The entities spawned since the snapshot was taken are despawned, and the registered
+    /// resources inserted since are removed.
+    pub fn restore(&self, world: &mut World) -> Result<(), SnapshotError> {
+        world.flush();
+        let internal = world.component_id::<Internal>();
+
+        // Check that every entity can be spawned again before changing anything.
+        for &(entity, _) in &self.entities {
+            if let Some(current) = world.entities().resolve_from_id(entity.row())
+                && current != entity
+                && world
+                    .get_entity(current)
+                    .is_ok_and(|current| internal.is_some_and(|id| current.contains_id(id)))
+            {
+                return Err(SnapshotError::EntityInUse(entity));
+            }
+        }
+
+        let despawned = world
+            .archetypes()
+            .iter()
+            .filter(|archetype| !internal.is_some_and(|id| archetype.contains(id)))
+            .flat_map(|archetype| archetype.entities().iter().map(ArchetypeEntity::id))
+            .collect::<Vec<_>>();
+        for entity in despawned {
+            // Despawning an entity can despawn others with it.
+            if let Ok(entity) = world.get_entity_mut(entity) {
+                entity.despawn();
+            }
+        }
+
+        let entities = self
+            .entities
+            .iter()
+            .map(|(entity, _)| *entity)
+            .collect::<Vec<_>>();
+        world
+            .spawn_empty_at(&entities)
+            .map_err(SnapshotError::EntityInUse)?;
+
+        let registry = world.resource::<AppTypeRegistry>().clone();
+        let registry = registry.read();
+        for (entity, components) in &self.entities {
+            let mut entity_mut = world.entity_mut(*entity);
+            for (reflect_component, value) in components {
+                // Relationship targets are part of the snapshot, they must not be updated by
+                // the hooks of the relationships.
+                reflect_component.apply_or_insert_mapped(
+                    &mut entity_mut,
+                    value.as_partial_reflect(),
+                    &registry,
+                    &mut (),
+                    RelationshipHookMode::Skip,
+                );
+            }
+        }
+
+        for (registration, reflect_resource) in registry.iter_with_data::<ReflectResource>() {
+            match self
+                .resources
+                .iter()
+                .find(|(type_id, _)| *type_id == registration.type_id())
+            {
+                Some((_, value)) => {
+                    reflect_resource.insert(world, value.as_partial_reflect(), &registry);
+                }
+                None => reflect_resource.remove(world),
+            }
+        }
+
+        world.try_resource_scope(|world, mut events: Mut<EventRegistry>| {
+            events.clear_all(world);
+        });
+        Ok(())
+    }
+}
+
+impl App {
+    /// Takes a [`WorldSnapshot`] of the main world.
+    pub fn snapshot(&self) -> Result<WorldSnapshot, SnapshotError> {
+        WorldSnapshot::take(self.world())
+    }
+
+    /// Restores the main world to the state of a [`WorldSnapshot`] taken with
+    /// [`App::snapshot`].
+    pub fn restore(&mut self, snapshot: &WorldSnapshot) -> Result<(), SnapshotError> {
+        snapshot.restore(self.world_mut())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Update;
+    use bevy_ecs::prelude::*;
+
+    #[derive(Component, Reflect, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Position(f32);
+
+    #[derive(Component, Reflect, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Target(Entity);
+
+    #[derive(Resource, Reflect, Debug, PartialEq)]
+    #[reflect(Resource)]
+    struct Score(u32);
+
+    fn app() -> App {
+        let mut app = App::new();
+        app.register_type::<Position>()
+            .register_type::<Target>()
+            .register_type::<Score>()
+            .insert_resource(Score(0));
+        app
+    }
+
+    #[test]
+    fn restores_values() {
+        let mut app = app();
+        let a = app.world_mut().spawn(Position(1.0)).id();
+        let b = app.world_mut().spawn((Position(2.0), Target(a))).id();
+        let snapshot = app.snapshot().unwrap();
+
+        app.add_systems(Update, |mut positions: Query<&mut Position>| {
+            for mut position in &mut positions {
+                position.0 += 10.0;
+            }
+        })
+        .add_systems(Update, |mut score: ResMut<Score>| score.0 += 1);
+        app.update();
+        app.world_mut().entity_mut(b).remove::<Target>();
+        app.world_mut().spawn(Position(3.0));
+
+        app.restore(&snapshot).unwrap();
+        let world = app.world_mut();
+        assert_eq!(world.resource::<Score>(), &Score(0));
+        assert_eq!(world.get::<Position>(a), Some(&Position(1.0)));
+        assert_eq!(world.get::<Position>(b), Some(&Position(2.0)));
+        assert_eq!(world.get::<Target>(b), Some(&Target(a)));
+        assert_eq!(world.query::<&Position>().iter(world).len(), 2);
+    }
+
+    #[test]
+    fn preserves_entity_ids() {
+        let mut app = app();
+        let a = app.world_mut().spawn(Position(1.0)).id();
+        let b = app.world_mut().spawn(Target(a)).id();
+        let snapshot = app.snapshot().unwrap();
+
+        // The rows of the despawned entities are reused by new ones.
+        app.world_mut().entity_mut(a).despawn();
+        app.world_mut().entity_mut(b).despawn();
+        app.world_mut().spawn_batch([Position(5.0), Position(6.0)]);
+
+        app.restore(&snapshot).unwrap();
+        let target = app.world().get::<Target>(b).unwrap().0;
+        assert_eq!(target, a);
+        assert_eq!(app.world().get::<Position>(target), Some(&Position(1.0)));
+
+        // Restoring can be repeated.
+        app.world_mut().entity_mut(a).insert(Position(7.0));
+        app.restore(&snapshot).unwrap();
+        assert_eq!(app.world().get::<Position>(a), Some(&Position(1.0)));
+    }
+
+    #[test]
+    fn lists_non_snapshotable_types() {
+        #[derive(Component)]
+        struct NotReflected;
+
+        let mut app = app();
+        app.world_mut().spawn((Position(1.0), NotReflected));
+
+        let Err(SnapshotError::NotSnapshotable(types)) = app.snapshot() else {
+            panic!("the snapshot should fail");
+        };
+        assert_eq!(types.len(), 1);
+        assert!(types[0].ends_with("NotReflected"));
+    }
+
+    #[test]
+    fn fails_if_an_internal_entity_took_an_id() {
+        let mut app = app();
+        let a = app.world_mut().spawn(Position(1.0)).id();
+        let snapshot = app.snapshot().unwrap();
+
+        app.world_mut().entity_mut(a).despawn();
+        // Observers are internal entities, which are not despawned by the restore.
+        let observer = app.world_mut().add_observer(|_: On<Add, Position>| {}).id();
+        assert_eq!(observer.index(), a.index());
+
+        assert_eq!(app.restore(&snapshot), Err(SnapshotError::EntityInUse(a)));
+    }
+
+    #[test]
+    fn clears_events() {
+        #[derive(Event, BufferedEvent)]
+        struct Hit;
+
+        let mut app = app();
+        app.add_event::<Hit>();
+        let snapshot = app.snapshot().unwrap();
+
+        app.world_mut().write_event(Hit);
+        app.restore(&snapshot).unwrap();
+        assert!(app.world().resource::<Events<Hit>>().is_empty());
+    }
+}
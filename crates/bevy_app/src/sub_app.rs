@@ -1,13 +1,29 @@
-use crate::{App, AppLabel, InternedAppLabel, Plugin, Plugins, PluginsState};
-use alloc::{boxed::Box, string::String, vec::Vec};
+use crate::{
+    capabilities::by_plugin, plugin::shared_plugin, plugin_rebuild::PluginRecord, App, AppLabel,
+    Capabilities, InternedAppLabel, LateStartup, PlaceholderPlugin, Plugin, Plugins, PluginsState,
+};
+use alloc::{
+    borrow::Cow,
+    boxed::Box,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
 use bevy_ecs::{
-    event::EventRegistry,
+    event::{EventRegistry, EventSettings},
     prelude::*,
-    schedule::{InternedScheduleLabel, InternedSystemSet, ScheduleBuildSettings, ScheduleLabel},
+    schedule::{
+        Description, InternedScheduleLabel, InternedSystemSet, ScheduleBuildSettings,
+        ScheduleDescriptions, ScheduleLabel,
+    },
     system::{ScheduleSystem, SystemId, SystemInput},
 };
-use bevy_platform::collections::{HashMap, HashSet};
-use core::fmt::Debug;
+use bevy_platform::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+use core::fmt::{Arguments, Debug};
+use log::warn;
 
 #[cfg(feature = "trace")]
 use tracing::info_span;
@@ -62,12 +78,26 @@ pub struct SubApp {
     /// The data of this application.
     world: World,
     /// List of plugins that have been added.
-    pub(crate) plugin_registry: Vec<Box<dyn Plugin>>,
+    ///
+    /// The plugins are shared with the [forks](App::fork) of the app.
+    pub(crate) plugin_registry: Vec<Arc<dyn Plugin>>,
+    /// Whether each plugin in `plugin_registry` was added by another plugin's build, rather
+    /// than directly to the app. [`App::fork`] only adds the latter.
+    pub(crate) nested_plugins: Vec<bool>,
     /// The names of plugins that have been added to this app. (used to track duplicates and
     /// already-registered plugins)
     pub(crate) plugin_names: HashSet<String>,
+    /// Plugins whose [`Plugin::build`] is deferred until their required resources exist.
+    pub(crate) deferred_plugins: Vec<Arc<dyn Plugin>>,
+    /// What each plugin in `plugin_registry` registered during its build, once
+    /// [`App::enable_plugin_rebuilds`] has been called.
+    pub(crate) plugin_records: Option<Vec<Option<PluginRecord>>>,
+    /// The names of the plugins being built, innermost last.
+    ///
     /// Panics if an update is attempted while plugins are building.
-    pub(crate) plugin_build_depth: usize,
+    pub(crate) building_plugins: Vec<String>,
+    /// The capabilities declared by plugins.
+    pub(crate) capabilities: Capabilities,
     pub(crate) plugins_state: PluginsState,
     /// The schedule that will be run by [`update`](Self::update).
     pub update_schedule: Option<InternedScheduleLabel>,
@@ -89,8 +119,12 @@ impl Default for SubApp {
         Self {
             world,
             plugin_registry: Vec::default(),
+            nested_plugins: Vec::new(),
             plugin_names: HashSet::default(),
-            plugin_build_depth: 0,
+            deferred_plugins: Vec::new(),
+            plugin_records: None,
+            building_plugins: Vec::new(),
+            capabilities: Capabilities::default(),
             plugins_state: PluginsState::Adding,
             update_schedule: None,
             extract: None,
@@ -158,6 +192,11 @@ impl SubApp {
     /// Sets the method that will be called by [`extract`](Self::extract).
     ///
     /// The first argument is the `World` to extract data from, the second argument is the app `World`.
+    ///
+    /// The method has exclusive access to both worlds. When the sub-app is part of an [`App`], it
+    /// is called on each [`App::update`], after the main app is updated and before this sub-app
+    /// is, and after the extraction and update of the sub-apps ordered before this one with
+    /// [`App::configure_sub_app_order`].
     pub fn set_extract<F>(&mut self, extract: F) -> &mut Self
     where
         F: FnMut(&mut World, &mut World) + Send + 'static,
@@ -213,6 +252,7 @@ impl SubApp {
         schedule: impl ScheduleLabel,
         systems: impl IntoScheduleConfigs<ScheduleSystem, M>,
     ) -> &mut Self {
+        let schedule = LateStartup::redirect(&self.world, schedule.intern());
         let mut schedules = self.world.resource_mut::<Schedules>();
         schedules.add_systems(schedule, systems);
 
@@ -238,11 +278,49 @@ impl SubApp {
         schedule: impl ScheduleLabel,
         sets: impl IntoScheduleConfigs<InternedSystemSet, M>,
     ) -> &mut Self {
+        let schedule = LateStartup::redirect(&self.world, schedule.intern());
         let mut schedules = self.world.resource_mut::<Schedules>();
         schedules.configure_sets(schedule, sets);
         self
     }
 
+    /// See [`App::describe_schedule`].
+    pub fn describe_schedule(
+        &mut self,
+        label: impl ScheduleLabel,
+        text: impl Into<Cow<'static, str>>,
+    ) -> &mut Self {
+        let description = self.new_description(text);
+        let previous = self
+            .world
+            .get_resource_or_init::<ScheduleDescriptions>()
+            .describe_schedule(label.intern(), description.clone());
+        warn_replaced_description(format_args!("schedule {label:?}"), previous, &description);
+        self
+    }
+
+    /// See [`App::describe_set`].
+    pub fn describe_set(
+        &mut self,
+        set: impl SystemSet,
+        text: impl Into<Cow<'static, str>>,
+    ) -> &mut Self {
+        let description = self.new_description(text);
+        let previous = self
+            .world
+            .get_resource_or_init::<ScheduleDescriptions>()
+            .describe_set(set.intern(), description.clone());
+        warn_replaced_description(format_args!("system set {set:?}"), previous, &description);
+        self
+    }
+
+    fn new_description(&self, text: impl Into<Cow<'static, str>>) -> Description {
+        Description {
+            text: text.into(),
+            described_by: self.building_plugins.last().cloned(),
+        }
+    }
+
     /// See [`App::add_schedule`].
     pub fn add_schedule(&mut self, schedule: Schedule) -> &mut Self {
         let mut schedules = self.world.resource_mut::<Schedules>();
@@ -347,6 +425,18 @@ impl SubApp {
         self
     }
 
+    /// See [`App::add_event_with`].
+    pub fn add_event_with<T>(&mut self, settings: EventSettings) -> &mut Self
+    where
+        T: BufferedEvent,
+    {
+        self.add_event::<T>();
+        self.world
+            .resource_mut::<Events<T>>()
+            .set_settings(settings);
+        self
+    }
+
     /// See [`App::add_plugins`].
     pub fn add_plugins<M>(&mut self, plugins: impl Plugins<M>) -> &mut Self {
         self.run_as_app(|app| plugins.add_to_app(app));
@@ -363,18 +453,70 @@ impl SubApp {
 
     /// See [`App::get_added_plugins`].
     pub fn get_added_plugins<T>(&self) -> Vec<&T>
+    where
+        T: Plugin,
+    {
+        self.iter_plugins_of().collect()
+    }
+
+    /// See [`App::iter_plugins`].
+    pub fn iter_plugins(&self) -> impl Iterator<Item = &dyn Plugin> {
+        self.plugin_registry
+            .iter()
+            .map(|plugin| &**plugin)
+            .filter(|plugin| !plugin.is::<PlaceholderPlugin>())
+    }
+
+    /// See [`App::iter_plugins_of`].
+    pub fn iter_plugins_of<T>(&self) -> impl Iterator<Item = &T>
     where
         T: Plugin,
     {
         self.plugin_registry
             .iter()
-            .filter_map(|p| p.downcast_ref())
-            .collect()
+            .filter_map(|plugin| plugin.downcast_ref())
+    }
+
+    /// See [`App::iter_plugins_mut`].
+    pub fn iter_plugins_mut(&mut self) -> impl Iterator<Item = &mut dyn Plugin> {
+        assert!(
+            self.plugins_state < PluginsState::Finished,
+            "plugins can only be modified before the app starts"
+        );
+        // Plugins shared with a fork can't be modified, they are skipped.
+        self.plugin_registry
+            .iter_mut()
+            .filter_map(Arc::get_mut)
+            .filter(|plugin| !plugin.is::<PlaceholderPlugin>())
     }
 
     /// Returns `true` if there is no plugin in the middle of being built.
     pub(crate) fn is_building_plugins(&self) -> bool {
-        self.plugin_build_depth > 0
+        !self.building_plugins.is_empty()
+    }
+
+    /// Panics if some plugins are still waiting for their required resources.
+    pub(crate) fn assert_no_deferred_plugins(&self) {
+        if self.deferred_plugins.is_empty() {
+            return;
+        }
+        let plugins = self
+            .deferred_plugins
+            .iter()
+            .map(|plugin| {
+                let missing = plugin
+                    .required_resources()
+                    .into_iter()
+                    .filter(|resource| !resource.exists(&self.world))
+                    .map(|resource| resource.name())
+                    .collect::<Vec<_>>();
+                format!("{} (missing {})", plugin.name(), missing.join(", "))
+            })
+            .collect::<Vec<_>>();
+        panic!(
+            "Some plugins were never built because their required resources were never inserted: {}",
+            plugins.join("; ")
+        );
     }
 
     /// Return the state of plugins.
@@ -382,6 +524,7 @@ impl SubApp {
     pub fn plugins_state(&mut self) -> PluginsState {
         match self.plugins_state {
             PluginsState::Adding => {
+                self.run_as_app(App::build_deferred_plugins);
                 let mut state = PluginsState::Ready;
                 let plugins = core::mem::take(&mut self.plugin_registry);
                 self.run_as_app(|app| {
@@ -399,10 +542,28 @@ impl SubApp {
         }
     }
 
+    /// Returns the names of the plugins that are not [ready](Plugin::ready) yet.
+    pub(crate) fn unready_plugins(&mut self) -> Vec<String> {
+        let mut names = Vec::new();
+        let plugins = core::mem::take(&mut self.plugin_registry);
+        self.run_as_app(|app| {
+            names.extend(
+                plugins
+                    .iter()
+                    .filter(|plugin| !plugin.ready(app))
+                    .map(|plugin| plugin.name().to_string()),
+            );
+        });
+        self.plugin_registry = plugins;
+        names
+    }
+
     /// Runs [`Plugin::finish`] for each plugin.
     pub fn finish(&mut self) {
-        // do hokey pokey with a boxed zst plugin (doesn't allocate)
-        let mut hokeypokey: Box<dyn Plugin> = Box::new(crate::HokeyPokey);
+        self.run_as_app(App::build_deferred_plugins);
+        self.assert_no_deferred_plugins();
+        // do hokey pokey with a zst plugin (allocates only the reference counts)
+        let mut hokeypokey = shared_plugin(crate::HokeyPokey);
         for i in 0..self.plugin_registry.len() {
             core::mem::swap(&mut self.plugin_registry[i], &mut hokeypokey);
             self.run_as_app(|app| {
@@ -415,8 +576,8 @@ impl SubApp {
 
     /// Runs [`Plugin::cleanup`] for each plugin.
     pub fn cleanup(&mut self) {
-        // do hokey pokey with a boxed zst plugin (doesn't allocate)
-        let mut hokeypokey: Box<dyn Plugin> = Box::new(crate::HokeyPokey);
+        // do hokey pokey with a zst plugin (allocates only the reference counts)
+        let mut hokeypokey = shared_plugin(crate::HokeyPokey);
         for i in 0..self.plugin_registry.len() {
             core::mem::swap(&mut self.plugin_registry[i], &mut hokeypokey);
             self.run_as_app(|app| {
@@ -427,6 +588,19 @@ impl SubApp {
         self.plugins_state = PluginsState::Cleaned;
     }
 
+    /// Runs [`Plugin::on_exit`] for each plugin.
+    pub fn run_exit_hooks(&mut self) {
+        // do hokey pokey with a zst plugin (allocates only the reference counts)
+        let mut hokeypokey = shared_plugin(crate::HokeyPokey);
+        for i in 0..self.plugin_registry.len() {
+            core::mem::swap(&mut self.plugin_registry[i], &mut hokeypokey);
+            self.run_as_app(|app| {
+                hokeypokey.on_exit(app);
+            });
+            core::mem::swap(&mut self.plugin_registry[i], &mut hokeypokey);
+        }
+    }
+
     /// See [`App::register_type`].
     #[cfg(feature = "bevy_reflect")]
     pub fn register_type<T: bevy_reflect::GetTypeRegistration>(&mut self) -> &mut Self {
@@ -463,7 +637,7 @@ impl SubApp {
     #[cfg(feature = "reflect_functions")]
     pub fn register_function_with_name<F, Marker>(
         &mut self,
-        name: impl Into<alloc::borrow::Cow<'static, str>>,
+        name: impl Into<Cow<'static, str>>,
         function: F,
     ) -> &mut Self
     where
@@ -482,11 +656,29 @@ pub struct SubApps {
     pub main: SubApp,
     /// Other, labeled sub-apps.
     pub sub_apps: HashMap<InternedAppLabel, SubApp>,
+    /// The labels of the sub-apps, in the order they were inserted.
+    insertion_order: Vec<InternedAppLabel>,
+    /// The constraints added with [`SubApps::configure_order`].
+    constraints: Vec<SubAppOrder>,
+    /// The order used by [`SubApps::update`], recomputed when it is outdated.
+    update_order: Vec<InternedAppLabel>,
 }
 
 impl SubApps {
+    /// Creates a collection with only the `main` sub-app.
+    pub(crate) fn new(main: SubApp) -> Self {
+        Self {
+            main,
+            sub_apps: HashMap::default(),
+            insertion_order: Vec::new(),
+            constraints: Vec::new(),
+            update_order: Vec::new(),
+        }
+    }
+
     /// Calls [`update`](SubApp::update) for the main sub-app, and then calls
-    /// [`extract`](SubApp::extract) and [`update`](SubApp::update) for the rest.
+    /// [`extract`](SubApp::extract) and [`update`](SubApp::update) for the rest, in their
+    /// [order](SubApps::order).
     pub fn update(&mut self) {
         #[cfg(feature = "trace")]
         let _bevy_update_span = info_span!("update").entered();
@@ -495,9 +687,21 @@ impl SubApps {
             let _bevy_frame_update_span = info_span!("main app").entered();
             self.main.run_default_schedule();
         }
-        for (_label, sub_app) in self.sub_apps.iter_mut() {
+
+        let is_outdated = self.update_order.len() != self.sub_apps.len()
+            || !self
+                .update_order
+                .iter()
+                .all(|label| self.sub_apps.contains_key(label));
+        if is_outdated {
+            self.update_order = self.order();
+        }
+        for label in &self.update_order {
+            let Some(sub_app) = self.sub_apps.get_mut(label) else {
+                continue;
+            };
             #[cfg(feature = "trace")]
-            let _sub_app_span = info_span!("sub app", name = ?_label).entered();
+            let _sub_app_span = info_span!("sub app", name = ?label).entered();
             sub_app.extract(&mut self.main.world);
             sub_app.update();
         }
@@ -507,12 +711,19 @@ impl SubApps {
 
     /// Returns an iterator over the sub-apps (starting with the main one).
     pub fn iter(&self) -> impl Iterator<Item = &SubApp> + '_ {
-        core::iter::once(&self.main).chain(self.sub_apps.values())
+        core::iter::once(&self.main).chain(
+            self.order()
+                .into_iter()
+                .filter_map(|label| self.sub_apps.get(&label)),
+        )
     }
 
     /// Returns a mutable iterator over the sub-apps (starting with the main one).
     pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut SubApp> + '_ {
-        core::iter::once(&mut self.main).chain(self.sub_apps.values_mut())
+        let order = self.order();
+        let mut sub_apps = self.sub_apps.iter_mut().collect::<Vec<_>>();
+        sub_apps.sort_by_key(|(label, _)| order.iter().position(|ordered| ordered == *label));
+        core::iter::once(&mut self.main).chain(sub_apps.into_iter().map(|(_, sub_app)| sub_app))
     }
 
     /// Extract data from the main world into the [`SubApp`] with the given label and perform an update if it exists.
@@ -522,4 +733,152 @@ impl SubApps {
             sub_app.update();
         }
     }
+
+    /// Inserts a [`SubApp`] with the given label, replacing and returning the previous one.
+    ///
+    /// A sub-app replacing another one keeps its place in the [order](SubApps::order).
+    pub fn insert(&mut self, label: InternedAppLabel, sub_app: SubApp) -> Option<SubApp> {
+        if !self.insertion_order.contains(&label) {
+            self.insertion_order.push(label);
+        }
+        self.sub_apps.insert(label, sub_app)
+    }
+
+    /// Removes the [`SubApp`] with the given label, if it exists.
+    pub fn remove(&mut self, label: InternedAppLabel) -> Option<SubApp> {
+        self.insertion_order.retain(|inserted| *inserted != label);
+        self.sub_apps.remove(&label)
+    }
+
+    /// Adds a constraint on the order the sub-apps are extracted and updated in.
+    ///
+    /// See [`App::configure_sub_app_order`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the constraint would make the sub-apps ordering cyclic.
+    pub fn configure_order(&mut self, order: SubAppOrder) {
+        if order.first == order.then {
+            panic!(
+                "Sub-app {:?} can't be ordered relative to itself",
+                order.first
+            );
+        }
+        self.constraints.push(order);
+        let mut labels = Vec::new();
+        for constraint in &self.constraints {
+            for label in [constraint.first, constraint.then] {
+                if !labels.contains(&label) {
+                    labels.push(label);
+                }
+            }
+        }
+        if sort_topologically(&labels, &self.constraints).len() != labels.len() {
+            self.constraints.pop();
+            panic!(
+                "Ordering sub-app {:?} before {:?} creates a cycle",
+                order.first, order.then
+            );
+        }
+        self.update_order.clear();
+    }
+
+    /// Returns the labels of the sub-apps other than the main one, in the order they are
+    /// extracted and updated.
+    ///
+    /// The sub-apps are ordered by the constraints added with [`SubApps::configure_order`], and
+    /// otherwise by the order they were inserted in.
+    pub fn order(&self) -> Vec<InternedAppLabel> {
+        let mut labels = self
+            .insertion_order
+            .iter()
+            .copied()
+            .filter(|label| self.sub_apps.contains_key(label))
+            .collect::<Vec<_>>();
+        // Sub-apps inserted directly in `sub_apps` come last, sorted by name to stay deterministic.
+        let mut others = self
+            .sub_apps
+            .keys()
+            .copied()
+            .filter(|label| !self.insertion_order.contains(label))
+            .collect::<Vec<_>>();
+        others.sort_by_cached_key(|label| format!("{label:?}"));
+        labels.extend(others);
+
+        sort_topologically(&labels, &self.constraints)
+    }
+}
+
+/// Sorts `labels` so that they respect the `constraints` between them, keeping their order
+/// otherwise. Labels that are part of a cycle are left out.
+fn sort_topologically(
+    labels: &[InternedAppLabel],
+    constraints: &[SubAppOrder],
+) -> Vec<InternedAppLabel> {
+    let constraints = constraints
+        .iter()
+        .filter(|order| labels.contains(&order.first) && labels.contains(&order.then))
+        .collect::<Vec<_>>();
+    let mut sorted = Vec::with_capacity(labels.len());
+    while sorted.len() < labels.len() {
+        let next = labels.iter().find(|&&label| {
+            !sorted.contains(&label)
+                && constraints
+                    .iter()
+                    .all(|order| order.then != label || sorted.contains(&order.first))
+        });
+        match next {
+            Some(&label) => sorted.push(label),
+            None => break,
+        }
+    }
+    sorted
+}
+
+/// A constraint on the order of two sub-apps, created with [`SubAppOrderExt`] and added with
+/// [`App::configure_sub_app_order`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SubAppOrder {
+    /// The sub-app that is extracted and updated first.
+    pub first: InternedAppLabel,
+    /// The sub-app that is extracted and updated after [`first`](Self::first).
+    pub then: InternedAppLabel,
+}
+
+/// Creates [`SubAppOrder`] constraints from [`AppLabel`]s.
+pub trait SubAppOrderExt: AppLabel + Sized {
+    /// Orders this sub-app before `other`.
+    fn before(self, other: impl AppLabel) -> SubAppOrder {
+        SubAppOrder {
+            first: self.intern(),
+            then: other.intern(),
+        }
+    }
+
+    /// Orders this sub-app after `other`.
+    fn after(self, other: impl AppLabel) -> SubAppOrder {
+        SubAppOrder {
+            first: other.intern(),
+            then: self.intern(),
+        }
+    }
+}
+
+impl<T: AppLabel> SubAppOrderExt for T {}
+
+/// Warns when a description replaces a different one, naming who gave each.
+fn warn_replaced_description(
+    described: Arguments,
+    previous: Option<Description>,
+    description: &Description,
+) {
+    if let Some(previous) = previous
+        && previous.text != description.text
+    {
+        warn!(
+            "The description of the {described} given by {} replaces the one given by {}",
+            by_plugin(&description.described_by),
+            by_plugin(&previous.described_by),
+        );
+    }
 }
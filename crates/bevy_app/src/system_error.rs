@@ -0,0 +1,234 @@
+use crate::{shutdown::start_shutdown, App, AppExit, AppExitWriter, Main, ShutdownReason};
+use alloc::format;
+use bevy_ecs::{
+    error::{BevyError, SystemErrorQueue},
+    event::{BufferedEvent, EventWriter},
+    resource::Resource,
+    schedule::{InternedScheduleLabel, IntoScheduleConfigs},
+    system::Res,
+    world::World,
+};
+use bevy_utils::prelude::DebugName;
+use log::{error, warn};
+
+/// An error returned by a system of the main world, once handled by the app's
+/// [`SystemErrorHandler`].
+///
+/// Written as an event by the [`write_system_error_events`] handler, so that it can be displayed
+/// to the user, for example in a UI.
+#[derive(BufferedEvent, Debug)]
+pub struct SystemErrorEvent {
+    /// The error returned by the system.
+    pub error: BevyError,
+    /// The name of the system that failed.
+    pub system: DebugName,
+    /// The label of the schedule that ran the system.
+    pub schedule: InternedScheduleLabel,
+}
+
+/// What the app does with an error returned by a system, as decided by its
+/// [`SystemErrorHandler`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SystemErrorAction {
+    /// Ignores the error.
+    Ignore,
+    /// Logs the error at the `error` level.
+    Log,
+    /// Writes the error as a [`SystemErrorEvent`].
+    Event,
+    /// Logs the error and writes [`AppExit::error`] with the [`ShutdownReason::FatalError`]
+    /// reason.
+    Exit,
+}
+
+/// Decides what the app does with the errors returned by the systems of its main world.
+///
+/// Set with [`App::set_system_error_handler`]. The handler is looked up every time errors are
+/// handled, so replacing this resource takes effect on the next error, without rebuilding any
+/// schedule.
+#[derive(Resource, Clone, Copy)]
+pub struct SystemErrorHandler(pub fn(&SystemErrorEvent) -> SystemErrorAction);
+
+/// System error handler that logs every error.
+pub fn log_system_errors(_: &SystemErrorEvent) -> SystemErrorAction {
+    SystemErrorAction::Log
+}
+
+/// System error handler that writes every error as a [`SystemErrorEvent`].
+pub fn write_system_error_events(_: &SystemErrorEvent) -> SystemErrorAction {
+    SystemErrorAction::Event
+}
+
+/// System error handler that exits the app on the first error.
+pub fn exit_on_system_error(_: &SystemErrorEvent) -> SystemErrorAction {
+    SystemErrorAction::Exit
+}
+
+impl App {
+    /// Sets the handler deciding what to do with the errors returned by the systems of the main
+    /// world: log them, write them as [`SystemErrorEvent`]s or exit the app.
+    ///
+    /// This replaces the [default error handler](App::set_error_handler) for these errors only.
+    /// The errors of run conditions, commands, observers and the systems of sub-apps are still
+    /// passed to it. The errors are handled at the end of each frame, after the [`Main`]
+    /// schedule and before the app starts [shutting down](crate::OnShutdown), and right after
+    /// the [`OnShutdown`](crate::OnShutdown) and [`OnAppExit`](crate::OnAppExit) schedules.
+    ///
+    /// Until then, the errors are kept in the [`SystemErrorQueue`], which drops the errors over
+    /// its [limit](SystemErrorQueue::with_max_len), for example when the systems are run outside
+    /// of the [`Main`] schedule.
+    ///
+    /// Unlike the default error handler, this one can be replaced at any time, by calling this
+    /// method again or by modifying the [`SystemErrorHandler`] resource.
+    ///
+    /// ```
+    /// # use bevy_app::{prelude::*, write_system_error_events, SystemErrorEvent};
+    /// # use bevy_ecs::prelude::*;
+    /// fn load_level() -> Result {
+    ///     Err("The level file is missing".into())
+    /// }
+    ///
+    /// fn show_errors(mut errors: EventReader<SystemErrorEvent>) {
+    ///     for error in errors.read() {
+    ///         // Display `error.error` in a popup.
+    ///     }
+    /// }
+    ///
+    /// App::new()
+    ///     .set_system_error_handler(write_system_error_events)
+    ///     .add_systems(Update, (load_level, show_errors.after(load_level)))
+    ///     .update();
+    /// ```
+    pub fn set_system_error_handler(
+        &mut self,
+        handler: fn(&SystemErrorEvent) -> SystemErrorAction,
+    ) -> &mut Self {
+        if !self.world().contains_resource::<SystemErrorQueue>() {
+            self.add_event::<SystemErrorEvent>()
+                .init_resource::<SystemErrorQueue>()
+                .add_systems(
+                    Main,
+                    handle_system_errors
+                        .after(Main::run_main)
+                        .before(start_shutdown),
+                );
+        }
+        self.insert_resource(SystemErrorHandler(handler))
+    }
+}
+
+/// Passes the errors collected since the last frame to the [`SystemErrorHandler`].
+fn handle_system_errors(
+    queue: Res<SystemErrorQueue>,
+    handler: Res<SystemErrorHandler>,
+    mut events: EventWriter<SystemErrorEvent>,
+    mut exit: AppExitWriter,
+) {
+    let dropped = queue.take_dropped();
+    if dropped > 0 {
+        warn!("{dropped} system errors were dropped, as the system error queue was full");
+    }
+    for queued in queue.drain() {
+        let event = SystemErrorEvent {
+            error: queued.error,
+            system: queued.name,
+            schedule: queued.schedule,
+        };
+        match (handler.0)(&event) {
+            SystemErrorAction::Ignore => {}
+            SystemErrorAction::Log => error!(
+                "Encountered an error in system `{}` of schedule {:?}: {}",
+                event.system, event.schedule, event.error
+            ),
+            SystemErrorAction::Event => {
+                events.write(event);
+            }
+            SystemErrorAction::Exit => {
+                let message = format!("System `{}` failed: {}", event.system, event.error);
+                error!("{message}");
+                exit.write_with_message(AppExit::error(), ShutdownReason::FatalError, message);
+            }
+        }
+    }
+}
+
+/// Handles the errors of the systems that ran since the errors were last handled, for the
+/// schedules running after [`Main`], like [`OnShutdown`](crate::OnShutdown).
+pub(crate) fn flush_system_errors(world: &mut World) {
+    if world.contains_resource::<SystemErrorHandler>() {
+        let _ = world.run_system_cached(handle_system_errors);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        exit_on_system_error, write_system_error_events, App, AppExit, OnAppExit, OnShutdown,
+        ShutdownReason, SystemErrorAction, SystemErrorEvent, SystemErrorHandler, Update,
+    };
+    use alloc::{string::ToString, vec::Vec};
+    use bevy_ecs::{event::Events, prelude::*, schedule::ScheduleLabel};
+
+    fn fail() -> Result {
+        Err("Failed".into())
+    }
+
+    fn app(handler: fn(&SystemErrorEvent) -> SystemErrorAction) -> App {
+        let mut app = App::new();
+        app.set_system_error_handler(handler)
+            .add_systems(Update, fail);
+        app
+    }
+
+    #[test]
+    fn errors_are_written_as_events() {
+        let mut app = app(write_system_error_events);
+        app.update();
+
+        let events = app.world().resource::<Events<SystemErrorEvent>>();
+        let event = events.iter_current_update_events().next().unwrap();
+        assert!(event.error.to_string().starts_with("Failed"));
+        assert!(event.system.as_string().ends_with("fail"));
+        assert_eq!(event.schedule, Update.intern());
+        assert!(app.should_exit().is_none());
+    }
+
+    #[test]
+    fn errors_can_exit_the_app() {
+        let mut app = app(exit_on_system_error);
+        app.update();
+
+        assert_eq!(app.should_exit(), Some(AppExit::error()));
+        let status = app.exit_status().unwrap();
+        assert_eq!(status.reason, ShutdownReason::FatalError);
+    }
+
+    #[test]
+    fn handler_can_be_replaced_after_startup() {
+        let mut app = app(|_| SystemErrorAction::Ignore);
+        app.update();
+        assert_eq!(app.world().resource::<Events<SystemErrorEvent>>().len(), 0);
+
+        app.world_mut().resource_mut::<SystemErrorHandler>().0 = write_system_error_events;
+        app.update();
+        assert_eq!(app.world().resource::<Events<SystemErrorEvent>>().len(), 1);
+    }
+
+    #[test]
+    fn errors_of_the_exit_schedules_are_handled() {
+        let mut app = App::new();
+        app.set_system_error_handler(write_system_error_events)
+            .add_systems(OnShutdown, fail)
+            .add_systems(OnAppExit, fail);
+        app.world_mut().write_event(AppExit::Success);
+        app.update();
+        app.run_exit_hooks();
+
+        let events = app.world().resource::<Events<SystemErrorEvent>>();
+        let schedules: Vec<_> = events
+            .iter_current_update_events()
+            .map(|event| event.schedule)
+            .collect();
+        assert_eq!(schedules, [OnShutdown.intern(), OnAppExit.intern()]);
+    }
+}
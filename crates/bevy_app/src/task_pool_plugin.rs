@@ -1,14 +1,18 @@
-use crate::{App, Plugin};
+use crate::{App, Last, Plugin};
 
-use alloc::string::ToString;
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use bevy_ecs::{resource::Resource, system::ResMut};
 use bevy_platform::sync::Arc;
-use bevy_tasks::{AsyncComputeTaskPool, ComputeTaskPool, IoTaskPool, TaskPoolBuilder};
+use bevy_tasks::{AsyncComputeTaskPool, ComputeTaskPool, IoTaskPool, TaskPool, TaskPoolBuilder};
 use core::fmt::Debug;
 use log::trace;
 
 cfg_if::cfg_if! {
     if #[cfg(not(all(target_arch = "wasm32", feature = "web")))] {
-        use {crate::Last, bevy_tasks::tick_global_task_pools_on_main_thread};
+        use bevy_tasks::tick_global_task_pools_on_main_thread;
         use bevy_ecs::system::NonSendMarker;
 
         /// A system used to check and advanced our task pools.
@@ -22,6 +26,10 @@ cfg_if::cfg_if! {
 }
 
 /// Setup of default task pools: [`AsyncComputeTaskPool`], [`ComputeTaskPool`], [`IoTaskPool`].
+///
+/// The threads of the pools are named after their pool and index, such as `bevy-compute-3`, and
+/// the [`TaskPoolInfo`] resource lists them. When a thread of a pool panics, the pool and the
+/// [name of the task](TaskPool::spawn_named) are logged before the panic message.
 #[derive(Default)]
 pub struct TaskPoolPlugin {
     /// Options for the [`TaskPool`](bevy_tasks::TaskPool) created at application start.
@@ -29,12 +37,185 @@ pub struct TaskPoolPlugin {
 }
 
 impl Plugin for TaskPoolPlugin {
-    fn build(&self, _app: &mut App) {
+    fn build(&self, app: &mut App) {
         // Setup the default bevy task pools
         self.task_pool_options.create_default_pools();
 
+        #[cfg(all(feature = "std", not(all(target_arch = "wasm32", feature = "web"))))]
+        set_task_pool_panic_hook();
+
+        let mut info = TaskPoolInfo::default();
+        info.update();
+        app.insert_resource(info)
+            .add_systems(Last, TaskPoolInfo::update_system);
+
         #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
-        _app.add_systems(Last, tick_global_task_pools);
+        app.add_systems(Last, tick_global_task_pools);
+    }
+}
+
+/// The default task pools and the [dedicated threads](spawn_app_thread) of the app, updated in
+/// [`Last`], for example to show them in a diagnostics overlay.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq)]
+pub struct TaskPoolInfo {
+    /// The default task pools that are initialized.
+    pub pools: Vec<TaskPoolStatus>,
+    /// The names of the running threads spawned with [`spawn_app_thread`].
+    pub dedicated_threads: Vec<String>,
+}
+
+/// The status of a task pool, see [`TaskPoolInfo`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaskPoolStatus {
+    /// The name of the pool.
+    pub name: String,
+    /// The names of the threads of the pool.
+    pub threads: Vec<String>,
+    /// The number of tasks spawned on the pool that didn't complete yet, see
+    /// [`TaskPool::pending_tasks`].
+    pub pending_tasks: usize,
+}
+
+impl TaskPoolInfo {
+    /// Returns the status of the pool named `name`.
+    pub fn pool(&self, name: &str) -> Option<&TaskPoolStatus> {
+        self.pools.iter().find(|pool| pool.name == name)
+    }
+
+    /// Updates the lists of threads and the number of pending tasks.
+    pub fn update(&mut self) {
+        let pools: [(&str, Option<&TaskPool>); 3] = [
+            ("compute", ComputeTaskPool::try_get().map(|pool| &**pool)),
+            (
+                "async compute",
+                AsyncComputeTaskPool::try_get().map(|pool| &**pool),
+            ),
+            ("io", IoTaskPool::try_get().map(|pool| &**pool)),
+        ];
+        let pools = pools
+            .into_iter()
+            .filter_map(|(default_name, pool)| Some((default_name, pool?)));
+
+        for (index, (default_name, pool)) in pools.enumerate() {
+            let name = pool.name().unwrap_or(default_name);
+            match self.pools.get_mut(index) {
+                Some(status) if status.name == name => status.pending_tasks = pool.pending_tasks(),
+                _ => {
+                    self.pools.truncate(index);
+                    self.pools.push(TaskPoolStatus {
+                        name: name.to_string(),
+                        threads: pool.thread_names().map(ToString::to_string).collect(),
+                        pending_tasks: pool.pending_tasks(),
+                    });
+                }
+            }
+        }
+
+        #[cfg(all(feature = "std", not(all(target_arch = "wasm32", feature = "web"))))]
+        {
+            let threads = APP_THREADS
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner);
+            if self.dedicated_threads != *threads {
+                self.dedicated_threads.clone_from(&threads);
+            }
+        }
+    }
+
+    /// Updates the [`TaskPoolInfo`] resource.
+    pub fn update_system(mut info: ResMut<Self>) {
+        info.update();
+    }
+}
+
+cfg_if::cfg_if! {
+    if #[cfg(all(feature = "std", not(all(target_arch = "wasm32", feature = "web"))))] {
+        use alloc::{boxed::Box, format};
+        use bevy_platform::sync::Mutex;
+        use core::cell::Cell;
+        use std::thread::{self, JoinHandle};
+
+        /// The names of the running threads spawned with [`spawn_app_thread`].
+        static APP_THREADS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+        std::thread_local! {
+            static IS_APP_THREAD: Cell<bool> = const { Cell::new(false) };
+        }
+
+        /// Removes the thread from [`APP_THREADS`] when it ends.
+        struct AppThreadGuard(String);
+
+        impl Drop for AppThreadGuard {
+            fn drop(&mut self) {
+                let mut threads = APP_THREADS.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+                if let Some(index) = threads.iter().position(|thread| *thread == self.0) {
+                    threads.remove(index);
+                }
+            }
+        }
+
+        /// Spawns a thread named `name` dedicated to a part of the app, such as a sub-app running on
+        /// its own thread, like the task pool threads named by the [`TaskPoolPlugin`].
+        ///
+        /// The thread is listed in [`TaskPoolInfo::dedicated_threads`] while it runs, and its
+        /// panics are attributed to it.
+        ///
+        /// # Panics
+        ///
+        /// Panics if the OS fails to create the thread.
+        pub fn spawn_app_thread<T: Send + 'static>(
+            name: impl Into<String>,
+            f: impl FnOnce() -> T + Send + 'static,
+        ) -> JoinHandle<T> {
+            let name = name.into();
+            APP_THREADS
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner)
+                .push(name.clone());
+            let guard = AppThreadGuard(name.clone());
+            thread::Builder::new()
+                .name(name)
+                .spawn(move || {
+                    let _guard = guard;
+                    IS_APP_THREAD.with(|is_app_thread| is_app_thread.set(true));
+                    f()
+                })
+                .expect("Failed to spawn thread.")
+        }
+
+        /// Describes the task pool, thread and task running on the current thread, which is logged
+        /// when it panics.
+        ///
+        /// Returns `None` if the current thread is neither a thread of a named [`TaskPool`] nor a
+        /// thread spawned with [`spawn_app_thread`].
+        pub fn task_panic_context() -> Option<String> {
+            let thread = thread::current();
+            let thread = thread.name().unwrap_or("<unnamed>");
+            let task = TaskPool::current_task_name()
+                .map(|task| format!("task `{task}` on "))
+                .unwrap_or_default();
+            if let Some(pool) = TaskPool::current_pool_name() {
+                Some(format!("panic in {task}thread `{thread}` of the `{pool}` task pool"))
+            } else if IS_APP_THREAD.with(Cell::get) {
+                Some(format!("panic in {task}dedicated app thread `{thread}`"))
+            } else {
+                None
+            }
+        }
+
+        /// Logs the [`task_panic_context`] before calling the previous panic hook.
+        fn set_task_pool_panic_hook() {
+            static SET_HOOK: std::sync::Once = std::sync::Once::new();
+            SET_HOOK.call_once(|| {
+                let previous_hook = std::panic::take_hook();
+                std::panic::set_hook(Box::new(move |info| {
+                    if let Some(context) = task_panic_context() {
+                        log::error!("{context}");
+                    }
+                    previous_hook(info);
+                }));
+            });
+        }
     }
 }
 
@@ -88,6 +269,27 @@ impl TaskPoolThreadAssignmentPolicy {
         // <= 2 threads.
         desired.clamp(self.min_threads, self.max_threads)
     }
+
+    /// Creates the builder of a pool named `name`, whose threads are named `<name>-<index>`.
+    fn builder(&self, name: &str, num_threads: usize) -> TaskPoolBuilder {
+        let builder = TaskPoolBuilder::default()
+            .num_threads(num_threads)
+            .name(name.to_string());
+
+        #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+        let builder = {
+            let mut builder = builder;
+            if let Some(f) = self.on_thread_spawn.clone() {
+                builder = builder.on_thread_spawn(move || f());
+            }
+            if let Some(f) = self.on_thread_destroy.clone() {
+                builder = builder.on_thread_destroy(move || f());
+            }
+            builder
+        };
+
+        builder
+    }
 }
 
 /// Helper for configuring and creating the default task pools. For end-users who want full control,
@@ -173,25 +375,7 @@ impl TaskPoolOptions {
             trace!("IO Threads: {io_threads}");
             remaining_threads = remaining_threads.saturating_sub(io_threads);
 
-            IoTaskPool::get_or_init(|| {
-                let builder = TaskPoolBuilder::default()
-                    .num_threads(io_threads)
-                    .thread_name("IO Task Pool".to_string());
-
-                #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
-                let builder = {
-                    let mut builder = builder;
-                    if let Some(f) = self.io.on_thread_spawn.clone() {
-                        builder = builder.on_thread_spawn(move || f());
-                    }
-                    if let Some(f) = self.io.on_thread_destroy.clone() {
-                        builder = builder.on_thread_destroy(move || f());
-                    }
-                    builder
-                };
-
-                builder.build()
-            });
+            IoTaskPool::get_or_init(|| self.io.builder("bevy-io", io_threads).build());
         }
 
         {
@@ -204,23 +388,9 @@ impl TaskPoolOptions {
             remaining_threads = remaining_threads.saturating_sub(async_compute_threads);
 
             AsyncComputeTaskPool::get_or_init(|| {
-                let builder = TaskPoolBuilder::default()
-                    .num_threads(async_compute_threads)
-                    .thread_name("Async Compute Task Pool".to_string());
-
-                #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
-                let builder = {
-                    let mut builder = builder;
-                    if let Some(f) = self.async_compute.on_thread_spawn.clone() {
-                        builder = builder.on_thread_spawn(move || f());
-                    }
-                    if let Some(f) = self.async_compute.on_thread_destroy.clone() {
-                        builder = builder.on_thread_destroy(move || f());
-                    }
-                    builder
-                };
-
-                builder.build()
+                self.async_compute
+                    .builder("bevy-async-compute", async_compute_threads)
+                    .build()
             });
         }
 
@@ -234,23 +404,9 @@ impl TaskPoolOptions {
             trace!("Compute Threads: {compute_threads}");
 
             ComputeTaskPool::get_or_init(|| {
-                let builder = TaskPoolBuilder::default()
-                    .num_threads(compute_threads)
-                    .thread_name("Compute Task Pool".to_string());
-
-                #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
-                let builder = {
-                    let mut builder = builder;
-                    if let Some(f) = self.compute.on_thread_spawn.clone() {
-                        builder = builder.on_thread_spawn(move || f());
-                    }
-                    if let Some(f) = self.compute.on_thread_destroy.clone() {
-                        builder = builder.on_thread_destroy(move || f());
-                    }
-                    builder
-                };
-
-                builder.build()
+                self.compute
+                    .builder("bevy-compute", compute_threads)
+                    .build()
             });
         }
     }
@@ -293,4 +449,93 @@ mod tests {
         compute_rx.try_recv().unwrap();
         io_rx.try_recv().unwrap();
     }
+
+    #[test]
+    fn pools_are_named() {
+        let options = TaskPoolOptions::default();
+        let pool = options.compute.builder("bevy-compute", 2).build();
+
+        let thread =
+            bevy_tasks::block_on(pool.spawn(async { thread::current().name().map(String::from) }));
+        assert!(thread.unwrap().starts_with("bevy-compute-"));
+        assert_eq!(
+            pool.thread_names().collect::<Vec<_>>(),
+            ["bevy-compute-0", "bevy-compute-1"]
+        );
+    }
+
+    #[test]
+    fn panic_context_names_pool_and_task() {
+        let pool = TaskPoolOptions::default().io.builder("bevy-io", 1).build();
+
+        let context =
+            bevy_tasks::block_on(pool.spawn_named("load-level", async { task_panic_context() }));
+        assert_eq!(
+            context.as_deref(),
+            Some("panic in task `load-level` on thread `bevy-io-0` of the `bevy-io` task pool")
+        );
+
+        let context = bevy_tasks::block_on(pool.spawn(async { task_panic_context() }));
+        assert_eq!(
+            context.as_deref(),
+            Some("panic in thread `bevy-io-0` of the `bevy-io` task pool")
+        );
+
+        // Panics on other threads are left to the previous panic hook.
+        assert_eq!(task_panic_context(), None);
+    }
+
+    #[test]
+    fn info_lists_pools() {
+        let mut app = App::new();
+        app.add_plugins(TaskPoolPlugin::default());
+        app.update();
+
+        let info = app.world().resource::<TaskPoolInfo>();
+        assert_eq!(info.pools.len(), 3);
+        for (status, pool) in info.pools.iter().zip([
+            &**ComputeTaskPool::get(),
+            &**AsyncComputeTaskPool::get(),
+            &**IoTaskPool::get(),
+        ]) {
+            assert_eq!(status.threads.len(), pool.thread_num());
+            assert_eq!(status.threads, pool.thread_names().collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn dedicated_app_threads() {
+        let (started_tx, started_rx) = crossbeam_channel::bounded(0);
+        let (stop_tx, stop_rx) = crossbeam_channel::bounded::<()>(0);
+        let thread = spawn_app_thread("bevy-test-app", move || {
+            started_tx
+                .send((
+                    thread::current().name().map(String::from),
+                    task_panic_context(),
+                ))
+                .unwrap();
+            stop_rx.recv().unwrap();
+        });
+
+        let (name, context) = started_rx.recv().unwrap();
+        assert_eq!(name.as_deref(), Some("bevy-test-app"));
+        assert_eq!(
+            context.as_deref(),
+            Some("panic in dedicated app thread `bevy-test-app`")
+        );
+        let mut info = TaskPoolInfo::default();
+        info.update();
+        assert!(info
+            .dedicated_threads
+            .iter()
+            .any(|name| name == "bevy-test-app"));
+
+        stop_tx.send(()).unwrap();
+        thread.join().unwrap();
+        info.update();
+        assert!(!info
+            .dedicated_threads
+            .iter()
+            .any(|name| name == "bevy-test-app"));
+    }
 }
@@ -1,9 +1,15 @@
-use core::sync::atomic::{AtomicBool, Ordering};
+use core::{
+    sync::atomic::{AtomicBool, Ordering},
+    time::Duration,
+};
 
-use bevy_ecs::event::EventWriter;
-
-use crate::{App, AppExit, Plugin, Update};
+use crate::{App, AppExit, AppExitWriter, Plugin, ShutdownReason, Update};
+use bevy_ecs::{
+    resource::Resource,
+    system::{Commands, Local, Res},
+};
 
+#[cfg(not(target_arch = "wasm32"))]
 pub use ctrlc;
 
 /// Indicates that all [`App`]'s should exit.
@@ -11,6 +17,12 @@ static SHOULD_EXIT: AtomicBool = AtomicBool::new(false);
 
 /// Gracefully handles `Ctrl+C` by emitting a [`AppExit`] event. This plugin is part of the `DefaultPlugins`.
 ///
+/// Instead of killing the process, `Ctrl+C` makes the app finish its current update, run
+/// [`OnShutdown`](crate::OnShutdown) and [`OnAppExit`](crate::OnAppExit), and call
+/// [`Plugin::on_exit`] before its runner returns. If this takes longer than the
+/// [`CtrlCExitTimeout`], the process is terminated. On the web, closing the page
+/// (`beforeunload`) is handled the same way, as far as the browser lets the app run.
+///
 /// ```no_run
 /// # use bevy_app::{App, NoopPluginGroup as MinimalPlugins, PluginGroup, TerminalCtrlCHandlerPlugin};
 /// fn main() {
@@ -41,33 +53,138 @@ static SHOULD_EXIT: AtomicBool = AtomicBool::new(false);
 #[derive(Default)]
 pub struct TerminalCtrlCHandlerPlugin;
 
+/// How long the app has to exit after `Ctrl+C` before the [`TerminalCtrlCHandlerPlugin`]
+/// terminates the process, with the exit code 130.
+///
+/// This keeps a hanging [`OnShutdown`](crate::OnShutdown) or [`OnAppExit`](crate::OnAppExit)
+/// system from keeping the process alive. `None` waits forever. Defaults to 5 seconds.
+///
+/// Has no effect on the web, where the browser decides when the page is unloaded.
+#[derive(Resource, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CtrlCExitTimeout(pub Option<Duration>);
+
+impl Default for CtrlCExitTimeout {
+    fn default() -> Self {
+        Self(Some(Duration::from_secs(5)))
+    }
+}
+
 impl TerminalCtrlCHandlerPlugin {
     /// Sends the [`AppExit`] event to all apps using this plugin to make them gracefully exit.
     pub fn gracefully_exit() {
         SHOULD_EXIT.store(true, Ordering::Relaxed);
     }
 
-    /// Sends a [`AppExit`] event when the user presses `Ctrl+C` on the terminal.
-    pub fn exit_on_flag(mut events: EventWriter<AppExit>) {
-        if SHOULD_EXIT.load(Ordering::Relaxed) {
-            events.write(AppExit::from_code(130));
+    /// Clears the flag set by [`gracefully_exit`](Self::gracefully_exit), so that it doesn't
+    /// leak into the other tests of the process.
+    #[cfg(test)]
+    pub(crate) fn reset_exit_flag() {
+        SHOULD_EXIT.store(false, Ordering::Relaxed);
+    }
+
+    /// Sends a [`AppExit`] event when the user presses `Ctrl+C` on the terminal, with the
+    /// [`ShutdownReason::Signal`] reason, and starts the [`CtrlCExitTimeout`].
+    pub fn exit_on_flag(
+        mut exit: AppExitWriter,
+        timeout: Option<Res<CtrlCExitTimeout>>,
+        mut commands: Commands,
+        mut exiting: Local<bool>,
+    ) {
+        if !SHOULD_EXIT.load(Ordering::Relaxed) {
+            return;
+        }
+        exit.write_with_reason(AppExit::from_code(130), ShutdownReason::Signal);
+        if core::mem::replace(&mut *exiting, true) {
+            return;
+        }
+
+        let timeout = timeout
+            .map_or(CtrlCExitTimeout::default(), |timeout| *timeout)
+            .0;
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(timeout) = timeout {
+            commands.insert_resource(ExitWatchdog::start(timeout));
         }
+        #[cfg(target_arch = "wasm32")]
+        let _ = (timeout, commands);
     }
 }
 
 impl Plugin for TerminalCtrlCHandlerPlugin {
     fn build(&self, app: &mut App) {
-        let result = ctrlc::try_set_handler(move || {
-            Self::gracefully_exit();
-        });
-        match result {
-            Ok(()) => {}
-            Err(ctrlc::Error::MultipleHandlers) => {
-                log::info!("Skipping installing `Ctrl+C` handler as one was already installed. Please call `TerminalCtrlCHandlerPlugin::gracefully_exit` in your own `Ctrl+C` handler if you want Bevy to gracefully exit on `Ctrl+C`.");
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let result = ctrlc::try_set_handler(move || {
+                Self::gracefully_exit();
+            });
+            match result {
+                Ok(()) => {}
+                Err(ctrlc::Error::MultipleHandlers) => {
+                    log::info!("Skipping installing `Ctrl+C` handler as one was already installed. Please call `TerminalCtrlCHandlerPlugin::gracefully_exit` in your own `Ctrl+C` handler if you want Bevy to gracefully exit on `Ctrl+C`.");
+                }
+                Err(err) => log::warn!("Failed to set `Ctrl+C` handler: {err}"),
             }
-            Err(err) => log::warn!("Failed to set `Ctrl+C` handler: {err}"),
         }
+        #[cfg(target_arch = "wasm32")]
+        set_before_unload_handler();
+
+        app.init_resource::<CtrlCExitTimeout>()
+            .add_systems(Update, TerminalCtrlCHandlerPlugin::exit_on_flag);
+    }
+}
+
+/// Exits gracefully when the page is about to be unloaded.
+#[cfg(target_arch = "wasm32")]
+fn set_before_unload_handler() {
+    use wasm_bindgen::{closure::Closure, JsCast};
+
+    let Some(window) = web_sys::window() else {
+        return;
+    };
+    let handler = Closure::<dyn FnMut()>::new(TerminalCtrlCHandlerPlugin::gracefully_exit);
+    if let Err(err) =
+        window.add_event_listener_with_callback("beforeunload", handler.as_ref().unchecked_ref())
+    {
+        log::warn!("Failed to set `beforeunload` handler: {err:?}");
+    }
+    // The handler stays registered for the lifetime of the page.
+    handler.forget();
+}
+
+/// Terminates the process if it is still running once the [`CtrlCExitTimeout`] elapsed.
+///
+/// The watchdog is stopped when this resource is dropped along with the app, so that an app
+/// embedded in a longer running program doesn't take it down.
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Resource)]
+struct ExitWatchdog(alloc::sync::Arc<AtomicBool>);
+
+#[cfg(not(target_arch = "wasm32"))]
+impl ExitWatchdog {
+    fn start(timeout: Duration) -> Self {
+        let stopped = alloc::sync::Arc::new(AtomicBool::new(false));
+        let watchdog_stopped = stopped.clone();
+        let spawned = std::thread::Builder::new()
+            .name("Ctrl+C watchdog".into())
+            .spawn(move || {
+                std::thread::sleep(timeout);
+                if !watchdog_stopped.load(Ordering::Relaxed) {
+                    log::error!(
+                        "The app didn't exit within {timeout:?} after `Ctrl+C`, terminating the process"
+                    );
+                    std::process::exit(130);
+                }
+            });
+        if let Err(err) = spawned {
+            log::warn!("Failed to start the `Ctrl+C` watchdog: {err}");
+        }
+        Self(stopped)
+    }
+}
 
-        app.add_systems(Update, TerminalCtrlCHandlerPlugin::exit_on_flag);
+#[cfg(not(target_arch = "wasm32"))]
+impl Drop for ExitWatchdog {
+    fn drop(&mut self) {
+        self.0.store(true, Ordering::Relaxed);
     }
 }
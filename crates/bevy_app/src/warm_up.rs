@@ -0,0 +1,192 @@
+use crate::{
+    App, FixedMain, FixedMainScheduleOrder, Main, MainScheduleOrder, RunFixedMainLoop, ScheduleTime,
+};
+use alloc::{boxed::Box, vec::Vec};
+use bevy_ecs::{
+    resource::Resource,
+    schedule::{InternedScheduleLabel, ScheduleLabel},
+    world::World,
+};
+use bevy_platform::time::Instant;
+use core::time::Duration;
+
+/// Timings of the work done before the first frame, recorded by [`App::warm_up`].
+#[derive(Resource, Debug, Default, Clone, PartialEq, Eq)]
+pub struct StartupTimings {
+    /// The time spent initializing each schedule, in the order they were initialized.
+    pub warm_up: Vec<ScheduleTime>,
+    /// The time spent running the closures added with [`App::add_warm_up`].
+    pub warm_up_closures: Duration,
+}
+
+/// The closures added with [`App::add_warm_up`] that didn't run yet.
+#[derive(Resource, Default)]
+struct WarmUpClosures(Vec<Box<dyn FnOnce(&mut World) + Send + Sync>>);
+
+impl App {
+    /// Initializes the systems of the [`Main`] schedules, so that the first frame doesn't pay for
+    /// it.
+    ///
+    /// The first run of a schedule builds its executable graph and initializes the state of its
+    /// systems, which caches the archetypes matching their queries. This does that work ahead of
+    /// time for [`Main`] and the schedules it runs, including the fixed timestep ones, without
+    /// running any system. Then the closures added with [`App::add_warm_up`] are run.
+    ///
+    /// This should be called after [`App::finish`] and [`App::cleanup`], once the plugins added
+    /// all their systems. It can be called again: only the schedules that changed since are
+    /// initialized again, and the closures only run once. The time spent is recorded in
+    /// [`StartupTimings`].
+    ///
+    /// The [`ScheduleRunnerPlugin`](crate::ScheduleRunnerPlugin) calls this before the first
+    /// frame when its `warm_up` flag is set.
+    pub fn warm_up(&mut self) -> &mut Self {
+        let world = self.world_mut();
+        let mut timings = Vec::new();
+        for label in warm_up_labels(world) {
+            let start = Instant::now();
+            // Schedules that fail to build are left for their first run to report.
+            let initialized = world.try_schedule_scope(label, |world, schedule| {
+                let _ = schedule.initialize(world);
+            });
+            if initialized.is_ok() {
+                timings.push(ScheduleTime {
+                    label,
+                    time: start.elapsed(),
+                });
+            }
+        }
+
+        let start = Instant::now();
+        let closures = world
+            .remove_resource::<WarmUpClosures>()
+            .unwrap_or_default();
+        for closure in closures.0 {
+            closure(world);
+        }
+        let closures_time = start.elapsed();
+
+        let mut startup_timings = world.get_resource_or_init::<StartupTimings>();
+        startup_timings.warm_up = timings;
+        startup_timings.warm_up_closures += closures_time;
+        self
+    }
+
+    /// Adds a closure run once by [`App::warm_up`], for example to touch code paths that are
+    /// slow the first time they run.
+    pub fn add_warm_up(
+        &mut self,
+        closure: impl FnOnce(&mut World) + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.world_mut()
+            .get_resource_or_init::<WarmUpClosures>()
+            .0
+            .push(Box::new(closure));
+        self
+    }
+}
+
+/// Returns the labels of [`Main`] and of the schedules it runs, without duplicates.
+pub(crate) fn warm_up_labels(world: &World) -> Vec<InternedScheduleLabel> {
+    let mut labels = Vec::new();
+    labels.push(Main.intern());
+    if let Some(order) = world.get_resource::<MainScheduleOrder>() {
+        labels.extend(&order.startup_labels);
+        labels.extend(&order.labels);
+    }
+    labels.push(RunFixedMainLoop.intern());
+    labels.push(FixedMain.intern());
+    if let Some(order) = world.get_resource::<FixedMainScheduleOrder>() {
+        labels.extend(&order.labels);
+    }
+
+    let mut unique = Vec::with_capacity(labels.len());
+    for label in labels {
+        if !unique.contains(&label) {
+            unique.push(label);
+        }
+    }
+    unique
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{App, FixedUpdate, StartupTimings, Update};
+    use bevy_ecs::{prelude::*, schedule::ScheduleLabel};
+
+    #[derive(Resource, Default)]
+    struct Initializations(u32);
+
+    /// Counts the initializations of the systems using it.
+    struct InitTracker;
+
+    impl FromWorld for InitTracker {
+        fn from_world(world: &mut World) -> Self {
+            world.resource_mut::<Initializations>().0 += 1;
+            InitTracker
+        }
+    }
+
+    #[derive(Component)]
+    struct Marker;
+
+    fn app() -> App {
+        let mut app = App::new();
+        app.init_resource::<Initializations>()
+            .add_systems(Update, |_: Local<InitTracker>, _: Query<&Marker>| {})
+            .add_systems(FixedUpdate, |_: Local<InitTracker>| {});
+        app
+    }
+
+    #[test]
+    fn initializes_systems_without_running_them() {
+        #[derive(Resource, Default)]
+        struct Runs(u32);
+
+        let mut app = app();
+        app.init_resource::<Runs>()
+            .add_systems(Update, |mut runs: ResMut<Runs>| runs.0 += 1);
+
+        app.warm_up();
+        assert_eq!(app.world().resource::<Initializations>().0, 2);
+        assert_eq!(app.world().resource::<Runs>().0, 0);
+
+        // The first frame doesn't initialize the systems again.
+        app.update();
+        assert_eq!(app.world().resource::<Initializations>().0, 2);
+        assert_eq!(app.world().resource::<Runs>().0, 1);
+    }
+
+    #[test]
+    fn warm_up_is_idempotent() {
+        #[derive(Resource, Default)]
+        struct ClosureRuns(u32);
+
+        let mut app = app();
+        app.init_resource::<ClosureRuns>()
+            .add_warm_up(|world| world.resource_mut::<ClosureRuns>().0 += 1);
+
+        app.warm_up().warm_up();
+        assert_eq!(app.world().resource::<Initializations>().0, 2);
+        assert_eq!(app.world().resource::<ClosureRuns>().0, 1);
+
+        // Systems added later are initialized by the next warm-up.
+        app.add_systems(Update, |_: Local<InitTracker>| {});
+        app.warm_up();
+        assert_eq!(app.world().resource::<Initializations>().0, 3);
+    }
+
+    #[test]
+    fn records_timings() {
+        let mut app = app();
+        app.warm_up();
+
+        let timings = app.world().resource::<StartupTimings>();
+        let labels = timings
+            .warm_up
+            .iter()
+            .map(|timing| timing.label)
+            .collect::<alloc::vec::Vec<_>>();
+        assert!(labels.contains(&Update.intern()));
+        assert!(labels.contains(&FixedUpdate.intern()));
+    }
+}
@@ -182,6 +182,7 @@ mod id;
 mod loader;
 mod loader_builders;
 mod path;
+mod preload;
 mod reflect;
 mod render_asset;
 mod server;
@@ -199,6 +200,7 @@ pub use loader_builders::{
     Deferred, DynamicTyped, Immediate, NestedLoader, StaticTyped, UnknownTyped,
 };
 pub use path::*;
+pub use preload::*;
 pub use reflect::*;
 pub use render_asset::*;
 pub use server::*;
@@ -216,11 +218,11 @@ use alloc::{
     sync::Arc,
     vec::Vec,
 };
-use bevy_app::{App, Plugin, PostUpdate, PreUpdate};
+use bevy_app::{App, Plugin, PostUpdate, PreStartup, PreUpdate};
 use bevy_ecs::prelude::Component;
 use bevy_ecs::{
     reflect::AppTypeRegistry,
-    schedule::{IntoScheduleConfigs, SystemSet},
+    schedule::{common_conditions::resource_exists, IntoScheduleConfigs, SystemSet},
     world::FromWorld,
 };
 use bevy_platform::collections::HashSet;
@@ -414,6 +416,7 @@ impl Plugin for AssetPlugin {
             .init_asset::<LoadedUntypedAsset>()
             .init_asset::<()>()
             .add_event::<UntypedAssetLoadFailedEvent>()
+            .add_event::<PreloadProgress>()
             .configure_sets(
                 PreUpdate,
                 AssetTrackingSystems.after(handle_internal_asset_events),
@@ -422,7 +425,17 @@ impl Plugin for AssetPlugin {
             // and as a result has ambiguous system ordering with all other systems in `PreUpdate`.
             // This is virtually never a real problem: asset loading is async and so anything that interacts directly with it
             // needs to be robust to stochastic delays anyways.
-            .add_systems(PreUpdate, handle_internal_asset_events.ambiguous_with_all());
+            .add_systems(PreUpdate, handle_internal_asset_events.ambiguous_with_all())
+            .add_systems(
+                PreStartup,
+                PreloadSet::track.run_if(resource_exists::<PreloadSet>),
+            )
+            .add_systems(
+                PreUpdate,
+                PreloadSet::track
+                    .run_if(resource_exists::<PreloadSet>)
+                    .after(handle_internal_asset_events),
+            );
     }
 }
 
@@ -538,6 +551,11 @@ pub trait AssetApp {
     /// Preregisters a loader for the given extensions, that will block asset loads until a real loader
     /// is registered.
     fn preregister_asset_loader<L: AssetLoader>(&mut self, extensions: &[&str]) -> &mut Self;
+    /// Adds assets to load when the app starts, and keep loaded, in the [`PreloadSet`].
+    ///
+    /// This can be called several times, each path is only loaded once. See [`PreloadProgress`]
+    /// to follow the loads and [`preload_complete`] to wait for them.
+    fn preload_assets<'a>(&mut self, paths: impl IntoIterator<Item = &'a str>) -> &mut Self;
 }
 
 impl AssetApp for App {
@@ -644,6 +662,14 @@ impl AssetApp for App {
             .preregister_loader::<L>(extensions);
         self
     }
+
+    fn preload_assets<'a>(&mut self, paths: impl IntoIterator<Item = &'a str>) -> &mut Self {
+        let mut preload = self.world_mut().get_resource_or_init::<PreloadSet>();
+        for path in paths {
+            preload.add(AssetPath::parse(path).into_owned());
+        }
+        self
+    }
 }
 
 /// A system set that holds all "track asset" operations.
@@ -0,0 +1,325 @@
+use crate::{
+    AssetPath, AssetServer, Handle, LoadState, LoadedUntypedAsset, RecursiveDependencyLoadState,
+};
+use alloc::vec::Vec;
+use bevy_ecs::{
+    event::{BufferedEvent, EventWriter},
+    resource::Resource,
+    system::{Res, ResMut},
+};
+use bevy_platform::collections::HashSet;
+
+/// The assets to load while the app starts, for example during a loading screen, added with
+/// [`AssetApp::preload_assets`](crate::AssetApp::preload_assets).
+///
+/// This resource holds the handles of the assets so they stay loaded. Their loads start in
+/// `PreStartup`, or in the next `PreUpdate` for the paths added later, and a [`PreloadProgress`]
+/// event is written whenever one of them finishes loading or fails.
+///
+/// ```
+/// # use bevy_app::prelude::*;
+/// # use bevy_asset::{preload_complete, AssetApp, PreloadProgress};
+/// # use bevy_ecs::prelude::*;
+/// fn loading_screen(mut progress: EventReader<PreloadProgress>) {
+///     for progress in progress.read() {
+///         println!("Loaded {}/{} assets", progress.loaded, progress.total);
+///     }
+/// }
+///
+/// fn start_game() {
+///     // Change the state of the game...
+/// }
+///
+/// # let mut app = App::new();
+/// app.preload_assets(["textures/player.png", "sounds/music.ogg"])
+///     .add_systems(Update, (loading_screen, start_game.run_if(preload_complete)));
+/// ```
+#[derive(Resource, Debug, Default)]
+pub struct PreloadSet {
+    entries: Vec<PreloadEntry>,
+    paths: HashSet<AssetPath<'static>>,
+    progress: Option<PreloadProgress>,
+}
+
+#[derive(Debug)]
+struct PreloadEntry {
+    path: AssetPath<'static>,
+    handle: Option<Handle<LoadedUntypedAsset>>,
+    state: PreloadState,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PreloadState {
+    Loading,
+    Loaded,
+    Failed,
+}
+
+/// A [`BufferedEvent`] written when the loads of the [`PreloadSet`] progress.
+///
+/// An event is written once the loads start, then whenever an asset finishes loading, with its
+/// dependencies, or fails to load. The last event is the one where `loaded + failed == total`.
+#[derive(BufferedEvent, Debug, Clone, Default, PartialEq, Eq)]
+pub struct PreloadProgress {
+    /// The number of assets loaded, with their dependencies.
+    pub loaded: usize,
+    /// The number of assets that failed to load, including the ones whose dependencies failed.
+    pub failed: usize,
+    /// The number of assets to preload.
+    pub total: usize,
+    /// The paths of the assets that failed to load, in the order they were added.
+    pub failed_paths: Vec<AssetPath<'static>>,
+}
+
+impl PreloadProgress {
+    /// Returns `true` if every asset finished loading or failed.
+    pub fn is_complete(&self) -> bool {
+        self.loaded + self.failed == self.total
+    }
+}
+
+impl PreloadSet {
+    /// Adds an asset to preload, returning `false` if it was already added.
+    pub fn add(&mut self, path: impl Into<AssetPath<'static>>) -> bool {
+        let path = path.into();
+        if !self.paths.insert(path.clone()) {
+            return false;
+        }
+        self.entries.push(PreloadEntry {
+            path,
+            handle: None,
+            state: PreloadState::Loading,
+        });
+        true
+    }
+
+    /// Returns the handle of the asset at `path`, if it is preloaded and its load started.
+    ///
+    /// Once the [`LoadedUntypedAsset`] is loaded, it holds the handle of the asset itself.
+    pub fn handle<'a>(
+        &self,
+        path: impl Into<AssetPath<'a>>,
+    ) -> Option<&Handle<LoadedUntypedAsset>> {
+        let path = path.into();
+        self.entries
+            .iter()
+            .find(|entry| entry.path == path)
+            .and_then(|entry| entry.handle.as_ref())
+    }
+
+    /// Iterates over the paths of the preloaded assets, in the order they were added.
+    pub fn paths(&self) -> impl Iterator<Item = &AssetPath<'static>> {
+        self.entries.iter().map(|entry| &entry.path)
+    }
+
+    /// Returns the current progress of the loads, as of the last [`PreloadProgress`] event.
+    pub fn progress(&self) -> PreloadProgress {
+        let mut progress = PreloadProgress {
+            total: self.entries.len(),
+            ..Default::default()
+        };
+        for entry in &self.entries {
+            match entry.state {
+                PreloadState::Loading => {}
+                PreloadState::Loaded => progress.loaded += 1,
+                PreloadState::Failed => {
+                    progress.failed += 1;
+                    progress.failed_paths.push(entry.path.clone());
+                }
+            }
+        }
+        progress
+    }
+
+    /// Returns `true` if every asset finished loading or failed, see [`preload_complete`].
+    pub fn is_complete(&self) -> bool {
+        self.entries
+            .iter()
+            .all(|entry| entry.state != PreloadState::Loading)
+    }
+
+    /// Starts the loads of the new assets and writes a [`PreloadProgress`] when the loads
+    /// progress.
+    pub(crate) fn track(
+        mut preload: ResMut<Self>,
+        server: Res<AssetServer>,
+        mut progress_events: EventWriter<PreloadProgress>,
+    ) {
+        for entry in &mut preload.entries {
+            let handle = entry
+                .handle
+                .get_or_insert_with(|| server.load_untyped(entry.path.clone()));
+            if entry.state != PreloadState::Loading {
+                continue;
+            }
+            match server.get_load_states(handle.id()) {
+                Some((LoadState::Failed(_), _, _))
+                | Some((_, _, RecursiveDependencyLoadState::Failed(_))) => {
+                    entry.state = PreloadState::Failed;
+                }
+                Some((LoadState::Loaded, _, RecursiveDependencyLoadState::Loaded)) => {
+                    entry.state = PreloadState::Loaded;
+                }
+                _ => {}
+            }
+        }
+
+        let progress = preload.progress();
+        if preload.progress.as_ref() != Some(&progress) {
+            preload.progress = Some(progress.clone());
+            progress_events.write(progress);
+        }
+    }
+}
+
+/// A run condition that returns `true` once every asset of the [`PreloadSet`] finished loading
+/// or failed.
+///
+/// This also returns `true` if no asset is preloaded. Adding assets to preload makes it return
+/// `false` again until they are loaded.
+pub fn preload_complete(preload: Option<Res<PreloadSet>>) -> bool {
+    preload.is_none_or(|preload| preload.is_complete())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{preload_complete, PreloadProgress, PreloadSet};
+    use crate::{
+        io::{
+            gated::{GateOpener, GatedReader},
+            memory::{Dir, MemoryAssetReader},
+            AssetSource, AssetSourceId,
+        },
+        tests::{CoolText, CoolTextLoader},
+        AssetApp, AssetPath, AssetPlugin,
+    };
+    use alloc::{boxed::Box, format, string::String, vec, vec::Vec};
+    use bevy_app::{App, TaskPoolPlugin, Update};
+    use bevy_ecs::prelude::*;
+    use std::path::Path;
+
+    #[derive(Resource, Default)]
+    struct Recorded {
+        progress: Vec<PreloadProgress>,
+        complete: Vec<bool>,
+    }
+
+    fn record(
+        mut events: EventReader<PreloadProgress>,
+        mut recorded: ResMut<Recorded>,
+        preload: Res<PreloadSet>,
+    ) {
+        recorded.progress.extend(events.read().cloned());
+        recorded.complete.push(preload.is_complete());
+    }
+
+    fn cool_text(text: &str) -> String {
+        format!("(text: \"{text}\", dependencies: [], embedded_dependencies: [], sub_texts: [])")
+    }
+
+    fn app() -> (App, GateOpener) {
+        let dir = Dir::default();
+        dir.insert_asset_text(Path::new("a.cool.ron"), &cool_text("a"));
+        dir.insert_asset_text(Path::new("b.cool.ron"), &cool_text("b"));
+        dir.insert_asset_text(Path::new("broken.cool.ron"), "(text: ");
+
+        let mut app = App::new();
+        let (reader, gate_opener) = GatedReader::new(MemoryAssetReader { root: dir });
+        app.register_asset_source(
+            AssetSourceId::Default,
+            AssetSource::build().with_reader(move || Box::new(reader.clone())),
+        )
+        .add_plugins((TaskPoolPlugin::default(), AssetPlugin::default()))
+        .init_asset::<CoolText>()
+        .register_asset_loader(CoolTextLoader)
+        .init_resource::<Recorded>()
+        .add_systems(Update, record);
+        (app, gate_opener)
+    }
+
+    /// Opens the gate of `path`, then updates the app until the preload progresses.
+    fn open_and_wait(app: &mut App, gate_opener: &GateOpener, path: &str) {
+        let before = app.world().resource::<Recorded>().progress.len();
+        gate_opener.open(path);
+        for _ in 0..10000 {
+            app.update();
+            if app.world().resource::<Recorded>().progress.len() > before {
+                return;
+            }
+        }
+        panic!("Preloading `{path}` didn't progress");
+    }
+
+    fn progress(loaded: usize, failed: usize, failed_paths: &[&'static str]) -> PreloadProgress {
+        PreloadProgress {
+            loaded,
+            failed,
+            total: 3,
+            failed_paths: failed_paths.iter().copied().map(AssetPath::from).collect(),
+        }
+    }
+
+    #[test]
+    fn progress_events_and_failures() {
+        let (mut app, gate_opener) = app();
+        app.preload_assets(["a.cool.ron", "missing.cool.ron"])
+            .preload_assets(["broken.cool.ron"]);
+
+        app.update();
+        open_and_wait(&mut app, &gate_opener, "a.cool.ron");
+        open_and_wait(&mut app, &gate_opener, "missing.cool.ron");
+        open_and_wait(&mut app, &gate_opener, "broken.cool.ron");
+
+        let recorded = app.world().resource::<Recorded>();
+        assert_eq!(
+            recorded.progress,
+            vec![
+                progress(0, 0, &[]),
+                progress(1, 0, &[]),
+                progress(1, 1, &["missing.cool.ron"]),
+                progress(1, 2, &["missing.cool.ron", "broken.cool.ron"]),
+            ]
+        );
+        assert!(recorded.progress.last().unwrap().is_complete());
+
+        // The handles are kept, so the asset stays loaded.
+        let preload = app.world().resource::<PreloadSet>();
+        assert!(preload.handle("a.cool.ron").is_some());
+    }
+
+    #[test]
+    fn deduplicated_paths() {
+        let (mut app, _) = app();
+        app.preload_assets(["a.cool.ron", "b.cool.ron"])
+            .preload_assets(["b.cool.ron", "a.cool.ron"]);
+
+        let preload = app.world().resource::<PreloadSet>();
+        assert_eq!(
+            preload.paths().collect::<Vec<_>>(),
+            [
+                &AssetPath::from("a.cool.ron"),
+                &AssetPath::from("b.cool.ron")
+            ]
+        );
+    }
+
+    #[test]
+    fn run_condition_flips_when_done() {
+        let (mut app, gate_opener) = app();
+        assert!(app.world_mut().run_system_cached(preload_complete).unwrap());
+
+        app.preload_assets(["a.cool.ron", "b.cool.ron"]);
+        app.update();
+        open_and_wait(&mut app, &gate_opener, "a.cool.ron");
+        assert!(!app.world_mut().run_system_cached(preload_complete).unwrap());
+        open_and_wait(&mut app, &gate_opener, "b.cool.ron");
+        assert!(app.world_mut().run_system_cached(preload_complete).unwrap());
+
+        // The condition flips in the same frame as the last progress event.
+        let recorded = app.world().resource::<Recorded>();
+        let frames = recorded.complete.len();
+        assert!(recorded.complete[frames - 1]);
+        assert!(!recorded.complete[frames - 2]);
+        assert!(recorded.progress.last().unwrap().is_complete());
+    }
+}
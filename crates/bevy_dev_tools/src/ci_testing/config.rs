@@ -1,4 +1,5 @@
 use bevy_ecs::prelude::*;
+use core::time::Duration;
 use serde::Deserialize;
 
 /// A configuration struct for automated CI testing.
@@ -16,6 +17,32 @@ pub struct CiTestingConfig {
     pub events: Vec<CiTestingEventOnFrame>,
 }
 
+impl CiTestingConfig {
+    /// Ends the test successfully after `frames` frames, see [`CiTestingSetup::max_frames`].
+    pub fn with_max_frames(mut self, frames: u32) -> Self {
+        self.setup.max_frames = Some(frames);
+        self
+    }
+
+    /// Fails the test if it is still running after `timeout`, see [`CiTestingSetup::timeout`].
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.setup.timeout = Some(timeout.as_secs_f32());
+        self
+    }
+
+    /// Fails the test when an error is logged, see [`CiTestingSetup::fail_on_error_log`].
+    pub fn with_fail_on_error_log(mut self) -> Self {
+        self.setup.fail_on_error_log = true;
+        self
+    }
+
+    /// Sends `event` at the given `frame`.
+    pub fn with_event(mut self, frame: u32, event: CiTestingEvent) -> Self {
+        self.events.push(CiTestingEventOnFrame(frame, event));
+        self
+    }
+}
+
 /// Setup for a test.
 #[derive(Deserialize, Default, PartialEq, Debug)]
 pub struct CiTestingSetup {
@@ -25,6 +52,29 @@ pub struct CiTestingSetup {
     ///
     /// [`TimeUpdateStrategy::ManualDuration`]: bevy_time::TimeUpdateStrategy::ManualDuration
     pub fixed_frame_time: Option<f32>,
+    /// The number of frames after which the test ends successfully, by sending
+    /// [`AppExit::Success`].
+    ///
+    /// [`AppExit::Success`]: bevy_app::AppExit::Success
+    pub max_frames: Option<u32>,
+    /// The wall-clock time in seconds, from the time the [`CiTestingPlugin`] is added, after which
+    /// the test fails by sending an [`AppExit::Error`].
+    ///
+    /// This is checked at the end of each frame. If the app stops responding instead, the process
+    /// exits with an error a few seconds after the timeout expired.
+    ///
+    /// [`CiTestingPlugin`]: super::CiTestingPlugin
+    /// [`AppExit::Error`]: bevy_app::AppExit::Error
+    pub timeout: Option<f32>,
+    /// Whether an error logged with `error!` fails the test by sending an [`AppExit::Error`].
+    ///
+    /// The errors are only counted if [`ci_testing_log_layer`] is used as the custom layer of the
+    /// `LogPlugin`.
+    ///
+    /// [`AppExit::Error`]: bevy_app::AppExit::Error
+    /// [`ci_testing_log_layer`]: super::ci_testing_log_layer
+    #[serde(default)]
+    pub fail_on_error_log: bool,
 }
 
 /// An event to send at a given frame, used for CI testing.
@@ -76,6 +126,7 @@ mod tests {
         let expected = CiTestingConfig {
             setup: CiTestingSetup {
                 fixed_frame_time: Some(0.03),
+                ..Default::default()
             },
             events: vec![
                 CiTestingEventOnFrame(100, CiTestingEvent::Custom("Hello, world!".into())),
@@ -88,4 +139,29 @@ mod tests {
 
         assert_eq!(config, expected);
     }
+
+    #[test]
+    fn deserialize_verdict() {
+        const INPUT: &str = r#"
+(
+    setup: (
+        max_frames: Some(300),
+        timeout: Some(60.0),
+        fail_on_error_log: true,
+    ),
+    events: [
+        (100, Custom("toggle")),
+    ],
+)"#;
+
+        let expected = CiTestingConfig::default()
+            .with_max_frames(300)
+            .with_timeout(Duration::from_secs(60))
+            .with_fail_on_error_log()
+            .with_event(100, CiTestingEvent::Custom("toggle".into()));
+
+        let config: CiTestingConfig = ron::from_str(INPUT).unwrap();
+
+        assert_eq!(config, expected);
+    }
 }
@@ -0,0 +1,82 @@
+use bevy_app::App;
+use bevy_ecs::prelude::*;
+use bevy_log::{
+    tracing_subscriber::{layer::Context, Layer},
+    BoxedLayer, Level,
+};
+use bevy_platform::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+use tracing::{Event, Subscriber};
+
+/// The number of errors logged since the start of the app, counted by [`ci_testing_log_layer`].
+#[derive(Resource, Clone, Default, Debug)]
+pub struct CiTestingLoggedErrors(Arc<AtomicUsize>);
+
+impl CiTestingLoggedErrors {
+    /// Returns the number of errors logged so far.
+    pub fn count(&self) -> usize {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// A custom layer for the `LogPlugin` that counts the logged errors in [`CiTestingLoggedErrors`],
+/// so that they fail the test when [`CiTestingSetup::fail_on_error_log`] is enabled.
+///
+/// ```no_run
+/// # use bevy_app::App;
+/// # use bevy_dev_tools::ci_testing::{ci_testing_log_layer, CiTestingPlugin};
+/// # use bevy_log::LogPlugin;
+/// App::new().add_plugins((
+///     LogPlugin {
+///         custom_layers: |app| Vec::from_iter(ci_testing_log_layer(app)),
+///         ..Default::default()
+///     },
+///     CiTestingPlugin,
+/// ));
+/// ```
+///
+/// [`CiTestingSetup::fail_on_error_log`]: super::CiTestingSetup::fail_on_error_log
+pub fn ci_testing_log_layer(app: &mut App) -> Option<BoxedLayer> {
+    let errors = app
+        .world_mut()
+        .get_resource_or_init::<CiTestingLoggedErrors>()
+        .clone();
+    Some(Box::new(ErrorCountingLayer(errors)))
+}
+
+struct ErrorCountingLayer(CiTestingLoggedErrors);
+
+impl<S: Subscriber> Layer<S> for ErrorCountingLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        if *event.metadata().level() == Level::ERROR {
+            self.0 .0.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_log::{
+        error,
+        tracing_subscriber::{layer::SubscriberExt, Registry},
+        warn,
+    };
+
+    #[test]
+    fn counts_errors() {
+        let mut app = App::new();
+        let layer = ci_testing_log_layer(&mut app).unwrap();
+        let subscriber = Registry::default().with(layer);
+
+        tracing::subscriber::with_default(subscriber, || {
+            warn!("not an error");
+            error!("first error");
+            error!("second error");
+        });
+
+        assert_eq!(app.world().resource::<CiTestingLoggedErrors>().count(), 2);
+    }
+}
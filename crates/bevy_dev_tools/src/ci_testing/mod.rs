@@ -1,21 +1,45 @@
 //! Utilities for testing in CI environments.
 
 mod config;
+mod log_layer;
 mod systems;
+mod timeout;
 
-pub use self::config::*;
+pub use self::{config::*, log_layer::*};
 
 use bevy_app::prelude::*;
 use bevy_ecs::prelude::*;
 use bevy_render::view::screenshot::trigger_screenshots;
 use bevy_time::TimeUpdateStrategy;
 use core::time::Duration;
+use tracing::warn;
 
 /// A plugin that instruments continuous integration testing by automatically executing user-defined actions.
 ///
 /// This plugin reads a [`ron`] file specified with the `CI_TESTING_CONFIG` environmental variable
 /// (`ci_testing_config.ron` by default) and executes its specified actions. For a reference of the
-/// allowed configuration, see [`CiTestingConfig`].
+/// allowed configuration, see [`CiTestingConfig`]. If a [`CiTestingConfig`] resource was inserted
+/// before adding this plugin, it is used instead of the file.
+///
+/// The test ends with a verdict: [`AppExit::Success`] once [`max_frames`](CiTestingSetup::max_frames)
+/// frames ran, or an [`AppExit::Error`] if the [`timeout`](CiTestingSetup::timeout) expired, or if
+/// an error was logged with [`fail_on_error_log`](CiTestingSetup::fail_on_error_log). If the app
+/// stops responding, for example in a frame that never ends, a watchdog thread ends the process
+/// with an error a few seconds after the timeout expired.
+///
+/// ```no_run
+/// # use bevy_app::prelude::*;
+/// # use bevy_dev_tools::ci_testing::{CiTestingConfig, CiTestingPlugin};
+/// # use core::time::Duration;
+/// App::new()
+///     .insert_resource(
+///         CiTestingConfig::default()
+///             .with_max_frames(300)
+///             .with_timeout(Duration::from_secs(60)),
+///     )
+///     .add_plugins(CiTestingPlugin)
+///     .run();
+/// ```
 ///
 /// This plugin is included within `DefaultPlugins` and `MinimalPlugins`
 /// when the `bevy_ci_testing` feature is enabled.
@@ -26,23 +50,10 @@ pub struct CiTestingPlugin;
 
 impl Plugin for CiTestingPlugin {
     fn build(&self, app: &mut App) {
-        #[cfg(not(target_arch = "wasm32"))]
-        let config: CiTestingConfig = {
-            let filename = std::env::var("CI_TESTING_CONFIG")
-                .unwrap_or_else(|_| "ci_testing_config.ron".to_string());
-            std::fs::read_to_string(filename)
-                .map(|content| {
-                    ron::from_str(&content)
-                        .expect("error deserializing CI testing configuration file")
-                })
-                .unwrap_or_default()
-        };
-
-        #[cfg(target_arch = "wasm32")]
-        let config: CiTestingConfig = {
-            let config = include_str!("../../../../ci_testing_config.ron");
-            ron::from_str(config).expect("error deserializing CI testing configuration file")
-        };
+        let config = app
+            .world_mut()
+            .remove_resource::<CiTestingConfig>()
+            .unwrap_or_else(load_config);
 
         // Configure a fixed frame time if specified.
         if let Some(fixed_frame_time) = config.setup.fixed_frame_time {
@@ -50,6 +61,9 @@ impl Plugin for CiTestingPlugin {
                 fixed_frame_time,
             )));
         }
+        if let Some(timeout) = config.setup.timeout {
+            app.insert_resource(timeout::CiTestingTimeout::start(timeout));
+        }
         app.add_event::<CiTestingCustomEvent>()
             .insert_resource(config)
             .add_systems(
@@ -59,7 +73,8 @@ impl Plugin for CiTestingPlugin {
                     .before(bevy_window::close_when_requested)
                     .in_set(EventSenderSystems)
                     .ambiguous_with_all(),
-            );
+            )
+            .add_systems(Last, systems::check_verdict);
 
         // The offending system does not exist in the wasm32 target.
         // As a result, we must conditionally order the two systems using a system set.
@@ -69,7 +84,105 @@ impl Plugin for CiTestingPlugin {
             EventSenderSystems.before(bevy_app::TerminalCtrlCHandlerPlugin::exit_on_flag),
         );
     }
+
+    fn finish(&self, app: &mut App) {
+        if app
+            .world()
+            .resource::<CiTestingConfig>()
+            .setup
+            .fail_on_error_log
+            && !app.world().contains_resource::<CiTestingLoggedErrors>()
+        {
+            warn!(
+                "`fail_on_error_log` is enabled, but the errors are not counted: \
+                use `ci_testing_log_layer` as the custom layer of the `LogPlugin`"
+            );
+        }
+    }
+}
+
+/// Loads the configuration from the file named by `CI_TESTING_CONFIG`.
+fn load_config() -> CiTestingConfig {
+    #[cfg(not(target_arch = "wasm32"))]
+    let config: CiTestingConfig = {
+        let filename = std::env::var("CI_TESTING_CONFIG")
+            .unwrap_or_else(|_| "ci_testing_config.ron".to_string());
+        std::fs::read_to_string(filename)
+            .map(|content| {
+                ron::from_str(&content).expect("error deserializing CI testing configuration file")
+            })
+            .unwrap_or_default()
+    };
+
+    #[cfg(target_arch = "wasm32")]
+    let config: CiTestingConfig = {
+        let config = include_str!("../../../../ci_testing_config.ron");
+        ron::from_str(config).expect("error deserializing CI testing configuration file")
+    };
+
+    config
 }
 
 #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
 struct EventSenderSystems;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_log::{
+        error,
+        tracing_subscriber::{layer::SubscriberExt, Registry},
+    };
+    use core::time::Duration;
+
+    fn run(config: CiTestingConfig) -> (Option<AppExit>, u32) {
+        let mut app = App::new();
+        app.insert_resource(config).add_plugins(CiTestingPlugin);
+        let exit = app.run_frames(20).unwrap();
+        let frames = app.world().resource::<bevy_app::FrameNumber>().get() as u32;
+        (exit, frames)
+    }
+
+    #[test]
+    fn succeeds_after_max_frames() {
+        let config = CiTestingConfig::default().with_max_frames(5);
+        assert_eq!(run(config), (Some(AppExit::Success), 5));
+    }
+
+    #[test]
+    fn fails_on_timeout() {
+        let config = CiTestingConfig::default()
+            .with_max_frames(5)
+            .with_timeout(Duration::ZERO);
+        assert_eq!(run(config), (Some(AppExit::error()), 1));
+    }
+
+    #[test]
+    fn fails_on_logged_errors() {
+        let mut app = App::new();
+        let layer = ci_testing_log_layer(&mut app).unwrap();
+        app.insert_resource(
+            CiTestingConfig::default()
+                .with_max_frames(5)
+                .with_fail_on_error_log(),
+        )
+        .add_plugins(CiTestingPlugin);
+
+        assert_eq!(app.run_frames(2), Ok(None));
+        let subscriber = Registry::default().with(layer);
+        tracing::subscriber::with_default(subscriber, || error!("failure"));
+        assert_eq!(app.run_frames(2), Ok(Some(AppExit::error())));
+        assert_eq!(
+            app.shutdown_reason(),
+            Some(bevy_app::ShutdownReason::TestHarness)
+        );
+    }
+
+    #[test]
+    fn sends_events_at_their_frame() {
+        let config = CiTestingConfig::default()
+            .with_max_frames(10)
+            .with_event(3, CiTestingEvent::AppExit);
+        assert_eq!(run(config), (Some(AppExit::Success), 4));
+    }
+}
@@ -1,5 +1,5 @@
-use super::config::*;
-use bevy_app::AppExit;
+use super::{config::*, timeout::CiTestingTimeout, CiTestingLoggedErrors};
+use bevy_app::{AppExit, AppExitWriter, ShutdownReason};
 use bevy_ecs::prelude::*;
 use bevy_render::view::screenshot::{save_to_disk, Screenshot};
 use tracing::{debug, info};
@@ -60,3 +60,49 @@ pub(crate) fn send_events(world: &mut World, mut current_frame: Local<u32>) {
 
     *current_frame += 1;
 }
+
+/// Ends the test once it succeeded or failed, see [`CiTestingSetup`].
+pub(crate) fn check_verdict(
+    config: Res<CiTestingConfig>,
+    logged_errors: Option<Res<CiTestingLoggedErrors>>,
+    timeout: Option<Res<CiTestingTimeout>>,
+    mut exit: AppExitWriter,
+    mut current_frame: Local<u32>,
+) {
+    let setup = &config.setup;
+    *current_frame += 1;
+
+    // The message is logged when the app starts shutting down.
+    if setup.fail_on_error_log
+        && let Some(logged_errors) = logged_errors
+        && logged_errors.count() > 0
+    {
+        exit.write_with_message(
+            AppExit::error(),
+            ShutdownReason::TestHarness,
+            format!(
+                "Test failed after {} frames: {} error(s) were logged.",
+                *current_frame,
+                logged_errors.count()
+            ),
+        );
+    } else if let Some(timeout) = timeout
+        && timeout.expired()
+    {
+        exit.write_with_message(
+            AppExit::error(),
+            ShutdownReason::TestHarness,
+            format!(
+                "Test failed after {} frames: timed out after {} seconds.",
+                *current_frame,
+                timeout.seconds()
+            ),
+        );
+    } else if setup.max_frames.is_some_and(|max| *current_frame >= max) {
+        exit.write_with_message(
+            AppExit::Success,
+            ShutdownReason::TestHarness,
+            format!("Test successful after {} frames.", *current_frame),
+        );
+    }
+}
@@ -0,0 +1,70 @@
+use bevy_ecs::prelude::*;
+use bevy_platform::time::Instant;
+use core::time::Duration;
+
+/// How long the watchdog lets the app notice the timeout by itself before ending the process.
+#[cfg(not(target_arch = "wasm32"))]
+const WATCHDOG_GRACE: Duration = Duration::from_secs(5);
+
+/// The wall-clock timeout of the test, see [`CiTestingSetup::timeout`].
+///
+/// The timeout is checked at the end of each frame, but a frame that never ends, or an app that
+/// never starts its first frame, would never be checked. So a watchdog thread ends the process
+/// with an error if the app is still running a while after the timeout expired. The watchdog
+/// stops when this resource is dropped with the app.
+///
+/// [`CiTestingSetup::timeout`]: super::CiTestingSetup::timeout
+#[derive(Resource)]
+pub(crate) struct CiTestingTimeout {
+    start: Instant,
+    timeout: Duration,
+    /// Disconnects the watchdog when dropped.
+    #[cfg(not(target_arch = "wasm32"))]
+    _watchdog: std::sync::mpsc::Sender<()>,
+}
+
+impl CiTestingTimeout {
+    /// Starts the timeout of `timeout` seconds, and its watchdog.
+    pub(crate) fn start(timeout: f32) -> Self {
+        let timeout = Duration::from_secs_f32(timeout);
+
+        #[cfg(not(target_arch = "wasm32"))]
+        let _watchdog = {
+            use std::sync::mpsc::{channel, RecvTimeoutError};
+
+            let (sender, receiver) = channel::<()>();
+            std::thread::Builder::new()
+                .name("CI testing watchdog".to_string())
+                .spawn(move || {
+                    if receiver.recv_timeout(timeout + WATCHDOG_GRACE)
+                        == Err(RecvTimeoutError::Timeout)
+                    {
+                        tracing::error!(
+                            "Test failed: timed out after {} seconds, the app stopped responding.",
+                            timeout.as_secs_f32()
+                        );
+                        std::process::exit(1);
+                    }
+                })
+                .expect("failed to spawn the CI testing watchdog thread");
+            sender
+        };
+
+        Self {
+            start: Instant::now(),
+            timeout,
+            #[cfg(not(target_arch = "wasm32"))]
+            _watchdog,
+        }
+    }
+
+    /// Returns the timeout, in seconds.
+    pub(crate) fn seconds(&self) -> f32 {
+        self.timeout.as_secs_f32()
+    }
+
+    /// Returns `true` once the timeout expired.
+    pub(crate) fn expired(&self) -> bool {
+        self.start.elapsed() >= self.timeout
+    }
+}
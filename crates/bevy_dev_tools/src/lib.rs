@@ -15,6 +15,7 @@ pub mod ci_testing;
 
 pub mod fps_overlay;
 pub mod frame_time_graph;
+pub mod log_overlay;
 
 pub mod picking_debug;
 
@@ -0,0 +1,255 @@
+//! Module containing logic for the log overlay.
+
+use bevy_app::{App, Plugin, Update};
+use bevy_camera::{visibility::Visibility, Camera};
+use bevy_color::{palettes::tailwind, Alpha, Color};
+use bevy_ecs::{
+    change_detection::DetectChangesMut,
+    children,
+    component::Component,
+    entity::Entity,
+    event::{EventReader, Events},
+    hierarchy::Children,
+    prelude::Local,
+    query::With,
+    resource::Resource,
+    schedule::{
+        common_conditions::{any_with_component, not, resource_changed},
+        IntoScheduleConfigs,
+    },
+    spawn::SpawnRelated,
+    system::{Commands, Query, Res, ResMut, Single},
+};
+use bevy_input::{keyboard::KeyCode, ButtonInput};
+use bevy_log::{warn, Level, LogMessage};
+use bevy_text::{TextColor, TextFont, TextSpan};
+use bevy_ui::{
+    widget::Text, BackgroundColor, FlexDirection, GlobalZIndex, Node, PositionType, UiPlugin,
+    UiRect, Val,
+};
+
+/// [`GlobalZIndex`] used to render the log overlay.
+///
+/// This is just under the [`FPS_OVERLAY_ZINDEX`](crate::fps_overlay::FPS_OVERLAY_ZINDEX), so that
+/// both overlays can be shown at once.
+pub const LOG_OVERLAY_ZINDEX: i32 = i32::MAX - 33;
+
+/// A plugin that shows the most recent logs in the bottom left corner of the window.
+///
+/// The logs are read from the [`LogMessage`] events, so the `capture` of the `LogPlugin` must be
+/// set, and this plugin must be added with the `UiPlugin` and a camera rendering the UI.
+/// Otherwise, a warning is logged and the overlay isn't shown.
+///
+/// ```no_run
+/// # use bevy_app::{App, NoopPluginGroup as DefaultPlugins, PluginGroup};
+/// # use bevy_dev_tools::log_overlay::LogOverlayPlugin;
+/// # use bevy_log::{LogCaptureConfig, LogPlugin};
+/// App::new()
+///     .add_plugins((
+///         DefaultPlugins.set(LogPlugin {
+///             capture: Some(LogCaptureConfig::default()),
+///             ..Default::default()
+///         }),
+///         LogOverlayPlugin::default(),
+///     ))
+///     .run();
+/// ```
+#[derive(Default)]
+pub struct LogOverlayPlugin {
+    /// Starting configuration of overlay, this can be later be changed through [`LogOverlayConfig`] resource.
+    pub config: LogOverlayConfig,
+}
+
+impl Plugin for LogOverlayPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(self.config.clone());
+    }
+
+    fn finish(&self, app: &mut App) {
+        // The other plugins may be added after this one, so they are only checked here.
+        if !app.is_plugin_added::<UiPlugin>() {
+            warn!("The LogOverlayPlugin requires the UiPlugin, the log overlay is disabled");
+            return;
+        }
+        if !app.world().contains_resource::<Events<LogMessage>>() {
+            warn!(
+                "The LogOverlayPlugin requires the `capture` of the LogPlugin to be set, the log \
+                overlay is disabled"
+            );
+            return;
+        }
+
+        app.add_systems(
+            Update,
+            (
+                toggle_overlay,
+                spawn_overlay.run_if(not(any_with_component::<LogOverlay>)),
+                update_overlay,
+                toggle_display.run_if(resource_changed::<LogOverlayConfig>),
+            )
+                .chain(),
+        );
+    }
+}
+
+/// Configuration options for the log overlay.
+#[derive(Resource, Clone)]
+pub struct LogOverlayConfig {
+    /// Displays the log overlay if true.
+    pub enabled: bool,
+    /// The key showing or hiding the overlay, if any.
+    ///
+    /// Defaults to [`KeyCode::F9`].
+    pub toggle_key: Option<KeyCode>,
+    /// The least important level of the logs shown. For example, [`Level::WARN`] only shows the
+    /// warnings and the errors.
+    ///
+    /// Defaults to [`Level::INFO`].
+    pub min_level: Level,
+    /// The maximum number of logs shown. The oldest logs are removed past that.
+    ///
+    /// Defaults to 20.
+    pub max_lines: usize,
+    /// Configuration of text in the overlay.
+    ///
+    /// Changes only apply to the logs shown afterwards.
+    pub text_config: TextFont,
+    /// Color of the messages in the overlay. The levels are colored depending on their value.
+    ///
+    /// Changes only apply to the logs shown afterwards.
+    pub text_color: Color,
+    /// Color of the background of the overlay.
+    pub background_color: Color,
+}
+
+impl Default for LogOverlayConfig {
+    fn default() -> Self {
+        LogOverlayConfig {
+            enabled: true,
+            toggle_key: Some(KeyCode::F9),
+            min_level: Level::INFO,
+            max_lines: 20,
+            text_config: TextFont {
+                font_size: 14.0,
+                ..Default::default()
+            },
+            text_color: Color::WHITE,
+            background_color: Color::BLACK.with_alpha(0.6),
+        }
+    }
+}
+
+#[derive(Component)]
+struct LogOverlay;
+
+fn spawn_overlay(
+    mut commands: Commands,
+    config: Res<LogOverlayConfig>,
+    cameras: Query<(), With<Camera>>,
+    mut warned: Local<bool>,
+) {
+    if cameras.is_empty() {
+        if !*warned {
+            warn!("The log overlay isn't shown until a camera is spawned");
+            *warned = true;
+        }
+        return;
+    }
+
+    commands.spawn((
+        Node {
+            // We need to make sure the overlay doesn't affect the position of other UI nodes
+            position_type: PositionType::Absolute,
+            left: Val::Px(0.0),
+            bottom: Val::Px(0.0),
+            flex_direction: FlexDirection::Column,
+            padding: UiRect::all(Val::Px(4.0)),
+            ..Default::default()
+        },
+        BackgroundColor(config.background_color),
+        if config.enabled {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        },
+        // Render overlay on top of everything
+        GlobalZIndex(LOG_OVERLAY_ZINDEX),
+        LogOverlay,
+    ));
+}
+
+fn update_overlay(
+    mut commands: Commands,
+    mut logs: EventReader<LogMessage>,
+    config: Res<LogOverlayConfig>,
+    overlay: Single<(Entity, Option<&Children>), With<LogOverlay>>,
+) {
+    let (overlay, lines) = *overlay;
+    let max_lines = config.max_lines;
+    // The most important levels are the smallest.
+    let logs: Vec<_> = logs
+        .read()
+        .filter(|log| log.level <= config.min_level)
+        .collect();
+    let new_lines = &logs[logs.len().saturating_sub(max_lines)..];
+
+    // Remove the oldest lines so that the overlay doesn't grow indefinitely.
+    let lines = lines.map(|lines| &lines[..]).unwrap_or_default();
+    let excess = (lines.len() + new_lines.len()).saturating_sub(max_lines);
+    for &line in &lines[..excess.min(lines.len())] {
+        commands.entity(line).despawn();
+    }
+
+    if new_lines.is_empty() {
+        return;
+    }
+    commands.entity(overlay).with_children(|overlay| {
+        for log in new_lines {
+            overlay.spawn((
+                Text::default(),
+                config.text_config.clone(),
+                TextColor(config.text_color),
+                children![
+                    (
+                        TextSpan::new(format!("{:5} ", log.level)),
+                        config.text_config.clone(),
+                        TextColor(level_color(log.level)),
+                    ),
+                    (TextSpan::new(&log.message), config.text_config.clone()),
+                ],
+            ));
+        }
+    });
+}
+
+fn toggle_overlay(input: Option<Res<ButtonInput<KeyCode>>>, mut config: ResMut<LogOverlayConfig>) {
+    if let Some(input) = input
+        && let Some(key) = config.toggle_key
+        && input.just_pressed(key)
+    {
+        config.enabled = !config.enabled;
+    }
+}
+
+fn toggle_display(
+    config: Res<LogOverlayConfig>,
+    mut query: Query<(&mut Visibility, &mut BackgroundColor), With<LogOverlay>>,
+) {
+    for (mut visibility, mut background_color) in &mut query {
+        visibility.set_if_neq(match config.enabled {
+            true => Visibility::Inherited,
+            false => Visibility::Hidden,
+        });
+        background_color.set_if_neq(BackgroundColor(config.background_color));
+    }
+}
+
+fn level_color(level: Level) -> Color {
+    Color::from(match level {
+        Level::WARN => tailwind::ORANGE_400,
+        Level::ERROR => tailwind::RED_400,
+        Level::INFO => tailwind::GREEN_400,
+        Level::TRACE => tailwind::PURPLE_400,
+        Level::DEBUG => tailwind::BLUE_400,
+    })
+}
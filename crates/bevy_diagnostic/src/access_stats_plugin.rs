@@ -0,0 +1,435 @@
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use bevy_app::{prelude::*, MainScheduleOrder};
+use bevy_ecs::{
+    component::ComponentId,
+    prelude::*,
+    query::ComponentAccessKind,
+    schedule::{InternedScheduleLabel, ScheduleLabel, Schedules, SystemKey, SystemWithAccess},
+};
+use bevy_platform::collections::{HashMap, HashSet};
+use core::fmt::Write;
+
+/// Samples which systems access each component and resource type over a number of frames, to
+/// help decide how to lay out the data, for example which components to split.
+///
+/// Once [`sample_frames`](Self::sample_frames) frames were sampled, the [`AccessStatsReport`] can
+/// be retrieved from the [`AccessStatsSampler`] resource, which can also start a new sampling
+/// window. The sampling system doesn't run outside of a sampling window.
+pub struct AccessStatsPlugin {
+    /// The number of frames to sample once the app starts. No frame is sampled if this is `0`,
+    /// until [`AccessStatsSampler::start`] is called.
+    pub sample_frames: u32,
+}
+
+impl Default for AccessStatsPlugin {
+    fn default() -> Self {
+        Self { sample_frames: 120 }
+    }
+}
+
+impl Plugin for AccessStatsPlugin {
+    fn build(&self, app: &mut App) {
+        let mut sampler = AccessStatsSampler::default();
+        sampler.start(self.sample_frames);
+
+        app.insert_resource(sampler)
+            .init_schedule(SampleAccessStats)
+            .add_systems(
+                SampleAccessStats,
+                AccessStatsSampler::sample_system
+                    .run_if(|sampler: Res<AccessStatsSampler>| sampler.is_sampling()),
+            )
+            .world_mut()
+            .resource_mut::<MainScheduleOrder>()
+            .insert_after(Last, SampleAccessStats);
+    }
+}
+
+/// Runs after [`Last`], so every system of the frame ran when the accesses are sampled.
+#[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+struct SampleAccessStats;
+
+/// Samples the accesses of the systems that ran in each frame of a sampling window, see
+/// [`AccessStatsPlugin`].
+///
+/// A system counts as a reader or a writer of a type if it ran at least once during the window,
+/// and its parameters read or write that type. Exclusive systems and systems accessing every
+/// component, such as the ones querying [`EntityRef`](bevy_ecs::world::EntityRef), are not
+/// counted.
+#[derive(Resource, Debug, Default)]
+pub struct AccessStatsSampler {
+    remaining_frames: u32,
+    frames: u32,
+    samples: HashMap<ComponentId, Sample>,
+    report: Option<AccessStatsReport>,
+}
+
+#[derive(Debug)]
+struct Sample {
+    name: String,
+    kind: AccessStatsKind,
+    readers: HashSet<(InternedScheduleLabel, SystemKey)>,
+    writers: HashSet<(InternedScheduleLabel, SystemKey)>,
+    entities_matched: u64,
+}
+
+impl AccessStatsSampler {
+    /// Starts a new sampling window of `frames` frames, discarding the current window and the
+    /// last report.
+    pub fn start(&mut self, frames: u32) {
+        self.remaining_frames = frames;
+        self.frames = 0;
+        self.samples.clear();
+        self.report = None;
+    }
+
+    /// Ends the current sampling window early and returns its report.
+    ///
+    /// Returns `None` if no frame is being sampled.
+    pub fn stop(&mut self) -> Option<&AccessStatsReport> {
+        if !self.is_sampling() {
+            return None;
+        }
+        self.finish();
+        self.report.as_ref()
+    }
+
+    /// Returns `true` while the frames are sampled.
+    pub fn is_sampling(&self) -> bool {
+        self.remaining_frames > 0
+    }
+
+    /// Returns the number of frames left in the current sampling window.
+    pub fn remaining_frames(&self) -> u32 {
+        self.remaining_frames
+    }
+
+    /// Returns the report of the last sampling window, once it is complete.
+    pub fn report(&self) -> Option<&AccessStatsReport> {
+        self.report.as_ref()
+    }
+
+    /// Samples the systems that ran in this frame.
+    pub fn sample_system(world: &mut World) {
+        world.resource_scope(|world, mut sampler: Mut<Self>| sampler.sample(world));
+    }
+
+    fn sample(&mut self, world: &World) {
+        // The systems that ran in this frame are the ones that ran since the trackers were last
+        // cleared, at the end of the previous frame.
+        let (frame_start, this_run) = (world.last_change_tick(), world.read_change_tick());
+
+        let mut entities = HashMap::<ComponentId, u64>::default();
+        for archetype in world.archetypes().iter() {
+            for component in archetype.components() {
+                *entities.entry(component).or_default() += u64::from(archetype.len());
+            }
+        }
+
+        for (_, schedule) in world.resource::<Schedules>().iter() {
+            let Ok(systems) = schedule.systems_with_access() else {
+                continue;
+            };
+            for (key, SystemWithAccess { system, access }) in systems {
+                if system.is_exclusive()
+                    || !system.get_last_run().is_newer_than(frame_start, this_run)
+                {
+                    continue;
+                }
+                let system = (schedule.label(), key);
+                let access = access.combined_access();
+
+                if let Ok(components) = access.try_iter_component_access() {
+                    for access in components {
+                        let (component, write) = match access {
+                            ComponentAccessKind::Shared(id) => (id, false),
+                            ComponentAccessKind::Exclusive(id) => (id, true),
+                            ComponentAccessKind::Archetypal(_) => continue,
+                        };
+                        let sample = self.sample_of(world, component, AccessStatsKind::Component);
+                        sample.record(system, write);
+                        sample.entities_matched += entities.get(&component).copied().unwrap_or(0);
+                    }
+                }
+                for resource in access.resource_reads() {
+                    self.sample_of(world, resource, AccessStatsKind::Resource)
+                        .record(system, false);
+                }
+                for resource in access.resource_writes() {
+                    self.sample_of(world, resource, AccessStatsKind::Resource)
+                        .record(system, true);
+                }
+            }
+        }
+
+        self.frames += 1;
+        self.remaining_frames -= 1;
+        if self.remaining_frames == 0 {
+            self.finish();
+        }
+    }
+
+    fn sample_of(&mut self, world: &World, id: ComponentId, kind: AccessStatsKind) -> &mut Sample {
+        self.samples.entry(id).or_insert_with(|| Sample {
+            name: world
+                .components()
+                .get_name(id)
+                .map_or_else(|| format!("{id:?}"), |name| name.to_string()),
+            kind,
+            readers: HashSet::default(),
+            writers: HashSet::default(),
+            entities_matched: 0,
+        })
+    }
+
+    fn finish(&mut self) {
+        let mut entries = self
+            .samples
+            .drain()
+            .map(|(id, sample)| AccessStats {
+                id,
+                name: sample.name,
+                kind: sample.kind,
+                readers: sample.readers.len(),
+                writers: sample.writers.len(),
+                entities_matched: sample.entities_matched,
+            })
+            .collect::<Vec<_>>();
+        entries.sort_by(|a, b| a.name.cmp(&b.name));
+
+        self.report = Some(AccessStatsReport {
+            frames: self.frames,
+            entries,
+        });
+        self.remaining_frames = 0;
+    }
+}
+
+impl Sample {
+    fn record(&mut self, system: (InternedScheduleLabel, SystemKey), write: bool) {
+        if write {
+            self.writers.insert(system);
+        } else {
+            self.readers.insert(system);
+        }
+    }
+}
+
+/// Whether an [`AccessStats`] is about a component or a resource.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AccessStatsKind {
+    /// The accesses of a component, through queries.
+    Component,
+    /// The accesses of a resource.
+    Resource,
+}
+
+/// The accesses of a component or resource type during a sampling window, see
+/// [`AccessStatsReport`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccessStats {
+    /// The id of the component or resource.
+    pub id: ComponentId,
+    /// The name of the component or resource type.
+    pub name: String,
+    /// Whether this is a component or a resource.
+    pub kind: AccessStatsKind,
+    /// The number of systems that read this type.
+    pub readers: usize,
+    /// The number of systems that wrote this type.
+    pub writers: usize,
+    /// The number of entities with this component each time a system accessing it ran, summed
+    /// over the window.
+    ///
+    /// This counts the entities of every archetype with the component, not the ones the systems
+    /// actually iterated, so it is only an upper bound of the entities touched: the filters of the
+    /// queries and the entities they skip aren't taken into account. This is always `0` for
+    /// resources.
+    pub entities_matched: u64,
+}
+
+/// The statistics of the accesses of each component and resource type over a sampling window,
+/// produced by the [`AccessStatsPlugin`].
+///
+/// The types that weren't accessed by any system during the window are not listed.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AccessStatsReport {
+    /// The number of frames sampled.
+    pub frames: u32,
+    /// The statistics of each accessed type, sorted by name.
+    pub entries: Vec<AccessStats>,
+}
+
+impl AccessStatsReport {
+    /// Returns the statistics of the component or resource `id`, if it was accessed.
+    pub fn get(&self, id: ComponentId) -> Option<&AccessStats> {
+        self.entries.iter().find(|stats| stats.id == id)
+    }
+
+    /// Exports the report as CSV, with a header row and the columns `name`, `kind`, `readers`,
+    /// `writers` and `entities_matched`.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("name,kind,readers,writers,entities_matched\n");
+        for stats in &self.entries {
+            let kind = match stats.kind {
+                AccessStatsKind::Component => "component",
+                AccessStatsKind::Resource => "resource",
+            };
+            // Type names contain commas in their generics, so they are always quoted.
+            let _ = writeln!(
+                csv,
+                "\"{}\",{kind},{},{},{}",
+                stats.name.replace('"', "\"\""),
+                stats.readers,
+                stats.writers,
+                stats.entities_matched
+            );
+        }
+        csv
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AccessStats, AccessStatsKind, AccessStatsPlugin, AccessStatsReport};
+    use crate::AccessStatsSampler;
+    use alloc::{string::String, vec};
+    use bevy_app::prelude::*;
+    use bevy_ecs::{component::ComponentId, prelude::*};
+
+    #[derive(Component)]
+    struct Position;
+
+    #[derive(Component)]
+    struct Velocity;
+
+    #[derive(Resource)]
+    struct Gravity;
+
+    fn read_positions(_: Query<&Position>) {}
+
+    fn move_entities(_: Query<(&mut Position, &Velocity)>, _: Res<Gravity>) {}
+
+    fn change_gravity(_: ResMut<Gravity>) {}
+
+    fn read_velocities(_: Query<&Velocity>) {}
+
+    fn app(sample_frames: u32) -> App {
+        let mut app = App::new();
+        app.add_plugins(AccessStatsPlugin { sample_frames })
+            .insert_resource(Gravity)
+            .add_systems(
+                Update,
+                (
+                    read_positions,
+                    move_entities,
+                    change_gravity,
+                    read_velocities.run_if(|| false),
+                ),
+            );
+        let world = app.world_mut();
+        world.spawn_batch((0..10).map(|_| (Position, Velocity)));
+        world.spawn_batch((0..5).map(|_| Position));
+        app
+    }
+
+    fn sampler(app: &mut App) -> Mut<'_, AccessStatsSampler> {
+        app.world_mut().resource_mut::<AccessStatsSampler>()
+    }
+
+    #[test]
+    fn counts_accesses() {
+        let mut app = app(4);
+        for _ in 0..6 {
+            app.update();
+        }
+
+        let world = app.world();
+        let position = world.component_id::<Position>().unwrap();
+        let velocity = world.component_id::<Velocity>().unwrap();
+        let gravity = world.resource_id::<Gravity>().unwrap();
+        let report = world.resource::<AccessStatsSampler>().report().unwrap();
+        assert_eq!(report.frames, 4);
+
+        let position = report.get(position).unwrap();
+        assert_eq!(position.kind, AccessStatsKind::Component);
+        assert_eq!((position.readers, position.writers), (1, 1));
+        // Two systems touching the 15 positions in each of the 4 frames.
+        assert_eq!(position.entities_matched, 2 * 15 * 4);
+
+        // The condition of `read_velocities` is never met.
+        let velocity = report.get(velocity).unwrap();
+        assert_eq!((velocity.readers, velocity.writers), (1, 0));
+        assert_eq!(velocity.entities_matched, 10 * 4);
+
+        let gravity = report.get(gravity).unwrap();
+        assert_eq!(gravity.kind, AccessStatsKind::Resource);
+        assert_eq!((gravity.readers, gravity.writers), (1, 1));
+        assert_eq!(gravity.entities_matched, 0);
+    }
+
+    #[test]
+    fn sampling_window() {
+        let mut app = app(0);
+        app.update();
+        assert!(!sampler(&mut app).is_sampling());
+        assert!(sampler(&mut app).report().is_none());
+        assert!(sampler(&mut app).stop().is_none());
+
+        sampler(&mut app).start(2);
+        app.update();
+        assert_eq!(sampler(&mut app).remaining_frames(), 1);
+        assert!(sampler(&mut app).report().is_none());
+        app.update();
+        assert!(!sampler(&mut app).is_sampling());
+        assert_eq!(sampler(&mut app).report().unwrap().frames, 2);
+
+        // The report doesn't change once the window is over.
+        let report = sampler(&mut app).report().cloned();
+        app.update();
+        assert_eq!(sampler(&mut app).report().cloned(), report);
+
+        // Stopping early reports the frames sampled so far.
+        sampler(&mut app).start(10);
+        assert!(sampler(&mut app).report().is_none());
+        app.update();
+        assert_eq!(sampler(&mut app).stop().unwrap().frames, 1);
+        assert!(!sampler(&mut app).is_sampling());
+    }
+
+    #[test]
+    fn csv_export() {
+        let report = AccessStatsReport {
+            frames: 2,
+            entries: vec![
+                AccessStats {
+                    id: ComponentId::new(0),
+                    name: String::from("game::Health<f32, \"hp\">"),
+                    kind: AccessStatsKind::Component,
+                    readers: 3,
+                    writers: 1,
+                    entities_matched: 40,
+                },
+                AccessStats {
+                    id: ComponentId::new(1),
+                    name: String::from("game::Score"),
+                    kind: AccessStatsKind::Resource,
+                    readers: 2,
+                    writers: 0,
+                    entities_matched: 0,
+                },
+            ],
+        };
+
+        assert_eq!(
+            report.to_csv(),
+            "name,kind,readers,writers,entities_matched\n\
+             \"game::Health<f32, \"\"hp\"\">\",component,3,1,40\n\
+             \"game::Score\",resource,2,0,0\n"
+        );
+    }
+}
@@ -0,0 +1,341 @@
+use alloc::vec::Vec;
+use bevy_app::prelude::*;
+use bevy_ecs::{
+    event::{BufferedEvent, EventCounts, EventRegistry, EventUpdateSystems},
+    prelude::*,
+};
+use bevy_platform::{
+    collections::{HashMap, HashSet},
+    time::Instant,
+};
+
+use crate::{
+    Diagnostic, DiagnosticMeasurement, DiagnosticPath, DiagnosticsStore, DEFAULT_MAX_HISTORY_LENGTH,
+};
+
+/// Adds "event count" diagnostics to an App: how many events of each type were written and read
+/// during the frame, as `events/written/<type>` and `events/read/<type>`, for example
+/// `events/written/my_game::Damage`, to catch the systems writing far more events than expected.
+///
+/// The events are counted by the [`Events`] themselves as they are written and read, see
+/// [`Events::counts`], and the counts are sampled right after the events are updated, at the start
+/// of each frame. The plugin turns the counting of the reads on with [`Events::count_reads`]. An
+/// event read by several readers is counted once per reader, once the reader iterated past it.
+///
+/// All the event types registered with [`App::add_event`] are measured, unless some are picked
+/// with [`with_event`](Self::with_event). To keep the number of diagnostics bounded, only the
+/// first [`max_tracked_events`](Self::max_tracked_events) types get their own diagnostics. The
+/// events of the other types are summed in [`EventCountDiagnosticsPlugin::WRITTEN_OTHER`] and
+/// [`EventCountDiagnosticsPlugin::READ_OTHER`].
+///
+/// ```
+/// # use bevy_app::prelude::*;
+/// # use bevy_diagnostic::EventCountDiagnosticsPlugin;
+/// # use bevy_ecs::prelude::*;
+/// # #[derive(BufferedEvent)]
+/// # struct Damage;
+/// # #[derive(BufferedEvent)]
+/// # struct Collision;
+/// App::new().add_plugins(
+///     EventCountDiagnosticsPlugin::default()
+///         .with_event::<Damage>()
+///         .with_event::<Collision>(),
+/// );
+/// ```
+///
+/// # See also
+///
+/// [`LogDiagnosticsPlugin`](crate::LogDiagnosticsPlugin) to output diagnostics to the console.
+pub struct EventCountDiagnosticsPlugin {
+    /// The maximum number of event types with their own diagnostics.
+    pub max_tracked_events: usize,
+    /// The total number of values to keep for each diagnostic.
+    pub max_history_length: usize,
+    /// The type names of the events to measure, or `None` for all of them.
+    events: Option<Vec<&'static str>>,
+}
+
+impl Default for EventCountDiagnosticsPlugin {
+    fn default() -> Self {
+        Self {
+            max_tracked_events: 64,
+            max_history_length: DEFAULT_MAX_HISTORY_LENGTH,
+            events: None,
+        }
+    }
+}
+
+impl EventCountDiagnosticsPlugin {
+    /// Measures the events of type `E`. Once an event type is picked, the events of the types
+    /// that aren't picked are not measured.
+    pub fn with_event<E: BufferedEvent>(mut self) -> Self {
+        self.events
+            .get_or_insert_with(Vec::new)
+            .push(core::any::type_name::<E>());
+        self
+    }
+
+    /// Gives their own diagnostics to at most `max_tracked_events` event types.
+    pub fn with_max_tracked_events(mut self, max_tracked_events: usize) -> Self {
+        self.max_tracked_events = max_tracked_events;
+        self
+    }
+}
+
+impl Plugin for EventCountDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DiagnosticsStore>()
+            .insert_resource(EventCountStats {
+                max_tracked_events: self.max_tracked_events,
+                max_history_length: self.max_history_length,
+                events: self
+                    .events
+                    .as_ref()
+                    .map(|events| events.iter().copied().collect()),
+                tracked: HashSet::default(),
+                previous: HashMap::default(),
+            })
+            .add_systems(First, Self::diagnostic_system.after(EventUpdateSystems));
+    }
+}
+
+impl EventCountDiagnosticsPlugin {
+    /// The events written of the types past [`max_tracked_events`](Self::max_tracked_events).
+    pub const WRITTEN_OTHER: DiagnosticPath = DiagnosticPath::const_new("events/written/other");
+
+    /// The events read of the types past [`max_tracked_events`](Self::max_tracked_events).
+    pub const READ_OTHER: DiagnosticPath = DiagnosticPath::const_new("events/read/other");
+
+    /// Returns the path of the diagnostic of the events of type `E` written during the frame.
+    pub fn written_path<E: BufferedEvent>() -> DiagnosticPath {
+        Self::path("written", core::any::type_name::<E>())
+    }
+
+    /// Returns the path of the diagnostic of the events of type `E` read during the frame.
+    pub fn read_path<E: BufferedEvent>() -> DiagnosticPath {
+        Self::path("read", core::any::type_name::<E>())
+    }
+
+    fn path(kind: &str, type_name: &str) -> DiagnosticPath {
+        DiagnosticPath::from_components(["events", kind, type_name])
+    }
+
+    /// Updates the event count measurements.
+    pub fn diagnostic_system(world: &mut World) {
+        // The event types registered since the last run start counting their reads too.
+        if world
+            .try_resource_scope(|world, registry: Mut<EventRegistry>| {
+                registry.count_all_reads(world);
+            })
+            .is_none()
+        {
+            return;
+        }
+        let registry = world.resource::<EventRegistry>();
+        let counts = registry.iter_counts(world).collect::<Vec<_>>();
+        world.resource_scope(|world, mut stats: Mut<EventCountStats>| {
+            let frame = stats.record_frame(counts);
+            let now = Instant::now();
+            let mut diagnostics = world.resource_mut::<DiagnosticsStore>();
+            for (path, count) in frame {
+                if diagnostics.get(&path).is_none() {
+                    diagnostics.add(
+                        Diagnostic::new(path.clone())
+                            .with_max_history_length(stats.max_history_length),
+                    );
+                }
+                if let Some(diagnostic) = diagnostics.get_mut(&path) {
+                    diagnostic.add_measurement(DiagnosticMeasurement {
+                        time: now,
+                        value: count as f64,
+                    });
+                }
+            }
+        });
+    }
+}
+
+/// The event types measured by the [`EventCountDiagnosticsPlugin`].
+#[derive(Resource, Debug)]
+pub struct EventCountStats {
+    max_tracked_events: usize,
+    max_history_length: usize,
+    events: Option<HashSet<&'static str>>,
+    tracked: HashSet<&'static str>,
+    previous: HashMap<&'static str, EventCounts>,
+}
+
+impl EventCountStats {
+    /// Returns `true` if the events of the type named `type_name` have their own diagnostics,
+    /// instead of being counted in [`EventCountDiagnosticsPlugin::WRITTEN_OTHER`] and
+    /// [`EventCountDiagnosticsPlugin::READ_OTHER`].
+    pub fn is_tracked(&self, type_name: &str) -> bool {
+        self.tracked.contains(type_name)
+    }
+
+    /// Iterates over the type names of the events that have their own diagnostics.
+    pub fn tracked(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.tracked.iter().copied()
+    }
+
+    /// Records the counts of the events since they were created, returning the number of events
+    /// written and read during the frame for each diagnostic.
+    fn record_frame(
+        &mut self,
+        counts: impl IntoIterator<Item = (&'static str, EventCounts)>,
+    ) -> HashMap<DiagnosticPath, usize> {
+        let mut frame = HashMap::<DiagnosticPath, usize>::default();
+        for (type_name, counts) in counts {
+            if self
+                .events
+                .as_ref()
+                .is_some_and(|events| !events.contains(type_name))
+            {
+                continue;
+            }
+            let mut previous = self.previous.insert(type_name, counts).unwrap_or_default();
+            if counts.written < previous.written {
+                // The events were removed and registered again, so they are counted from zero.
+                previous = EventCounts::default();
+            }
+            let written = counts.written - previous.written;
+            let read = counts.read.saturating_sub(previous.read);
+
+            let tracked = self.tracked.contains(type_name)
+                || (self.tracked.len() < self.max_tracked_events && self.tracked.insert(type_name));
+            let (written_path, read_path) = if tracked {
+                (
+                    EventCountDiagnosticsPlugin::path("written", type_name),
+                    EventCountDiagnosticsPlugin::path("read", type_name),
+                )
+            } else {
+                (
+                    EventCountDiagnosticsPlugin::WRITTEN_OTHER,
+                    EventCountDiagnosticsPlugin::READ_OTHER,
+                )
+            };
+            *frame.entry(written_path).or_default() += written;
+            *frame.entry(read_path).or_default() += read;
+        }
+        frame
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EventCountDiagnosticsPlugin, EventCountStats};
+    use crate::{DiagnosticPath, DiagnosticsStore};
+    use alloc::vec::Vec;
+    use bevy_app::prelude::*;
+    use bevy_ecs::prelude::*;
+
+    #[derive(BufferedEvent)]
+    struct Damage;
+
+    #[derive(BufferedEvent)]
+    struct Collision;
+
+    #[derive(Resource)]
+    struct Burst(usize);
+
+    fn write_burst(burst: Res<Burst>, mut damage: EventWriter<Damage>) {
+        damage.write_batch((0..burst.0).map(|_| Damage));
+    }
+
+    fn read_damage(mut damage: EventReader<Damage>) {
+        damage.read().for_each(drop);
+    }
+
+    fn values(app: &App, path: &DiagnosticPath) -> Vec<f64> {
+        let diagnostics = app.world().resource::<DiagnosticsStore>();
+        diagnostics.get(path).unwrap().values().copied().collect()
+    }
+
+    #[test]
+    fn events_are_counted_per_frame() {
+        let mut app = App::new();
+        app.add_plugins(EventCountDiagnosticsPlugin::default())
+            .add_event::<Damage>()
+            .insert_resource(Burst(0))
+            .add_systems(Update, (write_burst, read_damage).chain());
+        for burst in [3, 10, 0, 5] {
+            app.world_mut().resource_mut::<Burst>().0 = burst;
+            app.update();
+        }
+        // The events of a frame are measured at the start of the next one.
+        app.update();
+
+        let written = EventCountDiagnosticsPlugin::written_path::<Damage>();
+        let read = EventCountDiagnosticsPlugin::read_path::<Damage>();
+        assert_eq!(values(&app, &written), [0.0, 3.0, 10.0, 0.0, 5.0]);
+        assert_eq!(values(&app, &read), [0.0, 3.0, 10.0, 0.0, 5.0]);
+        assert!(written.as_str().ends_with("::Damage"));
+    }
+
+    #[test]
+    fn only_the_events_iterated_past_are_read() {
+        let mut app = App::new();
+        app.add_plugins(EventCountDiagnosticsPlugin::default())
+            .add_event::<Damage>()
+            .insert_resource(Burst(3))
+            .add_systems(
+                Update,
+                (write_burst, |mut damage: EventReader<Damage>| {
+                    damage.read().next();
+                })
+                    .chain(),
+            );
+        app.update();
+        app.update();
+
+        let read = EventCountDiagnosticsPlugin::read_path::<Damage>();
+        assert_eq!(values(&app, &read), [0.0, 1.0]);
+    }
+
+    #[test]
+    fn only_the_picked_events_are_counted() {
+        let mut app = App::new();
+        app.add_plugins(EventCountDiagnosticsPlugin::default().with_event::<Collision>())
+            .add_event::<Damage>()
+            .add_event::<Collision>()
+            .add_systems(Update, |mut collisions: EventWriter<Collision>| {
+                collisions.write(Collision);
+            });
+        app.update();
+        app.update();
+
+        let diagnostics = app.world().resource::<DiagnosticsStore>();
+        assert!(diagnostics
+            .get(&EventCountDiagnosticsPlugin::written_path::<Damage>())
+            .is_none());
+        let written = EventCountDiagnosticsPlugin::written_path::<Collision>();
+        assert_eq!(values(&app, &written), [0.0, 1.0]);
+    }
+
+    #[test]
+    fn untracked_events_are_summed() {
+        let mut app = App::new();
+        app.add_plugins(EventCountDiagnosticsPlugin::default().with_max_tracked_events(2))
+            .add_event::<Damage>()
+            .add_event::<Collision>()
+            .insert_resource(Burst(2))
+            .add_systems(
+                Update,
+                (write_burst, |mut collisions: EventWriter<Collision>| {
+                    collisions.write(Collision);
+                }),
+            );
+        app.update();
+        app.update();
+
+        // The types are tracked in the order they were registered, after `AppExit`.
+        let stats = app.world().resource::<EventCountStats>();
+        assert!(stats.is_tracked(core::any::type_name::<AppExit>()));
+        assert!(stats.is_tracked(core::any::type_name::<Damage>()));
+        assert!(!stats.is_tracked(core::any::type_name::<Collision>()));
+        let written = EventCountDiagnosticsPlugin::written_path::<Damage>();
+        assert_eq!(values(&app, &written), [0.0, 2.0]);
+        let other = EventCountDiagnosticsPlugin::WRITTEN_OTHER;
+        assert_eq!(values(&app, &other), [0.0, 1.0]);
+    }
+}
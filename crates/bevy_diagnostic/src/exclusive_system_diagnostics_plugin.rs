@@ -0,0 +1,422 @@
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use bevy_app::prelude::*;
+use bevy_ecs::{
+    prelude::*,
+    schedule::{ExclusiveSystemTimings, InternedScheduleLabel, Schedules},
+};
+use bevy_platform::{collections::HashMap, time::Instant};
+use core::{fmt::Write, time::Duration};
+use log::warn;
+
+use crate::{Diagnostic, DiagnosticMeasurement, DiagnosticPath, DiagnosticsStore};
+
+/// Adds "exclusive system" diagnostics to an App: how long each exclusive system ran during the
+/// frame, as `exclusive/<system>/ms`.
+///
+/// Exclusive systems need the whole [`World`], so no other system of their schedule can run at the
+/// same time. A long exclusive system stalls the whole frame. The frames in which an exclusive
+/// system ran longer than [`threshold`](Self::threshold) are counted in the
+/// [`ExclusiveSystemStats`], and a warning naming the worst offender is logged at most once per
+/// [`warn_interval`](Self::warn_interval).
+///
+/// # See also
+///
+/// [`LogDiagnosticsPlugin`](crate::LogDiagnosticsPlugin) to output diagnostics to the console.
+pub struct ExclusiveSystemDiagnosticsPlugin {
+    /// How long an exclusive system can run in a frame before it is considered a stall.
+    pub threshold: Duration,
+    /// The minimum time between two warnings about stalls.
+    pub warn_interval: Duration,
+    /// The total number of values to keep for each diagnostic.
+    pub max_history_length: usize,
+}
+
+impl Default for ExclusiveSystemDiagnosticsPlugin {
+    fn default() -> Self {
+        Self {
+            threshold: Duration::from_millis(4),
+            warn_interval: Duration::from_secs(10),
+            max_history_length: crate::DEFAULT_MAX_HISTORY_LENGTH,
+        }
+    }
+}
+
+impl Plugin for ExclusiveSystemDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DiagnosticsStore>()
+            .init_resource::<ExclusiveSystemTimings>()
+            .insert_resource(ExclusiveSystemStats {
+                threshold: self.threshold,
+                warn_interval: self.warn_interval,
+                max_history_length: self.max_history_length,
+                systems: HashMap::default(),
+                last_warning: None,
+            })
+            .add_systems(Last, Self::diagnostic_system);
+    }
+}
+
+impl ExclusiveSystemDiagnosticsPlugin {
+    /// Returns the path of the diagnostic of the exclusive system named `name`.
+    pub fn path(name: &str) -> DiagnosticPath {
+        DiagnosticPath::from_components(["exclusive", name, "ms"])
+    }
+
+    /// Updates the exclusive system measurements and stats, and warns about stalls.
+    pub fn diagnostic_system(
+        mut timings: ResMut<ExclusiveSystemTimings>,
+        mut stats: ResMut<ExclusiveSystemStats>,
+        mut diagnostics: ResMut<DiagnosticsStore>,
+        schedules: Res<Schedules>,
+    ) {
+        let now = Instant::now();
+        let frame =
+            stats.record_frame(timings.drain().map(|(name, time)| (name.to_string(), time)));
+        for (name, time) in frame {
+            let path = Self::path(&name);
+            if diagnostics.get(&path).is_none() {
+                diagnostics.add(
+                    Diagnostic::new(path.clone())
+                        .with_suffix("ms")
+                        .with_max_history_length(stats.max_history_length),
+                );
+            }
+            if let Some(diagnostic) = diagnostics.get_mut(&path) {
+                diagnostic.add_measurement(DiagnosticMeasurement {
+                    time: now,
+                    value: time.as_secs_f64() * 1000.0,
+                });
+            }
+        }
+
+        let Some((name, frames)) = stats.warning(now) else {
+            return;
+        };
+        let mut message = format!(
+            "Exclusive system `{name}` stalled its schedule for more than {:?} in {frames} frame(s)",
+            stats.threshold
+        );
+        let waiting = waiting_systems(&schedules)
+            .into_iter()
+            .filter(|waiting| waiting.exclusive_system == name)
+            .flat_map(|waiting| waiting.waiting)
+            .collect::<Vec<_>>();
+        if !waiting.is_empty() {
+            let _ = write!(
+                message,
+                ". Systems that could otherwise run in parallel: {}",
+                waiting.join(", ")
+            );
+        }
+        warn!("{message}");
+    }
+}
+
+/// Stats about the exclusive systems timed by the [`ExclusiveSystemDiagnosticsPlugin`].
+#[derive(Resource, Debug)]
+pub struct ExclusiveSystemStats {
+    threshold: Duration,
+    warn_interval: Duration,
+    max_history_length: usize,
+    systems: HashMap<String, ExclusiveSystemStat>,
+    last_warning: Option<Instant>,
+}
+
+/// Stats about an exclusive system, see [`ExclusiveSystemStats`].
+///
+/// When a system runs several times in a frame, for example in `FixedUpdate`, the times are summed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ExclusiveSystemStat {
+    /// How long the system ran in the last frame it ran.
+    pub last: Duration,
+    /// The longest the system ran in a frame.
+    pub max: Duration,
+    /// The number of frames in which the system ran.
+    pub frames: u64,
+    /// The number of frames in which the system ran longer than the threshold.
+    pub frames_over_threshold: u64,
+    /// The value of `frames_over_threshold` when the system was last warned about.
+    warned_frames_over_threshold: u64,
+}
+
+impl ExclusiveSystemStats {
+    /// Returns how long an exclusive system can run in a frame before it is considered a stall.
+    pub fn threshold(&self) -> Duration {
+        self.threshold
+    }
+
+    /// Returns the stats of the exclusive system named `name`, if it ran.
+    pub fn get(&self, name: &str) -> Option<&ExclusiveSystemStat> {
+        self.systems.get(name)
+    }
+
+    /// Iterates over the stats of the exclusive systems that ran, by name.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &ExclusiveSystemStat)> {
+        self.systems
+            .iter()
+            .map(|(name, stat)| (name.as_str(), stat))
+    }
+
+    /// Returns the exclusive system that ran longer than the threshold in the most frames, if any.
+    pub fn worst(&self) -> Option<(&str, &ExclusiveSystemStat)> {
+        self.iter()
+            .filter(|(_, stat)| stat.frames_over_threshold > 0)
+            .max_by_key(|(name, stat)| (stat.frames_over_threshold, stat.max, *name))
+    }
+
+    /// Records the timings of a frame, returning the total time of each system.
+    fn record_frame(
+        &mut self,
+        timings: impl Iterator<Item = (String, Duration)>,
+    ) -> HashMap<String, Duration> {
+        let mut frame = HashMap::<String, Duration>::default();
+        for (name, time) in timings {
+            *frame.entry(name).or_default() += time;
+        }
+        for (name, &time) in &frame {
+            let stat = self.systems.entry(name.clone()).or_default();
+            stat.last = time;
+            stat.max = stat.max.max(time);
+            stat.frames += 1;
+            if time > self.threshold {
+                stat.frames_over_threshold += 1;
+            }
+        }
+        frame
+    }
+
+    /// Returns the system that ran longer than the threshold in the most frames since the last
+    /// warning, if a warning is due at `now`, along with that number of frames.
+    fn warning(&mut self, now: Instant) -> Option<(String, u64)> {
+        if let Some(last_warning) = self.last_warning
+            && now.saturating_duration_since(last_warning) < self.warn_interval
+        {
+            return None;
+        }
+        let warning = self
+            .systems
+            .iter()
+            .map(|(name, stat)| {
+                let frames = stat.frames_over_threshold - stat.warned_frames_over_threshold;
+                (name, stat, frames)
+            })
+            .filter(|(_, _, frames)| *frames > 0)
+            .max_by_key(|(name, stat, frames)| (*frames, stat.max, *name))
+            .map(|(name, _, frames)| (name.clone(), frames))?;
+        for stat in self.systems.values_mut() {
+            stat.warned_frames_over_threshold = stat.frames_over_threshold;
+        }
+        self.last_warning = Some(now);
+        Some(warning)
+    }
+}
+
+/// The systems that had to wait for an exclusive system, see [`waiting_systems`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WaitingSystems {
+    /// The schedule of the systems.
+    pub schedule: InternedScheduleLabel,
+    /// The name of the exclusive system.
+    pub exclusive_system: String,
+    /// The names of the systems that aren't ordered with the exclusive system, so they could
+    /// otherwise run in parallel with it.
+    pub waiting: Vec<String>,
+}
+
+/// Lists, for each exclusive system of the initialized schedules, the non-exclusive systems that
+/// had to wait for it.
+///
+/// This is derived from the structure of the schedules: a system waits for an exclusive system if
+/// they aren't ordered with each other, so it could have run in parallel with a non-exclusive
+/// system. Systems ordered after the exclusive system also wait for it, but would have anyway.
+/// Systems marked as `ambiguous_with_all` aren't listed.
+///
+/// Schedules that were never run are skipped, as are the schedules that are running, such as the
+/// one of the calling system, since they are not in [`Schedules`].
+pub fn waiting_systems(schedules: &Schedules) -> Vec<WaitingSystems> {
+    let mut result = Vec::new();
+    for (_, schedule) in schedules.iter() {
+        // The systems are moved out of the graph once the schedule is initialized.
+        let Ok(systems) = schedule.systems() else {
+            continue;
+        };
+        let systems = systems.collect::<HashMap<_, _>>();
+        let mut waiting = HashMap::<String, Vec<String>>::default();
+        for (a, b, _) in schedule.graph().conflicting_systems() {
+            let (Some(a), Some(b)) = (systems.get(a), systems.get(b)) else {
+                continue;
+            };
+            match (a.is_exclusive(), b.is_exclusive()) {
+                (true, false) => waiting
+                    .entry(a.name().to_string())
+                    .or_default()
+                    .push(b.name().to_string()),
+                (false, true) => waiting
+                    .entry(b.name().to_string())
+                    .or_default()
+                    .push(a.name().to_string()),
+                _ => {}
+            }
+        }
+        for (exclusive_system, mut waiting) in waiting {
+            waiting.sort();
+            result.push(WaitingSystems {
+                schedule: schedule.label(),
+                exclusive_system,
+                waiting,
+            });
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{waiting_systems, ExclusiveSystemDiagnosticsPlugin, ExclusiveSystemStats};
+    use crate::DiagnosticsStore;
+    use alloc::{
+        string::{String, ToString},
+        vec,
+    };
+    use bevy_app::prelude::*;
+    use bevy_ecs::{prelude::*, schedule::ScheduleLabel};
+    use bevy_platform::time::Instant;
+    use core::time::Duration;
+    use std::thread;
+
+    fn slow(_: &mut World) {
+        thread::sleep(Duration::from_millis(20));
+    }
+
+    fn fast(_: &mut World) {}
+
+    fn parallel(_: Query<Entity>) {}
+
+    fn name<M>(system: impl IntoSystem<(), (), M>) -> String {
+        IntoSystem::into_system(system).name().to_string()
+    }
+
+    fn app() -> App {
+        let mut app = App::new();
+        app.add_plugins(ExclusiveSystemDiagnosticsPlugin {
+            threshold: Duration::from_millis(10),
+            ..Default::default()
+        });
+        app
+    }
+
+    #[test]
+    fn slow_exclusive_system_is_reported() {
+        let mut app = app();
+        app.add_systems(Update, (slow, fast, parallel));
+        app.update();
+
+        let stats = app.world().resource::<ExclusiveSystemStats>();
+        let (worst, stat) = stats.worst().unwrap();
+        assert_eq!(worst, name(slow));
+        assert!(stat.max >= Duration::from_millis(20));
+        assert_eq!(stats.get(&name(fast)).unwrap().frames_over_threshold, 0);
+
+        let diagnostics = app.world().resource::<DiagnosticsStore>();
+        let slow = diagnostics
+            .get(&ExclusiveSystemDiagnosticsPlugin::path(&name(slow)))
+            .unwrap();
+        assert!(slow.value().unwrap() >= 20.0);
+        assert!(diagnostics
+            .get(&ExclusiveSystemDiagnosticsPlugin::path(&name(fast)))
+            .is_some());
+    }
+
+    #[test]
+    fn frames_over_threshold_are_counted() {
+        #[derive(Resource)]
+        struct SleepMs(u64);
+
+        fn sleepy(world: &mut World) {
+            thread::sleep(Duration::from_millis(world.resource::<SleepMs>().0));
+        }
+
+        let mut app = app();
+        // Runs twice a frame: the times of both runs are summed.
+        app.insert_resource(SleepMs(6))
+            .add_systems(Update, (sleepy, sleepy));
+        app.update();
+        app.world_mut().resource_mut::<SleepMs>().0 = 20;
+        app.update();
+        app.world_mut().resource_mut::<SleepMs>().0 = 0;
+        app.update();
+
+        let stats = app.world().resource::<ExclusiveSystemStats>();
+        let stat = stats.get(&name(sleepy)).unwrap();
+        assert_eq!((stat.frames, stat.frames_over_threshold), (3, 2));
+        assert!(stat.max >= Duration::from_millis(40));
+        assert!(stat.last < Duration::from_millis(10));
+    }
+
+    #[test]
+    fn warnings_are_throttled() {
+        let mut stats = ExclusiveSystemStats {
+            threshold: Duration::from_millis(10),
+            warn_interval: Duration::from_secs(10),
+            max_history_length: 0,
+            systems: Default::default(),
+            last_warning: None,
+        };
+        let start = Instant::now();
+        let frame =
+            |system: &str, ms| [(system.to_string(), Duration::from_millis(ms))].into_iter();
+
+        // No warning until a system goes over the threshold.
+        stats.record_frame(frame("a", 5));
+        assert_eq!(stats.warning(start), None);
+
+        stats.record_frame(frame("a", 15));
+        stats.record_frame(frame("b", 50));
+        stats.record_frame(frame("a", 15));
+        assert_eq!(stats.warning(start), Some(("a".to_string(), 2)));
+        let a = stats.get("a").unwrap();
+        assert_eq!((a.frames, a.frames_over_threshold), (3, 2));
+
+        // At most one warning per interval, only about the stalls since the last warning.
+        stats.record_frame(frame("b", 50));
+        assert_eq!(stats.warning(start + Duration::from_secs(5)), None);
+        assert_eq!(
+            stats.warning(start + Duration::from_secs(10)),
+            Some(("b".to_string(), 1))
+        );
+        assert_eq!(stats.warning(start + Duration::from_secs(30)), None);
+    }
+
+    #[test]
+    fn non_exclusive_systems_have_no_entries() {
+        let mut app = app();
+        app.add_systems(Update, (parallel, fast.after(parallel), slow));
+        app.update();
+
+        let stats = app.world().resource::<ExclusiveSystemStats>();
+        assert!(stats.get(&name(slow)).is_some());
+        assert!(stats.get(&name(fast)).is_some());
+        assert!(stats.get(&name(parallel)).is_none());
+        let diagnostics = app.world().resource::<DiagnosticsStore>();
+        assert!(diagnostics
+            .get(&ExclusiveSystemDiagnosticsPlugin::path(&name(parallel)))
+            .is_none());
+
+        // `parallel` isn't ordered with `slow`, so it waits for it.
+        let waiting = waiting_systems(app.world().resource::<Schedules>());
+        let waiting_for_slow = waiting
+            .iter()
+            .find(|waiting| waiting.exclusive_system == name(slow))
+            .unwrap();
+        assert_eq!(waiting_for_slow.schedule, Update.intern());
+        assert_eq!(waiting_for_slow.waiting, vec![name(parallel)]);
+        // `fast` is ordered after `parallel`, and only conflicts with the other exclusive system.
+        assert!(waiting
+            .iter()
+            .all(|waiting| waiting.exclusive_system != name(fast)));
+    }
+}
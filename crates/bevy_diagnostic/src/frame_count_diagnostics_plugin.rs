@@ -1,4 +1,4 @@
-use bevy_app::prelude::*;
+use bevy_app::{prelude::*, FrameNumber};
 use bevy_ecs::prelude::*;
 
 #[cfg(feature = "serialize")]
@@ -33,9 +33,19 @@ impl Plugin for FrameCountPlugin {
 
 /// A system used to increment [`FrameCount`] with wrapping addition.
 ///
+/// When the [`FrameNumber`] of the app exists, [`FrameCount`] is derived from it instead, so that
+/// both counters agree.
+///
 /// See [`FrameCount`] for more details.
-pub fn update_frame_count(mut frame_count: ResMut<FrameCount>) {
-    frame_count.0 = frame_count.0.wrapping_add(1);
+pub fn update_frame_count(
+    mut frame_count: ResMut<FrameCount>,
+    frame_number: Option<Res<FrameNumber>>,
+) {
+    frame_count.0 = match frame_number {
+        // `FrameNumber` is advanced after `Last`, when this frame is over.
+        Some(frame_number) => frame_number.get().wrapping_add(1) as u32,
+        None => frame_count.0.wrapping_add(1),
+    };
 }
 
 #[cfg(feature = "serialize")]
@@ -85,6 +95,17 @@ mod tests {
         let frame_count = app.world().resource::<FrameCount>();
         assert_eq!(1, frame_count.0);
     }
+
+    #[test]
+    fn frame_counter_follows_frame_number() {
+        let mut app = App::new();
+        app.update();
+        app.add_plugins(FrameCountPlugin);
+        let stats = app.update_with_stats();
+
+        assert_eq!(stats.frame, 1);
+        assert_eq!(2, app.world().resource::<FrameCount>().0);
+    }
 }
 
 #[cfg(all(test, feature = "serialize"))]
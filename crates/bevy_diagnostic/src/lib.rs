@@ -15,22 +15,36 @@ extern crate std;
 
 extern crate alloc;
 
+mod access_stats_plugin;
 mod diagnostic;
 mod entity_count_diagnostics_plugin;
+mod event_count_diagnostics_plugin;
+mod exclusive_system_diagnostics_plugin;
 mod frame_count_diagnostics_plugin;
 mod frame_time_diagnostics_plugin;
 mod log_diagnostics_plugin;
 #[cfg(feature = "sysinfo_plugin")]
 mod system_information_diagnostics_plugin;
+mod system_time_diagnostics_plugin;
 
 pub use diagnostic::*;
 
+pub use access_stats_plugin::{
+    AccessStats, AccessStatsKind, AccessStatsPlugin, AccessStatsReport, AccessStatsSampler,
+};
+
 pub use entity_count_diagnostics_plugin::EntityCountDiagnosticsPlugin;
+pub use event_count_diagnostics_plugin::{EventCountDiagnosticsPlugin, EventCountStats};
+pub use exclusive_system_diagnostics_plugin::{
+    waiting_systems, ExclusiveSystemDiagnosticsPlugin, ExclusiveSystemStat, ExclusiveSystemStats,
+    WaitingSystems,
+};
 pub use frame_count_diagnostics_plugin::{update_frame_count, FrameCount, FrameCountPlugin};
 pub use frame_time_diagnostics_plugin::FrameTimeDiagnosticsPlugin;
 pub use log_diagnostics_plugin::{LogDiagnosticsPlugin, LogDiagnosticsState};
 #[cfg(feature = "sysinfo_plugin")]
 pub use system_information_diagnostics_plugin::{SystemInfo, SystemInformationDiagnosticsPlugin};
+pub use system_time_diagnostics_plugin::{SystemTimeDiagnosticsPlugin, SystemTimeStats};
 
 use bevy_app::prelude::*;
 
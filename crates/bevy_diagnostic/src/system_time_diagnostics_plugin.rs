@@ -0,0 +1,296 @@
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use bevy_app::prelude::*;
+use bevy_ecs::{prelude::*, schedule::SystemTimings};
+use bevy_platform::{
+    collections::{HashMap, HashSet},
+    time::Instant,
+};
+use core::{fmt::Write, time::Duration};
+use log::info;
+
+use crate::{
+    Diagnostic, DiagnosticMeasurement, DiagnosticPath, DiagnosticsStore, DEFAULT_MAX_HISTORY_LENGTH,
+};
+
+/// Adds "system time" diagnostics to an App: how long each system ran during the frame, as
+/// `system_time/<system>`, in milliseconds.
+///
+/// Every system is timed by the schedule executors, without any profiler or tracing backend, so
+/// this also works in headless apps, for example to check the time budget of systems in CI.
+/// When a system runs several times in a frame, for example in `FixedUpdate`, the times are summed.
+///
+/// The times are collected in `Last`, while it is still running. With the multi-threaded
+/// executor, the times of the non-exclusive systems of `Last` are only recorded once it ends, so
+/// they are counted in the next frame. With the other executors, so are the times of the systems
+/// of `Last` that run after the [`diagnostic_system`](Self::diagnostic_system).
+///
+/// To keep the number of diagnostics bounded, only the first
+/// [`max_tracked_systems`](Self::max_tracked_systems) systems that run get their own diagnostic.
+/// The time of the other systems is summed in [`SystemTimeDiagnosticsPlugin::OTHER`].
+///
+/// Add [`log_slowest_systems`](Self::log_slowest_systems) to a schedule, with a run condition, to
+/// log the slowest systems on demand.
+///
+/// # See also
+///
+/// [`LogDiagnosticsPlugin`](crate::LogDiagnosticsPlugin) to output diagnostics to the console.
+pub struct SystemTimeDiagnosticsPlugin {
+    /// The maximum number of systems with their own diagnostic.
+    pub max_tracked_systems: usize,
+    /// The total number of values to keep for each diagnostic.
+    pub max_history_length: usize,
+    /// The smoothing factor for the exponential moving average. Usually `2.0 / (history_length + 1.0)`.
+    pub smoothing_factor: f64,
+}
+
+impl Default for SystemTimeDiagnosticsPlugin {
+    fn default() -> Self {
+        Self {
+            max_tracked_systems: 100,
+            max_history_length: DEFAULT_MAX_HISTORY_LENGTH,
+            smoothing_factor: 2.0 / (DEFAULT_MAX_HISTORY_LENGTH as f64 + 1.0),
+        }
+    }
+}
+
+impl Plugin for SystemTimeDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DiagnosticsStore>()
+            .init_resource::<SystemTimings>()
+            .insert_resource(SystemTimeStats {
+                max_tracked_systems: self.max_tracked_systems,
+                max_history_length: self.max_history_length,
+                smoothing_factor: self.smoothing_factor,
+                tracked: HashSet::default(),
+            })
+            .add_systems(Last, Self::diagnostic_system);
+    }
+}
+
+impl SystemTimeDiagnosticsPlugin {
+    /// The summed time of the systems past [`max_tracked_systems`](Self::max_tracked_systems).
+    pub const OTHER: DiagnosticPath = DiagnosticPath::const_new("system_time/other");
+
+    /// Returns the path of the diagnostic of the system named `name`.
+    pub fn path(name: &str) -> DiagnosticPath {
+        DiagnosticPath::from_components(["system_time", name])
+    }
+
+    /// Updates the system time measurements.
+    pub fn diagnostic_system(
+        mut timings: ResMut<SystemTimings>,
+        mut stats: ResMut<SystemTimeStats>,
+        mut diagnostics: ResMut<DiagnosticsStore>,
+    ) {
+        let now = Instant::now();
+        let frame =
+            stats.record_frame(timings.drain().map(|(name, time)| (name.to_string(), time)));
+        for (path, time) in frame {
+            if diagnostics.get(&path).is_none() {
+                diagnostics.add(
+                    Diagnostic::new(path.clone())
+                        .with_suffix("ms")
+                        .with_max_history_length(stats.max_history_length)
+                        .with_smoothing_factor(stats.smoothing_factor),
+                );
+            }
+            if let Some(diagnostic) = diagnostics.get_mut(&path) {
+                diagnostic.add_measurement(DiagnosticMeasurement {
+                    time: now,
+                    value: time.as_secs_f64() * 1000.0,
+                });
+            }
+        }
+    }
+
+    /// Logs the 10 systems with the highest smoothed time, including
+    /// [`OTHER`](Self::OTHER).
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_diagnostic::SystemTimeDiagnosticsPlugin;
+    /// # use bevy_ecs::prelude::*;
+    /// # #[derive(Resource)]
+    /// # struct DebugMenu { log_slowest: bool }
+    /// let mut app = App::new();
+    /// app.add_plugins(SystemTimeDiagnosticsPlugin::default())
+    ///     .add_systems(
+    ///         Last,
+    ///         SystemTimeDiagnosticsPlugin::log_slowest_systems
+    ///             .run_if(|menu: Res<DebugMenu>| menu.log_slowest),
+    ///     );
+    /// ```
+    pub fn log_slowest_systems(stats: Res<SystemTimeStats>, diagnostics: Res<DiagnosticsStore>) {
+        let mut message = String::from("Slowest systems:");
+        for (name, ms) in stats.slowest(&diagnostics, 10) {
+            let _ = write!(message, "\n{ms:>10.3}ms {name}");
+        }
+        info!("{message}");
+    }
+}
+
+/// The systems timed by the [`SystemTimeDiagnosticsPlugin`].
+#[derive(Resource, Debug)]
+pub struct SystemTimeStats {
+    max_tracked_systems: usize,
+    max_history_length: usize,
+    smoothing_factor: f64,
+    tracked: HashSet<String>,
+}
+
+impl SystemTimeStats {
+    /// Returns `true` if the system named `name` has its own diagnostic, instead of being counted
+    /// in [`SystemTimeDiagnosticsPlugin::OTHER`].
+    pub fn is_tracked(&self, name: &str) -> bool {
+        self.tracked.contains(name)
+    }
+
+    /// Iterates over the names of the systems that have their own diagnostic.
+    pub fn tracked(&self) -> impl Iterator<Item = &str> {
+        self.tracked.iter().map(String::as_str)
+    }
+
+    /// Returns the names and smoothed times in milliseconds of the `count` slowest systems,
+    /// slowest first. The untracked systems are named `other`.
+    pub fn slowest<'a>(
+        &'a self,
+        diagnostics: &DiagnosticsStore,
+        count: usize,
+    ) -> Vec<(&'a str, f64)> {
+        let mut slowest = self
+            .tracked()
+            .map(|name| (name, SystemTimeDiagnosticsPlugin::path(name)))
+            .chain([("other", SystemTimeDiagnosticsPlugin::OTHER)])
+            .filter_map(|(name, path)| Some((name, diagnostics.get(&path)?.smoothed()?)))
+            .collect::<Vec<_>>();
+        slowest.sort_by(|(a_name, a), (b_name, b)| b.total_cmp(a).then(a_name.cmp(b_name)));
+        slowest.truncate(count);
+        slowest
+    }
+
+    /// Records the timings of a frame, returning the total time of each diagnostic.
+    fn record_frame(
+        &mut self,
+        timings: impl Iterator<Item = (String, Duration)>,
+    ) -> HashMap<DiagnosticPath, Duration> {
+        let mut frame = HashMap::<DiagnosticPath, Duration>::default();
+        for (name, time) in timings {
+            let path = if self.tracked.contains(&name) {
+                SystemTimeDiagnosticsPlugin::path(&name)
+            } else if self.tracked.len() < self.max_tracked_systems {
+                let path = SystemTimeDiagnosticsPlugin::path(&name);
+                self.tracked.insert(name);
+                path
+            } else {
+                SystemTimeDiagnosticsPlugin::OTHER
+            };
+            *frame.entry(path).or_default() += time;
+        }
+        frame
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SystemTimeDiagnosticsPlugin, SystemTimeStats};
+    use crate::DiagnosticsStore;
+    use alloc::string::{String, ToString};
+    use bevy_app::prelude::*;
+    use bevy_ecs::prelude::*;
+    use core::time::Duration;
+    use std::thread;
+
+    fn slow(_: Query<Entity>) {
+        thread::sleep(Duration::from_millis(20));
+    }
+
+    fn fast(_: Res<DiagnosticsStore>) {}
+
+    fn exclusive(_: &mut World) {}
+
+    fn name<M>(system: impl IntoSystem<(), (), M>) -> String {
+        IntoSystem::into_system(system).name().to_string()
+    }
+
+    #[test]
+    fn systems_are_measured() {
+        let mut app = App::new();
+        app.add_plugins(SystemTimeDiagnosticsPlugin::default())
+            .add_systems(Update, (slow, fast, exclusive));
+        app.update();
+
+        let diagnostics = app.world().resource::<DiagnosticsStore>();
+        let slow_time = diagnostics
+            .get(&SystemTimeDiagnosticsPlugin::path(&name(slow)))
+            .unwrap();
+        assert!(slow_time.value().unwrap() >= 20.0);
+        for system in [name(fast), name(exclusive)] {
+            assert!(diagnostics
+                .get(&SystemTimeDiagnosticsPlugin::path(&system))
+                .is_some());
+        }
+        assert!(diagnostics
+            .get(&SystemTimeDiagnosticsPlugin::OTHER)
+            .is_none());
+
+        let stats = app.world().resource::<SystemTimeStats>();
+        let slowest = stats.slowest(diagnostics, 2);
+        assert_eq!(slowest.len(), 2);
+        assert_eq!(slowest[0].0, name(slow));
+    }
+
+    #[test]
+    fn untracked_systems_are_summed() {
+        let mut app = App::new();
+        app.add_plugins(SystemTimeDiagnosticsPlugin {
+            max_tracked_systems: 1,
+            ..Default::default()
+        })
+        .add_systems(Update, (slow, fast, exclusive));
+        app.update();
+        app.update();
+
+        // Which system is tracked depends on the order they ran in the first frame.
+        let stats = app.world().resource::<SystemTimeStats>();
+        assert_eq!(stats.tracked().count(), 1);
+        let diagnostics = app.world().resource::<DiagnosticsStore>();
+        let systems = [name(slow), name(fast), name(exclusive)];
+        for system in &systems {
+            let diagnostic = diagnostics.get(&SystemTimeDiagnosticsPlugin::path(system));
+            assert_eq!(diagnostic.is_some(), stats.is_tracked(system));
+        }
+        let other = diagnostics
+            .get(&SystemTimeDiagnosticsPlugin::OTHER)
+            .unwrap();
+        assert_eq!(other.history_len(), 2);
+        if !stats.is_tracked(&systems[0]) {
+            assert!(other.value().unwrap() >= 20.0);
+        }
+    }
+
+    #[test]
+    fn repeated_runs_are_summed() {
+        let mut stats = SystemTimeStats {
+            max_tracked_systems: 1,
+            max_history_length: 0,
+            smoothing_factor: 0.0,
+            tracked: Default::default(),
+        };
+        let ms = Duration::from_millis;
+        let frame = stats.record_frame(
+            [
+                ("a".to_string(), ms(1)),
+                ("b".to_string(), ms(2)),
+                ("a".to_string(), ms(3)),
+                ("c".to_string(), ms(4)),
+            ]
+            .into_iter(),
+        );
+        assert_eq!(frame[&SystemTimeDiagnosticsPlugin::path("a")], ms(4));
+        assert_eq!(frame[&SystemTimeDiagnosticsPlugin::OTHER], ms(6));
+        assert_eq!(frame.len(), 2);
+    }
+}
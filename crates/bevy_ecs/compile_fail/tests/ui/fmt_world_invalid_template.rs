@@ -0,0 +1,12 @@
+use bevy_ecs::{fmt_world, prelude::*};
+
+fn main() {
+    let mut world = World::new();
+    let entity = world.spawn_empty().id();
+
+    let _ = fmt_world!(&world, "hp={entity:Health.}", entity = entity);
+    //~^ E0080
+
+    let _ = fmt_world!(&world, "{target:name}", entity = entity);
+    //~^ E0080
+}
@@ -17,6 +17,9 @@ pub const EVENT: &str = "entity_event";
 pub const AUTO_PROPAGATE: &str = "auto_propagate";
 pub const TRAVERSAL: &str = "traversal";
 
+pub const BUFFERED_EVENT: &str = "buffered_event";
+pub const AUTO_REGISTER: &str = "auto_register";
+
 pub fn derive_event(input: TokenStream) -> TokenStream {
     let mut ast = parse_macro_input!(input as DeriveInput);
     let bevy_ecs_path: Path = crate::bevy_ecs_path();
@@ -83,8 +86,32 @@ pub fn derive_entity_event(input: TokenStream) -> TokenStream {
 
 pub fn derive_buffered_event(input: TokenStream) -> TokenStream {
     let mut ast = parse_macro_input!(input as DeriveInput);
+    let mut auto_register = false;
     let bevy_ecs_path: Path = crate::bevy_ecs_path();
 
+    for attr in ast
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident(BUFFERED_EVENT))
+    {
+        if let Err(e) = attr.parse_nested_meta(|meta| match meta.path.get_ident() {
+            Some(ident) if ident == AUTO_REGISTER => {
+                if auto_register {
+                    return Err(meta.error(format!("duplicate attribute: {ident}")));
+                }
+                if !ast.generics.params.is_empty() {
+                    return Err(meta.error("generic events can't be registered automatically"));
+                }
+                auto_register = true;
+                Ok(())
+            }
+            Some(ident) => Err(meta.error(format!("unsupported attribute: {ident}"))),
+            None => Err(meta.error("expected identifier")),
+        }) {
+            return e.to_compile_error().into();
+        }
+    }
+
     ast.generics
         .make_where_clause()
         .predicates
@@ -93,8 +120,18 @@ pub fn derive_buffered_event(input: TokenStream) -> TokenStream {
     let struct_name = &ast.ident;
     let (impl_generics, type_generics, where_clause) = &ast.generics.split_for_impl();
 
+    // Without the feature, the events have to be added manually.
+    let registration = (cfg!(feature = "auto_register_events") && auto_register).then(|| {
+        quote! {
+            #bevy_ecs_path::__macro_exports::inventory::submit! {
+                #bevy_ecs_path::__macro_exports::DerivedEventRegistration::of::<#struct_name>()
+            }
+        }
+    });
+
     TokenStream::from(quote! {
         impl #impl_generics #bevy_ecs_path::event::BufferedEvent for #struct_name #type_generics #where_clause {}
+        #registration
     })
 }
 
@@ -13,7 +13,10 @@ use crate::{
     component::map_entities, query_data::derive_query_data_impl,
     query_filter::derive_query_filter_impl,
 };
-use bevy_macro_utils::{derive_label, ensure_no_collision, get_struct_fields, BevyManifest};
+use bevy_macro_utils::{
+    derive_label, derive_label_with_methods, ensure_no_collision, get_lit_str, get_struct_fields,
+    BevyManifest, Symbol,
+};
 use proc_macro::TokenStream;
 use proc_macro2::{Ident, Span};
 use quote::{format_ident, quote, ToTokens};
@@ -519,16 +522,43 @@ pub fn derive_query_filter(input: TokenStream) -> TokenStream {
 
 /// Derive macro generating an impl of the trait `ScheduleLabel`.
 ///
+/// The `#[schedule_doc = "..."]` attribute sets the description returned by `ScheduleLabel::doc`.
+///
 /// This does not work for unions.
-#[proc_macro_derive(ScheduleLabel)]
+#[proc_macro_derive(ScheduleLabel, attributes(schedule_doc))]
 pub fn derive_schedule_label(input: TokenStream) -> TokenStream {
+    const SCHEDULE_DOC: Symbol = Symbol("schedule_doc");
+
     let input = parse_macro_input!(input as DeriveInput);
     let mut trait_path = bevy_ecs_path();
     trait_path.segments.push(format_ident!("schedule").into());
     trait_path
         .segments
         .push(format_ident!("ScheduleLabel").into());
-    derive_label(input, "ScheduleLabel", &trait_path)
+
+    let mut doc = None;
+    for attr in &input.attrs {
+        if attr.path() != SCHEDULE_DOC {
+            continue;
+        }
+        let result = attr
+            .meta
+            .require_name_value()
+            .and_then(|meta| get_lit_str(SCHEDULE_DOC, &meta.value));
+        match result {
+            Ok(lit) => doc = Some(lit.clone()),
+            Err(err) => return err.into_compile_error().into(),
+        }
+    }
+    let extra_methods = match doc {
+        Some(doc) => quote! {
+            fn doc(&self) -> ::core::option::Option<&'static str> {
+                ::core::option::Option::Some(#doc)
+            }
+        },
+        None => quote! {},
+    };
+    derive_label_with_methods(input, "ScheduleLabel", &trait_path, extra_methods)
 }
 
 /// Derive macro generating an impl of the trait `SystemSet`.
@@ -570,7 +600,15 @@ pub fn derive_entity_event(input: TokenStream) -> TokenStream {
 }
 
 /// Implement the `BufferedEvent` trait.
-#[proc_macro_derive(BufferedEvent)]
+///
+/// ```ignore
+/// #[derive(BufferedEvent)]
+/// /// Add the event to the apps without `App::add_event`,
+/// /// with the `auto_register_events` feature
+/// #[buffered_event(auto_register)]
+/// struct MyEvent;
+/// ```
+#[proc_macro_derive(BufferedEvent, attributes(buffered_event))]
 pub fn derive_buffered_event(input: TokenStream) -> TokenStream {
     component::derive_buffered_event(input)
 }
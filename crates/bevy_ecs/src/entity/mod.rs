@@ -79,9 +79,14 @@ use crate::{
     component::{CheckChangeTicks, Tick},
     storage::{SparseSetIndex, TableId, TableRow},
 };
-use alloc::vec::Vec;
-use bevy_platform::sync::atomic::Ordering;
-use core::{fmt, hash::Hash, mem, num::NonZero, panic::Location};
+use alloc::{boxed::Box, vec::Vec};
+use bevy_platform::{
+    collections::HashMap,
+    hash::FixedHasher,
+    sync::atomic::{AtomicBool, AtomicU32, AtomicU8, Ordering},
+};
+use core::{fmt, hash::Hash, mem, num::NonZero, ops::Range, panic::Location};
+use fixedbitset::FixedBitSet;
 use log::warn;
 
 #[cfg(feature = "serialize")]
@@ -664,7 +669,7 @@ pub struct ReserveEntitiesIterator<'a> {
     freelist_indices: core::slice::Iter<'a, EntityRow>,
 
     // New Entity indices to hand out, outside the range of meta.len().
-    new_indices: core::ops::Range<u32>,
+    new_indices: Range<u32>,
 }
 
 impl<'a> Iterator for ReserveEntitiesIterator<'a> {
@@ -752,6 +757,12 @@ pub struct Entities {
     /// [`flush`]: Entities::flush
     pending: Vec<EntityRow>,
     free_cursor: AtomicIdCursor,
+    /// Rows set aside by [`Entities::begin_reserved_range`], handed out by key
+    /// through [`Entities::claim_reserved`] instead of the regular allocator.
+    reserved_range: Option<ReservedRange>,
+    /// The rows whose generation was set back by [`Entities::alloc_at`], with the generation
+    /// they had reached, which they skip to when they are freed.
+    rewound: HashMap<u32, EntityGeneration>,
 }
 
 impl Entities {
@@ -760,6 +771,8 @@ impl Entities {
             meta: Vec::new(),
             pending: Vec::new(),
             free_cursor: AtomicIdCursor::new(0),
+            reserved_range: None,
+            rewound: HashMap::with_hasher(FixedHasher),
         }
     }
 
@@ -844,6 +857,121 @@ impl Entities {
         }
     }
 
+    /// Sets aside `len` rows starting at index `start` that the regular allocator will never hand
+    /// out, and returns their indices.
+    ///
+    /// Rows in the range are only given out by [`claim_reserved`](Self::claim_reserved), which
+    /// picks a row from a caller-provided key rather than from allocation order. Together with a
+    /// fixed `start`, this makes the resulting ids independent of whatever else was allocated
+    /// before or in the meantime. Unused rows below `start` are added to the free list.
+    /// Call [`end_reserved_range`](Self::end_reserved_range) to hand unclaimed rows back to the
+    /// free list.
+    ///
+    /// If rows at or above `start` have already been allocated, the range starts after the last
+    /// allocated row instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a reserved range is already open.
+    pub fn begin_reserved_range(&mut self, start: u32, len: u32) -> Range<u32> {
+        self.verify_flushed();
+        assert!(
+            self.reserved_range.is_none(),
+            "a reserved entity range is already open"
+        );
+
+        let meta_len = u32::try_from(self.meta.len()).expect("too many entities");
+        let start = start.max(meta_len);
+        let end = start
+            .checked_add(len)
+            .filter(|end| *end < u32::MAX)
+            .expect("too many entities");
+        self.meta.resize(end as usize, EntityMeta::EMPTY);
+        // Push the padding in reverse so that the lowest rows are reused first.
+        for index in (meta_len..start).rev() {
+            // SAFETY: `index` is below `end`, which is less than `u32::MAX`.
+            let row = unsafe { NonMaxU32::new_unchecked(index) };
+            self.pending.push(EntityRow::new(row));
+        }
+        *self.free_cursor.get_mut() = self.pending.len() as IdCursor;
+        self.reserved_range = Some(ReservedRange {
+            start,
+            slots: (0..len)
+                .map(|_| AtomicU8::new(ReservedRange::UNCLAIMED))
+                .collect(),
+            claimed: AtomicU32::new(0),
+            needs_flush: AtomicBool::new(false),
+        });
+        start..end
+    }
+
+    /// Returns the rows of the currently open reserved range, if any.
+    pub fn reserved_range(&self) -> Option<Range<u32>> {
+        self.reserved_range
+            .as_ref()
+            .map(|range| range.start..range.start + range.slots.len() as u32)
+    }
+
+    /// Claims a row of the open reserved range concurrently, picking it from `key`.
+    ///
+    /// The row at `key` scaled to the range length is used if it is still free, otherwise the
+    /// following rows are probed in order. The same sequence of keys therefore always yields
+    /// the same ids, as long as keys do not collide with each other.
+    ///
+    /// Returns `None` if no range is open or every row of it has been claimed.
+    /// Like [`reserve_entity`](Self::reserve_entity), [`flush`](Self::flush) must be called
+    /// before the returned entity is valid.
+    pub fn claim_reserved(&self, key: u64) -> Option<Entity> {
+        let range = self.reserved_range.as_ref()?;
+        let len = range.slots.len() as u64;
+        if len == 0 {
+            return None;
+        }
+        // Map the key onto the range using its high bits, which are better distributed.
+        let first = ((u128::from(key) * u128::from(len)) >> 64) as u64;
+        (0..len).find_map(|probe| {
+            let slot = ((first + probe) % len) as usize;
+            range.slots[slot]
+                .compare_exchange(
+                    ReservedRange::UNCLAIMED,
+                    ReservedRange::CLAIMED,
+                    Ordering::Relaxed,
+                    Ordering::Relaxed,
+                )
+                .ok()?;
+            range.claimed.fetch_add(1, Ordering::Relaxed);
+            range.needs_flush.store(true, Ordering::Release);
+            // SAFETY: `begin_reserved_range` checked that the range ends below `u32::MAX`.
+            let row =
+                EntityRow::new(unsafe { NonMaxU32::new_unchecked(range.start + slot as u32) });
+            Some(Entity::from_raw_and_generation(
+                row,
+                self.meta[row.index() as usize].generation,
+            ))
+        })
+    }
+
+    /// Closes the open reserved range, pushing every row that was never claimed onto the free
+    /// list so the regular allocator can reuse it. Returns the number of claimed rows.
+    ///
+    /// Does nothing and returns `0` if no range is open.
+    pub fn end_reserved_range(&mut self) -> u32 {
+        self.verify_flushed();
+        let Some(range) = self.reserved_range.take() else {
+            return 0;
+        };
+        // Push in reverse so that the lowest rows are reused first.
+        for (slot, state) in range.slots.iter().enumerate().rev() {
+            if state.load(Ordering::Relaxed) == ReservedRange::UNCLAIMED {
+                // SAFETY: `begin_reserved_range` checked that the range ends below `u32::MAX`.
+                let row = unsafe { NonMaxU32::new_unchecked(range.start + slot as u32) };
+                self.pending.push(EntityRow::new(row));
+            }
+        }
+        *self.free_cursor.get_mut() = self.pending.len() as IdCursor;
+        range.claimed.into_inner()
+    }
+
     /// Check that we do not have pending work requiring `flush()` to be called.
     fn verify_flushed(&mut self) {
         debug_assert!(
@@ -869,6 +997,73 @@ impl Entities {
         }
     }
 
+    /// Allocates each of `entities` directly, with its exact generation, if all of their rows are
+    /// free. Otherwise, nothing is allocated and the first entity whose row is in use, or that
+    /// appears twice, is returned.
+    ///
+    /// Rows above the allocated ones are allocated directly, and the rows skipped to reach them are
+    /// added to the free list. Rows of the open [reserved range](Self::begin_reserved_range) can't
+    /// be allocated this way.
+    ///
+    /// The rows get the generation of `entities` back, which brings back entities that were
+    /// freed with the same ids. The ids handed out for the rows since then stay invalid: once
+    /// the rows are freed again, their generation skips past these ids.
+    ///
+    /// This runs in linear time in the number of entities and free rows.
+    pub fn alloc_at(&mut self, entities: &[Entity]) -> Result<(), Entity> {
+        self.verify_flushed();
+        let meta_len = u32::try_from(self.meta.len()).expect("too many entities");
+        let len = entities
+            .iter()
+            .map(|entity| entity.index() as usize + 1)
+            .max()
+            .unwrap_or(0)
+            .max(meta_len as usize);
+        let mut free = FixedBitSet::with_capacity(len);
+        free.extend(self.pending.iter().map(|row| row.index() as usize));
+        free.insert_range(meta_len as usize..);
+        if let Some(range) = self.reserved_range() {
+            free.remove_range(range.start as usize..range.end as usize);
+        }
+
+        let mut claimed = FixedBitSet::with_capacity(len);
+        for entity in entities {
+            let index = entity.index() as usize;
+            if !free.contains(index) || claimed.put(index) {
+                return Err(*entity);
+            }
+        }
+
+        self.pending.retain(|row| !claimed.contains(row.index() as usize));
+        self.meta.resize(len, EntityMeta::EMPTY);
+        // Push the padding in reverse so that the lowest rows are reused first.
+        for index in (meta_len as usize..len).rev() {
+            if !claimed.contains(index) {
+                // SAFETY: `index` is below the index of an entity, which is not `u32::MAX`.
+                let row = unsafe { NonMaxU32::new_unchecked(index as u32) };
+                self.pending.push(EntityRow::new(row));
+            }
+        }
+        for entity in entities {
+            let meta = &mut self.meta[entity.index() as usize];
+            // No id was handed out with the current generation of a free row yet.
+            let next = mem::replace(&mut meta.generation, entity.generation());
+            if !entity.generation().cmp_approx(&next).is_lt() {
+                continue;
+            }
+            self.rewound
+                .entry(entity.index())
+                .and_modify(|generation| {
+                    if generation.cmp_approx(&next).is_lt() {
+                        *generation = next;
+                    }
+                })
+                .or_insert(next);
+        }
+        *self.free_cursor.get_mut() = self.pending.len() as IdCursor;
+        Ok(())
+    }
+
     /// Destroy an entity, allowing it to be reused.
     ///
     /// Returns the `Option<EntityLocation>` of the entity or `None` if the `entity` was not present.
@@ -882,7 +1077,13 @@ impl Entities {
             return None;
         }
 
-        let (new_generation, aliased) = meta.generation.after_versions_and_could_alias(1);
+        let (mut new_generation, aliased) = meta.generation.after_versions_and_could_alias(1);
+        if !self.rewound.is_empty()
+            && let Some(next) = self.rewound.remove(&entity.index())
+            && new_generation.cmp_approx(&next).is_lt()
+        {
+            new_generation = next;
+        }
         meta.generation = new_generation;
         if aliased {
             warn!(
@@ -934,6 +1135,8 @@ impl Entities {
         self.meta.clear();
         self.pending.clear();
         *self.free_cursor.get_mut() = 0;
+        self.reserved_range = None;
+        self.rewound.clear();
     }
 
     /// Returns the [`EntityLocation`] of an [`Entity`].
@@ -1019,6 +1222,10 @@ impl Entities {
 
     fn needs_flush(&mut self) -> bool {
         *self.free_cursor.get_mut() != self.pending.len() as IdCursor
+            || self
+                .reserved_range
+                .as_mut()
+                .is_some_and(|range| *range.needs_flush.get_mut())
     }
 
     /// Allocates space for entities previously reserved with [`reserve_entity`](Entities::reserve_entity) or
@@ -1070,6 +1277,26 @@ impl Entities {
             );
             meta.spawned_or_despawned = SpawnedOrDespawned { by, at };
         }
+
+        if let Some(range) = &mut self.reserved_range
+            && mem::take(range.needs_flush.get_mut())
+        {
+            for (slot, state) in range.slots.iter_mut().enumerate() {
+                if *state.get_mut() != ReservedRange::CLAIMED {
+                    continue;
+                }
+                *state.get_mut() = ReservedRange::FLUSHED;
+                let index = range.start + slot as u32;
+                let meta = &mut self.meta[index as usize];
+                // SAFETY: `begin_reserved_range` checked that the range ends below `u32::MAX`.
+                let row = EntityRow::new(unsafe { NonMaxU32::new_unchecked(index) });
+                init(
+                    Entity::from_raw_and_generation(row, meta.generation),
+                    &mut meta.location,
+                );
+                meta.spawned_or_despawned = SpawnedOrDespawned { by, at };
+            }
+        }
     }
 
     /// Flushes all reserved entities to an "invalid" state. Attempting to retrieve them will return `None`
@@ -1121,8 +1348,12 @@ impl Entities {
     /// The count of currently allocated entities.
     #[inline]
     pub fn len(&self) -> u32 {
+        // Unclaimed rows of a reserved range exist in `meta` but are not allocated.
+        let unclaimed = self.reserved_range.as_ref().map_or(0, |range| {
+            range.slots.len() - range.claimed.load(Ordering::Relaxed) as usize
+        });
         // `pending`, by definition, can't be bigger than `meta`.
-        (self.meta.len() - self.pending.len()) as u32
+        (self.meta.len() - self.pending.len() - unclaimed) as u32
     }
 
     /// Checks if any entity is currently active.
@@ -1278,6 +1509,25 @@ impl EntityMeta {
     };
 }
 
+/// Rows set aside by [`Entities::begin_reserved_range`].
+#[derive(Debug)]
+struct ReservedRange {
+    /// Index of the first row in the range.
+    start: u32,
+    /// Claim state of every row in the range.
+    slots: Box<[AtomicU8]>,
+    /// Number of rows that have been claimed.
+    claimed: AtomicU32,
+    /// Whether some claimed rows still need to be initialized by [`Entities::flush`].
+    needs_flush: AtomicBool,
+}
+
+impl ReservedRange {
+    const UNCLAIMED: u8 = 0;
+    const CLAIMED: u8 = 1;
+    const FLUSHED: u8 = 2;
+}
+
 /// A location of an entity in an archetype.
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub struct EntityLocation {
@@ -1413,6 +1663,38 @@ mod tests {
             .is_gt());
     }
 
+    #[test]
+    fn reserved_range_is_separate_from_regular_allocation() {
+        let mut entities = Entities::new();
+        let before = entities.alloc();
+        let range = entities.begin_reserved_range(3, 4);
+        assert_eq!(range, 3..7);
+        assert_eq!(entities.len(), 1);
+
+        // Regular allocation uses the padding below the range, then skips the reserved rows.
+        let regular = [(); 3].map(|()| entities.reserve_entity().index());
+        assert_eq!(regular, [1, 2, 7]);
+
+        // Claims are picked by key and probe forward on collision.
+        let a = entities.claim_reserved(1 << 63).unwrap();
+        let b = entities.claim_reserved(1 << 63).unwrap();
+        assert_eq!(a.index(), 5);
+        assert_eq!(b.index(), 6);
+        entities.flush_as_invalid(MaybeLocation::caller(), Tick::new(0));
+        assert_eq!(entities.len(), 6);
+
+        // Unclaimed rows go back to the free list.
+        assert_eq!(entities.end_reserved_range(), 2);
+        assert_eq!(entities.len(), 6);
+        let reused = [entities.alloc(), entities.alloc()];
+        assert_eq!(reused.map(Entity::index), [3, 4]);
+        assert_ne!(before, a);
+        assert_eq!(entities.claim_reserved(0), None);
+
+        // Allocated rows are never reserved again.
+        assert_eq!(entities.begin_reserved_range(0, 2), 8..10);
+    }
+
     #[test]
     #[expect(
         clippy::nonminimal_bool,
@@ -1,6 +1,10 @@
+use alloc::vec::Vec;
 use core::fmt::Display;
 
-use crate::{component::Tick, error::BevyError, prelude::Resource};
+use crate::{
+    component::Tick, error::BevyError, prelude::Resource, schedule::InternedScheduleLabel,
+};
+use bevy_platform::sync::{Arc, Mutex, PoisonError};
 use bevy_utils::prelude::DebugName;
 use derive_more::derive::{Deref, DerefMut};
 
@@ -106,6 +110,88 @@ impl Default for DefaultErrorHandler {
     }
 }
 
+/// Collects the errors returned by the systems of a [`World`](crate::world::World) instead of
+/// passing them to its [`DefaultErrorHandler`], so that they can be handled later with access to
+/// the world, for example to turn them into events.
+///
+/// The errors of run conditions, commands and observers are still passed to the
+/// [`DefaultErrorHandler`]. Like it, the queue is looked up when a [`Schedule`] starts running.
+/// Clones of a queue share the same errors.
+///
+/// The queue holds at most [`max_len`](Self::with_max_len) errors, so that it doesn't grow
+/// forever if it is never drained. The errors pushed while it is full are dropped and counted,
+/// see [`SystemErrorQueue::take_dropped`].
+///
+/// [`Schedule`]: crate::schedule::Schedule
+#[derive(Resource, Clone, Debug)]
+pub struct SystemErrorQueue(Arc<Mutex<ErrorQueue>>);
+
+#[derive(Debug)]
+struct ErrorQueue {
+    errors: Vec<QueuedSystemError>,
+    max_len: usize,
+    dropped: usize,
+}
+
+impl SystemErrorQueue {
+    /// The default maximum number of errors in the queue.
+    pub const DEFAULT_MAX_LEN: usize = 1024;
+
+    /// Creates a queue holding at most `max_len` errors.
+    pub fn with_max_len(max_len: usize) -> Self {
+        Self(Arc::new(Mutex::new(ErrorQueue {
+            errors: Vec::new(),
+            max_len,
+            dropped: 0,
+        })))
+    }
+
+    /// Adds an error to the queue, or drops it if the queue is full.
+    pub fn push(&self, error: QueuedSystemError) {
+        let mut queue = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+        if queue.errors.len() < queue.max_len {
+            queue.errors.push(error);
+        } else {
+            queue.dropped += 1;
+        }
+    }
+
+    /// Removes and returns the errors in the queue, in the order they were pushed.
+    pub fn drain(&self) -> Vec<QueuedSystemError> {
+        core::mem::take(&mut self.0.lock().unwrap_or_else(PoisonError::into_inner).errors)
+    }
+
+    /// Returns the number of errors dropped because the queue was full since the last call.
+    pub fn take_dropped(&self) -> usize {
+        core::mem::take(
+            &mut self
+                .0
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .dropped,
+        )
+    }
+}
+
+impl Default for SystemErrorQueue {
+    fn default() -> Self {
+        Self::with_max_len(Self::DEFAULT_MAX_LEN)
+    }
+}
+
+/// An error returned by a system, collected by a [`SystemErrorQueue`].
+#[derive(Debug)]
+pub struct QueuedSystemError {
+    /// The error returned by the system.
+    pub error: BevyError,
+    /// The name of the system that failed.
+    pub name: DebugName,
+    /// The last tick that the system was run.
+    pub last_run: Tick,
+    /// The label of the schedule that ran the system.
+    pub schedule: InternedScheduleLabel,
+}
+
 /// Error handler that panics with the system error.
 #[track_caller]
 #[inline]
@@ -10,6 +10,8 @@
 //! You can change the default behavior by registering a custom error handler:
 //! Use [`DefaultErrorHandler`] to set a custom error handler function for a world,
 //! or `App::set_error_handler` for a whole app.
+//! The errors of systems can also be collected in a [`SystemErrorQueue`] and handled later with
+//! access to the world, which `App::set_system_error_handler` uses to turn them into events.
 //! In practice, this is generally feature-flagged: panicking or loudly logging errors in development,
 //! and quietly logging or ignoring them in production to avoid crashing the app.
 //!
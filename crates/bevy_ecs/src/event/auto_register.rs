@@ -0,0 +1,112 @@
+use crate::{
+    event::{BufferedEvent, EventRegistry, Events},
+    world::World,
+};
+
+/// Registers an event type derived with `#[buffered_event(auto_register)]`.
+///
+/// The derive collects these with `inventory`, see [`EventRegistry::register_derived_events`].
+pub struct DerivedEventRegistration(fn(&mut World) -> bool);
+
+impl DerivedEventRegistration {
+    /// Creates the registration of the events of type `E`.
+    pub const fn of<E: BufferedEvent>() -> Self {
+        Self(register_if_missing::<E>)
+    }
+}
+
+inventory::collect!(DerivedEventRegistration);
+
+/// Registers the events of type `E` unless their [`Events<E>`] are already in `world`, returning
+/// `true` if they were registered.
+fn register_if_missing<E: BufferedEvent>(world: &mut World) -> bool {
+    if world.contains_resource::<Events<E>>() {
+        return false;
+    }
+    EventRegistry::register_event::<E>(world);
+    true
+}
+
+impl EventRegistry {
+    /// Registers the event types derived with `#[buffered_event(auto_register)]` whose
+    /// [`Events`] aren't in `world` yet, and returns how many were registered.
+    ///
+    /// In an `App`, this is done when the app is finished, after all the plugins are built, so
+    /// the events added explicitly, for example with custom settings, are left as they are.
+    ///
+    /// The event types are collected with [`inventory`](https://docs.rs/inventory), which has
+    /// a few caveats:
+    /// - It isn't supported on all platforms. On WebAssembly, the types are only collected once
+    ///   the constructors of the module ran, which the automatic reflect registration of
+    ///   `bevy_reflect` does when the `auto_register_inventory` feature is enabled.
+    /// - The linker may discard the types of a crate whose items are never used, for example
+    ///   one only linked for its events. Such events have to be added manually.
+    ///
+    /// ```
+    /// # use bevy_ecs::{event::EventRegistry, prelude::*};
+    /// #[derive(BufferedEvent)]
+    /// #[buffered_event(auto_register)]
+    /// struct LevelUp;
+    ///
+    /// let mut world = World::new();
+    /// EventRegistry::register_derived_events(&mut world);
+    /// assert!(world.contains_resource::<Events<LevelUp>>());
+    /// ```
+    pub fn register_derived_events(world: &mut World) -> usize {
+        inventory::iter::<DerivedEventRegistration>
+            .into_iter()
+            .filter(|registration| registration.0(world))
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        event::{BufferedEvent, EventRegistry, EventSettings, Events},
+        world::World,
+    };
+
+    #[derive(BufferedEvent)]
+    #[buffered_event(auto_register)]
+    struct LevelUp;
+
+    #[derive(BufferedEvent)]
+    #[buffered_event(auto_register)]
+    struct ItemDropped;
+
+    #[derive(BufferedEvent)]
+    struct NotRegistered;
+
+    #[test]
+    fn derived_events_are_registered_once() {
+        let mut world = World::new();
+        EventRegistry::register_event::<ItemDropped>(&mut world);
+        world
+            .resource_mut::<Events<ItemDropped>>()
+            .set_settings(EventSettings {
+                capacity: 4,
+                ..Default::default()
+            });
+
+        EventRegistry::register_derived_events(&mut world);
+        assert!(world.contains_resource::<Events<LevelUp>>());
+        assert!(!world.contains_resource::<Events<NotRegistered>>());
+        // The events that were already registered are left as they are.
+        assert_eq!(
+            world
+                .resource::<Events<ItemDropped>>()
+                .settings()
+                .map(|settings| settings.capacity),
+            Some(4)
+        );
+        let registered = world
+            .resource::<EventRegistry>()
+            .iter_counts(&world)
+            .filter(|(type_name, _)| type_name.ends_with("::LevelUp"))
+            .count();
+        assert_eq!(registered, 1);
+
+        assert_eq!(EventRegistry::register_derived_events(&mut world), 0);
+    }
+}
@@ -319,6 +319,18 @@ pub trait EntityEvent: Event {
 /// }
 /// ```
 ///
+/// The events have to be added to the app with `App::add_event` before they are written or
+/// read. With the `auto_register_events` feature, the derive can add them when the app is
+/// finished instead, see `EventRegistry::register_derived_events`:
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// #
+/// #[derive(BufferedEvent)]
+/// #[buffered_event(auto_register)]
+/// struct Message(String);
+/// ```
+///
 /// [`World`]: crate::world::World
 /// [`Observer`]: crate::observer::Observer
 /// [`Events<E>`]: super::Events
@@ -367,6 +379,26 @@ pub struct EventId<E: BufferedEvent> {
     pub(super) _marker: PhantomData<E>,
 }
 
+impl<E: BufferedEvent> EventId<E> {
+    /// Returns the sequence number the event was stamped with when it was written, if it is still
+    /// stored in `events` and they are [stamped](super::Events::stamp_sequence).
+    ///
+    /// Unlike the [`id`](Self::id), which orders the events of a single type, the sequence numbers
+    /// taken from the same [`EventSequenceCounter`](super::EventSequenceCounter), such as the one
+    /// of the world, order the events of all the stamped types by the order they were written in.
+    /// See [`EventReader::read_merged`](super::EventReader::read_merged) to read the events of two
+    /// types in that order.
+    ///
+    /// The sequence numbers are stored alongside the events rather than in the ids, so a system
+    /// reading the events with an [`EventReader`](super::EventReader) can look them up with a
+    /// `Res<Events<E>>`. This is the same as
+    /// [`Events::get_sequence`](super::Events::get_sequence).
+    #[inline]
+    pub fn sequence(&self, events: &super::Events<E>) -> Option<u64> {
+        events.get_sequence(self.id)
+    }
+}
+
 impl<E: BufferedEvent> Copy for EventId<E> {}
 
 impl<E: BufferedEvent> Clone for EventId<E> {
@@ -1,13 +1,26 @@
-use alloc::vec::Vec;
+use alloc::collections::VecDeque;
+use alloc::vec::{self, Vec};
 use bevy_ecs::{
-    change_detection::MaybeLocation,
-    event::{BufferedEvent, EventCursor, EventId, EventInstance},
+    change_detection::{DetectChangesMut, MaybeLocation},
+    event::{sender::SentEvents, BufferedEvent, EventCursor, EventId, EventInstance, EventSender},
     resource::Resource,
+    world::World,
 };
+use bevy_platform::{
+    sync::{
+        atomic::{AtomicU64, AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Instant,
+};
+use bevy_utils::prelude::DebugName;
 use core::{
     marker::PhantomData,
     ops::{Deref, DerefMut},
+    panic::Location,
+    time::Duration,
 };
+use log::warn;
 #[cfg(feature = "bevy_reflect")]
 use {
     bevy_ecs::reflect::ReflectResource,
@@ -77,6 +90,8 @@ use {
 ///   before those updates.
 ///
 /// The buffers in [`Events`] will grow indefinitely if [`update`](Events::update) is never called.
+/// They can be bounded with [`EventSettings`], for example for the events written by another
+/// thread, which may pile up while a frame hitches.
 ///
 /// An alternative call pattern would be to call [`update`](Events::update)
 /// manually across frames to control when events are cleared.
@@ -94,11 +109,31 @@ use {
 #[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Resource, Default))]
 pub struct Events<E: BufferedEvent> {
     /// Holds the oldest still active events.
-    /// Note that `a.start_event_count + a.len()` should always be equal to `events_b.start_event_count`.
+    /// Note that `a.start_event_count + a.len()` should always be equal to `events_b.start_event_count`,
+    /// unless events were removed with [`Events::drain_where`], which leaves gaps in the ids.
     pub(crate) events_a: EventSequence<E>,
     /// Holds the newer events.
     pub(crate) events_b: EventSequence<E>,
     pub(crate) event_count: usize,
+    /// The counter stamping the events written since [`Events::stamp_sequence`].
+    #[cfg_attr(feature = "bevy_reflect", reflect(ignore))]
+    sequence_stamps: Option<SequenceStamps>,
+    /// Tracks which events were read since [`Events::track_consumption`].
+    #[cfg(debug_assertions)]
+    #[cfg_attr(feature = "bevy_reflect", reflect(ignore))]
+    consumption: Option<ConsumptionTracker>,
+    /// The capacity and the retention of the buffers, see [`Events::set_settings`].
+    #[cfg_attr(feature = "bevy_reflect", reflect(ignore))]
+    settings: Option<Settings>,
+    /// The number of events returned to the readers since [`Events::count_reads`], see
+    /// [`Events::counts`].
+    #[cfg_attr(feature = "bevy_reflect", reflect(ignore))]
+    read_count: Option<AtomicUsize>,
+    /// Whether the written events are discarded, see [`Events::set_writes_blocked`].
+    writes_blocked: bool,
+    /// The events sent from other threads, see [`Events::sender`].
+    #[cfg_attr(feature = "bevy_reflect", reflect(ignore))]
+    sent: Option<SentEvents<E>>,
 }
 
 // Derived Default impl would incorrectly require E: Default
@@ -108,6 +143,13 @@ impl<E: BufferedEvent> Default for Events<E> {
             events_a: Default::default(),
             events_b: Default::default(),
             event_count: Default::default(),
+            sequence_stamps: None,
+            #[cfg(debug_assertions)]
+            consumption: None,
+            settings: None,
+            read_count: None,
+            writes_blocked: false,
+            sent: None,
         }
     }
 }
@@ -127,6 +169,19 @@ impl<E: BufferedEvent> Events<E> {
     }
 
     pub(crate) fn write_with_caller(&mut self, event: E, caller: MaybeLocation) -> EventId<E> {
+        if self.writes_blocked || (self.is_bounded() && self.admit(1) == 0) {
+            // The id the event would have had, which the next written event will get.
+            return EventId {
+                id: self.event_count,
+                caller,
+                _marker: PhantomData,
+            };
+        }
+        self.push(event, caller)
+    }
+
+    /// Writes `event`, regardless of the [`EventSettings`].
+    fn push(&mut self, event: E, caller: MaybeLocation) -> EventId<E> {
         let event_id = EventId {
             id: self.event_count,
             caller,
@@ -138,6 +193,9 @@ impl<E: BufferedEvent> Events<E> {
         let event_instance = EventInstance { event_id, event };
 
         self.events_b.push(event_instance);
+        if let Some(stamps) = &mut self.sequence_stamps {
+            stamps.stamp(self.event_count, self.events_a.start_event_count);
+        }
         self.event_count += 1;
 
         event_id
@@ -145,7 +203,8 @@ impl<E: BufferedEvent> Events<E> {
 
     /// Writes a list of `events` all at once, which can later be read by [`EventReader`](super::EventReader)s.
     /// This is more efficient than writing each event individually.
-    /// This method returns the [IDs](`EventId`) of the written `events`.
+    /// This method returns the [IDs](`EventId`) of the written `events`, whose
+    /// [`len`](ExactSizeIterator::len) is the number of written events.
     #[track_caller]
     pub fn write_batch(&mut self, events: impl IntoIterator<Item = E>) -> WriteBatchIds<E> {
         let last_count = self.event_count;
@@ -159,6 +218,148 @@ impl<E: BufferedEvent> Events<E> {
         }
     }
 
+    /// Writes at most `max` of the `events`, leaving the others in the iterator, for example to
+    /// bound the work of a system transferring events from a channel.
+    /// This method returns the [IDs](`EventId`) of the written `events`, and the lower bound of
+    /// the [`size_hint`](Iterator::size_hint) of the remaining ones.
+    ///
+    /// Many iterators can't tell how many items they have left, and always return 0: for example
+    /// the `try_iter` of a `std::sync::mpsc` channel, which may receive more items at any time.
+    /// The returned number is then no measure of the backlog, which has to be asked to the source
+    /// of the events instead, like the `len` of a `crossbeam` channel.
+    #[track_caller]
+    pub fn write_batch_capped<I>(&mut self, events: I, max: usize) -> (WriteBatchIds<E>, usize)
+    where
+        I: IntoIterator<Item = E>,
+    {
+        let mut events = events.into_iter();
+        let ids = self.write_batch(events.by_ref().take(max));
+        (ids, events.size_hint().0)
+    }
+
+    /// Bounds the number of events stored in the buffers, and sets how long they are kept for,
+    /// see [`EventSettings`]. Without settings, the buffers are unbounded and the events are
+    /// kept for two frames.
+    ///
+    /// If the buffers already hold more events than the new capacity, they are kept until they
+    /// are removed by [`Events::update`].
+    pub fn set_settings(&mut self, settings: EventSettings) {
+        let dropped = self.dropped();
+        self.settings = Some(Settings {
+            settings,
+            dropped,
+            warned: false,
+            updates: VecDeque::new(),
+            consumed_until: AtomicUsize::new(self.events_a.start_event_count),
+        });
+    }
+
+    /// Discards the events written from now on while `blocked` is `true`, for example to replace
+    /// the events of the live writers by recorded ones.
+    ///
+    /// The discarded events don't get an id: the [`EventId`] returned for them is the one the
+    /// next written event will get.
+    pub fn set_writes_blocked(&mut self, blocked: bool) {
+        self.writes_blocked = blocked;
+    }
+
+    /// Returns `true` if the written events are discarded, see [`Events::set_writes_blocked`].
+    pub fn writes_blocked(&self) -> bool {
+        self.writes_blocked
+    }
+
+    /// Returns the settings set with [`Events::set_settings`], if any.
+    pub fn settings(&self) -> Option<&EventSettings> {
+        self.settings.as_ref().map(|settings| &settings.settings)
+    }
+
+    /// Returns the number of events dropped since the events were created, because the buffers
+    /// were full, see [`EventSettings`].
+    pub fn dropped(&self) -> usize {
+        self.settings
+            .as_ref()
+            .map_or(0, |settings| settings.dropped)
+    }
+
+    /// Removes the events that were read by an [`EventReader`](super::EventReader) or an
+    /// [`EventMutator`](super::EventMutator), or a cursor, since they were written.
+    ///
+    /// This is how the events are removed with [`RetentionPolicy::Manual`], which
+    /// [`Events::update`] never removes. Since the events are removed as soon as any reader read
+    /// them, this is meant for events with a single reader.
+    ///
+    /// An event counts as read once a reader iterated past it: the events left when a reader
+    /// stops iterating early are kept. The run conditions [`on_event_matching`] and
+    /// [`on_all_events_matching`] only peek at the events, and don't count as readers.
+    ///
+    /// [`on_event_matching`]: crate::schedule::common_conditions::on_event_matching
+    /// [`on_all_events_matching`]: crate::schedule::common_conditions::on_all_events_matching
+    pub fn clear_consumed(&mut self) {
+        let Some(settings) = &mut self.settings else {
+            return;
+        };
+        let consumed_until = *settings.consumed_until.get_mut();
+        let [a, b] = [&self.events_a, &self.events_b].map(|events| events.index_of(consumed_until));
+        self.drop_oldest(a + b);
+    }
+
+    /// Returns `true` if the [`EventSettings`] limit the number of stored events.
+    #[inline]
+    fn is_bounded(&self) -> bool {
+        self.settings
+            .as_ref()
+            .is_some_and(|settings| settings.settings.capacity < usize::MAX)
+    }
+
+    /// Makes room for `count` new events according to the [`EventSettings`], returning how many
+    /// of them should be written: the first ones with [`Overflow::DropNewest`], and the last
+    /// ones otherwise.
+    fn admit(&mut self, count: usize) -> usize {
+        let len = self.len();
+        let Some(settings) = &mut self.settings else {
+            return count;
+        };
+        let capacity = settings.settings.capacity;
+        match settings.settings.overflow {
+            Overflow::DropNewest => {
+                let keep = count.min(capacity.saturating_sub(len));
+                settings.dropped += count - keep;
+                keep
+            }
+            Overflow::DropOldest => {
+                let keep = count.min(capacity);
+                let stored = (len + keep).saturating_sub(capacity);
+                settings.dropped += count - keep + stored;
+                self.drop_oldest(stored);
+                keep
+            }
+            Overflow::Warn => {
+                if len + count > capacity && !settings.warned {
+                    settings.warned = true;
+                    warn!(
+                        "More than {capacity} `{}` events are stored, the readers may be falling behind",
+                        DebugName::type_name::<E>()
+                    );
+                }
+                count
+            }
+        }
+    }
+
+    /// Removes the `count` oldest events, which readers that didn't read them yet will miss.
+    fn drop_oldest(&mut self, count: usize) {
+        let from_a = count.min(self.events_a.len());
+        self.events_a.drain(..from_a);
+        let from_b = count - from_a;
+        if from_b > 0 {
+            self.events_b.drain(..from_b);
+            self.events_b.start_event_count = self.events_b.start_after(self.event_count);
+        }
+        // Once empty, the oldest buffer starts where the newest one does.
+        self.events_a.start_event_count =
+            self.events_a.start_after(self.events_b.start_event_count);
+    }
+
     /// Writes the default value of the event. Useful when the event is an empty struct.
     /// This method returns the [ID](`EventId`) of the written `event`.
     #[track_caller]
@@ -217,12 +418,18 @@ impl<E: BufferedEvent> Events<E> {
     ///
     /// If you need access to the events that were removed, consider using [`Events::update_drain`].
     pub fn update(&mut self) {
+        self.check_consumption();
+        if self.settings.is_some() {
+            let removed = self.retain();
+            self.events_a.drain(..removed);
+            return;
+        }
         core::mem::swap(&mut self.events_a, &mut self.events_b);
         self.events_b.clear();
         self.events_b.start_event_count = self.event_count;
-        debug_assert_eq!(
-            self.events_a.start_event_count + self.events_a.len(),
-            self.events_b.start_event_count
+        debug_assert!(
+            self.events_a.start_event_count + self.events_a.len()
+                <= self.events_b.start_event_count
         );
     }
 
@@ -232,17 +439,75 @@ impl<E: BufferedEvent> Events<E> {
     /// If you do not need to take ownership of the removed events, use [`Events::update`] instead.
     #[must_use = "If you do not need the returned events, call .update() instead."]
     pub fn update_drain(&mut self) -> impl Iterator<Item = E> + '_ {
-        core::mem::swap(&mut self.events_a, &mut self.events_b);
-        let iter = self.events_b.events.drain(..);
-        self.events_b.start_event_count = self.event_count;
-        debug_assert_eq!(
-            self.events_a.start_event_count + self.events_a.len(),
-            self.events_b.start_event_count
-        );
+        self.check_consumption();
+        let iter = if self.settings.is_some() {
+            let removed = self.retain();
+            self.events_a.events.drain(..removed)
+        } else {
+            core::mem::swap(&mut self.events_a, &mut self.events_b);
+            let iter = self.events_b.events.drain(..);
+            self.events_b.start_event_count = self.event_count;
+            debug_assert!(
+                self.events_a.start_event_count + self.events_a.len()
+                    <= self.events_b.start_event_count
+            );
+            iter
+        };
 
         iter.map(|e| e.event)
     }
 
+    /// Moves the events written since the last update to the oldest buffer, and returns how many
+    /// of its first events the [`RetentionPolicy`] removes. The caller must remove them.
+    fn retain(&mut self) -> usize {
+        let Some(settings) = &mut self.settings else {
+            return 0;
+        };
+        settings.warned = false;
+        let now = Instant::now();
+        let updates = &mut settings.updates;
+        let keep_from = match settings.settings.retention {
+            RetentionPolicy::Frames(frames) => {
+                updates.push_back((self.event_count, now));
+                let frames = (frames as usize).max(1);
+                updates
+                    .len()
+                    .checked_sub(frames)
+                    .map(|index| updates[index].0)
+            }
+            RetentionPolicy::Duration(duration) => {
+                updates.push_back((self.event_count, now));
+                // The events are also kept until the update after the one following their write,
+                // like without settings, so that the readers running every frame don't miss any.
+                let previous_update = updates.len().checked_sub(2).map(|index| updates[index].0);
+                updates
+                    .iter()
+                    .take_while(|(_, time)| now.saturating_duration_since(*time) > duration)
+                    .last()
+                    .map(|(event_count, _)| *event_count)
+                    .zip(previous_update)
+                    .map(|(expired, previous)| expired.min(previous))
+            }
+            RetentionPolicy::Manual => None,
+        };
+        let keep_from = keep_from.unwrap_or(0).max(self.events_a.start_event_count);
+        // Only the updates after the oldest kept event are needed to remove it later.
+        while updates.len() > 1 && updates[0].0 <= keep_from {
+            updates.pop_front();
+        }
+
+        let events_b = core::mem::take(&mut self.events_b.events);
+        self.events_a.extend(events_b);
+        self.events_b.start_event_count = self.event_count;
+        let removed = self.events_a.index_of(keep_from);
+        self.events_a.start_event_count = keep_from;
+        debug_assert!(
+            self.events_a.start_event_count + self.events_a.len() - removed
+                <= self.events_b.start_event_count
+        );
+        removed
+    }
+
     #[inline]
     fn reset_start_event_count(&mut self) {
         self.events_a.start_event_count = self.event_count;
@@ -271,6 +536,7 @@ impl<E: BufferedEvent> Events<E> {
 
     /// Creates a draining iterator that removes all events.
     pub fn drain(&mut self) -> impl Iterator<Item = E> + '_ {
+        self.mark_consumed();
         self.reset_start_event_count();
 
         // Drain the oldest events first, then the newest
@@ -280,6 +546,250 @@ impl<E: BufferedEvent> Events<E> {
             .map(|i| i.event)
     }
 
+    /// Removes the events matching `predicate` and returns them, oldest first, leaving the other
+    /// events to the readers.
+    ///
+    /// This lets a system take the events meant for it, for example the messages of a single
+    /// network channel, while the other systems keep reading the rest. The remaining events keep
+    /// their [`EventId`], and the cursors of the [`EventReader`]s are left untouched: a reader
+    /// still reads each remaining event exactly once, and never reads the removed ones if it
+    /// didn't already.
+    ///
+    /// The events are removed from both buffers as soon as this is called, even if the returned
+    /// iterator is not consumed.
+    ///
+    /// ```
+    /// # use bevy_ecs::event::{BufferedEvent, Events};
+    /// #[derive(BufferedEvent)]
+    /// struct NetworkMessage {
+    ///     channel: u8,
+    /// }
+    ///
+    /// let mut events = Events::<NetworkMessage>::default();
+    /// let mut cursor = events.get_cursor();
+    /// events.write_batch([1, 3, 2, 3].map(|channel| NetworkMessage { channel }));
+    ///
+    /// let drained = events.drain_where(|message| message.channel == 3);
+    /// assert_eq!(drained.count(), 2);
+    ///
+    /// let channels = cursor.read(&events).map(|message| message.channel);
+    /// assert_eq!(channels.collect::<Vec<_>>(), [1, 2]);
+    /// ```
+    ///
+    /// [`EventReader`]: super::EventReader
+    pub fn drain_where(&mut self, mut predicate: impl FnMut(&E) -> bool) -> vec::IntoIter<E> {
+        let mut drained = Vec::new();
+        for sequence in [&mut self.events_a, &mut self.events_b] {
+            // The buffers are left as they are until an event matches.
+            let Some(first) = sequence
+                .iter()
+                .position(|instance| predicate(&instance.event))
+            else {
+                continue;
+            };
+            let mut rest = sequence.split_off(first).into_iter();
+            drained.extend(rest.next().map(|instance| instance.event));
+            for instance in rest {
+                if predicate(&instance.event) {
+                    drained.push(instance.event);
+                } else {
+                    sequence.push(instance);
+                }
+            }
+        }
+        drained.into_iter()
+    }
+
+    /// Returns a handle to write events from any thread, see [`EventSender`].
+    ///
+    /// The sent events are written by [`Events::receive_sent`], which the event update pass of an
+    /// `App` calls right after [`Events::update`].
+    pub fn sender(&mut self) -> EventSender<E> {
+        self.sent.get_or_insert_with(SentEvents::default).sender()
+    }
+
+    /// Writes the events sent through the [`EventSender`]s, in the order each sender sent them,
+    /// returning how many were written.
+    ///
+    /// The events sent meanwhile, for example by another thread, and the ones over the limit set
+    /// by [`Events::set_max_received`] are left for the next call.
+    #[track_caller]
+    pub fn receive_sent(&mut self) -> usize {
+        let Some(sent) = self.sent.take() else {
+            return 0;
+        };
+        let mut received = 0;
+        self.extend(sent.take().inspect(|_| received += 1));
+        self.sent = Some(sent);
+        received
+    }
+
+    /// Limits the number of events written by each call to [`Events::receive_sent`], so at most
+    /// `max` sent events are written per frame in an `App`. `None` removes the limit.
+    ///
+    /// The other events are kept for the next calls. This keeps a thread sending events faster
+    /// than the app can handle them from making the frames longer and longer.
+    pub fn set_max_received(&mut self, max: Option<usize>) {
+        self.sent
+            .get_or_insert_with(SentEvents::default)
+            .max_per_receive = max.unwrap_or(usize::MAX);
+    }
+
+    /// Returns `true` if events sent through the [`EventSender`]s are waiting to be written by
+    /// [`Events::receive_sent`].
+    pub fn has_sent(&self) -> bool {
+        self.sent.as_ref().is_some_and(|sent| !sent.is_empty())
+    }
+
+    /// Stamps every event written from now on with a sequence number taken from `counter`.
+    ///
+    /// Sharing a counter between several event types gives a total order over the events
+    /// written to all of them, in the order they were written. Stamping is a single atomic
+    /// increment, so it stays cheap for events written by systems running in parallel.
+    ///
+    /// The sequence numbers are kept alongside the stored events, see [`Events::get_sequence`].
+    /// The world has a counter of its own, see [`World::stamp_event_sequence`].
+    ///
+    /// [`World::stamp_event_sequence`]: crate::world::World::stamp_event_sequence
+    pub fn stamp_sequence(&mut self, counter: EventSequenceCounter) {
+        self.sequence_stamps = Some(SequenceStamps {
+            counter,
+            stamps: VecDeque::new(),
+            drained_until: self.event_count,
+        });
+    }
+
+    /// Returns the sequence number the event `id` was stamped with when it was written, if it
+    /// is still stored and the events are [stamped](Self::stamp_sequence).
+    ///
+    /// Unlike the ids, which order the events of a single type, the sequence numbers taken from
+    /// the same [`EventSequenceCounter`], such as the one of the world, order the events of all
+    /// the stamped types by the order they were written in. See
+    /// [`EventReader::read_merged`](super::EventReader::read_merged) to read the events of two
+    /// types in that order.
+    pub fn get_sequence(&self, id: usize) -> Option<u64> {
+        if id < self.oldest_event_count() {
+            return None;
+        }
+        self.sequence_stamps.as_ref()?.get(id)
+    }
+
+    /// Returns the counter the events are [stamped](Self::stamp_sequence) with, if any.
+    pub fn sequence_counter(&self) -> Option<&EventSequenceCounter> {
+        self.sequence_stamps.as_ref().map(|stamps| &stamps.counter)
+    }
+
+    /// Returns the id and sequence number of the events stamped since the last call, in write
+    /// order.
+    ///
+    /// The events removed since, for example by [`Events::update`], are skipped, but the ids may
+    /// still refer to events removed by [`Events::drain_where`]. Use [`Events::get_event`] to
+    /// look them up. Returns nothing if the events aren't [stamped](Self::stamp_sequence).
+    pub fn drain_sequence_stamps(&mut self) -> impl Iterator<Item = (usize, u64)> + '_ {
+        let oldest = self.oldest_event_count();
+        let event_count = self.event_count;
+        self.sequence_stamps
+            .as_mut()
+            .map(|stamps| {
+                let from = core::mem::replace(&mut stamps.drained_until, event_count).max(oldest);
+                let start = stamps.stamps.partition_point(|&(id, _)| id < from);
+                stamps.stamps.range(start..).copied()
+            })
+            .into_iter()
+            .flatten()
+    }
+
+    /// Starts tracking whether every event is read before the next [`Events::update`], which
+    /// swaps the buffers and makes the events a frame old.
+    ///
+    /// An event counts as read once an [`EventReader`] or [`EventMutator`] iterated past it, or
+    /// cleared the events, while it was in the buffers: creating an iterator without reading the
+    /// events doesn't count. The events that were not read are counted on each
+    /// update, and can be retrieved with [`Events::take_unconsumed`].
+    ///
+    /// The tracking only happens in debug builds: in release builds, this does nothing.
+    ///
+    /// [`EventReader`]: super::EventReader
+    /// [`EventMutator`]: super::EventMutator
+    pub fn track_consumption(&mut self) {
+        #[cfg(debug_assertions)]
+        if self.consumption.is_none() {
+            self.consumption = Some(ConsumptionTracker {
+                consumed_until: AtomicUsize::new(self.event_count),
+                unconsumed: None,
+            });
+        }
+    }
+
+    /// Removes and returns the events that were not read before the last [`Events::update`]
+    /// calls, if any, see [`Events::track_consumption`].
+    ///
+    /// Always returns `None` in release builds.
+    pub fn take_unconsumed(&mut self) -> Option<UnconsumedEvents> {
+        #[cfg(debug_assertions)]
+        let unconsumed = self
+            .consumption
+            .as_mut()
+            .and_then(|tracker| tracker.unconsumed.take());
+
+        #[cfg(not(debug_assertions))]
+        let unconsumed = None;
+
+        unconsumed
+    }
+
+    /// Returns the number of events written since the events were created, and the number of
+    /// events read since [`Events::count_reads`] was called.
+    ///
+    /// The counts only grow, so the events written or read during a frame are the difference
+    /// between the counts of two frames. This is how the `EventCountDiagnosticsPlugin` measures
+    /// them, without going through the events.
+    pub fn counts(&self) -> EventCounts {
+        EventCounts {
+            written: self.event_count,
+            read: self
+                .read_count
+                .as_ref()
+                .map_or(0, |read_count| read_count.load(Ordering::Relaxed)),
+        }
+    }
+
+    /// Starts counting the events read, see [`Events::counts`].
+    ///
+    /// The events are not counted by default, so that reading them doesn't pay for the
+    /// counting when nothing looks at the counts.
+    pub fn count_reads(&mut self) {
+        self.read_count.get_or_insert_with(|| AtomicUsize::new(0));
+    }
+
+    /// Records that all the events currently in the buffers were read.
+    #[inline]
+    pub(crate) fn mark_consumed(&self) {
+        self.reads().record(self.event_count, 0);
+    }
+
+    /// Counts the events written since the last update that were not read, before they become a
+    /// frame old.
+    fn check_consumption(&mut self) {
+        #[cfg(debug_assertions)]
+        if let Some(tracker) = &mut self.consumption {
+            let consumed_until = *tracker.consumed_until.get_mut();
+            let unread = self
+                .events_b
+                .iter()
+                .filter(|instance| instance.event_id.id >= consumed_until);
+            for instance in unread {
+                let unconsumed = tracker.unconsumed.get_or_insert_with(Default::default);
+                unconsumed.count += 1;
+                if let Some(location) = instance.event_id.caller.into_option()
+                    && !unconsumed.writers.contains(&location)
+                {
+                    unconsumed.writers.push(location);
+                }
+            }
+        }
+    }
+
     /// Iterates over events that happened since the last "update" call.
     /// WARNING: You probably don't want to use this call. In most cases you should use an
     /// [`EventReader`]. You should only use this if you know you only need to consume events
@@ -299,13 +809,48 @@ impl<E: BufferedEvent> Events<E> {
         }
 
         let sequence = self.sequence(id);
-        let index = id.saturating_sub(sequence.start_event_count);
 
         sequence
-            .get(index)
+            .get(sequence.index_of(id))
+            .filter(|instance| instance.event_id.id == id)
             .map(|instance| (&instance.event, instance.event_id))
     }
 
+    /// Returns the stored events with an id of at least `id`, in the oldest and the newest
+    /// buffers.
+    pub(crate) fn events_from(&self, id: usize) -> [&[EventInstance<E>]; 2] {
+        [&self.events_a, &self.events_b].map(|events| &events[events.index_of(id)..])
+    }
+
+    /// Returns the stored events with an id of at least `id`, in the oldest and the newest
+    /// buffers, and where their reader records how far it read them.
+    pub(crate) fn events_from_mut(
+        &mut self,
+        id: usize,
+    ) -> ([&mut [EventInstance<E>]; 2], EventReads<'_>) {
+        let reads = EventReads::new(
+            &self.settings,
+            #[cfg(debug_assertions)]
+            &self.consumption,
+            &self.read_count,
+        );
+        let events = [&mut self.events_a, &mut self.events_b].map(|events| {
+            let index = events.index_of(id);
+            &mut events.events[index..]
+        });
+        (events, reads)
+    }
+
+    /// Returns where the readers record how far they read the events.
+    pub(crate) fn reads(&self) -> EventReads<'_> {
+        EventReads::new(
+            &self.settings,
+            #[cfg(debug_assertions)]
+            &self.consumption,
+            &self.read_count,
+        )
+    }
+
     /// Which event buffer is this event id a part of.
     fn sequence(&self, id: usize) -> &EventSequence<E> {
         if id < self.events_b.start_event_count {
@@ -322,14 +867,43 @@ impl<E: BufferedEvent> Extend<E> for Events<E> {
     where
         I: IntoIterator<Item = E>,
     {
+        if self.writes_blocked {
+            iter.into_iter().for_each(drop);
+            return;
+        }
+        if self.is_bounded() {
+            let caller = MaybeLocation::caller();
+            let events: Vec<E> = iter.into_iter().collect();
+            let keep = self.admit(events.len());
+            let skip = match self.settings().map(|settings| settings.overflow) {
+                Some(Overflow::DropNewest) => 0,
+                _ => events.len() - keep,
+            };
+            if skip > 0 {
+                // The first events are dropped as soon as they are written, along with all the
+                // stored ones. They still get an id, so that the ids follow the write order.
+                self.event_count += skip;
+                self.reset_start_event_count();
+            }
+            for event in events.into_iter().skip(skip).take(keep) {
+                self.push(event, caller);
+            }
+            return;
+        }
+
         let old_count = self.event_count;
         let mut event_count = self.event_count;
+        let oldest = self.events_a.start_event_count;
+        let mut sequence_stamps = self.sequence_stamps.as_mut();
         let events = iter.into_iter().map(|event| {
             let event_id = EventId {
                 id: event_count,
                 caller: MaybeLocation::caller(),
                 _marker: PhantomData,
             };
+            if let Some(stamps) = &mut sequence_stamps {
+                stamps.stamp(event_count, oldest);
+            }
             event_count += 1;
             EventInstance { event_id, event }
         });
@@ -366,6 +940,30 @@ impl<E: BufferedEvent> Default for EventSequence<E> {
     }
 }
 
+impl<E: BufferedEvent> EventSequence<E> {
+    /// Returns the index of the first event with an id of at least `id`.
+    pub(crate) fn index_of(&self, id: usize) -> usize {
+        // The ids only have gaps where events were removed with `Events::drain_where`, so the
+        // event is at most at the index it would have with contiguous ids.
+        let max = id.saturating_sub(self.start_event_count).min(self.len());
+        let events = &self.events[..max];
+        if events
+            .last()
+            .is_none_or(|instance| instance.event_id.id < id)
+        {
+            max
+        } else {
+            events.partition_point(|instance| instance.event_id.id < id)
+        }
+    }
+
+    /// Returns the id the sequence starts at: the id of its first event, or `end` if it is
+    /// empty.
+    fn start_after(&self, end: usize) -> usize {
+        self.first().map_or(end, |instance| instance.event_id.id)
+    }
+}
+
 impl<E: BufferedEvent> Deref for EventSequence<E> {
     type Target = Vec<EventInstance<E>>;
 
@@ -380,6 +978,248 @@ impl<E: BufferedEvent> DerefMut for EventSequence<E> {
     }
 }
 
+/// A shared monotonic counter used to stamp written events with a sequence number, see
+/// [`Events::stamp_sequence`].
+///
+/// Cloning the counter shares it. As a resource, it is the counter of the world, see
+/// [`World::stamp_event_sequence`](crate::world::World::stamp_event_sequence).
+#[derive(Resource, Clone, Debug, Default)]
+pub struct EventSequenceCounter(Arc<AtomicU64>);
+
+impl EventSequenceCounter {
+    /// Returns the next sequence number.
+    #[inline]
+    pub fn next(&self) -> u64 {
+        self.0.fetch_add(1, Ordering::Relaxed)
+    }
+}
+
+impl World {
+    /// [Stamps](Events::stamp_sequence) the events of type `E` written from now on with the
+    /// sequence numbers of the world's [`EventSequenceCounter`], so that they can be ordered
+    /// against the events of the other stamped types, see [`Events::get_sequence`].
+    ///
+    /// This initializes the [`EventSequenceCounter`] and the [`Events<E>`] resources if needed,
+    /// and does nothing if the events are already stamped, even with another counter.
+    pub fn stamp_event_sequence<E: BufferedEvent>(&mut self) {
+        let counter = self.get_resource_or_init::<EventSequenceCounter>().clone();
+        let mut events = self.get_resource_or_init::<Events<E>>();
+        if events.sequence_counter().is_none() {
+            events.bypass_change_detection().stamp_sequence(counter);
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct SequenceStamps {
+    counter: EventSequenceCounter,
+    /// The id and sequence number of the stamped events, in write order, down to the oldest
+    /// stored event at the last stamp.
+    stamps: VecDeque<(usize, u64)>,
+    /// The id of the first event not returned by [`Events::drain_sequence_stamps`] yet.
+    drained_until: usize,
+}
+
+impl SequenceStamps {
+    /// Stamps the event `event_id`, forgetting the stamps of the events older than `oldest`,
+    /// which are no longer stored.
+    #[inline]
+    fn stamp(&mut self, event_id: usize, oldest: usize) {
+        while self.stamps.front().is_some_and(|&(id, _)| id < oldest) {
+            self.stamps.pop_front();
+        }
+        self.stamps.push_back((event_id, self.counter.next()));
+    }
+
+    fn get(&self, event_id: usize) -> Option<u64> {
+        let index = self
+            .stamps
+            .binary_search_by_key(&event_id, |&(id, _)| id)
+            .ok()?;
+        Some(self.stamps[index].1)
+    }
+}
+
+/// Bounds the number of events stored by [`Events`], and sets how long they are kept for, see
+/// [`Events::set_settings`].
+///
+/// The capacity counts all the stored events, so a frame writing many events leaves less room for
+/// the next ones, until [`Events::update`] removes them.
+///
+/// ```
+/// # use bevy_ecs::event::{BufferedEvent, EventSettings, Events, Overflow};
+/// #[derive(BufferedEvent)]
+/// struct LogLine(String);
+///
+/// let mut events = Events::<LogLine>::default();
+/// events.set_settings(EventSettings {
+///     capacity: 2,
+///     overflow: Overflow::DropOldest,
+///     ..Default::default()
+/// });
+/// events.write_batch(["a", "b", "c"].map(|line| LogLine(line.to_string())));
+///
+/// let lines: Vec<_> = events.iter_current_update_events().map(|line| &line.0).collect();
+/// assert_eq!(lines, ["b", "c"]);
+/// assert_eq!(events.dropped(), 1);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EventSettings {
+    /// The maximum number of events stored. Defaults to `usize::MAX`, for no limit.
+    pub capacity: usize,
+    /// What happens to the events written once the capacity is reached.
+    pub overflow: Overflow,
+    /// How long the events are kept for.
+    pub retention: RetentionPolicy,
+}
+
+impl Default for EventSettings {
+    fn default() -> Self {
+        Self {
+            capacity: usize::MAX,
+            overflow: Overflow::DropOldest,
+            retention: RetentionPolicy::default(),
+        }
+    }
+}
+
+/// What happens to the events written once the [capacity](EventSettings::capacity) of the
+/// [`Events`] is reached.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Overflow {
+    /// The oldest events are dropped to make room for the new ones. The readers that didn't read
+    /// them yet miss them, like the events removed by [`Events::update`].
+    DropOldest,
+    /// The new events are dropped until there is room for them. Writing an event still returns
+    /// an id, which no event has until the next event is written.
+    DropNewest,
+    /// All the events are kept, but a warning is logged the first time the capacity is exceeded
+    /// in a frame.
+    Warn,
+}
+
+/// The number of events written and read by the readers of [`Events`], see [`Events::counts`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct EventCounts {
+    /// The number of events written, including the ones dropped because the buffers were full.
+    pub written: usize,
+    /// The number of events read since [`Events::count_reads`] was called, counted once per
+    /// reader, and only once the reader iterated past them.
+    pub read: usize,
+}
+
+/// How long the events are kept for by [`Events`], see [`EventSettings::retention`].
+///
+/// Whatever the policy, the [`EventReader`](super::EventReader)s read each event at most once, and
+/// only miss the events removed before they read them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RetentionPolicy {
+    /// The events are removed by the given [`Events::update`] after they were written. Defaults
+    /// to 2, so that the readers running once per frame, before or after the writers, read all
+    /// the events.
+    ///
+    /// A reader running every `k` frames reads all the events if they are kept for `k + 1`
+    /// frames. With 1 frame, the events are removed by the first update, so only the readers
+    /// running after the writers in the same frame read them.
+    Frames(u32),
+    /// The events are removed by the first [`Events::update`] once they are older than the
+    /// duration, but never before the second update after they were written, like by default.
+    Duration(Duration),
+    /// The events are never removed by [`Events::update`], only by
+    /// [`Events::clear_consumed`], [`Events::clear`] or the [capacity](EventSettings::capacity).
+    Manual,
+}
+
+impl Default for RetentionPolicy {
+    fn default() -> Self {
+        Self::Frames(2)
+    }
+}
+
+/// The [`EventSettings`] of [`Events`], and their state.
+#[derive(Debug)]
+struct Settings {
+    settings: EventSettings,
+    /// The number of events dropped since the events were created.
+    dropped: usize,
+    /// Whether the capacity was exceeded since the last update, with [`Overflow::Warn`].
+    warned: bool,
+    /// The event count and the time of the last updates, to remove the events once they are too
+    /// old for the [`RetentionPolicy`].
+    updates: VecDeque<(usize, Instant)>,
+    /// The event count up to which the events were read, for [`Events::clear_consumed`].
+    consumed_until: AtomicUsize,
+}
+
+/// Where the readers of [`Events`] record how far they read the events, for
+/// [`Events::clear_consumed`] and [`Events::track_consumption`], and how many they read, for
+/// [`Events::counts`].
+///
+/// The readers record the position of their cursor as it moves past the events, so the events
+/// they didn't get to, for example after stopping early, are not counted as read.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct EventReads<'a> {
+    /// The [`Settings::consumed_until`] of the events, if they have settings.
+    consumed_until: Option<&'a AtomicUsize>,
+    /// The [`ConsumptionTracker::consumed_until`] of the events, if they are tracked.
+    #[cfg(debug_assertions)]
+    tracked_until: Option<&'a AtomicUsize>,
+    /// The number of events read, if they are counted.
+    read_count: Option<&'a AtomicUsize>,
+}
+
+impl<'a> EventReads<'a> {
+    fn new(
+        settings: &'a Option<Settings>,
+        #[cfg(debug_assertions)] tracker: &'a Option<ConsumptionTracker>,
+        read_count: &'a Option<AtomicUsize>,
+    ) -> Self {
+        Self {
+            consumed_until: settings.as_ref().map(|settings| &settings.consumed_until),
+            #[cfg(debug_assertions)]
+            tracked_until: tracker.as_ref().map(|tracker| &tracker.consumed_until),
+            read_count: read_count.as_ref(),
+        }
+    }
+
+    /// Records that a reader read `read` events, up to the event count `cursor`.
+    #[inline]
+    pub(crate) fn record(&self, cursor: usize, read: usize) {
+        if let Some(consumed_until) = self.consumed_until {
+            consumed_until.fetch_max(cursor, Ordering::Relaxed);
+        }
+        #[cfg(debug_assertions)]
+        if let Some(tracked_until) = self.tracked_until {
+            tracked_until.fetch_max(cursor, Ordering::Relaxed);
+        }
+        if let Some(read_count) = self.read_count
+            && read > 0
+        {
+            read_count.fetch_add(read, Ordering::Relaxed);
+        }
+    }
+}
+
+/// The events that were not read before they became a frame old, see
+/// [`Events::track_consumption`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UnconsumedEvents {
+    /// The number of events that were not read.
+    pub count: usize,
+    /// Where the events that were not read were written, without duplicates.
+    ///
+    /// Empty unless the `track_location` feature is enabled.
+    pub writers: Vec<&'static Location<'static>>,
+}
+
+#[cfg(debug_assertions)]
+#[derive(Debug)]
+struct ConsumptionTracker {
+    /// The events with an id below this one were read.
+    consumed_until: AtomicUsize,
+    unconsumed: Option<UnconsumedEvents>,
+}
+
 /// [`Iterator`] over written [`EventIds`](`EventId`) from a batch.
 pub struct WriteBatchIds<E> {
     last_count: usize,
@@ -419,7 +1259,11 @@ impl<E: BufferedEvent> ExactSizeIterator for WriteBatchIds<E> {
 
 #[cfg(test)]
 mod tests {
-    use crate::event::{BufferedEvent, Events};
+    use crate::event::{
+        BufferedEvent, EventCursor, EventSettings, Events, Overflow, RetentionPolicy,
+    };
+    use alloc::vec::Vec;
+    use core::time::Duration;
 
     #[test]
     fn iter_current_update_events_iterates_over_current_events() {
@@ -452,4 +1296,255 @@ mod tests {
         assert_eq!(test_events.len(), 2); // Events are double-buffered, so we see 2 + 0 = 2
         assert_eq!(test_events.iter_current_update_events().count(), 0);
     }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    fn track_consumption() {
+        #[derive(BufferedEvent)]
+        struct TestEvent;
+
+        let mut events = Events::<TestEvent>::default();
+        events.track_consumption();
+        let mut cursor = EventCursor::default();
+
+        // Read before the update.
+        events.write(TestEvent);
+        assert_eq!(cursor.read(&events).count(), 1);
+        events.update();
+        assert_eq!(events.take_unconsumed(), None);
+
+        // Only read after the update, when the events are a frame old.
+        events.write(TestEvent);
+        events.write(TestEvent);
+        events.update();
+        assert_eq!(cursor.read(&events).count(), 2);
+        let unconsumed = events.take_unconsumed().unwrap();
+        assert_eq!(unconsumed.count, 2);
+        assert_eq!(events.take_unconsumed(), None);
+
+        // Draining reads the events.
+        events.write(TestEvent);
+        assert_eq!(events.drain().count(), 3);
+        events.update();
+        assert_eq!(events.take_unconsumed(), None);
+
+        // Stopping early only reads the events up to there.
+        events.write(TestEvent);
+        events.write(TestEvent);
+        assert!(cursor.read(&events).next().is_some());
+        events.update();
+        assert_eq!(events.take_unconsumed().unwrap().count, 1);
+    }
+
+    #[derive(BufferedEvent, Debug, PartialEq, Eq)]
+    struct Numbered(u32);
+
+    fn bounded(capacity: usize, overflow: Overflow) -> Events<Numbered> {
+        let mut events = Events::default();
+        events.set_settings(EventSettings {
+            capacity,
+            overflow,
+            ..Default::default()
+        });
+        events
+    }
+
+    fn stored(events: &Events<Numbered>) -> Vec<u32> {
+        events
+            .get_cursor()
+            .read(events)
+            .map(|event| event.0)
+            .collect()
+    }
+
+    #[test]
+    fn drop_oldest_keeps_the_newest_events() {
+        let mut events = bounded(3, Overflow::DropOldest);
+        let mut cursor = events.get_cursor();
+        events.write(Numbered(0));
+        events.write(Numbered(1));
+        events.update();
+        // The events of the previous frame are dropped first.
+        events.write_batch([2, 3].map(Numbered));
+        assert_eq!(stored(&events), [1, 2, 3]);
+        events.write_batch([4, 5, 6, 7].map(Numbered));
+        assert_eq!(stored(&events), [5, 6, 7]);
+        assert_eq!(events.dropped(), 5);
+
+        // The readers miss the dropped events, and the ids still refer to the right events.
+        assert_eq!(cursor.missed_events(&events), 5);
+        let read: Vec<_> = cursor
+            .read_with_id(&events)
+            .map(|(event, id)| (event.0, id.id))
+            .collect();
+        assert_eq!(read, [(5, 5), (6, 6), (7, 7)]);
+        assert_eq!(events.get_event(6).map(|(event, _)| event.0), Some(6));
+        assert!(events.get_event(4).is_none());
+
+        // The retention of the events is unchanged.
+        events.update();
+        assert_eq!(stored(&events), [5, 6, 7]);
+        events.update();
+        assert!(events.is_empty());
+    }
+
+    #[test]
+    fn drop_newest_keeps_the_oldest_events() {
+        let mut events = bounded(3, Overflow::DropNewest);
+        events.write_batch([0, 1].map(Numbered));
+        events.update();
+        let ids = events.write_batch([2, 3].map(Numbered));
+        assert_eq!(ids.len(), 1);
+        let id = events.write(Numbered(4));
+        assert!(events.get_event(id.id).is_none());
+        assert_eq!(stored(&events), [0, 1, 2]);
+        assert_eq!(events.dropped(), 2);
+
+        // There is room again once the events of the previous frame are removed.
+        events.update();
+        events.write_batch([5, 6, 7].map(Numbered));
+        assert_eq!(stored(&events), [2, 5, 6]);
+        assert_eq!(events.get_event(id.id).map(|(event, _)| event.0), Some(5));
+        assert_eq!(events.dropped(), 3);
+    }
+
+    #[test]
+    fn warn_keeps_all_the_events() {
+        let mut events = bounded(2, Overflow::Warn);
+        events.write_batch([0, 1, 2].map(Numbered));
+        events.write(Numbered(3));
+        assert_eq!(stored(&events), [0, 1, 2, 3]);
+        assert_eq!(events.dropped(), 0);
+    }
+
+    #[test]
+    fn events_are_unbounded_by_default() {
+        let mut events = Events::<Numbered>::default();
+        assert_eq!(events.settings(), None);
+        events.write_batch((0..1000).map(Numbered));
+        assert_eq!(events.len(), 1000);
+        assert_eq!(events.dropped(), 0);
+    }
+
+    fn retained(retention: RetentionPolicy) -> Events<Numbered> {
+        let mut events = Events::default();
+        events.set_settings(EventSettings {
+            retention,
+            ..Default::default()
+        });
+        events
+    }
+
+    #[test]
+    fn readers_running_every_few_frames_read_all_the_events_once() {
+        for k in 1..=4 {
+            let mut events = retained(RetentionPolicy::Frames(k + 1));
+            let mut cursor = events.get_cursor();
+            let mut read = Vec::new();
+            for frame in 0..40 {
+                if frame % k == 0 {
+                    assert_eq!(cursor.missed_events(&events), 0);
+                    read.extend(cursor.read(&events).map(|event| event.0));
+                }
+                events.write_batch([2 * frame, 2 * frame + 1].map(Numbered));
+                events.update();
+                // The events are removed once they are older than the retention.
+                assert!(events.len() <= 2 * k as usize);
+            }
+            read.extend(cursor.read(&events).map(|event| event.0));
+            assert_eq!(read, (0..80).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn two_frames_retention_is_the_default() {
+        let mut events = retained(RetentionPolicy::default());
+        events.write(Numbered(0));
+        events.update();
+        events.write(Numbered(1));
+        assert_eq!(stored(&events), [0, 1]);
+        assert_eq!(events.iter_current_update_events().count(), 1);
+        events.update();
+        assert_eq!(stored(&events), [1]);
+        events.update();
+        assert!(events.is_empty());
+    }
+
+    #[test]
+    fn events_are_kept_for_the_duration() {
+        let mut events = retained(RetentionPolicy::Duration(Duration::from_secs(3600)));
+        events.write(Numbered(0));
+        for _ in 0..10 {
+            events.update();
+        }
+        assert_eq!(stored(&events), [0]);
+
+        // The events are still kept for two frames when the duration is shorter.
+        let mut events = retained(RetentionPolicy::Duration(Duration::ZERO));
+        events.write(Numbered(0));
+        events.update();
+        assert_eq!(stored(&events), [0]);
+        events.update();
+        assert!(events.is_empty());
+    }
+
+    #[test]
+    fn manual_retention_keeps_the_events_until_they_are_consumed() {
+        let mut events = retained(RetentionPolicy::Manual);
+        let mut cursor = events.get_cursor();
+        events.write_batch([0, 1].map(Numbered));
+        for _ in 0..10 {
+            events.update();
+        }
+        assert_eq!(events.len(), 2);
+        events.clear_consumed();
+        assert_eq!(events.len(), 2);
+
+        assert_eq!(cursor.read(&events).count(), 2);
+        events.write(Numbered(2));
+        events.clear_consumed();
+        // The event written after the read is kept, and can still be read.
+        assert_eq!(events.len(), 1);
+        assert_eq!(
+            cursor
+                .read(&events)
+                .map(|event| event.0)
+                .collect::<Vec<_>>(),
+            [2]
+        );
+        assert_eq!(events.dropped(), 0);
+    }
+
+    #[test]
+    fn manual_retention_keeps_the_events_left_unread() {
+        let mut events = retained(RetentionPolicy::Manual);
+        let mut cursor = events.get_cursor();
+        events.write_batch([0, 1, 2, 3].map(Numbered));
+
+        // Looks at the stored events without reading them.
+        let stored = |events: &Events<Numbered>| {
+            let [a, b] = events.events_from(0);
+            a.iter()
+                .chain(b)
+                .map(|instance| instance.event.0)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(cursor.read(&events).next(), Some(&Numbered(0)));
+        events.clear_consumed();
+        assert_eq!(stored(&events), [1, 2, 3]);
+
+        // Creating the iterator without reading doesn't consume anything either.
+        let _ = cursor.read(&events);
+        assert_eq!(cursor.read(&events).nth(1), Some(&Numbered(2)));
+        events.clear_consumed();
+        assert_eq!(stored(&events), [3]);
+        assert_eq!(
+            cursor
+                .read(&events)
+                .map(|event| event.0)
+                .collect::<Vec<_>>(),
+            [3]
+        );
+    }
 }
@@ -90,6 +90,12 @@ impl<E: BufferedEvent> EventCursor<E> {
         EventIteratorWithId::new(self, events)
     }
 
+    /// Reads the events like [`EventCursor::read`], without counting them as read for
+    /// [`Events::clear_consumed`].
+    pub(crate) fn peek<'a>(&'a mut self, events: &'a Events<E>) -> EventIterator<'a, E> {
+        self.read_with_id(events).peeking().without_id()
+    }
+
     /// See [`EventMutator::read_with_id`](super::EventMutator::read_with_id)
     pub fn read_mut_with_id<'a>(
         &'a mut self,
@@ -112,14 +118,11 @@ impl<E: BufferedEvent> EventCursor<E> {
 
     /// See [`EventReader::len`](super::EventReader::len)
     pub fn len(&self, events: &Events<E>) -> usize {
-        // The number of events in this reader is the difference between the most recent event
-        // and the last event seen by it. This will be at most the number of events contained
-        // with the events (any others have already been dropped)
+        // The number of events in this reader is the number of stored events written after the
+        // last event seen by it (any others have already been dropped or drained)
         // TODO: Warn when there are dropped events, or return e.g. a `Result<usize, (usize, usize)>`
-        events
-            .event_count
-            .saturating_sub(self.last_event_count)
-            .min(events.len())
+        let [a, b] = events.events_from(self.last_event_count);
+        a.len() + b.len()
     }
 
     /// Amount of events we missed.
@@ -136,6 +139,7 @@ impl<E: BufferedEvent> EventCursor<E> {
 
     /// See [`EventReader::clear()`](super::EventReader::clear)
     pub fn clear(&mut self, events: &Events<E>) {
+        events.mark_consumed();
         self.last_event_count = events.event_count;
     }
 }
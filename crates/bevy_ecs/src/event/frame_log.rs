@@ -0,0 +1,223 @@
+use alloc::{boxed::Box, vec::Vec};
+use core::{any::Any, fmt};
+
+use serde::{ser::SerializeStruct, Serialize, Serializer};
+
+use crate::{
+    event::{BufferedEvent, EventSequenceCounter, Events},
+    resource::Resource,
+};
+
+/// Gathers the events of several types into a single batch, ordered by the order in which they
+/// were written.
+///
+/// Each event type logged to the frame log is [stamped](Events::stamp_sequence) with the
+/// [`counter`](Self::counter) of the log, and its events are [collected](Self::collect) once per
+/// frame. The batch can then be [drained](Self::drain), for example to send all the events of a
+/// frame over the network in a single message.
+///
+/// In an `App`, this is set up by `App::log_events_to_frame_log`.
+#[derive(Resource, Default)]
+pub struct FrameEventLog {
+    counter: EventSequenceCounter,
+    entries: Vec<FrameEventLogEntry>,
+}
+
+impl FrameEventLog {
+    /// Creates an empty log whose events are stamped with `counter`, for example the world's
+    /// [`EventSequenceCounter`], so that the logged events keep the sequence numbers of the
+    /// [`EventId`](super::EventId)s.
+    pub fn new(counter: EventSequenceCounter) -> Self {
+        Self {
+            counter,
+            entries: Vec::new(),
+        }
+    }
+
+    /// Returns the counter that stamps the events logged to this log.
+    pub fn counter(&self) -> &EventSequenceCounter {
+        &self.counter
+    }
+
+    /// Appends the events stamped in `events` since the last collection.
+    ///
+    /// Events that were already removed from `events` are skipped.
+    pub fn collect<E: BufferedEvent + Clone + Serialize>(&mut self, events: &mut Events<E>) {
+        let stamps = events.drain_sequence_stamps().collect::<Vec<_>>();
+        self.entries
+            .extend(stamps.into_iter().filter_map(|(id, sequence)| {
+                let (event, _) = events.get_event(id)?;
+                Some(FrameEventLogEntry {
+                    sequence,
+                    event_type: core::any::type_name::<E>(),
+                    event: Box::new(event.clone()),
+                })
+            }));
+    }
+
+    /// Returns the number of events in the log.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the log is empty.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Removes and returns the logged events, ordered by the order in which they were written.
+    pub fn drain(&mut self) -> Vec<FrameEventLogEntry> {
+        let mut entries = core::mem::take(&mut self.entries);
+        entries.sort_by_key(|entry| entry.sequence);
+        entries
+    }
+
+    /// Removes the logged events.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+/// An event of a [`FrameEventLog`].
+///
+/// Serializes as a struct with the `sequence` number, the `event_type` name and the `event`.
+pub struct FrameEventLogEntry {
+    /// The sequence number the event was stamped with when it was written.
+    pub sequence: u64,
+    /// The type name of the event.
+    pub event_type: &'static str,
+    event: Box<dyn LoggedEvent>,
+}
+
+impl FrameEventLogEntry {
+    /// Returns the event, if it is of type `E`.
+    pub fn downcast_ref<E: BufferedEvent>(&self) -> Option<&E> {
+        self.event.as_any().downcast_ref()
+    }
+}
+
+impl fmt::Debug for FrameEventLogEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FrameEventLogEntry")
+            .field("sequence", &self.sequence)
+            .field("event_type", &self.event_type)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Serialize for FrameEventLogEntry {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("FrameEventLogEntry", 3)?;
+        state.serialize_field("sequence", &self.sequence)?;
+        state.serialize_field("event_type", self.event_type)?;
+        state.serialize_field("event", self.event.as_serialize())?;
+        state.end()
+    }
+}
+
+/// A type-erased event that can be serialized.
+trait LoggedEvent: Send + Sync {
+    fn as_any(&self) -> &dyn Any;
+    fn as_serialize(&self) -> &dyn erased_serde::Serialize;
+}
+
+impl<E: BufferedEvent + Serialize> LoggedEvent for E {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_serialize(&self) -> &dyn erased_serde::Serialize {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Serialize;
+    use serde_test::{assert_ser_tokens, Token};
+
+    use super::FrameEventLog;
+    use crate::event::{BufferedEvent, Events};
+
+    #[derive(BufferedEvent, Serialize, Clone, Debug, PartialEq)]
+    struct Moved(u32);
+
+    #[derive(BufferedEvent, Serialize, Clone, Debug, PartialEq)]
+    struct Fired;
+
+    #[test]
+    fn collect_orders_by_write_order() {
+        let mut log = FrameEventLog::default();
+        let mut moved = Events::<Moved>::default();
+        let mut fired = Events::<Fired>::default();
+        moved.write(Moved(0));
+        moved.stamp_sequence(log.counter().clone());
+        fired.stamp_sequence(log.counter().clone());
+
+        moved.write(Moved(1));
+        fired.write(Fired);
+        moved.write_batch([Moved(2), Moved(3)]);
+        fired.write(Fired);
+
+        log.collect(&mut moved);
+        log.collect(&mut fired);
+        let entries = log.drain();
+        assert!(log.is_empty());
+
+        let sequences = entries
+            .iter()
+            .map(|e| e.sequence)
+            .collect::<alloc::vec::Vec<_>>();
+        assert_eq!(sequences, [0, 1, 2, 3, 4]);
+        assert_eq!(entries[0].downcast_ref::<Moved>(), Some(&Moved(1)));
+        assert_eq!(entries[1].downcast_ref::<Fired>(), Some(&Fired));
+        assert_eq!(entries[1].downcast_ref::<Moved>(), None);
+        assert_eq!(entries[3].downcast_ref::<Moved>(), Some(&Moved(3)));
+
+        // Events are only collected once, and removed events are skipped.
+        moved.write(Moved(4));
+        moved.update();
+        moved.update();
+        log.collect(&mut moved);
+        log.collect(&mut fired);
+        assert!(log.is_empty());
+    }
+
+    #[test]
+    fn serialize_mixed_batch() {
+        let mut log = FrameEventLog::default();
+        let mut moved = Events::<Moved>::default();
+        let mut fired = Events::<Fired>::default();
+        moved.stamp_sequence(log.counter().clone());
+        fired.stamp_sequence(log.counter().clone());
+        fired.write(Fired);
+        moved.write(Moved(7));
+        log.collect(&mut moved);
+        log.collect(&mut fired);
+
+        let entry = |sequence, event_type| {
+            [
+                Token::Struct {
+                    name: "FrameEventLogEntry",
+                    len: 3,
+                },
+                Token::Str("sequence"),
+                Token::U64(sequence),
+                Token::Str("event_type"),
+                Token::Str(event_type),
+                Token::Str("event"),
+            ]
+        };
+        let mut tokens = alloc::vec![Token::Seq { len: Some(2) }];
+        tokens.extend(entry(0, "bevy_ecs::event::frame_log::tests::Fired"));
+        tokens.extend([Token::UnitStruct { name: "Fired" }, Token::StructEnd]);
+        tokens.extend(entry(1, "bevy_ecs::event::frame_log::tests::Moved"));
+        tokens.extend([
+            Token::NewtypeStruct { name: "Moved" },
+            Token::U32(7),
+            Token::StructEnd,
+            Token::SeqEnd,
+        ]);
+        assert_ser_tokens(&log.drain(), &tokens);
+    }
+}
@@ -1,6 +1,6 @@
 #[cfg(feature = "multi_threaded")]
 use bevy_ecs::batching::BatchingStrategy;
-use bevy_ecs::event::{BufferedEvent, EventCursor, EventId, EventInstance, Events};
+use bevy_ecs::event::{BufferedEvent, EventCursor, EventId, EventInstance, EventReads, Events};
 use core::{iter::Chain, slice::Iter};
 
 /// An iterator that yields any unread events from an [`EventReader`](super::EventReader) or [`EventCursor`].
@@ -47,24 +47,29 @@ pub struct EventIteratorWithId<'a, E: BufferedEvent> {
     reader: &'a mut EventCursor<E>,
     chain: Chain<Iter<'a, EventInstance<E>>, Iter<'a, EventInstance<E>>>,
     unread: usize,
+    /// The id of the next event to be written, which the cursor moves to once all the events
+    /// are read.
+    end: usize,
+    /// Where the cursor position is recorded as it moves.
+    reads: EventReads<'a>,
+    /// The events being read, to look up their sequence numbers.
+    events: &'a Events<E>,
 }
 
 impl<'a, E: BufferedEvent> EventIteratorWithId<'a, E> {
     /// Creates a new iterator that yields any `events` that have not yet been seen by `reader`.
     pub fn new(reader: &'a mut EventCursor<E>, events: &'a Events<E>) -> Self {
-        let a_index = reader
-            .last_event_count
-            .saturating_sub(events.events_a.start_event_count);
-        let b_index = reader
-            .last_event_count
-            .saturating_sub(events.events_b.start_event_count);
-        let a = events.events_a.get(a_index..).unwrap_or_default();
-        let b = events.events_b.get(b_index..).unwrap_or_default();
+        let end = events.event_count;
+        let [a, b] = events.events_from(reader.last_event_count);
 
         let unread_count = a.len() + b.len();
         // Ensure `len` is implemented correctly
         debug_assert_eq!(unread_count, reader.len(events));
-        reader.last_event_count = events.event_count - unread_count;
+        // Skip the events that were missed.
+        reader.last_event_count = a
+            .first()
+            .or(b.first())
+            .map_or(end, |instance| instance.event_id.id);
         // Iterate the oldest first, then the newer events
         let chain = a.iter().chain(b.iter());
 
@@ -72,13 +77,50 @@ impl<'a, E: BufferedEvent> EventIteratorWithId<'a, E> {
             reader,
             chain,
             unread: unread_count,
+            end,
+            reads: events.reads(),
+            events,
         }
     }
 
+    /// Returns the sequence number of the next event without reading it, `u64::MAX` if it isn't
+    /// stamped, or `None` if all the events were read.
+    fn peek_sequence(&self) -> Option<u64> {
+        let instance = self.chain.clone().next()?;
+        Some(
+            self.events
+                .get_sequence(instance.event_id.id)
+                .unwrap_or(u64::MAX),
+        )
+    }
+
+    /// Returns where the cursor is once the event `id` is read: right after it, or past all the
+    /// events written so far if it was the last unread one.
+    fn cursor_after(&self, id: EventId<E>) -> usize {
+        if self.unread == 0 {
+            self.end
+        } else {
+            id.id + 1
+        }
+    }
+
+    /// Moves the cursor to the event count `cursor` past `read` events, recording how far the
+    /// events were read.
+    fn move_cursor(&mut self, cursor: usize, read: usize) {
+        self.reader.last_event_count = cursor;
+        self.reads.record(cursor, read);
+    }
+
     /// Iterate over only the events.
     pub fn without_id(self) -> EventIterator<'a, E> {
         EventIterator { iter: self }
     }
+
+    /// Doesn't record how far the events were read, so that they aren't counted as read.
+    pub(crate) fn peeking(mut self) -> Self {
+        self.reads = EventReads::default();
+        self
+    }
 }
 
 impl<'a, E: BufferedEvent> Iterator for EventIteratorWithId<'a, E> {
@@ -92,8 +134,8 @@ impl<'a, E: BufferedEvent> Iterator for EventIteratorWithId<'a, E> {
             Some(item) => {
                 #[cfg(feature = "detailed_trace")]
                 tracing::trace!("EventReader::iter() -> {}", item.1);
-                self.reader.last_event_count += 1;
                 self.unread -= 1;
+                self.move_cursor(self.cursor_after(item.1), 1);
                 Some(item)
             }
             None => None,
@@ -104,27 +146,27 @@ impl<'a, E: BufferedEvent> Iterator for EventIteratorWithId<'a, E> {
         self.chain.size_hint()
     }
 
-    fn count(self) -> usize {
-        self.reader.last_event_count += self.unread;
+    fn count(mut self) -> usize {
+        self.move_cursor(self.end, self.unread);
         self.unread
     }
 
-    fn last(self) -> Option<Self::Item>
+    fn last(mut self) -> Option<Self::Item>
     where
         Self: Sized,
     {
-        let EventInstance { event_id, event } = self.chain.last()?;
-        self.reader.last_event_count += self.unread;
+        let EventInstance { event_id, event } = self.chain.by_ref().last()?;
+        self.move_cursor(self.end, self.unread);
         Some((event, *event_id))
     }
 
     fn nth(&mut self, n: usize) -> Option<Self::Item> {
         if let Some(EventInstance { event_id, event }) = self.chain.nth(n) {
-            self.reader.last_event_count += n + 1;
             self.unread -= n + 1;
+            self.move_cursor(self.cursor_after(*event_id), n + 1);
             Some((event, *event_id))
         } else {
-            self.reader.last_event_count += self.unread;
+            self.move_cursor(self.end, self.unread);
             self.unread = 0;
             None
         }
@@ -137,6 +179,68 @@ impl<'a, E: BufferedEvent> ExactSizeIterator for EventIteratorWithId<'a, E> {
     }
 }
 
+/// An event yielded by a [`MergedEventIterator`], of either of the two merged types.
+#[derive(Debug)]
+pub enum MergedEvent<'a, A: BufferedEvent, B: BufferedEvent> {
+    /// An event of the first type.
+    First(&'a A, EventId<A>),
+    /// An event of the second type.
+    Second(&'a B, EventId<B>),
+}
+
+/// An iterator that yields the unread events of two types, ordered by their
+/// [sequence numbers](Events::get_sequence), see
+/// [`EventReader::read_merged`](super::EventReader::read_merged).
+///
+/// The events of each type are yielded in the order they were written. Events without a
+/// sequence number are yielded after the ones with a sequence number, and on ties, the events of
+/// the first type are yielded first.
+#[derive(Debug)]
+pub struct MergedEventIterator<'a, A: BufferedEvent, B: BufferedEvent> {
+    first: EventIteratorWithId<'a, A>,
+    second: EventIteratorWithId<'a, B>,
+}
+
+impl<'a, A: BufferedEvent, B: BufferedEvent> MergedEventIterator<'a, A, B> {
+    /// Creates a new iterator merging the unread events of `first` and `second`.
+    pub fn new(first: EventIteratorWithId<'a, A>, second: EventIteratorWithId<'a, B>) -> Self {
+        Self { first, second }
+    }
+}
+
+impl<'a, A: BufferedEvent, B: BufferedEvent> Iterator for MergedEventIterator<'a, A, B> {
+    type Item = MergedEvent<'a, A, B>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Peek without reading, so that the cursors only move past the yielded events.
+        match (self.first.peek_sequence(), self.second.peek_sequence()) {
+            (Some(a), Some(b)) if b < a => self
+                .second
+                .next()
+                .map(|(event, id)| MergedEvent::Second(event, id)),
+            (Some(_), _) => self
+                .first
+                .next()
+                .map(|(event, id)| MergedEvent::First(event, id)),
+            (None, _) => self
+                .second
+                .next()
+                .map(|(event, id)| MergedEvent::Second(event, id)),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<'a, A: BufferedEvent, B: BufferedEvent> ExactSizeIterator for MergedEventIterator<'a, A, B> {
+    fn len(&self) -> usize {
+        self.first.len() + self.second.len()
+    }
+}
+
 /// A parallel iterator over `BufferedEvent`s.
 #[cfg(feature = "multi_threaded")]
 #[derive(Debug)]
@@ -146,25 +250,26 @@ pub struct EventParIter<'a, E: BufferedEvent> {
     batching_strategy: BatchingStrategy,
     #[cfg(not(target_arch = "wasm32"))]
     unread: usize,
+    end: usize,
+    reads: EventReads<'a>,
+    events: &'a Events<E>,
 }
 
 #[cfg(feature = "multi_threaded")]
 impl<'a, E: BufferedEvent> EventParIter<'a, E> {
     /// Creates a new parallel iterator over `events` that have not yet been seen by `reader`.
     pub fn new(reader: &'a mut EventCursor<E>, events: &'a Events<E>) -> Self {
-        let a_index = reader
-            .last_event_count
-            .saturating_sub(events.events_a.start_event_count);
-        let b_index = reader
-            .last_event_count
-            .saturating_sub(events.events_b.start_event_count);
-        let a = events.events_a.get(a_index..).unwrap_or_default();
-        let b = events.events_b.get(b_index..).unwrap_or_default();
+        let end = events.event_count;
+        let [a, b] = events.events_from(reader.last_event_count);
 
         let unread_count = a.len() + b.len();
         // Ensure `len` is implemented correctly
         debug_assert_eq!(unread_count, reader.len(events));
-        reader.last_event_count = events.event_count - unread_count;
+        // Skip the events that were missed.
+        reader.last_event_count = a
+            .first()
+            .or(b.first())
+            .map_or(end, |instance| instance.event_id.id);
 
         Self {
             reader,
@@ -172,6 +277,9 @@ impl<'a, E: BufferedEvent> EventParIter<'a, E> {
             batching_strategy: BatchingStrategy::default(),
             #[cfg(not(target_arch = "wasm32"))]
             unread: unread_count,
+            end,
+            reads: events.reads(),
+            events,
         }
     }
 
@@ -188,6 +296,10 @@ impl<'a, E: BufferedEvent> EventParIter<'a, E> {
     ///
     /// Unlike normal iteration, the event order is not guaranteed in any form.
     ///
+    /// The events are split in batches according to the [`BatchingStrategy`]. When they all fit
+    /// in a single batch, for example when there are fewer events than the minimum batch size,
+    /// they are read sequentially on the current thread, avoiding the cost of spawning tasks.
+    ///
     /// # Panics
     /// If the [`ComputeTaskPool`] is not initialized. If using this from an event reader that is being
     /// initialized and run from the ECS scheduler, this should never panic.
@@ -228,6 +340,9 @@ impl<'a, E: BufferedEvent> EventParIter<'a, E> {
             let batch_size = self
                 .batching_strategy
                 .calc_batch_size(|| self.len(), thread_count);
+            if self.len() <= batch_size {
+                return self.into_iter().for_each(|(e, i)| func(e, i));
+            }
             let chunks = self.slices.map(|s| s.chunks_exact(batch_size));
             let remainders = chunks.each_ref().map(core::slice::ChunksExact::remainder);
 
@@ -243,7 +358,8 @@ impl<'a, E: BufferedEvent> EventParIter<'a, E> {
             });
 
             // Events are guaranteed to be read at this point.
-            self.reader.last_event_count += self.unread;
+            self.reader.last_event_count = self.end;
+            self.reads.record(self.end, self.unread);
             self.unread = 0;
         }
     }
@@ -268,6 +384,9 @@ impl<'a, E: BufferedEvent> IntoIterator for EventParIter<'a, E> {
         let EventParIter {
             reader,
             slices: [a, b],
+            end,
+            reads,
+            events,
             ..
         } = self;
         let unread = a.len() + b.len();
@@ -276,6 +395,9 @@ impl<'a, E: BufferedEvent> IntoIterator for EventParIter<'a, E> {
             reader,
             chain,
             unread,
+            end,
+            reads,
+            events,
         }
     }
 }
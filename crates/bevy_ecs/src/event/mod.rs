@@ -1,30 +1,45 @@
 //! Event handling types.
+#[cfg(feature = "auto_register_events")]
+mod auto_register;
 mod base;
 mod collections;
 mod event_cursor;
+#[cfg(feature = "serialize")]
+mod frame_log;
 mod iterators;
 mod mut_iterators;
 mod mutator;
 mod reader;
 mod registry;
+mod sender;
 mod update;
 mod writer;
 
+#[cfg(feature = "auto_register_events")]
+#[doc(hidden)]
+pub use auto_register::DerivedEventRegistration;
 pub(crate) use base::EventInstance;
 pub use base::{BufferedEvent, EntityEvent, Event, EventId, EventKey};
 pub use bevy_ecs_macros::{BufferedEvent, EntityEvent, Event};
+pub(crate) use collections::EventReads;
 #[expect(deprecated, reason = "`SendBatchIds` was renamed to `WriteBatchIds`.")]
-pub use collections::{Events, SendBatchIds, WriteBatchIds};
+pub use collections::{
+    EventCounts, EventSequenceCounter, EventSettings, Events, Overflow, RetentionPolicy,
+    SendBatchIds, UnconsumedEvents, WriteBatchIds,
+};
 pub use event_cursor::EventCursor;
+#[cfg(feature = "serialize")]
+pub use frame_log::{FrameEventLog, FrameEventLogEntry};
 #[cfg(feature = "multi_threaded")]
 pub use iterators::EventParIter;
-pub use iterators::{EventIterator, EventIteratorWithId};
+pub use iterators::{EventIterator, EventIteratorWithId, MergedEvent, MergedEventIterator};
 #[cfg(feature = "multi_threaded")]
 pub use mut_iterators::EventMutParIter;
 pub use mut_iterators::{EventMutIterator, EventMutIteratorWithId};
 pub use mutator::EventMutator;
 pub use reader::EventReader;
 pub use registry::{EventRegistry, ShouldUpdateEvents};
+pub use sender::{EventSender, EventSenderClosedError};
 #[expect(
     deprecated,
     reason = "`EventUpdates` was renamed to `EventUpdateSystems`."
@@ -233,6 +248,79 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_blocked_writes_are_discarded() {
+        let mut events = Events::<TestEvent>::default();
+        let mut reader = events.get_cursor();
+        events.write(TestEvent { i: 0 });
+        events.set_writes_blocked(true);
+        let id = events.write(TestEvent { i: 1 });
+        let ids = events.write_batch((2..4).map(|i| TestEvent { i }));
+        assert_eq!(ids.len(), 0);
+        events.set_writes_blocked(false);
+        assert_eq!(events.write(TestEvent { i: 4 }), id);
+        assert_eq!(
+            reader
+                .read(&events)
+                .map(|event| event.i)
+                .collect::<Vec<_>>(),
+            [0, 4]
+        );
+    }
+
+    #[test]
+    fn test_event_counts() {
+        let mut events = Events::<TestEvent>::default();
+        let mut reader_a = events.get_cursor();
+        let mut reader_b = events.get_cursor();
+        events.write_batch((0..3).map(|i| TestEvent { i }));
+        // The reads are only counted once enabled.
+        assert_eq!(reader_b.clone().read(&events).count(), 3);
+        assert_eq!(events.counts().read, 0);
+        events.count_reads();
+        assert_eq!(reader_a.read(&events).count(), 3);
+        events.update();
+        events.write(TestEvent { i: 3 });
+        // Each reader counts the events it reads, and the counts keep growing across updates.
+        assert_eq!(reader_a.read(&events).count(), 1);
+        assert_eq!(reader_b.read(&events).count(), 4);
+        assert_eq!(reader_b.read(&events).count(), 0);
+        assert_eq!(
+            events.counts(),
+            EventCounts {
+                written: 4,
+                read: 8
+            }
+        );
+
+        // Only the events the reader got to are counted.
+        let mut reader_c = events.get_cursor();
+        events.write_batch((4..7).map(|i| TestEvent { i }));
+        assert!(reader_c.read(&events).next().is_some());
+        let _ = reader_c.read(&events);
+        assert_eq!(events.counts().read, 9);
+    }
+
+    #[test]
+    fn test_write_batch_capped() {
+        let mut events = Events::<TestEvent>::default();
+        events.write(TestEvent { i: 0 });
+
+        let mut pending = (1..6).map(|i| TestEvent { i });
+        let (event_ids, remaining) = events.write_batch_capped(&mut pending, 3);
+        assert_eq!(event_ids.len(), 3);
+        assert_eq!(remaining, 2);
+        let ids: Vec<_> = event_ids.map(|id| id.id).collect();
+        assert_eq!(ids, [1, 2, 3]);
+        // The other events are left in the iterator.
+        assert_eq!(pending.next(), Some(TestEvent { i: 4 }));
+
+        let (event_ids, remaining) = events.write_batch_capped(pending, 3);
+        assert_eq!(event_ids.len(), 1);
+        assert_eq!(remaining, 0);
+        assert_eq!(events.len(), 5);
+    }
+
     #[test]
     fn test_event_registry_can_add_and_remove_events_to_world() {
         use bevy_ecs::prelude::*;
@@ -274,6 +362,61 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_events_drain_where() {
+        let mut events = Events::<TestEvent>::default();
+        let mut router = events.get_cursor();
+        let mut other = events.get_cursor();
+
+        // The events span both buffers.
+        events.write_batch((0..3).map(|i| TestEvent { i }));
+        events.update();
+        events.write_batch((3..6).map(|i| TestEvent { i }));
+        assert!(other.read(&events).take(2).map(|e| e.i).eq([0, 1]));
+
+        let drained = events.drain_where(|e| e.i % 2 == 0);
+        assert!(drained.map(|e| e.i).eq([0, 2, 4]));
+        assert!(events.get_event(2).is_none());
+        assert_eq!(events.get_event(3).unwrap().1.id, 3);
+
+        // The router reads the remaining events, with their original ids.
+        assert_eq!(router.len(&events), 3);
+        let read = router.read_with_id(&events).map(|(e, id)| (e.i, id.id));
+        assert!(read.eq([(1, 1), (3, 3), (5, 5)]));
+
+        // The other reader only reads the remaining events it didn't read yet, once.
+        assert_eq!(other.len(&events), 2);
+        assert!(other.read(&events).map(|e| e.i).eq([3, 5]));
+        assert_eq!(other.read(&events).count(), 0);
+
+        events.write(TestEvent { i: 6 });
+        assert!(router.read(&events).map(|e| e.i).eq([6]));
+        assert!(other.read(&events).map(|e| e.i).eq([6]));
+        assert_eq!(other.missed_events(&events), 0);
+    }
+
+    #[test]
+    fn test_events_drain_where_then_drop_oldest() {
+        let mut events = Events::<TestEvent>::default();
+        events.set_settings(EventSettings {
+            capacity: 4,
+            overflow: Overflow::DropOldest,
+            ..Default::default()
+        });
+        let mut reader = events.get_cursor();
+        events.write_batch((0..4).map(|i| TestEvent { i }));
+        assert_eq!(events.drain_where(|e| e.i < 2).count(), 2);
+
+        // Only the oldest remaining event is dropped to make room for the new ones.
+        events.write_batch((4..7).map(|i| TestEvent { i }));
+        assert_eq!(events.len(), 4);
+        assert!(reader.read(&events).map(|e| e.i).eq([3, 4, 5, 6]));
+
+        events.update();
+        events.update();
+        assert!(events.is_empty());
+    }
+
     #[test]
     fn test_events_empty() {
         let mut events = Events::<TestEvent>::default();
@@ -468,6 +611,36 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "multi_threaded")]
+    #[test]
+    fn test_event_cursor_par_read_single_batch() {
+        use crate::batching::BatchingStrategy;
+        use bevy_platform::sync::Mutex;
+        use bevy_tasks::{ComputeTaskPool, TaskPool};
+        use std::thread;
+
+        ComputeTaskPool::get_or_init(TaskPool::default);
+        let mut events = Events::<TestEvent>::default();
+        let written = events.write_batch((0..100).map(|i| TestEvent { i }));
+        let mut cursor = events.get_cursor();
+
+        // All the events fit in a single batch, so they are read on the current thread.
+        let current = thread::current().id();
+        let read = Mutex::new(Vec::new());
+        cursor
+            .par_read(&events)
+            .batching_strategy(BatchingStrategy::fixed(1000))
+            .for_each_with_id(|event, id| {
+                assert_eq!(thread::current().id(), current);
+                read.lock().unwrap().push((event.i, id));
+            });
+        let mut read = read.into_inner().unwrap();
+        read.sort_by_key(|(i, _)| *i);
+        assert!(read.iter().map(|(i, _)| *i).eq(0..100));
+        assert!(read.iter().map(|(_, id)| *id).eq(written));
+        assert_eq!(cursor.len(&events), 0);
+    }
+
     #[cfg(feature = "multi_threaded")]
     #[test]
     fn test_event_cursor_par_read_mut() {
@@ -627,4 +800,71 @@ mod tests {
         });
         schedule.run(&mut world);
     }
+
+    #[test]
+    fn test_event_reader_read_merged() {
+        use bevy_ecs::prelude::*;
+
+        #[derive(BufferedEvent)]
+        struct Damage(u32);
+
+        #[derive(BufferedEvent)]
+        struct Death(u32);
+
+        #[derive(Resource, Default)]
+        struct CombatLog(Vec<(&'static str, u32)>);
+
+        let mut world = World::new();
+        world.init_resource::<CombatLog>();
+        world.stamp_event_sequence::<Damage>();
+        world.stamp_event_sequence::<Death>();
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(
+            (
+                |mut damage: EventWriter<Damage>, mut deaths: EventWriter<Death>| {
+                    damage.write(Damage(1));
+                    deaths.write(Death(1));
+                    damage.write_batch([Damage(2), Damage(3)]);
+                    deaths.write(Death(2));
+                    damage.write(Damage(4));
+                },
+                |mut damage: EventReader<Damage>,
+                 mut deaths: EventReader<Death>,
+                 mut log: ResMut<CombatLog>| {
+                    let merged = damage.read_merged(&mut deaths);
+                    assert_eq!(merged.len(), 6);
+                    log.0.extend(merged.map(|event| match event {
+                        MergedEvent::First(Damage(i), _) => ("damage", *i),
+                        MergedEvent::Second(Death(i), _) => ("death", *i),
+                    }));
+                    assert!(damage.is_empty());
+                    assert!(deaths.is_empty());
+                },
+            )
+                .chain(),
+        );
+        schedule.run(&mut world);
+        schedule.run(&mut world);
+
+        let expected = [
+            ("damage", 1),
+            ("death", 1),
+            ("damage", 2),
+            ("damage", 3),
+            ("death", 2),
+            ("damage", 4),
+        ];
+        let log = &world.resource::<CombatLog>().0;
+        assert_eq!(log[..6], expected);
+        assert_eq!(log[6..], expected);
+
+        let damage = world.resource::<Events<Damage>>();
+        let sequences = damage
+            .get_cursor()
+            .read_with_id(damage)
+            .map(|(_, id)| id.sequence(damage).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(sequences, [0, 2, 3, 5, 6, 8, 9, 11]);
+    }
 }
@@ -1,6 +1,6 @@
 #[cfg(feature = "multi_threaded")]
 use bevy_ecs::batching::BatchingStrategy;
-use bevy_ecs::event::{BufferedEvent, EventCursor, EventId, EventInstance, Events};
+use bevy_ecs::event::{BufferedEvent, EventCursor, EventId, EventInstance, EventReads, Events};
 use core::{iter::Chain, slice::IterMut};
 
 /// An iterator that yields any unread events from an [`EventMutator`] or [`EventCursor`].
@@ -51,23 +51,26 @@ pub struct EventMutIteratorWithId<'a, E: BufferedEvent> {
     mutator: &'a mut EventCursor<E>,
     chain: Chain<IterMut<'a, EventInstance<E>>, IterMut<'a, EventInstance<E>>>,
     unread: usize,
+    /// The id of the next event to be written, which the cursor moves to once all the events
+    /// are read.
+    end: usize,
+    /// Where the cursor position is recorded as it moves.
+    reads: EventReads<'a>,
 }
 
 impl<'a, E: BufferedEvent> EventMutIteratorWithId<'a, E> {
     /// Creates a new iterator that yields any `events` that have not yet been seen by `mutator`.
     pub fn new(mutator: &'a mut EventCursor<E>, events: &'a mut Events<E>) -> Self {
-        let a_index = mutator
-            .last_event_count
-            .saturating_sub(events.events_a.start_event_count);
-        let b_index = mutator
-            .last_event_count
-            .saturating_sub(events.events_b.start_event_count);
-        let a = events.events_a.get_mut(a_index..).unwrap_or_default();
-        let b = events.events_b.get_mut(b_index..).unwrap_or_default();
+        let end = events.event_count;
+        let ([a, b], reads) = events.events_from_mut(mutator.last_event_count);
 
         let unread_count = a.len() + b.len();
 
-        mutator.last_event_count = events.event_count - unread_count;
+        // Skip the events that were missed.
+        mutator.last_event_count = a
+            .first()
+            .or(b.first())
+            .map_or(end, |instance| instance.event_id.id);
         // Iterate the oldest first, then the newer events
         let chain = a.iter_mut().chain(b.iter_mut());
 
@@ -75,9 +78,28 @@ impl<'a, E: BufferedEvent> EventMutIteratorWithId<'a, E> {
             mutator,
             chain,
             unread: unread_count,
+            end,
+            reads,
         }
     }
 
+    /// Returns where the cursor is once the event `id` is read: right after it, or past all the
+    /// events written so far if it was the last unread one.
+    fn cursor_after(&self, id: EventId<E>) -> usize {
+        if self.unread == 0 {
+            self.end
+        } else {
+            id.id + 1
+        }
+    }
+
+    /// Moves the cursor to the event count `cursor` past `read` events, recording how far the
+    /// events were read.
+    fn move_cursor(&mut self, cursor: usize, read: usize) {
+        self.mutator.last_event_count = cursor;
+        self.reads.record(cursor, read);
+    }
+
     /// Iterate over only the events.
     pub fn without_id(self) -> EventMutIterator<'a, E> {
         EventMutIterator { iter: self }
@@ -95,8 +117,8 @@ impl<'a, E: BufferedEvent> Iterator for EventMutIteratorWithId<'a, E> {
             Some(item) => {
                 #[cfg(feature = "detailed_trace")]
                 tracing::trace!("EventMutator::iter() -> {}", item.1);
-                self.mutator.last_event_count += 1;
                 self.unread -= 1;
+                self.move_cursor(self.cursor_after(item.1), 1);
                 Some(item)
             }
             None => None,
@@ -107,27 +129,27 @@ impl<'a, E: BufferedEvent> Iterator for EventMutIteratorWithId<'a, E> {
         self.chain.size_hint()
     }
 
-    fn count(self) -> usize {
-        self.mutator.last_event_count += self.unread;
+    fn count(mut self) -> usize {
+        self.move_cursor(self.end, self.unread);
         self.unread
     }
 
-    fn last(self) -> Option<Self::Item>
+    fn last(mut self) -> Option<Self::Item>
     where
         Self: Sized,
     {
-        let EventInstance { event_id, event } = self.chain.last()?;
-        self.mutator.last_event_count += self.unread;
+        let EventInstance { event_id, event } = self.chain.by_ref().last()?;
+        self.move_cursor(self.end, self.unread);
         Some((event, *event_id))
     }
 
     fn nth(&mut self, n: usize) -> Option<Self::Item> {
         if let Some(EventInstance { event_id, event }) = self.chain.nth(n) {
-            self.mutator.last_event_count += n + 1;
             self.unread -= n + 1;
+            self.move_cursor(self.cursor_after(*event_id), n + 1);
             Some((event, *event_id))
         } else {
-            self.mutator.last_event_count += self.unread;
+            self.move_cursor(self.end, self.unread);
             self.unread = 0;
             None
         }
@@ -149,23 +171,23 @@ pub struct EventMutParIter<'a, E: BufferedEvent> {
     batching_strategy: BatchingStrategy,
     #[cfg(not(target_arch = "wasm32"))]
     unread: usize,
+    end: usize,
+    reads: EventReads<'a>,
 }
 
 #[cfg(feature = "multi_threaded")]
 impl<'a, E: BufferedEvent> EventMutParIter<'a, E> {
     /// Creates a new parallel iterator over `events` that have not yet been seen by `mutator`.
     pub fn new(mutator: &'a mut EventCursor<E>, events: &'a mut Events<E>) -> Self {
-        let a_index = mutator
-            .last_event_count
-            .saturating_sub(events.events_a.start_event_count);
-        let b_index = mutator
-            .last_event_count
-            .saturating_sub(events.events_b.start_event_count);
-        let a = events.events_a.get_mut(a_index..).unwrap_or_default();
-        let b = events.events_b.get_mut(b_index..).unwrap_or_default();
+        let end = events.event_count;
+        let ([a, b], reads) = events.events_from_mut(mutator.last_event_count);
 
         let unread_count = a.len() + b.len();
-        mutator.last_event_count = events.event_count - unread_count;
+        // Skip the events that were missed.
+        mutator.last_event_count = a
+            .first()
+            .or(b.first())
+            .map_or(end, |instance| instance.event_id.id);
 
         Self {
             mutator,
@@ -173,6 +195,8 @@ impl<'a, E: BufferedEvent> EventMutParIter<'a, E> {
             batching_strategy: BatchingStrategy::default(),
             #[cfg(not(target_arch = "wasm32"))]
             unread: unread_count,
+            end,
+            reads,
         }
     }
 
@@ -189,6 +213,9 @@ impl<'a, E: BufferedEvent> EventMutParIter<'a, E> {
     ///
     /// Unlike normal iteration, the event order is not guaranteed in any form.
     ///
+    /// The events are read sequentially on the current thread when they all fit in a single
+    /// batch, like with [`EventParIter::for_each`](super::EventParIter::for_each).
+    ///
     /// # Panics
     /// If the [`ComputeTaskPool`] is not initialized. If using this from an event reader that is being
     /// initialized and run from the ECS scheduler, this should never panic.
@@ -232,6 +259,9 @@ impl<'a, E: BufferedEvent> EventMutParIter<'a, E> {
             let batch_size = self
                 .batching_strategy
                 .calc_batch_size(|| self.len(), thread_count);
+            if self.len() <= batch_size {
+                return self.into_iter().for_each(|(e, i)| func(e, i));
+            }
             let chunks = self.slices.map(|s| s.chunks_mut(batch_size));
 
             pool.scope(|scope| {
@@ -246,7 +276,8 @@ impl<'a, E: BufferedEvent> EventMutParIter<'a, E> {
             });
 
             // Events are guaranteed to be read at this point.
-            self.mutator.last_event_count += self.unread;
+            self.mutator.last_event_count = self.end;
+            self.reads.record(self.end, self.unread);
             self.unread = 0;
         }
     }
@@ -271,6 +302,8 @@ impl<'a, E: BufferedEvent> IntoIterator for EventMutParIter<'a, E> {
         let EventMutParIter {
             mutator: reader,
             slices: [a, b],
+            end,
+            reads,
             ..
         } = self;
         let unread = a.len() + b.len();
@@ -279,6 +312,8 @@ impl<'a, E: BufferedEvent> IntoIterator for EventMutParIter<'a, E> {
             mutator: reader,
             chain,
             unread,
+            end,
+            reads,
         }
     }
 }
@@ -1,7 +1,9 @@
 #[cfg(feature = "multi_threaded")]
 use bevy_ecs::event::EventParIter;
 use bevy_ecs::{
-    event::{BufferedEvent, EventCursor, EventIterator, EventIteratorWithId, Events},
+    event::{
+        BufferedEvent, EventCursor, EventIterator, EventIteratorWithId, Events, MergedEventIterator,
+    },
     system::{Local, Res, SystemParam},
 };
 
@@ -33,6 +35,50 @@ impl<'w, 's, E: BufferedEvent> EventReader<'w, 's, E> {
         self.reader.read_with_id(&self.events)
     }
 
+    /// Iterates over the events like [`read`](Self::read), without counting them as read for
+    /// [`Events::clear_consumed`].
+    pub(crate) fn peek(&mut self) -> EventIterator<'_, E> {
+        self.reader.peek(&self.events)
+    }
+
+    /// Iterates over the events this [`EventReader`] and `other` have not seen yet, in the order
+    /// they were written, across both types.
+    ///
+    /// The order comes from the [sequence numbers](Events::get_sequence) of the events, so
+    /// both event types should be stamped with the same counter, for example with
+    /// [`World::stamp_event_sequence`](crate::world::World::stamp_event_sequence). Otherwise,
+    /// the events of each type are still yielded in order, but the events that aren't stamped
+    /// come last. See [`MergedEventIterator`].
+    ///
+    /// ```
+    /// # use bevy_ecs::{event::MergedEvent, prelude::*};
+    /// #[derive(BufferedEvent)]
+    /// struct Damage(u32);
+    ///
+    /// #[derive(BufferedEvent)]
+    /// struct Death;
+    ///
+    /// fn combat_log(mut damage: EventReader<Damage>, mut deaths: EventReader<Death>) {
+    ///     for event in damage.read_merged(&mut deaths) {
+    ///         match event {
+    ///             MergedEvent::First(Damage(amount), _) => println!("took {amount} damage"),
+    ///             MergedEvent::Second(Death, _) => println!("died"),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut world = World::new();
+    /// world.stamp_event_sequence::<Damage>();
+    /// world.stamp_event_sequence::<Death>();
+    /// # bevy_ecs::system::assert_is_system(combat_log);
+    /// ```
+    pub fn read_merged<'a, F: BufferedEvent>(
+        &'a mut self,
+        other: &'a mut EventReader<'_, '_, F>,
+    ) -> MergedEventIterator<'a, E, F> {
+        MergedEventIterator::new(self.read_with_id(), other.read_with_id())
+    }
+
     /// Returns a parallel iterator over the events this [`EventReader`] has not seen yet.
     /// See also [`for_each`](EventParIter::for_each).
     ///
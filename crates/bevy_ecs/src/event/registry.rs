@@ -1,20 +1,34 @@
 use alloc::vec::Vec;
 use bevy_ecs::{
     change_detection::{DetectChangesMut, MutUntyped},
-    component::Tick,
-    event::{BufferedEvent, EventKey, Events},
+    component::{ComponentId, Tick},
+    event::{BufferedEvent, EventCounts, EventKey, Events},
     resource::Resource,
     world::World,
 };
+use bevy_ptr::Ptr;
 
 #[doc(hidden)]
 struct RegisteredEvent {
     event_key: EventKey,
+    type_name: &'static str,
     // Required to flush the secondary buffer and drop events even if left unchanged.
     previously_updated: bool,
+    // Whether the last update wrote events sent from other threads, which the next update must
+    // handle like a change.
+    received_sent: bool,
     // SAFETY: The `EventKey`'s component ID and the function must be used to fetch the Events<T> resource
     // of the same type initialized in `register_event`, or improper type casts will occur.
-    update: unsafe fn(MutUntyped),
+    // Returns whether events sent from other threads were written after the update.
+    update: unsafe fn(MutUntyped) -> bool,
+    // SAFETY: Same as `update`.
+    clear: unsafe fn(MutUntyped),
+    // SAFETY: Same as `update`.
+    counts: unsafe fn(Ptr) -> EventCounts,
+    // SAFETY: Same as `update`.
+    count_reads: unsafe fn(MutUntyped),
+    // SAFETY: Same as `update`.
+    has_sent: unsafe fn(Ptr) -> bool,
 }
 
 /// A registry of all of the [`Events`] in the [`World`], used by [`event_update_system`](crate::event::update::event_update_system)
@@ -52,16 +66,54 @@ impl EventRegistry {
         let mut registry = world.get_resource_or_init::<Self>();
         registry.event_updates.push(RegisteredEvent {
             event_key: EventKey(component_id),
+            type_name: core::any::type_name::<T>(),
             previously_updated: false,
+            received_sent: false,
             update: |ptr| {
+                // SAFETY: The resource was initialized with the type Events<T>.
+                let mut events = unsafe { ptr.with_type::<Events<T>>() };
+                let events = events.bypass_change_detection();
+                events.update();
+                events.receive_sent() > 0
+            },
+            clear: |ptr| {
                 // SAFETY: The resource was initialized with the type Events<T>.
                 unsafe { ptr.with_type::<Events<T>>() }
                     .bypass_change_detection()
-                    .update();
+                    .clear();
+            },
+            counts: |ptr| {
+                // SAFETY: The resource was initialized with the type Events<T>.
+                unsafe { ptr.deref::<Events<T>>() }.counts()
+            },
+            count_reads: |ptr| {
+                // SAFETY: The resource was initialized with the type Events<T>.
+                unsafe { ptr.with_type::<Events<T>>() }
+                    .bypass_change_detection()
+                    .count_reads();
+            },
+            has_sent: |ptr| {
+                // SAFETY: The resource was initialized with the type Events<T>.
+                unsafe { ptr.deref::<Events<T>>() }.has_sent()
             },
         });
     }
 
+    /// Returns the type name and the [`EventCounts`] of each registered event type, such as
+    /// `my_game::Damage` for `Events<my_game::Damage>`.
+    pub fn iter_counts<'w>(
+        &'w self,
+        world: &'w World,
+    ) -> impl Iterator<Item = (&'static str, EventCounts)> + 'w {
+        self.event_updates.iter().filter_map(|registered_event| {
+            let events = world.get_resource_by_id(registered_event.event_key.component_id())?;
+            // SAFETY: The counts function pointer is called with the resource fetched from the
+            // same component ID.
+            let counts = unsafe { (registered_event.counts)(events) };
+            Some((registered_event.type_name, counts))
+        })
+    }
+
     /// Updates all of the registered events in the World.
     pub fn run_updates(&mut self, world: &mut World, last_change_tick: Tick) {
         for registered_event in &mut self.event_updates {
@@ -69,11 +121,15 @@ impl EventRegistry {
             if let Some(events) =
                 world.get_resource_mut_by_id(registered_event.event_key.component_id())
             {
-                let has_changed = events.has_changed_since(last_change_tick);
+                let has_changed = events.has_changed_since(last_change_tick)
+                    || registered_event.received_sent
+                    // SAFETY: The has_sent function pointer is called with the resource fetched
+                    // from the same component ID.
+                    || unsafe { (registered_event.has_sent)(events.as_ref()) };
                 if registered_event.previously_updated || has_changed {
                     // SAFETY: The update function pointer is called with the resource
                     // fetched from the same component ID.
-                    unsafe { (registered_event.update)(events) };
+                    registered_event.received_sent = unsafe { (registered_event.update)(events) };
                     // Always set to true if the events have changed, otherwise disable running on the second invocation
                     // to wait for more changes.
                     registered_event.previously_updated =
@@ -83,6 +139,35 @@ impl EventRegistry {
         }
     }
 
+    /// Removes the events of all of the registered events in the World.
+    pub fn clear_all(&mut self, world: &mut World) {
+        for registered_event in &mut self.event_updates {
+            if let Some(events) =
+                world.get_resource_mut_by_id(registered_event.event_key.component_id())
+            {
+                // SAFETY: The clear function pointer is called with the resource
+                // fetched from the same component ID.
+                unsafe { (registered_event.clear)(events) };
+                registered_event.previously_updated = false;
+                registered_event.received_sent = false;
+            }
+        }
+    }
+
+    /// Starts counting the events read of all of the registered events in the World, see
+    /// [`Events::count_reads`].
+    pub fn count_all_reads(&self, world: &mut World) {
+        for registered_event in &self.event_updates {
+            if let Some(events) =
+                world.get_resource_mut_by_id(registered_event.event_key.component_id())
+            {
+                // SAFETY: The count_reads function pointer is called with the resource
+                // fetched from the same component ID.
+                unsafe { (registered_event.count_reads)(events) };
+            }
+        }
+    }
+
     /// Removes an event from the world and its associated [`EventRegistry`].
     pub fn deregister_events<T: BufferedEvent>(world: &mut World) {
         let component_id = world.init_resource::<Events<T>>();
@@ -92,4 +177,23 @@ impl EventRegistry {
             .retain(|e| e.event_key.component_id() != component_id);
         world.remove_resource::<Events<T>>();
     }
+
+    /// Removes the [`Events`] resource with the given [`ComponentId`] from the world and its
+    /// associated [`EventRegistry`].
+    ///
+    /// Returns `false`, without removing anything, if no event is registered with this id.
+    pub fn deregister_events_by_id(world: &mut World, component_id: ComponentId) -> bool {
+        let Some(mut registry) = world.get_resource_mut::<Self>() else {
+            return false;
+        };
+        let len = registry.event_updates.len();
+        registry
+            .event_updates
+            .retain(|e| e.event_key.component_id() != component_id);
+        if registry.event_updates.len() == len {
+            return false;
+        }
+        world.remove_resource_by_id(component_id);
+        true
+    }
 }
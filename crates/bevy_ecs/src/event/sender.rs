@@ -0,0 +1,295 @@
+use crate::{
+    change_detection::DetectChangesMut,
+    event::{BufferedEvent, Events},
+    world::World,
+};
+use bevy_platform::sync::Arc;
+use concurrent_queue::{ConcurrentQueue, PushError};
+use core::fmt;
+
+/// A handle to write [`BufferedEvent`]s of type `E` from any thread, for example from an audio
+/// callback, a network thread or a file watcher.
+///
+/// The events are pushed to a lock-free queue owned by the [`Events<E>`] resource, and written
+/// to it during the next event update, as if they were written right after it. In an `App`, this
+/// happens at the start of each frame, in `First`, so the systems of the frame can read them.
+/// Outside of an `App`, [`Events::receive_sent`] writes them.
+///
+/// Events sent through the same handle, or its clones, are written in the order they were sent.
+/// There is no ordering guarantee between handles obtained separately.
+///
+/// Once the [`Events<E>`] are dropped, usually along with the world, sending an event returns it
+/// in an [`EventSenderClosedError`].
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// #[derive(BufferedEvent)]
+/// struct PacketReceived(Vec<u8>);
+///
+/// let mut world = World::new();
+/// world.init_resource::<Events<PacketReceived>>();
+/// let sender = world.event_sender::<PacketReceived>();
+/// std::thread::spawn(move || {
+///     for packet in [vec![1, 2, 3], vec![4, 5]] {
+///         // Sending only fails once the world is dropped.
+///         if sender.send(PacketReceived(packet)).is_err() {
+///             break;
+///         }
+///     }
+/// })
+/// .join()
+/// .unwrap();
+///
+/// let mut events = world.resource_mut::<Events<PacketReceived>>();
+/// events.receive_sent();
+/// assert_eq!(events.len(), 2);
+/// ```
+pub struct EventSender<E: BufferedEvent> {
+    queue: Arc<ConcurrentQueue<E>>,
+}
+
+impl<E: BufferedEvent> EventSender<E> {
+    /// Sends `event`, to be written during the next event update.
+    ///
+    /// Returns the event in an error if the [`Events<E>`] were dropped, so that the thread
+    /// sending the events can stop.
+    pub fn send(&self, event: E) -> Result<(), EventSenderClosedError<E>> {
+        self.queue.push(event).map_err(|error| match error {
+            PushError::Full(event) | PushError::Closed(event) => EventSenderClosedError(event),
+        })
+    }
+
+    /// Returns `true` if the [`Events<E>`] were dropped, so events can't be sent anymore.
+    pub fn is_closed(&self) -> bool {
+        self.queue.is_closed()
+    }
+}
+
+impl<E: BufferedEvent> Clone for EventSender<E> {
+    fn clone(&self) -> Self {
+        Self {
+            queue: self.queue.clone(),
+        }
+    }
+}
+
+impl<E: BufferedEvent> fmt::Debug for EventSender<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EventSender")
+            .field("len", &self.queue.len())
+            .field("closed", &self.queue.is_closed())
+            .finish()
+    }
+}
+
+/// The error returned by [`EventSender::send`] when the [`Events`] it sends events to were
+/// dropped, holding the event that couldn't be sent.
+#[derive(thiserror::Error, PartialEq, Eq)]
+#[error("The events receiving the sent event were dropped")]
+pub struct EventSenderClosedError<E>(pub E);
+
+impl<E> fmt::Debug for EventSenderClosedError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EventSenderClosedError")
+            .finish_non_exhaustive()
+    }
+}
+
+/// The queue of the events sent through the [`EventSender`]s of an [`Events`].
+///
+/// Dropping it, along with the [`Events`], closes the queue: the events that weren't written are
+/// dropped, and sending new ones fails.
+pub(crate) struct SentEvents<E: BufferedEvent> {
+    queue: Arc<ConcurrentQueue<E>>,
+    /// The maximum number of events written per call to [`Events::receive_sent`].
+    pub(crate) max_per_receive: usize,
+}
+
+impl<E: BufferedEvent> SentEvents<E> {
+    pub(crate) fn sender(&self) -> EventSender<E> {
+        EventSender {
+            queue: self.queue.clone(),
+        }
+    }
+
+    /// Returns `true` if no event is waiting to be written.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+
+    /// Removes the events waiting to be written, up to the limit. The events over the limit and
+    /// the ones sent meanwhile, for example by another thread, are left for the next call.
+    pub(crate) fn take(&self) -> impl Iterator<Item = E> + '_ {
+        self.queue
+            .try_iter()
+            .take(self.queue.len().min(self.max_per_receive))
+    }
+}
+
+impl<E: BufferedEvent> Default for SentEvents<E> {
+    fn default() -> Self {
+        Self {
+            queue: Arc::new(ConcurrentQueue::unbounded()),
+            max_per_receive: usize::MAX,
+        }
+    }
+}
+
+impl<E: BufferedEvent> Drop for SentEvents<E> {
+    fn drop(&mut self) {
+        self.queue.close();
+    }
+}
+
+impl<E: BufferedEvent> fmt::Debug for SentEvents<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SentEvents")
+            .field("len", &self.queue.len())
+            .field("max_per_receive", &self.max_per_receive)
+            .finish()
+    }
+}
+
+impl World {
+    /// Returns a handle to write events of type `E` into this world from any thread, see
+    /// [`EventSender`].
+    ///
+    /// This initializes the [`Events<E>`] resource if needed. The events are only written
+    /// during the event updates if they are registered, for example with `App::add_event`.
+    pub fn event_sender<E: BufferedEvent>(&mut self) -> EventSender<E> {
+        self.get_resource_or_init::<Events<E>>()
+            .bypass_change_detection()
+            .sender()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+    use std::thread;
+
+    use super::EventSenderClosedError;
+    use crate::{
+        event::{event_update_system, BufferedEvent, EventReader, EventRegistry, Events},
+        resource::Resource,
+        schedule::{IntoScheduleConfigs, Schedule},
+        system::ResMut,
+        world::World,
+    };
+
+    #[derive(BufferedEvent, Clone, Copy, Debug, PartialEq, Eq)]
+    struct Packet {
+        thread: usize,
+        seq: usize,
+    }
+
+    #[derive(Resource, Default)]
+    struct Received(Vec<Packet>);
+
+    fn receive(mut packets: EventReader<Packet>, mut received: ResMut<Received>) {
+        received.0.extend(packets.read().copied());
+    }
+
+    fn world_with_packets() -> (World, Schedule) {
+        let mut world = World::new();
+        EventRegistry::register_event::<Packet>(&mut world);
+        world.init_resource::<Received>();
+        let mut schedule = Schedule::default();
+        schedule.add_systems((event_update_system, receive).chain());
+        (world, schedule)
+    }
+
+    #[test]
+    fn events_sent_from_many_threads_are_written_once_in_order() {
+        const THREADS: usize = 8;
+        const PACKETS: usize = 2_000;
+
+        let (mut world, mut schedule) = world_with_packets();
+        let sender = world.event_sender::<Packet>();
+        let threads = (0..THREADS)
+            .map(|thread| {
+                let sender = sender.clone();
+                thread::spawn(move || {
+                    for seq in 0..PACKETS {
+                        sender.send(Packet { thread, seq }).unwrap();
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+
+        // The events are written while the threads are still sending them.
+        while threads.iter().any(|thread| !thread.is_finished()) {
+            schedule.run(&mut world);
+        }
+        threads
+            .into_iter()
+            .for_each(|thread| thread.join().unwrap());
+        schedule.run(&mut world);
+
+        let received = &world.resource::<Received>().0;
+        assert_eq!(received.len(), THREADS * PACKETS);
+        for thread in 0..THREADS {
+            let seqs = received
+                .iter()
+                .filter(|packet| packet.thread == thread)
+                .map(|packet| packet.seq);
+            assert!(seqs.eq(0..PACKETS));
+        }
+    }
+
+    #[test]
+    fn sent_events_are_written_after_the_update() {
+        let (mut world, mut schedule) = world_with_packets();
+        let sender = world.event_sender::<Packet>();
+        sender.send(Packet { thread: 0, seq: 0 }).unwrap();
+        assert!(world.resource::<Events<Packet>>().is_empty());
+
+        schedule.run(&mut world);
+        let events = world.resource::<Events<Packet>>();
+        assert_eq!(events.iter_current_update_events().count(), 1);
+        assert_eq!(world.resource::<Received>().0.len(), 1);
+
+        // Like the other events, they are removed after two updates.
+        schedule.run(&mut world);
+        schedule.run(&mut world);
+        assert!(world.resource::<Events<Packet>>().is_empty());
+        assert_eq!(world.resource::<Received>().0.len(), 1);
+    }
+
+    #[test]
+    fn events_over_the_limit_are_written_on_the_next_updates() {
+        let (mut world, mut schedule) = world_with_packets();
+        world
+            .resource_mut::<Events<Packet>>()
+            .set_max_received(Some(2));
+        let sender = world.event_sender::<Packet>();
+        for seq in 0..5 {
+            sender.send(Packet { thread: 0, seq }).unwrap();
+        }
+
+        let mut received = Vec::new();
+        for _ in 0..3 {
+            schedule.run(&mut world);
+            received.push(world.resource::<Received>().0.len());
+        }
+        assert_eq!(received, [2, 4, 5]);
+        let seqs = world
+            .resource::<Received>()
+            .0
+            .iter()
+            .map(|packet| packet.seq);
+        assert!(seqs.eq(0..5));
+    }
+
+    #[test]
+    fn sending_after_the_world_is_dropped_fails() {
+        let (mut world, _) = world_with_packets();
+        let sender = world.event_sender::<Packet>();
+        assert!(!sender.is_closed());
+
+        drop(world);
+        assert!(sender.is_closed());
+        let packet = Packet { thread: 0, seq: 1 };
+        assert_eq!(sender.send(packet), Err(EventSenderClosedError(packet)));
+    }
+}
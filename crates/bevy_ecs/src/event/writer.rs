@@ -77,7 +77,8 @@ impl<'w, E: BufferedEvent> EventWriter<'w, E> {
 
     /// Writes a list of `events` all at once, which can later be read by [`EventReader`](super::EventReader)s.
     /// This is more efficient than writing each event individually.
-    /// This method returns the [IDs](`EventId`) of the written `events`.
+    /// This method returns the [IDs](`EventId`) of the written `events`, whose
+    /// [`len`](ExactSizeIterator::len) is the number of written events.
     ///
     /// See [`Events`] for details.
     #[doc(alias = "send_batch")]
@@ -86,6 +87,24 @@ impl<'w, E: BufferedEvent> EventWriter<'w, E> {
         self.events.write_batch(events)
     }
 
+    /// Writes at most `max` of the `events`, leaving the others in the iterator.
+    /// This method returns the [IDs](`EventId`) of the written `events`, and a hint of the
+    /// number of remaining ones.
+    ///
+    /// The hint is the lower bound of the [`size_hint`](Iterator::size_hint) of `events`, which
+    /// is 0 for the iterators that can't tell how many items they have left, such as the
+    /// `try_iter` of a `std::sync::mpsc` channel. Ask the source of the events for the backlog
+    /// in that case.
+    ///
+    /// See [`Events::write_batch_capped`] for details.
+    #[track_caller]
+    pub fn write_batch_capped<I>(&mut self, events: I, max: usize) -> (WriteBatchIds<E>, usize)
+    where
+        I: IntoIterator<Item = E>,
+    {
+        self.events.write_batch_capped(events, max)
+    }
+
     /// Writes the default value of the event. Useful when the event is an empty struct.
     /// This method returns the [ID](`EventId`) of the written `event`.
     ///
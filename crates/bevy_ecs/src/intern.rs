@@ -4,13 +4,17 @@
 //! speed up code by shrinking the stack size of large types,
 //! and make comparisons for any type as fast as integers.
 
-use alloc::{borrow::ToOwned, boxed::Box};
+use alloc::{borrow::ToOwned, boxed::Box, vec::Vec};
 use bevy_platform::{
-    collections::HashSet,
-    hash::FixedHasher,
+    collections::{HashMap, HashSet},
+    hash::{FixedHasher, NoOpHash},
     sync::{PoisonError, RwLock},
 };
-use core::{fmt::Debug, hash::Hash, ops::Deref};
+use core::{
+    fmt::{self, Debug},
+    hash::{BuildHasher, Hash},
+    ops::Deref,
+};
 
 /// An interned value. Will stay valid until the end of the program and will not drop.
 ///
@@ -75,7 +79,7 @@ impl<T: ?Sized + Internable> Hash for Interned<T> {
 }
 
 impl<T: ?Sized + Debug> Debug for Interned<T> {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.0.fmt(f)
     }
 }
@@ -167,13 +171,165 @@ impl<T: ?Sized> Default for Interner<T> {
     }
 }
 
+/// A name interned by a [`NameInterner`], identified by a `u32`.
+///
+/// Unlike [`Interned<str>`], an [`InternedName`] is only 4 bytes, so it is cheap to hash and
+/// compare in maps that are looked up often, and the name can be retrieved with
+/// [`NameInterner::resolve`] on the interner that interned it.
+///
+/// Two names are only guaranteed to compare equal if they were interned using the same
+/// [`NameInterner`] instance.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct InternedName(u32);
+
+impl InternedName {
+    /// Interns `name` with the [global](NameInterner::global) interner.
+    pub fn new(name: &str) -> Self {
+        NameInterner::global().intern(name)
+    }
+
+    /// Returns the id of the name in its [`NameInterner`].
+    pub fn id(self) -> u32 {
+        self.0
+    }
+}
+
+/// A thread-safe interner which creates an [`InternedName`] from a `&str`, and can retrieve the
+/// name from it.
+///
+/// Most code should use the [global](Self::global) interner, through [`InternedName::new`].
+///
+/// Interned names are leaked, so only names from a bounded set, like type names, log targets or
+/// plugin names, should be interned.
+pub struct NameInterner<S = FixedHasher> {
+    hasher: S,
+    table: RwLock<NameTable>,
+}
+
+struct NameTable {
+    /// The names, indexed by their id.
+    names: Vec<&'static str>,
+    /// The first name interned for each hash. The names are hashed once, by
+    /// [`NameInterner::intern`], so this map doesn't hash them again.
+    ids: HashMap<u64, InternedName, NoOpHash>,
+    /// The names whose hash is already used by another name.
+    collisions: Vec<(u64, InternedName)>,
+}
+
+impl NameTable {
+    fn find(&self, hash: u64, name: &str) -> Option<InternedName> {
+        let id = *self.ids.get(&hash)?;
+        if self.names[id.0 as usize] == name {
+            return Some(id);
+        }
+        self.collisions
+            .iter()
+            .find(|&&(other_hash, id)| other_hash == hash && self.names[id.0 as usize] == name)
+            .map(|&(_, id)| id)
+    }
+}
+
+static GLOBAL_NAME_INTERNER: NameInterner = NameInterner::new();
+
+impl NameInterner {
+    /// Creates a new empty interner.
+    pub const fn new() -> Self {
+        Self::with_hasher(FixedHasher)
+    }
+
+    /// Returns the global interner, used by [`InternedName::new`].
+    pub fn global() -> &'static NameInterner {
+        &GLOBAL_NAME_INTERNER
+    }
+}
+
+impl<S> NameInterner<S> {
+    /// Creates a new empty interner which hashes the names with `hasher`.
+    pub const fn with_hasher(hasher: S) -> Self {
+        Self {
+            hasher,
+            table: RwLock::new(NameTable {
+                names: Vec::new(),
+                ids: HashMap::with_hasher(NoOpHash),
+                collisions: Vec::new(),
+            }),
+        }
+    }
+
+    /// Returns the name `name` was interned from, if it was interned by this interner.
+    pub fn resolve(&self, name: InternedName) -> Option<&'static str> {
+        let table = self.table.read().unwrap_or_else(PoisonError::into_inner);
+        table.names.get(name.0 as usize).copied()
+    }
+
+    /// Returns the number of names interned by this interner.
+    pub fn len(&self) -> usize {
+        let table = self.table.read().unwrap_or_else(PoisonError::into_inner);
+        table.names.len()
+    }
+
+    /// Returns `true` if no name was interned by this interner.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<S: BuildHasher> NameInterner<S> {
+    /// Returns the [`InternedName`] corresponding to `name`.
+    ///
+    /// The first time a name is interned, it is leaked and given a new id. Subsequent calls for
+    /// the same name return the same [`InternedName`]. Either way, `name` is hashed once.
+    pub fn intern(&self, name: &str) -> InternedName {
+        let hash = self.hasher.hash_one(name);
+        {
+            let table = self.table.read().unwrap_or_else(PoisonError::into_inner);
+            if let Some(id) = table.find(hash, name) {
+                return id;
+            }
+        }
+
+        let mut table = self.table.write().unwrap_or_else(PoisonError::into_inner);
+        if let Some(id) = table.find(hash, name) {
+            return id;
+        }
+        let id =
+            InternedName(u32::try_from(table.names.len()).expect("too many names were interned"));
+        table.names.push(name.leak());
+        if table.ids.contains_key(&hash) {
+            table.collisions.push((hash, id));
+        } else {
+            table.ids.insert(hash, id);
+        }
+        id
+    }
+
+    /// Returns the [`InternedName`] corresponding to `name`, if it was already interned.
+    pub fn get(&self, name: &str) -> Option<InternedName> {
+        let hash = self.hasher.hash_one(name);
+        let table = self.table.read().unwrap_or_else(PoisonError::into_inner);
+        table.find(hash, name)
+    }
+}
+
+impl Default for NameInterner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use alloc::{boxed::Box, string::ToString};
-    use bevy_platform::hash::FixedHasher;
+    use bevy_platform::{
+        hash::FixedHasher,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+    };
     use core::hash::{BuildHasher, Hash, Hasher};
 
-    use crate::intern::{Internable, Interned, Interner};
+    use crate::intern::{Internable, Interned, InternedName, Interner, NameInterner};
 
     #[test]
     fn zero_sized_type() {
@@ -277,4 +433,95 @@ mod tests {
 
         assert_ne!(a, b);
     }
+
+    #[test]
+    fn name_interning_is_stable() {
+        let interner = NameInterner::new();
+        assert!(interner.is_empty());
+        let a = interner.intern("bevy_render");
+        let b = interner.intern("bevy_pbr");
+        assert_ne!(a, b);
+        let owned = "bevy_render".to_string();
+        assert_eq!(interner.intern(&owned), a);
+        assert_eq!(interner.get("bevy_pbr"), Some(b));
+        assert_eq!(interner.get("bevy_ui"), None);
+        assert_eq!(interner.len(), 2);
+
+        // Ids are per interner.
+        let other = NameInterner::new();
+        assert_eq!(other.intern("bevy_pbr").id(), a.id());
+    }
+
+    #[test]
+    fn name_reverse_lookup() {
+        let interner = NameInterner::new();
+        let name = interner.intern("bevy_render::RenderPlugin");
+        assert_eq!(interner.resolve(name), Some("bevy_render::RenderPlugin"));
+        assert_eq!(NameInterner::new().resolve(name), None);
+
+        let name = InternedName::new("bevy_ecs::intern::tests::name_reverse_lookup");
+        assert_eq!(
+            NameInterner::global().resolve(name),
+            Some("bevy_ecs::intern::tests::name_reverse_lookup")
+        );
+    }
+
+    /// Counts the names hashed by a [`NameInterner`].
+    #[derive(Default, Clone)]
+    struct CountingHasher(Arc<AtomicUsize>);
+
+    impl BuildHasher for CountingHasher {
+        type Hasher = <FixedHasher as BuildHasher>::Hasher;
+
+        fn build_hasher(&self) -> Self::Hasher {
+            self.0.fetch_add(1, Ordering::Relaxed);
+            FixedHasher.build_hasher()
+        }
+    }
+
+    #[test]
+    fn names_are_hashed_once() {
+        let hashes = CountingHasher::default();
+        let interner = NameInterner::with_hasher(hashes.clone());
+
+        // Interning hashes the name once, whether it is new or not.
+        let name = interner.intern("bevy_render");
+        assert_eq!(hashes.0.load(Ordering::Relaxed), 1);
+        assert_eq!(interner.intern("bevy_render"), name);
+        assert_eq!(hashes.0.load(Ordering::Relaxed), 2);
+
+        // So does looking a name up, and resolving it doesn't hash it.
+        assert_eq!(interner.get("bevy_render"), Some(name));
+        assert_eq!(interner.resolve(name), Some("bevy_render"));
+        assert_eq!(hashes.0.load(Ordering::Relaxed), 3);
+    }
+
+    #[test]
+    fn name_hash_collisions() {
+        /// Hashes every name to the same value.
+        struct Colliding;
+
+        impl BuildHasher for Colliding {
+            type Hasher = Colliding;
+
+            fn build_hasher(&self) -> Self::Hasher {
+                Colliding
+            }
+        }
+
+        impl Hasher for Colliding {
+            fn finish(&self) -> u64 {
+                0
+            }
+
+            fn write(&mut self, _: &[u8]) {}
+        }
+
+        let interner = NameInterner::with_hasher(Colliding);
+        let names = ["a", "b", "c"].map(|name| interner.intern(name));
+        assert_eq!(names.map(InternedName::id), [0, 1, 2]);
+        assert_eq!(interner.intern("b"), names[1]);
+        assert_eq!(interner.get("c"), Some(names[2]));
+        assert_eq!(interner.get("d"), None);
+    }
 }
@@ -134,6 +134,11 @@ pub mod __macro_exports {
     // included `extern crate alloc;`. This re-export ensures we have access
     // to `Vec` in `no_std` and `std` contexts.
     pub use alloc::vec::Vec;
+
+    #[cfg(feature = "auto_register_events")]
+    pub use crate::event::DerivedEventRegistration;
+    #[cfg(feature = "auto_register_events")]
+    pub use inventory;
 }
 
 /// Event sent when a hotpatch happens.
@@ -9,6 +9,7 @@
 //!     - These are split by target type, in order to allow for different lookup strategies.
 //!     - [`CachedComponentObservers`] is one of these maps, which contains observers that are specifically targeted at a component.
 
+use alloc::vec::Vec;
 use bevy_platform::collections::HashMap;
 
 use crate::{
@@ -16,7 +17,7 @@ use crate::{
     change_detection::MaybeLocation,
     component::ComponentId,
     entity::EntityHashMap,
-    observer::{ObserverRunner, ObserverTrigger},
+    observer::{ObserverOrdering, ObserverRunner, ObserverTrigger},
     prelude::*,
     world::DeferredWorld,
 };
@@ -112,37 +113,24 @@ impl Observers {
                 propagate,
             );
         };
-        // Trigger observers listening for any kind of this trigger
-        observers
-            .global_observers
-            .iter()
-            .for_each(&mut trigger_observer);
 
-        // Trigger entity observers listening for this kind of trigger
-        if let Some(target_entity) = current_target {
-            if let Some(map) = observers.entity_observers.get(&target_entity) {
-                map.iter().for_each(&mut trigger_observer);
+        if observers.ordering.is_ordered() {
+            let mut ordered = Vec::new();
+            observers.for_each_matching(
+                current_target,
+                trigger_for_components,
+                |(&observer, &runner)| {
+                    ordered.push((observers.ordering.rank(observer), observer, runner));
+                },
+            );
+            // Stable, so an observer matching several times still runs that many times in a row.
+            ordered.sort_by_key(|(rank, ..)| *rank);
+            for (_, observer, runner) in &ordered {
+                trigger_observer((observer, runner));
             }
+        } else {
+            observers.for_each_matching(current_target, trigger_for_components, trigger_observer);
         }
-
-        // Trigger observers listening to this trigger targeting a specific component
-        trigger_for_components.for_each(|id| {
-            if let Some(component_observers) = observers.component_observers.get(&id) {
-                component_observers
-                    .global_observers
-                    .iter()
-                    .for_each(&mut trigger_observer);
-
-                if let Some(target_entity) = current_target {
-                    if let Some(map) = component_observers
-                        .entity_component_observers
-                        .get(&target_entity)
-                    {
-                        map.iter().for_each(&mut trigger_observer);
-                    }
-                }
-            }
-        });
     }
 
     pub(crate) fn is_archetype_cached(event_key: EventKey) -> Option<ArchetypeFlags> {
@@ -197,9 +185,45 @@ pub struct CachedObservers {
     pub(super) component_observers: HashMap<ComponentId, CachedComponentObservers>,
     // Observers listening for this trigger fired at a specific entity
     pub(super) entity_observers: EntityHashMap<ObserverMap>,
+    // The order in which all of the above observers run
+    pub(super) ordering: ObserverOrdering,
 }
 
 impl CachedObservers {
+    /// Calls `f` on every observer matching a trigger targeting `current_target` and `components`.
+    fn for_each_matching(
+        &self,
+        current_target: Option<Entity>,
+        components: impl Iterator<Item = ComponentId>,
+        mut f: impl FnMut((&Entity, &ObserverRunner)),
+    ) {
+        // Observers listening for any kind of this trigger
+        self.global_observers.iter().for_each(&mut f);
+
+        // Entity observers listening for this kind of trigger
+        if let Some(target_entity) = current_target {
+            if let Some(map) = self.entity_observers.get(&target_entity) {
+                map.iter().for_each(&mut f);
+            }
+        }
+
+        // Observers listening to this trigger targeting a specific component
+        components.for_each(|id| {
+            if let Some(component_observers) = self.component_observers.get(&id) {
+                component_observers.global_observers.iter().for_each(&mut f);
+
+                if let Some(target_entity) = current_target {
+                    if let Some(map) = component_observers
+                        .entity_component_observers
+                        .get(&target_entity)
+                    {
+                        map.iter().for_each(&mut f);
+                    }
+                }
+            }
+        });
+    }
+
     /// Returns the observers listening for this trigger, regardless of target.
     /// These observers will also respond to events targeting specific components or entities.
     pub fn global_observers(&self) -> &ObserverMap {
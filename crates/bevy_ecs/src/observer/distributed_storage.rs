@@ -19,11 +19,12 @@ use crate::{
     entity_disabling::Internal,
     error::{ErrorContext, ErrorHandler},
     lifecycle::{ComponentHook, HookContext},
-    observer::{observer_system_runner, ObserverRunner},
+    observer::{observer_system_runner, ObserverOrder, ObserverRunner},
     prelude::*,
     system::{IntoObserverSystem, ObserverSystem},
     world::DeferredWorld,
 };
+use alloc::borrow::Cow;
 use alloc::boxed::Box;
 use alloc::vec::Vec;
 use bevy_utils::prelude::DebugName;
@@ -314,6 +315,66 @@ impl Observer {
         self
     }
 
+    /// Sets the priority of this observer: among the observers of the same event, the ones with
+    /// a higher priority run first. The default priority is `0`.
+    ///
+    /// See [`ObserverOrder`] for how observers are ordered.
+    /// Note that if this is called _after_ an [`Observer`] is spawned, it will produce no effects.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # let mut world = World::default();
+    /// # #[derive(Event)]
+    /// # struct Purchase;
+    /// world.spawn(Observer::new(|_: On<Purchase>| println!("second")));
+    /// world.spawn(Observer::new(|_: On<Purchase>| println!("first")).with_priority(10));
+    /// world.trigger(Purchase);
+    /// ```
+    pub fn with_priority(mut self, priority: i32) -> Self {
+        self.descriptor.order.priority = priority;
+        self
+    }
+
+    /// Names this observer, so that other observers of the same event can run
+    /// [`before`](Self::before) or [`after`](Self::after) it.
+    ///
+    /// Several observers can share the same name.
+    /// Note that if this is called _after_ an [`Observer`] is spawned, it will produce no effects.
+    pub fn with_order_name(mut self, name: impl Into<Cow<'static, str>>) -> Self {
+        self.descriptor.order.name = Some(name.into());
+        self
+    }
+
+    /// Runs this observer before the observers of the same event [named](Self::with_order_name)
+    /// `name`.
+    ///
+    /// Note that if this is called _after_ an [`Observer`] is spawned, it will produce no effects.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # let mut world = World::default();
+    /// # #[derive(Event)]
+    /// # struct Purchase;
+    /// world.spawn(
+    ///     Observer::new(|_: On<Purchase>| println!("apply the purchase")).with_order_name("apply"),
+    /// );
+    /// world.spawn(Observer::new(|_: On<Purchase>| println!("validate the purchase")).before("apply"));
+    /// world.trigger(Purchase);
+    /// ```
+    pub fn before(mut self, name: impl Into<Cow<'static, str>>) -> Self {
+        self.descriptor.order.before.push(name.into());
+        self
+    }
+
+    /// Runs this observer after the observers of the same event [named](Self::with_order_name)
+    /// `name`.
+    ///
+    /// Note that if this is called _after_ an [`Observer`] is spawned, it will produce no effects.
+    pub fn after(mut self, name: impl Into<Cow<'static, str>>) -> Self {
+        self.descriptor.order.after.push(name.into());
+        self
+    }
+
     /// Sets the error handler to use for this observer.
     ///
     /// See the [`error` module-level documentation](crate::error) for more information.
@@ -382,6 +443,9 @@ pub struct ObserverDescriptor {
 
     /// The entities the observer is watching.
     pub(super) entities: Vec<Entity>,
+
+    /// Where the observer runs relative to the other observers of the same events.
+    pub(super) order: ObserverOrder,
 }
 
 impl ObserverDescriptor {
@@ -420,6 +484,11 @@ impl ObserverDescriptor {
     pub fn entities(&self) -> &[Entity] {
         &self.entities
     }
+
+    /// Returns where the observer runs relative to the other observers of the same events.
+    pub fn order(&self) -> &ObserverOrder {
+        &self.order
+    }
 }
 
 /// A [`ComponentHook`] used by [`Observer`] to handle its [`on-add`](`crate::lifecycle::ComponentHooks::on_add`).
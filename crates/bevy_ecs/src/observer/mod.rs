@@ -81,8 +81,9 @@
 //! To control the relative ordering of observers sent from different systems,
 //! order the systems in the schedule relative to each other.
 //!
-//! Currently, Bevy does not provide [a way to specify the ordering of observers](https://github.com/bevyengine/bevy/issues/14890)
-//! listening to the same event relative to each other.
+//! Observers listening to the same event run in an unspecified order relative to each other,
+//! unless they are given a priority with [`Observer::with_priority`] or ordering constraints with
+//! [`Observer::before`] and [`Observer::after`]. See [`ObserverOrder`] for details.
 //!
 //! Commands sent by observers are [currently not immediately applied](https://github.com/bevyengine/bevy/issues/19569).
 //! Instead, all queued observers will run, and then all of the commands from those observers will be applied.
@@ -132,16 +133,20 @@
 mod centralized_storage;
 mod distributed_storage;
 mod entity_cloning;
+mod ordering;
 mod runner;
 mod system_param;
 mod trigger_targets;
 
 pub use centralized_storage::*;
 pub use distributed_storage::*;
+pub use ordering::*;
 pub use runner::*;
 pub use system_param::*;
 pub use trigger_targets::*;
 
+use alloc::vec::Vec;
+
 use crate::{
     change_detection::MaybeLocation,
     component::ComponentId,
@@ -186,6 +191,46 @@ impl World {
         self.spawn(Observer::new(system))
     }
 
+    /// Spawns a "global" [`Observer`] like [`World::add_observer`], with the given `priority`
+    /// relative to the other observers of the same event.
+    ///
+    /// Observers with a higher priority run first. See [`ObserverOrder`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #[derive(Event)]
+    /// struct Purchase;
+    ///
+    /// # let mut world = World::new();
+    /// world.add_observer(|_: On<Purchase>| {
+    ///     // Runs second.
+    /// });
+    /// world.add_observer_with_priority(|_: On<Purchase>| {
+    ///     // Runs first.
+    /// }, 10);
+    /// ```
+    pub fn add_observer_with_priority<E: Event, B: Bundle, M>(
+        &mut self,
+        system: impl IntoObserverSystem<E, B, M>,
+        priority: i32,
+    ) -> EntityWorldMut<'_> {
+        self.spawn(Observer::new(system).with_priority(priority))
+    }
+
+    /// Returns the observers watching the event `E`, in the order they run.
+    ///
+    /// Only the observers matching a trigger run, so the actual order of a trigger is a subset of
+    /// this list. If none of the observers have a priority or ordering constraints, they run in an
+    /// unspecified order, and are listed in the order they were registered.
+    pub fn observers_for<E: Event>(&self) -> Vec<Entity> {
+        E::event_key(self)
+            .and_then(|event_key| self.observers.try_get_observers(event_key))
+            .map(|observers| observers.ordering.observers())
+            .unwrap_or_default()
+    }
+
     /// Triggers the given [`Event`], which will run any [`Observer`]s watching for it.
     ///
     /// While event types commonly implement [`Copy`],
@@ -379,6 +424,23 @@ impl World {
         };
         let descriptor = &observer_state.descriptor;
 
+        for (i, &event_key) in descriptor.event_keys.iter().enumerate() {
+            let ordering = &mut observers.get_observers_mut(event_key).ordering;
+            if let Err(unordered) = ordering.insert(observer_entity, descriptor.order.clone()) {
+                for &event_key in &descriptor.event_keys[..i] {
+                    observers
+                        .get_observers_mut(event_key)
+                        .ordering
+                        .remove(observer_entity);
+                }
+                panic!(
+                    "Observer {observer_entity} ({}) could not be registered: the ordering constraints \
+                    of the observers of the same event form a cycle. Observers that could not be ordered: {unordered:?}",
+                    observer_state.system_name()
+                );
+            }
+        }
+
         for &event_key in &descriptor.event_keys {
             let cache = observers.get_observers_mut(event_key);
 
@@ -432,6 +494,7 @@ impl World {
 
         for &event_key in &descriptor.event_keys {
             let cache = observers.get_observers_mut(event_key);
+            cache.ordering.remove(entity);
             if descriptor.components.is_empty() && descriptor.entities.is_empty() {
                 cache.global_observers.remove(&entity);
             } else if descriptor.components.is_empty() {
@@ -634,6 +697,129 @@ mod tests {
         assert_eq!(vec!["replace", "insert"], world.resource::<Order>().0);
     }
 
+    #[test]
+    fn observer_priority_ordering() {
+        let mut world = World::new();
+        world.init_resource::<Order>();
+
+        world.add_observer(|_: On<EventA>, mut res: ResMut<Order>| res.observed("zero_a"));
+        world.add_observer_with_priority(
+            |_: On<EventA>, mut res: ResMut<Order>| res.observed("low"),
+            -5,
+        );
+        world.add_observer_with_priority(
+            |_: On<EventA>, mut res: ResMut<Order>| res.observed("high"),
+            5,
+        );
+        world.add_observer(|_: On<EventA>, mut res: ResMut<Order>| res.observed("zero_b"));
+        let entity = world.spawn_empty().id();
+        world
+            .entity_mut(entity)
+            .observe(|_: On<EventA>, mut res: ResMut<Order>| res.observed("entity"));
+        world.trigger_targets(EventA, entity);
+
+        assert_eq!(
+            vec!["high", "zero_a", "zero_b", "entity", "low"],
+            world.resource::<Order>().0
+        );
+    }
+
+    #[test]
+    fn observer_before_after_ordering() {
+        let mut world = World::new();
+        world.init_resource::<Order>();
+
+        world.spawn(
+            Observer::new(|_: On<Add, A>, mut res: ResMut<Order>| res.observed("apply"))
+                .with_order_name("apply")
+                .after("validate"),
+        );
+        world.spawn(
+            Observer::new(|_: On<Add, A>, mut res: ResMut<Order>| res.observed("log"))
+                .after("apply"),
+        );
+        // A higher priority can't make an observer run before the ones it is after.
+        world.spawn(
+            Observer::new(|_: On<Add, A>, mut res: ResMut<Order>| res.observed("validate"))
+                .with_order_name("validate")
+                .with_priority(-10),
+        );
+        world.spawn(A);
+
+        assert_eq!(
+            vec!["validate", "apply", "log"],
+            world.resource::<Order>().0
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "form a cycle")]
+    fn observer_ordering_cycle_panics() {
+        let mut world = World::new();
+
+        world.spawn(
+            Observer::new(|_: On<EventA>| {})
+                .with_order_name("a")
+                .after("b"),
+        );
+        world.spawn(
+            Observer::new(|_: On<EventA>| {})
+                .with_order_name("b")
+                .after("a"),
+        );
+    }
+
+    #[test]
+    fn observers_for_lists_observers_in_order() {
+        let mut world = World::new();
+        assert!(world.observers_for::<EventA>().is_empty());
+
+        let first = world.add_observer(|_: On<EventA>| {}).id();
+        let second = world.add_observer(|_: On<EventA>| {}).id();
+        world.add_observer(|_: On<EventWithData>| {});
+        assert_eq!(world.observers_for::<EventA>(), vec![first, second]);
+
+        // No observer is named "missing", so this doesn't change the order.
+        let third = world
+            .spawn(Observer::new(|_: On<EventA>| {}).before("missing"))
+            .id();
+        assert_eq!(world.observers_for::<EventA>(), vec![first, second, third]);
+
+        let fourth = world.add_observer_with_priority(|_: On<EventA>| {}, 1).id();
+        assert_eq!(
+            world.observers_for::<EventA>(),
+            vec![fourth, first, second, third]
+        );
+
+        world.despawn(fourth);
+        world.flush();
+        assert_eq!(world.observers_for::<EventA>(), vec![first, second, third]);
+    }
+
+    #[test]
+    fn unconstrained_observers_keep_running_in_default_order() {
+        let mut world = World::new();
+        world.init_resource::<Order>();
+
+        let named = world
+            .spawn(
+                Observer::new(|_: On<EventA>, mut res: ResMut<Order>| res.observed("named"))
+                    .with_order_name("named"),
+            )
+            .id();
+        world.add_observer(|_: On<EventA>, mut res: ResMut<Order>| res.observed("other"));
+        let entity = world.spawn_empty().id();
+        world.trigger_targets(EventA, entity);
+
+        // A name alone doesn't constrain the order.
+        let observers = world
+            .observers()
+            .try_get_observers(EventA::event_key(&world).unwrap());
+        assert!(!observers.unwrap().ordering.is_ordered());
+        assert_eq!(world.resource::<Order>().0.len(), 2);
+        assert_eq!(world.observers_for::<EventA>()[0], named);
+    }
+
     #[test]
     fn observer_order_recursive() {
         let mut world = World::new();
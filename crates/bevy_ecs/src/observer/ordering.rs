@@ -0,0 +1,179 @@
+//! Ordering of the observers watching the same event.
+//!
+//! By default, the observers of an event run in an unspecified order. [`ObserverOrder`] lets an
+//! [`Observer`] run before or after other observers of the same event, and the [`ObserverOrdering`]
+//! stored for each event in [`CachedObservers`](super::CachedObservers) turns these constraints
+//! into the order in which the observers run.
+
+use alloc::{borrow::Cow, collections::BinaryHeap, vec::Vec};
+use bevy_platform::collections::HashMap;
+use core::cmp::Reverse;
+
+use crate::{entity::EntityHashMap, prelude::*};
+
+/// Where an [`Observer`] runs relative to the other observers of the same event.
+///
+/// Observers of an event normally run in an unspecified order. As soon as one of them has a
+/// non-zero [`priority`](Self::priority) or an ordering constraint, all observers of that event
+/// run in a well-defined order instead:
+/// - an observer runs after the observers it is [`after`](Self::after), and before the observers
+///   it is [`before`](Self::before),
+/// - otherwise, observers with a higher priority run first,
+/// - and observers with the same priority run in the order they were registered.
+///
+/// Constraints refer to observers by their [`name`](Self::name). Several observers can share a
+/// name, in which case a constraint applies to all of them. Names that no observer of the event
+/// uses are ignored. Registering an observer whose constraints form a cycle panics.
+///
+/// This is configured with [`Observer::with_priority`], [`Observer::with_order_name`],
+/// [`Observer::before`] and [`Observer::after`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ObserverOrder {
+    /// Observers with a higher priority run first.
+    pub priority: i32,
+    /// The name that other observers use to refer to this one in their constraints.
+    pub name: Option<Cow<'static, str>>,
+    /// The names of the observers this one runs before.
+    pub before: Vec<Cow<'static, str>>,
+    /// The names of the observers this one runs after.
+    pub after: Vec<Cow<'static, str>>,
+}
+
+impl ObserverOrder {
+    /// Returns `true` if this doesn't constrain the order of the observer.
+    pub fn is_unconstrained(&self) -> bool {
+        self.priority == 0 && self.before.is_empty() && self.after.is_empty()
+    }
+}
+
+/// The order in which the observers of an event run.
+#[derive(Default, Debug)]
+pub(crate) struct ObserverOrdering {
+    /// Every observer of the event with its order, in registration order.
+    observers: Vec<(Entity, ObserverOrder)>,
+    /// The position of each observer in the order they run.
+    ///
+    /// Empty if no observer constrains the order, as they run in an unspecified order.
+    ranks: EntityHashMap<u32>,
+}
+
+impl ObserverOrdering {
+    /// Adds an observer of the event.
+    ///
+    /// If the constraints form a cycle, the observer isn't added and the observers that can't be
+    /// ordered are returned instead.
+    pub(crate) fn insert(
+        &mut self,
+        observer: Entity,
+        order: ObserverOrder,
+    ) -> Result<(), Vec<Entity>> {
+        self.observers.push((observer, order));
+        self.update_ranks().inspect_err(|_| {
+            self.observers.pop();
+            // The remaining observers could already be ordered.
+            let _ = self.update_ranks();
+        })
+    }
+
+    /// Removes an observer of the event.
+    pub(crate) fn remove(&mut self, observer: Entity) {
+        let len = self.observers.len();
+        self.observers.retain(|(entity, _)| *entity != observer);
+        if self.observers.len() != len {
+            // Removing an observer can't create a cycle.
+            let _ = self.update_ranks();
+        }
+    }
+
+    /// Returns `true` if the observers run in a well-defined order.
+    pub(crate) fn is_ordered(&self) -> bool {
+        !self.ranks.is_empty()
+    }
+
+    /// Returns the position of `observer` in the order the observers run.
+    pub(crate) fn rank(&self, observer: Entity) -> u32 {
+        self.ranks.get(&observer).copied().unwrap_or(u32::MAX)
+    }
+
+    /// Returns the observers in the order they run, or in registration order if they run in an
+    /// unspecified order.
+    pub(crate) fn observers(&self) -> Vec<Entity> {
+        let mut observers = self
+            .observers
+            .iter()
+            .map(|(entity, _)| *entity)
+            .collect::<Vec<_>>();
+        if self.is_ordered() {
+            observers.sort_by_key(|&entity| self.rank(entity));
+        }
+        observers
+    }
+
+    fn update_ranks(&mut self) -> Result<(), Vec<Entity>> {
+        self.ranks.clear();
+        if self
+            .observers
+            .iter()
+            .all(|(_, order)| order.is_unconstrained())
+        {
+            return Ok(());
+        }
+
+        let mut named = HashMap::<&str, Vec<usize>>::default();
+        for (index, (_, order)) in self.observers.iter().enumerate() {
+            if let Some(name) = &order.name {
+                named.entry(name).or_default().push(index);
+            }
+        }
+
+        let mut successors = alloc::vec![Vec::new(); self.observers.len()];
+        let mut predecessors = alloc::vec![0_usize; self.observers.len()];
+        for (index, (_, order)) in self.observers.iter().enumerate() {
+            for name in &order.before {
+                for &next in named.get(&**name).into_iter().flatten() {
+                    successors[index].push(next);
+                    predecessors[next] += 1;
+                }
+            }
+            for name in &order.after {
+                for &previous in named.get(&**name).into_iter().flatten() {
+                    successors[previous].push(index);
+                    predecessors[index] += 1;
+                }
+            }
+        }
+
+        // Topological sort, picking the observer with the highest priority, then the earliest
+        // registered one, among those that are free to run.
+        let mut ready = BinaryHeap::new();
+        for (index, (_, order)) in self.observers.iter().enumerate() {
+            if predecessors[index] == 0 {
+                ready.push((order.priority, Reverse(index)));
+            }
+        }
+        let mut rank = 0;
+        while let Some((_, Reverse(index))) = ready.pop() {
+            self.ranks.insert(self.observers[index].0, rank);
+            rank += 1;
+            for &next in &successors[index] {
+                predecessors[next] -= 1;
+                if predecessors[next] == 0 {
+                    ready.push((self.observers[next].1.priority, Reverse(next)));
+                }
+            }
+        }
+
+        if self.ranks.len() == self.observers.len() {
+            Ok(())
+        } else {
+            let unordered = self
+                .observers
+                .iter()
+                .map(|(entity, _)| *entity)
+                .filter(|entity| !self.ranks.contains_key(entity))
+                .collect();
+            self.ranks.clear();
+            Err(unordered)
+        }
+    }
+}
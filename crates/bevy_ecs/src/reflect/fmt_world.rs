@@ -0,0 +1,472 @@
+use crate::{
+    entity::Entity,
+    name::Name,
+    reflect::{AppTypeRegistry, ReflectComponent, ReflectResource},
+    world::World,
+};
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+use bevy_reflect::{GetPath, PartialReflect, Reflect, TypeRegistration};
+
+/// Formats a string with values read from a [`World`], resolved when the string is formatted.
+///
+/// The template is a string literal where each placeholder names an entity argument and what to
+/// show about it:
+///
+/// - `{entity:name}`: the [`Name`] of the entity, or `<no name>`;
+/// - `{entity:id}` or `{entity}`: the [`Entity`] itself;
+/// - `{entity:Health}`, `{entity:Health.0}`, `{entity:Stats.health.current}`: a reflected
+///   component, or one of its fields by [reflection path](bevy_reflect::GetPath), or
+///   `<missing Health>` if the entity doesn't have it;
+/// - `{resource:Score.0}`: a reflected resource or one of its fields, with the same syntax.
+///
+/// Components and resources are looked up by their short type path in the [`AppTypeRegistry`],
+/// and must be registered with [`ReflectComponent`] or [`ReflectResource`]. Fields are shown with
+/// their [`Debug`](core::fmt::Debug) implementation, except for strings which are shown as is.
+/// Use `{{` and `}}` to write braces.
+///
+/// The syntax of the template and the argument names are checked at compile time. Whether the
+/// types and fields exist can only be known when formatting, so unknown types and fields are
+/// shown as `<unregistered Health>` and `<invalid path Health.foo>` instead.
+///
+/// This returns a [`String`], so it can be used in the log macros as an argument.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_ecs::{fmt_world, prelude::*};
+/// # use bevy_reflect::Reflect;
+/// #[derive(Component, Reflect)]
+/// #[reflect(Component)]
+/// struct Health(f32);
+///
+/// let mut world = World::new();
+/// world.init_resource::<AppTypeRegistry>();
+/// world.resource::<AppTypeRegistry>().write().register::<Health>();
+///
+/// let goblin = world.spawn((Name::new("Goblin"), Health(10.0))).id();
+/// let message = fmt_world!(
+///     &world,
+///     "Selected {entity:name} ({entity:id}) hp={entity:Health.0}",
+///     entity = goblin,
+/// );
+/// assert_eq!(message, format!("Selected Goblin ({goblin}) hp=10.0"));
+/// ```
+#[macro_export]
+macro_rules! fmt_world {
+    ($world:expr, $template:literal $(, $name:ident = $entity:expr)* $(,)?) => {{
+        const _: () =
+            $crate::reflect::check_world_template($template, &[$(stringify!($name)),*]);
+        $crate::reflect::format_world($world, $template, &[$((stringify!($name), $entity)),*])
+    }};
+}
+
+/// Formats `template` with the entities of `args`, see [`fmt_world!`](crate::fmt_world).
+///
+/// Unlike the macro, this doesn't check the template: malformed placeholders and unknown
+/// arguments are written as is.
+pub fn format_world(world: &World, template: &str, args: &[(&str, Entity)]) -> String {
+    let mut output = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find(['{', '}']) {
+        output.push_str(&rest[..start]);
+        let brace = &rest[start..=start];
+        let after = &rest[start + 1..];
+        if let Some(after) = after.strip_prefix(brace) {
+            output.push_str(brace);
+            rest = after;
+        } else if let Some((placeholder, after)) = after.split_once('}')
+            && brace == "{"
+        {
+            output.push_str(&format_placeholder(world, placeholder, args));
+            rest = after;
+        } else {
+            output.push_str(brace);
+            rest = after;
+        }
+    }
+    output.push_str(rest);
+    output
+}
+
+fn format_placeholder(world: &World, placeholder: &str, args: &[(&str, Entity)]) -> String {
+    let (name, spec) = placeholder.split_once(':').unwrap_or((placeholder, "id"));
+    if name == "resource" {
+        return format_resource(world, spec);
+    }
+    match args.iter().find(|(arg, _)| *arg == name) {
+        Some(&(_, entity)) => format_entity(world, entity, spec),
+        None => format!("{{{placeholder}}}"),
+    }
+}
+
+fn format_entity(world: &World, entity: Entity, spec: &str) -> String {
+    match spec {
+        "id" => return entity.to_string(),
+        "name" => {
+            return world
+                .get::<Name>(entity)
+                .map_or_else(|| "<no name>".into(), |name| name.as_str().into());
+        }
+        _ => {}
+    }
+
+    let (type_name, _) = split_type_path(spec);
+    let Some(registry) = world.get_resource::<AppTypeRegistry>() else {
+        return format!("<unregistered {type_name}>");
+    };
+    let registry = registry.read();
+    let Some(reflect_component) = find_registration(&registry, type_name)
+        .and_then(TypeRegistration::data::<ReflectComponent>)
+    else {
+        return format!("<unregistered {type_name}>");
+    };
+    match world
+        .get_entity(entity)
+        .ok()
+        .and_then(|entity| reflect_component.reflect(entity))
+    {
+        Some(component) => format_field(component, spec),
+        None => format!("<missing {type_name}>"),
+    }
+}
+
+fn format_resource(world: &World, spec: &str) -> String {
+    let (type_name, _) = split_type_path(spec);
+    let Some(registry) = world.get_resource::<AppTypeRegistry>() else {
+        return format!("<unregistered {type_name}>");
+    };
+    let registry = registry.read();
+    let Some(reflect_resource) =
+        find_registration(&registry, type_name).and_then(TypeRegistration::data::<ReflectResource>)
+    else {
+        return format!("<unregistered {type_name}>");
+    };
+    match reflect_resource.reflect(world) {
+        Ok(resource) => format_field(resource, spec),
+        Err(_) => format!("<missing {type_name}>"),
+    }
+}
+
+fn find_registration<'a>(
+    registry: &'a bevy_reflect::TypeRegistry,
+    type_name: &str,
+) -> Option<&'a TypeRegistration> {
+    registry
+        .get_with_short_type_path(type_name)
+        .or_else(|| registry.get_with_type_path(type_name))
+}
+
+/// Splits `Health.0` into the type `Health` and the path `.0`.
+fn split_type_path(spec: &str) -> (&str, &str) {
+    spec.split_at(spec.find(['.', '#', '[']).unwrap_or(spec.len()))
+}
+
+fn format_field(value: &dyn Reflect, spec: &str) -> String {
+    let (_, path) = split_type_path(spec);
+    match value.reflect_path(path) {
+        Ok(field) => format_value(field),
+        Err(_) => format!("<invalid path {spec}>"),
+    }
+}
+
+fn format_value(value: &dyn PartialReflect) -> String {
+    match value.try_downcast_ref::<String>() {
+        Some(string) => string.clone(),
+        None => format!("{value:?}"),
+    }
+}
+
+/// Checks the syntax of a [`fmt_world!`](crate::fmt_world) template at compile time.
+#[doc(hidden)]
+pub const fn check_world_template(template: &str, args: &[&str]) {
+    let mut i = 0;
+    while i < args.len() {
+        if bytes_eq(args[i].as_bytes(), 0, args[i].len(), b"resource") {
+            panic!("`resource` can't be used as an argument name of `fmt_world!`");
+        }
+        i += 1;
+    }
+
+    let bytes = template.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        let next = if i + 1 < bytes.len() { bytes[i + 1] } else { 0 };
+        i = match bytes[i] {
+            b'{' if next == b'{' => i + 2,
+            b'}' if next == b'}' => i + 2,
+            b'{' => check_placeholder(bytes, i + 1, args),
+            b'}' => panic!("unmatched `}}` in `fmt_world!` template, use `}}}}` to write a brace"),
+            _ => i + 1,
+        };
+    }
+}
+
+/// Checks the placeholder starting at `start`, after its `{`, and returns the index after its `}`.
+const fn check_placeholder(bytes: &[u8], start: usize, args: &[&str]) -> usize {
+    let name_end = identifier_end(bytes, start);
+    if name_end == start {
+        panic!("expected an argument name after `{{` in `fmt_world!` template");
+    }
+    let is_resource = bytes_eq(bytes, start, name_end, b"resource");
+    if !is_resource && !is_argument(bytes, start, name_end, args) {
+        panic!("unknown argument in `fmt_world!` template");
+    }
+
+    let mut i = name_end;
+    if i < bytes.len() && bytes[i] == b':' {
+        let spec_end = identifier_end(bytes, i + 1);
+        if spec_end == i + 1 {
+            panic!("expected `name`, `id` or a type after `:` in `fmt_world!` template");
+        }
+        let is_keyword =
+            bytes_eq(bytes, i + 1, spec_end, b"name") || bytes_eq(bytes, i + 1, spec_end, b"id");
+        if is_keyword && is_resource {
+            panic!("expected a resource type after `resource:` in `fmt_world!` template");
+        }
+        i = if is_keyword {
+            spec_end
+        } else {
+            check_path(bytes, spec_end)
+        };
+    } else if is_resource {
+        panic!("expected a resource type after `resource:` in `fmt_world!` template");
+    }
+
+    if i >= bytes.len() || bytes[i] != b'}' {
+        panic!("invalid field path or unclosed placeholder in `fmt_world!` template");
+    }
+    i + 1
+}
+
+/// Checks the reflection path starting at `start`, and returns the index after it.
+const fn check_path(bytes: &[u8], start: usize) -> usize {
+    let mut i = start;
+    while i < bytes.len() {
+        i = match bytes[i] {
+            b'.' => {
+                let end = identifier_end(bytes, i + 1);
+                if end == i + 1 {
+                    panic!("expected a field name or index after `.` in `fmt_world!` template");
+                }
+                end
+            }
+            b'#' => {
+                let end = digits_end(bytes, i + 1);
+                if end == i + 1 {
+                    panic!("expected a field index after `#` in `fmt_world!` template");
+                }
+                end
+            }
+            b'[' => {
+                let end = digits_end(bytes, i + 1);
+                if end == i + 1 || end >= bytes.len() || bytes[end] != b']' {
+                    panic!("expected a list index like `[0]` in `fmt_world!` template");
+                }
+                end + 1
+            }
+            _ => return i,
+        };
+    }
+    i
+}
+
+const fn identifier_end(bytes: &[u8], start: usize) -> usize {
+    let mut i = start;
+    while i < bytes.len() && (bytes[i].is_ascii_alphanumeric() || bytes[i] == b'_') {
+        i += 1;
+    }
+    i
+}
+
+const fn digits_end(bytes: &[u8], start: usize) -> usize {
+    let mut i = start;
+    while i < bytes.len() && bytes[i].is_ascii_digit() {
+        i += 1;
+    }
+    i
+}
+
+const fn is_argument(bytes: &[u8], start: usize, end: usize, args: &[&str]) -> bool {
+    let mut i = 0;
+    while i < args.len() {
+        if bytes_eq(bytes, start, end, args[i].as_bytes()) {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
+
+/// Returns whether `bytes[start..end]` is `expected`.
+const fn bytes_eq(bytes: &[u8], start: usize, end: usize, expected: &[u8]) -> bool {
+    if end - start != expected.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < expected.len() {
+        if bytes[start + i] != expected[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::check_world_template;
+    use crate::{
+        component::Component,
+        name::Name,
+        prelude::{AppTypeRegistry, ReflectComponent, ReflectResource},
+        resource::Resource,
+        world::World,
+    };
+    use alloc::{format, string::String, vec, vec::Vec};
+    use bevy_reflect::Reflect;
+
+    #[derive(Component, Reflect)]
+    #[reflect(Component)]
+    struct Health(f32);
+
+    #[derive(Reflect)]
+    struct Gauge {
+        current: u32,
+        max: u32,
+    }
+
+    #[derive(Component, Reflect)]
+    #[reflect(Component)]
+    struct Stats {
+        health: Gauge,
+        title: String,
+        levels: Vec<u8>,
+    }
+
+    #[derive(Resource, Reflect)]
+    #[reflect(Resource)]
+    struct Score(u32);
+
+    fn world() -> World {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        {
+            let mut registry = world.resource::<AppTypeRegistry>().write();
+            registry.register::<Health>();
+            registry.register::<Stats>();
+            registry.register::<Score>();
+        }
+        world
+    }
+
+    #[test]
+    fn interpolates_names_ids_and_fields() {
+        let mut world = world();
+        let goblin = world.spawn((Name::new("Goblin"), Health(10.0))).id();
+
+        let message = fmt_world!(
+            &world,
+            "Selected {entity:name} ({entity:id}) hp={entity:Health.0}",
+            entity = goblin,
+        );
+        assert_eq!(message, format!("Selected Goblin ({goblin}) hp=10.0"));
+        assert_eq!(
+            fmt_world!(&world, "{{{e}}}", e = goblin),
+            format!("{{{goblin}}}")
+        );
+        // Whole components are shown with their reflected `Debug` implementation.
+        assert!(fmt_world!(&world, "{e:Health}", e = goblin).ends_with("Health(10.0)"));
+    }
+
+    #[test]
+    fn falls_back_on_missing_values() {
+        #[derive(Component, Reflect)]
+        #[reflect(Component)]
+        struct Unregistered;
+
+        let mut world = world();
+        let nameless = world.spawn(Unregistered).id();
+        let despawned = world.spawn(Health(1.0)).id();
+        world.despawn(despawned);
+
+        assert_eq!(
+            fmt_world!(
+                &world,
+                "{e:name} {e:Health.0} {e:Unregistered} {e:Health.1} {resource:Score.0}",
+                e = nameless,
+            ),
+            "<no name> <missing Health> <unregistered Unregistered> <missing Health> <missing Score>"
+        );
+        assert_eq!(
+            fmt_world!(&world, "{e:name} {e:Health}", e = despawned),
+            "<no name> <missing Health>"
+        );
+
+        let goblin = world.spawn(Health(10.0)).id();
+        assert_eq!(
+            fmt_world!(&world, "{e:Health.1}", e = goblin),
+            "<invalid path Health.1>"
+        );
+    }
+
+    #[test]
+    fn resolves_nested_field_paths() {
+        let mut world = world();
+        world.insert_resource(Score(42));
+        let hero = world
+            .spawn(Stats {
+                health: Gauge {
+                    current: 7,
+                    max: 12,
+                },
+                title: "the Brave".into(),
+                levels: vec![3, 5],
+            })
+            .id();
+
+        assert_eq!(
+            fmt_world!(
+                &world,
+                "{hero:Stats.title}: {hero:Stats.health.current}/{hero:Stats#0.max}, \
+                level {hero:Stats.levels[1]}, score {resource:Score.0}",
+                hero = hero,
+            ),
+            "the Brave: 7/12, level 5, score 42"
+        );
+    }
+
+    #[test]
+    fn usable_in_log_macros() {
+        let mut world = world();
+        let goblin = world.spawn(Name::new("Goblin")).id();
+        let other = world.spawn_empty().id();
+
+        log::info!(
+            "{}",
+            fmt_world!(&world, "{a:name} meets {b:name}", a = goblin, b = other)
+        );
+    }
+
+    #[test]
+    fn accepts_valid_templates() {
+        check_world_template(
+            "{{}} {e} {e:id} {e:name} {e:A.b#0[1].c} {resource:R}",
+            &["e"],
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "expected a field name or index after `.`")]
+    fn rejects_invalid_paths() {
+        check_world_template("{e:Health.}", &["e"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "unknown argument")]
+    fn rejects_unknown_arguments() {
+        check_world_template("{entity:name}", &["e"]);
+    }
+}
@@ -3,6 +3,8 @@
 // The derive macro for the `Resource` trait
 pub use bevy_ecs_macros::Resource;
 
+pub use bevy_platform::cell::SyncCell;
+
 /// A type that can be inserted into a [`World`] as a singleton.
 ///
 /// You can access resource data in systems using the [`Res`] and [`ResMut`] system parameters
@@ -62,6 +64,45 @@ pub use bevy_ecs_macros::Resource;
 /// }
 /// ```
 ///
+/// This is also how the receiving end of a channel, such as [`std::sync::mpsc::Receiver`], which
+/// is `!Sync`, is stored as a regular resource rather than a non-send one: the systems using it
+/// can then run on any thread.
+/// ```
+/// # use bevy_ecs::{prelude::*, resource::SyncCell};
+/// use std::sync::mpsc::{self, Receiver};
+///
+/// #[derive(BufferedEvent)]
+/// struct Tick(u32);
+///
+/// #[derive(Resource)]
+/// struct TickReceiver(SyncCell<Receiver<u32>>);
+///
+/// fn transfer_ticks(mut receiver: ResMut<TickReceiver>, mut ticks: EventWriter<Tick>) {
+///     ticks.write_batch(receiver.0.get_mut().try_iter().map(Tick));
+/// }
+///
+/// let mut world = World::new();
+/// world.init_resource::<Events<Tick>>();
+/// let (sender, receiver) = mpsc::channel::<u32>();
+/// world.insert_resource(TickReceiver(SyncCell::new(receiver)));
+/// sender.send(1).unwrap();
+///
+/// world.run_system_cached(transfer_ticks).unwrap();
+/// assert_eq!(world.resource::<Events<Tick>>().len(), 1);
+/// ```
+///
+/// The value of a `SyncCell` can only be accessed mutably, so it can't be used through a [`Res`].
+/// ```compile_fail
+/// # use bevy_ecs::{prelude::*, resource::SyncCell};
+/// # use std::sync::mpsc::Receiver;
+/// #[derive(Resource)]
+/// struct TickReceiver(SyncCell<Receiver<u32>>);
+///
+/// fn read_only(receiver: Res<TickReceiver>) {
+///     receiver.0.get_mut().try_recv();
+/// }
+/// ```
+///
 /// [`Exclusive`]: https://doc.rust-lang.org/nightly/std/sync/struct.Exclusive.html
 /// [`World`]: crate::world::World
 /// [`Res`]: crate::system::Res
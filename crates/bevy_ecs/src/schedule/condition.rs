@@ -889,6 +889,126 @@ pub mod common_conditions {
         reader.read().count() > 0
     }
 
+    /// Generates a [`SystemCondition`]-satisfying closure that returns `true`
+    /// if any event of type `T` written since the last time the condition ran matches `predicate`.
+    ///
+    /// Like [`on_event`], the condition reads the events with its own [`EventReader`], so the
+    /// readers of the system it gates still read all the events, including the ones that don't
+    /// match. All the events are checked on each run, so an event is never checked twice.
+    ///
+    /// The condition only peeks at the events: they don't count as read for
+    /// [`Events::clear_consumed`](crate::event::Events::clear_consumed).
+    ///
+    /// See [`on_all_events_matching`] to require all the events to match.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # #[derive(Resource, Default)]
+    /// # struct Counter(u8);
+    /// # let mut app = Schedule::default();
+    /// # let mut world = World::new();
+    /// # world.init_resource::<Counter>();
+    /// # world.init_resource::<Events<Damage>>();
+    /// app.add_systems(
+    ///     // `on_event_matching` will only return true if a `Damage` event of at least 10 arrived
+    ///     my_system.run_if(on_event_matching(|damage: &Damage| damage.0 >= 10)),
+    /// );
+    ///
+    /// #[derive(BufferedEvent)]
+    /// struct Damage(u32);
+    ///
+    /// fn my_system(mut counter: ResMut<Counter>, mut damages: EventReader<Damage>) {
+    ///     counter.0 += damages.read().count() as u8;
+    /// }
+    ///
+    /// world.write_event(Damage(2));
+    ///
+    /// // No `Damage` event of at least 10 arrived, so `my_system` won't run
+    /// app.run(&mut world);
+    /// assert_eq!(world.resource::<Counter>().0, 0);
+    ///
+    /// world.write_event(Damage(20));
+    ///
+    /// // A `Damage` event of at least 10 arrived, so `my_system` will run and read both events
+    /// app.run(&mut world);
+    /// assert_eq!(world.resource::<Counter>().0, 2);
+    /// ```
+    pub fn on_event_matching<T: BufferedEvent>(
+        mut predicate: impl FnMut(&T) -> bool + Send + Sync + 'static,
+    ) -> impl FnMut(EventReader<T>) -> bool {
+        move |mut reader: EventReader<T>| {
+            // The events after the first match are read too, so that they aren't checked again
+            // on the next run.
+            let mut matched = false;
+            for event in reader.peek() {
+                matched |= predicate(event);
+            }
+            matched
+        }
+    }
+
+    /// Generates a [`SystemCondition`]-satisfying closure that returns `true`
+    /// if events of type `T` were written since the last time the condition ran, and all of
+    /// them match `predicate`.
+    ///
+    /// Like [`on_event_matching`], the condition reads the events with its own
+    /// [`EventReader`], so the readers of the system it gates still read all the events.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # #[derive(Resource, Default)]
+    /// # struct Counter(u8);
+    /// # let mut app = Schedule::default();
+    /// # let mut world = World::new();
+    /// # world.init_resource::<Counter>();
+    /// # world.init_resource::<Events<Damage>>();
+    /// app.add_systems(
+    ///     // `on_all_events_matching` will only return true if all the `Damage` events are healing
+    ///     my_system.run_if(on_all_events_matching(|damage: &Damage| damage.0 < 0)),
+    /// );
+    ///
+    /// #[derive(BufferedEvent)]
+    /// struct Damage(i32);
+    ///
+    /// fn my_system(mut counter: ResMut<Counter>) {
+    ///     counter.0 += 1;
+    /// }
+    ///
+    /// world.write_event(Damage(-2));
+    /// world.write_event(Damage(5));
+    ///
+    /// // Not all the `Damage` events are healing, so `my_system` won't run
+    /// app.run(&mut world);
+    /// assert_eq!(world.resource::<Counter>().0, 0);
+    ///
+    /// // No `Damage` event arrived, so `my_system` won't run
+    /// app.run(&mut world);
+    /// assert_eq!(world.resource::<Counter>().0, 0);
+    ///
+    /// world.write_event(Damage(-3));
+    ///
+    /// // All the `Damage` events are healing, so `my_system` will run
+    /// app.run(&mut world);
+    /// assert_eq!(world.resource::<Counter>().0, 1);
+    /// ```
+    pub fn on_all_events_matching<T: BufferedEvent>(
+        mut predicate: impl FnMut(&T) -> bool + Send + Sync + 'static,
+    ) -> impl FnMut(EventReader<T>) -> bool {
+        move |mut reader: EventReader<T>| {
+            let mut any = false;
+            let mut all = true;
+            for event in reader.peek() {
+                any = true;
+                all &= predicate(event);
+            }
+            any && all
+        }
+    }
+
     /// A [`SystemCondition`]-satisfying system that returns `true`
     /// if there are any entities with the given component type.
     ///
@@ -1264,7 +1384,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::{common_conditions::*, SystemCondition};
-    use crate::event::BufferedEvent;
+    use crate::event::{BufferedEvent, EventReader, Events};
     use crate::query::With;
     use crate::{
         change_detection::ResMut,
@@ -1273,6 +1393,7 @@ mod tests {
         system::Local,
         world::World,
     };
+    use alloc::{vec, vec::Vec};
     use bevy_ecs_macros::Resource;
 
     #[derive(Resource, Default)]
@@ -1387,6 +1508,56 @@ mod tests {
     #[derive(BufferedEvent)]
     struct TestEvent;
 
+    #[derive(BufferedEvent)]
+    struct Level(u8);
+
+    #[derive(Resource, Default)]
+    struct Read(Vec<u8>);
+
+    fn read_levels(mut levels: EventReader<Level>, mut read: ResMut<Read>) {
+        read.0.extend(levels.read().map(|level| level.0));
+    }
+
+    /// Runs `schedule` once after writing each batch of `levels`, returning what the gated
+    /// system read after each run.
+    fn run_with_levels(mut schedule: Schedule, batches: &[&[u8]]) -> Vec<Vec<u8>> {
+        let mut world = World::new();
+        world.init_resource::<Events<Level>>();
+        world.init_resource::<Read>();
+        batches
+            .iter()
+            .map(|levels| {
+                world.write_event_batch(levels.iter().copied().map(Level));
+                schedule.run(&mut world);
+                core::mem::take(&mut world.resource_mut::<Read>().0)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn on_event_matching_peeks_the_events() {
+        let mut schedule = Schedule::default();
+        schedule.add_systems(read_levels.run_if(on_event_matching(|level: &Level| level.0 >= 3)));
+        let read = run_with_levels(schedule, &[&[], &[1, 2], &[1, 4, 2], &[3, 5], &[1]]);
+        // The gated system reads all the events since it last ran, including the unmatched ones.
+        assert_eq!(
+            read,
+            [vec![], vec![], vec![1, 2, 1, 4, 2], vec![3, 5], vec![]]
+        );
+    }
+
+    #[test]
+    fn on_all_events_matching_peeks_the_events() {
+        let mut schedule = Schedule::default();
+        schedule
+            .add_systems(read_levels.run_if(on_all_events_matching(|level: &Level| level.0 >= 3)));
+        let read = run_with_levels(schedule, &[&[], &[1, 2], &[1, 4], &[3, 5], &[1]]);
+        assert_eq!(
+            read,
+            [vec![], vec![], vec![], vec![1, 2, 1, 4, 3, 5], vec![]]
+        );
+    }
+
     #[derive(Resource)]
     struct TestResource(());
 
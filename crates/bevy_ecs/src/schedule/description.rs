@@ -0,0 +1,166 @@
+use alloc::{borrow::Cow, string::String};
+use bevy_platform::collections::HashMap;
+
+use crate::{
+    resource::Resource,
+    schedule::{InternedScheduleLabel, InternedSystemSet, ScheduleLabel, SystemSet},
+};
+
+/// Human-readable descriptions of schedules and system sets, for tools such as schedule
+/// inspectors.
+///
+/// Descriptions are usually given by the plugins adding the schedules and sets, with
+/// `App::describe_schedule` and `App::describe_set`. A schedule label can also give a default
+/// description with the `#[schedule_doc = "..."]` attribute, see [`ScheduleLabel::doc`].
+///
+/// ```
+/// # use bevy_ecs::schedule::{Description, ScheduleDescriptions, ScheduleLabel};
+/// #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+/// #[schedule_doc = "Moves the bodies of the physics simulation."]
+/// struct PhysicsStep;
+///
+/// #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+/// struct Render;
+///
+/// let mut descriptions = ScheduleDescriptions::default();
+/// descriptions.describe_schedule(Render, Description::new("Draws the frame."));
+/// assert_eq!(descriptions.schedule(Render), Some("Draws the frame."));
+/// assert_eq!(
+///     descriptions.schedule(PhysicsStep),
+///     Some("Moves the bodies of the physics simulation.")
+/// );
+/// ```
+#[derive(Resource, Debug, Default)]
+pub struct ScheduleDescriptions {
+    schedules: HashMap<InternedScheduleLabel, Description>,
+    sets: HashMap<InternedSystemSet, Description>,
+}
+
+/// The description of a schedule or system set in the [`ScheduleDescriptions`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Description {
+    /// The description itself.
+    pub text: Cow<'static, str>,
+    /// The name of the plugin that gave the description, or `None` if it wasn't given while
+    /// building a plugin.
+    pub described_by: Option<String>,
+}
+
+impl Description {
+    /// Creates a description that wasn't given by a plugin.
+    pub fn new(text: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            text: text.into(),
+            described_by: None,
+        }
+    }
+}
+
+impl ScheduleDescriptions {
+    /// Sets the description of a schedule, returning the previous one if any.
+    ///
+    /// This replaces the description given by the `#[schedule_doc]` attribute of the label.
+    pub fn describe_schedule(
+        &mut self,
+        label: impl ScheduleLabel,
+        description: Description,
+    ) -> Option<Description> {
+        self.schedules.insert(label.intern(), description)
+    }
+
+    /// Sets the description of a system set, returning the previous one if any.
+    pub fn describe_set(
+        &mut self,
+        set: impl SystemSet,
+        description: Description,
+    ) -> Option<Description> {
+        self.sets.insert(set.intern(), description)
+    }
+
+    /// Returns the description of a schedule, falling back to the one given by the
+    /// `#[schedule_doc]` attribute of the label.
+    pub fn schedule(&self, label: impl ScheduleLabel) -> Option<&str> {
+        match self.schedules.get(&label.intern()) {
+            Some(description) => Some(&description.text),
+            None => label.doc(),
+        }
+    }
+
+    /// Returns the description of a system set.
+    pub fn set(&self, set: impl SystemSet) -> Option<&str> {
+        self.sets
+            .get(&set.intern())
+            .map(|description| &*description.text)
+    }
+
+    /// Iterates over the descriptions of schedules given with
+    /// [`describe_schedule`](Self::describe_schedule).
+    pub fn iter_schedules(&self) -> impl Iterator<Item = (InternedScheduleLabel, &Description)> {
+        self.schedules
+            .iter()
+            .map(|(label, description)| (*label, description))
+    }
+
+    /// Iterates over the descriptions of system sets.
+    pub fn iter_sets(&self) -> impl Iterator<Item = (InternedSystemSet, &Description)> {
+        self.sets
+            .iter()
+            .map(|(set, description)| (*set, description))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Description, ScheduleDescriptions};
+    use crate::schedule::{ScheduleLabel, SystemSet};
+
+    #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+    #[schedule_doc = "Runs the physics."]
+    struct Physics;
+
+    #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+    enum Network {
+        Send,
+        Receive,
+    }
+
+    #[derive(SystemSet, Clone, Debug, PartialEq, Eq, Hash)]
+    struct Movement;
+
+    #[test]
+    fn schedule_doc_attribute() {
+        assert_eq!(Physics.doc(), Some("Runs the physics."));
+        assert_eq!(Physics.intern().doc(), Some("Runs the physics."));
+        assert_eq!(Network::Send.doc(), None);
+
+        let mut descriptions = ScheduleDescriptions::default();
+        assert_eq!(descriptions.schedule(Physics), Some("Runs the physics."));
+        assert_eq!(descriptions.schedule(Network::Send), None);
+        assert_eq!(descriptions.iter_schedules().count(), 0);
+
+        // Explicit descriptions replace the attribute.
+        descriptions.describe_schedule(Physics, Description::new("Steps the simulation."));
+        assert_eq!(
+            descriptions.schedule(Physics),
+            Some("Steps the simulation.")
+        );
+    }
+
+    #[test]
+    fn describe() {
+        let mut descriptions = ScheduleDescriptions::default();
+        assert_eq!(
+            descriptions.describe_schedule(Network::Send, Description::new("Sends packets.")),
+            None
+        );
+        descriptions.describe_set(Movement, Description::new("Moves the player."));
+        let previous =
+            descriptions.describe_set(Movement, Description::new("Moves the characters."));
+
+        assert_eq!(previous, Some(Description::new("Moves the player.")));
+        assert_eq!(descriptions.schedule(Network::Send), Some("Sends packets."));
+        assert_eq!(descriptions.schedule(Network::Receive), None);
+        assert_eq!(descriptions.set(Movement), Some("Moves the characters."));
+        assert_eq!(descriptions.iter_sets().count(), 1);
+    }
+}
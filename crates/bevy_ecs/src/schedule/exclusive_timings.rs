@@ -0,0 +1,91 @@
+use alloc::vec::Vec;
+use bevy_utils::prelude::DebugName;
+use core::time::Duration;
+
+use crate::resource::Resource;
+
+/// Collects how long each exclusive system took to run, for diagnostics.
+///
+/// While this resource exists, the executors time every exclusive system they run and record the
+/// result here, as they finish. Exclusive systems stall every other system of their schedule, so
+/// long ones are a common cause of frame spikes.
+///
+/// Timings accumulate until they are [drained](Self::drain), usually once per frame by a
+/// diagnostics plugin.
+#[derive(Resource, Debug, Default)]
+pub struct ExclusiveSystemTimings {
+    timings: Vec<(DebugName, Duration)>,
+}
+
+impl ExclusiveSystemTimings {
+    /// Records that the exclusive system `name` ran for `duration`.
+    pub fn record(&mut self, name: DebugName, duration: Duration) {
+        self.timings.push((name, duration));
+    }
+
+    /// Returns the timings recorded since the last call to [`drain`](Self::drain), in the order
+    /// the systems ran.
+    pub fn timings(&self) -> &[(DebugName, Duration)] {
+        &self.timings
+    }
+
+    /// Removes and returns the recorded timings, in the order the systems ran.
+    pub fn drain(&mut self) -> impl Iterator<Item = (DebugName, Duration)> + '_ {
+        self.timings.drain(..)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ExclusiveSystemTimings;
+    use crate::{
+        prelude::*,
+        schedule::{ExecutorKind, Schedule},
+    };
+    use alloc::{
+        string::{String, ToString},
+        vec::Vec,
+    };
+
+    fn exclusive(_: &mut World) {}
+
+    fn parallel(_: Query<Entity>) {}
+
+    fn timed_systems(executor: ExecutorKind) -> Vec<String> {
+        let mut world = World::new();
+        world.init_resource::<ExclusiveSystemTimings>();
+        let mut schedule = Schedule::default();
+        schedule.set_executor_kind(executor);
+        schedule.add_systems((exclusive, parallel, exclusive.after(parallel)));
+        schedule.run(&mut world);
+        world
+            .resource_mut::<ExclusiveSystemTimings>()
+            .drain()
+            .map(|(name, _)| name.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn only_exclusive_systems_are_timed() {
+        #[expect(deprecated, reason = "We still need to support this.")]
+        let executors = [
+            ExecutorKind::SingleThreaded,
+            ExecutorKind::Simple,
+            #[cfg(feature = "multi_threaded")]
+            ExecutorKind::MultiThreaded,
+        ];
+        for executor in executors {
+            let names = timed_systems(executor);
+            assert_eq!(names.len(), 2, "{executor:?}");
+            let exclusive = IntoSystem::into_system(exclusive).name().to_string();
+            assert!(names.iter().all(|name| *name == exclusive));
+        }
+
+        // Nothing is timed without the resource.
+        let mut world = World::new();
+        let mut schedule = Schedule::default();
+        schedule.add_systems(exclusive);
+        schedule.run(&mut world);
+        assert!(!world.contains_resource::<ExclusiveSystemTimings>());
+    }
+}
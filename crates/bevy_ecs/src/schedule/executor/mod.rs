@@ -37,11 +37,17 @@ pub(super) trait SystemExecutor: Send + Sync {
         schedule: &mut SystemSchedule,
         world: &mut World,
         skip_systems: Option<&FixedBitSet>,
-        error_handler: fn(BevyError, ErrorContext),
+        error_handler: ExecutorErrorHandler<'_>,
     );
     fn set_apply_final_deferred(&mut self, value: bool);
 }
 
+/// Handles the errors of the systems and conditions run by a [`SystemExecutor`].
+///
+/// This is the world's [`DefaultErrorHandler`](crate::error::DefaultErrorHandler), unless the
+/// errors of its systems are collected by a [`SystemErrorQueue`](crate::error::SystemErrorQueue).
+pub(super) type ExecutorErrorHandler<'a> = &'a (dyn Fn(BevyError, ErrorContext) + Sync);
+
 /// Specifies how a [`Schedule`](super::Schedule) will be run.
 ///
 /// The default depends on the target platform:
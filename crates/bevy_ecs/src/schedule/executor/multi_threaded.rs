@@ -1,9 +1,10 @@
 use alloc::{boxed::Box, vec::Vec};
 use bevy_platform::cell::SyncUnsafeCell;
 use bevy_platform::sync::Arc;
+use bevy_platform::time::Instant;
 use bevy_tasks::{ComputeTaskPool, Scope, TaskPool, ThreadExecutor};
 use concurrent_queue::ConcurrentQueue;
-use core::{any::Any, panic::AssertUnwindSafe};
+use core::{any::Any, panic::AssertUnwindSafe, time::Duration};
 use fixedbitset::FixedBitSet;
 #[cfg(feature = "std")]
 use std::eprintln;
@@ -13,11 +14,11 @@ use std::sync::{Mutex, MutexGuard};
 use tracing::{info_span, Span};
 
 use crate::{
-    error::{ErrorContext, ErrorHandler, Result},
+    error::{ErrorContext, Result},
     prelude::Resource,
     schedule::{
-        is_apply_deferred, ConditionWithAccess, ExecutorKind, SystemExecutor, SystemSchedule,
-        SystemWithAccess,
+        is_apply_deferred, ConditionWithAccess, ExecutorErrorHandler, ExecutorKind, SystemExecutor,
+        SystemSchedule, SystemTimer, SystemTimings, SystemWithAccess,
     },
     system::{RunSystemError, ScheduleSystem},
     world::{unsafe_world_cell::UnsafeWorldCell, World},
@@ -33,6 +34,8 @@ struct Environment<'env, 'sys> {
     systems: &'sys [SyncUnsafeCell<SystemWithAccess>],
     conditions: SyncUnsafeCell<Conditions<'sys>>,
     world_cell: UnsafeWorldCell<'env>,
+    /// Whether the non-exclusive systems are timed for the [`SystemTimings`].
+    time_systems: bool,
 }
 
 struct Conditions<'a> {
@@ -57,6 +60,7 @@ impl<'env, 'sys> Environment<'env, 'sys> {
                 sets_with_conditions_of_systems: &schedule.sets_with_conditions_of_systems,
                 systems_in_sets_with_conditions: &schedule.systems_in_sets_with_conditions,
             }),
+            time_systems: world.contains_resource::<SystemTimings>(),
             world_cell: world.as_unsafe_world_cell(),
         }
     }
@@ -83,6 +87,8 @@ struct SystemTaskMetadata {
 /// The result of running a system that is sent across a channel.
 struct SystemResult {
     system_index: usize,
+    /// How long the system ran, if it was timed.
+    duration: Option<Duration>,
 }
 
 /// Runs the schedule using a thread pool. Non-conflicting systems can run in parallel.
@@ -129,6 +135,9 @@ pub struct ExecutorState {
     completed_systems: FixedBitSet,
     /// Systems that have run but have not had their buffers applied.
     unapplied_systems: FixedBitSet,
+    /// How long the timed non-exclusive systems ran, recorded in the [`SystemTimings`] once the
+    /// schedule ends.
+    system_timings: Vec<(usize, Duration)>,
 }
 
 /// References to data required by the executor.
@@ -139,7 +148,7 @@ pub struct ExecutorState {
 struct Context<'scope, 'env, 'sys> {
     environment: &'env Environment<'env, 'sys>,
     scope: &'scope Scope<'scope, 'env, ()>,
-    error_handler: ErrorHandler,
+    error_handler: ExecutorErrorHandler<'env>,
 }
 
 impl Default for MultiThreadedExecutor {
@@ -239,8 +248,8 @@ impl SystemExecutor for MultiThreadedExecutor {
         &mut self,
         schedule: &mut SystemSchedule,
         world: &mut World,
-        _skip_systems: Option<&FixedBitSet>,
-        error_handler: ErrorHandler,
+        skip_systems: Option<&FixedBitSet>,
+        error_handler: ExecutorErrorHandler<'_>,
     ) {
         let state = self.state.get_mut().unwrap();
         // reset counts
@@ -253,10 +262,8 @@ impl SystemExecutor for MultiThreadedExecutor {
             .clone_from(&schedule.system_dependencies);
         state.ready_systems.clone_from(&self.starting_systems);
 
-        // If stepping is enabled, make sure we skip those systems that should
-        // not be run.
-        #[cfg(feature = "bevy_debug_stepping")]
-        if let Some(skipped_systems) = _skip_systems {
+        // Skip the systems that should not be run, for stepping or `Schedule::run_sets`.
+        if let Some(skipped_systems) = skip_systems {
             debug_assert_eq!(skipped_systems.len(), state.completed_systems.len());
             // mark skipped systems as completed
             state.completed_systems |= skipped_systems;
@@ -307,6 +314,14 @@ impl SystemExecutor for MultiThreadedExecutor {
             state.unapplied_systems.clear();
         }
 
+        if let Some(mut timings) = world.get_resource_mut::<SystemTimings>() {
+            for (system_index, duration) in state.system_timings.drain(..) {
+                timings.record(schedule.systems[system_index].system.name(), duration);
+            }
+        } else {
+            state.system_timings.clear();
+        }
+
         // check to see if there was a panic
         let payload = self.panic_payload.get_mut().unwrap();
         if let Some(payload) = payload.take() {
@@ -331,12 +346,16 @@ impl<'scope, 'env: 'scope, 'sys> Context<'scope, 'env, 'sys> {
         system_index: usize,
         res: Result<(), Box<dyn Any + Send>>,
         system: &ScheduleSystem,
+        duration: Option<Duration>,
     ) {
         // tell the executor that the system finished
         self.environment
             .executor
             .system_completion
-            .push(SystemResult { system_index })
+            .push(SystemResult {
+                system_index,
+                duration,
+            })
             .unwrap_or_else(|error| unreachable!("{}", error));
         if let Err(payload) = res {
             #[cfg(feature = "std")]
@@ -418,12 +437,14 @@ impl ExecutorState {
             skipped_systems: FixedBitSet::new(),
             completed_systems: FixedBitSet::new(),
             unapplied_systems: FixedBitSet::new(),
+            system_timings: Vec::new(),
         }
     }
 
     fn tick(&mut self, context: &Context, conditions: &mut Conditions) {
         #[cfg(feature = "trace")]
-        let _span = context.environment.executor.executor_span.enter();
+        let _span = crate::schedule::schedule_spans()
+            .then(|| context.environment.executor.executor_span.enter());
 
         for result in context.environment.executor.system_completion.try_iter() {
             self.finish_system_and_handle_dependents(result);
@@ -583,7 +604,7 @@ impl ExecutorState {
         system: &mut ScheduleSystem,
         conditions: &mut Conditions,
         world: UnsafeWorldCell,
-        error_handler: ErrorHandler,
+        error_handler: ExecutorErrorHandler<'_>,
     ) -> bool {
         let mut should_run = !self.skipped_systems.contains(system_index);
 
@@ -674,6 +695,7 @@ impl ExecutorState {
         let system_meta = &self.system_task_metadata[system_index];
 
         let task = async move {
+            let start = context.environment.time_systems.then(Instant::now);
             let res = std::panic::catch_unwind(AssertUnwindSafe(|| {
                 // SAFETY:
                 // - The caller ensures that we have permission to
@@ -696,7 +718,8 @@ impl ExecutorState {
                     }
                 };
             }));
-            context.system_completed(system_index, res, system);
+            let duration = start.map(|start| start.elapsed());
+            context.system_completed(system_index, res, system, duration);
         };
 
         if system_meta.is_send {
@@ -724,7 +747,7 @@ impl ExecutorState {
                 // that no other systems currently have access to the world.
                 let world = unsafe { context.environment.world_cell.world_mut() };
                 let res = apply_deferred(&unapplied_systems, context.environment.systems, world);
-                context.system_completed(system_index, res, system);
+                context.system_completed(system_index, res, system, None);
             };
 
             context.scope.spawn_on_scope(task);
@@ -733,6 +756,7 @@ impl ExecutorState {
                 // SAFETY: `can_run` returned true for this system, which means
                 // that no other systems currently have access to the world.
                 let world = unsafe { context.environment.world_cell.world_mut() };
+                let timer = SystemTimer::start(&**system, world);
                 let res = std::panic::catch_unwind(AssertUnwindSafe(|| {
                     if let Err(RunSystemError::Failed(err)) =
                         __rust_begin_short_backtrace::run(system, world)
@@ -746,7 +770,8 @@ impl ExecutorState {
                         );
                     }
                 }));
-                context.system_completed(system_index, res, system);
+                timer.finish(&**system, world);
+                context.system_completed(system_index, res, system, None);
             };
 
             context.scope.spawn_on_scope(task);
@@ -757,7 +782,14 @@ impl ExecutorState {
     }
 
     fn finish_system_and_handle_dependents(&mut self, result: SystemResult) {
-        let SystemResult { system_index, .. } = result;
+        let SystemResult {
+            system_index,
+            duration,
+        } = result;
+
+        if let Some(duration) = duration {
+            self.system_timings.push((system_index, duration));
+        }
 
         if self.system_task_metadata[system_index].is_exclusive {
             self.exclusive_running = false;
@@ -825,7 +857,7 @@ fn apply_deferred(
 unsafe fn evaluate_and_fold_conditions(
     conditions: &mut [ConditionWithAccess],
     world: UnsafeWorldCell,
-    error_handler: ErrorHandler,
+    error_handler: ExecutorErrorHandler<'_>,
 ) -> bool {
     #[expect(
         clippy::unnecessary_fold,
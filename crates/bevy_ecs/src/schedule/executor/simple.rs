@@ -12,10 +12,10 @@ use std::eprintln;
 #[cfg(feature = "hotpatching")]
 use crate::{change_detection::DetectChanges, HotPatchChanges};
 use crate::{
-    error::{ErrorContext, ErrorHandler},
+    error::ErrorContext,
     schedule::{
-        executor::is_apply_deferred, ConditionWithAccess, ExecutorKind, SystemExecutor,
-        SystemSchedule,
+        executor::is_apply_deferred, ConditionWithAccess, ExecutorErrorHandler, ExecutorKind,
+        SystemExecutor, SystemSchedule, SystemTimer,
     },
     system::RunSystemError,
     world::World,
@@ -53,13 +53,11 @@ impl SystemExecutor for SimpleExecutor {
         &mut self,
         schedule: &mut SystemSchedule,
         world: &mut World,
-        _skip_systems: Option<&FixedBitSet>,
-        error_handler: ErrorHandler,
+        skip_systems: Option<&FixedBitSet>,
+        error_handler: ExecutorErrorHandler<'_>,
     ) {
-        // If stepping is enabled, make sure we skip those systems that should
-        // not be run.
-        #[cfg(feature = "bevy_debug_stepping")]
-        if let Some(skipped_systems) = _skip_systems {
+        // Skip the systems that should not be run, for stepping or `Schedule::run_sets`.
+        if let Some(skipped_systems) = skip_systems {
             // mark skipped systems as completed
             self.completed_systems |= skipped_systems;
         }
@@ -74,7 +72,8 @@ impl SystemExecutor for SimpleExecutor {
             #[cfg(feature = "trace")]
             let name = schedule.systems[system_index].system.name();
             #[cfg(feature = "trace")]
-            let should_run_span = info_span!("check_conditions", name = name.as_string()).entered();
+            let should_run_span = crate::schedule::system_spans()
+                .then(|| info_span!("check_conditions", name = name.as_string()).entered());
 
             let mut should_run = !self.completed_systems.contains(system_index);
             for set_idx in schedule.sets_with_conditions_of_systems[system_index].ones() {
@@ -110,7 +109,7 @@ impl SystemExecutor for SimpleExecutor {
             let system = &mut schedule.systems[system_index].system;
 
             #[cfg(feature = "trace")]
-            should_run_span.exit();
+            drop(should_run_span);
 
             #[cfg(feature = "hotpatching")]
             if hotpatch_tick.is_newer_than(system.get_last_run(), world.change_tick()) {
@@ -128,6 +127,7 @@ impl SystemExecutor for SimpleExecutor {
                 continue;
             }
 
+            let timer = SystemTimer::start(&**system, world);
             let f = AssertUnwindSafe(|| {
                 if let Err(RunSystemError::Failed(err)) =
                     __rust_begin_short_backtrace::run(system, world)
@@ -155,6 +155,8 @@ impl SystemExecutor for SimpleExecutor {
             {
                 (f)();
             }
+
+            timer.finish(&**system, world);
         }
 
         self.evaluated_sets.clear();
@@ -183,7 +185,7 @@ impl SimpleExecutor {
 fn evaluate_and_fold_conditions(
     conditions: &mut [ConditionWithAccess],
     world: &mut World,
-    error_handler: ErrorHandler,
+    error_handler: ExecutorErrorHandler<'_>,
 ) -> bool {
     #[cfg(feature = "hotpatching")]
     let hotpatch_tick = world
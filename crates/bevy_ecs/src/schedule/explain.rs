@@ -0,0 +1,568 @@
+use alloc::{
+    borrow::ToOwned,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use bevy_utils::prelude::DebugName;
+use core::fmt;
+use thiserror::Error;
+
+use crate::{
+    schedule::{
+        executor::is_apply_deferred, graph::Direction, ConditionWithAccess, InternedScheduleLabel,
+        NodeId, Schedule, ScheduleBuildError, ScheduleGraph, ScheduleLabel, Schedules, SystemKey,
+    },
+    world::{error::TryRunScheduleError, World},
+};
+
+/// Which systems of a schedule would run if it ran now, and why, returned by
+/// [`Schedule::explain`].
+///
+/// Its [`Display`](fmt::Display) implementation lists the systems by set, for example:
+///
+/// ```text
+/// Update:
+///   GameplaySet:
+///     run     move_player
+///     skip    spawn_enemies: `in_state<GameState>` of GameplaySet is false
+///   (no set):
+///     unknown autosave: `on_timer` can't be evaluated without mutating the world
+/// ```
+#[derive(Debug, Clone)]
+pub struct FrameExplanation {
+    /// The label of the explained schedule.
+    pub schedule: InternedScheduleLabel,
+    /// The systems of the schedule grouped by set, in the order the groups first appear in the
+    /// schedule.
+    pub groups: Vec<ExplainedSet>,
+}
+
+/// The systems of a [`FrameExplanation`] in the same system set.
+#[derive(Debug, Clone)]
+pub struct ExplainedSet {
+    /// The name of the set, or `None` for the systems that aren't in a named set.
+    ///
+    /// Systems in several sets are grouped in the first set they were added to.
+    pub set: Option<String>,
+    /// The systems of the set, in the order of the schedule.
+    pub systems: Vec<ExplainedSystem>,
+}
+
+/// A system of a [`FrameExplanation`].
+#[derive(Debug, Clone)]
+pub struct ExplainedSystem {
+    /// The name of the system.
+    pub name: DebugName,
+    /// Whether the system would run.
+    pub verdict: SystemVerdict,
+}
+
+/// Whether a system of a [`FrameExplanation`] would run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SystemVerdict {
+    /// All the conditions of the system and of its sets are met.
+    Run,
+    /// The system would be skipped because a condition isn't met.
+    Skipped {
+        /// The name of the first condition that isn't met.
+        condition: DebugName,
+        /// The name of the set the condition was added to, or `None` if it was added to the
+        /// system itself.
+        set: Option<String>,
+    },
+    /// All the evaluated conditions are met, but some conditions couldn't be evaluated without
+    /// mutating the world.
+    Unknown {
+        /// The names of the conditions that weren't evaluated.
+        conditions: Vec<DebugName>,
+    },
+}
+
+impl FrameExplanation {
+    /// Iterates over the systems of the schedule, set by set.
+    pub fn systems(&self) -> impl Iterator<Item = &ExplainedSystem> {
+        self.groups.iter().flat_map(|group| &group.systems)
+    }
+
+    /// Iterates over the systems that would run.
+    pub fn would_run(&self) -> impl Iterator<Item = &ExplainedSystem> {
+        self.systems()
+            .filter(|system| system.verdict == SystemVerdict::Run)
+    }
+
+    fn push(&mut self, set: Option<String>, system: ExplainedSystem) {
+        match self.groups.iter_mut().find(|group| group.set == set) {
+            Some(group) => group.systems.push(system),
+            None => self.groups.push(ExplainedSet {
+                set,
+                systems: Vec::from([system]),
+            }),
+        }
+    }
+}
+
+impl fmt::Display for FrameExplanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{:?}:", self.schedule)?;
+        for group in &self.groups {
+            writeln!(f, "  {}:", group.set.as_deref().unwrap_or("(no set)"))?;
+            for system in &group.systems {
+                writeln!(f, "    {system}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for ExplainedSystem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = self.name.shortname();
+        match &self.verdict {
+            SystemVerdict::Run => write!(f, "run     {name}"),
+            SystemVerdict::Skipped {
+                condition,
+                set: Some(set),
+            } => write!(
+                f,
+                "skip    {name}: `{}` of {set} is false",
+                condition.shortname()
+            ),
+            SystemVerdict::Skipped {
+                condition,
+                set: None,
+            } => write!(f, "skip    {name}: `{}` is false", condition.shortname()),
+            SystemVerdict::Unknown { conditions } => {
+                let conditions = conditions
+                    .iter()
+                    .map(|condition| format!("`{}`", condition.shortname()))
+                    .collect::<Vec<_>>();
+                write!(
+                    f,
+                    "unknown {name}: {} can't be evaluated without mutating the world",
+                    conditions.join(", ")
+                )
+            }
+        }
+    }
+}
+
+/// An error returned by [`World::explain_schedule`] and [`World::run_explain_command`].
+#[derive(Error, Debug)]
+pub enum ExplainError {
+    /// The command isn't an `explain <schedule>` command.
+    #[error("Expected `explain <schedule>`, got `{0}`")]
+    InvalidCommand(String),
+    /// No schedule of the world is named like the one given to the command.
+    #[error("No schedule is named `{0}`")]
+    UnknownSchedule(String),
+    /// The schedule doesn't exist.
+    #[error(transparent)]
+    MissingSchedule(#[from] TryRunScheduleError),
+    /// The schedule couldn't be built.
+    #[error(transparent)]
+    Build(#[from] ScheduleBuildError),
+}
+
+/// The result of each condition in a dry run: `None` if it wasn't evaluated.
+type ConditionResults = Vec<(DebugName, Option<bool>)>;
+
+impl Schedule {
+    /// Explains which systems would run if this schedule ran now on `world`, without running
+    /// them.
+    ///
+    /// The run conditions of the systems and of their sets are evaluated against the current
+    /// state of the world, and the [`FrameExplanation`] tells which systems would run, which would
+    /// be skipped and by which condition. As when the schedule runs, a condition that fails
+    /// is considered not met.
+    ///
+    /// Conditions with deferred mutations, for example the ones using
+    /// [`Commands`](crate::system::Commands), are not evaluated, and the systems depending on them
+    /// are reported as [`SystemVerdict::Unknown`]. The change ticks of the evaluated conditions
+    /// are restored, so they see the same changes when the schedule actually runs, but other
+    /// state kept by a condition, such as the cursor of the reader of
+    /// [`on_event`](crate::schedule::common_conditions::on_event), is updated by the dry run.
+    pub fn explain(&mut self, world: &mut World) -> Result<FrameExplanation, ScheduleBuildError> {
+        self.initialize(world)?;
+
+        let graph = self.graph();
+        let executable = self.executable();
+        let set_names = executable
+            .set_ids
+            .iter()
+            .map(|&key| graph.get_node_name(&NodeId::Set(key)))
+            .collect::<Vec<_>>();
+        let system_groups = executable
+            .system_ids
+            .iter()
+            .map(|&key| group_of(graph, key))
+            .collect::<Vec<_>>();
+
+        let mut explanation = FrameExplanation {
+            schedule: self.label(),
+            groups: Vec::new(),
+        };
+        let world = &*world;
+        let executable = self.executable_mut();
+        let set_results = executable
+            .set_conditions
+            .iter_mut()
+            .map(|conditions| evaluate(conditions, world))
+            .collect::<Vec<_>>();
+
+        for (index, group) in system_groups.into_iter().enumerate() {
+            let system = &executable.systems[index].system;
+            if is_apply_deferred(&**system) {
+                continue;
+            }
+
+            let mut verdict = None;
+            let mut unknown = Vec::new();
+            for set_index in executable.sets_with_conditions_of_systems[index].ones() {
+                let set = Some(&set_names[set_index]);
+                fold(&set_results[set_index], set, &mut verdict, &mut unknown);
+            }
+            let results = evaluate(&mut executable.system_conditions[index], world);
+            fold(&results, None, &mut verdict, &mut unknown);
+
+            let verdict = verdict.unwrap_or(if unknown.is_empty() {
+                SystemVerdict::Run
+            } else {
+                SystemVerdict::Unknown {
+                    conditions: unknown,
+                }
+            });
+            let name = executable.systems[index].system.name();
+            explanation.push(group, ExplainedSystem { name, verdict });
+        }
+
+        Ok(explanation)
+    }
+}
+
+/// Returns the name of the first named set containing the system.
+fn group_of(graph: &ScheduleGraph, key: SystemKey) -> Option<String> {
+    graph
+        .hierarchy()
+        .graph()
+        .neighbors_directed(NodeId::System(key), Direction::Incoming)
+        .filter_map(|node| match node {
+            NodeId::Set(key) => Some(key),
+            NodeId::System(_) => None,
+        })
+        .find(|&key| {
+            graph
+                .system_sets
+                .get(key)
+                .is_some_and(|set| set.system_type().is_none() && !set.is_anonymous())
+        })
+        .map(|key| graph.get_node_name(&NodeId::Set(key)))
+}
+
+/// Evaluates the conditions that don't mutate the world, restoring their change ticks.
+fn evaluate(conditions: &mut [ConditionWithAccess], world: &World) -> ConditionResults {
+    conditions
+        .iter_mut()
+        .map(|ConditionWithAccess { condition, .. }| {
+            let name = condition.name();
+            if condition.is_exclusive() || condition.has_deferred() {
+                return (name, None);
+            }
+            let last_run = condition.get_last_run();
+            let met = condition.run_readonly((), world).unwrap_or(false);
+            condition.set_last_run(last_run);
+            (name, Some(met))
+        })
+        .collect()
+}
+
+/// Records the first condition that isn't met as the verdict, and the unknown conditions.
+fn fold(
+    results: &ConditionResults,
+    set: Option<&String>,
+    verdict: &mut Option<SystemVerdict>,
+    unknown: &mut Vec<DebugName>,
+) {
+    for (condition, met) in results {
+        match met {
+            Some(true) => {}
+            Some(false) => {
+                verdict.get_or_insert_with(|| SystemVerdict::Skipped {
+                    condition: condition.clone(),
+                    set: set.cloned(),
+                });
+            }
+            None => unknown.push(condition.clone()),
+        }
+    }
+}
+
+impl World {
+    /// Explains which systems of the schedule would run if it ran now, without running them, see
+    /// [`Schedule::explain`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the schedule doesn't exist or can't be built.
+    pub fn explain_schedule(
+        &mut self,
+        label: impl ScheduleLabel,
+    ) -> Result<FrameExplanation, ExplainError> {
+        Ok(self.try_schedule_scope(label, |world, schedule| schedule.explain(world))??)
+    }
+
+    /// Runs an `explain` console command, such as `explain Update`, and returns the text to print.
+    ///
+    /// The schedule is found by the [`Debug`](fmt::Debug) output of its label, and the
+    /// explanation is rendered with its [`Display`](fmt::Display) implementation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the command isn't an `explain` command, if no schedule has this name,
+    /// or if [`World::explain_schedule`] fails.
+    pub fn run_explain_command(&mut self, command: &str) -> Result<String, ExplainError> {
+        let command = command.trim();
+        let name = command
+            .strip_prefix("explain")
+            .filter(|name| name.starts_with(char::is_whitespace))
+            .map(str::trim)
+            .ok_or_else(|| ExplainError::InvalidCommand(command.to_owned()))?;
+        let label = self
+            .get_resource::<Schedules>()
+            .and_then(|schedules| {
+                schedules
+                    .iter()
+                    .find(|(label, _)| format!("{label:?}") == name)
+                    .map(|(_, schedule)| schedule.label())
+            })
+            .ok_or_else(|| ExplainError::UnknownSchedule(name.to_owned()))?;
+        Ok(self.explain_schedule(label)?.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ExplainError, FrameExplanation, SystemVerdict};
+    use crate::{
+        prelude::*,
+        schedule::{common_conditions::resource_changed, ScheduleLabel},
+        system::ScheduleSystem,
+    };
+    use alloc::{string::ToString, vec::Vec};
+    use bevy_utils::prelude::DebugName;
+
+    #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+    struct Update;
+
+    #[derive(SystemSet, Clone, Debug, PartialEq, Eq, Hash)]
+    struct Gameplay;
+
+    #[derive(SystemSet, Clone, Debug, PartialEq, Eq, Hash)]
+    struct Ui;
+
+    #[derive(Resource)]
+    struct Paused(bool);
+
+    #[derive(Resource, Default)]
+    struct Ran(usize);
+
+    fn paused(paused: Res<Paused>) -> bool {
+        paused.0
+    }
+
+    fn running(paused: Res<Paused>) -> bool {
+        !paused.0
+    }
+
+    fn spawns(_: Commands) -> bool {
+        true
+    }
+
+    fn a(mut ran: ResMut<Ran>) {
+        ran.0 += 1;
+    }
+
+    fn b(mut ran: ResMut<Ran>) {
+        ran.0 += 1;
+    }
+
+    fn c(mut ran: ResMut<Ran>) {
+        ran.0 += 1;
+    }
+
+    fn name<M>(system: impl IntoSystem<(), bool, M>) -> DebugName {
+        IntoSystem::into_system(system).name()
+    }
+
+    fn system_name<M>(system: impl IntoScheduleConfigs<ScheduleSystem, M>) -> DebugName {
+        let mut schedule = Schedule::default();
+        schedule.add_systems(system);
+        let mut world = World::new();
+        schedule.initialize(&mut world).unwrap();
+        schedule.systems().unwrap().next().unwrap().1.name()
+    }
+
+    fn verdict(explanation: &FrameExplanation, system: DebugName) -> &SystemVerdict {
+        &explanation
+            .systems()
+            .find(|explained| explained.name == system)
+            .unwrap()
+            .verdict
+    }
+
+    fn world() -> World {
+        let mut world = World::new();
+        world.insert_resource(Paused(true));
+        world.init_resource::<Ran>();
+        world
+    }
+
+    #[test]
+    fn skip_attribution() {
+        let mut world = world();
+        let mut schedule = Schedule::new(Update);
+        schedule
+            .configure_sets(Gameplay.run_if(running))
+            .add_systems((
+                a.run_if(paused),
+                b.run_if(running),
+                c.in_set(Gameplay).run_if(paused),
+            ));
+
+        let explanation = schedule.explain(&mut world).unwrap();
+        assert_eq!(verdict(&explanation, system_name(a)), &SystemVerdict::Run);
+        assert_eq!(
+            verdict(&explanation, system_name(b)),
+            &SystemVerdict::Skipped {
+                condition: name(running),
+                set: None,
+            }
+        );
+        // The conditions of the sets are attributed to the set.
+        assert_eq!(
+            verdict(&explanation, system_name(c)),
+            &SystemVerdict::Skipped {
+                condition: name(running),
+                set: Some("Gameplay".to_string()),
+            }
+        );
+        assert_eq!(explanation.would_run().count(), 1);
+        // No system ran.
+        assert_eq!(world.resource::<Ran>().0, 0);
+
+        world.resource_mut::<Paused>().0 = false;
+        let explanation = schedule.explain(&mut world).unwrap();
+        assert_eq!(
+            verdict(&explanation, system_name(c)),
+            &SystemVerdict::Skipped {
+                condition: name(paused),
+                set: None,
+            }
+        );
+    }
+
+    #[test]
+    fn change_ticks_are_restored() {
+        let mut world = world();
+        let mut schedule = Schedule::new(Update);
+        schedule.add_systems(a.run_if(resource_changed::<Paused>));
+
+        for _ in 0..2 {
+            let explanation = schedule.explain(&mut world).unwrap();
+            assert_eq!(explanation.would_run().count(), 1);
+        }
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<Ran>().0, 1);
+        let explanation = schedule.explain(&mut world).unwrap();
+        assert_eq!(explanation.would_run().count(), 0);
+    }
+
+    #[test]
+    fn unknown_for_mutating_conditions() {
+        let mut world = world();
+        let mut schedule = Schedule::new(Update);
+        schedule.add_systems((
+            a.run_if(spawns),
+            b.run_if(spawns).run_if(running),
+            c.run_if(paused).run_if(spawns),
+        ));
+
+        let explanation = schedule.explain(&mut world).unwrap();
+        assert_eq!(
+            verdict(&explanation, system_name(a)),
+            &SystemVerdict::Unknown {
+                conditions: Vec::from([name(spawns)]),
+            }
+        );
+        // A condition that isn't met is enough to know the system is skipped.
+        assert_eq!(
+            verdict(&explanation, system_name(b)),
+            &SystemVerdict::Skipped {
+                condition: name(running),
+                set: None,
+            }
+        );
+        assert!(matches!(
+            verdict(&explanation, system_name(c)),
+            SystemVerdict::Unknown { .. }
+        ));
+        // The commands of the condition weren't queued.
+        assert_eq!(world.entities().len(), 0);
+    }
+
+    #[test]
+    fn grouping() {
+        let mut world = world();
+        let mut schedule = Schedule::new(Update);
+        schedule.configure_sets(Ui.after(Gameplay)).add_systems((
+            a.in_set(Ui),
+            b,
+            c.in_set(Gameplay),
+        ));
+
+        let explanation = schedule.explain(&mut world).unwrap();
+        let groups = explanation
+            .groups
+            .iter()
+            .map(|group| (group.set.as_deref(), group.systems.len()))
+            .collect::<Vec<_>>();
+        assert_eq!(groups.len(), 3);
+        assert!(groups.contains(&(Some("Gameplay"), 1)));
+        assert!(groups.contains(&(Some("Ui"), 1)));
+        assert!(groups.contains(&(None, 1)));
+        let gameplay = groups.iter().position(|(set, _)| *set == Some("Gameplay"));
+        let ui = groups.iter().position(|(set, _)| *set == Some("Ui"));
+        assert!(gameplay < ui);
+    }
+
+    #[test]
+    fn console_command() {
+        let mut world = world();
+        world.add_schedule(Schedule::new(Update));
+        world.resource_mut::<Schedules>().add_systems(
+            Update,
+            (
+                a.in_set(Gameplay).run_if(paused),
+                b.run_if(spawns).after(Gameplay),
+            ),
+        );
+
+        let output = world.run_explain_command("explain Update").unwrap();
+        assert!(output.starts_with("Update:\n  Gameplay:\n    run     "));
+        assert!(output.contains("\n  (no set):\n    unknown "));
+        assert!(output.ends_with(" can't be evaluated without mutating the world\n"));
+
+        assert!(matches!(
+            world.run_explain_command("explain Render"),
+            Err(ExplainError::UnknownSchedule(name)) if name == "Render"
+        ));
+        assert!(matches!(
+            world.run_explain_command("explainUpdate"),
+            Err(ExplainError::InvalidCommand(_))
+        ));
+        assert!(matches!(
+            world.run_explain_command("query With<Paused>"),
+            Err(ExplainError::InvalidCommand(_))
+        ));
+    }
+}
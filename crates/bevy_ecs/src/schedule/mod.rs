@@ -3,17 +3,27 @@
 mod auto_insert_apply_deferred;
 mod condition;
 mod config;
+mod description;
 mod error;
+mod exclusive_timings;
+mod explain;
 mod executor;
 mod node;
 mod pass;
 mod schedule;
 mod set;
 mod stepping;
+mod system_timings;
+mod trace_spans;
 
 pub use self::graph::GraphInfo;
 use self::graph::*;
-pub use self::{condition::*, config::*, error::*, executor::*, node::*, schedule::*, set::*};
+#[cfg(feature = "trace")]
+pub(crate) use self::trace_spans::{command_spans, schedule_spans, system_spans};
+pub use self::{
+    condition::*, config::*, description::*, error::*, exclusive_timings::*, executor::*,
+    explain::*, node::*, schedule::*, set::*, system_timings::*, trace_spans::TraceSpanConfig,
+};
 pub use pass::ScheduleBuildPass;
 
 /// An implementation of a graph data structure.
@@ -106,6 +116,56 @@ mod tests {
             assert_eq!(world.resource::<SystemOrder>().0, vec![0]);
         }
 
+        #[test]
+        fn run_sets() {
+            let mut world = World::default();
+            let mut schedule = Schedule::default();
+
+            world.init_resource::<SystemOrder>();
+
+            schedule.configure_sets(TestSystems::B.in_set(TestSystems::A));
+            schedule.add_systems((
+                make_function_system(0),
+                make_function_system(1).in_set(TestSystems::A),
+                make_function_system(2).in_set(TestSystems::B),
+                make_exclusive_system(3).in_set(TestSystems::C),
+            ));
+            schedule.add_systems(
+                make_function_system(4)
+                    .in_set(TestSystems::C)
+                    .run_if(|| false),
+            );
+            schedule.run_sets(
+                &mut world,
+                &[TestSystems::A.intern(), TestSystems::C.intern()],
+            );
+
+            let mut order = world.resource::<SystemOrder>().0.clone();
+            order.sort();
+            assert_eq!(order, vec![1, 2, 3]);
+
+            schedule.run(&mut world);
+            assert_eq!(world.resource::<SystemOrder>().0.len(), 7);
+
+            // The systems added after a run are included once the schedule is rebuilt.
+            world.resource_mut::<SystemOrder>().0.clear();
+            schedule.add_systems(make_function_system(5).in_set(TestSystems::B));
+            schedule.run_sets(
+                &mut world,
+                &[TestSystems::A.intern(), TestSystems::C.intern()],
+            );
+            let mut order = world.resource::<SystemOrder>().0.clone();
+            order.sort();
+            assert_eq!(order, vec![1, 2, 3, 5]);
+
+            // Other sets get their own skipped systems.
+            world.resource_mut::<SystemOrder>().0.clear();
+            schedule.run_sets(&mut world, &[TestSystems::B.intern()]);
+            let mut order = world.resource::<SystemOrder>().0.clone();
+            order.sort();
+            assert_eq!(order, vec![2, 5]);
+        }
+
         #[test]
         #[cfg(not(miri))]
         fn parallel_execution() {
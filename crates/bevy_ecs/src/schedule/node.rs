@@ -477,6 +477,20 @@ impl Systems {
         self.nodes.is_empty()
     }
 
+    /// Returns `true` if a system with the given key exists in this container.
+    ///
+    /// Unlike [`Systems::get`], this also returns `true` for systems that are
+    /// currently stored in the executable schedule.
+    pub fn contains(&self, key: SystemKey) -> bool {
+        self.nodes.contains_key(key)
+    }
+
+    /// Returns an iterator over the keys of all systems in this container,
+    /// including the ones that are currently stored in the executable schedule.
+    pub fn keys(&self) -> impl Iterator<Item = SystemKey> + '_ {
+        self.nodes.keys()
+    }
+
     /// Returns a reference to the system with the given key, if it exists.
     pub fn get(&self, key: SystemKey) -> Option<&SystemWithAccess> {
         self.nodes.get(key).and_then(|node| node.get())
@@ -554,6 +568,14 @@ impl Systems {
         key
     }
 
+    /// Removes the system with the given key from this container, along with its conditions.
+    pub(crate) fn remove(&mut self, key: SystemKey) -> Option<SystemNode> {
+        let node = self.nodes.remove(key)?;
+        self.conditions.remove(key);
+        self.uninit.retain(|&uninit| uninit != key);
+        Some(node)
+    }
+
     /// Returns `true` if all systems in this container have been initialized.
     pub fn is_initialized(&self) -> bool {
         self.uninit.is_empty()
@@ -646,6 +668,11 @@ impl SystemSets {
         self.sets.get(key).map(|set| &**set)
     }
 
+    /// Returns the key for the given system set, if it is present in this container.
+    pub fn get_key(&self, set: InternedSystemSet) -> Option<SystemSetKey> {
+        self.ids.get(&set).copied()
+    }
+
     /// Returns the key for the given system set, inserting it into this
     /// container if it does not already exist.
     pub fn get_key_or_insert(&mut self, set: InternedSystemSet) -> SystemSetKey {
@@ -26,6 +26,7 @@ use tracing::info_span;
 use crate::{component::CheckChangeTicks, system::System};
 use crate::{
     component::{ComponentId, Components},
+    error::{BevyError, ErrorContext, QueuedSystemError, SystemErrorQueue},
     prelude::Component,
     resource::Resource,
     schedule::*,
@@ -333,6 +334,9 @@ pub struct Schedule {
     executor: Box<dyn SystemExecutor>,
     executor_initialized: bool,
     warnings: Vec<ScheduleBuildWarning>,
+    /// The sets of the last [`Schedule::run_sets`] and the systems outside of them, kept until
+    /// the executable schedule is rebuilt.
+    systems_outside_sets: Option<(Vec<InternedSystemSet>, FixedBitSet)>,
 }
 
 #[derive(ScheduleLabel, Hash, PartialEq, Eq, Debug, Clone)]
@@ -358,6 +362,7 @@ impl Schedule {
             executor: make_executor(ExecutorKind::default()),
             executor_initialized: false,
             warnings: Vec::new(),
+            systems_outside_sets: None,
         };
         // Call `set_build_settings` to add any default build passes
         this.set_build_settings(Default::default());
@@ -474,8 +479,21 @@ impl Schedule {
 
     /// Runs all systems in this schedule on the `world`, using its current execution strategy.
     pub fn run(&mut self, world: &mut World) {
+        self.run_inner(world, None);
+    }
+
+    /// Runs the systems of this schedule that are in one of the `sets`, directly or through nested
+    /// sets, and skips the others.
+    ///
+    /// The systems that run keep their ordering and run conditions. This is useful to keep a
+    /// part of a schedule running while the rest of it is paused.
+    pub fn run_sets(&mut self, world: &mut World, sets: &[InternedSystemSet]) {
+        self.run_inner(world, Some(sets));
+    }
+
+    fn run_inner(&mut self, world: &mut World, sets: Option<&[InternedSystemSet]>) {
         #[cfg(feature = "trace")]
-        let _span = info_span!("schedule", name = ?self.label).entered();
+        let _span = schedule_spans().then(|| info_span!("schedule", name = ?self.label).entered());
 
         world.check_change_ticks();
         self.initialize(world).unwrap_or_else(|e| {
@@ -486,26 +504,82 @@ impl Schedule {
             )
         });
 
-        let error_handler = world.default_error_handler();
-
-        #[cfg(not(feature = "bevy_debug_stepping"))]
-        self.executor
-            .run(&mut self.executable, world, None, error_handler);
+        let default_error_handler = world.default_error_handler();
+        let system_errors = world.get_resource::<SystemErrorQueue>().cloned();
+        let label = self.label;
+        let error_handler =
+            move |error: BevyError, context: ErrorContext| match (&system_errors, context) {
+                (Some(system_errors), ErrorContext::System { name, last_run }) => {
+                    system_errors.push(QueuedSystemError {
+                        error,
+                        name,
+                        last_run,
+                        schedule: label,
+                    });
+                }
+                (_, context) => default_error_handler(error, context),
+            };
+        if let Some(sets) = sets
+            && self
+                .systems_outside_sets
+                .as_ref()
+                .is_none_or(|(cached, _)| cached.as_slice() != sets)
+        {
+            self.systems_outside_sets = Some((sets.to_vec(), self.systems_outside_of(sets)));
+        }
+        let skip_systems = sets
+            .and(self.systems_outside_sets.as_ref())
+            .map(|(_, skip)| skip);
 
         #[cfg(feature = "bevy_debug_stepping")]
+        let stepping_skip;
+        #[cfg(feature = "bevy_debug_stepping")]
+        let skip_systems = match world
+            .get_resource_mut::<Stepping>()
+            .and_then(|mut stepping| stepping.skipped_systems(self))
         {
-            let skip_systems = match world.get_resource_mut::<Stepping>() {
-                None => None,
-                Some(mut stepping) => stepping.skipped_systems(self),
-            };
+            None => skip_systems,
+            Some(mut skip) => {
+                if let Some(skip_outside_sets) = skip_systems {
+                    skip.union_with(skip_outside_sets);
+                }
+                stepping_skip = skip;
+                Some(&stepping_skip)
+            }
+        };
 
-            self.executor.run(
-                &mut self.executable,
-                world,
-                skip_systems.as_ref(),
-                error_handler,
-            );
+        self.executor
+            .run(&mut self.executable, world, skip_systems, &error_handler);
+    }
+
+    /// Returns the executable indices of the systems that are not in any of the `sets`.
+    fn systems_outside_of(&self, sets: &[InternedSystemSet]) -> FixedBitSet {
+        let hierarchy = self.graph.hierarchy().graph();
+        let mut included = HashSet::<SystemKey>::default();
+        let mut stack: Vec<NodeId> = sets
+            .iter()
+            .filter_map(|&set| self.graph.system_sets.get_key(set))
+            .map(NodeId::Set)
+            .collect();
+        while let Some(node) = stack.pop() {
+            for child in hierarchy.neighbors_directed(node, Outgoing) {
+                match child {
+                    NodeId::System(key) => {
+                        included.insert(key);
+                    }
+                    NodeId::Set(_) => stack.push(child),
+                }
+            }
         }
+
+        let system_ids = &self.executable.system_ids;
+        let mut skip = FixedBitSet::with_capacity(system_ids.len());
+        for (index, key) in system_ids.iter().enumerate() {
+            if !included.contains(key) {
+                skip.insert(index);
+            }
+        }
+        skip
     }
 
     /// Initializes any newly-added systems and conditions, rebuilds the executable schedule,
@@ -532,11 +606,29 @@ impl Schedule {
         if !self.executor_initialized {
             self.executor.init(&self.executable);
             self.executor_initialized = true;
+            self.systems_outside_sets = None;
         }
 
         Ok(())
     }
 
+    /// Removes the system with the given key from the schedule, and returns it.
+    ///
+    /// The system's run conditions and the ordering constraints it is part of are removed with
+    /// it. This means that if `b` was removed from `(a, b, c).chain()`, `a` and `c` are no longer
+    /// ordered. Systems sets are kept, even if they end up empty.
+    ///
+    /// The executable schedule is rebuilt the next time the schedule runs.
+    /// Returns `None` if there is no system with this key in the schedule.
+    pub fn remove_system(&mut self, key: SystemKey) -> Option<ScheduleSystem> {
+        if !self.graph.systems.contains(key) {
+            return None;
+        }
+        self.graph.reclaim_systems(&mut self.executable);
+        self.executor_initialized = false;
+        self.graph.remove_system(key)
+    }
+
     /// Returns the [`ScheduleGraph`].
     pub fn graph(&self) -> &ScheduleGraph {
         &self.graph
@@ -552,6 +644,11 @@ impl Schedule {
         &self.executable
     }
 
+    /// Returns a mutable reference to the [`SystemSchedule`].
+    pub(crate) fn executable_mut(&mut self) -> &mut SystemSchedule {
+        &mut self.executable
+    }
+
     /// Iterates the change ticks of all systems in the schedule and clamps any older than
     /// [`MAX_CHANGE_AGE`](crate::change_detection::MAX_CHANGE_AGE).
     /// This prevents overflow and thus prevents false positives.
@@ -611,6 +708,31 @@ impl Schedule {
         Ok(iter)
     }
 
+    /// Returns an iterator over all systems in this schedule, along with the [`FilteredAccessSet`]
+    /// of the world they access.
+    ///
+    /// Note: this method will return [`ScheduleNotInitialized`] if the
+    /// schedule has never been initialized or run.
+    ///
+    /// [`FilteredAccessSet`]: crate::query::FilteredAccessSet
+    pub fn systems_with_access(
+        &self,
+    ) -> Result<impl Iterator<Item = (SystemKey, &SystemWithAccess)> + Sized, ScheduleNotInitialized>
+    {
+        if !self.executor_initialized {
+            return Err(ScheduleNotInitialized);
+        }
+
+        let iter = self
+            .executable
+            .system_ids
+            .iter()
+            .copied()
+            .zip(&self.executable.systems);
+
+        Ok(iter)
+    }
+
     /// Returns the number of systems in this schedule.
     pub fn systems_len(&self) -> usize {
         if !self.executor_initialized {
@@ -654,6 +776,11 @@ impl<N: GraphNodeId> Dag<N> {
     pub fn cached_topsort(&self) -> &[N] {
         &self.topsort
     }
+
+    fn remove_node(&mut self, node: N) {
+        self.graph.remove_node(node);
+        self.topsort.retain(|&n| n != node);
+    }
 }
 
 impl<N: GraphNodeId> Default for Dag<N> {
@@ -1328,19 +1455,8 @@ impl ScheduleGraph {
         }
     }
 
-    /// Updates the `SystemSchedule` from the `ScheduleGraph`.
-    fn update_schedule(
-        &mut self,
-        world: &mut World,
-        schedule: &mut SystemSchedule,
-        ignored_ambiguities: &BTreeSet<ComponentId>,
-        schedule_label: InternedScheduleLabel,
-    ) -> Result<Vec<ScheduleBuildWarning>, ScheduleBuildError> {
-        if !self.systems.is_initialized() || !self.system_sets.is_initialized() {
-            return Err(ScheduleBuildError::Uninitialized);
-        }
-
-        // move systems out of old schedule
+    /// Moves the systems and run conditions of `schedule` back into the graph, leaving it empty.
+    fn reclaim_systems(&mut self, schedule: &mut SystemSchedule) {
         for ((key, system), conditions) in schedule
             .system_ids
             .drain(..)
@@ -1358,6 +1474,36 @@ impl ScheduleGraph {
         {
             *self.system_sets.get_conditions_mut(key).unwrap() = conditions;
         }
+    }
+
+    /// Removes the system with the given key along with its conditions and every edge it is part
+    /// of. The system must not be stored in an executable schedule.
+    fn remove_system(&mut self, key: SystemKey) -> Option<ScheduleSystem> {
+        let node = self.systems.remove(key)?;
+        let id = NodeId::System(key);
+        self.hierarchy.remove_node(id);
+        self.dependency.remove_node(id);
+        self.ambiguous_with.remove_node(id);
+        self.ambiguous_with_all.remove(&id);
+        self.conflicting_systems
+            .retain(|(a, b, _)| *a != key && *b != key);
+        self.changed = true;
+        node.inner.map(|inner| inner.system)
+    }
+
+    /// Updates the `SystemSchedule` from the `ScheduleGraph`.
+    fn update_schedule(
+        &mut self,
+        world: &mut World,
+        schedule: &mut SystemSchedule,
+        ignored_ambiguities: &BTreeSet<ComponentId>,
+        schedule_label: InternedScheduleLabel,
+    ) -> Result<Vec<ScheduleBuildWarning>, ScheduleBuildError> {
+        if !self.systems.is_initialized() || !self.system_sets.is_initialized() {
+            return Err(ScheduleBuildError::Uninitialized);
+        }
+
+        self.reclaim_systems(schedule);
 
         let (new_schedule, warnings) = self.build_schedule(world, ignored_ambiguities)?;
         *schedule = new_schedule;
@@ -1767,10 +1913,11 @@ pub struct ScheduleNotInitialized;
 
 #[cfg(test)]
 mod tests {
+    use alloc::vec::Vec;
     use bevy_ecs_macros::ScheduleLabel;
 
     use crate::{
-        error::{ignore, panic, DefaultErrorHandler, Result},
+        error::{ignore, panic, DefaultErrorHandler, Result, SystemErrorQueue},
         prelude::{ApplyDeferred, Res, Resource},
         schedule::{
             tests::ResMut, IntoScheduleConfigs, Schedule, ScheduleBuildSettings, SystemSet,
@@ -2561,6 +2708,36 @@ mod tests {
         assert_eq!(value.0, 2);
     }
 
+    #[test]
+    fn remove_system_after_run() {
+        let mut world = World::new();
+        world.insert_resource(CheckSystemRan(0));
+        let mut schedule = Schedule::new(TestSchedule);
+        schedule.add_systems(
+            (
+                |mut ran: ResMut<CheckSystemRan>| ran.0 += 1,
+                |mut ran: ResMut<CheckSystemRan>| ran.0 += 10,
+                |mut ran: ResMut<CheckSystemRan>| ran.0 += 100,
+            )
+                .chain(),
+        );
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<CheckSystemRan>().0, 111);
+
+        let keys = schedule.graph().systems.keys().collect::<Vec<_>>();
+        assert!(schedule.remove_system(keys[1]).is_some());
+        assert!(schedule.remove_system(keys[1]).is_none());
+        assert!(!schedule
+            .graph()
+            .dependency()
+            .graph()
+            .contains_node(keys[1].into()));
+
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<CheckSystemRan>().0, 212);
+        assert_eq!(schedule.systems_len(), 2);
+    }
+
     #[test]
     fn test_default_error_handler() {
         #[derive(Resource, Default)]
@@ -2588,4 +2765,50 @@ mod tests {
         );
         schedule.run(&mut world);
     }
+
+    #[test]
+    fn system_error_queue() {
+        use crate::schedule::ScheduleLabel;
+        use alloc::string::ToString;
+        use core::sync::atomic::{AtomicBool, Ordering};
+
+        fn system() -> Result {
+            Err("I failed!".into())
+        }
+
+        fn condition() -> Result<bool> {
+            Err("I failed too!".into())
+        }
+
+        let mut world = World::default();
+        world.insert_resource(DefaultErrorHandler(panic));
+        let queue = SystemErrorQueue::default();
+        world.insert_resource(queue.clone());
+        let mut schedule = Schedule::new(TestSchedule);
+        schedule.add_systems(system).run(&mut world);
+
+        let errors = queue.drain();
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].error.to_string().starts_with("I failed!"));
+        assert_eq!(errors[0].schedule, TestSchedule.intern());
+        assert!(queue.drain().is_empty());
+
+        // A full queue drops the new errors.
+        let queue = SystemErrorQueue::with_max_len(1);
+        world.insert_resource(queue.clone());
+        schedule.run(&mut world);
+        schedule.run(&mut world);
+        assert_eq!(queue.drain().len(), 1);
+        assert_eq!(queue.take_dropped(), 1);
+        assert_eq!(queue.take_dropped(), 0);
+
+        // The errors of run conditions are still passed to the default error handler.
+        static HANDLED: AtomicBool = AtomicBool::new(false);
+        world.insert_resource(DefaultErrorHandler(|_, _| HANDLED.store(true, Ordering::Relaxed)));
+        Schedule::default()
+            .add_systems(system.run_if(condition))
+            .run(&mut world);
+        assert!(HANDLED.load(Ordering::Relaxed));
+        assert!(queue.drain().is_empty());
+    }
 }
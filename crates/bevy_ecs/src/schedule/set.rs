@@ -57,7 +57,23 @@ define_label!(
         note = "consider annotating `{Self}` with `#[derive(ScheduleLabel)]`"
     )]
     ScheduleLabel,
-    SCHEDULE_LABEL_INTERNER
+    SCHEDULE_LABEL_INTERNER,
+    extra_methods: {
+        /// Returns the description of this schedule given by the `#[schedule_doc = "..."]`
+        /// attribute, if any.
+        ///
+        /// This is the default description of the schedule, see [`ScheduleDescriptions`].
+        ///
+        /// [`ScheduleDescriptions`]: crate::schedule::ScheduleDescriptions
+        fn doc(&self) -> Option<&'static str> {
+            None
+        }
+    },
+    extra_methods_impl: {
+        fn doc(&self) -> Option<&'static str> {
+            (**self).doc()
+        }
+    }
 );
 
 define_label!(
@@ -0,0 +1,148 @@
+use alloc::vec::Vec;
+use bevy_platform::time::Instant;
+use bevy_utils::prelude::DebugName;
+use core::time::Duration;
+
+use crate::{resource::Resource, schedule::ExclusiveSystemTimings, system::System, world::World};
+
+/// Collects how long each system took to run, for diagnostics.
+///
+/// While this resource exists, the executors time every system they run, exclusive or not, and
+/// record the result here. Unlike a profiler, this works without any tracing backend, including
+/// in headless apps.
+///
+/// The multi-threaded executor records the timings of the non-exclusive systems of a schedule
+/// once the schedule ends, while the other timings are recorded as each system finishes. So the
+/// timings of the schedule that drains them, for example `Last`, can be recorded after the drain,
+/// and are only returned by the next one.
+///
+/// Timings accumulate until they are [drained](Self::drain), usually once per frame by a
+/// diagnostics plugin.
+#[derive(Resource, Debug, Default)]
+pub struct SystemTimings {
+    timings: Vec<(DebugName, Duration)>,
+}
+
+impl SystemTimings {
+    /// Records that the system `name` ran for `duration`.
+    pub fn record(&mut self, name: DebugName, duration: Duration) {
+        self.timings.push((name, duration));
+    }
+
+    /// Returns the timings recorded since the last call to [`drain`](Self::drain).
+    pub fn timings(&self) -> &[(DebugName, Duration)] {
+        &self.timings
+    }
+
+    /// Removes and returns the recorded timings.
+    pub fn drain(&mut self) -> impl Iterator<Item = (DebugName, Duration)> + '_ {
+        self.timings.drain(..)
+    }
+}
+
+/// Times a system for the [`SystemTimings`] and, if it is exclusive, the
+/// [`ExclusiveSystemTimings`], if the resources exist.
+pub(super) struct SystemTimer {
+    start: Option<Instant>,
+    exclusive: bool,
+}
+
+impl SystemTimer {
+    pub(super) fn start<S: System + ?Sized>(system: &S, world: &World) -> Self {
+        let exclusive =
+            system.is_exclusive() && world.contains_resource::<ExclusiveSystemTimings>();
+        Self {
+            start: (exclusive || world.contains_resource::<SystemTimings>()).then(Instant::now),
+            exclusive,
+        }
+    }
+
+    pub(super) fn finish<S: System + ?Sized>(self, system: &S, world: &mut World) {
+        let Some(start) = self.start else {
+            return;
+        };
+        let duration = start.elapsed();
+        if self.exclusive
+            && let Some(mut timings) = world.get_resource_mut::<ExclusiveSystemTimings>()
+        {
+            timings.record(system.name(), duration);
+        }
+        if let Some(mut timings) = world.get_resource_mut::<SystemTimings>() {
+            timings.record(system.name(), duration);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SystemTimings;
+    use crate::{
+        prelude::*,
+        schedule::{ExclusiveSystemTimings, ExecutorKind, Schedule},
+    };
+    use alloc::{
+        string::{String, ToString},
+        vec::Vec,
+    };
+
+    fn exclusive(_: &mut World) {}
+
+    fn parallel(_: Query<Entity>) {}
+
+    fn timed_systems(executor: ExecutorKind) -> Vec<String> {
+        let mut world = World::new();
+        world.init_resource::<SystemTimings>();
+        let mut schedule = Schedule::default();
+        schedule.set_executor_kind(executor);
+        schedule.add_systems((exclusive, parallel, exclusive.after(parallel)));
+        schedule.run(&mut world);
+        let mut names: Vec<_> = world
+            .resource_mut::<SystemTimings>()
+            .drain()
+            .map(|(name, _)| name.to_string())
+            .collect();
+        names.sort();
+        names
+    }
+
+    #[test]
+    fn every_system_is_timed() {
+        #[expect(deprecated, reason = "We still need to support this.")]
+        let executors = [
+            ExecutorKind::SingleThreaded,
+            ExecutorKind::Simple,
+            #[cfg(feature = "multi_threaded")]
+            ExecutorKind::MultiThreaded,
+        ];
+        let exclusive_name = IntoSystem::into_system(exclusive).name().to_string();
+        let parallel_name = IntoSystem::into_system(parallel).name().to_string();
+        let mut expected = [exclusive_name.clone(), exclusive_name, parallel_name];
+        expected.sort();
+        for executor in executors {
+            assert_eq!(timed_systems(executor), expected, "{executor:?}");
+        }
+
+        // Nothing is timed without the resource.
+        let mut world = World::new();
+        let mut schedule = Schedule::default();
+        schedule.add_systems(parallel);
+        schedule.run(&mut world);
+        assert!(!world.contains_resource::<SystemTimings>());
+    }
+
+    #[test]
+    fn exclusive_systems_are_timed_once_for_both_timings() {
+        let mut world = World::new();
+        world.init_resource::<SystemTimings>();
+        world.init_resource::<ExclusiveSystemTimings>();
+        let mut schedule = Schedule::default();
+        schedule.add_systems((exclusive, parallel));
+        schedule.run(&mut world);
+
+        let exclusive_timings = world.resource::<ExclusiveSystemTimings>().timings();
+        assert_eq!(exclusive_timings.len(), 1);
+        let timings = world.resource::<SystemTimings>().timings();
+        assert_eq!(timings.len(), 2);
+        assert!(timings.contains(&exclusive_timings[0]));
+    }
+}
@@ -0,0 +1,183 @@
+use core::sync::atomic::{AtomicBool, Ordering};
+
+use crate::resource::Resource;
+
+static SCHEDULE_SPANS: AtomicBool = AtomicBool::new(true);
+static SYSTEM_SPANS: AtomicBool = AtomicBool::new(true);
+static COMMAND_SPANS: AtomicBool = AtomicBool::new(true);
+
+/// Enables or disables the tracing spans around schedules, systems and the application of their
+/// commands while the app is running.
+///
+/// The spans are only compiled in with the `trace` feature, and are all enabled by default. This
+/// allows a build with the feature to only record spans when a profile is needed, for example
+/// from a debug menu, with a single relaxed atomic load per span when they are disabled.
+///
+/// The settings are global: they apply to every [`World`](crate::world::World) of the process,
+/// and take effect immediately, even for the schedules and systems already running.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::schedule::TraceSpanConfig;
+/// fn toggle_profiling(mut config: ResMut<TraceSpanConfig>) {
+///     // Keep the schedule spans, which are cheap, but skip the per-system ones.
+///     config.set_all(true).set_system_spans(false);
+/// }
+/// ```
+#[derive(Resource, Debug, Default)]
+pub struct TraceSpanConfig {
+    _private: (),
+}
+
+impl TraceSpanConfig {
+    /// Returns whether the spans around the run of each schedule, and around the ticks of the
+    /// multi-threaded executor, are enabled.
+    pub fn schedule_spans(&self) -> bool {
+        SCHEDULE_SPANS.load(Ordering::Relaxed)
+    }
+
+    /// Enables or disables the spans around the run of each schedule, and around the ticks of the
+    /// multi-threaded executor.
+    pub fn set_schedule_spans(&mut self, enabled: bool) -> &mut Self {
+        SCHEDULE_SPANS.store(enabled, Ordering::Relaxed);
+        self
+    }
+
+    /// Returns whether the spans around the run of each system, and around the evaluation of its
+    /// run conditions, are enabled.
+    pub fn system_spans(&self) -> bool {
+        SYSTEM_SPANS.load(Ordering::Relaxed)
+    }
+
+    /// Enables or disables the spans around the run of each system, and around the evaluation of
+    /// its run conditions.
+    pub fn set_system_spans(&mut self, enabled: bool) -> &mut Self {
+        SYSTEM_SPANS.store(enabled, Ordering::Relaxed);
+        self
+    }
+
+    /// Returns whether the spans around the application of the commands of each system are
+    /// enabled.
+    pub fn command_spans(&self) -> bool {
+        COMMAND_SPANS.load(Ordering::Relaxed)
+    }
+
+    /// Enables or disables the spans around the application of the commands of each system.
+    pub fn set_command_spans(&mut self, enabled: bool) -> &mut Self {
+        COMMAND_SPANS.store(enabled, Ordering::Relaxed);
+        self
+    }
+
+    /// Enables or disables all the spans.
+    pub fn set_all(&mut self, enabled: bool) -> &mut Self {
+        self.set_schedule_spans(enabled)
+            .set_system_spans(enabled)
+            .set_command_spans(enabled)
+    }
+}
+
+/// Whether the schedule spans are enabled, see [`TraceSpanConfig::schedule_spans`].
+#[cfg(feature = "trace")]
+#[inline]
+pub(crate) fn schedule_spans() -> bool {
+    SCHEDULE_SPANS.load(Ordering::Relaxed)
+}
+
+/// Whether the system spans are enabled, see [`TraceSpanConfig::system_spans`].
+#[cfg(feature = "trace")]
+#[inline]
+pub(crate) fn system_spans() -> bool {
+    SYSTEM_SPANS.load(Ordering::Relaxed)
+}
+
+/// Whether the command spans are enabled, see [`TraceSpanConfig::command_spans`].
+#[cfg(feature = "trace")]
+#[inline]
+pub(crate) fn command_spans() -> bool {
+    COMMAND_SPANS.load(Ordering::Relaxed)
+}
+
+#[cfg(all(test, feature = "trace"))]
+mod tests {
+    use alloc::{string::String, sync::Arc, vec::Vec};
+    use std::sync::Mutex;
+    use tracing::{span, subscriber, Event, Metadata, Subscriber};
+
+    use super::TraceSpanConfig;
+    use crate::{prelude::*, schedule::Schedule};
+
+    /// Records the names of the spans entered.
+    #[derive(Default)]
+    struct EnteredSpans {
+        names: Mutex<Vec<&'static str>>,
+        entered: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl Subscriber for EnteredSpans {
+        fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &span::Attributes<'_>) -> span::Id {
+            let mut names = self.names.lock().unwrap();
+            names.push(span.metadata().name());
+            span::Id::from_u64(names.len() as u64)
+        }
+
+        fn record(&self, _span: &span::Id, _values: &span::Record<'_>) {}
+
+        fn record_follows_from(&self, _span: &span::Id, _follows: &span::Id) {}
+
+        fn event(&self, _event: &Event<'_>) {}
+
+        fn enter(&self, span: &span::Id) {
+            let name = self.names.lock().unwrap()[span.into_u64() as usize - 1];
+            self.entered.lock().unwrap().push(name.into());
+        }
+
+        fn exit(&self, _span: &span::Id) {}
+    }
+
+    fn entered_spans(config: impl FnOnce(&mut TraceSpanConfig)) -> Vec<String> {
+        fn spawn(mut commands: Commands) {
+            commands.spawn_empty();
+        }
+
+        let subscriber = EnteredSpans::default();
+        let entered = subscriber.entered.clone();
+        subscriber::with_default(subscriber, || {
+            let mut world = World::new();
+            world.init_resource::<TraceSpanConfig>();
+            config(&mut world.resource_mut::<TraceSpanConfig>());
+            let mut schedule = Schedule::default();
+            schedule.set_executor_kind(crate::schedule::ExecutorKind::SingleThreaded);
+            schedule.add_systems(spawn);
+            schedule.run(&mut world);
+            world.resource_mut::<TraceSpanConfig>().set_all(true);
+        });
+        let mut entered = entered.lock().unwrap().clone();
+        entered.dedup();
+        entered
+    }
+
+    #[test]
+    fn spans_can_be_disabled_at_runtime() {
+        let all = entered_spans(|config| {
+            config.set_all(true);
+        });
+        for span in ["schedule", "check_conditions", "system", "system_commands"] {
+            assert!(all.iter().any(|name| name == span), "{span} in {all:?}");
+        }
+
+        let schedules_only = entered_spans(|config| {
+            config.set_all(false).set_schedule_spans(true);
+        });
+        assert!(schedules_only.iter().any(|name| name == "schedule"));
+        for span in ["check_conditions", "system", "system_commands"] {
+            assert!(
+                !schedules_only.iter().any(|name| name == span),
+                "{span} in {schedules_only:?}"
+            );
+        }
+    }
+}
@@ -11,7 +11,11 @@ pub use entity_command::EntityCommand;
 pub use parallel_scope::*;
 
 use alloc::boxed::Box;
-use core::marker::PhantomData;
+use bevy_platform::{
+    hash::FixedHasher,
+    sync::atomic::{AtomicU32, Ordering},
+};
+use core::{hash::BuildHasher, marker::PhantomData};
 
 use crate::{
     self as bevy_ecs,
@@ -100,6 +104,18 @@ use crate::{
 pub struct Commands<'w, 's> {
     queue: InternalQueue<'s>,
     entities: &'w Entities,
+    spawn_source: Option<&'s SpawnSource>,
+}
+
+/// Identifies the entities spawned by a system's [`Commands`], so that they can be
+/// given ids from a reserved range in a deterministic order.
+///
+/// See [`World::begin_reserved_entity_range`].
+struct SpawnSource {
+    /// Hash of the system's name, computed on first use.
+    name_hash: Option<u64>,
+    /// Number of entities spawned during the current system run.
+    spawn_index: AtomicU32,
 }
 
 // SAFETY: All commands [`Command`] implement [`Send`]
@@ -113,6 +129,7 @@ const _: () = {
     #[doc(hidden)]
     pub struct FetchState {
         state: <__StructFieldsAlias<'static, 'static> as bevy_ecs::system::SystemParam>::State,
+        spawn_source: SpawnSource,
     }
     // SAFETY: Only reads Entities
     unsafe impl bevy_ecs::system::SystemParam for Commands<'_, '_> {
@@ -125,6 +142,10 @@ const _: () = {
                 state: <__StructFieldsAlias<'_, '_> as bevy_ecs::system::SystemParam>::init_state(
                     world,
                 ),
+                spawn_source: SpawnSource {
+                    name_hash: None,
+                    spawn_index: AtomicU32::new(0),
+                },
             }
         }
 
@@ -186,10 +207,24 @@ const _: () = {
             world: UnsafeWorldCell<'w>,
             change_tick: bevy_ecs::component::Tick,
         ) -> Self::Item<'w, 's> {
-            let(f0, f1) =  <(Deferred<'s, CommandQueue>, &'w Entities) as bevy_ecs::system::SystemParam>::get_param(&mut state.state, system_meta, world, change_tick);
+            let FetchState {
+                state,
+                spawn_source,
+            } = state;
+            let(f0, f1) =  <(Deferred<'s, CommandQueue>, &'w Entities) as bevy_ecs::system::SystemParam>::get_param(state, system_meta, world, change_tick);
+            // Spawn indices only matter while a reserved range is open, so skip
+            // hashing the system name otherwise.
+            let spawn_source = f1.reserved_range().is_some().then(|| {
+                spawn_source
+                    .name_hash
+                    .get_or_insert_with(|| FixedHasher.hash_one(&**system_meta.name()));
+                *spawn_source.spawn_index.get_mut() = 0;
+                &*spawn_source
+            });
             Commands {
                 queue: InternalQueue::CommandQueue(f0),
                 entities: f1,
+                spawn_source,
             }
         }
     }
@@ -218,6 +253,7 @@ impl<'w, 's> Commands<'w, 's> {
         Self {
             queue: InternalQueue::CommandQueue(Deferred(queue)),
             entities,
+            spawn_source: None,
         }
     }
 
@@ -235,6 +271,7 @@ impl<'w, 's> Commands<'w, 's> {
         Self {
             queue: InternalQueue::RawCommandQueue(queue),
             entities,
+            spawn_source: None,
         }
     }
 
@@ -266,7 +303,30 @@ impl<'w, 's> Commands<'w, 's> {
                 }
             },
             entities: self.entities,
+            spawn_source: self.spawn_source,
+        }
+    }
+
+    /// Reserves the id of an entity about to be spawned.
+    ///
+    /// While a reserved range is open (see [`World::begin_reserved_entity_range`]), the id is
+    /// claimed from it using this system's name and spawn index, falling back to the regular
+    /// allocator once the range is full.
+    fn reserve_spawned_entity(&self) -> Entity {
+        if let Some(SpawnSource {
+            name_hash: Some(name_hash),
+            spawn_index,
+        }) = self.spawn_source
+        {
+            let index = spawn_index.fetch_add(1, Ordering::Relaxed);
+            if let Some(entity) = self
+                .entities
+                .claim_reserved(FixedHasher.hash_one((name_hash, index)))
+            {
+                return entity;
+            }
         }
+        self.entities.reserve_entity()
     }
 
     /// Take all commands from `other` and append them to `self`, leaving `other` empty.
@@ -314,7 +374,7 @@ impl<'w, 's> Commands<'w, 's> {
     ///   with the same combination of components.
     #[track_caller]
     pub fn spawn_empty(&mut self) -> EntityCommands<'_> {
-        let entity = self.entities.reserve_entity();
+        let entity = self.reserve_spawned_entity();
         let mut entity_commands = EntityCommands {
             entity,
             commands: self.reborrow(),
@@ -376,7 +436,7 @@ impl<'w, 's> Commands<'w, 's> {
     ///   with the same combination of components.
     #[track_caller]
     pub fn spawn<T: Bundle>(&mut self, bundle: T) -> EntityCommands<'_> {
-        let entity = self.entities.reserve_entity();
+        let entity = self.reserve_spawned_entity();
         let mut entity_commands = EntityCommands {
             entity,
             commands: self.reborrow(),
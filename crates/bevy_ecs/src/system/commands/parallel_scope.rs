@@ -58,7 +58,8 @@ impl SystemBuffer for ParallelCommandQueue {
     #[inline]
     fn apply(&mut self, _system_meta: &SystemMeta, world: &mut World) {
         #[cfg(feature = "trace")]
-        let _system_span = _system_meta.commands_span.enter();
+        let _system_span =
+            crate::schedule::command_spans().then(|| _system_meta.commands_span.enter());
         for cq in self.thread_queues.iter_mut() {
             cq.apply(world);
         }
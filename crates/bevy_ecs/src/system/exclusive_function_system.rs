@@ -112,7 +112,8 @@ where
         let world = unsafe { world.world_mut() };
         world.last_change_tick_scope(self.system_meta.last_run, |world| {
             #[cfg(feature = "trace")]
-            let _span_guard = self.system_meta.system_span.enter();
+            let _span_guard =
+                crate::schedule::system_spans().then(|| self.system_meta.system_span.enter());
 
             let params = F::Param::get_param(
                 self.param_state.as_mut().expect(PARAM_MESSAGE),
@@ -681,7 +681,8 @@ where
         world: UnsafeWorldCell,
     ) -> Result<Self::Out, RunSystemError> {
         #[cfg(feature = "trace")]
-        let _span_guard = self.system_meta.system_span.enter();
+        let _span_guard =
+            crate::schedule::system_spans().then(|| self.system_meta.system_span.enter());
 
         let change_tick = world.increment_change_tick();
 
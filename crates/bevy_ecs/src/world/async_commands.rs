@@ -0,0 +1,297 @@
+use crate::{
+    bundle::Bundle,
+    error::HandleError,
+    event::BufferedEvent,
+    resource::Resource,
+    system::{command, Command},
+    world::World,
+};
+use alloc::boxed::Box;
+use bevy_platform::sync::Arc;
+use concurrent_queue::ConcurrentQueue;
+use core::fmt;
+
+type BoxedCommand = Box<dyn FnOnce(&mut World) + Send>;
+
+/// A handle to queue commands into a [`World`] from any thread, for example from an async task.
+///
+/// The methods of this handle mirror a subset of [`Commands`](crate::system::Commands). The
+/// commands are stored in the [`AsyncCommandQueue`] of the world, and applied when
+/// [`World::apply_async_commands`] is called. In an `App`, this happens at the start of each
+/// frame, in `First`.
+///
+/// Commands queued through the same handle, or its clones, are applied in the order they were
+/// queued. There is no ordering guarantee between handles obtained separately.
+///
+/// Once the world is dropped, queuing a command returns an [`AsyncCommandsClosedError`].
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// #[derive(Component)]
+/// struct Loaded(u32);
+///
+/// let mut world = World::new();
+/// let commands = world.async_commands();
+/// std::thread::spawn(move || {
+///     // Load something...
+///     commands.spawn(Loaded(42)).unwrap();
+/// })
+/// .join()
+/// .unwrap();
+///
+/// world.apply_async_commands();
+/// assert_eq!(world.query::<&Loaded>().single(&world).unwrap().0, 42);
+/// ```
+#[derive(Clone)]
+pub struct AsyncCommands {
+    queue: Arc<ConcurrentQueue<BoxedCommand>>,
+}
+
+impl AsyncCommands {
+    /// Pushes a generic [`Command`] to the queue, like [`Commands::queue`](crate::system::Commands::queue).
+    ///
+    /// Since any closure taking a `&mut World` is a command, this can be used to run arbitrary
+    /// code with access to the world. If the command returns an error, it is handled by the
+    /// [`DefaultErrorHandler`](crate::error::DefaultErrorHandler) of the world.
+    pub fn queue<C: Command<T> + HandleError<T>, T>(
+        &self,
+        command: C,
+    ) -> Result<(), AsyncCommandsClosedError> {
+        let command = command.handle_error();
+        self.queue
+            .push(Box::new(move |world: &mut World| command.apply(world)))
+            .map_err(|_| AsyncCommandsClosedError)
+    }
+
+    /// Spawns a new entity with the given components.
+    ///
+    /// Unlike [`Commands::spawn`](crate::system::Commands::spawn), the entity isn't known until
+    /// the command is applied. Use [`queue`](Self::queue) to do something with it.
+    pub fn spawn<B: Bundle>(&self, bundle: B) -> Result<(), AsyncCommandsClosedError> {
+        self.queue(move |world: &mut World| {
+            world.spawn(bundle);
+        })
+    }
+
+    /// Inserts a [`Resource`] into the world, replacing the previous value if any.
+    pub fn insert_resource<R: Resource>(
+        &self,
+        resource: R,
+    ) -> Result<(), AsyncCommandsClosedError> {
+        self.queue(command::insert_resource(resource))
+    }
+
+    /// Writes a [`BufferedEvent`].
+    pub fn write_event<E: BufferedEvent>(&self, event: E) -> Result<(), AsyncCommandsClosedError> {
+        self.queue(command::write_event(event))
+    }
+
+    /// Returns `true` if the world was dropped, so commands can't be queued anymore.
+    pub fn is_closed(&self) -> bool {
+        self.queue.is_closed()
+    }
+}
+
+impl fmt::Debug for AsyncCommands {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AsyncCommands")
+            .field("len", &self.queue.len())
+            .field("closed", &self.queue.is_closed())
+            .finish()
+    }
+}
+
+/// The queue of the commands sent through [`AsyncCommands`], created by
+/// [`World::async_commands`].
+///
+/// Dropping this resource, usually along with the world, closes the queue: the commands that
+/// weren't applied are dropped, and queuing new ones returns an [`AsyncCommandsClosedError`].
+#[derive(Resource)]
+pub struct AsyncCommandQueue {
+    queue: Arc<ConcurrentQueue<BoxedCommand>>,
+}
+
+impl AsyncCommandQueue {
+    /// Returns a new handle to this queue.
+    pub fn handle(&self) -> AsyncCommands {
+        AsyncCommands {
+            queue: self.queue.clone(),
+        }
+    }
+
+    /// Returns the number of commands waiting to be applied.
+    pub fn len(&self) -> usize {
+        self.queue.len()
+    }
+
+    /// Returns `true` if no command is waiting to be applied.
+    pub fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+}
+
+impl Default for AsyncCommandQueue {
+    fn default() -> Self {
+        Self {
+            queue: Arc::new(ConcurrentQueue::unbounded()),
+        }
+    }
+}
+
+impl Drop for AsyncCommandQueue {
+    fn drop(&mut self) {
+        self.queue.close();
+    }
+}
+
+impl fmt::Debug for AsyncCommandQueue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AsyncCommandQueue")
+            .field("len", &self.queue.len())
+            .finish()
+    }
+}
+
+/// The error returned by [`AsyncCommands`] when the [`World`] it sends commands to was dropped.
+#[derive(thiserror::Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("The world receiving the async commands was dropped")]
+pub struct AsyncCommandsClosedError;
+
+impl World {
+    /// Returns a handle to queue commands into this world from any thread, see [`AsyncCommands`].
+    ///
+    /// This initializes the [`AsyncCommandQueue`] resource if needed.
+    pub fn async_commands(&mut self) -> AsyncCommands {
+        self.get_resource_or_init::<AsyncCommandQueue>().handle()
+    }
+
+    /// Applies the commands queued through [`AsyncCommands`], in the order they were queued.
+    ///
+    /// Commands queued while applying, for example by another thread, are left for the next call.
+    pub fn apply_async_commands(&mut self) {
+        let Some(queue) = self.get_resource::<AsyncCommandQueue>() else {
+            return;
+        };
+        let queue = queue.queue.clone();
+        for command in queue.try_iter().take(queue.len()) {
+            command(self);
+            self.flush();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use std::thread;
+
+    use super::{AsyncCommandQueue, AsyncCommandsClosedError};
+    use crate::{
+        component::Component,
+        event::{BufferedEvent, Events},
+        resource::Resource,
+        world::World,
+    };
+
+    #[derive(Component, Debug, PartialEq)]
+    struct Loaded(u32);
+
+    #[derive(BufferedEvent)]
+    struct Done(u32);
+
+    #[derive(Resource, Default, Debug, PartialEq)]
+    struct Order(Vec<u32>);
+
+    #[test]
+    fn cross_thread_commands() {
+        let mut world = World::new();
+        world.init_resource::<Events<Done>>();
+        let commands = world.async_commands();
+
+        let tasks = (0..4)
+            .map(|i| {
+                let commands = commands.clone();
+                thread::spawn(move || {
+                    commands.spawn(Loaded(i)).unwrap();
+                    commands.write_event(Done(i)).unwrap();
+                })
+            })
+            .collect::<Vec<_>>();
+        for task in tasks {
+            task.join().unwrap();
+        }
+        assert_eq!(world.resource::<AsyncCommandQueue>().len(), 8);
+        assert_eq!(world.query::<&Loaded>().iter(&world).count(), 0);
+
+        world.apply_async_commands();
+        assert!(world.resource::<AsyncCommandQueue>().is_empty());
+        let mut loaded = world
+            .query::<&Loaded>()
+            .iter(&world)
+            .map(|loaded| loaded.0)
+            .collect::<Vec<_>>();
+        loaded.sort();
+        assert_eq!(loaded, [0, 1, 2, 3]);
+        assert_eq!(world.resource::<Events<Done>>().len(), 4);
+    }
+
+    #[test]
+    fn fifo_per_handle() {
+        let mut world = World::new();
+        let commands = world.async_commands();
+        let clone = commands.clone();
+        for i in 0..3 {
+            commands
+                .queue(move |world: &mut World| {
+                    world.get_resource_or_init::<Order>().0.push(i);
+                })
+                .unwrap();
+            clone
+                .queue(move |world: &mut World| {
+                    world.get_resource_or_init::<Order>().0.push(10 + i);
+                })
+                .unwrap();
+        }
+        world.apply_async_commands();
+        assert_eq!(world.resource::<Order>().0, vec![0, 10, 1, 11, 2, 12]);
+    }
+
+    #[test]
+    fn closure_with_world_access() {
+        let mut world = World::new();
+        world.spawn(Loaded(1));
+        let commands = world.async_commands();
+        let handle = commands.clone();
+        commands
+            .queue(move |world: &mut World| {
+                let total = world.query::<&Loaded>().iter(world).map(|l| l.0).sum();
+                world.insert_resource(Order(vec![total]));
+                // Commands queued while applying are left for the next call.
+                handle.insert_resource(Order(vec![])).unwrap();
+            })
+            .unwrap();
+
+        world.apply_async_commands();
+        assert_eq!(world.resource::<Order>().0, vec![1]);
+        world.apply_async_commands();
+        assert_eq!(world.resource::<Order>().0, vec![]);
+    }
+
+    #[test]
+    fn closed_after_world_is_dropped() {
+        let mut world = World::new();
+        let commands = world.async_commands();
+        commands.spawn(Loaded(0)).unwrap();
+        assert!(!commands.is_closed());
+
+        drop(world);
+        assert!(commands.is_closed());
+        assert_eq!(commands.spawn(Loaded(1)), Err(AsyncCommandsClosedError));
+        assert_eq!(
+            thread::spawn(move || commands.insert_resource(Order::default()))
+                .join()
+                .unwrap(),
+            Err(AsyncCommandsClosedError)
+        );
+    }
+}
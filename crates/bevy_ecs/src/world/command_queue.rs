@@ -322,7 +322,8 @@ impl SystemBuffer for CommandQueue {
     #[inline]
     fn apply(&mut self, _system_meta: &SystemMeta, world: &mut World) {
         #[cfg(feature = "trace")]
-        let _span_guard = _system_meta.commands_span.enter();
+        let _span_guard =
+            crate::schedule::command_spans().then(|| _system_meta.commands_span.enter());
         self.apply(world);
     }
 
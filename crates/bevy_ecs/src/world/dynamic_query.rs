@@ -0,0 +1,687 @@
+//! Queries whose filter is only known at runtime, such as the ones typed in a dev console.
+//!
+//! A [`DynamicQueryFilter`] refers to components by the name they were registered with in the
+//! [`AppTypeRegistry`], and [`World::dynamic_query`] returns a page of the matching entities with
+//! their reflected component values, ready to be displayed.
+
+use alloc::{
+    borrow::ToOwned,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{fmt, str::FromStr};
+
+use bevy_reflect::{TypeRegistration, TypeRegistry};
+use thiserror::Error;
+
+use crate::{
+    component::{ComponentId, Tick},
+    entity::Entity,
+    entity_disabling::DefaultQueryFilters,
+    name::Name,
+    reflect::{AppTypeRegistry, ReflectComponent},
+    world::World,
+};
+
+/// A query filter built from component names, for [`World::dynamic_query`].
+///
+/// Components are referred to by their type path or their short type path, as registered in the
+/// [`AppTypeRegistry`], and must have [`ReflectComponent`] type data.
+///
+/// ```
+/// # use bevy_ecs::{prelude::*, world::dynamic_query::DynamicQueryFilter};
+/// # use bevy_reflect::Reflect;
+/// #[derive(Component, Reflect)]
+/// #[reflect(Component)]
+/// struct Health(f32);
+///
+/// #[derive(Component, Reflect)]
+/// #[reflect(Component)]
+/// struct Dead;
+///
+/// let mut world = World::new();
+/// world.init_resource::<AppTypeRegistry>();
+/// {
+///     let mut registry = world.resource::<AppTypeRegistry>().write();
+///     registry.register::<Health>();
+///     registry.register::<Dead>();
+/// }
+/// world.spawn(Health(10.0));
+/// world.spawn((Health(0.0), Dead));
+///
+/// let filter = DynamicQueryFilter::new().with("Health").without("Dead");
+/// let results = world.dynamic_query(&filter).unwrap();
+/// assert_eq!(results.total(), 1);
+///
+/// // The same filter, as typed in a console.
+/// let filter: DynamicQueryFilter = "With<Health> Without<Dead>".parse().unwrap();
+/// assert_eq!(world.dynamic_query(&filter).unwrap().total(), 1);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DynamicQueryFilter {
+    with: Vec<String>,
+    without: Vec<String>,
+    changed: Vec<String>,
+    changed_since: Option<Tick>,
+    offset: usize,
+    limit: usize,
+}
+
+impl Default for DynamicQueryFilter {
+    fn default() -> Self {
+        Self {
+            with: Vec::new(),
+            without: Vec::new(),
+            changed: Vec::new(),
+            changed_since: None,
+            offset: 0,
+            limit: Self::DEFAULT_LIMIT,
+        }
+    }
+}
+
+impl DynamicQueryFilter {
+    /// The number of entities returned by default.
+    pub const DEFAULT_LIMIT: usize = 50;
+
+    /// Creates a filter that matches every entity.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only matches entities with the component `name`, like [`With`](crate::query::With).
+    ///
+    /// The value of the component is included in the results.
+    pub fn with(mut self, name: impl Into<String>) -> Self {
+        self.with.push(name.into());
+        self
+    }
+
+    /// Only matches entities without the component `name`, like [`Without`](crate::query::Without).
+    pub fn without(mut self, name: impl Into<String>) -> Self {
+        self.without.push(name.into());
+        self
+    }
+
+    /// Only matches entities whose component `name` changed, like [`Changed`](crate::query::Changed).
+    ///
+    /// The value of the component is included in the results.
+    pub fn changed(mut self, name: impl Into<String>) -> Self {
+        self.changed.push(name.into());
+        self
+    }
+
+    /// Sets the tick that [`changed`](Self::changed) compares against.
+    ///
+    /// Defaults to [`World::last_change_tick`], as for a query run directly on the [`World`].
+    pub fn changed_since(mut self, tick: Tick) -> Self {
+        self.changed_since = Some(tick);
+        self
+    }
+
+    /// Skips the first `offset` matching entities.
+    pub fn offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Returns at most `limit` matching entities.
+    ///
+    /// Only the returned entities are reflected, which bounds the cost of a query matching many
+    /// entities. Defaults to [`DEFAULT_LIMIT`](Self::DEFAULT_LIMIT).
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+}
+
+impl FromStr for DynamicQueryFilter {
+    type Err = DynamicQueryError;
+
+    /// Parses whitespace separated terms: `With<Name>`, `Without<Name>`, `Changed<Name>`,
+    /// `offset=N` and `limit=N`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut filter = Self::new();
+        for term in s.split_whitespace() {
+            let invalid = || DynamicQueryError::InvalidTerm(term.to_owned());
+            if let Some((key, value)) = term.split_once('=') {
+                let value = value.parse().map_err(|_| invalid())?;
+                filter = match key {
+                    "offset" => filter.offset(value),
+                    "limit" => filter.limit(value),
+                    _ => return Err(invalid()),
+                };
+                continue;
+            }
+            let (kind, name) = term
+                .strip_suffix('>')
+                .and_then(|term| term.split_once('<'))
+                .filter(|(_, name)| !name.is_empty())
+                .ok_or_else(invalid)?;
+            filter = match kind {
+                "With" => filter.with(name),
+                "Without" => filter.without(name),
+                "Changed" => filter.changed(name),
+                _ => return Err(invalid()),
+            };
+        }
+        Ok(filter)
+    }
+}
+
+/// A page of the entities matching a [`DynamicQueryFilter`], returned by [`World::dynamic_query`].
+///
+/// The [`Display`](fmt::Display) implementation lists the entities with their component values.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DynamicQueryResults {
+    total: usize,
+    offset: usize,
+    entities: Vec<DynamicQueryEntity>,
+}
+
+impl DynamicQueryResults {
+    /// Returns the number of entities matching the filter, including the ones outside this page.
+    pub fn total(&self) -> usize {
+        self.total
+    }
+
+    /// Returns the number of matching entities skipped before this page.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the matching entities in this page.
+    pub fn entities(&self) -> &[DynamicQueryEntity] {
+        &self.entities
+    }
+}
+
+impl fmt::Display for DynamicQueryResults {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.entities.is_empty() {
+            return write!(f, "{} matching entities, none shown", self.total);
+        }
+        write!(
+            f,
+            "{} matching entities, showing {}-{}",
+            self.total,
+            self.offset + 1,
+            self.offset + self.entities.len()
+        )?;
+        for entity in &self.entities {
+            write!(f, "\n{entity}")?;
+        }
+        Ok(())
+    }
+}
+
+/// An entity matching a [`DynamicQueryFilter`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DynamicQueryEntity {
+    /// The matching entity.
+    pub entity: Entity,
+    /// The [`Name`] of the entity, if it has one.
+    pub name: Option<String>,
+    /// The components the filter requires, in the order they appear in the filter.
+    pub components: Vec<DynamicQueryComponent>,
+}
+
+impl fmt::Display for DynamicQueryEntity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.entity)?;
+        if let Some(name) = &self.name {
+            write!(f, " {name:?}")?;
+        }
+        for component in &self.components {
+            write!(f, "\n  {}: {}", component.name, component.value)?;
+        }
+        Ok(())
+    }
+}
+
+/// The reflected value of a component of a [`DynamicQueryEntity`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DynamicQueryComponent {
+    /// The short type path of the component.
+    pub name: &'static str,
+    /// The [`Debug`] representation of the reflected component value.
+    pub value: String,
+}
+
+/// An error returned by [`World::dynamic_query`], or when parsing a [`DynamicQueryFilter`].
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum DynamicQueryError {
+    /// The [`World`] was missing the [`AppTypeRegistry`] resource.
+    #[error("The `World` was missing the `AppTypeRegistry` resource")]
+    MissingAppTypeRegistry,
+    /// No type is registered with this name.
+    #[error("No type named `{0}` is registered")]
+    UnknownComponent(String),
+    /// Several registered types have this short type path.
+    #[error("Several types are named `{0}`, use the full type path instead")]
+    AmbiguousComponent(String),
+    /// The type is registered, but not as a component.
+    #[error("`{0}` is not a component (is it missing `#[reflect(Component)]`?)")]
+    NotAComponent(String),
+    /// A term of the filter couldn't be parsed.
+    #[error("Invalid query term `{0}`, expected `With<Name>`, `Without<Name>`, `Changed<Name>`, `offset=N` or `limit=N`")]
+    InvalidTerm(String),
+}
+
+/// A component of the filter, resolved through the type registry.
+struct ResolvedComponent {
+    /// `None` if the component was never used in the world.
+    id: Option<ComponentId>,
+    name: &'static str,
+    reflect: ReflectComponent,
+}
+
+impl ResolvedComponent {
+    fn resolve(
+        world: &World,
+        registry: &TypeRegistry,
+        name: &str,
+    ) -> Result<Self, DynamicQueryError> {
+        let registration = registry
+            .get_with_type_path(name)
+            .or_else(|| registry.get_with_short_type_path(name))
+            .ok_or_else(|| {
+                if registry.is_ambiguous(name) {
+                    DynamicQueryError::AmbiguousComponent(name.to_owned())
+                } else {
+                    DynamicQueryError::UnknownComponent(name.to_owned())
+                }
+            })?;
+        let reflect = registration
+            .data::<ReflectComponent>()
+            .ok_or_else(|| DynamicQueryError::NotAComponent(name.to_owned()))?;
+        Ok(Self {
+            id: world.components().get_valid_id(registration.type_id()),
+            name: short_path(registration),
+            reflect: reflect.clone(),
+        })
+    }
+}
+
+fn short_path(registration: &TypeRegistration) -> &'static str {
+    registration.type_info().type_path_table().short_path()
+}
+
+impl World {
+    /// Returns the entities matching a [`DynamicQueryFilter`], with the values of the components
+    /// the filter requires.
+    ///
+    /// This behaves like a typed query run on the [`World`], including ignoring
+    /// [disabled entities](crate::entity_disabling) unless the filter mentions the disabling
+    /// component. Only the page of entities selected by [`DynamicQueryFilter::offset`] and
+    /// [`DynamicQueryFilter::limit`] is reflected.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the [`AppTypeRegistry`] is missing, or if a component name of the
+    /// filter can't be resolved. See [`DynamicQueryError`].
+    pub fn dynamic_query(
+        &self,
+        filter: &DynamicQueryFilter,
+    ) -> Result<DynamicQueryResults, DynamicQueryError> {
+        let registry = self
+            .get_resource::<AppTypeRegistry>()
+            .ok_or(DynamicQueryError::MissingAppTypeRegistry)?
+            .read();
+        let resolve = |names: &[String]| {
+            names
+                .iter()
+                .map(|name| ResolvedComponent::resolve(self, &registry, name))
+                .collect::<Result<Vec<_>, _>>()
+        };
+        let with = resolve(&filter.with)?;
+        let without = resolve(&filter.without)?;
+        let changed = resolve(&filter.changed)?;
+
+        let mut results = DynamicQueryResults {
+            offset: filter.offset,
+            ..Default::default()
+        };
+        // No entity has a component that was never used.
+        let (Some(with_ids), Some(changed_ids)) = (
+            with.iter().map(|c| c.id).collect::<Option<Vec<_>>>(),
+            changed.iter().map(|c| c.id).collect::<Option<Vec<_>>>(),
+        ) else {
+            return Ok(results);
+        };
+        let mut without_ids = without.iter().filter_map(|c| c.id).collect::<Vec<_>>();
+        if let Some(default_filters) = self.get_resource::<DefaultQueryFilters>() {
+            for id in default_filters.disabling_ids() {
+                if !with_ids.contains(&id)
+                    && !without_ids.contains(&id)
+                    && !changed_ids.contains(&id)
+                {
+                    without_ids.push(id);
+                }
+            }
+        }
+
+        let mut shown = with.iter().collect::<Vec<_>>();
+        shown.extend(
+            changed
+                .iter()
+                .filter(|c| !with_ids.contains(&c.id.unwrap())),
+        );
+
+        let last_run = filter.changed_since.unwrap_or(self.last_change_tick());
+        let this_run = self.read_change_tick();
+        for archetype in self.archetypes().iter() {
+            if !with_ids.iter().all(|&id| archetype.contains(id))
+                || !changed_ids.iter().all(|&id| archetype.contains(id))
+                || without_ids.iter().any(|&id| archetype.contains(id))
+            {
+                continue;
+            }
+            for archetype_entity in archetype.entities() {
+                let entity = self.entity(archetype_entity.id());
+                if !changed_ids.iter().all(|&id| {
+                    entity
+                        .get_change_ticks_by_id(id)
+                        .is_some_and(|ticks| ticks.is_changed(last_run, this_run))
+                }) {
+                    continue;
+                }
+                if results.total >= filter.offset && results.entities.len() < filter.limit {
+                    results.entities.push(DynamicQueryEntity {
+                        entity: entity.id(),
+                        name: entity.get::<Name>().map(|name| name.as_str().to_owned()),
+                        components: shown
+                            .iter()
+                            .map(|component| DynamicQueryComponent {
+                                name: component.name,
+                                value: component.reflect.reflect(entity).map_or_else(
+                                    || "<not reflected>".to_string(),
+                                    |value| format!("{value:?}"),
+                                ),
+                            })
+                            .collect(),
+                    });
+                }
+                results.total += 1;
+            }
+        }
+        Ok(results)
+    }
+
+    /// Runs a `query` console command, such as `query With<Health> Without<Dead>`, and returns
+    /// the text to print.
+    ///
+    /// The arguments of the command are parsed as a [`DynamicQueryFilter`], and the results are
+    /// rendered with their [`Display`](fmt::Display) implementation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the command isn't a `query` command, if it can't be parsed, or if
+    /// [`World::dynamic_query`] fails.
+    pub fn run_query_command(&self, command: &str) -> Result<String, DynamicQueryError> {
+        let command = command.trim_start();
+        let arguments = command
+            .strip_prefix("query")
+            .filter(|arguments| arguments.is_empty() || arguments.starts_with(char::is_whitespace))
+            .ok_or_else(|| {
+                DynamicQueryError::InvalidTerm(
+                    command
+                        .split_whitespace()
+                        .next()
+                        .unwrap_or_default()
+                        .to_owned(),
+                )
+            })?;
+        let filter = arguments.parse::<DynamicQueryFilter>()?;
+        Ok(self.dynamic_query(&filter)?.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{string::ToString, vec::Vec};
+
+    use bevy_reflect::Reflect;
+
+    use super::*;
+    use crate::{
+        entity_disabling::Disabled,
+        prelude::{Changed, Component, With, Without},
+        query::QueryFilter,
+    };
+
+    #[derive(Component, Reflect)]
+    #[reflect(Component)]
+    struct Health {
+        current: f32,
+        max: f32,
+    }
+
+    #[derive(Component, Reflect)]
+    #[reflect(Component)]
+    struct Dead;
+
+    #[derive(Component, Reflect)]
+    #[reflect(Component)]
+    struct Poisoned;
+
+    #[derive(Reflect)]
+    struct NotAComponent;
+
+    mod other {
+        use crate::prelude::Component;
+        use bevy_reflect::Reflect;
+
+        #[derive(Component, Reflect)]
+        pub struct Health;
+    }
+
+    fn world() -> World {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        {
+            let mut registry = world.resource::<AppTypeRegistry>().write();
+            registry.register::<Health>();
+            registry.register::<Dead>();
+            registry.register::<Poisoned>();
+            registry.register::<NotAComponent>();
+            registry.register::<Disabled>();
+        }
+        world
+    }
+
+    fn health(current: f32) -> Health {
+        Health { current, max: 10.0 }
+    }
+
+    fn sorted(mut entities: Vec<Entity>) -> Vec<Entity> {
+        entities.sort();
+        entities
+    }
+
+    fn dynamic(world: &World, filter: &str) -> Vec<Entity> {
+        let filter = filter
+            .parse::<DynamicQueryFilter>()
+            .unwrap()
+            .limit(usize::MAX);
+        let results = world.dynamic_query(&filter).unwrap();
+        assert_eq!(results.total(), results.entities().len());
+        sorted(results.entities().iter().map(|e| e.entity).collect())
+    }
+
+    fn typed<F: QueryFilter>(world: &mut World) -> Vec<Entity> {
+        sorted(world.query_filtered::<Entity, F>().iter(world).collect())
+    }
+
+    #[test]
+    fn name_resolution_errors() {
+        let mut world = world();
+        let query = |world: &World, filter: DynamicQueryFilter| world.dynamic_query(&filter);
+
+        assert_eq!(
+            query(&world, DynamicQueryFilter::new().with("Mana")),
+            Err(DynamicQueryError::UnknownComponent("Mana".to_string()))
+        );
+        assert_eq!(
+            query(&world, DynamicQueryFilter::new().without("NotAComponent")),
+            Err(DynamicQueryError::NotAComponent(
+                "NotAComponent".to_string()
+            ))
+        );
+        assert_eq!(
+            "With<Health> Whatever<Dead>".parse::<DynamicQueryFilter>(),
+            Err(DynamicQueryError::InvalidTerm("Whatever<Dead>".to_string()))
+        );
+        assert_eq!(
+            "limit=many".parse::<DynamicQueryFilter>(),
+            Err(DynamicQueryError::InvalidTerm("limit=many".to_string()))
+        );
+        assert_eq!(
+            world.run_query_command("spawn With<Health>"),
+            Err(DynamicQueryError::InvalidTerm("spawn".to_string()))
+        );
+
+        // Full type paths resolve too, and disambiguate identical short type paths.
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<other::Health>();
+        assert_eq!(
+            query(&world, DynamicQueryFilter::new().with("Health")),
+            Err(DynamicQueryError::AmbiguousComponent("Health".to_string()))
+        );
+        assert!(query(
+            &world,
+            DynamicQueryFilter::new().with(core::any::type_name::<Health>())
+        )
+        .is_ok());
+
+        world.remove_resource::<AppTypeRegistry>();
+        assert_eq!(
+            query(&world, DynamicQueryFilter::new()),
+            Err(DynamicQueryError::MissingAppTypeRegistry)
+        );
+    }
+
+    #[test]
+    fn filters_match_typed_queries() {
+        let mut world = world();
+        // Not used by any entity yet.
+        assert!(dynamic(&world, "With<Poisoned>").is_empty());
+
+        world.spawn(health(10.0));
+        world.spawn((health(0.0), Dead));
+        world.spawn((health(5.0), Poisoned));
+        world.spawn(Dead);
+        world.spawn((health(8.0), Disabled));
+        world.clear_trackers();
+        let added = world.spawn(health(10.0)).id();
+        world.spawn(Poisoned);
+
+        assert_eq!(
+            dynamic(&world, "With<Health>"),
+            typed::<With<Health>>(&mut world)
+        );
+        assert_eq!(
+            dynamic(&world, "With<Health> Without<Dead>"),
+            typed::<(With<Health>, Without<Dead>)>(&mut world)
+        );
+        assert_eq!(
+            dynamic(&world, "Without<Health>"),
+            typed::<Without<Health>>(&mut world)
+        );
+        assert_eq!(
+            dynamic(&world, "With<Health> With<Disabled>"),
+            typed::<(With<Health>, With<Disabled>)>(&mut world)
+        );
+        assert_eq!(dynamic(&world, "Changed<Health>"), [added]);
+        assert_eq!(
+            dynamic(&world, "Changed<Health>"),
+            typed::<Changed<Health>>(&mut world)
+        );
+
+        let mut entity = world.query_filtered::<Entity, With<Poisoned>>();
+        let poisoned = entity.iter(&world).next().unwrap();
+        world.clear_trackers();
+        world.get_mut::<Health>(poisoned).unwrap().current = 4.0;
+        assert_eq!(dynamic(&world, "Changed<Health>"), [poisoned]);
+        assert_eq!(
+            dynamic(&world, "Changed<Health>"),
+            typed::<Changed<Health>>(&mut world)
+        );
+    }
+
+    #[test]
+    fn pagination() {
+        let mut world = world();
+        let mut all = (0..5)
+            .map(|i| world.spawn(health(i as f32)).id())
+            .collect::<Vec<_>>();
+        world.spawn(Dead);
+
+        let mut paged = Vec::new();
+        for offset in [0, 2, 4] {
+            let filter = DynamicQueryFilter::new()
+                .with("Health")
+                .offset(offset)
+                .limit(2);
+            let results = world.dynamic_query(&filter).unwrap();
+            assert_eq!(results.total(), 5);
+            assert_eq!(results.offset(), offset);
+            assert_eq!(results.entities().len(), if offset == 4 { 1 } else { 2 });
+            paged.extend(results.entities().iter().map(|e| e.entity));
+        }
+        all.sort();
+        assert_eq!(sorted(paged), all);
+
+        let results = world
+            .dynamic_query(&"With<Health> offset=5".parse().unwrap())
+            .unwrap();
+        assert_eq!(results.total(), 5);
+        assert!(results.entities().is_empty());
+        assert_eq!(results.to_string(), "5 matching entities, none shown");
+    }
+
+    #[test]
+    fn reflected_values() {
+        let mut world = world();
+        let player = world
+            .spawn((
+                Name::new("Player"),
+                Health {
+                    current: 7.5,
+                    max: 10.0,
+                },
+            ))
+            .id();
+
+        let results = world
+            .dynamic_query(&DynamicQueryFilter::new().with("Health").changed("Health"))
+            .unwrap();
+        let health_value = format!(
+            "{} {{ current: 7.5, max: 10.0 }}",
+            core::any::type_name::<Health>()
+        );
+        assert_eq!(
+            results.entities(),
+            [DynamicQueryEntity {
+                entity: player,
+                name: Some("Player".to_string()),
+                components: alloc::vec![DynamicQueryComponent {
+                    name: "Health",
+                    value: health_value.clone(),
+                }],
+            }]
+        );
+
+        assert_eq!(
+            world
+                .run_query_command("query With<Health> Without<Dead>")
+                .unwrap(),
+            format!(
+                "1 matching entities, showing 1-1\n{player} \"Player\"\n  Health: {health_value}"
+            )
+        );
+    }
+}
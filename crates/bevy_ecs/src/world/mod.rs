@@ -1,7 +1,10 @@
 //! Defines the [`World`] and APIs for accessing it directly.
 
+mod async_commands;
 pub(crate) mod command_queue;
 mod deferred_world;
+#[cfg(feature = "bevy_reflect")]
+pub mod dynamic_query;
 mod entity_fetch;
 mod entity_ref;
 pub mod error;
@@ -23,6 +26,7 @@ use crate::{
     lifecycle::{ComponentHooks, ADD, DESPAWN, INSERT, REMOVE, REPLACE},
     prelude::{Add, Despawn, Insert, Remove, Replace},
 };
+pub use async_commands::*;
 pub use bevy_ecs_macros::FromWorld;
 use bevy_utils::prelude::DebugName;
 pub use deferred_world::DeferredWorld;
@@ -69,7 +73,7 @@ use crate::{
 use alloc::{boxed::Box, vec::Vec};
 use bevy_platform::sync::atomic::{AtomicU32, Ordering};
 use bevy_ptr::{OwningPtr, Ptr, UnsafeCellDeref};
-use core::{any::TypeId, fmt};
+use core::{any::TypeId, fmt, ops::Range};
 use log::warn;
 use unsafe_world_cell::{UnsafeEntityCell, UnsafeWorldCell};
 
@@ -216,6 +220,29 @@ impl World {
         &mut self.entities
     }
 
+    /// Sets aside `len` entity rows starting at index `start` and returns their indices.
+    ///
+    /// Until [`end_reserved_entity_range`](Self::end_reserved_entity_range) is called,
+    /// [`Commands::spawn`] and [`Commands::spawn_empty`] in systems take their ids from this range,
+    /// choosing the row from the system's name and how many entities it has spawned so far.
+    /// Ids handed out this way do not depend on which other systems ran or spawned before.
+    /// See [`Entities::begin_reserved_range`] for details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a reserved range is already open.
+    pub fn begin_reserved_entity_range(&mut self, start: u32, len: u32) -> Range<u32> {
+        self.flush_entities();
+        self.entities.begin_reserved_range(start, len)
+    }
+
+    /// Closes the range opened by [`begin_reserved_entity_range`](Self::begin_reserved_entity_range),
+    /// returning its unclaimed rows to the regular allocator. Returns the number of claimed rows.
+    pub fn end_reserved_entity_range(&mut self) -> u32 {
+        self.flush_entities();
+        self.entities.end_reserved_range()
+    }
+
     /// Retrieves this world's [`Archetypes`] collection.
     #[inline]
     pub fn archetypes(&self) -> &Archetypes {
@@ -1094,6 +1121,27 @@ impl World {
         unsafe { self.spawn_at_empty_internal(entity, MaybeLocation::caller()) }
     }
 
+    /// Spawns each of `entities` without any components, reusing their exact ids, for example
+    /// to restore a snapshot of the world so that stored references to the entities are valid
+    /// again.
+    ///
+    /// If the row of one of the entities is in use, or an entity appears twice, nothing is
+    /// spawned and that entity is returned in the error.
+    ///
+    /// The ids given to other entities using these rows since `entities` were despawned stay
+    /// invalid, see [`Entities::alloc_at`].
+    #[track_caller]
+    pub fn spawn_empty_at(&mut self, entities: &[Entity]) -> Result<(), Entity> {
+        self.flush();
+        self.entities.alloc_at(entities)?;
+        let caller = MaybeLocation::caller();
+        for &entity in entities {
+            // SAFETY: entity was just allocated
+            unsafe { self.spawn_at_empty_internal(entity, caller) };
+        }
+        Ok(())
+    }
+
     /// Spawns a new [`Entity`] with a given [`Bundle`] of [components](`Component`) and returns
     /// a corresponding [`EntityWorldMut`], which can be used to add components to the entity or
     /// retrieve its id. In case large batches of entities need to be spawned, consider using
@@ -3667,7 +3715,7 @@ mod tests {
     use crate::{
         change_detection::{DetectChangesMut, MaybeLocation},
         component::{ComponentCloneBehavior, ComponentDescriptor, ComponentInfo, StorageType},
-        entity::EntityHashSet,
+        entity::{Entity, EntityHashSet},
         entity_disabling::{DefaultQueryFilters, Disabled},
         ptr::OwningPtr,
         resource::Resource,
@@ -4221,6 +4269,52 @@ mod tests {
         world.spawn(());
     }
 
+    #[test]
+    fn spawn_empty_at() {
+        let mut world = World::new();
+        let e1 = world.spawn_empty().id();
+        let e2 = world.spawn_empty().id();
+        world.despawn(e1);
+        world.despawn(e2);
+        // The row of `e2` is reused with a newer generation.
+        let reused = world.spawn_empty().id();
+        assert_eq!(reused.index(), e2.index());
+
+        // Live entities, rows in use and duplicates can't be spawned at, and nothing is spawned
+        // when one of them can't.
+        assert_eq!(world.spawn_empty_at(&[e1, reused]), Err(reused));
+        assert_eq!(world.spawn_empty_at(&[e1, e2]), Err(e2));
+        assert_eq!(world.spawn_empty_at(&[e1, e1]), Err(e1));
+        assert!(world.get_entity(e1).is_err());
+
+        // Rows above the allocated ones can be spawned at, the rows in between are free.
+        let far = Entity::from_raw_u32(10).unwrap();
+        assert_eq!(world.spawn_empty_at(&[far, e1]), Ok(()));
+        assert!(world.get_entity(e1).is_ok());
+        assert!(world.get_entity(far).is_ok());
+        assert_eq!(world.spawn_empty().id().index(), 2);
+        assert_eq!(world.spawn_empty().id().index(), 3);
+    }
+
+    #[test]
+    fn spawn_empty_at_does_not_revive_newer_ids() {
+        let mut world = World::new();
+        let old = world.spawn_empty().id();
+        world.despawn(old);
+        let newer = world.spawn_empty().id();
+        world.despawn(newer);
+
+        world.spawn_empty_at(&[old]).unwrap();
+        assert!(world.get_entity(newer).is_err());
+
+        // Once the row is reused again, it skips the generation of `newer`.
+        world.despawn(old);
+        let reused = world.spawn_empty().id();
+        assert_eq!(reused.index(), old.index());
+        assert_ne!(reused, newer);
+        assert!(world.get_entity(newer).is_err());
+    }
+
     #[test]
     fn get_entity() {
         let mut world = World::new();
@@ -1,4 +1,7 @@
-use bevy_app::{plugin_group, Plugin};
+use bevy_app::{
+    plugin_group, FrameLimitPlugin, Plugin, PluginGroup, PluginGroupBuilder, ScheduleRunnerPlugin,
+};
+use core::time::Duration;
 
 plugin_group! {
     /// This plugin group will add all the default plugins for a *Bevy* application:
@@ -19,7 +22,7 @@ plugin_group! {
         #[cfg(feature = "bevy_window")]
         bevy_a11y:::AccessibilityPlugin,
         #[cfg(feature = "std")]
-        #[custom(cfg(any(all(unix, not(target_os = "horizon")), windows)))]
+        #[custom(cfg(any(all(unix, not(target_os = "horizon")), windows, all(target_arch = "wasm32", feature = "web"))))]
         bevy_app:::TerminalCtrlCHandlerPlugin,
         #[cfg(feature = "bevy_asset")]
         bevy_asset:::AssetPlugin,
@@ -121,6 +124,63 @@ impl Plugin for IgnoreAmbiguitiesPlugin {
     }
 }
 
+plugin_group! {
+    /// This plugin group will add the plugins for a *Bevy* application that runs without a display
+    /// or an audio device:
+    pub struct HeadlessPlugins {
+        bevy_app:::PanicHandlerPlugin,
+        #[cfg(feature = "bevy_log")]
+        bevy_log:::LogPlugin,
+        bevy_app:::TaskPoolPlugin,
+        bevy_diagnostic:::FrameCountPlugin,
+        bevy_time:::TimePlugin,
+        bevy_transform:::TransformPlugin,
+        bevy_diagnostic:::DiagnosticsPlugin,
+        bevy_app:::ScheduleRunnerPlugin,
+        #[cfg(feature = "std")]
+        #[custom(cfg(any(all(unix, not(target_os = "horizon")), windows, all(target_arch = "wasm32", feature = "web"))))]
+        bevy_app:::TerminalCtrlCHandlerPlugin,
+        #[cfg(feature = "bevy_asset")]
+        bevy_asset:::AssetPlugin,
+        #[cfg(feature = "bevy_ci_testing")]
+        bevy_dev_tools::ci_testing:::CiTestingPlugin,
+    }
+    /// Use this for dedicated servers or CI builds, instead of disabling the window, rendering,
+    /// audio and input device plugins of [`DefaultPlugins`] one by one. Like [`DefaultPlugins`],
+    /// [`HeadlessPlugins`] obeys *Cargo* *feature* flags, but it never includes a plugin requiring
+    /// a display or an audio device, whatever the enabled features.
+    ///
+    /// The app is driven by a
+    /// [schedule runner (`ScheduleRunnerPlugin`)](crate::app::ScheduleRunnerPlugin), which runs
+    /// as fast as possible by default. Use [`HeadlessPlugins::with_tick_rate`] to run a fixed
+    /// number of frames per second instead.
+    /// # Example:
+    /// ```rust, no_run
+    /// # use bevy_app::App;
+    /// # use bevy_internal::HeadlessPlugins;
+    /// App::new().add_plugins(HeadlessPlugins::with_tick_rate(30.0)).run();
+    /// ```
+}
+
+impl HeadlessPlugins {
+    /// Returns the [`HeadlessPlugins`] with a schedule runner that runs `ticks_per_second`
+    /// frames per second.
+    ///
+    /// The other plugins of the group can still be configured with the returned builder.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ticks_per_second` isn't strictly positive, or is NaN, or is so small that the
+    /// time between two frames doesn't fit in a [`Duration`].
+    pub fn with_tick_rate(ticks_per_second: f64) -> PluginGroupBuilder {
+        let wait = Some(ticks_per_second)
+            .filter(|&rate| rate > 0.0)
+            .and_then(|rate| Duration::try_from_secs_f64(rate.recip()).ok())
+            .unwrap_or_else(|| panic!("invalid tick rate: {ticks_per_second} ticks per second"));
+        Self.set(ScheduleRunnerPlugin::run_loop(wait))
+    }
+}
+
 plugin_group! {
     /// This plugin group will add the minimal plugins for a *Bevy* application:
     pub struct MinimalPlugins {
@@ -151,3 +211,54 @@ plugin_group! {
     ///     Duration::from_secs_f64(1.0 / 60.0),
     /// ))).run();
 }
+
+/// This plugin group will add the plugins for headless tests and examples:
+/// - [`TaskPoolPlugin`](crate::app::TaskPoolPlugin)
+/// - [`FrameCountPlugin`](crate::diagnostic::FrameCountPlugin)
+/// - [`TimePlugin`](crate::time::TimePlugin)
+/// - [`ScheduleRunnerPlugin`], looping as fast as possible
+/// - [`LogPlugin`](crate::log::LogPlugin) - with feature `bevy_log`, logging only warnings and
+///   errors
+/// - [`FrameLimitPlugin`], exiting after a single frame
+///
+/// This is [`MinimalPlugins`] with logging and a frame limit, so that tests and examples don't
+/// have to wire them by hand. Use [`TestPlugins::with_frame_limit`] to run more frames, and
+/// [`PluginGroup::set`] to configure the plugins like with the other groups.
+///
+/// # Example:
+/// ```rust, no_run
+/// # use bevy_app::{App, PluginGroup};
+/// # use bevy_internal::{log::{Level, LogPlugin}, TestPlugins};
+/// App::new()
+///     .add_plugins(TestPlugins::with_frame_limit(10).set(LogPlugin {
+///         level: Level::DEBUG,
+///         ..Default::default()
+///     }))
+///     .run();
+/// ```
+pub struct TestPlugins;
+
+impl TestPlugins {
+    /// Returns the [`TestPlugins`] with a [`FrameLimitPlugin`] exiting after `frames` frames.
+    ///
+    /// The other plugins of the group can still be configured with the returned builder.
+    pub fn with_frame_limit(frames: u32) -> PluginGroupBuilder {
+        Self.set(FrameLimitPlugin::new(frames))
+    }
+}
+
+impl PluginGroup for TestPlugins {
+    fn build(self) -> PluginGroupBuilder {
+        let group = PluginGroupBuilder::start::<Self>()
+            .add(bevy_app::TaskPoolPlugin::default())
+            .add(bevy_diagnostic::FrameCountPlugin)
+            .add(bevy_time::TimePlugin)
+            .add(ScheduleRunnerPlugin::default());
+        #[cfg(feature = "bevy_log")]
+        let group = group.add(bevy_log::LogPlugin {
+            level: bevy_log::Level::WARN,
+            ..Default::default()
+        });
+        group.add(FrameLimitPlugin::default())
+    }
+}
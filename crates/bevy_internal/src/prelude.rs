@@ -2,7 +2,7 @@
 pub use crate::{
     app::prelude::*, ecs::prelude::*, input::prelude::*, math::prelude::*, platform::prelude::*,
     reflect::prelude::*, time::prelude::*, transform::prelude::*, utils::prelude::*,
-    DefaultPlugins, MinimalPlugins,
+    DefaultPlugins, HeadlessPlugins, MinimalPlugins, TestPlugins,
 };
 
 #[doc(hidden)]
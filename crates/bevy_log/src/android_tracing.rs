@@ -5,10 +5,54 @@ use tracing::{
     span::{Attributes, Record},
     Event, Id, Level, Subscriber,
 };
+use tracing_log::NormalizeEvent;
 use tracing_subscriber::{field::Visit, layer::Context, registry::LookupSpan, Layer};
 
-#[derive(Default)]
-pub(crate) struct AndroidLayer;
+/// The maximum length of a logcat message, in bytes. Logcat truncates longer messages, so they
+/// are split in several messages.
+const MAX_MESSAGE_LEN: usize = 4000;
+
+pub(crate) struct AndroidLayer {
+    /// The logcat tag of all the logs, or `None` to tag each log with the crate that wrote it.
+    tag: Option<CString>,
+}
+
+impl AndroidLayer {
+    pub(crate) fn new(tag: Option<&str>) -> Self {
+        Self {
+            tag: tag.map(sanitize),
+        }
+    }
+}
+
+/// Removes the nul bytes of `string`, which would end it early.
+fn sanitize(string: &str) -> CString {
+    let bytes: Vec<u8> = string
+        .as_bytes()
+        .iter()
+        .copied()
+        .filter(|byte| *byte != 0)
+        .collect();
+    CString::new(bytes).unwrap()
+}
+
+/// Splits `message` in chunks of at most `max_len` bytes, without splitting its characters.
+fn chunks(message: &str, max_len: usize) -> impl Iterator<Item = &str> {
+    let mut rest = message;
+    core::iter::from_fn(move || {
+        let first_char = rest.chars().next()?;
+        let mut end = rest.len().min(max_len);
+        while !rest.is_char_boundary(end) {
+            end -= 1;
+        }
+        // A character longer than `max_len` is kept whole.
+        let (chunk, next) = rest.split_at(end.max(first_char.len_utf8()));
+        rest = next;
+        Some(chunk)
+    })
+    // An empty message is still written.
+    .chain(message.is_empty().then_some(""))
+}
 
 struct StringRecorder(String, bool);
 impl StringRecorder {
@@ -66,19 +110,12 @@ impl<S: Subscriber + for<'a> LookupSpan<'a>> Layer<S> for AndroidLayer {
 
     #[allow(unsafe_code)]
     fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
-        fn sanitize(string: &str) -> CString {
-            let bytes: Vec<u8> = string
-                .as_bytes()
-                .into_iter()
-                .copied()
-                .filter(|byte| *byte != 0)
-                .collect();
-            CString::new(bytes).unwrap()
-        }
-
         let mut recorder = StringRecorder::new();
         event.record(&mut recorder);
-        let meta = event.metadata();
+        // The logs of the `log` crate all have the same metadata, the actual one is in their
+        // fields.
+        let normalized = event.normalized_metadata();
+        let meta = normalized.as_ref().unwrap_or_else(|| event.metadata());
         let priority = match *meta.level() {
             Level::TRACE => android_log_sys::LogPriority::VERBOSE,
             Level::DEBUG => android_log_sys::LogPriority::DEBUG,
@@ -86,14 +123,41 @@ impl<S: Subscriber + for<'a> LookupSpan<'a>> Layer<S> for AndroidLayer {
             Level::WARN => android_log_sys::LogPriority::WARN,
             Level::ERROR => android_log_sys::LogPriority::ERROR,
         };
-        // SAFETY: Called only on Android platforms. priority is guaranteed to be in range of c_int.
-        // The provided tag and message are null terminated properly.
-        unsafe {
-            android_log_sys::__android_log_write(
-                priority as android_log_sys::c_int,
-                sanitize(meta.name()).as_ptr(),
-                sanitize(&recorder.0).as_ptr(),
-            );
+        let crate_tag;
+        let tag = match &self.tag {
+            Some(tag) => tag,
+            None => {
+                crate_tag = sanitize(meta.target().split("::").next().unwrap_or_default());
+                &crate_tag
+            }
+        };
+        for chunk in chunks(&recorder.0, MAX_MESSAGE_LEN) {
+            // SAFETY: Called only on Android platforms. priority is guaranteed to be in range of c_int.
+            // The provided tag and message are null terminated properly.
+            unsafe {
+                android_log_sys::__android_log_write(
+                    priority as android_log_sys::c_int,
+                    tag.as_ptr(),
+                    sanitize(chunk).as_ptr(),
+                );
+            }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::chunks;
+
+    #[test]
+    fn long_messages_are_split_between_characters() {
+        let message = "ab\u{e9}cd\u{1f980}e";
+        let split: Vec<_> = chunks(message, 4).collect();
+        assert_eq!(split, ["ab\u{e9}", "cd", "\u{1f980}", "e"]);
+        assert_eq!(
+            chunks("\u{1f980}a", 2).collect::<Vec<_>>(),
+            ["\u{1f980}", "a"]
+        );
+        assert_eq!(chunks("", 4).collect::<Vec<_>>(), [""]);
+    }
+}
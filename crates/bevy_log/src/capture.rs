@@ -0,0 +1,569 @@
+use crate::{BoxedLayer, Level};
+use bevy_app::{App, PreUpdate};
+use bevy_ecs::{
+    event::{BufferedEvent, EventWriter},
+    resource::Resource,
+    system::ResMut,
+};
+use bevy_platform::cell::SyncCell;
+use core::fmt::{self, Write};
+use std::sync::mpsc::{self, Receiver, SyncSender};
+use tracing::{
+    field::{Field, Visit},
+    Event, Subscriber,
+};
+use tracing_log::NormalizeEvent;
+use tracing_subscriber::{
+    fmt::{format::DefaultFields, FormattedFields},
+    layer::Context,
+    registry::{LookupSpan, Scope},
+    Layer,
+};
+
+/// Settings of the capture of the logs into the ECS by the [`LogPlugin`](crate::LogPlugin), as
+/// [`LogMessage`] events.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LogCaptureConfig {
+    /// The maximum number of logs written as [`LogMessage`]s each frame.
+    ///
+    /// The logs are buffered between two frames. Once this many logs are waiting, the next ones
+    /// are dropped until the next frame, so that a stalled app doesn't buffer an unbounded
+    /// number of logs.
+    pub max_per_frame: usize,
+    /// Whether the fields of the logs other than their message are captured in
+    /// [`LogMessage::fields`].
+    ///
+    /// Each captured field allocates its name's entry and, for strings and [`Debug`](fmt::Debug)
+    /// values, its text. Disable this if the app logs many fields in hot paths and only the
+    /// messages are needed.
+    ///
+    /// Defaults to `true`.
+    pub fields: bool,
+}
+
+impl Default for LogCaptureConfig {
+    fn default() -> Self {
+        Self {
+            max_per_frame: 1024,
+            fields: true,
+        }
+    }
+}
+
+/// A log captured by the [`LogPlugin`](crate::LogPlugin), when its
+/// [`capture`](crate::LogPlugin::capture) is set.
+///
+/// The logs are written as events in [`PreUpdate`], so the logs of a frame are read during the
+/// next one. Only the logs that pass the [`LogPlugin::filter`](crate::LogPlugin::filter) are
+/// captured.
+///
+/// ```no_run
+/// # use bevy_app::{App, NoopPluginGroup as DefaultPlugins, PluginGroup, Update};
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_log::{LogCaptureConfig, LogMessage, LogPlugin};
+/// fn show_logs(mut logs: EventReader<LogMessage>) {
+///     for log in logs.read() {
+///         // Display `log.message` in the UI, and filter the logs of a player with their fields.
+///         if log.field("player").is_some() {
+///             // ...
+///         }
+///     }
+/// }
+///
+/// App::new()
+///     .add_plugins(DefaultPlugins.set(LogPlugin {
+///         capture: Some(LogCaptureConfig::default()),
+///         ..Default::default()
+///     }))
+///     .add_systems(Update, show_logs)
+///     .run();
+/// ```
+#[derive(BufferedEvent, Clone, Debug, PartialEq)]
+pub struct LogMessage {
+    /// The message of the log.
+    pub message: String,
+    /// The level of the log.
+    pub level: Level,
+    /// The target of the log, usually the path of the module that wrote it.
+    pub target: String,
+    /// The path of the module that wrote the log, if known.
+    pub module_path: Option<String>,
+    /// The source file that wrote the log, if known.
+    pub file: Option<String>,
+    /// The line of the source file that wrote the log, if known.
+    pub line: Option<u32>,
+    /// The names of the spans the log was written in, from the outermost to the innermost.
+    pub spans: Vec<&'static str>,
+    /// The fields of the log other than its message, in the order they were written, such as
+    /// `player` and `health` for `info!(player = ?id, health = 80, "Spawned")`.
+    ///
+    /// This is empty if [`LogCaptureConfig::fields`] is `false`.
+    pub fields: Vec<(&'static str, FieldValue)>,
+    /// The spans the log was written in, from the innermost to the outermost, one per line, with
+    /// their fields, such as:
+    ///
+    /// ```text
+    /// in load_level{name="forest"}
+    /// in system{name="mygame::load_level"}
+    /// in schedule{name=Update}
+    /// ```
+    ///
+    /// This is only set for the errors and warnings written in spans, when the
+    /// [`LogPlugin::span_traces`](crate::LogPlugin::span_traces) are enabled. The fields of the
+    /// spans are only known if they are formatted by the console output or by the `ErrorLayer`
+    /// of the `span_traces` feature.
+    pub span_trace: Option<String>,
+}
+
+impl LogMessage {
+    /// Returns the value of the field named `name`, if the log has one.
+    pub fn field(&self, name: &str) -> Option<&FieldValue> {
+        self.fields
+            .iter()
+            .find(|(field, _)| *field == name)
+            .map(|(_, value)| value)
+    }
+}
+
+/// The value of a field of a [`LogMessage`].
+///
+/// Numbers, booleans and strings keep their type. The other values, such as the ones written with
+/// `?` or `%`, are formatted with [`Debug`](fmt::Debug) or [`Display`](fmt::Display) when the
+/// log is captured.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FieldValue {
+    /// A string, such as `name = "forest"`.
+    Str(String),
+    /// A signed integer, such as `offset = -3`.
+    I64(i64),
+    /// An unsigned integer, such as `level = 3u32`.
+    U64(u64),
+    /// A floating point number, such as `health = 0.5`.
+    F64(f64),
+    /// A boolean, such as `replayed = true`.
+    Bool(bool),
+    /// Any other value, formatted, such as `player = ?id`.
+    Debug(String),
+}
+
+impl FieldValue {
+    /// Returns the string of a [`FieldValue::Str`], or the text of a [`FieldValue::Debug`].
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            FieldValue::Str(value) | FieldValue::Debug(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of a [`FieldValue::I64`], or of a [`FieldValue::U64`] that fits in an
+    /// `i64`.
+    pub fn as_i64(&self) -> Option<i64> {
+        match *self {
+            FieldValue::I64(value) => Some(value),
+            FieldValue::U64(value) => value.try_into().ok(),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of a [`FieldValue::U64`], or of a positive [`FieldValue::I64`].
+    pub fn as_u64(&self) -> Option<u64> {
+        match *self {
+            FieldValue::U64(value) => Some(value),
+            FieldValue::I64(value) => value.try_into().ok(),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of a [`FieldValue::F64`].
+    pub fn as_f64(&self) -> Option<f64> {
+        match *self {
+            FieldValue::F64(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of a [`FieldValue::Bool`].
+    pub fn as_bool(&self) -> Option<bool> {
+        match *self {
+            FieldValue::Bool(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+/// Formats the value as the console output does: strings as is, and the other values with their
+/// [`Debug`](fmt::Debug) formatting.
+impl fmt::Display for FieldValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FieldValue::Str(value) | FieldValue::Debug(value) => f.write_str(value),
+            FieldValue::I64(value) => write!(f, "{value}"),
+            FieldValue::U64(value) => write!(f, "{value}"),
+            FieldValue::F64(value) => write!(f, "{value:?}"),
+            FieldValue::Bool(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+/// The logs captured since the last frame.
+#[derive(Resource)]
+struct CapturedLogs {
+    receiver: SyncCell<Receiver<LogMessage>>,
+    max_per_frame: usize,
+}
+
+/// Creates the layer capturing the logs, and adds the [`LogMessage`] event and the system
+/// writing them to `app`.
+///
+/// The errors and warnings have a [`LogMessage::span_trace`] if `span_traces` is `true`.
+pub(crate) fn capture_layer(
+    config: LogCaptureConfig,
+    span_traces: bool,
+    app: &mut App,
+) -> BoxedLayer {
+    let max_per_frame = config.max_per_frame.max(1);
+    let (sender, receiver) = mpsc::sync_channel(max_per_frame);
+    app.add_event::<LogMessage>()
+        .insert_resource(CapturedLogs {
+            receiver: SyncCell::new(receiver),
+            max_per_frame,
+        })
+        .add_systems(PreUpdate, write_log_messages);
+    Box::new(CaptureLayer {
+        sender,
+        span_traces,
+        fields: config.fields,
+    })
+}
+
+/// Writes the logs captured since the last frame as [`LogMessage`]s.
+fn write_log_messages(mut logs: ResMut<CapturedLogs>, mut messages: EventWriter<LogMessage>) {
+    let max_per_frame = logs.max_per_frame;
+    messages.write_batch(logs.receiver.get().try_iter().take(max_per_frame));
+}
+
+/// Sends the logs to the [`CapturedLogs`].
+struct CaptureLayer {
+    sender: SyncSender<LogMessage>,
+    span_traces: bool,
+    /// Whether the fields other than the message are captured.
+    fields: bool,
+}
+
+impl<S: Subscriber + for<'a> LookupSpan<'a>> Layer<S> for CaptureLayer {
+    fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+        let mut visitor = FieldsVisitor {
+            message: None,
+            fields: Vec::new(),
+            capture_fields: self.fields,
+        };
+        event.record(&mut visitor);
+        let Some(message) = visitor.message else {
+            return;
+        };
+        // The logs of the `log` crate all have the same metadata, the actual one is in their
+        // fields.
+        let normalized = event.normalized_metadata();
+        let metadata = normalized.as_ref().unwrap_or_else(|| event.metadata());
+        let span_trace = if self.span_traces && *metadata.level() <= Level::WARN {
+            ctx.event_scope(event).map(span_trace)
+        } else {
+            None
+        };
+        // The log is dropped if too many logs are waiting, or if the app was dropped.
+        let _ = self.sender.try_send(LogMessage {
+            message,
+            level: *metadata.level(),
+            target: metadata.target().to_string(),
+            module_path: metadata.module_path().map(ToString::to_string),
+            file: metadata.file().map(ToString::to_string),
+            line: metadata.line(),
+            spans: ctx
+                .event_scope(event)
+                .map(|scope| scope.from_root().map(|span| span.name()).collect())
+                .unwrap_or_default(),
+            fields: visitor.fields,
+            span_trace,
+        });
+    }
+}
+
+/// Renders the spans of `scope`, from the innermost to the outermost, with their fields.
+fn span_trace<R: for<'a> LookupSpan<'a>>(scope: Scope<'_, R>) -> String {
+    let mut trace = String::new();
+    for span in scope {
+        if !trace.is_empty() {
+            trace.push('\n');
+        }
+        let _ = write!(trace, "in {}", span.name());
+        let extensions = span.extensions();
+        if let Some(fields) = extensions.get::<FormattedFields<DefaultFields>>()
+            && !fields.is_empty()
+        {
+            let _ = write!(trace, "{{{}}}", fields.fields);
+        }
+    }
+    trace
+}
+
+/// Records the message and, if `capture_fields` is set, the other fields of a log.
+struct FieldsVisitor {
+    message: Option<String>,
+    fields: Vec<(&'static str, FieldValue)>,
+    capture_fields: bool,
+}
+
+impl FieldsVisitor {
+    /// Records the field, creating its value only if it is captured.
+    fn record(&mut self, field: &Field, value: impl FnOnce() -> FieldValue) {
+        match field.name() {
+            "message" => {
+                self.message = Some(match value() {
+                    FieldValue::Str(message) | FieldValue::Debug(message) => message,
+                    other => other.to_string(),
+                });
+            }
+            // The metadata of the logs of the `log` crate, already normalized.
+            name if name.starts_with("log.") => {}
+            name if self.capture_fields => self.fields.push((name, value())),
+            _ => {}
+        }
+    }
+}
+
+impl Visit for FieldsVisitor {
+    fn record_f64(&mut self, field: &Field, value: f64) {
+        self.record(field, || FieldValue::F64(value));
+    }
+
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        self.record(field, || FieldValue::I64(value));
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.record(field, || FieldValue::U64(value));
+    }
+
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        self.record(field, || FieldValue::Bool(value));
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.record(field, || FieldValue::Str(value.to_string()));
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.record(field, || FieldValue::Debug(format!("{value:?}")));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_app::App;
+    use bevy_ecs::event::Events;
+    use tracing::{info, info_span, warn};
+    use tracing_subscriber::{prelude::*, Registry};
+
+    use super::{capture_layer, FieldValue, LogCaptureConfig, LogMessage};
+    use crate::Level;
+
+    fn capture(app: &mut App, config: LogCaptureConfig, log: impl FnOnce()) {
+        let subscriber = Registry::default().with(capture_layer(config, false, app));
+        tracing::subscriber::with_default(subscriber, log);
+    }
+
+    fn messages(app: &App) -> Vec<String> {
+        let events = app.world().resource::<Events<LogMessage>>();
+        events
+            .iter_current_update_events()
+            .map(|log| log.message.clone())
+            .collect()
+    }
+
+    #[test]
+    fn logs_are_written_as_events() {
+        let mut app = App::new();
+        let mut line = 0;
+        capture(&mut app, LogCaptureConfig::default(), || {
+            let _frame = info_span!("frame").entered();
+            let _level = info_span!("level", name = "forest").entered();
+            line = line!() + 1;
+            warn!(answer = 42, question = "unknown", "The answer is {}", 42);
+        });
+        app.update();
+
+        let events = app.world().resource::<Events<LogMessage>>();
+        let log = events.iter_current_update_events().next().unwrap();
+        assert_eq!(
+            log,
+            &LogMessage {
+                message: "The answer is 42".to_string(),
+                level: Level::WARN,
+                target: module_path!().to_string(),
+                module_path: Some(module_path!().to_string()),
+                file: Some(file!().to_string()),
+                line: Some(line),
+                spans: vec!["frame", "level"],
+                fields: vec![
+                    ("answer", FieldValue::I64(42)),
+                    ("question", FieldValue::Str("unknown".to_string()))
+                ],
+                span_trace: None,
+            }
+        );
+    }
+
+    #[test]
+    fn fields_keep_their_type() {
+        #[derive(Debug)]
+        struct PlayerId(u32);
+
+        let mut app = App::new();
+        capture(&mut app, LogCaptureConfig::default(), || {
+            info!(
+                player = ?PlayerId(7),
+                zone = %"forest",
+                name = "Alice",
+                offset = -3,
+                level = 3u32,
+                health = 0.5,
+                alive = true,
+                "Spawned"
+            );
+        });
+        app.update();
+
+        let events = app.world().resource::<Events<LogMessage>>();
+        let log = events.iter_current_update_events().next().unwrap();
+        assert_eq!(log.message, "Spawned");
+        assert_eq!(
+            log.fields,
+            [
+                ("player", FieldValue::Debug("PlayerId(7)".to_string())),
+                ("zone", FieldValue::Debug("forest".to_string())),
+                ("name", FieldValue::Str("Alice".to_string())),
+                ("offset", FieldValue::I64(-3)),
+                ("level", FieldValue::U64(3)),
+                ("health", FieldValue::F64(0.5)),
+                ("alive", FieldValue::Bool(true)),
+            ]
+        );
+        assert_eq!(log.field("offset").and_then(FieldValue::as_i64), Some(-3));
+        assert_eq!(log.field("level").and_then(FieldValue::as_i64), Some(3));
+        assert_eq!(log.field("health").unwrap().to_string(), "0.5");
+        assert_eq!(log.field("player").unwrap().to_string(), "PlayerId(7)");
+        assert_eq!(log.field("missing"), None);
+    }
+
+    #[test]
+    fn fields_can_be_skipped() {
+        let mut app = App::new();
+        let config = LogCaptureConfig {
+            fields: false,
+            ..Default::default()
+        };
+        capture(&mut app, config, || {
+            info!(answer = 42, question = "unknown", "The answer is {}", 42);
+            info!(message = 42);
+        });
+        app.update();
+
+        let events = app.world().resource::<Events<LogMessage>>();
+        let logs: Vec<_> = events
+            .iter_current_update_events()
+            .map(|log| (log.message.as_str(), log.fields.len()))
+            .collect();
+        assert_eq!(logs, [("The answer is 42", 0), ("42", 0)]);
+    }
+
+    #[test]
+    fn logs_are_dropped_past_the_limit() {
+        let mut app = App::new();
+        let config = LogCaptureConfig {
+            max_per_frame: 2,
+            ..Default::default()
+        };
+        capture(&mut app, config, || {
+            info!("first");
+            info!("second");
+            info!("third");
+        });
+        app.update();
+        assert_eq!(messages(&app), ["first", "second"]);
+    }
+
+    #[test]
+    fn logs_are_dropped_once_the_app_is_dropped() {
+        let mut app = App::new();
+        let subscriber =
+            Registry::default().with(capture_layer(LogCaptureConfig::default(), false, &mut app));
+        drop(app);
+        tracing::subscriber::with_default(subscriber, || info!("Nobody is listening"));
+    }
+
+    #[cfg(feature = "tracing-error")]
+    #[test]
+    fn errors_and_warnings_have_span_traces() {
+        use tracing::{error, info_span};
+
+        let mut app = App::new();
+        let subscriber = Registry::default()
+            .with(capture_layer(LogCaptureConfig::default(), true, &mut app))
+            .with(tracing_error::ErrorLayer::default());
+        tracing::subscriber::with_default(subscriber, || {
+            let _schedule = info_span!("schedule", name = "Update").entered();
+            let _system = info_span!("system", name = "mygame::load_level").entered();
+            let _level = info_span!("load_level", name = "forest", attempt = 2).entered();
+            error!("Missing the level file");
+            info!("Loading the default level");
+        });
+        app.update();
+
+        let events = app.world().resource::<Events<LogMessage>>();
+        let span_traces: Vec<_> = events
+            .iter_current_update_events()
+            .map(|log| log.span_trace.as_deref())
+            .collect();
+        assert_eq!(
+            span_traces,
+            [
+                Some(
+                    "in load_level{name=\"forest\" attempt=2}\n\
+                    in system{name=\"mygame::load_level\"}\n\
+                    in schedule{name=\"Update\"}"
+                ),
+                None
+            ]
+        );
+    }
+
+    #[cfg(feature = "early_logs")]
+    #[test]
+    fn logs_written_before_the_plugin_are_replayed() {
+        use crate::LogPlugin;
+
+        let mut app = App::new();
+        info!(answer = 42, "Written before the LogPlugin");
+        // Only the logs of the default level of the plugin are buffered.
+        tracing::debug!("Debug log written before the LogPlugin");
+        app.add_plugins(LogPlugin {
+            capture: Some(LogCaptureConfig::default()),
+            level: Level::TRACE,
+            ..Default::default()
+        });
+        app.update();
+
+        let events = app.world().resource::<Events<LogMessage>>();
+        let log = events
+            .iter_current_update_events()
+            .find(|log| log.message.starts_with("Written before the LogPlugin"))
+            .unwrap();
+        assert_eq!(log.message, "Written before the LogPlugin answer=42");
+        assert_eq!(log.level, Level::INFO);
+        assert_eq!(log.target, module_path!());
+        assert_eq!(log.fields, [("replayed", FieldValue::Bool(true))]);
+        assert!(!events
+            .iter_current_update_events()
+            .any(|log| log.message.starts_with("Debug log written before")));
+    }
+}
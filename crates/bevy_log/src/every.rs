@@ -0,0 +1,210 @@
+use crate::Level;
+use bevy_platform::{
+    sync::atomic::{AtomicU64, Ordering},
+    time::Instant,
+};
+use core::time::Duration;
+use std::sync::OnceLock;
+use tracing::level_filters::LevelFilter;
+
+/// The time of the last log of a call site of the `*_every!` macros, and the number of logs
+/// suppressed since then.
+#[doc(hidden)]
+pub struct IntervalFlag {
+    /// The time of the last log, in nanoseconds since the first log of any call site, plus one.
+    /// `0` means that nothing was logged yet.
+    last: AtomicU64,
+    suppressed: AtomicU64,
+}
+
+impl IntervalFlag {
+    /// Create a new flag that never fired.
+    pub const fn new() -> Self {
+        Self {
+            last: AtomicU64::new(0),
+            suppressed: AtomicU64::new(0),
+        }
+    }
+
+    /// Returns the number of logs suppressed since the last one if a log of `level` should be
+    /// written, at most once every `interval`, or `None` if it's suppressed.
+    ///
+    /// Nothing is counted if `level` is disabled.
+    #[inline]
+    pub fn fire(&self, level: Level, interval: Duration) -> Option<u64> {
+        if level > LevelFilter::current() {
+            return None;
+        }
+        static START: OnceLock<Instant> = OnceLock::new();
+        self.fire_at(START.get_or_init(Instant::now).elapsed(), interval)
+    }
+
+    /// Like [`fire`](Self::fire), at `now` since an arbitrary start.
+    fn fire_at(&self, now: Duration, interval: Duration) -> Option<u64> {
+        let now = u64::try_from(now.as_nanos())
+            .unwrap_or(u64::MAX - 1)
+            .saturating_add(1);
+        let last = self.last.load(Ordering::Relaxed);
+        if last != 0 && u128::from(now.saturating_sub(last)) < interval.as_nanos() {
+            self.suppressed.fetch_add(1, Ordering::Relaxed);
+            return None;
+        }
+        // Another thread may log at the same time, in which case this log is suppressed.
+        if self
+            .last
+            .compare_exchange(last, now, Ordering::Relaxed, Ordering::Relaxed)
+            .is_err()
+        {
+            self.suppressed.fetch_add(1, Ordering::Relaxed);
+            return None;
+        }
+        Some(self.suppressed.swap(0, Ordering::Relaxed))
+    }
+}
+
+impl Default for IntervalFlag {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Call [`trace!`](crate::trace) at most once per `interval` per call site, followed by the
+/// number of calls suppressed since the last log, such as `(suppressed 12 similar)`.
+///
+/// See [`warn_every!`](crate::warn_every).
+#[macro_export]
+macro_rules! trace_every {
+    ($interval:expr, $($arg:tt)+) => ({
+        static FLAG: $crate::IntervalFlag = $crate::IntervalFlag::new();
+        if $crate::Level::TRACE <= $crate::tracing::level_filters::STATIC_MAX_LEVEL {
+            match FLAG.fire($crate::Level::TRACE, $interval) {
+                Some(0) => $crate::trace!($($arg)+),
+                Some(suppressed) => $crate::trace!(
+                    "{} (suppressed {suppressed} similar)",
+                    format_args!($($arg)+)
+                ),
+                None => {}
+            }
+        }
+    });
+}
+
+/// Call [`debug!`](crate::debug) at most once per `interval` per call site, followed by the
+/// number of calls suppressed since the last log, such as `(suppressed 12 similar)`.
+///
+/// See [`warn_every!`](crate::warn_every).
+#[macro_export]
+macro_rules! debug_every {
+    ($interval:expr, $($arg:tt)+) => ({
+        static FLAG: $crate::IntervalFlag = $crate::IntervalFlag::new();
+        if $crate::Level::DEBUG <= $crate::tracing::level_filters::STATIC_MAX_LEVEL {
+            match FLAG.fire($crate::Level::DEBUG, $interval) {
+                Some(0) => $crate::debug!($($arg)+),
+                Some(suppressed) => $crate::debug!(
+                    "{} (suppressed {suppressed} similar)",
+                    format_args!($($arg)+)
+                ),
+                None => {}
+            }
+        }
+    });
+}
+
+/// Call [`info!`](crate::info) at most once per `interval` per call site, followed by the
+/// number of calls suppressed since the last log, such as `(suppressed 12 similar)`.
+///
+/// See [`warn_every!`](crate::warn_every).
+#[macro_export]
+macro_rules! info_every {
+    ($interval:expr, $($arg:tt)+) => ({
+        static FLAG: $crate::IntervalFlag = $crate::IntervalFlag::new();
+        if $crate::Level::INFO <= $crate::tracing::level_filters::STATIC_MAX_LEVEL {
+            match FLAG.fire($crate::Level::INFO, $interval) {
+                Some(0) => $crate::info!($($arg)+),
+                Some(suppressed) => $crate::info!(
+                    "{} (suppressed {suppressed} similar)",
+                    format_args!($($arg)+)
+                ),
+                None => {}
+            }
+        }
+    });
+}
+
+/// Call [`warn!`](crate::warn) at most once per `interval` per call site, followed by the
+/// number of calls suppressed since the last log, such as `(suppressed 12 similar)`.
+///
+/// Useful for logging conditions that can last for many frames, in systems or anywhere else.
+/// The arguments after the interval are a format string and its arguments, like with
+/// [`format!`]. Nothing is counted when the level is disabled, and the macro compiles to nothing
+/// when the level is disabled at compile time.
+///
+/// ```
+/// # use bevy_log::warn_every;
+/// # use core::time::Duration;
+/// # let depth = 1000;
+/// warn_every!(Duration::from_secs(5), "Queue depth {}", depth);
+/// ```
+#[macro_export]
+macro_rules! warn_every {
+    ($interval:expr, $($arg:tt)+) => ({
+        static FLAG: $crate::IntervalFlag = $crate::IntervalFlag::new();
+        if $crate::Level::WARN <= $crate::tracing::level_filters::STATIC_MAX_LEVEL {
+            match FLAG.fire($crate::Level::WARN, $interval) {
+                Some(0) => $crate::warn!($($arg)+),
+                Some(suppressed) => $crate::warn!(
+                    "{} (suppressed {suppressed} similar)",
+                    format_args!($($arg)+)
+                ),
+                None => {}
+            }
+        }
+    });
+}
+
+/// Call [`error!`](crate::error) at most once per `interval` per call site, followed by the
+/// number of calls suppressed since the last log, such as `(suppressed 12 similar)`.
+///
+/// See [`warn_every!`](crate::warn_every).
+#[macro_export]
+macro_rules! error_every {
+    ($interval:expr, $($arg:tt)+) => ({
+        static FLAG: $crate::IntervalFlag = $crate::IntervalFlag::new();
+        if $crate::Level::ERROR <= $crate::tracing::level_filters::STATIC_MAX_LEVEL {
+            match FLAG.fire($crate::Level::ERROR, $interval) {
+                Some(0) => $crate::error!($($arg)+),
+                Some(suppressed) => $crate::error!(
+                    "{} (suppressed {suppressed} similar)",
+                    format_args!($($arg)+)
+                ),
+                None => {}
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+
+    use super::IntervalFlag;
+
+    #[test]
+    fn logs_are_counted_between_intervals() {
+        let flag = IntervalFlag::new();
+        let interval = Duration::from_secs(5);
+        let at = Duration::from_secs;
+
+        assert_eq!(flag.fire_at(at(10), interval), Some(0));
+        assert_eq!(flag.fire_at(at(11), interval), None);
+        assert_eq!(flag.fire_at(at(14), interval), None);
+        assert_eq!(flag.fire_at(at(15), interval), Some(2));
+        assert_eq!(flag.fire_at(at(16), interval), None);
+        assert_eq!(flag.fire_at(at(30), interval), Some(1));
+        assert_eq!(flag.fire_at(at(40), interval), Some(0));
+
+        let flag = IntervalFlag::new();
+        assert_eq!(flag.fire_at(Duration::ZERO, interval), Some(0));
+        assert_eq!(flag.fire_at(Duration::ZERO, Duration::ZERO), Some(0));
+    }
+}
@@ -0,0 +1,89 @@
+use crate::Level;
+use std::path::PathBuf;
+
+/// Settings of the log file written by the [`LogPlugin`](crate::LogPlugin), next to its console
+/// output.
+///
+/// Logs are written to the file on a background thread, so that logging doesn't wait for the
+/// disk. They are flushed when the app exits. The file never contains ANSI escape codes, even
+/// when the console output is colored.
+///
+/// File logging isn't supported on the web, where these settings are ignored.
+///
+/// ```no_run
+/// # use bevy_app::{App, NoopPluginGroup as DefaultPlugins, PluginGroup};
+/// # use bevy_log::{FileLogConfig, Level, LogPlugin, LogRotation};
+/// App::new()
+///     .add_plugins(DefaultPlugins.set(LogPlugin {
+///         file: Some(
+///             FileLogConfig::new("logs/game.log")
+///                 .with_rotation(LogRotation::Daily)
+///                 .with_keep(7)
+///                 .with_level(Level::WARN),
+///         ),
+///         ..Default::default()
+///     }))
+///     .run();
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FileLogConfig {
+    /// The path of the log file. Its parent directories are created if they don't exist.
+    ///
+    /// The logs are appended to the file if it already exists. Rotated files are numbered from
+    /// the newest to the oldest, so `logs/game.log` is rotated to `logs/game.1.log`,
+    /// `logs/game.1.log` to `logs/game.2.log` and so on.
+    pub path: PathBuf,
+    /// When the log file is rotated.
+    pub rotation: LogRotation,
+    /// How many rotated files are kept next to the current one. Older files are deleted.
+    pub keep: usize,
+    /// Filters out the file logs that are "less than" the given level, in addition to the
+    /// [`LogPlugin::filter`](crate::LogPlugin::filter) that also applies to the console. All the
+    /// logs that pass that filter are written if this is `None`.
+    pub level: Option<Level>,
+}
+
+impl FileLogConfig {
+    /// Writes the logs to `path`, without rotating it.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            rotation: LogRotation::Never,
+            keep: 5,
+            level: None,
+        }
+    }
+
+    /// Sets when the log file is rotated.
+    pub fn with_rotation(mut self, rotation: LogRotation) -> Self {
+        self.rotation = rotation;
+        self
+    }
+
+    /// Sets how many rotated files are kept next to the current one.
+    pub fn with_keep(mut self, keep: usize) -> Self {
+        self.keep = keep;
+        self
+    }
+
+    /// Only writes the logs of the given level or more important to the file.
+    pub fn with_level(mut self, level: Level) -> Self {
+        self.level = Some(level);
+        self
+    }
+}
+
+/// When the log file of a [`FileLogConfig`] is rotated: closed, renamed, and replaced with an
+/// empty one.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LogRotation {
+    /// Never rotates the file, which grows forever.
+    #[default]
+    Never,
+    /// Rotates the file when the day changes, at midnight UTC, including between two runs of the
+    /// app.
+    Daily,
+    /// Rotates the file before it grows past the given number of bytes. A single log larger than
+    /// that is still written whole.
+    SizeLimit(u64),
+}
@@ -0,0 +1,353 @@
+use crate::{format::format_layer, FileLogConfig, LevelStyle, LogFormat, LogRotation, LogTimer};
+use bevy_app::App;
+use bevy_ecs::resource::Resource;
+use std::{
+    ffi::OsString,
+    fs::{self, File},
+    io::{self, BufWriter, Write},
+    path::PathBuf,
+    sync::mpsc::{self, Receiver, Sender},
+    thread::JoinHandle,
+    time::{SystemTime, UNIX_EPOCH},
+};
+use tracing_subscriber::{
+    field::RecordFields,
+    filter::{Filtered, LevelFilter},
+    fmt::{
+        format::{DefaultFields, Writer},
+        FormatFields, MakeWriter,
+    },
+    Layer,
+};
+
+/// The [`Layer`] writing the logs to the file of a [`FileLogConfig`].
+pub(crate) type FileLayer<S> = Filtered<Box<dyn Layer<S> + Send + Sync>, LevelFilter, S>;
+
+/// Creates the [`FileLayer`] and the thread writing the logs to disk, or returns `None` if the log
+/// file can't be opened.
+#[expect(clippy::print_stderr, reason = "Allowed during logger setup")]
+pub(crate) fn file_layer<S>(
+    config: &FileLogConfig,
+    format: LogFormat,
+    timer: Option<LogTimer>,
+    app: &mut App,
+) -> Option<FileLayer<S>>
+where
+    S: tracing::Subscriber + for<'a> tracing_subscriber::registry::LookupSpan<'a>,
+{
+    let (writer, guard) = match spawn_writer(config) {
+        Ok(writer) => writer,
+        Err(err) => {
+            // we cannot use the `error!` macro here because the logger is not ready yet.
+            eprintln!(
+                "LogPlugin failed to open the log file {}: {err}",
+                config.path.display()
+            );
+            return None;
+        }
+    };
+    app.insert_resource(guard);
+
+    let level = config
+        .level
+        .map_or(LevelFilter::TRACE, LevelFilter::from_level);
+    // The file keeps the names of the levels, without the prefixes of the console output.
+    let layer = format_layer(
+        format,
+        false,
+        LevelStyle::default(),
+        timer,
+        FileFields::default(),
+        writer,
+    );
+    Some(layer.with_filter(level))
+}
+
+/// Formats the fields of the logs written to the file.
+///
+/// The formatted fields of spans are cached per formatter type. This keeps those of the file apart
+/// from those of the console, which may contain ANSI escape codes.
+#[derive(Default)]
+pub(crate) struct FileFields(DefaultFields);
+
+impl<'writer> FormatFields<'writer> for FileFields {
+    fn format_fields<R: RecordFields>(
+        &self,
+        writer: Writer<'writer>,
+        fields: R,
+    ) -> core::fmt::Result {
+        self.0.format_fields(writer, fields)
+    }
+}
+
+enum Message {
+    Write(Vec<u8>),
+    Flush(Sender<()>),
+}
+
+/// Sends the logs to the thread writing them to the file.
+#[derive(Clone)]
+pub(crate) struct FileWriter(Sender<Message>);
+
+impl Write for FileWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        // Logs written after the thread stopped are dropped.
+        let _ = self.0.send(Message::Write(buf.to_vec()));
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl<'a> MakeWriter<'a> for FileWriter {
+    type Writer = FileWriter;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        self.clone()
+    }
+}
+
+/// Flushes the log file when the app exits or when it is dropped.
+#[derive(Resource)]
+pub(crate) struct FileLogGuard {
+    sender: Sender<Message>,
+    // The thread is never joined, the subscriber keeps a sender for the whole process.
+    _thread: JoinHandle<()>,
+}
+
+impl FileLogGuard {
+    /// Waits until the logs sent so far are written to the file.
+    pub(crate) fn flush(&self) {
+        flush(&self.sender);
+    }
+
+    /// Returns a function doing the same as [`FileLogGuard::flush`], which can outlive the guard.
+    pub(crate) fn flusher(&self) -> impl Fn() + Send + Sync + 'static {
+        let sender = self.sender.clone();
+        move || flush(&sender)
+    }
+}
+
+fn flush(sender: &Sender<Message>) {
+    let (done, receiver) = mpsc::channel();
+    if sender.send(Message::Flush(done)).is_ok() {
+        let _ = receiver.recv();
+    }
+}
+
+impl Drop for FileLogGuard {
+    fn drop(&mut self) {
+        self.flush();
+    }
+}
+
+fn spawn_writer(config: &FileLogConfig) -> io::Result<(FileWriter, FileLogGuard)> {
+    let mut file = LogFile::open(config)?;
+    let (sender, receiver) = mpsc::channel();
+    let thread = std::thread::Builder::new()
+        .name("bevy_log file writer".to_string())
+        .spawn(move || file.run(receiver))?;
+    Ok((
+        FileWriter(sender.clone()),
+        FileLogGuard {
+            sender,
+            _thread: thread,
+        },
+    ))
+}
+
+/// The log file, rotated according to its [`FileLogConfig`].
+struct LogFile {
+    path: PathBuf,
+    rotation: LogRotation,
+    keep: usize,
+    file: BufWriter<File>,
+    /// The size of the file, in bytes.
+    size: u64,
+    /// The day the file was last written to, in days since the Unix epoch.
+    day: u64,
+    /// Whether a write error was reported, to only report the first one.
+    failed: bool,
+}
+
+impl LogFile {
+    fn open(config: &FileLogConfig) -> io::Result<Self> {
+        if let Some(parent) = config.path.parent()
+            && !parent.as_os_str().is_empty()
+        {
+            fs::create_dir_all(parent)?;
+        }
+        let (file, size, day) = open_file(&config.path)?;
+        let mut log_file = Self {
+            path: config.path.clone(),
+            rotation: config.rotation,
+            keep: config.keep,
+            file,
+            size,
+            day,
+            failed: false,
+        };
+        // The file may be from a previous day.
+        if log_file.rotation == LogRotation::Daily && log_file.size > 0 && log_file.day != today() {
+            log_file.rotate()?;
+        }
+        Ok(log_file)
+    }
+
+    #[expect(clippy::print_stderr, reason = "The logger can't log its own errors")]
+    fn run(&mut self, receiver: Receiver<Message>) {
+        while let Ok(message) = receiver.recv() {
+            // Write what was sent in the meantime before flushing.
+            let result = core::iter::once(message)
+                .chain(receiver.try_iter())
+                .try_for_each(|message| match message {
+                    Message::Write(log) => self.write(&log),
+                    Message::Flush(done) => {
+                        let result = self.file.flush();
+                        let _ = done.send(());
+                        result
+                    }
+                })
+                .and_then(|()| self.file.flush());
+            if let Err(err) = result
+                && !core::mem::replace(&mut self.failed, true)
+            {
+                eprintln!(
+                    "Failed to write to the log file {}: {err}",
+                    self.path.display()
+                );
+            }
+        }
+    }
+
+    fn write(&mut self, log: &[u8]) -> io::Result<()> {
+        let len = log.len() as u64;
+        let rotate = match self.rotation {
+            LogRotation::Never => false,
+            LogRotation::Daily => self.day != today(),
+            LogRotation::SizeLimit(limit) => self.size > 0 && self.size + len > limit,
+        };
+        if rotate {
+            self.rotate()?;
+        }
+        self.file.write_all(log)?;
+        self.size += len;
+        self.day = today();
+        Ok(())
+    }
+
+    /// Closes the file, shifts the rotated files and opens a new file.
+    fn rotate(&mut self) -> io::Result<()> {
+        self.file.flush()?;
+        if self.keep == 0 {
+            fs::remove_file(&self.path)?;
+        } else {
+            ignore_not_found(fs::remove_file(self.rotated_path(self.keep)))?;
+            for index in (1..self.keep).rev() {
+                ignore_not_found(fs::rename(
+                    self.rotated_path(index),
+                    self.rotated_path(index + 1),
+                ))?;
+            }
+            fs::rename(&self.path, self.rotated_path(1))?;
+        }
+        (self.file, self.size, _) = open_file(&self.path)?;
+        self.day = today();
+        Ok(())
+    }
+
+    /// Returns the path of the `index`th rotated file, `game.2.log` for `game.log`.
+    fn rotated_path(&self, index: usize) -> PathBuf {
+        let mut name = self
+            .path
+            .file_stem()
+            .map(OsString::from)
+            .unwrap_or_default();
+        name.push(format!(".{index}"));
+        if let Some(extension) = self.path.extension() {
+            name.push(".");
+            name.push(extension);
+        }
+        self.path.with_file_name(name)
+    }
+}
+
+/// Opens the file in append mode, returning its size and the day it was last modified.
+fn open_file(path: &PathBuf) -> io::Result<(BufWriter<File>, u64, u64)> {
+    let file = File::options().create(true).append(true).open(path)?;
+    let metadata = file.metadata()?;
+    let day = metadata.modified().map_or_else(|_| today(), day_of);
+    Ok((BufWriter::new(file), metadata.len(), day))
+}
+
+fn ignore_not_found(result: io::Result<()>) -> io::Result<()> {
+    match result {
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(()),
+        result => result,
+    }
+}
+
+fn today() -> u64 {
+    day_of(SystemTime::now())
+}
+
+fn day_of(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs() / (24 * 60 * 60))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, io::Write, path::PathBuf};
+
+    use super::{spawn_writer, FileLogConfig, LogFile, LogRotation};
+
+    fn test_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("bevy_log_{name}_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn size_limit_rotates_the_file() {
+        let dir = test_dir("size_limit");
+        let config = FileLogConfig::new(dir.join("game.log"))
+            .with_rotation(LogRotation::SizeLimit(16))
+            .with_keep(2);
+        let mut file = LogFile::open(&config).unwrap();
+        for log in ["first\n", "second\n", "third\n", "fourth\n", "fifth\n"] {
+            file.write(log.as_bytes()).unwrap();
+        }
+        file.rotate().unwrap();
+        file.write(b"sixth\n").unwrap();
+        drop(file);
+
+        let read = |name: &str| fs::read_to_string(dir.join(name)).unwrap();
+        assert_eq!(read("game.log"), "sixth\n");
+        assert_eq!(read("game.1.log"), "fifth\n");
+        assert_eq!(read("game.2.log"), "third\nfourth\n");
+        assert!(!dir.join("game.3.log").exists());
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn guard_flushes_the_logs() {
+        let dir = test_dir("flush");
+        let config = FileLogConfig::new(dir.join("game"));
+        let (mut writer, guard) = spawn_writer(&config).unwrap();
+        writer.write_all(b"one\n").unwrap();
+        writer.write_all(b"two\n").unwrap();
+        guard.flush();
+        assert_eq!(fs::read_to_string(dir.join("game")).unwrap(), "one\ntwo\n");
+
+        writer.write_all(b"three\n").unwrap();
+        drop(guard);
+        assert_eq!(
+            fs::read_to_string(dir.join("game")).unwrap(),
+            "one\ntwo\nthree\n"
+        );
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,869 @@
+use crate::{
+    error,
+    span_filter::{SharedSpanRules, SpanRule},
+    FilteredSubscriber, Level,
+};
+use bevy_ecs::{resource::Resource, system::ResMut};
+use core::{error::Error, fmt};
+use tracing::{level_filters::LevelFilter, Subscriber};
+use tracing_subscriber::{
+    filter::Directive,
+    layer::{Filter, Layer},
+    registry::LookupSpan,
+    reload, EnvFilter,
+};
+
+/// The [`EnvFilter`] of the [`LogPlugin`](crate::LogPlugin), which can be changed while the app
+/// is running.
+///
+/// Changes are applied at the end of the frame, in [`PostUpdate`](bevy_app::PostUpdate), and
+/// replace the whole filter at once. Invalid directives are rejected when they are set, with a
+/// [`LogFilterError`], leaving the filter unchanged.
+///
+/// The outputs with their own filter, such as the
+/// [`console_filter`](crate::LogPlugin::console_filter), keep it: this only changes the filter of
+/// the other outputs.
+///
+/// The [`SpanRule`]s, suppressing the logs written within some spans, are changed through this
+/// resource as well.
+///
+/// This resource is only inserted if the [`LogPlugin`](crate::LogPlugin) could set the global
+/// tracing subscriber.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_log::{Level, LogFilter};
+/// fn enable_trace_logs(mut filter: ResMut<LogFilter>) {
+///     filter.set_global(Level::TRACE);
+///     // Keep the graphics crates quiet.
+///     filter.set_directive("wgpu=warn").unwrap();
+///     // Except the flood of messages of the asset hot-reload.
+///     filter.suppress_span("hot_reload");
+/// }
+/// ```
+#[derive(Resource)]
+pub struct LogFilter {
+    directives: Vec<String>,
+    reload: Reload,
+    changed: bool,
+    span_rules: Vec<SpanRule>,
+    shared_span_rules: SharedSpanRules,
+    span_rules_changed: bool,
+}
+
+/// Replaces the filters of the subscriber with a valid filter, in the [`EnvFilter`] format.
+type Reload = Box<dyn Fn(&str) -> Result<(), reload::Error> + Send + Sync>;
+
+impl LogFilter {
+    pub(crate) fn new(
+        filter: &str,
+        reload: impl Fn(&str) -> Result<(), reload::Error> + Send + Sync + 'static,
+        span_rules: Vec<SpanRule>,
+        shared_span_rules: SharedSpanRules,
+    ) -> Self {
+        Self {
+            directives: split_directives(filter),
+            reload: Box::new(reload),
+            changed: false,
+            span_rules,
+            shared_span_rules,
+            span_rules_changed: false,
+        }
+    }
+
+    /// Returns the filter that will be used from the end of the frame, in the [`EnvFilter`]
+    /// format.
+    pub fn filter(&self) -> String {
+        self.directives.join(",")
+    }
+
+    /// Sets the level of the logs that aren't matched by a more specific directive.
+    pub fn set_global(&mut self, level: Level) -> &mut Self {
+        self.insert(Directive::from(level));
+        self
+    }
+
+    /// Adds a directive, such as `wgpu=warn`, replacing the directive with the same target, span
+    /// and fields if there is one.
+    ///
+    /// Returns an error if the directive is invalid, in which case the filter is left unchanged.
+    pub fn set_directive(&mut self, directive: &str) -> Result<&mut Self, LogFilterError> {
+        let directive = directive
+            .parse()
+            .map_err(|err| LogFilterError::new(directive, 0, err))?;
+        self.insert(directive);
+        Ok(self)
+    }
+
+    /// Replaces the whole filter, for example with `info,wgpu=error,naga=warn`.
+    ///
+    /// Returns an error if any of the directives is invalid, in which case the filter is left
+    /// unchanged.
+    pub fn reset_to(&mut self, filter: &str) -> Result<&mut Self, LogFilterError> {
+        let filter = parse_filter(filter)?;
+        self.directives = split_directives(&filter.to_string());
+        self.changed = true;
+        Ok(self)
+    }
+
+    /// Returns the [`SpanRule`]s that will be used from the end of the frame.
+    pub fn span_rules(&self) -> &[SpanRule] {
+        &self.span_rules
+    }
+
+    /// Suppresses all the logs written within the spans named `span`, see [`SpanRule::suppress`].
+    pub fn suppress_span(&mut self, span: impl Into<String>) -> &mut Self {
+        self.insert_span_rule(SpanRule::suppress(span));
+        self
+    }
+
+    /// Only keeps the logs at `level` or more severe written within the spans named `span`, see
+    /// [`SpanRule::limit`].
+    pub fn limit_span(&mut self, span: impl Into<String>, level: Level) -> &mut Self {
+        self.insert_span_rule(SpanRule::limit(span, level));
+        self
+    }
+
+    /// Removes the rule of the spans named `span`, if there is one.
+    pub fn remove_span_rule(&mut self, span: &str) -> &mut Self {
+        self.span_rules.retain(|rule| rule.span != span);
+        self.span_rules_changed = true;
+        self
+    }
+
+    fn insert_span_rule(&mut self, rule: SpanRule) {
+        match self
+            .span_rules
+            .iter_mut()
+            .find(|existing| existing.span == rule.span)
+        {
+            Some(existing) => *existing = rule,
+            None => self.span_rules.push(rule),
+        }
+        self.span_rules_changed = true;
+    }
+
+    fn insert(&mut self, directive: Directive) {
+        let directive = directive.to_string();
+        let key = directive_key(&directive);
+        match self
+            .directives
+            .iter_mut()
+            .find(|existing| directive_key(existing) == key)
+        {
+            Some(existing) => *existing = directive,
+            None => self.directives.push(directive),
+        }
+        self.changed = true;
+    }
+}
+
+impl fmt::Debug for LogFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LogFilter")
+            .field("directives", &self.directives)
+            .field("changed", &self.changed)
+            .field("span_rules", &self.span_rules)
+            .field("span_rules_changed", &self.span_rules_changed)
+            .finish_non_exhaustive()
+    }
+}
+
+/// An invalid directive in a log filter, such as `wgpu=eror`.
+///
+/// This is returned when changing the [`LogFilter`], and logged by the
+/// [`LogPlugin`](crate::LogPlugin) when its filter or the `RUST_LOG` environment variable is
+/// invalid.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LogFilterError {
+    directive: String,
+    position: usize,
+    reason: String,
+}
+
+impl LogFilterError {
+    fn new(directive: &str, position: usize, reason: impl fmt::Display) -> Self {
+        Self {
+            directive: directive.to_string(),
+            position,
+            reason: reason.to_string(),
+        }
+    }
+
+    /// Returns the invalid directive.
+    pub fn directive(&self) -> &str {
+        &self.directive
+    }
+
+    /// Returns the position of the invalid directive in the filter, in bytes.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+}
+
+impl fmt::Display for LogFilterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid log filter directive `{}` at position {}: {}. Expected directives separated \
+            by commas, each a level or a target and a level, such as `warn`, `wgpu=error` or \
+            `mygame::net=debug`, see the `EnvFilter` documentation",
+            self.directive, self.position, self.reason
+        )
+    }
+}
+
+impl Error for LogFilterError {}
+
+/// Parses a filter in the [`EnvFilter`] format, returning its first invalid directive if any.
+pub(crate) fn parse_filter(filter: &str) -> Result<EnvFilter, LogFilterError> {
+    EnvFilter::try_new(filter).map_err(|err| {
+        let mut position = 0;
+        for directive in filter.split(',') {
+            if !directive.is_empty()
+                && let Err(err) = directive.parse::<Directive>()
+            {
+                return LogFilterError::new(directive, position, err);
+            }
+            position += directive.len() + 1;
+        }
+        LogFilterError::new(filter, 0, err)
+    })
+}
+
+fn split_directives(filter: &str) -> Vec<String> {
+    filter
+        .split(',')
+        .filter(|directive| !directive.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+/// Returns what a directive matches, without its level. Empty for the global level.
+///
+/// The level never contains a `=`, so the last one separates it from the rest of the directive.
+fn directive_key(directive: &str) -> &str {
+    directive.rsplit_once('=').map_or("", |(key, _level)| key)
+}
+
+/// Returns the initial filter of the [`LogPlugin`](crate::LogPlugin): its global `level`, then the
+/// directives of its `modules`, then its `filter` string.
+pub(crate) fn plugin_filter(level: Level, modules: &[(String, Level)], filter: &str) -> String {
+    let mut directives = vec![level.to_string()];
+    for (module, level) in modules {
+        assert_module_path(module);
+        directives.push(format!("{module}={level}"));
+    }
+    directives.extend(split_directives(filter));
+    directives.join(",")
+}
+
+/// Returns the initial filter of the [`LogPlugin`](crate::LogPlugin): the `env` filter, from the
+/// `RUST_LOG` environment variable, if it's set and valid, or else the [`plugin_filter`].
+///
+/// If the `filter` string of the plugin is invalid, it's ignored, keeping only the `level` and
+/// the `modules`. The errors are returned to be logged once the tracing subscriber is set.
+pub(crate) fn initial_filter(
+    env: Option<&str>,
+    level: Level,
+    modules: &[(String, Level)],
+    filter: &str,
+) -> (EnvFilter, Vec<String>) {
+    let mut errors = Vec::new();
+    if let Some(env) = env {
+        match parse_filter(env) {
+            Ok(filter) => return (filter, errors),
+            Err(err) => errors.push(format!(
+                "Ignoring the `RUST_LOG` environment variable `{env}`: {err}"
+            )),
+        }
+    }
+    let filter = match parse_filter(filter) {
+        Ok(_) => plugin_filter(level, modules, filter),
+        Err(err) => {
+            let fallback = plugin_filter(level, modules, "");
+            errors.push(format!(
+                "Ignoring the `LogPlugin::filter` `{filter}`, using `{fallback}` instead: {err}"
+            ));
+            fallback
+        }
+    };
+    // The level and the modules are always valid.
+    (EnvFilter::new(filter), errors)
+}
+
+/// The filters of the outputs of the [`LogPlugin`](crate::LogPlugin), when some of them have
+/// their own, such as the [`console_filter`](crate::LogPlugin::console_filter).
+///
+/// Each output is then filtered on its own, by its filter or by a copy of the default one. The
+/// global filter only keeps the most verbose level of all of them, so that the logs that no output
+/// wants are still skipped before reaching any layer.
+pub(crate) struct OutputFilters {
+    console: Option<EnvFilter>,
+    file: Option<EnvFilter>,
+    capture: Option<EnvFilter>,
+    /// The filters of the [`filtered_layers`](crate::LogPlugin::filtered_layers), in order.
+    layers: Vec<Option<EnvFilter>>,
+    /// The most verbose level of the outputs with their own filter.
+    own_level: LevelFilter,
+    /// The filter of the other outputs, which can be changed through the [`LogFilter`].
+    default: String,
+    /// Reloads the copies of the default filter.
+    reloads: Vec<Reload>,
+}
+
+/// A filter of an output, see [`OutputFilters`].
+pub(crate) type OutputFilter<S> = Box<dyn Filter<S> + Send + Sync>;
+
+impl OutputFilters {
+    /// Parses the filters of the outputs, or returns `None` if none of them has its own.
+    ///
+    /// The invalid filters are ignored, and their errors are returned in `errors` to be logged
+    /// once the tracing subscriber is set.
+    pub(crate) fn new(
+        console: Option<&str>,
+        file: Option<&str>,
+        capture: Option<&str>,
+        layers: &[&str],
+        default: &str,
+        errors: &mut Vec<String>,
+    ) -> Option<Self> {
+        let mut parse = |setting: &str, filter: Option<&str>| {
+            let filter = filter?;
+            parse_filter(filter)
+                .inspect_err(|err| {
+                    errors.push(format!(
+                        "Ignoring the `LogPlugin::{setting}` `{filter}`, using the default filter \
+                        instead: {err}"
+                    ));
+                })
+                .ok()
+        };
+        let console = parse("console_filter", console);
+        let file = parse("file_filter", file);
+        let capture = parse("capture_filter", capture);
+        let layers: Vec<_> = layers
+            .iter()
+            .map(|filter| parse("filtered_layers", Some(filter)))
+            .collect();
+        let own_level = [&console, &file, &capture]
+            .into_iter()
+            .chain(&layers)
+            .flatten()
+            .map(max_level)
+            .max()?;
+        Some(Self {
+            console,
+            file,
+            capture,
+            layers,
+            own_level,
+            default: default.to_string(),
+            reloads: Vec::new(),
+        })
+    }
+
+    /// Returns the filter of the console output.
+    pub(crate) fn console<S: Subscriber>(&mut self) -> OutputFilter<S> {
+        let own = self.console.take();
+        self.output(own)
+    }
+
+    /// Returns the filter of the [`file`](crate::LogPlugin::file).
+    pub(crate) fn file<S: Subscriber>(&mut self) -> OutputFilter<S> {
+        let own = self.file.take();
+        self.output(own)
+    }
+
+    /// Returns the filter of the [`capture`](crate::LogPlugin::capture).
+    pub(crate) fn capture<S: Subscriber>(&mut self) -> OutputFilter<S> {
+        let own = self.capture.take();
+        self.output(own)
+    }
+
+    /// Returns the filter of the filtered layer at `index`.
+    pub(crate) fn layer<S: Subscriber>(&mut self, index: usize) -> OutputFilter<S> {
+        let own = self.layers.get_mut(index).and_then(Option::take);
+        self.output(own)
+    }
+
+    /// Returns a copy of the default filter, for the layers without a filter setting.
+    pub(crate) fn default<S: Subscriber>(&mut self) -> OutputFilter<S> {
+        self.output(None)
+    }
+
+    fn output<S: Subscriber>(&mut self, own: Option<EnvFilter>) -> OutputFilter<S> {
+        if let Some(own) = own {
+            return Box::new(own);
+        }
+        let (filter, handle) = reload::Layer::new(EnvFilter::new(&self.default));
+        self.reloads.push(Box::new(move |filter| {
+            handle.reload(EnvFilter::new(filter))
+        }));
+        Box::new(filter)
+    }
+
+    /// Returns the global filter, keeping the most verbose level of the outputs.
+    pub(crate) fn global_filter(&self) -> EnvFilter {
+        global_filter(self.own_level, &self.default)
+    }
+
+    /// Returns the function reloading the copies of the default filter and the global filter,
+    /// which has the handle `global`, for the [`LogFilter`].
+    pub(crate) fn into_reload(
+        self,
+        global: reload::Handle<EnvFilter, FilteredSubscriber>,
+    ) -> impl Fn(&str) -> Result<(), reload::Error> + Send + Sync + 'static {
+        let Self {
+            own_level, reloads, ..
+        } = self;
+        move |filter| {
+            for reload in &reloads {
+                reload(filter)?;
+            }
+            global.reload(global_filter(own_level, filter))
+        }
+    }
+}
+
+/// Boxes `layer`, filtered by `filter` if some outputs have their own filter.
+pub(crate) fn with_output_filter<S, L>(
+    layer: L,
+    filter: Option<OutputFilter<S>>,
+) -> Box<dyn Layer<S> + Send + Sync>
+where
+    S: Subscriber + for<'a> LookupSpan<'a> + 'static,
+    L: Layer<S> + Send + Sync + 'static,
+{
+    match filter {
+        Some(filter) => Box::new(layer.with_filter(filter)),
+        None => Box::new(layer),
+    }
+}
+
+/// Returns the most verbose level `filter` enables.
+fn max_level(filter: &EnvFilter) -> LevelFilter {
+    filter.max_level_hint().unwrap_or(LevelFilter::TRACE)
+}
+
+/// Returns the filter keeping the most verbose level of the outputs with their own filter, at
+/// `own_level`, and of the `default` filter.
+fn global_filter(own_level: LevelFilter, default: &str) -> EnvFilter {
+    let level = own_level.max(max_level(&EnvFilter::new(default)));
+    EnvFilter::new(level.to_string())
+}
+
+/// Panics if `module` isn't a module path, such as `wgpu` or `mygame::net`.
+#[track_caller]
+pub(crate) fn assert_module_path(module: &str) {
+    let is_identifier = |segment: &str| {
+        let mut chars = segment.chars();
+        chars.next().is_some_and(|c| c.is_alphabetic() || c == '_')
+            && chars.all(|c| c.is_alphanumeric() || c == '_')
+    };
+    assert!(
+        module.split("::").all(is_identifier),
+        "`{module}` is not a valid module path for `LogPlugin::with_module`, expected identifiers \
+        separated by `::`, such as `wgpu` or `mygame::net`"
+    );
+}
+
+/// Applies the changes made to the [`LogFilter`] during the frame.
+pub(crate) fn apply_log_filter(mut filter: ResMut<LogFilter>) {
+    if filter.span_rules_changed {
+        filter.span_rules_changed = false;
+        filter.shared_span_rules.set(filter.span_rules.clone());
+    }
+    if !filter.changed {
+        return;
+    }
+    filter.changed = false;
+
+    let new_filter = filter.filter();
+    match parse_filter(&new_filter) {
+        Ok(_) => {
+            if let Err(err) = (filter.reload)(&new_filter) {
+                error!("Could not change the log filter: {err}");
+            }
+        }
+        Err(err) => error!(
+            "Could not change the log filter to `{}`: {err}",
+            filter.filter()
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_app::{App, PostUpdate};
+    use bevy_ecs::event::Events;
+    use tracing::{level_filters::LevelFilter, Subscriber};
+    use tracing_subscriber::{prelude::*, reload, EnvFilter, Registry};
+
+    use super::{
+        apply_log_filter, initial_filter, parse_filter, plugin_filter, with_output_filter,
+        LogFilter, OutputFilters,
+    };
+    use crate::{
+        capture::capture_layer,
+        span_filter::{SharedSpanRules, SpanRule},
+        test::LogCapture,
+        BoxedLayer, FilteredSubscriber, Level, LogCaptureConfig, LogMessage, LogPlugin,
+    };
+
+    fn log_filter(
+        filter: &str,
+    ) -> (
+        LogFilter,
+        reload::Handle<EnvFilter, FilteredSubscriber>,
+        impl Sized,
+    ) {
+        let (layer, handle) = reload::Layer::new(EnvFilter::new(filter));
+        let subscriber = Registry::default()
+            .with(None::<Vec<BoxedLayer>>)
+            .with(layer);
+        let reload = {
+            let handle = handle.clone();
+            move |filter: &str| handle.reload(EnvFilter::new(filter))
+        };
+        (
+            LogFilter::new(filter, reload, Vec::new(), SharedSpanRules::default()),
+            handle,
+            subscriber,
+        )
+    }
+
+    #[test]
+    fn directives_replace_the_same_target() {
+        let (mut filter, _handle, _subscriber) = log_filter("info,wgpu=error");
+        filter
+            .set_global(Level::TRACE)
+            .set_directive("wgpu=warn")
+            .unwrap()
+            .set_directive("naga")
+            .unwrap();
+        assert_eq!(filter.filter(), "trace,wgpu=warn,naga=trace");
+
+        filter.reset_to("debug").unwrap();
+        assert_eq!(filter.filter(), "debug");
+    }
+
+    #[test]
+    fn invalid_directives_are_rejected() {
+        let (mut filter, _handle, _subscriber) = log_filter("wgpu=error,info");
+        assert!(filter.set_directive("wgpu=loud").is_err());
+        assert!(filter.reset_to("warn,naga=[").is_err());
+        assert_eq!(filter.filter(), "wgpu=error,info");
+        assert!(!filter.changed);
+    }
+
+    #[test]
+    fn invalid_directives_are_reported() {
+        let err = parse_filter("warn,wgpu=eror,naga=info").unwrap_err();
+        assert_eq!(err.directive(), "wgpu=eror");
+        assert_eq!(err.position(), 5);
+        assert!(err.to_string().contains("`wgpu=eror` at position 5"));
+
+        let err = parse_filter("info,mygame::net[=debug").unwrap_err();
+        assert_eq!(err.directive(), "mygame::net[=debug");
+        assert_eq!(err.position(), 5);
+
+        let (mut filter, _handle, _subscriber) = log_filter("info");
+        let err = filter.set_directive("wgpu=loud").unwrap_err();
+        assert_eq!(err.directive(), "wgpu=loud");
+        let err = filter.reset_to("debug,/mygame=trace").unwrap_err();
+        assert_eq!(err.directive(), "/mygame=trace");
+        assert_eq!(err.position(), 6);
+    }
+
+    #[test]
+    fn invalid_filters_fall_back_to_the_level() {
+        let modules = [("wgpu".to_string(), Level::ERROR)];
+        let (filter, errors) = initial_filter(None, Level::WARN, &modules, "naga=verbose");
+        assert_eq!(
+            filter.to_string(),
+            EnvFilter::new("warn,wgpu=error").to_string()
+        );
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].contains("`naga=verbose` at position 0"));
+
+        let (filter, errors) = initial_filter(Some("=="), Level::WARN, &modules, "naga=debug");
+        assert_eq!(
+            filter.to_string(),
+            EnvFilter::new("warn,wgpu=error,naga=debug").to_string()
+        );
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].contains("RUST_LOG"));
+
+        let (filter, errors) = initial_filter(Some("trace"), Level::WARN, &modules, "naga=oops");
+        assert_eq!(filter.to_string(), "trace");
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn changes_are_applied_in_post_update() {
+        let (filter, handle, subscriber) = log_filter("info");
+
+        let mut app = App::new();
+        app.insert_resource(filter)
+            .add_systems(PostUpdate, apply_log_filter);
+        let capture = LogCapture::install_single_threaded(&mut app);
+        app.world_mut()
+            .resource_mut::<LogFilter>()
+            .set_global(Level::TRACE);
+        assert_eq!(handle.with_current(ToString::to_string).unwrap(), "info");
+
+        app.update();
+        assert_eq!(handle.with_current(ToString::to_string).unwrap(), "trace");
+        capture.assert_not_contains(Level::ERROR, "Could not change the log filter");
+
+        // The filter can't be changed once its subscriber is dropped.
+        drop(subscriber);
+        app.world_mut()
+            .resource_mut::<LogFilter>()
+            .set_global(Level::WARN);
+        app.update();
+        capture.assert_contains(Level::ERROR, "Could not change the log filter");
+    }
+
+    #[test]
+    fn span_rules_are_applied_in_post_update() {
+        let shared = SharedSpanRules::new(vec![SpanRule::suppress("hot_reload")]);
+        let filter = LogFilter::new(
+            "info",
+            |_: &str| Ok(()),
+            vec![SpanRule::suppress("hot_reload")],
+            shared.clone(),
+        );
+        let mut app = App::new();
+        app.insert_resource(filter)
+            .add_systems(PostUpdate, apply_log_filter);
+        app.world_mut()
+            .resource_mut::<LogFilter>()
+            .limit_span("hot_reload", Level::WARN)
+            .suppress_span("compile_shader");
+        let rules = vec![
+            SpanRule::limit("hot_reload", Level::WARN),
+            SpanRule::suppress("compile_shader"),
+        ];
+        assert_eq!(app.world().resource::<LogFilter>().span_rules(), rules);
+        assert_eq!(*shared.read(), [SpanRule::suppress("hot_reload")]);
+
+        app.update();
+        assert_eq!(*shared.read(), rules);
+
+        app.world_mut()
+            .resource_mut::<LogFilter>()
+            .remove_span_rule("hot_reload");
+        app.update();
+        assert_eq!(*shared.read(), [SpanRule::suppress("compile_shader")]);
+    }
+
+    fn plugin_filter_of(plugin: &LogPlugin) -> String {
+        plugin_filter(plugin.level, &plugin.modules, &plugin.filter)
+    }
+
+    #[test]
+    fn modules_are_filtered_by_level() {
+        let plugin = LogPlugin {
+            filter: "mygame::net::packets=off".to_string(),
+            ..Default::default()
+        }
+        .with_level(Level::WARN)
+        .with_module("wgpu", Level::ERROR)
+        .with_module("mygame::net", Level::TRACE);
+        let filter = plugin_filter_of(&plugin);
+        assert_eq!(
+            filter,
+            "WARN,wgpu=ERROR,mygame::net=TRACE,mygame::net::packets=off"
+        );
+
+        let mut app = App::new();
+        let subscriber = Registry::default()
+            .with(capture_layer(LogCaptureConfig::default(), false, &mut app))
+            .with(EnvFilter::new(filter));
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::warn!(target: "wgpu::core", "wgpu warning");
+            tracing::error!(target: "wgpu::core", "wgpu error");
+            tracing::info!(target: "mygame", "game info");
+            tracing::warn!(target: "mygame", "game warning");
+            tracing::trace!(target: "mygame::net", "net trace");
+            tracing::error!(target: "mygame::net::packets", "packets error");
+        });
+        app.update();
+
+        let events = app.world().resource::<Events<LogMessage>>();
+        let messages: Vec<_> = events
+            .iter_current_update_events()
+            .map(|log| log.message.as_str())
+            .collect();
+        assert_eq!(messages, ["wgpu error", "game warning", "net trace"]);
+    }
+
+    #[test]
+    fn filter_overrides_the_same_module() {
+        let plugin = LogPlugin {
+            filter: "wgpu=warn".to_string(),
+            ..Default::default()
+        }
+        .with_module("wgpu", Level::ERROR);
+        let filter = EnvFilter::new(plugin_filter_of(&plugin));
+        assert_eq!(filter.to_string(), "wgpu=warn,info");
+    }
+
+    fn messages(app: &App) -> Vec<&str> {
+        app.world()
+            .resource::<Events<LogMessage>>()
+            .iter_current_update_events()
+            .map(|log| log.message.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn outputs_have_their_own_filter() {
+        let mut errors = Vec::new();
+        let mut filters = OutputFilters::new(
+            Some("info"),
+            Some("debug,wgpu=off"),
+            None,
+            &[],
+            "warn",
+            &mut errors,
+        )
+        .unwrap();
+        assert!(errors.is_empty());
+
+        // The captures stand in for the console and the file, and the custom layers get the
+        // default filter.
+        let (mut console, mut file, mut custom) = (App::new(), App::new(), App::new());
+        let layers = vec![
+            with_output_filter(
+                capture_layer(LogCaptureConfig::default(), false, &mut console),
+                Some(filters.console()),
+            ),
+            with_output_filter(
+                capture_layer(LogCaptureConfig::default(), false, &mut file),
+                Some(filters.file()),
+            ),
+            with_output_filter(
+                vec![capture_layer(
+                    LogCaptureConfig::default(),
+                    false,
+                    &mut custom,
+                )],
+                Some(filters.default()),
+            ),
+        ];
+        let (global, handle) = reload::Layer::new(filters.global_filter());
+        let subscriber = Registry::default().with(Some(layers)).with(global);
+        let reload = filters.into_reload(handle);
+
+        // The logs more verbose than all the outputs are still skipped.
+        assert_eq!(subscriber.max_level_hint(), Some(LevelFilter::DEBUG));
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::debug!("debug");
+            tracing::info!("info");
+            tracing::warn!("warning");
+            tracing::trace!("trace");
+            tracing::warn!(target: "wgpu", "wgpu warning");
+            // The default filter can still be changed.
+            reload("error").unwrap();
+            tracing::warn!("second warning");
+        });
+        for app in [&mut console, &mut file, &mut custom] {
+            app.update();
+        }
+        assert_eq!(
+            messages(&console),
+            ["info", "warning", "wgpu warning", "second warning"]
+        );
+        assert_eq!(
+            messages(&file),
+            ["debug", "info", "warning", "second warning"]
+        );
+        assert_eq!(messages(&custom), ["warning", "wgpu warning"]);
+    }
+
+    #[test]
+    fn filtered_layers_have_their_own_filter() {
+        let mut errors = Vec::new();
+        let mut filters = OutputFilters::new(
+            None,
+            None,
+            None,
+            &["trace", "wgpu=loud"],
+            "info",
+            &mut errors,
+        )
+        .unwrap();
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].contains("`LogPlugin::filtered_layers` `wgpu=loud`"));
+
+        let (mut verbose, mut invalid, mut custom) = (App::new(), App::new(), App::new());
+        let layers = vec![
+            with_output_filter(
+                vec![capture_layer(
+                    LogCaptureConfig::default(),
+                    false,
+                    &mut custom,
+                )],
+                Some(filters.default()),
+            ),
+            with_output_filter(
+                capture_layer(LogCaptureConfig::default(), false, &mut verbose),
+                Some(filters.layer(0)),
+            ),
+            // The invalid filter is replaced by the default one.
+            with_output_filter(
+                capture_layer(LogCaptureConfig::default(), false, &mut invalid),
+                Some(filters.layer(1)),
+            ),
+        ];
+        let (global, _handle) = reload::Layer::new(filters.global_filter());
+        let subscriber = Registry::default().with(Some(layers)).with(global);
+
+        // The global filter doesn't clip the most verbose layer.
+        assert_eq!(subscriber.max_level_hint(), Some(LevelFilter::TRACE));
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::trace!("trace");
+            tracing::info!("info");
+        });
+        for app in [&mut verbose, &mut invalid, &mut custom] {
+            app.update();
+        }
+        assert_eq!(messages(&verbose), ["trace", "info"]);
+        assert_eq!(messages(&invalid), ["info"]);
+        assert_eq!(messages(&custom), ["info"]);
+    }
+
+    #[test]
+    fn global_filter_keeps_the_most_verbose_level() {
+        let mut errors = Vec::new();
+        let filters =
+            OutputFilters::new(Some("error"), None, None, &[], "info", &mut errors).unwrap();
+        assert_eq!(filters.global_filter().to_string(), "info");
+        let filters =
+            OutputFilters::new(None, Some("wgpu=trace"), None, &[], "info", &mut errors).unwrap();
+        assert_eq!(filters.global_filter().to_string(), "trace");
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn invalid_output_filters_are_ignored() {
+        let mut errors = Vec::new();
+        assert!(OutputFilters::new(None, None, None, &[], "info", &mut errors).is_none());
+        assert!(
+            OutputFilters::new(Some("info,wgpu=eror"), None, None, &[], "info", &mut errors)
+                .is_none()
+        );
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].contains("`LogPlugin::console_filter`"));
+        assert!(errors[0].contains("`wgpu=eror` at position 5"));
+    }
+
+    #[test]
+    #[should_panic(expected = "`mygame:net` is not a valid module path")]
+    fn invalid_module_paths_panic() {
+        let _ = LogPlugin::default().with_module("mygame:net", Level::TRACE);
+    }
+}
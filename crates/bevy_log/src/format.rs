@@ -0,0 +1,573 @@
+use crate::{LevelStyle, LogTimer};
+use std::io::{self, IsTerminal};
+use tracing::{Event, Subscriber};
+use tracing_subscriber::{
+    fmt::{
+        self,
+        format::{DefaultFields, Format, Full, Writer},
+        time::FormatTime,
+        writer::BoxMakeWriter,
+        FmtContext, FormatEvent, FormatFields, MakeWriter,
+    },
+    registry::LookupSpan,
+    Layer,
+};
+
+/// The format of the logs written by the [`LogPlugin`](crate::LogPlugin) to the console and to
+/// its [log file](crate::FileLogConfig).
+///
+/// The format isn't used by the [`LogPlugin::fmt_layer`](crate::LogPlugin::fmt_layer) replacing
+/// the console output, nor with a [`LogPlugin::custom_format`](crate::LogPlugin::custom_format),
+/// nor by the platform-specific outputs of the web, Android and iOS.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LogFormat {
+    /// Human-readable logs, one per line.
+    #[default]
+    Text,
+    /// One JSON object per line, for log aggregation.
+    ///
+    /// Each object has the `timestamp`, `level` and `target` of the log, its fields, including
+    /// its `message`, and the `span` it was written in and the list of all its parent `spans`,
+    /// with their fields.
+    #[cfg(feature = "json")]
+    Json,
+    /// The same JSON objects as [`LogFormat::Json`], pretty-printed on several lines, for local
+    /// debugging.
+    #[cfg(feature = "json")]
+    PrettyJson,
+}
+
+/// Whether the [`LogPlugin`](crate::LogPlugin) colors its console output with ANSI escape codes.
+///
+/// This only applies to the text console output: the [log file](crate::FileLogConfig) and the
+/// JSON [formats](LogFormat) never contain escape codes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AnsiMode {
+    /// Colors the output if it's written to a terminal, unless the `NO_COLOR` environment
+    /// variable is set. Setting the `CLICOLOR_FORCE` environment variable to anything but `0`
+    /// colors the output even if it isn't written to a terminal, for example in CI.
+    ///
+    /// See <https://no-color.org> and <https://bixense.com/clicolors>.
+    #[default]
+    Auto,
+    /// Always colors the output.
+    Always,
+    /// Never colors the output.
+    Never,
+}
+
+impl AnsiMode {
+    /// Returns `true` if the output should be colored, given whether it's written to a terminal.
+    pub fn enabled(self, is_terminal: bool) -> bool {
+        match self {
+            AnsiMode::Always => true,
+            AnsiMode::Never => false,
+            AnsiMode::Auto => {
+                let var = |name| std::env::var_os(name).filter(|value| !value.is_empty());
+                if var("NO_COLOR").is_some() {
+                    false
+                } else if var("CLICOLOR_FORCE").is_some_and(|value| value != "0") {
+                    true
+                } else {
+                    is_terminal
+                }
+            }
+        }
+    }
+}
+
+/// The stream the [`LogPlugin`](crate::LogPlugin) writes its console output to.
+///
+/// This isn't used by the [`LogPlugin::fmt_layer`](crate::LogPlugin::fmt_layer) replacing the
+/// console output, nor by the platform-specific outputs of the web, Android and iOS. A
+/// [`LogPlugin::custom_writer`](crate::LogPlugin::custom_writer) is used instead of the standard
+/// streams, unless the console output is disabled with [`ConsoleOutput::None`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ConsoleOutput {
+    /// Writes the logs to the standard error, leaving the standard output to the app.
+    #[default]
+    Stderr,
+    /// Writes the logs to the standard output, for example for tools whose standard error is
+    /// reserved.
+    Stdout,
+    /// Doesn't write the logs to the console. The [log file](crate::FileLogConfig), the
+    /// [`capture`](crate::LogPlugin::capture) and the
+    /// [`custom_layers`](crate::LogPlugin::custom_layers) still get them.
+    None,
+}
+
+impl ConsoleOutput {
+    /// Returns the writer of the selected stream, if any.
+    pub(crate) fn writer(self) -> Option<BoxMakeWriter> {
+        self.select(io::stdout, io::stderr)
+    }
+
+    /// Returns `true` if the selected stream is a terminal.
+    pub(crate) fn is_terminal(self) -> bool {
+        match self {
+            ConsoleOutput::Stderr => io::stderr().is_terminal(),
+            ConsoleOutput::Stdout => io::stdout().is_terminal(),
+            ConsoleOutput::None => false,
+        }
+    }
+
+    fn select<O, E>(self, stdout: O, stderr: E) -> Option<BoxMakeWriter>
+    where
+        O: for<'writer> MakeWriter<'writer> + Send + Sync + 'static,
+        E: for<'writer> MakeWriter<'writer> + Send + Sync + 'static,
+    {
+        match self {
+            ConsoleOutput::Stderr => Some(BoxMakeWriter::new(stderr)),
+            ConsoleOutput::Stdout => Some(BoxMakeWriter::new(stdout)),
+            ConsoleOutput::None => None,
+        }
+    }
+}
+
+/// Returns a layer formatting the logs with `format` and writing them to `writer`, with the
+/// timestamps of `timer` if any.
+///
+/// The text format formats the fields with `fields`, and the levels with `level_style`, with ANSI
+/// escape codes if `ansi` is `true`.
+pub(crate) fn format_layer<S, N, W>(
+    format: LogFormat,
+    ansi: bool,
+    level_style: LevelStyle,
+    timer: Option<LogTimer>,
+    fields: N,
+    writer: W,
+) -> Box<dyn Layer<S> + Send + Sync>
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    N: for<'writer> FormatFields<'writer> + Send + Sync + 'static,
+    W: for<'writer> MakeWriter<'writer> + Send + Sync + 'static,
+{
+    let layer = fmt::Layer::default().with_ansi(ansi).fmt_fields(fields);
+    match format {
+        LogFormat::Text => Box::new(
+            layer
+                .event_format(TextFormat {
+                    timer,
+                    level_style,
+                    format: fmt::format().without_time().with_level(false),
+                })
+                .with_writer(writer),
+        ),
+        #[cfg(feature = "json")]
+        LogFormat::Json | LogFormat::PrettyJson => {
+            let pretty = format == LogFormat::PrettyJson;
+            // Without a timer, the `timestamp` field must not be written either.
+            match timer {
+                Some(timer) => json_layer(layer.with_timer(timer), pretty, writer),
+                None => json_layer(layer.without_time(), pretty, writer),
+            }
+        }
+    }
+}
+
+#[cfg(feature = "json")]
+fn json_layer<S, N, T, W>(
+    layer: fmt::Layer<S, N, Format<Full, T>>,
+    pretty: bool,
+    writer: W,
+) -> Box<dyn Layer<S> + Send + Sync>
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    N: for<'writer> FormatFields<'writer> + Send + Sync + 'static,
+    T: FormatTime + Send + Sync + 'static,
+    W: for<'writer> MakeWriter<'writer> + Send + Sync + 'static,
+{
+    if pretty {
+        Box::new(json(layer).with_writer(PrettyJson(writer)))
+    } else {
+        Box::new(json(layer).with_writer(writer))
+    }
+}
+
+/// The text format: the timestamp of a log and its level, styled with a [`LevelStyle`], followed
+/// by the rest of the default format of `tracing`.
+struct TextFormat {
+    timer: Option<LogTimer>,
+    level_style: LevelStyle,
+    /// The default format, without the timestamp and the level.
+    format: Format<Full, ()>,
+}
+
+impl<S, N> FormatEvent<S, N> for TextFormat
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    N: for<'a> FormatFields<'a> + 'static,
+{
+    fn format_event(
+        &self,
+        ctx: &FmtContext<'_, S, N>,
+        mut writer: Writer<'_>,
+        event: &Event<'_>,
+    ) -> core::fmt::Result {
+        if let Some(timer) = &self.timer {
+            write_timestamp(timer, &mut writer)?;
+        }
+        self.level_style
+            .write(&mut writer, *event.metadata().level())?;
+        self.format.format_event(ctx, writer, event)
+    }
+}
+
+/// Writes the timestamp of a log followed by a space, dimmed if `writer` accepts ANSI escape
+/// codes, like the default format of `tracing`.
+fn write_timestamp(timer: &LogTimer, writer: &mut Writer<'_>) -> core::fmt::Result {
+    let ansi = writer.has_ansi_escapes();
+    if ansi {
+        writer.write_str("\x1b[2m")?;
+    }
+    timer.format_time(writer)?;
+    if ansi {
+        writer.write_str("\x1b[0m")?;
+    }
+    writer.write_char(' ')
+}
+
+/// Returns a layer formatting the logs with the custom `formatter` of the
+/// [`LogPlugin`](crate::LogPlugin) and writing them to `writer`, after the timestamps of `timer`
+/// if any.
+///
+/// The formatter can check [`Writer::has_ansi_escapes`] to only color its output if `ansi` is
+/// `true`.
+pub(crate) fn custom_format_layer<S, W>(
+    formatter: Box<dyn FormatEvent<S, DefaultFields> + Send + Sync>,
+    ansi: bool,
+    timer: Option<LogTimer>,
+    writer: W,
+) -> Box<dyn Layer<S> + Send + Sync>
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    W: for<'writer> MakeWriter<'writer> + Send + Sync + 'static,
+{
+    Box::new(
+        fmt::Layer::default()
+            .with_ansi(ansi)
+            .event_format(CustomFormat { formatter, timer })
+            .with_writer(writer),
+    )
+}
+
+/// Writes the timestamp of a log before the output of a custom formatter.
+struct CustomFormat<S, N> {
+    formatter: Box<dyn FormatEvent<S, N> + Send + Sync>,
+    timer: Option<LogTimer>,
+}
+
+impl<S, N> FormatEvent<S, N> for CustomFormat<S, N>
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    N: for<'a> FormatFields<'a> + 'static,
+{
+    fn format_event(
+        &self,
+        ctx: &FmtContext<'_, S, N>,
+        mut writer: Writer<'_>,
+        event: &Event<'_>,
+    ) -> core::fmt::Result {
+        if let Some(timer) = &self.timer {
+            write_timestamp(timer, &mut writer)?;
+        }
+        self.formatter.format_event(ctx, writer, event)
+    }
+}
+
+#[cfg(feature = "json")]
+fn json<S, N, T>(
+    layer: fmt::Layer<S, N, Format<Full, T>>,
+) -> fmt::Layer<S, fmt::format::JsonFields, Format<fmt::format::Json, T>>
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    N: for<'writer> FormatFields<'writer> + 'static,
+{
+    layer
+        .with_ansi(false)
+        .json()
+        .flatten_event(true)
+        .with_span_list(true)
+}
+
+/// Pretty-prints the JSON objects written by the logs.
+#[cfg(feature = "json")]
+struct PrettyJson<W>(W);
+
+#[cfg(feature = "json")]
+impl<'a, W: MakeWriter<'a>> MakeWriter<'a> for PrettyJson<W> {
+    type Writer = PrettyJson<W::Writer>;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        PrettyJson(self.0.make_writer())
+    }
+}
+
+#[cfg(feature = "json")]
+impl<W: io::Write> io::Write for PrettyJson<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        // Each log is written at once, as a single line.
+        match serde_json::from_slice::<serde_json::Value>(buf) {
+            Ok(value) => {
+                serde_json::to_writer_pretty(&mut self.0, &value)?;
+                self.0.write_all(b"\n")?;
+            }
+            Err(_) => self.0.write_all(buf)?,
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.0.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::sync::Arc;
+    use core::fmt;
+    use std::{
+        io::{self, Write},
+        sync::Mutex,
+    };
+    use tracing::{info, info_span, warn, Event};
+    use tracing_subscriber::{
+        fmt::{format::Writer, writer::BoxMakeWriter, FmtContext, FormatFields},
+        prelude::*,
+        Registry,
+    };
+
+    use super::{
+        custom_format_layer, format_layer, AnsiMode, ConsoleOutput, DefaultFields, LogFormat,
+    };
+    use crate::{AnsiColor, LevelLabel, LevelStyle, TimestampMode};
+
+    struct Capture(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Capture {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn capture_writer() -> (Arc<Mutex<Vec<u8>>>, impl Fn() -> Capture + Send + Sync) {
+        let output = Arc::new(Mutex::new(Vec::new()));
+        let writer = {
+            let output = output.clone();
+            move || Capture(output.clone())
+        };
+        (output, writer)
+    }
+
+    fn capture(format: LogFormat, ansi: bool) -> String {
+        let (output, writer) = capture_writer();
+        let layer = format_layer(
+            format,
+            ansi,
+            LevelStyle::default(),
+            TimestampMode::default().timer(),
+            DefaultFields::default(),
+            writer,
+        );
+        let subscriber = Registry::default().with(layer);
+        tracing::subscriber::with_default(subscriber, || {
+            let _frame = info_span!("frame", number = 3).entered();
+            info!(answer = 42, "Computed the answer");
+        });
+        let output = output.lock().unwrap();
+        String::from_utf8(output.clone()).unwrap()
+    }
+
+    #[test]
+    fn ansi_mode_controls_escape_codes() {
+        assert!(AnsiMode::Always.enabled(false));
+        assert!(!AnsiMode::Never.enabled(false));
+
+        let colored = capture(LogFormat::Text, true);
+        assert!(colored.contains('\x1b'));
+        let plain = capture(LogFormat::Text, AnsiMode::Never.enabled(false));
+        assert!(!plain.contains('\x1b'));
+        assert!(plain.contains("frame{number=3}: bevy_log::format::tests: Computed the answer"));
+    }
+
+    fn capture_styled(level_style: LevelStyle, ansi: bool) -> String {
+        let (output, writer) = capture_writer();
+        let layer = format_layer(
+            LogFormat::Text,
+            ansi,
+            level_style,
+            None,
+            DefaultFields::default(),
+            writer,
+        );
+        let subscriber = Registry::default().with(layer);
+        tracing::subscriber::with_default(subscriber, || {
+            info!("Ready");
+            warn!("Low health");
+            tracing::error!("Lost");
+        });
+        let output = output.lock().unwrap();
+        String::from_utf8(output.clone()).unwrap()
+    }
+
+    #[test]
+    fn default_level_style_matches_tracing() {
+        let target = module_path!();
+        assert_eq!(
+            capture_styled(LevelStyle::default(), false),
+            format!(" INFO {target}: Ready\n WARN {target}: Low health\nERROR {target}: Lost\n")
+        );
+        // The colors of the default format of `tracing`, and its dimmed target.
+        let colored = capture_styled(LevelStyle::default(), true);
+        assert!(colored.starts_with("\x1b[32m INFO\x1b[0m \x1b[2mbevy_log"));
+        assert!(colored.contains("\x1b[33m WARN\x1b[0m "));
+        assert!(colored.contains("\x1b[31mERROR\x1b[0m "));
+    }
+
+    #[test]
+    fn level_styles_are_applied() {
+        let target = module_path!();
+        let level_style = LevelStyle {
+            error: LevelLabel {
+                color: Some(AnsiColor::Rgb(255, 0, 128)),
+                bold: true,
+                underline: true,
+                prefix: Some("[E]".into()),
+            },
+            warn: LevelLabel {
+                color: Some(AnsiColor::Fixed(208)),
+                prefix: Some("⚠️".into()),
+                ..Default::default()
+            },
+            ..LevelStyle::uncolored()
+        };
+
+        let colored = capture_styled(level_style.clone(), true);
+        let levels: Vec<_> = colored
+            .lines()
+            .map(|line| line.split_once(target).unwrap().0)
+            .collect();
+        assert_eq!(
+            levels,
+            [
+                " INFO \x1b[2m",
+                "\x1b[38;5;208m⚠️\x1b[0m \x1b[2m",
+                "\x1b[1;4;38;2;255;0;128m[E]\x1b[0m \x1b[2m"
+            ]
+        );
+
+        // Only the prefixes are kept when the output isn't colored.
+        assert_eq!(
+            capture_styled(level_style, AnsiMode::Never.enabled(true)),
+            format!(" INFO {target}: Ready\n⚠️ {target}: Low health\n[E] {target}: Lost\n")
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "json")]
+    fn json_logs_are_one_object_per_line() {
+        let output = capture(LogFormat::Json, true);
+        assert_eq!(output.lines().count(), 1);
+
+        assert!(!output.contains('\x1b'));
+        let log: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert!(log["timestamp"].is_string());
+        assert_eq!(log["level"], "INFO");
+        assert_eq!(log["target"], module_path!());
+        assert_eq!(log["message"], "Computed the answer");
+        assert_eq!(log["answer"], 42);
+        assert_eq!(log["span"]["name"], "frame");
+        assert_eq!(log["spans"][0]["number"], 3);
+    }
+
+    #[test]
+    #[cfg(feature = "json")]
+    fn pretty_json_logs_span_several_lines() {
+        let output = capture(LogFormat::PrettyJson, true);
+        assert!(output.lines().count() > 1);
+
+        let log: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(log["message"], "Computed the answer");
+    }
+
+    #[test]
+    fn console_output_selects_the_stream() {
+        fn route(output: ConsoleOutput) -> (String, String) {
+            let (stdout, stdout_writer) = capture_writer();
+            let (stderr, stderr_writer) = capture_writer();
+            if let Some(writer) = output.select(stdout_writer, stderr_writer) {
+                let layer = format_layer(
+                    LogFormat::Text,
+                    false,
+                    LevelStyle::default(),
+                    None,
+                    DefaultFields::default(),
+                    writer,
+                );
+                let subscriber = Registry::default().with(layer);
+                tracing::subscriber::with_default(subscriber, || {
+                    info!("Ready");
+                });
+            }
+            let read = |output: Arc<Mutex<Vec<u8>>>| {
+                String::from_utf8(output.lock().unwrap().clone()).unwrap()
+            };
+            (read(stdout), read(stderr))
+        }
+
+        let (stdout, stderr) = route(ConsoleOutput::Stderr);
+        assert_eq!(stdout, "");
+        assert!(stderr.contains("Ready"));
+        let (stdout, stderr) = route(ConsoleOutput::Stdout);
+        assert!(stdout.contains("Ready"));
+        assert_eq!(stderr, "");
+        assert_eq!(route(ConsoleOutput::None), (String::new(), String::new()));
+        assert!(!ConsoleOutput::None.is_terminal());
+    }
+
+    fn compact(
+        ctx: &FmtContext<'_, Registry, DefaultFields>,
+        mut writer: Writer<'_>,
+        event: &Event<'_>,
+    ) -> fmt::Result {
+        write!(writer, "[{}] ", event.metadata().level())?;
+        ctx.field_format().format_fields(writer.by_ref(), event)?;
+        writeln!(writer)
+    }
+
+    #[test]
+    fn custom_formatters_write_to_custom_writers() {
+        let (output, writer) = capture_writer();
+        type Compact =
+            fn(&FmtContext<'_, Registry, DefaultFields>, Writer<'_>, &Event<'_>) -> fmt::Result;
+        let layer = custom_format_layer(
+            Box::new(compact as Compact),
+            false,
+            TimestampMode::Uptime.timer(),
+            BoxMakeWriter::new(writer),
+        );
+        let subscriber = Registry::default().with(layer);
+        tracing::subscriber::with_default(subscriber, || {
+            info!(answer = 42, "Computed the answer");
+            warn!("Not sure about the question");
+        });
+
+        let output = output.lock().unwrap();
+        let output = String::from_utf8(output.clone()).unwrap();
+        let lines: Vec<_> = output
+            .lines()
+            .map(|line| line.split_once("s ").unwrap().1)
+            .collect();
+        assert_eq!(
+            lines,
+            [
+                "[INFO] Computed the answer answer=42",
+                "[WARN] Not sure about the question"
+            ]
+        );
+    }
+}
@@ -0,0 +1,221 @@
+use crate::{BoxedLayer, Level};
+use alloc::collections::VecDeque;
+use bevy_app::{App, LogMessageVisitor, PreUpdate};
+use bevy_ecs::{resource::Resource, system::ResMut};
+use bevy_platform::{
+    sync::{Arc, Mutex, PoisonError},
+    time::SystemTime,
+};
+use core::fmt;
+use tracing::{Event, Subscriber};
+use tracing_log::NormalizeEvent;
+use tracing_subscriber::{layer::Context, Layer};
+
+/// Settings of the [`LogHistory`] kept by the [`LogPlugin`](crate::LogPlugin).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LogHistoryConfig {
+    /// The maximum number of logs kept. The oldest logs are evicted past that.
+    pub capacity: usize,
+}
+
+impl Default for LogHistoryConfig {
+    fn default() -> Self {
+        Self { capacity: 500 }
+    }
+}
+
+/// A log kept in the [`LogHistory`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LogRecord {
+    /// The level of the log.
+    pub level: Level,
+    /// The target of the log, usually the path of the module that wrote it.
+    pub target: String,
+    /// The message of the log, followed by its other fields, such as `Loaded level=3`.
+    pub message: String,
+    /// When the log was written.
+    pub timestamp: SystemTime,
+}
+
+/// The most recent logs, kept by the [`LogPlugin`](crate::LogPlugin) when its
+/// [`history`](crate::LogPlugin::history) is set, for example for a log window in dev tools.
+///
+/// The logs of a frame are added in [`PreUpdate`], during the next one. Only the logs that pass
+/// the [`LogPlugin::filter`](crate::LogPlugin::filter) are kept. The history works alongside the
+/// [`capture`](crate::LogPlugin::capture) and the
+/// [`custom_layers`](crate::LogPlugin::custom_layers).
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_log::{Level, LogHistory};
+/// fn show_problems(history: Res<LogHistory>) {
+///     for record in history.iter_level_at_least(Level::WARN) {
+///         // Display `record.message` in the dev tools.
+///     }
+/// }
+/// ```
+#[derive(Resource)]
+pub struct LogHistory {
+    records: VecDeque<LogRecord>,
+    capacity: usize,
+    /// The logs written since the last frame, bounded by the capacity too.
+    pending: Arc<Mutex<VecDeque<LogRecord>>>,
+}
+
+impl LogHistory {
+    /// Returns the logs, from the oldest to the most recent.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &LogRecord> + ExactSizeIterator {
+        self.records.iter()
+    }
+
+    /// Returns the logs of `level` or more important, from the oldest to the most recent. For
+    /// example, [`Level::WARN`] returns the warnings and the errors.
+    pub fn iter_level_at_least(&self, level: Level) -> impl DoubleEndedIterator<Item = &LogRecord> {
+        // The most important levels are the smallest.
+        self.records
+            .iter()
+            .filter(move |record| record.level <= level)
+    }
+
+    /// Returns the number of logs kept.
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    /// Returns `true` if no logs are kept.
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// Returns the maximum number of logs kept.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Removes all the logs, including those written since the last frame.
+    pub fn clear(&mut self) {
+        self.records.clear();
+        self.pending
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clear();
+    }
+}
+
+impl fmt::Debug for LogHistory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LogHistory")
+            .field("records", &self.records)
+            .field("capacity", &self.capacity)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Pushes `record` to `records`, evicting the oldest record if they are full.
+fn push(records: &mut VecDeque<LogRecord>, capacity: usize, record: LogRecord) {
+    if records.len() == capacity {
+        records.pop_front();
+    }
+    records.push_back(record);
+}
+
+/// Creates the layer keeping the logs, and adds the [`LogHistory`] and the system filling it to
+/// `app`.
+pub(crate) fn history_layer(config: LogHistoryConfig, app: &mut App) -> BoxedLayer {
+    let capacity = config.capacity.max(1);
+    let pending = Arc::new(Mutex::new(VecDeque::new()));
+    app.insert_resource(LogHistory {
+        records: VecDeque::with_capacity(capacity),
+        capacity,
+        pending: pending.clone(),
+    })
+    .add_systems(PreUpdate, update_log_history);
+    Box::new(HistoryLayer { pending, capacity })
+}
+
+/// Adds the logs written since the last frame to the [`LogHistory`].
+fn update_log_history(mut history: ResMut<LogHistory>) {
+    let history = &mut *history;
+    let mut pending = history
+        .pending
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    for record in pending.drain(..) {
+        push(&mut history.records, history.capacity, record);
+    }
+}
+
+/// Adds the logs to the pending logs of the [`LogHistory`].
+struct HistoryLayer {
+    pending: Arc<Mutex<VecDeque<LogRecord>>>,
+    capacity: usize,
+}
+
+impl<S: Subscriber> Layer<S> for HistoryLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let mut pending = self.pending.lock().unwrap_or_else(PoisonError::into_inner);
+        push(&mut pending, self.capacity, log_record(event));
+    }
+}
+
+/// Creates the [`LogRecord`] of `event`, written now.
+pub(crate) fn log_record(event: &Event<'_>) -> LogRecord {
+    let mut visitor = LogMessageVisitor::default();
+    event.record(&mut visitor);
+    // The logs of the `log` crate all have the same metadata, the actual one is in their
+    // fields.
+    let normalized = event.normalized_metadata();
+    let metadata = normalized.as_ref().unwrap_or_else(|| event.metadata());
+    LogRecord {
+        level: *metadata.level(),
+        target: metadata.target().to_string(),
+        message: visitor.message,
+        timestamp: SystemTime::now(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_app::App;
+    use tracing::{error, info, warn};
+    use tracing_subscriber::{prelude::*, Registry};
+
+    use super::{history_layer, LogHistory, LogHistoryConfig, LogRecord};
+    use crate::Level;
+
+    fn messages<'a>(records: impl Iterator<Item = &'a LogRecord>) -> Vec<&'a str> {
+        records.map(|record| record.message.as_str()).collect()
+    }
+
+    #[test]
+    fn oldest_logs_are_evicted_in_order() {
+        let mut app = App::new();
+        let subscriber =
+            Registry::default().with(history_layer(LogHistoryConfig { capacity: 3 }, &mut app));
+        tracing::subscriber::with_default(subscriber, || {
+            info!("first");
+            warn!("second");
+            app.update();
+            error!(code = 3, "third");
+            info!("fourth");
+            app.update();
+            warn!("fifth");
+        });
+        app.update();
+
+        let history = app.world().resource::<LogHistory>();
+        assert_eq!(history.len(), 3);
+        assert_eq!(
+            messages(history.iter()),
+            ["third code=3", "fourth", "fifth"]
+        );
+        assert_eq!(
+            messages(history.iter_level_at_least(Level::WARN)),
+            ["third code=3", "fifth"]
+        );
+        assert_eq!(history.iter().next().unwrap().target, module_path!());
+
+        app.world_mut().resource_mut::<LogHistory>().clear();
+        assert!(app.world().resource::<LogHistory>().is_empty());
+    }
+}
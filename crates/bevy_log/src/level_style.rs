@@ -0,0 +1,188 @@
+use crate::Level;
+use alloc::borrow::Cow;
+use core::fmt;
+use tracing_subscriber::fmt::format::Writer;
+
+/// How the [`LogPlugin`](crate::LogPlugin) writes the level of each log in its text console
+/// output, for example to keep the levels readable on a light terminal or for colorblind users.
+///
+/// The styles are only applied when the output is colored, according to the
+/// [`AnsiMode`](crate::AnsiMode), while the prefixes are always written. Neither is used by the
+/// JSON [formats](crate::LogFormat), the [log file](crate::FileLogConfig), nor a
+/// [`custom_format`](crate::LogPlugin::custom_format).
+///
+/// ```
+/// # use bevy_log::{AnsiColor, LevelLabel, LevelStyle, LogPlugin};
+/// let level_style = LevelStyle {
+///     error: LevelLabel {
+///         color: Some(AnsiColor::Magenta),
+///         bold: true,
+///         prefix: Some("[E]".into()),
+///         ..Default::default()
+///     },
+///     warn: LevelLabel {
+///         color: Some(AnsiColor::Cyan),
+///         underline: true,
+///         prefix: Some("[W]".into()),
+///         ..Default::default()
+///     },
+///     ..LevelStyle::uncolored()
+/// };
+/// LogPlugin {
+///     level_style,
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LevelStyle {
+    /// The label of [`Level::ERROR`].
+    pub error: LevelLabel,
+    /// The label of [`Level::WARN`].
+    pub warn: LevelLabel,
+    /// The label of [`Level::INFO`].
+    pub info: LevelLabel,
+    /// The label of [`Level::DEBUG`].
+    pub debug: LevelLabel,
+    /// The label of [`Level::TRACE`].
+    pub trace: LevelLabel,
+}
+
+impl LevelStyle {
+    /// The levels without any style or prefix.
+    pub fn uncolored() -> Self {
+        Self {
+            error: LevelLabel::default(),
+            warn: LevelLabel::default(),
+            info: LevelLabel::default(),
+            debug: LevelLabel::default(),
+            trace: LevelLabel::default(),
+        }
+    }
+
+    /// Returns the label of `level`.
+    pub fn get(&self, level: Level) -> &LevelLabel {
+        match level {
+            Level::ERROR => &self.error,
+            Level::WARN => &self.warn,
+            Level::INFO => &self.info,
+            Level::DEBUG => &self.debug,
+            Level::TRACE => &self.trace,
+        }
+    }
+
+    /// Writes the label of `level`, followed by a space, styled if `writer` accepts ANSI escape
+    /// codes.
+    pub(crate) fn write(&self, writer: &mut Writer<'_>, level: Level) -> fmt::Result {
+        let label = self.get(level);
+        let styled = writer.has_ansi_escapes() && label.is_styled();
+        if styled {
+            label.write_escape(writer)?;
+        }
+        match &label.prefix {
+            Some(prefix) => writer.write_str(prefix)?,
+            // Right-aligned, so that the messages of all the levels line up.
+            None => write!(writer, "{:>5}", level.as_str())?,
+        }
+        if styled {
+            writer.write_str("\x1b[0m")?;
+        }
+        writer.write_char(' ')
+    }
+}
+
+/// The levels colored like the default console output of `tracing`.
+impl Default for LevelStyle {
+    fn default() -> Self {
+        let colored = |color| LevelLabel {
+            color: Some(color),
+            ..Default::default()
+        };
+        Self {
+            error: colored(AnsiColor::Red),
+            warn: colored(AnsiColor::Yellow),
+            info: colored(AnsiColor::Green),
+            debug: colored(AnsiColor::Blue),
+            trace: colored(AnsiColor::Magenta),
+        }
+    }
+}
+
+/// How a level is written, see [`LevelStyle`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LevelLabel {
+    /// The color of the label, or `None` to keep the color of the terminal.
+    pub color: Option<AnsiColor>,
+    /// Whether the label is bold.
+    pub bold: bool,
+    /// Whether the label is underlined.
+    pub underline: bool,
+    /// Written instead of the name of the level, such as `[E]` or an emoji. The name of the
+    /// level is written if `None`.
+    pub prefix: Option<Cow<'static, str>>,
+}
+
+impl LevelLabel {
+    fn is_styled(&self) -> bool {
+        self.color.is_some() || self.bold || self.underline
+    }
+
+    /// Writes the ANSI escape code starting the style of the label.
+    fn write_escape(&self, writer: &mut Writer<'_>) -> fmt::Result {
+        let mut codes = Vec::new();
+        if self.bold {
+            codes.push(Cow::Borrowed("1"));
+        }
+        if self.underline {
+            codes.push(Cow::Borrowed("4"));
+        }
+        if let Some(color) = self.color {
+            codes.push(color.code());
+        }
+        write!(writer, "\x1b[{}m", codes.join(";"))
+    }
+}
+
+/// A foreground color of a terminal, see [`LevelLabel::color`].
+///
+/// The first eight colors are picked from the palette of the terminal, so they follow its theme.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AnsiColor {
+    /// Black.
+    Black,
+    /// Red.
+    Red,
+    /// Green.
+    Green,
+    /// Yellow.
+    Yellow,
+    /// Blue.
+    Blue,
+    /// Magenta, also called purple.
+    Magenta,
+    /// Cyan.
+    Cyan,
+    /// White.
+    White,
+    /// A color of the 256-color palette of the terminal.
+    Fixed(u8),
+    /// A 24-bit color, for the terminals supporting them.
+    Rgb(u8, u8, u8),
+}
+
+impl AnsiColor {
+    /// Returns the SGR parameters setting this color as the foreground color.
+    fn code(self) -> Cow<'static, str> {
+        match self {
+            AnsiColor::Black => "30".into(),
+            AnsiColor::Red => "31".into(),
+            AnsiColor::Green => "32".into(),
+            AnsiColor::Yellow => "33".into(),
+            AnsiColor::Blue => "34".into(),
+            AnsiColor::Magenta => "35".into(),
+            AnsiColor::Cyan => "36".into(),
+            AnsiColor::White => "37".into(),
+            AnsiColor::Fixed(index) => format!("38;5;{index}").into(),
+            AnsiColor::Rgb(r, g, b) => format!("38;2;{r};{g};{b}").into(),
+        }
+    }
+}
@@ -18,11 +18,31 @@
 
 extern crate alloc;
 
-use core::error::Error;
-
 #[cfg(target_os = "android")]
 mod android_tracing;
+mod capture;
+mod every;
+mod file;
+#[cfg(not(target_arch = "wasm32"))]
+mod file_writer;
+mod filter;
+mod format;
+mod history;
+mod level_style;
 mod once;
+#[cfg(feature = "otlp")]
+mod otlp;
+mod panic_hook;
+#[cfg(feature = "bevy_reflect")]
+mod pretty_reflect;
+mod rate_limit;
+mod span_filter;
+pub mod test;
+mod timestamp;
+#[cfg(any(feature = "tracing-chrome", feature = "tracing-tracy"))]
+mod tracing_capture;
+#[cfg(target_arch = "wasm32")]
+mod web_console;
 
 #[cfg(feature = "trace_tracy_memory")]
 #[global_allocator]
@@ -41,6 +61,9 @@ pub mod prelude {
     #[doc(hidden)]
     pub use crate::{debug_once, error_once, info_once, trace_once, warn_once};
 
+    #[doc(hidden)]
+    pub use crate::{debug_every, error_every, info_every, trace_every, warn_every};
+
     #[doc(hidden)]
     pub use bevy_utils::once;
 }
@@ -52,40 +75,45 @@ pub use tracing::{
 };
 pub use tracing_subscriber;
 
-use bevy_app::{App, Plugin};
+pub use capture::{FieldValue, LogCaptureConfig, LogMessage};
+#[doc(hidden)]
+pub use every::IntervalFlag;
+pub use file::{FileLogConfig, LogRotation};
+pub use filter::{LogFilter, LogFilterError};
+pub use format::{AnsiMode, ConsoleOutput, LogFormat};
+pub use history::{LogHistory, LogHistoryConfig, LogRecord};
+pub use level_style::{AnsiColor, LevelLabel, LevelStyle};
+#[cfg(feature = "otlp")]
+pub use otlp::OtlpConfig;
+#[cfg(feature = "bevy_reflect")]
+pub use pretty_reflect::*;
+pub use rate_limit::LogRateLimit;
+pub use span_filter::SpanRule;
+pub use timestamp::{LogTimer, TimestampMode};
+#[cfg(any(feature = "tracing-chrome", feature = "tracing-tracy"))]
+pub use tracing_capture::{TracingCapture, TracingCaptureError};
+
+use bevy_app::{App, Plugin, PostUpdate};
+use filter::OutputFilters;
+use span_filter::SharedSpanRules;
 use tracing_log::LogTracer;
 use tracing_subscriber::{
-    filter::{FromEnvError, ParseError},
+    fmt::{format::DefaultFields, writer::BoxMakeWriter, FormatEvent},
     layer::Layered,
     prelude::*,
     registry::Registry,
-    EnvFilter, Layer,
-};
-#[cfg(feature = "tracing-chrome")]
-use {
-    bevy_ecs::resource::Resource,
-    bevy_platform::cell::SyncCell,
-    tracing_subscriber::fmt::{format::DefaultFields, FormattedFields},
+    reload, EnvFilter, Layer,
 };
 
-/// Wrapper resource for `tracing-chrome`'s flush guard.
-/// When the guard is dropped the chrome log is written to file.
-#[cfg(feature = "tracing-chrome")]
-#[expect(
-    dead_code,
-    reason = "`FlushGuard` never needs to be read, it just needs to be kept alive for the `App`'s lifetime."
-)]
-#[derive(Resource)]
-pub(crate) struct FlushGuard(SyncCell<tracing_chrome::FlushGuard>);
-
 /// Adds logging to Apps. This plugin is part of the `DefaultPlugins`. Adding
 /// this plugin will setup a collector appropriate to your target platform:
 /// * Using [`tracing-subscriber`](https://crates.io/crates/tracing-subscriber) by default,
-///   logging to `stdout`.
+///   logging to `stderr`, or to the stream of the [`console_output`](LogPlugin::console_output).
 /// * Using [`android_log-sys`](https://crates.io/crates/android_log-sys) on Android,
-///   logging to Android logs.
-/// * Using [`tracing-wasm`](https://crates.io/crates/tracing-wasm) in Wasm, logging
-///   to the browser console.
+///   logging to Android logs with the priority of the level of each log.
+/// * In Wasm, logging to the browser console with the `console` method of the level of each log,
+///   and using [`tracing-wasm`](https://crates.io/crates/tracing-wasm) to report the spans in the
+///   browser performance timeline.
 ///
 /// You can configure this plugin.
 /// ```no_run
@@ -94,11 +122,29 @@ pub(crate) struct FlushGuard(SyncCell<tracing_chrome::FlushGuard>);
 /// # use tracing::Level;
 /// fn main() {
 ///     App::new()
+///         .add_plugins(DefaultPlugins.set(
+///             LogPlugin::default()
+///                 .with_level(Level::WARN)
+///                 .with_module("wgpu", Level::ERROR)
+///                 .with_module("mygame::net", Level::TRACE),
+///         ))
+///         .run();
+/// }
+/// ```
+///
+/// The filter can also be set with a string, in the [`EnvFilter`] format:
+/// ```no_run
+/// # use bevy_app::{App, NoopPluginGroup as DefaultPlugins, PluginGroup};
+/// # use bevy_log::LogPlugin;
+/// # use tracing::Level;
+/// fn main() {
+///     App::new()
 ///         .add_plugins(DefaultPlugins.set(LogPlugin {
 ///             level: Level::DEBUG,
 ///             filter: "wgpu=error,bevy_render=info,bevy_ecs=trace".to_string(),
-///             custom_layer: |_| None,
+///             custom_layers: |_| Vec::new(),
 ///             fmt_layer: |_| None,
+///             ..Default::default()
 ///         }))
 ///         .run();
 /// }
@@ -111,6 +157,13 @@ pub(crate) struct FlushGuard(SyncCell<tracing_chrome::FlushGuard>);
 /// If you define the `RUST_LOG` environment variable, the [`LogPlugin`] settings
 /// will be ignored.
 ///
+/// An invalid filter, such as `warn,wgpu=eror`, is reported with an error quoting the invalid
+/// directive once the plugin is built. An invalid `RUST_LOG` is ignored, and an invalid
+/// [`LogPlugin::filter`] is ignored while keeping the [`level`](LogPlugin::level) and the
+/// [`modules`](LogPlugin::modules).
+///
+/// Once the app is running, the filter can be changed through the [`LogFilter`] resource.
+///
 /// Also, to disable color terminal output (ANSI escape codes), you can
 /// set the environment variable `NO_COLOR` to any value. This common
 /// convention is documented at [no-color.org](https://no-color.org/).
@@ -129,6 +182,13 @@ pub(crate) struct FlushGuard(SyncCell<tracing_chrome::FlushGuard>);
 /// }
 /// ```
 ///
+/// With the `early_logs` feature, the logs written after the [`App`] is created and before this
+/// plugin is built, for example by the plugins added before it, are buffered and replayed once it
+/// is built, with a `replayed = true` field. Only the logs of the default [`level`](Self::level),
+/// `INFO`, or more important are buffered. At most 1024 logs are kept, and they are dropped if
+/// this plugin isn't built within 30 seconds. Since the global tracing subscriber is set when the
+/// [`App`] is created, it can't be set by your own collector afterwards.
+///
 /// If you want to setup your own tracing collector, you should disable this
 /// plugin from `DefaultPlugins`:
 /// ```no_run
@@ -225,29 +285,128 @@ pub(crate) struct FlushGuard(SyncCell<tracing_chrome::FlushGuard>);
 /// ```
 pub struct LogPlugin {
     /// Filters logs using the [`EnvFilter`] format
+    ///
+    /// These directives come after the [`modules`](Self::modules), and override the directives
+    /// of the same modules.
     pub filter: String,
 
     /// Filters out logs that are "less than" the given level.
     /// This can be further filtered using the `filter` setting.
     pub level: Level,
 
+    /// Filters out the logs of the given modules, and of their submodules, that are "less than"
+    /// the given level, instead of the global [`level`](Self::level).
+    ///
+    /// This is a typed alternative to the [`filter`](Self::filter) string, see
+    /// [`LogPlugin::with_module`]. The most specific module matching a log applies.
+    ///
+    /// # Panics
+    ///
+    /// The plugin panics when it is built if a module isn't a valid module path.
+    pub modules: Vec<(String, Level)>,
+
+    /// Filters the console output with its own directives, in the [`EnvFilter`] format, instead
+    /// of the [`level`](Self::level), the [`modules`](Self::modules) and the
+    /// [`filter`](Self::filter), which are the default filter of the outputs.
+    ///
+    /// For example, the console can stay at `info` while the [`file_filter`](Self::file_filter)
+    /// keeps everything at `trace`. Each output with its own filter is filtered on its own, which
+    /// costs a little more per log than the single default filter. The logs that no output keeps
+    /// are still skipped before reaching any of them.
+    ///
+    /// This filter is used instead of `RUST_LOG`, and isn't changed by the [`LogFilter`]. An
+    /// invalid filter is reported with an error and replaced by the default filter.
+    pub console_filter: Option<String>,
+
+    /// Filters the [`file`](Self::file) with its own directives, in the [`EnvFilter`] format,
+    /// instead of the default filter, see [`console_filter`](Self::console_filter).
+    ///
+    /// The [`level`](FileLogConfig::level) of the file still applies.
+    pub file_filter: Option<String>,
+
+    /// Filters the [`capture`](Self::capture) with its own directives, in the [`EnvFilter`]
+    /// format, instead of the default filter, see [`console_filter`](Self::console_filter).
+    pub capture_filter: Option<String>,
+
+    /// Suppresses the logs written within the spans of some names, or keeps only their most
+    /// severe levels, see [`SpanRule`].
+    ///
+    /// The rules can be changed while the app is running through the [`LogFilter`].
+    pub span_rules: Vec<SpanRule>,
+
     /// Optionally add an extra [`Layer`] to the tracing subscriber
     ///
-    /// This function is only called once, when the plugin is built.
+    /// This function is only called once, when the plugin is built. Its layer is added before
+    /// the [`custom_layers`](Self::custom_layers).
+    #[deprecated(since = "0.17.0", note = "Use `custom_layers` instead")]
+    pub custom_layer: fn(app: &mut App) -> Option<BoxedLayer>,
+
+    /// Add extra [`Layer`]s to the tracing subscriber, in the order they are returned.
     ///
-    /// Because [`BoxedLayer`] takes a `dyn Layer`, `Vec<Layer>` is also an acceptable return value.
+    /// This function is only called once, when the plugin is built.
     ///
     /// Access to [`App`] is also provided to allow for communication between the
-    /// [`Subscriber`](tracing::Subscriber) and the [`App`].
+    /// [`Subscriber`](tracing::Subscriber) and the [`App`], for example to insert the resources
+    /// and systems each layer sends its data to. Independent layers can each have their own
+    /// function, called from this one:
     ///
-    /// Please see the `examples/log_layers.rs` for a complete example.
-    pub custom_layer: fn(app: &mut App) -> Option<BoxedLayer>,
+    /// ```no_run
+    /// # use bevy_app::{App, NoopPluginGroup as DefaultPlugins, PluginGroup};
+    /// # use bevy_log::{BoxedLayer, LogPlugin};
+    /// fn capture_layer(app: &mut App) -> BoxedLayer {
+    ///     // ...
+    /// #   unimplemented!()
+    /// }
+    ///
+    /// fn file_layer(app: &mut App) -> BoxedLayer {
+    ///     // ...
+    /// #   unimplemented!()
+    /// }
+    ///
+    /// App::new().add_plugins(DefaultPlugins.set(LogPlugin {
+    ///     custom_layers: |app| vec![capture_layer(app), file_layer(app)],
+    ///     ..Default::default()
+    /// }));
+    /// ```
+    ///
+    /// Please see the `examples/log_layers.rs` and `examples/log_layers_ecs.rs` for complete
+    /// examples.
+    pub custom_layers: fn(app: &mut App) -> Vec<BoxedLayer>,
+
+    /// Add extra [`Layer`]s to the tracing subscriber, each with its own filter in the
+    /// [`EnvFilter`] format instead of the [`filter`](Self::filter).
+    ///
+    /// A layer can then receive more verbose logs than the other outputs, for example to show
+    /// the `trace` logs in an in-game console while the terminal stays at `info`. The logs are
+    /// still skipped before reaching any layer when no output keeps them.
+    ///
+    /// Like the [`console_filter`](Self::console_filter), the filters are used instead of
+    /// `RUST_LOG`, and aren't changed by the [`LogFilter`]. An invalid filter is reported with an
+    /// error and replaced by the default filter.
+    ///
+    /// ```no_run
+    /// # use bevy_app::{App, NoopPluginGroup as DefaultPlugins, PluginGroup};
+    /// # use bevy_log::{BoxedLayer, LogPlugin};
+    /// fn console_layer(app: &mut App) -> BoxedLayer {
+    ///     // ...
+    /// #   unimplemented!()
+    /// }
+    ///
+    /// App::new().add_plugins(DefaultPlugins.set(LogPlugin {
+    ///     filtered_layers: |app| vec![(console_layer(app), "info,mygame=trace".to_string())],
+    ///     ..Default::default()
+    /// }));
+    /// ```
+    ///
+    /// This function is only called once, when the plugin is built, before the
+    /// [`custom_layers`](Self::custom_layers). Its layers are added after them.
+    pub filtered_layers: fn(app: &mut App) -> Vec<(BoxedLayer, String)>,
 
     /// Override the default [`tracing_subscriber::fmt::Layer`] with a custom one.
     ///
-    /// This differs from [`custom_layer`](Self::custom_layer) in that
+    /// This differs from [`custom_layers`](Self::custom_layers) in that
     /// [`fmt_layer`](Self::fmt_layer) allows you to overwrite the default formatter layer, while
-    /// `custom_layer` only allows you to add additional layers (which are unable to modify the
+    /// `custom_layers` only allows you to add additional layers (which are unable to modify the
     /// default formatter).
     ///
     /// For example, you can use [`tracing_subscriber::fmt::Layer::without_time`] to remove the
@@ -255,41 +414,217 @@ pub struct LogPlugin {
     ///
     /// Please see the `examples/log_layers.rs` for a complete example.
     pub fmt_layer: fn(app: &mut App) -> Option<BoxedFmtLayer>,
+
+    /// Formats the logs written to the console with a custom [`FormatEvent`], such as a compact
+    /// one-line format or a custom field order, instead of the [`format`](Self::format).
+    ///
+    /// The [`filter`](Self::filter) and the [`timestamps`](Self::timestamps) still apply: the
+    /// timestamp is written before the output of the formatter. The formatter can check
+    /// [`Writer::has_ansi_escapes`](tracing_subscriber::fmt::format::Writer::has_ansi_escapes) to
+    /// only color its output according to the [`ansi`](Self::ansi) mode.
+    ///
+    /// This only changes the default console output, it isn't used by the
+    /// [`fmt_layer`](Self::fmt_layer) replacing it, nor by the [`file`](Self::file) and the
+    /// [`custom_layers`](Self::custom_layers).
+    pub custom_format: fn(app: &mut App) -> Option<BoxedFmtFormatter>,
+
+    /// Writes the logs of the console output somewhere else than the standard streams, for
+    /// example to an in-memory buffer in tests.
+    ///
+    /// The [`format`](Self::format), or the [`custom_format`](Self::custom_format), still
+    /// applies. With [`AnsiMode::Auto`], the output isn't colored since it isn't a terminal.
+    ///
+    /// Like the [`custom_format`](Self::custom_format), this isn't used by the
+    /// [`fmt_layer`](Self::fmt_layer) replacing the console output.
+    pub custom_writer: fn(app: &mut App) -> Option<BoxedMakeWriter>,
+
+    /// Whether the console output is written to `stderr`, to `stdout`, or not at all, see
+    /// [`ConsoleOutput`].
+    ///
+    /// With [`AnsiMode::Auto`], the output is colored if the selected stream is a terminal.
+    pub console_output: ConsoleOutput,
+
+    /// Also writes the logs to a file, see [`FileLogConfig`].
+    ///
+    /// This is ignored on the web, with a warning.
+    pub file: Option<FileLogConfig>,
+
+    /// The format of the logs written to the console and to the [`file`](Self::file), as text
+    /// or as JSON objects, see [`LogFormat`].
+    pub format: LogFormat,
+
+    /// Whether the text logs written to the console are colored, see [`AnsiMode`].
+    ///
+    /// This isn't used by the [`fmt_layer`](Self::fmt_layer) replacing the console output.
+    pub ansi: AnsiMode,
+
+    /// The colors and prefixes of the levels of the text logs written to the console, see
+    /// [`LevelStyle`].
+    ///
+    /// The colors are only used if the output is colored according to the [`ansi`](Self::ansi)
+    /// mode. The [`file`](Self::file) always uses the names of the levels, without colors.
+    pub level_style: LevelStyle,
+
+    /// The timestamps of the logs written to the console and to the [`file`](Self::file), see
+    /// [`TimestampMode`].
+    pub timestamps: TimestampMode,
+
+    /// Also writes the logs as [`LogMessage`] events, for example to display them in the UI.
+    ///
+    /// This works alongside the [`custom_layers`](Self::custom_layers).
+    pub capture: Option<LogCaptureConfig>,
+
+    /// Keeps the most recent logs in the [`LogHistory`] resource, for example for a log window in
+    /// dev tools.
+    ///
+    /// This works alongside the [`capture`](Self::capture) and the
+    /// [`custom_layers`](Self::custom_layers).
+    pub history: Option<LogHistoryConfig>,
+
+    /// Suppresses the logs repeated too often, such as a warning written every frame, and logs
+    /// how many times they were repeated instead. See [`LogRateLimit`].
+    ///
+    /// The suppressed logs are not passed to any layer, including the
+    /// [`custom_layers`](Self::custom_layers).
+    pub rate_limit: Option<LogRateLimit>,
+
+    /// Exports the logs and spans to an OpenTelemetry collector, for example to monitor a
+    /// dedicated server. See [`OtlpConfig`].
+    ///
+    /// This works alongside the [`custom_layers`](Self::custom_layers). Requires the `otlp`
+    /// feature.
+    #[cfg(feature = "otlp")]
+    pub otlp: Option<OtlpConfig>,
+
+    /// Logs the panics as errors, with their location and a backtrace if it's enabled by the
+    /// `RUST_BACKTRACE` environment variable, so that they reach the [`file`](Self::file), the
+    /// [`capture`](Self::capture) and the [`custom_layers`](Self::custom_layers).
+    ///
+    /// The panic hook installed when the plugin is built writes the log file before calling the
+    /// previous panic hook.
+    pub log_panics: bool,
+
+    /// Groups the logs written in spans with `console.group` in the browser console, with a
+    /// nested group per span, so that they can be collapsed in the devtools.
+    ///
+    /// This is only used on the web.
+    pub group_spans: bool,
+
+    /// The logcat tag of all the logs on Android, for example to filter the logs of the app with
+    /// `adb logcat -s my_game`.
+    ///
+    /// By default, each log is tagged with the crate that wrote it, the first segment of its
+    /// target, such as `bevy_render` or `wgpu_core`. This is only used on Android.
+    pub android_tag: Option<String>,
+
+    /// Records the spans the errors and warnings are written in, with their fields, in the
+    /// [`LogMessage::span_trace`] of the [`capture`](Self::capture), so that an in-game console
+    /// shows that an error was written in the `load_level{name="forest"}` span, in a system, in
+    /// the `Update` schedule.
+    ///
+    /// With the `span_traces` feature, this also adds the
+    /// [`ErrorLayer`](https://docs.rs/tracing-error/latest/tracing_error/struct.ErrorLayer.html)
+    /// of `tracing-error`, so that the `SpanTrace`s captured anywhere in the app, such as in the
+    /// errors of the systems, contain the fields of their spans. The layer is always added with
+    /// the `trace` feature.
+    ///
+    /// The console output and the [`file`](Self::file) already show the spans of each log. The
+    /// cost of rendering the span trace is only paid for the errors and warnings.
+    pub span_traces: bool,
 }
 
-/// A boxed [`Layer`] that can be used with [`LogPlugin::custom_layer`].
+/// A boxed [`Layer`] that can be used with [`LogPlugin::custom_layers`].
 pub type BoxedLayer = Box<dyn Layer<Registry> + Send + Sync + 'static>;
 
-#[cfg(feature = "trace")]
-type BaseSubscriber =
-    Layered<EnvFilter, Layered<Option<Box<dyn Layer<Registry> + Send + Sync>>, Registry>>;
+/// The subscriber the [`LogFilter`] is layered on.
+type FilteredSubscriber = Layered<Option<Vec<BoxedLayer>>, Registry>;
 
-#[cfg(feature = "trace")]
-type PreFmtSubscriber = Layered<tracing_error::ErrorLayer<BaseSubscriber>, BaseSubscriber>;
+#[cfg(feature = "tracing-error")]
+type BaseSubscriber = Layered<reload::Layer<EnvFilter, FilteredSubscriber>, FilteredSubscriber>;
 
-#[cfg(not(feature = "trace"))]
-type PreFmtSubscriber =
-    Layered<EnvFilter, Layered<Option<Box<dyn Layer<Registry> + Send + Sync>>, Registry>>;
+#[cfg(feature = "tracing-error")]
+type PreFmtSubscriber = Layered<Option<tracing_error::ErrorLayer<BaseSubscriber>>, BaseSubscriber>;
+
+#[cfg(not(feature = "tracing-error"))]
+type PreFmtSubscriber = Layered<reload::Layer<EnvFilter, FilteredSubscriber>, FilteredSubscriber>;
 
 /// A boxed [`Layer`] that can be used with [`LogPlugin::fmt_layer`].
 pub type BoxedFmtLayer = Box<dyn Layer<PreFmtSubscriber> + Send + Sync + 'static>;
 
+/// A boxed [`FormatEvent`] that can be used with [`LogPlugin::custom_format`].
+pub type BoxedFmtFormatter = Box<dyn FormatEvent<PreFmtSubscriber, DefaultFields> + Send + Sync>;
+
+/// A boxed [`MakeWriter`](tracing_subscriber::fmt::MakeWriter) that can be used with
+/// [`LogPlugin::custom_writer`].
+pub type BoxedMakeWriter = BoxMakeWriter;
+
 /// The default [`LogPlugin`] [`EnvFilter`].
 pub const DEFAULT_FILTER: &str = "wgpu=error,naga=warn";
 
 impl Default for LogPlugin {
+    #[expect(deprecated, reason = "`custom_layer` still needs a default")]
     fn default() -> Self {
         Self {
             filter: DEFAULT_FILTER.to_string(),
             level: Level::INFO,
+            modules: Vec::new(),
+            console_filter: None,
+            file_filter: None,
+            capture_filter: None,
+            span_rules: Vec::new(),
             custom_layer: |_| None,
+            custom_layers: |_| Vec::new(),
+            filtered_layers: |_| Vec::new(),
             fmt_layer: |_| None,
+            custom_format: |_| None,
+            custom_writer: |_| None,
+            console_output: ConsoleOutput::default(),
+            file: None,
+            format: LogFormat::default(),
+            ansi: AnsiMode::default(),
+            level_style: LevelStyle::default(),
+            timestamps: TimestampMode::default(),
+            capture: None,
+            history: None,
+            rate_limit: None,
+            #[cfg(feature = "otlp")]
+            otlp: None,
+            log_panics: false,
+            group_spans: false,
+            android_tag: None,
+            span_traces: false,
         }
     }
 }
 
+impl LogPlugin {
+    /// Sets the [`level`](Self::level) of the logs that aren't filtered by a more specific
+    /// directive.
+    pub fn with_level(mut self, level: Level) -> Self {
+        self.level = level;
+        self
+    }
+
+    /// Filters out the logs of `module`, such as `wgpu` or `mygame::net`, and of its submodules
+    /// that are "less than" `level`. See [`modules`](Self::modules).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `module` isn't a valid module path: identifiers separated by `::`.
+    #[track_caller]
+    pub fn with_module(mut self, module: impl Into<String>, level: Level) -> Self {
+        let module = module.into();
+        filter::assert_module_path(&module);
+        self.modules.push((module, level));
+        self
+    }
+}
+
 impl Plugin for LogPlugin {
-    #[expect(clippy::print_stderr, reason = "Allowed during logger setup")]
+    #[cfg_attr(
+        feature = "trace",
+        expect(clippy::print_stderr, reason = "Allowed during logger setup")
+    )]
     fn build(&self, app: &mut App) {
         #[cfg(feature = "trace")]
         {
@@ -303,27 +638,97 @@ impl Plugin for LogPlugin {
         let finished_subscriber;
         let subscriber = Registry::default();
 
-        // add optional layer provided by user
-        let subscriber = subscriber.with((self.custom_layer)(app));
-
-        let default_filter = { format!("{},{}", self.level, self.filter) };
-        let filter_layer = EnvFilter::try_from_default_env()
-            .or_else(|from_env_error| {
-                _ = from_env_error
-                    .source()
-                    .and_then(|source| source.downcast_ref::<ParseError>())
-                    .map(|parse_err| {
-                        // we cannot use the `error!` macro here because the logger is not ready yet.
-                        eprintln!("LogPlugin failed to parse filter from env: {parse_err}");
-                    });
-
-                Ok::<EnvFilter, FromEnvError>(EnvFilter::builder().parse_lossy(&default_filter))
-            })
-            .unwrap();
+        let (filter_layer, mut filter_errors) = filter::initial_filter(
+            std::env::var(EnvFilter::DEFAULT_ENV).ok().as_deref(),
+            self.level,
+            &self.modules,
+            &self.filter,
+        );
+        let initial_filter = filter_layer.to_string();
+        let (filtered_layers, layer_filters): (Vec<_>, Vec<_>) =
+            (self.filtered_layers)(app).into_iter().unzip();
+        // When some outputs have their own filter, each output is filtered on its own.
+        let mut output_filters = OutputFilters::new(
+            self.console_filter.as_deref(),
+            self.file_filter.as_deref(),
+            self.capture_filter.as_deref(),
+            &layer_filters.iter().map(String::as_str).collect::<Vec<_>>(),
+            &initial_filter,
+            &mut filter_errors,
+        );
+
+        // add optional layers provided by user
+        #[expect(deprecated, reason = "`custom_layer` is still supported")]
+        let mut custom_layers = Vec::from_iter((self.custom_layer)(app));
+        custom_layers.extend((self.custom_layers)(app));
+        let rate_limit_layer = self
+            .rate_limit
+            .clone()
+            .map(|config| rate_limit::rate_limit_layer(config, app));
+        let capture_layer = self
+            .capture
+            .map(|config| capture::capture_layer(config, self.span_traces, app));
+        if let Some(config) = self.history {
+            custom_layers.push(history::history_layer(config, app));
+        }
+        #[cfg(feature = "otlp")]
+        if let Some(config) = &self.otlp {
+            custom_layers.extend(otlp::otlp_layers(config, app));
+        }
+        // The tracing backends can be started and stopped through the `TracingCapture`.
+        #[cfg(all(
+            any(feature = "tracing-chrome", feature = "tracing-tracy"),
+            not(target_arch = "wasm32"),
+            not(target_os = "android"),
+            not(target_os = "ios")
+        ))]
+        custom_layers.extend(tracing_capture::capture_layers(app));
+        // The span rules and the rate limit apply to all the outputs, whatever their filter. The
+        // logs suppressed by a span rule don't count towards the rate limit.
+        let span_rules = SharedSpanRules::new(self.span_rules.clone());
+        let mut layers = vec![span_filter::span_filter_layer(span_rules.clone())];
+        layers.extend(rate_limit_layer);
+        layers.extend(capture_layer.map(|layer| {
+            filter::with_output_filter(layer, output_filters.as_mut().map(OutputFilters::capture))
+        }));
+        if !custom_layers.is_empty() {
+            layers.push(filter::with_output_filter(
+                custom_layers,
+                output_filters.as_mut().map(OutputFilters::default),
+            ));
+        }
+        for (index, layer) in filtered_layers.into_iter().enumerate() {
+            layers.push(filter::with_output_filter(
+                layer,
+                output_filters.as_mut().map(|filters| filters.layer(index)),
+            ));
+        }
+        // An empty `Vec` of layers isn't interested in any callsite, which would disable all the
+        // logs, while a `None` layer is transparent.
+        let subscriber = subscriber.with((!layers.is_empty()).then_some(layers));
+
+        // The filter is reloadable so that it can be changed at runtime through `LogFilter`.
+        let (filter_layer, filter_handle) = reload::Layer::new(match &output_filters {
+            Some(filters) => filters.global_filter(),
+            None => filter_layer,
+        });
         let subscriber = subscriber.with(filter_layer);
 
-        #[cfg(feature = "trace")]
-        let subscriber = subscriber.with(tracing_error::ErrorLayer::default());
+        #[cfg(feature = "tracing-error")]
+        let subscriber = subscriber.with(
+            (cfg!(feature = "trace") || self.span_traces).then(tracing_error::ErrorLayer::default),
+        );
+
+        #[cfg(not(target_arch = "wasm32"))]
+        let timer = self.timestamps.timer();
+        #[cfg(not(target_arch = "wasm32"))]
+        let file_layer = self
+            .file
+            .as_ref()
+            .and_then(|config| file_writer::file_layer(config, self.format, timer.clone(), app))
+            .map(|layer| {
+                filter::with_output_filter(layer, output_filters.as_mut().map(OutputFilters::file))
+            });
 
         #[cfg(all(
             not(target_arch = "wasm32"),
@@ -331,38 +736,32 @@ impl Plugin for LogPlugin {
             not(target_os = "ios")
         ))]
         {
-            #[cfg(feature = "tracing-chrome")]
-            let chrome_layer = {
-                let mut layer = tracing_chrome::ChromeLayerBuilder::new();
-                if let Ok(path) = std::env::var("TRACE_CHROME") {
-                    layer = layer.file(path);
+            let fmt_layer = (self.fmt_layer)(app).or_else(|| {
+                if self.console_output == ConsoleOutput::None {
+                    return None;
                 }
-                let (chrome_layer, guard) = layer
-                    .name_fn(Box::new(|event_or_span| match event_or_span {
-                        tracing_chrome::EventOrSpan::Event(event) => event.metadata().name().into(),
-                        tracing_chrome::EventOrSpan::Span(span) => {
-                            if let Some(fields) =
-                                span.extensions().get::<FormattedFields<DefaultFields>>()
-                            {
-                                format!("{}: {}", span.metadata().name(), fields.fields.as_str())
-                            } else {
-                                span.metadata().name().into()
-                            }
-                        }
-                    }))
-                    .build();
-                app.insert_resource(FlushGuard(SyncCell::new(guard)));
-                chrome_layer
-            };
-
-            #[cfg(feature = "tracing-tracy")]
-            let tracy_layer = tracing_tracy::TracyLayer::default();
-
-            let fmt_layer = (self.fmt_layer)(app).unwrap_or_else(|| {
-                // note: the implementation of `Default` reads from the env var NO_COLOR
-                // to decide whether to use ANSI color codes, which is common convention
-                // https://no-color.org/
-                Box::new(tracing_subscriber::fmt::Layer::default().with_writer(std::io::stderr))
+                let writer = (self.custom_writer)(app);
+                let ansi = self
+                    .ansi
+                    .enabled(writer.is_none() && self.console_output.is_terminal());
+                let writer = writer.or_else(|| self.console_output.writer())?;
+                Some(match (self.custom_format)(app) {
+                    Some(formatter) => format::custom_format_layer(formatter, ansi, timer, writer),
+                    None => format::format_layer(
+                        self.format,
+                        ansi,
+                        self.level_style.clone(),
+                        timer,
+                        DefaultFields::default(),
+                        writer,
+                    ),
+                })
+            });
+            let fmt_layer = fmt_layer.map(|layer| {
+                filter::with_output_filter(
+                    layer,
+                    output_filters.as_mut().map(OutputFilters::console),
+                )
             });
 
             // bevy_render::renderer logs a `tracy.frame_mark` event every frame
@@ -373,35 +772,49 @@ impl Plugin for LogPlugin {
                     meta.fields().field("tracy.frame_mark").is_none()
                 }));
 
-            let subscriber = subscriber.with(fmt_layer);
-
-            #[cfg(feature = "tracing-chrome")]
-            let subscriber = subscriber.with(chrome_layer);
-            #[cfg(feature = "tracing-tracy")]
-            let subscriber = subscriber.with(tracy_layer);
-            finished_subscriber = subscriber;
+            finished_subscriber = subscriber.with(fmt_layer).with(file_layer);
         }
 
         #[cfg(target_arch = "wasm32")]
         {
-            finished_subscriber = subscriber.with(tracing_wasm::WASMLayer::new(
-                tracing_wasm::WASMLayerConfig::default(),
-            ));
+            let timings_layer = tracing_wasm::WASMLayer::new(
+                tracing_wasm::WASMLayerConfigBuilder::new()
+                    .set_console_config(tracing_wasm::ConsoleConfig::NoReporting)
+                    .build(),
+            );
+            finished_subscriber = subscriber
+                .with(timings_layer)
+                .with(filter::with_output_filter(
+                    web_console::WebConsoleLayer::new(self.group_spans),
+                    output_filters.as_mut().map(OutputFilters::console),
+                ));
         }
 
         #[cfg(target_os = "android")]
         {
-            finished_subscriber = subscriber.with(android_tracing::AndroidLayer::default());
+            finished_subscriber = subscriber.with(file_layer).with(filter::with_output_filter(
+                android_tracing::AndroidLayer::new(self.android_tag.as_deref()),
+                output_filters.as_mut().map(OutputFilters::console),
+            ));
         }
 
         #[cfg(target_os = "ios")]
         {
-            finished_subscriber = subscriber.with(tracing_oslog::OsLogger::default());
+            finished_subscriber = subscriber.with(file_layer).with(filter::with_output_filter(
+                tracing_oslog::OsLogger::default(),
+                output_filters.as_mut().map(OutputFilters::console),
+            ));
         }
 
         let logger_already_set = LogTracer::init().is_err();
+        #[cfg(not(feature = "early_logs"))]
         let subscriber_already_set =
             tracing::subscriber::set_global_default(finished_subscriber).is_err();
+        // The global subscriber buffering the early logs is already set, so they are replayed and
+        // forwarded to this one instead.
+        #[cfg(feature = "early_logs")]
+        let subscriber_already_set = bevy_app::forward_early_logs(finished_subscriber)
+            .is_err_and(|subscriber| tracing::subscriber::set_global_default(subscriber).is_err());
 
         match (logger_already_set, subscriber_already_set) {
             (true, true) => error!(
@@ -411,5 +824,96 @@ impl Plugin for LogPlugin {
             (false, true) => error!("Could not set global tracing subscriber as it is already set. Consider disabling LogPlugin."),
             (false, false) => (),
         }
+
+        // The invalid filters couldn't be logged before the subscriber was set.
+        for filter_error in filter_errors {
+            error!("{filter_error}");
+        }
+
+        #[cfg(target_arch = "wasm32")]
+        if self.file.is_some() {
+            warn!("File logging is not supported on the web, `LogPlugin::file` is ignored.");
+        }
+
+        if self.log_panics {
+            #[cfg(not(target_arch = "wasm32"))]
+            let flush_file = app
+                .world()
+                .get_resource::<file_writer::FileLogGuard>()
+                .map(file_writer::FileLogGuard::flusher);
+            panic_hook::install_panic_hook(move || {
+                #[cfg(not(target_arch = "wasm32"))]
+                if let Some(flush_file) = &flush_file {
+                    flush_file();
+                }
+            });
+        }
+
+        if !subscriber_already_set {
+            let reload: Box<dyn Fn(&str) -> Result<(), reload::Error> + Send + Sync> =
+                match output_filters {
+                    Some(filters) => Box::new(filters.into_reload(filter_handle)),
+                    None => Box::new(move |filter| filter_handle.reload(EnvFilter::new(filter))),
+                };
+            app.insert_resource(LogFilter::new(
+                &initial_filter,
+                reload,
+                self.span_rules.clone(),
+                span_rules,
+            ))
+            .add_systems(PostUpdate, filter::apply_log_filter);
+        }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn on_exit(&self, app: &mut App) {
+        if let Some(guard) = app.world().get_resource::<file_writer::FileLogGuard>() {
+            guard.flush();
+        }
+        #[cfg(feature = "otlp")]
+        if let Some(guard) = app.world().get_resource::<otlp::OtlpGuard>() {
+            guard.shutdown();
+        }
+    }
+
+    #[cfg(feature = "plugin_config")]
+    fn as_configurable(&mut self) -> Option<&mut dyn bevy_app::ConfigurablePlugin> {
+        Some(self)
+    }
+}
+
+/// Accepts the `filter` and `level` settings, for example `(filter: "wgpu=error", level: "debug")`.
+#[cfg(feature = "plugin_config")]
+impl bevy_app::ConfigurablePlugin for LogPlugin {
+    fn apply_config(&mut self, value: &bevy_app::ron::Value) -> Result<(), bevy_app::ron::Error> {
+        use bevy_app::ron::{Error, Value};
+
+        // `ron::Value` doesn't keep track of optional fields, so the settings are looked up one
+        // by one to leave the missing ones unchanged.
+        let Value::Map(settings) = value else {
+            return Err(Error::ExpectedMap);
+        };
+        // Both settings are parsed before either is applied, so an invalid config changes nothing.
+        let level = settings
+            .get(&Value::String("level".into()))
+            .map(|level| {
+                level
+                    .clone()
+                    .into_rust::<String>()?
+                    .parse()
+                    .map_err(|error| Error::Message(format!("{error}")))
+            })
+            .transpose()?;
+        let filter = settings
+            .get(&Value::String("filter".into()))
+            .map(|filter| filter.clone().into_rust())
+            .transpose()?;
+        if let Some(level) = level {
+            self.level = level;
+        }
+        if let Some(filter) = filter {
+            self.filter = filter;
+        }
+        Ok(())
     }
 }
@@ -0,0 +1,419 @@
+use crate::{BoxedLayer, IntervalFlag, Level};
+use bevy_app::App;
+use bevy_ecs::resource::Resource;
+use core::{fmt, time::Duration};
+use opentelemetry::trace::TracerProvider;
+use opentelemetry_appender_tracing::layer::OpenTelemetryTracingBridge;
+use opentelemetry_otlp::{LogExporter, SpanExporter, WithExportConfig};
+use opentelemetry_sdk::{
+    error::OTelSdkResult,
+    logs::{LogBatch, SdkLoggerProvider},
+    trace::{SdkTracerProvider, SpanData},
+    Resource as OtelResource,
+};
+use tracing::warn;
+use tracing_subscriber::{filter::FilterFn, Layer};
+
+/// Settings of the export of the logs and spans to an
+/// [OpenTelemetry](https://opentelemetry.io) collector, such as Jaeger, Grafana Alloy or the
+/// OpenTelemetry Collector, with the OTLP/HTTP protocol.
+///
+/// Only the logs and spans that pass the [`LogPlugin::filter`](crate::LogPlugin::filter) are
+/// exported. They are sent in batches on background threads, so that the frames never wait for
+/// the collector. If it can't be reached, a warning is logged at most every 10 seconds and the
+/// batches are dropped. The pending batches are sent when the app exits.
+///
+/// ```no_run
+/// # use bevy_app::{App, NoopPluginGroup as DefaultPlugins, PluginGroup};
+/// # use bevy_log::{LogPlugin, OtlpConfig};
+/// App::new()
+///     .add_plugins(DefaultPlugins.set(LogPlugin {
+///         otlp: Some(OtlpConfig {
+///             endpoint: "http://collector:4318".to_string(),
+///             service_name: "game_server".to_string(),
+///             ..Default::default()
+///         }),
+///         ..Default::default()
+///     }))
+///     .run();
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OtlpConfig {
+    /// The base URL of the OTLP/HTTP receiver of the collector. The spans are sent to its
+    /// `/v1/traces` path and the logs to its `/v1/logs` path.
+    pub endpoint: String,
+    /// The `service.name` of the exported logs and spans, which tells the apps apart in the
+    /// collector. Defaults to the name of the executable.
+    pub service_name: String,
+    /// Whether the logs are exported.
+    pub export_logs: bool,
+    /// Whether the spans are exported, with the logs written in them as span events.
+    pub export_spans: bool,
+}
+
+impl Default for OtlpConfig {
+    fn default() -> Self {
+        let service_name = std::env::current_exe()
+            .ok()
+            .and_then(|path| Some(path.file_stem()?.to_string_lossy().into_owned()))
+            .unwrap_or_else(|| "bevy".to_string());
+        Self {
+            endpoint: "http://localhost:4318".to_string(),
+            service_name,
+            export_logs: true,
+            export_spans: true,
+        }
+    }
+}
+
+/// The providers batching the exported logs and spans, shut down when the app exits.
+#[derive(Resource)]
+pub(crate) struct OtlpGuard {
+    tracer_provider: Option<SdkTracerProvider>,
+    logger_provider: Option<SdkLoggerProvider>,
+}
+
+impl OtlpGuard {
+    /// Waits until the logs and spans recorded so far are exported, or failed to be.
+    pub(crate) fn flush(&self) {
+        // The export errors are already logged by the `WarnOnError` exporters.
+        if let Some(provider) = &self.tracer_provider {
+            let _ = provider.force_flush();
+        }
+        if let Some(provider) = &self.logger_provider {
+            let _ = provider.force_flush();
+        }
+    }
+
+    /// Exports the pending logs and spans, and stops the background threads.
+    pub(crate) fn shutdown(&self) {
+        if let Some(provider) = &self.tracer_provider {
+            let _ = provider.shutdown();
+        }
+        if let Some(provider) = &self.logger_provider {
+            let _ = provider.shutdown();
+        }
+    }
+}
+
+/// Creates the layers exporting the logs and spans, and adds the [`OtlpGuard`] shutting them
+/// down to `app`.
+///
+/// A layer is skipped if its exporter can't be created.
+#[expect(clippy::print_stderr, reason = "Allowed during logger setup")]
+pub(crate) fn otlp_layers(config: &OtlpConfig, app: &mut App) -> Vec<BoxedLayer> {
+    let mut layers: Vec<BoxedLayer> = Vec::new();
+    let endpoint = config.endpoint.trim_end_matches('/');
+    let resource = OtelResource::builder()
+        .with_service_name(config.service_name.clone())
+        .build();
+
+    let tracer_provider = config
+        .export_spans
+        .then(|| {
+            SpanExporter::builder()
+                .with_http()
+                .with_endpoint(format!("{endpoint}/v1/traces"))
+                .build()
+        })
+        .and_then(|exporter| match exporter {
+            Ok(exporter) => Some(exporter),
+            Err(err) => {
+                // we cannot use the `error!` macro here because the logger is not ready yet.
+                eprintln!("LogPlugin failed to create the OTLP span exporter: {err}");
+                None
+            }
+        })
+        .map(|exporter| {
+            SdkTracerProvider::builder()
+                .with_batch_exporter(WarnOnError::new(exporter, "spans", endpoint))
+                .with_resource(resource.clone())
+                .build()
+        });
+    if let Some(provider) = &tracer_provider {
+        let layer = tracing_opentelemetry::layer().with_tracer(provider.tracer("bevy_log"));
+        layers.push(Box::new(layer.with_filter(FilterFn::new(|metadata| {
+            !is_exporter_target(metadata.target())
+        }))));
+    }
+
+    let logger_provider = config
+        .export_logs
+        .then(|| {
+            LogExporter::builder()
+                .with_http()
+                .with_endpoint(format!("{endpoint}/v1/logs"))
+                .build()
+        })
+        .and_then(|exporter| match exporter {
+            Ok(exporter) => Some(exporter),
+            Err(err) => {
+                eprintln!("LogPlugin failed to create the OTLP log exporter: {err}");
+                None
+            }
+        })
+        .map(|exporter| {
+            SdkLoggerProvider::builder()
+                .with_batch_exporter(WarnOnError::new(exporter, "logs", endpoint))
+                .with_resource(resource)
+                .build()
+        });
+    if let Some(provider) = &logger_provider {
+        let layer = OpenTelemetryTracingBridge::new(provider);
+        layers.push(Box::new(layer.with_filter(FilterFn::new(|metadata| {
+            !is_exporter_target(metadata.target())
+        }))));
+    }
+
+    app.insert_resource(OtlpGuard {
+        tracer_provider,
+        logger_provider,
+    });
+    layers
+}
+
+/// Returns `true` for the logs of the exporters themselves, which aren't exported so that a
+/// failing export doesn't export more logs about itself.
+fn is_exporter_target(target: &str) -> bool {
+    const TARGETS: [&str; 7] = [
+        "h2",
+        "hyper",
+        "hyper_util",
+        "opentelemetry",
+        "opentelemetry_otlp",
+        "opentelemetry_sdk",
+        "reqwest",
+    ];
+    // The warnings of the `WarnOnError` exporters.
+    target == module_path!()
+        || TARGETS.iter().any(|prefix| {
+            target
+                .strip_prefix(prefix)
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with("::"))
+        })
+}
+
+/// How often an exporter warns that it can't reach the collector.
+const WARN_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Wraps an exporter to log its errors as rate-limited warnings, instead of the internal logs of
+/// the OpenTelemetry SDK.
+struct WarnOnError<E> {
+    exporter: E,
+    signal: &'static str,
+    endpoint: String,
+    flag: IntervalFlag,
+}
+
+impl<E> WarnOnError<E> {
+    fn new(exporter: E, signal: &'static str, endpoint: &str) -> Self {
+        Self {
+            exporter,
+            signal,
+            endpoint: endpoint.to_string(),
+            flag: IntervalFlag::new(),
+        }
+    }
+
+    fn warn(&self, result: &OTelSdkResult) {
+        let Err(err) = result else {
+            return;
+        };
+        if let Some(suppressed) = self.flag.fire(Level::WARN, WARN_INTERVAL) {
+            let (signal, endpoint) = (self.signal, &self.endpoint);
+            if suppressed == 0 {
+                warn!("Could not export the {signal} to the OTLP collector at {endpoint}: {err}");
+            } else {
+                warn!(
+                    "Could not export the {signal} to the OTLP collector at {endpoint}: {err} ({suppressed} more failures)"
+                );
+            }
+        }
+    }
+}
+
+impl<E: fmt::Debug> fmt::Debug for WarnOnError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WarnOnError")
+            .field("exporter", &self.exporter)
+            .field("signal", &self.signal)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<E: opentelemetry_sdk::trace::SpanExporter> opentelemetry_sdk::trace::SpanExporter
+    for WarnOnError<E>
+{
+    async fn export(&self, batch: Vec<SpanData>) -> OTelSdkResult {
+        let result = self.exporter.export(batch).await;
+        self.warn(&result);
+        result
+    }
+
+    fn shutdown_with_timeout(&mut self, timeout: Duration) -> OTelSdkResult {
+        self.exporter.shutdown_with_timeout(timeout)
+    }
+
+    fn force_flush(&mut self) -> OTelSdkResult {
+        self.exporter.force_flush()
+    }
+
+    fn set_resource(&mut self, resource: &OtelResource) {
+        self.exporter.set_resource(resource);
+    }
+}
+
+impl<E: opentelemetry_sdk::logs::LogExporter> opentelemetry_sdk::logs::LogExporter
+    for WarnOnError<E>
+{
+    async fn export(&self, batch: LogBatch<'_>) -> OTelSdkResult {
+        let result = self.exporter.export(batch).await;
+        self.warn(&result);
+        result
+    }
+
+    fn shutdown_with_timeout(&self, timeout: Duration) -> OTelSdkResult {
+        self.exporter.shutdown_with_timeout(timeout)
+    }
+
+    fn set_resource(&mut self, resource: &OtelResource) {
+        self.exporter.set_resource(resource);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_exporter_target, otlp_layers, OtlpConfig, OtlpGuard};
+    use bevy_app::App;
+    use core::time::Duration;
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::{TcpListener, TcpStream},
+        sync::mpsc::{self, Receiver, Sender},
+        thread,
+        time::Instant,
+    };
+    use tracing::{info, info_span};
+    use tracing_subscriber::{layer::SubscriberExt, Registry};
+
+    /// Starts a collector answering all the OTLP requests, and returns its endpoint and the
+    /// paths and bodies of the requests it receives.
+    fn mock_collector() -> (String, Receiver<(String, Vec<u8>)>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let endpoint = format!("http://{}", listener.local_addr().unwrap());
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                let sender = sender.clone();
+                thread::spawn(move || serve(stream, sender));
+            }
+        });
+        (endpoint, receiver)
+    }
+
+    fn serve(mut stream: TcpStream, sender: Sender<(String, Vec<u8>)>) {
+        let mut reader = BufReader::new(stream.try_clone().unwrap());
+        // The connections are kept alive between the requests.
+        loop {
+            let mut request_line = String::new();
+            if reader.read_line(&mut request_line).unwrap_or(0) == 0 {
+                return;
+            }
+            let path = request_line
+                .split(' ')
+                .nth(1)
+                .unwrap_or_default()
+                .to_string();
+            let mut length = 0;
+            loop {
+                let mut header = String::new();
+                reader.read_line(&mut header).unwrap();
+                let header = header.trim_end();
+                if header.is_empty() {
+                    break;
+                }
+                if let Some((name, value)) = header.split_once(':')
+                    && name.eq_ignore_ascii_case("content-length")
+                {
+                    length = value.trim().parse().unwrap();
+                }
+            }
+            let mut body = vec![0; length];
+            reader.read_exact(&mut body).unwrap();
+            // Received before answering, so that the request is seen once the export returns.
+            let _ = sender.send((path, body));
+            stream
+                .write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 0\r\n\r\n")
+                .unwrap();
+        }
+    }
+
+    fn contains(body: &[u8], text: &str) -> bool {
+        body.windows(text.len())
+            .any(|window| window == text.as_bytes())
+    }
+
+    #[test]
+    fn spans_and_logs_reach_the_collector() {
+        let (endpoint, requests) = mock_collector();
+        let mut app = App::new();
+        let config = OtlpConfig {
+            endpoint: format!("{endpoint}/"),
+            service_name: "otlp_test".to_string(),
+            ..Default::default()
+        };
+        let subscriber = Registry::default().with(otlp_layers(&config, &mut app));
+        tracing::subscriber::with_default(subscriber, || {
+            let _span = info_span!("load_level", name = "forest").entered();
+            info!("Loaded the level");
+        });
+        app.world().resource::<OtlpGuard>().flush();
+
+        let mut requests: Vec<_> = requests.try_iter().collect();
+        requests.sort_by(|(a, _), (b, _)| a.cmp(b));
+        let [(logs_path, logs), (traces_path, traces)] = &requests[..] else {
+            panic!("expected one request per signal, got {requests:?}");
+        };
+        assert_eq!(logs_path, "/v1/logs");
+        assert!(contains(logs, "Loaded the level"));
+        assert!(contains(logs, "otlp_test"));
+        assert_eq!(traces_path, "/v1/traces");
+        assert!(contains(traces, "load_level"));
+        assert!(contains(traces, "forest"));
+        assert!(contains(traces, "otlp_test"));
+    }
+
+    #[test]
+    fn unreachable_collector_does_not_block() {
+        // Nothing listens on the port once the listener is dropped.
+        let endpoint = {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            format!("http://{}", listener.local_addr().unwrap())
+        };
+        let mut app = App::new();
+        let config = OtlpConfig {
+            endpoint,
+            export_logs: false,
+            ..Default::default()
+        };
+        let subscriber = Registry::default().with(otlp_layers(&config, &mut app));
+        let start = Instant::now();
+        tracing::subscriber::with_default(subscriber, || {
+            for _ in 0..100 {
+                let _span = info_span!("update").entered();
+            }
+        });
+        assert!(start.elapsed() < Duration::from_secs(1));
+        app.world().resource::<OtlpGuard>().shutdown();
+    }
+
+    #[test]
+    fn exporter_targets() {
+        assert!(is_exporter_target("hyper_util::client"));
+        assert!(is_exporter_target("opentelemetry_sdk"));
+        assert!(is_exporter_target("bevy_log::otlp"));
+        assert!(!is_exporter_target("bevy_log::otlp::tests"));
+        assert!(!is_exporter_target("hyperion"));
+        assert!(!is_exporter_target("bevy_log"));
+    }
+}
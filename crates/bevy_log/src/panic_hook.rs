@@ -0,0 +1,114 @@
+use crate::error;
+use alloc::boxed::Box;
+use std::{
+    backtrace::{Backtrace, BacktraceStatus},
+    panic::{self, PanicHookInfo},
+    sync::{Mutex, Once, PoisonError},
+    thread,
+};
+
+/// Flushes the outputs writing the logs in the background, see [`install_panic_hook`].
+type Flush = Box<dyn Fn() + Send + Sync>;
+
+/// The `flush` of the last call to [`install_panic_hook`].
+static FLUSH: Mutex<Option<Flush>> = Mutex::new(None);
+
+/// Installs a panic hook logging the panics as errors, then calling `flush` so that the outputs
+/// writing the logs in the background write them before the previous hook runs.
+///
+/// The hook is only installed once, so that building several apps doesn't chain as many hooks
+/// logging each panic: the next calls only replace `flush`.
+///
+/// On the web, the error is written with `console.error` by the subscriber of the web.
+pub(crate) fn install_panic_hook(flush: impl Fn() + Send + Sync + 'static) {
+    static INSTALL: Once = Once::new();
+
+    *FLUSH.lock().unwrap_or_else(PoisonError::into_inner) = Some(Box::new(flush));
+    INSTALL.call_once(|| {
+        let previous_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            log_panic(info);
+            if let Some(flush) = &*FLUSH.lock().unwrap_or_else(PoisonError::into_inner) {
+                flush();
+            }
+            previous_hook(info);
+        }));
+    });
+}
+
+/// Logs the payload and the location of a panic, with a backtrace if they are enabled by the
+/// `RUST_BACKTRACE` environment variable, like the default panic hook.
+fn log_panic(info: &PanicHookInfo) {
+    let thread = thread::current();
+    let thread = thread.name().unwrap_or("<unnamed>");
+    let payload = info.payload_as_str().unwrap_or("Box<dyn Any>");
+    let location = info.location().map(ToString::to_string).unwrap_or_default();
+    let backtrace = Backtrace::capture();
+    if backtrace.status() == BacktraceStatus::Captured {
+        error!(
+            target: "panic",
+            "thread '{thread}' panicked at {location}:\n{payload}\nstack backtrace:\n{backtrace}"
+        );
+    } else {
+        error!(target: "panic", "thread '{thread}' panicked at {location}:\n{payload}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_app::App;
+    use bevy_ecs::event::Events;
+    use core::sync::atomic::{AtomicUsize, Ordering};
+    use std::panic;
+    use tracing_subscriber::{prelude::*, Registry};
+
+    use super::install_panic_hook;
+    use crate::{capture::capture_layer, Level, LogCaptureConfig, LogMessage};
+
+    #[test]
+    fn panics_are_logged_then_flushed() {
+        static FLUSHES: AtomicUsize = AtomicUsize::new(0);
+        static UNUSED_FLUSHES: AtomicUsize = AtomicUsize::new(0);
+        let previous_hook = panic::take_hook();
+        install_panic_hook(|| {
+            UNUSED_FLUSHES.fetch_add(1, Ordering::Relaxed);
+        });
+        // Installing the hook again only replaces the flush.
+        install_panic_hook(|| {
+            FLUSHES.fetch_add(1, Ordering::Relaxed);
+        });
+
+        let mut app = App::new();
+        let subscriber =
+            Registry::default().with(capture_layer(LogCaptureConfig::default(), false, &mut app));
+        let line = line!() + 3;
+        tracing::subscriber::with_default(subscriber, || {
+            let result = panic::catch_unwind(|| {
+                panic!("The answer is {}", 42);
+            });
+            assert!(result.is_err());
+        });
+        // Restore the previous hook, for the panics of the other tests.
+        drop(panic::take_hook());
+        panic::set_hook(previous_hook);
+        app.update();
+
+        let events = app.world().resource::<Events<LogMessage>>();
+        let log = events.iter_current_update_events().next().unwrap();
+        assert_eq!(log.level, Level::ERROR);
+        assert_eq!(log.target, "panic");
+        let location = format!("{}:{line}:17", file!());
+        assert!(
+            log.message.starts_with(&format!(
+                "thread 'panic_hook::tests::panics_are_logged_then_flushed' panicked at \
+                {location}:\nThe answer is 42"
+            )),
+            "{}",
+            log.message
+        );
+        // A single hook logged the panic.
+        assert_eq!(events.iter_current_update_events().count(), 1);
+        assert!(FLUSHES.load(Ordering::Relaxed) >= 1);
+        assert_eq!(UNUSED_FLUSHES.load(Ordering::Relaxed), 0);
+    }
+}
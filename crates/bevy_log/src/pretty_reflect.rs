@@ -0,0 +1,457 @@
+use bevy_reflect::{PartialReflect, ReflectRef, VariantType};
+use core::fmt::{self, Write};
+
+/// Caps applied by [`pretty_reflect`] to keep the output of large values log-friendly.
+///
+/// Elided elements are replaced by `... (+N more)`, where `N` is the number of elided elements,
+/// and elided characters by `...`. Rendering stops as soon as a limit is reached, so the size of
+/// the elided content doesn't matter.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PrintLimits {
+    /// How deep to render nested values. The content of the values nested deeper is elided.
+    pub max_depth: usize,
+    /// How many fields or elements of a value to render.
+    pub max_elements: usize,
+    /// How many characters of a value that isn't made of fields or elements to render, for
+    /// example a string.
+    pub max_string_len: usize,
+}
+
+impl Default for PrintLimits {
+    fn default() -> Self {
+        Self {
+            max_depth: 4,
+            max_elements: 8,
+            max_string_len: 64,
+        }
+    }
+}
+
+/// Renders a reflected value on a single line, within the given [`PrintLimits`].
+///
+/// This is meant for logs, where the [`Debug`](core::fmt::Debug) output of a value can be huge,
+/// for example a component holding mesh data. Maps and sets are sorted by their rendered keys, so
+/// the output is stable.
+///
+/// ```
+/// # use bevy_log::{pretty_reflect, PrintLimits};
+/// # use bevy_reflect::Reflect;
+/// #[derive(Reflect)]
+/// struct Path {
+///     name: String,
+///     points: Vec<u32>,
+/// }
+///
+/// let path = Path {
+///     name: "patrol".to_string(),
+///     points: (0..100).collect(),
+/// };
+/// let limits = PrintLimits {
+///     max_elements: 3,
+///     ..Default::default()
+/// };
+/// assert_eq!(
+///     pretty_reflect(&path, limits),
+///     r#"Path { name: "patrol", points: [0, 1, 2, ... (+97 more)] }"#
+/// );
+/// ```
+pub fn pretty_reflect(value: &dyn PartialReflect, limits: PrintLimits) -> String {
+    reflect(value, limits).to_string()
+}
+
+/// Displays a reflected value like [`pretty_reflect`], only rendering it when displayed.
+///
+/// This is useful for log fields, as the value is only rendered if the log is enabled:
+///
+/// ```
+/// # use bevy_log::{info, reflect, PrintLimits};
+/// # use bevy_reflect::Reflect;
+/// # #[derive(Reflect)]
+/// # struct Player { health: u32 }
+/// # let player = Player { health: 100 };
+/// info!(player = %reflect(&player, PrintLimits::default()), "Player spawned");
+/// ```
+pub fn reflect(value: &dyn PartialReflect, limits: PrintLimits) -> PrettyReflect<'_> {
+    PrettyReflect {
+        value,
+        limits,
+        depth: 0,
+    }
+}
+
+/// A reflected value displayed within [`PrintLimits`], see [`reflect`].
+pub struct PrettyReflect<'a> {
+    value: &'a dyn PartialReflect,
+    limits: PrintLimits,
+    depth: usize,
+}
+
+/// An element of a value made of fields or elements.
+enum Element<'a> {
+    Value(&'a dyn PartialReflect),
+    Field(&'a str, &'a dyn PartialReflect),
+    Entry(String, &'a dyn PartialReflect),
+    Rendered(String),
+}
+
+impl<'a> PrettyReflect<'a> {
+    fn nested(&self, value: &'a dyn PartialReflect) -> Self {
+        Self {
+            value,
+            limits: self.limits,
+            depth: self.depth + 1,
+        }
+    }
+
+    /// The number of elements rendered out of `len`.
+    fn shown(&self, len: usize) -> usize {
+        if self.depth >= self.limits.max_depth {
+            0
+        } else {
+            len.min(self.limits.max_elements)
+        }
+    }
+
+    /// Renders the first `shown` elements by sorted rendered key, leaving the other keys
+    /// unrendered once the budget is spent.
+    fn sorted<T>(
+        &self,
+        len: usize,
+        elements: impl Iterator<Item = (&'a dyn PartialReflect, T)>,
+    ) -> Vec<(String, T)> {
+        let shown = self.shown(len);
+        if shown == 0 {
+            return Vec::new();
+        }
+        let mut elements = elements
+            .map(|(key, value)| (self.nested(key).to_string(), value))
+            .collect::<Vec<_>>();
+        if shown < elements.len() {
+            elements.select_nth_unstable_by(shown, |(a, _), (b, _)| a.cmp(b));
+            elements.truncate(shown);
+        }
+        elements.sort_by(|(a, _), (b, _)| a.cmp(b));
+        elements
+    }
+
+    /// Writes `open`, the elements within the limits, then `close`.
+    fn write_elements(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        open: &str,
+        close: &str,
+        len: usize,
+        elements: impl Iterator<Item = Element<'a>>,
+    ) -> fmt::Result {
+        f.write_str(open)?;
+        let shown = self.shown(len);
+        for (index, element) in elements.take(shown).enumerate() {
+            if index > 0 {
+                f.write_str(", ")?;
+            }
+            match element {
+                Element::Value(value) => write!(f, "{}", self.nested(value))?,
+                Element::Field(name, value) => write!(f, "{name}: {}", self.nested(value))?,
+                Element::Entry(key, value) => write!(f, "{key}: {}", self.nested(value))?,
+                Element::Rendered(value) => f.write_str(&value)?,
+            }
+        }
+        if shown < len {
+            if shown > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "... (+{} more)", len - shown)?;
+        }
+        f.write_str(close)
+    }
+}
+
+impl fmt::Display for PrettyReflect<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.value.reflect_ref() {
+            ReflectRef::Struct(value) => {
+                let name = value.reflect_short_type_path();
+                if value.field_len() == 0 {
+                    return f.write_str(name);
+                }
+                let fields = (0..value.field_len()).filter_map(|index| {
+                    Some(Element::Field(
+                        value.name_at(index)?,
+                        value.field_at(index)?,
+                    ))
+                });
+                self.write_elements(f, &format!("{name} {{ "), " }", value.field_len(), fields)
+            }
+            ReflectRef::TupleStruct(value) => {
+                let name = value.reflect_short_type_path();
+                let fields = value.iter_fields().map(Element::Value);
+                self.write_elements(f, &format!("{name}("), ")", value.field_len(), fields)
+            }
+            ReflectRef::Tuple(value) => {
+                let fields = value.iter_fields().map(Element::Value);
+                self.write_elements(f, "(", ")", value.field_len(), fields)
+            }
+            ReflectRef::List(value) => {
+                self.write_elements(f, "[", "]", value.len(), value.iter().map(Element::Value))
+            }
+            ReflectRef::Array(value) => {
+                self.write_elements(f, "[", "]", value.len(), value.iter().map(Element::Value))
+            }
+            ReflectRef::Map(value) => {
+                let entries = self
+                    .sorted(value.len(), value.iter())
+                    .into_iter()
+                    .map(|(key, value)| Element::Entry(key, value));
+                self.write_elements(f, "{", "}", value.len(), entries)
+            }
+            ReflectRef::Set(value) => {
+                let values = self
+                    .sorted(value.len(), value.iter().map(|value| (value, ())))
+                    .into_iter()
+                    .map(|(value, ())| Element::Rendered(value));
+                self.write_elements(f, "{", "}", value.len(), values)
+            }
+            ReflectRef::Enum(value) => {
+                let name = value.variant_name();
+                let fields = value.iter_fields().map(|field| match field.name() {
+                    Some(name) => Element::Field(name, field.value()),
+                    None => Element::Value(field.value()),
+                });
+                match value.variant_type() {
+                    VariantType::Unit => f.write_str(name),
+                    VariantType::Tuple => {
+                        self.write_elements(f, &format!("{name}("), ")", value.field_len(), fields)
+                    }
+                    VariantType::Struct => self.write_elements(
+                        f,
+                        &format!("{name} {{ "),
+                        " }",
+                        value.field_len(),
+                        fields,
+                    ),
+                }
+            }
+            _ => {
+                let mut debug = Truncated {
+                    text: String::new(),
+                    remaining: self.limits.max_string_len,
+                };
+                // The value is only formatted until the writer runs out of characters.
+                let truncated = write!(debug, "{:?}", DebugReflect(self.value)).is_err();
+                f.write_str(&debug.text)?;
+                if truncated {
+                    f.write_str("...")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// A writer keeping the first `remaining` characters written to it, then failing.
+struct Truncated {
+    text: String,
+    remaining: usize,
+}
+
+impl Write for Truncated {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        match s.char_indices().nth(self.remaining) {
+            Some((end, _)) => {
+                self.text.push_str(&s[..end]);
+                self.remaining = 0;
+                Err(fmt::Error)
+            }
+            None => {
+                self.text.push_str(s);
+                self.remaining -= s.chars().count();
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Formats a reflected value with [`PartialReflect::debug`].
+struct DebugReflect<'a>(&'a dyn PartialReflect);
+
+impl fmt::Debug for DebugReflect<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.debug(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_platform::collections::{HashMap, HashSet};
+    use bevy_reflect::Reflect;
+    use core::fmt::{self, Debug};
+
+    use super::{pretty_reflect, reflect, PrintLimits};
+
+    #[derive(Reflect)]
+    struct Mesh {
+        name: String,
+        positions: Vec<[f32; 3]>,
+        kind: Kind,
+    }
+
+    #[derive(Reflect)]
+    enum Kind {
+        Static,
+        Skinned { joints: u32 },
+        Morph(u8, (bool, char)),
+    }
+
+    #[derive(Reflect)]
+    struct Marker;
+
+    #[derive(Reflect)]
+    struct Index(u32);
+
+    #[test]
+    fn nested_rendering() {
+        let mesh = Mesh {
+            name: "cube".into(),
+            positions: vec![[0.0, 1.0, 2.5]],
+            kind: Kind::Skinned { joints: 3 },
+        };
+        assert_eq!(
+            pretty_reflect(&mesh, PrintLimits::default()),
+            r#"Mesh { name: "cube", positions: [[0.0, 1.0, 2.5]], kind: Skinned { joints: 3 } }"#
+        );
+        assert_eq!(
+            pretty_reflect(&Kind::Static, PrintLimits::default()),
+            "Static"
+        );
+        assert_eq!(
+            pretty_reflect(&Kind::Morph(1, (true, 'x')), PrintLimits::default()),
+            "Morph(1, (true, 'x'))"
+        );
+        assert_eq!(pretty_reflect(&Marker, PrintLimits::default()), "Marker");
+        assert_eq!(
+            pretty_reflect(&Index(4), PrintLimits::default()),
+            "Index(4)"
+        );
+        assert_eq!(
+            pretty_reflect(&Some(2u8), PrintLimits::default()),
+            "Some(2)"
+        );
+    }
+
+    #[test]
+    fn elision() {
+        let mesh = Mesh {
+            name: "a".repeat(10),
+            positions: vec![[0.0; 3]; 10],
+            kind: Kind::Static,
+        };
+        let limits = PrintLimits {
+            max_depth: 1,
+            max_elements: 2,
+            max_string_len: 4,
+        };
+        assert_eq!(
+            pretty_reflect(&mesh, limits),
+            r#"Mesh { name: "aaa..., positions: [... (+10 more)], ... (+1 more) }"#
+        );
+
+        let limits = PrintLimits {
+            max_depth: 0,
+            ..limits
+        };
+        assert_eq!(pretty_reflect(&mesh, limits), "Mesh { ... (+3 more) }");
+        // Values without fields or elements are never elided by depth.
+        assert_eq!(pretty_reflect(&Kind::Static, limits), "Static");
+        assert_eq!(pretty_reflect(&7u32, limits), "7");
+
+        let limits = PrintLimits {
+            max_elements: 0,
+            ..Default::default()
+        };
+        assert_eq!(pretty_reflect(&vec![1, 2], limits), "[... (+2 more)]");
+        assert_eq!(pretty_reflect(&Vec::<u8>::new(), limits), "[]");
+    }
+
+    #[test]
+    fn sorted_maps_and_sets() {
+        let map = ["c", "a", "d", "b"]
+            .into_iter()
+            .zip(0..)
+            .map(|(key, value)| (key.to_string(), value))
+            .collect::<HashMap<_, u32>>();
+        let limits = PrintLimits {
+            max_elements: 3,
+            ..Default::default()
+        };
+        assert_eq!(
+            pretty_reflect(&map, limits),
+            r#"{"a": 1, "b": 3, "c": 0, ... (+1 more)}"#
+        );
+
+        let set = [30u32, 10, 20].into_iter().collect::<HashSet<_>>();
+        assert_eq!(pretty_reflect(&set, limits), "{10, 20, 30}");
+    }
+
+    /// An opaque value with an endless [`Debug`] output.
+    #[derive(Reflect, Clone, PartialEq, Eq, Hash)]
+    #[reflect(opaque, Debug, Hash, PartialEq, Clone)]
+    struct Endless;
+
+    impl Debug for Endless {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            loop {
+                f.write_str("ab")?;
+            }
+        }
+    }
+
+    /// An opaque value that must not be rendered.
+    #[derive(Reflect, Clone, PartialEq, Eq, Hash)]
+    #[reflect(opaque, Debug, Hash, PartialEq, Clone)]
+    struct Unrendered(u8);
+
+    impl Debug for Unrendered {
+        fn fmt(&self, _: &mut fmt::Formatter<'_>) -> fmt::Result {
+            panic!("rendered an elided value");
+        }
+    }
+
+    #[test]
+    fn rendering_stops_at_the_limits() {
+        let limits = PrintLimits {
+            max_string_len: 5,
+            ..Default::default()
+        };
+        assert_eq!(pretty_reflect(&Endless, limits), "ababa...");
+
+        let map = (0..3)
+            .map(|key| (Unrendered(key), 0u8))
+            .collect::<HashMap<_, _>>();
+        let set = (0..3).map(Unrendered).collect::<HashSet<_>>();
+        let limits = PrintLimits {
+            max_depth: 0,
+            ..Default::default()
+        };
+        assert_eq!(pretty_reflect(&map, limits), "{... (+3 more)}");
+        assert_eq!(pretty_reflect(&set, limits), "{... (+3 more)}");
+        let limits = PrintLimits {
+            max_elements: 0,
+            ..Default::default()
+        };
+        assert_eq!(pretty_reflect(&map, limits), "{... (+3 more)}");
+    }
+
+    #[test]
+    fn lazy_field() {
+        let value = vec![1, 2, 3];
+        let limits = PrintLimits {
+            max_elements: 1,
+            ..Default::default()
+        };
+        assert_eq!(reflect(&value, limits).to_string(), "[1, ... (+2 more)]");
+        assert_eq!(
+            format!("{}", tracing::field::display(reflect(&value, limits))),
+            pretty_reflect(&value, limits)
+        );
+    }
+}
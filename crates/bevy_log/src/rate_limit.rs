@@ -0,0 +1,488 @@
+use crate::{debug, error, info, trace, warn, BoxedLayer, Level};
+use bevy_app::{App, Last};
+use bevy_ecs::{resource::Resource, system::Res};
+use bevy_platform::{
+    collections::HashMap,
+    hash::FixedHasher,
+    sync::{Arc, Mutex, PoisonError},
+    time::Instant,
+};
+use core::{
+    fmt::{self, Write},
+    hash::{BuildHasher, Hasher},
+    time::Duration,
+};
+use tracing::{
+    callsite::Identifier,
+    field::{Field, Visit},
+    Event, Subscriber,
+};
+use tracing_subscriber::{layer::Context, Layer};
+
+/// The target of the summaries of the suppressed logs, which are never suppressed themselves.
+const SUMMARY_TARGET: &str = "bevy_log::rate_limit";
+
+/// Settings of the suppression of repeated logs by the [`LogPlugin`](crate::LogPlugin), to keep
+/// a log written every frame from drowning the others.
+///
+/// Once the same log, written by the same line of code with the same message, was written
+/// [`max_per_window`](Self::max_per_window) times within a [`window`](Self::window), its next
+/// instances are suppressed until the window ends. A summary such as
+/// `Previous message repeated 1482 times: Missing texture` is then logged at the same level.
+///
+/// ```no_run
+/// # use bevy_app::{App, NoopPluginGroup as DefaultPlugins, PluginGroup};
+/// # use bevy_log::{LogPlugin, LogRateLimit};
+/// # use core::time::Duration;
+/// App::new()
+///     .add_plugins(DefaultPlugins.set(LogPlugin {
+///         rate_limit: Some(
+///             LogRateLimit::default()
+///                 // The physics logs are grouped over a longer time.
+///                 .with_window_for("my_game::physics", Duration::from_secs(10)),
+///         ),
+///         ..Default::default()
+///     }))
+///     .run();
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LogRateLimit {
+    /// How many times the same log is written within a window before its next instances are
+    /// suppressed.
+    pub max_per_window: u32,
+    /// How long the same log is counted before it's allowed again.
+    pub window: Duration,
+    /// The windows used instead of [`window`](Self::window) for the logs of some targets, and of
+    /// the modules they contain, such as `(String::from("my_game::physics"), 10s)`. The most
+    /// specific target is used.
+    pub target_windows: Vec<(String, Duration)>,
+    /// The logs of this level, or more important, are never suppressed. Only the `error` logs
+    /// are exempt by default.
+    pub exempt: Option<Level>,
+    /// The maximum number of different logs tracked at once, which bounds the memory used.
+    ///
+    /// When a new log is written while this many logs are tracked, the log whose window started
+    /// first stops being tracked, and the summary of its suppressed instances is logged.
+    pub max_tracked: usize,
+}
+
+impl Default for LogRateLimit {
+    fn default() -> Self {
+        Self {
+            max_per_window: 10,
+            window: Duration::from_secs(1),
+            target_windows: Vec::new(),
+            exempt: Some(Level::ERROR),
+            max_tracked: 256,
+        }
+    }
+}
+
+impl LogRateLimit {
+    /// Sets the window of the logs of `target` and of the modules it contains.
+    pub fn with_window_for(mut self, target: impl Into<String>, window: Duration) -> Self {
+        self.target_windows.push((target.into(), window));
+        self
+    }
+
+    /// Returns the window of the logs of `target`.
+    fn window(&self, target: &str) -> Duration {
+        self.target_windows
+            .iter()
+            .filter(|(prefix, _)| {
+                target
+                    .strip_prefix(prefix.as_str())
+                    .is_some_and(|rest| rest.is_empty() || rest.starts_with("::"))
+            })
+            .max_by_key(|(prefix, _)| prefix.len())
+            .map_or(self.window, |(_, window)| *window)
+    }
+}
+
+/// A log that was written too many times within its window.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Summary {
+    level: Level,
+    message: String,
+    suppressed: u32,
+}
+
+/// A log tracked by the [`RateLimiter`].
+struct Tracked {
+    window_start: Instant,
+    window: Duration,
+    count: u32,
+    level: Level,
+    /// The message of the log, kept once the log starts being suppressed.
+    message: Option<String>,
+}
+
+/// What the [`RateLimiter`] decided for a log.
+#[derive(Debug, PartialEq, Eq)]
+enum Decision {
+    Allow,
+    /// The log is suppressed. Its message is needed if it's the first suppressed instance of the
+    /// window.
+    Suppress {
+        first: bool,
+    },
+}
+
+/// Counts the logs written within their windows.
+struct RateLimiter {
+    config: LogRateLimit,
+    tracked: HashMap<(Identifier, u64), Tracked>,
+    /// The summaries of the logs that stopped being tracked.
+    summaries: Vec<Summary>,
+}
+
+impl RateLimiter {
+    fn new(config: LogRateLimit) -> Self {
+        Self {
+            config,
+            tracked: HashMap::default(),
+            summaries: Vec::new(),
+        }
+    }
+
+    fn record(
+        &mut self,
+        key: (Identifier, u64),
+        level: Level,
+        target: &str,
+        now: Instant,
+    ) -> Decision {
+        let max_per_window = self.config.max_per_window;
+        if !self.tracked.contains_key(&key) {
+            if self.tracked.len() >= self.config.max_tracked.max(1) {
+                self.evict_oldest();
+            }
+            let window = self.config.window(target);
+            self.tracked.insert(
+                key.clone(),
+                Tracked {
+                    window_start: now,
+                    window,
+                    count: 0,
+                    level,
+                    message: None,
+                },
+            );
+        }
+        let tracked = self.tracked.get_mut(&key).unwrap();
+        if now.saturating_duration_since(tracked.window_start) >= tracked.window {
+            self.summaries.extend(summary(tracked, max_per_window));
+            tracked.window_start = now;
+            tracked.count = 0;
+        }
+
+        tracked.count = tracked.count.saturating_add(1);
+        if tracked.count <= max_per_window {
+            Decision::Allow
+        } else {
+            Decision::Suppress {
+                first: tracked.count == max_per_window + 1,
+            }
+        }
+    }
+
+    fn set_message(&mut self, key: &(Identifier, u64), message: String) {
+        if let Some(tracked) = self.tracked.get_mut(key) {
+            tracked.message = Some(message);
+        }
+    }
+
+    /// Stops tracking the log whose window started first.
+    fn evict_oldest(&mut self) {
+        let Some(key) = self
+            .tracked
+            .iter()
+            .min_by_key(|(_, tracked)| tracked.window_start)
+            .map(|(key, _)| key.clone())
+        else {
+            return;
+        };
+        let mut tracked = self.tracked.remove(&key).unwrap();
+        self.summaries
+            .extend(summary(&mut tracked, self.config.max_per_window));
+    }
+
+    /// Returns the summaries of the logs whose window ended, and stops tracking these logs.
+    fn take_summaries(&mut self, now: Instant) -> Vec<Summary> {
+        let max_per_window = self.config.max_per_window;
+        let summaries = &mut self.summaries;
+        self.tracked.retain(|_, tracked| {
+            let ended = now.saturating_duration_since(tracked.window_start) >= tracked.window;
+            if ended {
+                summaries.extend(summary(tracked, max_per_window));
+            }
+            !ended
+        });
+        core::mem::take(summaries)
+    }
+}
+
+/// Returns the summary of the instances of `tracked` suppressed during its window.
+fn summary(tracked: &mut Tracked, max_per_window: u32) -> Option<Summary> {
+    let suppressed = tracked.count.saturating_sub(max_per_window);
+    (suppressed > 0).then(|| Summary {
+        level: tracked.level,
+        message: tracked.message.take().unwrap_or_default(),
+        suppressed,
+    })
+}
+
+/// The [`RateLimiter`] of the [`LogPlugin`](crate::LogPlugin), shared between its layer and the
+/// system logging the summaries.
+#[derive(Resource, Clone)]
+struct SharedRateLimiter(Arc<Mutex<RateLimiter>>);
+
+impl SharedRateLimiter {
+    fn lock(&self) -> impl core::ops::DerefMut<Target = RateLimiter> + '_ {
+        self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Creates the layer suppressing the repeated logs, and adds the system logging the summaries of
+/// the suppressed logs to `app`.
+pub(crate) fn rate_limit_layer(config: LogRateLimit, app: &mut App) -> BoxedLayer {
+    let exempt = config.exempt;
+    let limiter = SharedRateLimiter(Arc::new(Mutex::new(RateLimiter::new(config))));
+    app.insert_resource(limiter.clone())
+        .add_systems(Last, log_summaries);
+    Box::new(RateLimitLayer { limiter, exempt })
+}
+
+/// Logs the summaries of the logs whose window ended.
+fn log_summaries(limiter: Res<SharedRateLimiter>) {
+    // The lock is released before logging, since the layer locks it as well.
+    let summaries = limiter.lock().take_summaries(Instant::now());
+    for summary in summaries {
+        let message = format!(
+            "Previous message repeated {} times: {}",
+            summary.suppressed, summary.message
+        );
+        match summary.level {
+            Level::ERROR => error!(target: SUMMARY_TARGET, "{message}"),
+            Level::WARN => warn!(target: SUMMARY_TARGET, "{message}"),
+            Level::INFO => info!(target: SUMMARY_TARGET, "{message}"),
+            Level::DEBUG => debug!(target: SUMMARY_TARGET, "{message}"),
+            Level::TRACE => trace!(target: SUMMARY_TARGET, "{message}"),
+        }
+    }
+}
+
+/// Suppresses the logs written too many times within their window.
+struct RateLimitLayer {
+    limiter: SharedRateLimiter,
+    exempt: Option<Level>,
+}
+
+impl<S: Subscriber> Layer<S> for RateLimitLayer {
+    fn event_enabled(&self, event: &Event<'_>, _ctx: Context<'_, S>) -> bool {
+        let metadata = event.metadata();
+        if metadata.target() == SUMMARY_TARGET
+            || self
+                .exempt
+                .is_some_and(|exempt| *metadata.level() <= exempt)
+        {
+            return true;
+        }
+
+        // Only the hash of the message is computed for each log, not the message itself. The
+        // fields are recorded without holding the lock, in case formatting them logs.
+        let mut hasher = MessageHasher(FixedHasher.build_hasher());
+        event.record(&mut hasher);
+        let key = (metadata.callsite(), hasher.0.finish());
+        let decision = self.limiter.lock().record(
+            key.clone(),
+            *metadata.level(),
+            metadata.target(),
+            Instant::now(),
+        );
+        match decision {
+            Decision::Allow => true,
+            Decision::Suppress { first } => {
+                if first {
+                    let mut message = MessageVisitor(String::new());
+                    event.record(&mut message);
+                    self.limiter.lock().set_message(&key, message.0);
+                }
+                false
+            }
+        }
+    }
+}
+
+/// Hashes the `message` field of a log.
+struct MessageHasher<H>(H);
+
+impl<H: Hasher> Write for MessageHasher<H> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0.write(s.as_bytes());
+        Ok(())
+    }
+}
+
+impl<H: Hasher> Visit for MessageHasher<H> {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            let _ = self.write_str(value);
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "message" {
+            let _ = write!(self, "{value:?}");
+        }
+    }
+}
+
+/// Records the `message` field of a log.
+struct MessageVisitor(String);
+
+impl Visit for MessageVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.0.push_str(value);
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "message" {
+            let _ = write!(self.0, "{value:?}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_platform::{
+        sync::{Arc, Mutex},
+        time::Instant,
+    };
+    use core::time::Duration;
+    use tracing::{error, warn};
+    use tracing_subscriber::{prelude::*, Registry};
+
+    use super::{LogRateLimit, RateLimitLayer, RateLimiter, SharedRateLimiter, Summary};
+    use crate::Level;
+
+    fn limiter(config: LogRateLimit) -> SharedRateLimiter {
+        SharedRateLimiter(Arc::new(Mutex::new(RateLimiter::new(config))))
+    }
+
+    /// Logs through the layer, returning how many logs reached the layers after it.
+    fn log(limiter: &SharedRateLimiter, log: impl Fn(u32)) -> usize {
+        #[derive(Clone, Default)]
+        struct Count(Arc<Mutex<usize>>);
+
+        impl<S: tracing::Subscriber> tracing_subscriber::Layer<S> for Count {
+            fn on_event(
+                &self,
+                _event: &tracing::Event<'_>,
+                _ctx: tracing_subscriber::layer::Context<'_, S>,
+            ) {
+                *self.0.lock().unwrap() += 1;
+            }
+        }
+
+        let count = Count::default();
+        let subscriber = Registry::default()
+            .with(count.clone())
+            .with(RateLimitLayer {
+                limiter: limiter.clone(),
+                exempt: limiter.lock().config.exempt,
+            });
+        tracing::subscriber::with_default(subscriber, || (0..100).for_each(log));
+        *count.0.lock().unwrap()
+    }
+
+    fn later(limiter: &SharedRateLimiter, seconds: u64) -> Vec<Summary> {
+        limiter
+            .lock()
+            .take_summaries(Instant::now() + Duration::from_secs(seconds))
+    }
+
+    #[test]
+    fn repeated_logs_are_summarized() {
+        let limiter = limiter(LogRateLimit::default());
+        let logged = log(&limiter, |_| {
+            warn!("Missing texture");
+            warn!("Missing mesh");
+        });
+        assert_eq!(logged, 20);
+        assert_eq!(later(&limiter, 0), []);
+
+        let mut summaries = later(&limiter, 1);
+        summaries.sort_by(|a, b| a.message.cmp(&b.message));
+        assert_eq!(
+            summaries,
+            [
+                Summary {
+                    level: Level::WARN,
+                    message: "Missing mesh".to_string(),
+                    suppressed: 90,
+                },
+                Summary {
+                    level: Level::WARN,
+                    message: "Missing texture".to_string(),
+                    suppressed: 90,
+                },
+            ]
+        );
+        // The logs are allowed again once their window ended.
+        assert_eq!(log(&limiter, |_| warn!("Missing texture")), 10);
+    }
+
+    #[test]
+    fn different_messages_are_counted_apart() {
+        let limiter = limiter(LogRateLimit::default());
+        assert_eq!(log(&limiter, |i| warn!("Frame {}", i % 20)), 100);
+        assert_eq!(later(&limiter, 1), []);
+    }
+
+    #[test]
+    fn errors_are_exempt() {
+        let limiter = limiter(LogRateLimit::default());
+        assert_eq!(log(&limiter, |_| error!("Lost the connection")), 100);
+
+        let limiter = self::limiter(LogRateLimit {
+            exempt: None,
+            ..Default::default()
+        });
+        assert_eq!(log(&limiter, |_| error!("Lost the connection")), 10);
+    }
+
+    #[test]
+    fn target_windows_override_the_window() {
+        let config = LogRateLimit::default()
+            .with_window_for("bevy_log", Duration::from_secs(60))
+            .with_window_for("bevy_log::other", Duration::from_secs(5));
+        assert_eq!(config.window(module_path!()), Duration::from_secs(60));
+        assert_eq!(config.window("bevy_log_other"), Duration::from_secs(1));
+
+        let limiter = limiter(config);
+        log(&limiter, |_| warn!("Missing texture"));
+        assert_eq!(later(&limiter, 1), []);
+        assert_eq!(later(&limiter, 60)[0].suppressed, 90);
+    }
+
+    #[test]
+    fn tracked_logs_are_bounded() {
+        let limiter = limiter(LogRateLimit {
+            max_per_window: 1,
+            max_tracked: 2,
+            ..Default::default()
+        });
+        log(&limiter, |i| match i {
+            0..50 => warn!("Missing texture"),
+            _ => warn!("Frame {i}"),
+        });
+        // The first log stopped being tracked to track the others.
+        assert_eq!(limiter.lock().tracked.len(), 2);
+        let summaries = limiter.lock().take_summaries(Instant::now());
+        assert_eq!(summaries[0].message, "Missing texture");
+        assert_eq!(summaries[0].suppressed, 49);
+    }
+}
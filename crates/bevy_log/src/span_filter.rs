@@ -0,0 +1,160 @@
+use crate::{BoxedLayer, Level};
+use bevy_platform::sync::{Arc, PoisonError, RwLock, RwLockReadGuard};
+use tracing::{level_filters::LevelFilter, Event, Subscriber};
+use tracing_subscriber::{layer::Context, registry::LookupSpan, Layer};
+
+/// Suppresses the logs written within the spans of a name, or keeps only their most severe
+/// levels, for example to silence the hundreds of messages of an asset hot-reload.
+///
+/// A log is matched by a rule when any of the spans it is written in, from its parent span up to
+/// the root, has the name of the rule. When several rules match, the most restrictive one
+/// applies. The rules stack with the filter of the [`LogPlugin`](crate::LogPlugin): a log must
+/// pass both.
+///
+/// The rules are set with [`LogPlugin::span_rules`](crate::LogPlugin::span_rules) and changed
+/// while the app is running through the [`LogFilter`](crate::LogFilter).
+///
+/// ```
+/// # use bevy_log::{Level, LogPlugin, SpanRule};
+/// LogPlugin {
+///     span_rules: vec![
+///         SpanRule::suppress("hot_reload"),
+///         // Only the warnings and errors of the shader compilation are written.
+///         SpanRule::limit("compile_shader", Level::WARN),
+///     ],
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SpanRule {
+    /// The name of the spans, such as `hot_reload` for `info_span!("hot_reload")`.
+    pub span: String,
+    /// The most verbose level still written within the spans, or `None` to suppress all their
+    /// logs.
+    pub level: Option<Level>,
+}
+
+impl SpanRule {
+    /// Suppresses all the logs written within the spans named `span`.
+    pub fn suppress(span: impl Into<String>) -> Self {
+        Self {
+            span: span.into(),
+            level: None,
+        }
+    }
+
+    /// Only keeps the logs at `level` or more severe written within the spans named `span`.
+    pub fn limit(span: impl Into<String>, level: Level) -> Self {
+        Self {
+            span: span.into(),
+            level: Some(level),
+        }
+    }
+
+    fn level_filter(&self) -> LevelFilter {
+        self.level.map_or(LevelFilter::OFF, LevelFilter::from_level)
+    }
+}
+
+/// The [`SpanRule`]s shared by the [`LogFilter`](crate::LogFilter) and the layer applying them.
+#[derive(Clone, Default)]
+pub(crate) struct SharedSpanRules(Arc<RwLock<Vec<SpanRule>>>);
+
+impl SharedSpanRules {
+    pub(crate) fn new(rules: Vec<SpanRule>) -> Self {
+        Self(Arc::new(RwLock::new(rules)))
+    }
+
+    pub(crate) fn set(&self, rules: Vec<SpanRule>) {
+        *self.0.write().unwrap_or_else(PoisonError::into_inner) = rules;
+    }
+
+    pub(crate) fn read(&self) -> RwLockReadGuard<'_, Vec<SpanRule>> {
+        self.0.read().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Creates the layer applying the `rules`.
+pub(crate) fn span_filter_layer(rules: SharedSpanRules) -> BoxedLayer {
+    Box::new(SpanFilterLayer { rules })
+}
+
+/// Suppresses the logs matched by a [`SpanRule`].
+struct SpanFilterLayer {
+    rules: SharedSpanRules,
+}
+
+impl<S> Layer<S> for SpanFilterLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn event_enabled(&self, event: &Event<'_>, ctx: Context<'_, S>) -> bool {
+        let rules = self.rules.read();
+        if rules.is_empty() {
+            return true;
+        }
+        let Some(scope) = ctx.event_scope(event) else {
+            return true;
+        };
+        let level = *event.metadata().level();
+        scope
+            .flat_map(|span| rules.iter().filter(move |rule| rule.span == span.name()))
+            .all(|rule| rule.level_filter() >= level)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{span_filter_layer, SharedSpanRules, SpanRule};
+    use crate::{capture::capture_layer, Level, LogCaptureConfig, LogMessage};
+    use bevy_app::App;
+    use bevy_ecs::event::Events;
+    use tracing::{debug, debug_span, info, info_span, warn};
+    use tracing_subscriber::{prelude::*, Registry};
+
+    fn messages(app: &mut App) -> Vec<String> {
+        app.update();
+        app.world()
+            .resource::<Events<LogMessage>>()
+            .iter_current_update_events()
+            .map(|log| log.message.clone())
+            .collect()
+    }
+
+    #[test]
+    fn logs_within_suppressed_spans_are_skipped() {
+        let mut app = App::new();
+        let rules = SharedSpanRules::new(vec![
+            SpanRule::suppress("hot_reload"),
+            SpanRule::limit("compile_shader", Level::WARN),
+        ]);
+        let subscriber = Registry::default().with(vec![
+            span_filter_layer(rules.clone()),
+            capture_layer(LogCaptureConfig::default(), false, &mut app),
+        ]);
+        tracing::subscriber::with_default(subscriber, || {
+            info!("outside");
+            info_span!("hot_reload").in_scope(|| {
+                info!("reloaded");
+                // The rule applies to the nested spans as well.
+                debug_span!("load", path = "a.png").in_scope(|| warn!("nested"));
+            });
+            info_span!("compile_shader").in_scope(|| {
+                info!("compiled");
+                warn!("deprecated");
+            });
+            info_span!("other").in_scope(|| info!("unmatched span"));
+
+            // The rules can be changed while the subscriber is running.
+            rules.set(vec![SpanRule::limit("hot_reload", Level::INFO)]);
+            info_span!("hot_reload").in_scope(|| {
+                info!("reloaded again");
+                debug!("details");
+            });
+        });
+        assert_eq!(
+            messages(&mut app),
+            ["outside", "deprecated", "unmatched span", "reloaded again"]
+        );
+    }
+}
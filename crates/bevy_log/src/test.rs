@@ -0,0 +1,247 @@
+//! Utilities to check the logs written in tests.
+
+use crate::{history::log_record, Level, LogRecord};
+use bevy_app::App;
+use bevy_ecs::schedule::{ExecutorKind, Schedules};
+use bevy_platform::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use tracing::{subscriber::DefaultGuard, Event, Subscriber};
+use tracing_log::LogTracer;
+use tracing_subscriber::{fmt, layer::Context, prelude::*, Layer, Registry};
+
+/// Captures the logs written during a test, for example to check that a system warns when its
+/// configuration is invalid.
+///
+/// ```
+/// # use bevy_app::{App, Update};
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_log::{test::LogCapture, warn, Level};
+/// # #[derive(Resource)]
+/// # struct Config {
+/// #     speed: f32,
+/// # }
+/// fn check_config(config: Res<Config>) {
+///     if config.speed < 0.0 {
+///         warn!("invalid config: the speed is negative");
+///     }
+/// }
+///
+/// let mut app = App::new();
+/// app.insert_resource(Config { speed: -1.0 })
+///     .add_systems(Update, check_config);
+/// let capture = LogCapture::install_single_threaded(&mut app);
+/// app.update();
+/// capture.assert_contains(Level::WARN, "invalid config");
+/// ```
+///
+/// The logs are captured on the thread that installed the capture, so the tests running in
+/// parallel, each on their own thread, don't see the logs of each other. The logs written on
+/// other threads, such as in async tasks or by the systems the multi-threaded executor runs on
+/// the task pools, aren't captured. [`install_single_threaded`](Self::install_single_threaded)
+/// runs the systems of an app on this thread to capture their logs too.
+///
+/// On this thread, the capture replaces the subscriber of the [`LogPlugin`](crate::LogPlugin), if
+/// any, rather than being added to it: it captures all the levels regardless of the filter of the
+/// plugin, and the outputs of the plugin don't receive the logs while the capture is installed.
+/// The logs are also written to the output of the test, which is shown when it fails. The capture
+/// stops when it's dropped.
+///
+/// The logs written with the `log` crate, such as the ones of `bevy_app`, are only captured once
+/// they are forwarded to `tracing`, which a `LogPlugin` does when it's built. The capture doesn't
+/// forward them by itself, since that sets the global logger of the process, see
+/// [`forward_log_crate`](Self::forward_log_crate).
+#[derive(Debug)]
+pub struct LogCapture {
+    records: Arc<Mutex<Vec<LogRecord>>>,
+    _guard: DefaultGuard,
+}
+
+impl LogCapture {
+    /// Starts capturing the logs written on this thread.
+    pub fn install() -> Self {
+        let records = Arc::new(Mutex::new(Vec::new()));
+        let subscriber = Registry::default()
+            .with(CaptureLayer {
+                records: records.clone(),
+            })
+            .with(fmt::layer().with_test_writer().with_ansi(false));
+        Self {
+            records,
+            _guard: tracing::subscriber::set_default(subscriber),
+        }
+    }
+
+    /// Starts capturing the logs written on this thread, like [`install`](Self::install), and
+    /// switches the schedules of `app` using the multi-threaded executor to the single-threaded
+    /// one, so that all its systems run on this thread.
+    ///
+    /// The schedules are only switched once: install the capture after the systems are added.
+    /// This changes the order in which the systems that aren't ordered run, so the multi-threaded
+    /// executor should be kept when that matters.
+    pub fn install_single_threaded(app: &mut App) -> Self {
+        if let Some(mut schedules) = app.world_mut().get_resource_mut::<Schedules>() {
+            for (_, schedule) in schedules.iter_mut() {
+                if schedule.get_executor_kind() == ExecutorKind::MultiThreaded {
+                    schedule.set_executor_kind(ExecutorKind::SingleThreaded);
+                }
+            }
+        }
+        Self::install()
+    }
+
+    /// Forwards the logs written with the `log` crate to `tracing`, so that they are captured, in
+    /// a test that doesn't build a [`LogPlugin`](crate::LogPlugin).
+    ///
+    /// The logs are then forwarded to the subscriber of the thread that wrote them. This sets the
+    /// global logger of the process, so a `LogPlugin` built afterwards in the same process reports
+    /// that the logger is already set. This does nothing if the logger is already set, for
+    /// example by a `LogPlugin`.
+    pub fn forward_log_crate() {
+        let _ = LogTracer::init();
+    }
+
+    /// Returns the logs captured so far, from the oldest to the most recent.
+    pub fn records(&self) -> Vec<LogRecord> {
+        self.lock().clone()
+    }
+
+    /// Forgets the logs captured so far.
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    /// Returns `true` if a log of `level` containing `text` in its message was captured.
+    pub fn contains(&self, level: Level, text: &str) -> bool {
+        self.lock()
+            .iter()
+            .any(|record| record.level == level && record.message.contains(text))
+    }
+
+    /// Panics if no log of `level` containing `text` in its message was captured.
+    #[track_caller]
+    pub fn assert_contains(&self, level: Level, text: &str) {
+        if !self.contains(level, text) {
+            panic!(
+                "expected a {level} log containing {text:?}, captured:\n{}",
+                self.describe()
+            );
+        }
+    }
+
+    /// Panics if a log of `level` containing `text` in its message was captured.
+    #[track_caller]
+    pub fn assert_not_contains(&self, level: Level, text: &str) {
+        if self.contains(level, text) {
+            panic!(
+                "expected no {level} log containing {text:?}, captured:\n{}",
+                self.describe()
+            );
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Vec<LogRecord>> {
+        self.records.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Lists the captured logs, one per line.
+    fn describe(&self) -> String {
+        self.lock()
+            .iter()
+            .map(|record| {
+                format!(
+                    "{:>5} {}: {}\n",
+                    record.level, record.target, record.message
+                )
+            })
+            .collect()
+    }
+}
+
+/// Adds the logs to the records of a [`LogCapture`].
+struct CaptureLayer {
+    records: Arc<Mutex<Vec<LogRecord>>>,
+}
+
+impl<S: Subscriber> Layer<S> for CaptureLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        self.records
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(log_record(event));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LogCapture;
+    use crate::Level;
+    use bevy_app::{App, Update};
+    use bevy_ecs::schedule::{ExecutorKind, IntoScheduleConfigs};
+    use std::thread;
+    use tracing::{info, warn};
+
+    fn warn_twice() {
+        warn!("invalid config");
+        warn!("invalid config");
+    }
+
+    #[test]
+    fn logs_of_systems_are_captured() {
+        let mut app = App::new();
+        app.add_systems(Update, (warn_twice, || info!("loaded")).chain());
+        let capture = LogCapture::install_single_threaded(&mut app);
+        app.update();
+
+        capture.assert_contains(Level::WARN, "invalid config");
+        capture.assert_contains(Level::INFO, "loaded");
+        capture.assert_not_contains(Level::ERROR, "invalid config");
+        assert_eq!(capture.records().len(), 3);
+        assert_eq!(capture.records()[0].target, module_path!());
+
+        capture.clear();
+        assert!(capture.records().is_empty());
+        app.update();
+        assert_eq!(capture.records().len(), 3);
+    }
+
+    #[test]
+    fn captures_are_per_thread() {
+        let mut app = App::new();
+        app.add_systems(Update, warn_twice);
+        let capture = LogCapture::install_single_threaded(&mut app);
+        thread::spawn(|| {
+            let mut app = App::new();
+            app.add_systems(Update, || warn!("other test"));
+            let capture = LogCapture::install_single_threaded(&mut app);
+            app.update();
+            capture.assert_contains(Level::WARN, "other test");
+            capture.assert_not_contains(Level::WARN, "invalid config");
+        })
+        .join()
+        .unwrap();
+        app.update();
+        capture.assert_not_contains(Level::WARN, "other test");
+
+        let schedule = app.get_schedule(Update).unwrap();
+        assert_eq!(schedule.get_executor_kind(), ExecutorKind::SingleThreaded);
+    }
+
+    #[test]
+    fn executors_are_only_switched_on_request() {
+        let mut app = App::new();
+        app.add_systems(Update, warn_twice);
+        let executor = app.get_schedule(Update).unwrap().get_executor_kind();
+        let _capture = LogCapture::install();
+        assert_eq!(
+            app.get_schedule(Update).unwrap().get_executor_kind(),
+            executor
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "expected a WARN log containing \"missing\"")]
+    fn missing_logs_panic() {
+        let capture = LogCapture::install();
+        info!("missing");
+        capture.assert_contains(Level::WARN, "missing");
+    }
+}
@@ -0,0 +1,257 @@
+use bevy_platform::time::{Instant, SystemTime};
+use core::fmt::{self, Write};
+use tracing_subscriber::fmt::{format::Writer, time::FormatTime};
+
+/// The timestamps of the logs written by the [`LogPlugin`](crate::LogPlugin) to the console and
+/// to its [log file](crate::FileLogConfig).
+///
+/// The timestamps aren't used by the [`LogPlugin::fmt_layer`](crate::LogPlugin::fmt_layer)
+/// replacing the console output, which can use a [`LogTimer`] to get the same ones. The browser
+/// console, used on the web, shows its own timestamps.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TimestampMode {
+    /// No timestamps, for example to diff the logs of two runs in CI.
+    None,
+    /// The time since the [`LogPlugin`](crate::LogPlugin) was built, in seconds, for example to
+    /// debug the timing of frames.
+    Uptime,
+    /// The UTC date and time, formatted with the given format string.
+    ///
+    /// The format string supports the following specifiers, other characters are written as is:
+    ///
+    /// | Specifier | Example      | Description                              |
+    /// |-----------|--------------|------------------------------------------|
+    /// | `%Y`      | `2025`       | The year.                                |
+    /// | `%m`      | `07`         | The month, from `01` to `12`.            |
+    /// | `%d`      | `08`         | The day of the month, from `01` to `31`. |
+    /// | `%H`      | `00`         | The hour, from `00` to `23`.             |
+    /// | `%M`      | `34`         | The minute, from `00` to `59`.           |
+    /// | `%S`      | `59`         | The second, from `00` to `59`.           |
+    /// | `%.3f`    | `.026`       | The milliseconds, with a leading dot.    |
+    /// | `%.6f`    | `.026490`    | The microseconds, with a leading dot.    |
+    /// | `%.9f`    | `.026490708` | The nanoseconds, with a leading dot.     |
+    /// | `%%`      | `%`          | A literal `%`.                           |
+    ///
+    /// [`TimestampMode::RFC3339`] and [`TimestampMode::RFC3339_MILLIS`] are common formats.
+    SystemTime(String),
+}
+
+impl TimestampMode {
+    /// The [RFC 3339](https://datatracker.ietf.org/doc/html/rfc3339) format with microseconds,
+    /// `2025-07-08T00:34:59.026490Z`, used by default.
+    pub const RFC3339: &'static str = "%Y-%m-%dT%H:%M:%S%.6fZ";
+
+    /// The [RFC 3339](https://datatracker.ietf.org/doc/html/rfc3339) format with milliseconds,
+    /// `2025-07-08T00:34:59.026Z`.
+    pub const RFC3339_MILLIS: &'static str = "%Y-%m-%dT%H:%M:%S%.3fZ";
+
+    /// Returns the [`LogTimer`] writing these timestamps, or `None` if there are no timestamps.
+    ///
+    /// The uptime of the timer starts when it is created.
+    pub fn timer(&self) -> Option<LogTimer> {
+        match self {
+            TimestampMode::None => None,
+            TimestampMode::Uptime => Some(LogTimer::Uptime(Instant::now())),
+            TimestampMode::SystemTime(format) => Some(LogTimer::SystemTime(format.clone())),
+        }
+    }
+}
+
+impl Default for TimestampMode {
+    fn default() -> Self {
+        TimestampMode::SystemTime(TimestampMode::RFC3339.to_string())
+    }
+}
+
+/// Writes the timestamps of a [`TimestampMode`], as a [`FormatTime`] of a
+/// [`tracing_subscriber::fmt::Layer`].
+#[derive(Clone, Debug)]
+pub enum LogTimer {
+    /// Writes the time since the given instant.
+    Uptime(Instant),
+    /// Writes the UTC date and time with the given format string.
+    SystemTime(String),
+}
+
+impl FormatTime for LogTimer {
+    fn format_time(&self, w: &mut Writer<'_>) -> fmt::Result {
+        match self {
+            LogTimer::Uptime(start) => {
+                let uptime = start.elapsed();
+                write!(w, "{:4}.{:06}s", uptime.as_secs(), uptime.subsec_micros())
+            }
+            LogTimer::SystemTime(format) => write_system_time(w, format, SystemTime::now()),
+        }
+    }
+}
+
+/// Writes `time` in UTC with the `format` of a [`TimestampMode::SystemTime`].
+fn write_system_time(w: &mut impl Write, format: &str, time: SystemTime) -> fmt::Result {
+    // Times before the Unix epoch are written as the epoch.
+    let since_epoch = time
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap_or_default();
+    let seconds = since_epoch.as_secs();
+    let (year, month, day) = civil_from_days(seconds / 86_400);
+    let seconds_of_day = seconds % 86_400;
+    let nanos = since_epoch.subsec_nanos();
+
+    let mut chars = format.chars();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            w.write_char(c)?;
+            continue;
+        }
+        let rest = chars.as_str();
+        let (written, len) = match rest.as_bytes() {
+            [b'Y', ..] => (write!(w, "{year:04}"), 1),
+            [b'm', ..] => (write!(w, "{month:02}"), 1),
+            [b'd', ..] => (write!(w, "{day:02}"), 1),
+            [b'H', ..] => (write!(w, "{:02}", seconds_of_day / 3600), 1),
+            [b'M', ..] => (write!(w, "{:02}", seconds_of_day / 60 % 60), 1),
+            [b'S', ..] => (write!(w, "{:02}", seconds_of_day % 60), 1),
+            [b'.', b'3', b'f', ..] => (write!(w, ".{:03}", nanos / 1_000_000), 3),
+            [b'.', b'6', b'f', ..] => (write!(w, ".{:06}", nanos / 1_000), 3),
+            [b'.', b'9', b'f', ..] => (write!(w, ".{nanos:09}"), 3),
+            [b'%', ..] => (w.write_char('%'), 1),
+            // Unknown specifiers are written as is.
+            _ => (w.write_char('%'), 0),
+        };
+        written?;
+        chars = rest[len..].chars();
+    }
+    Ok(())
+}
+
+/// Returns the year, month and day of the given number of days since the Unix epoch, in the
+/// proleptic Gregorian calendar.
+///
+/// See <https://howardhinnant.github.io/date_algorithms.html#civil_from_days>.
+fn civil_from_days(days: u64) -> (u64, u64, u64) {
+    // Shifts the epoch to 0000-03-01, so that leap days are at the end of the years, which are
+    // grouped in eras of 400 years.
+    let days = days + 719_468;
+    let era = days / 146_097;
+    let day_of_era = days % 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    // The month, starting from March.
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = era * 400 + year_of_era + u64::from(month <= 2);
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::sync::Arc;
+    use bevy_platform::time::SystemTime;
+    use core::time::Duration;
+    use std::{
+        io::{self, Write},
+        sync::Mutex,
+    };
+    use tracing::{info, info_span};
+    use tracing_subscriber::{fmt::format::DefaultFields, prelude::*, Registry};
+
+    use super::{write_system_time, TimestampMode};
+    use crate::{format::format_layer, LevelStyle, LogFormat};
+
+    struct Capture(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Capture {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn capture(timestamps: TimestampMode) -> String {
+        let output = Arc::new(Mutex::new(Vec::new()));
+        let writer = {
+            let output = output.clone();
+            move || Capture(output.clone())
+        };
+        let layer = format_layer(
+            LogFormat::Text,
+            false,
+            LevelStyle::default(),
+            timestamps.timer(),
+            DefaultFields::default(),
+            writer,
+        );
+        tracing::subscriber::with_default(Registry::default().with(layer), || {
+            let _frame = info_span!("frame", number = 3).entered();
+            info!(answer = 42, "Computed the answer");
+        });
+        let output = output.lock().unwrap();
+        String::from_utf8(output.clone()).unwrap()
+    }
+
+    const LOG: &str = " INFO frame{number=3}: bevy_log::timestamp::tests: Computed the answer \
+        answer=42\n";
+
+    fn format(format: &str, since_epoch: Duration) -> String {
+        let mut output = String::new();
+        write_system_time(&mut output, format, SystemTime::UNIX_EPOCH + since_epoch).unwrap();
+        output
+    }
+
+    #[test]
+    fn no_timestamps() {
+        assert_eq!(capture(TimestampMode::None), LOG);
+    }
+
+    #[test]
+    fn uptime_timestamps() {
+        let output = capture(TimestampMode::Uptime);
+        let (uptime, log) = output.split_once("s ").unwrap();
+        assert_eq!(log, LOG);
+        assert!(uptime.starts_with("   0."), "{uptime}");
+        assert_eq!(uptime.len(), "   0.000000".len());
+    }
+
+    #[test]
+    fn system_time_timestamps() {
+        let output = capture(TimestampMode::SystemTime(
+            TimestampMode::RFC3339_MILLIS.into(),
+        ));
+        let (timestamp, log) = output.split_once("Z ").unwrap();
+        assert_eq!(log, LOG);
+        assert_eq!(timestamp.len(), "2025-07-08T00:34:59.026".len());
+        assert_eq!(timestamp.as_bytes()[10], b'T');
+    }
+
+    #[test]
+    fn system_time_formats() {
+        // 2025-07-08T00:34:59.026490708Z
+        let time = Duration::new(1_751_934_899, 26_490_708);
+        assert_eq!(
+            format(TimestampMode::RFC3339, time),
+            "2025-07-08T00:34:59.026490Z"
+        );
+        assert_eq!(
+            format(TimestampMode::RFC3339_MILLIS, time),
+            "2025-07-08T00:34:59.026Z"
+        );
+        assert_eq!(
+            format("%d/%m/%Y %H:%M:%S%.9f 100%% %q", time),
+            "08/07/2025 00:34:59.026490708 100% %q"
+        );
+        assert_eq!(format("%Y-%m-%d", Duration::ZERO), "1970-01-01");
+        // A leap day.
+        assert_eq!(
+            format("%Y-%m-%d", Duration::from_secs(951_782_400)),
+            "2000-02-29"
+        );
+    }
+}
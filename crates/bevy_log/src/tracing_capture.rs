@@ -0,0 +1,229 @@
+use crate::BoxedLayer;
+use bevy_app::App;
+use bevy_ecs::resource::Resource;
+use core::{error::Error, fmt};
+use std::io;
+#[cfg(feature = "tracing-chrome")]
+use {
+    bevy_platform::cell::SyncCell,
+    std::{fs::File, io::BufWriter, path::Path},
+    tracing_chrome::{ChromeLayer, ChromeLayerBuilder, EventOrSpan, FlushGuard},
+    tracing_subscriber::{
+        fmt::{format::DefaultFields, FormattedFields},
+        registry::Registry,
+        reload,
+    },
+};
+#[cfg(feature = "tracing-tracy")]
+use {
+    core::sync::atomic::{AtomicBool, Ordering},
+    tracing_subscriber::{filter::DynFilterFn, Layer},
+};
+
+/// Whether the Tracy layer sends the spans and events to the profiler.
+#[cfg(feature = "tracing-tracy")]
+static TRACY_CAPTURING: AtomicBool = AtomicBool::new(true);
+
+/// Starts and stops the capture of the spans and events by the tracing backends of the
+/// `trace_chrome` and `trace_tracy` features while the app is running, for example to only
+/// capture a few seconds from a debug menu when a performance issue shows up, instead of a whole
+/// play session.
+///
+/// The captures run from the start of the app. The Chrome trace is written to the file of the
+/// `TRACE_CHROME` environment variable, or to `./trace-{timestamp}.json`. Stopping a Chrome
+/// capture finishes its file, so that it can be opened in a trace viewer while the app keeps
+/// running.
+///
+/// To also skip the cost of the spans of the schedules and systems while not capturing, disable
+/// them with the [`TraceSpanConfig`](bevy_ecs::schedule::TraceSpanConfig).
+///
+/// ```no_run
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_log::TracingCapture;
+/// fn toggle_capture(mut capture: ResMut<TracingCapture>) {
+///     if capture.is_capturing() {
+///         capture.stop();
+///     } else {
+///         capture.start_chrome("capture.json").unwrap();
+///     }
+/// }
+/// ```
+///
+/// This resource is inserted by the [`LogPlugin`](crate::LogPlugin) on desktop platforms, and
+/// dropping it finishes the Chrome trace.
+#[derive(Resource)]
+pub struct TracingCapture {
+    #[cfg(feature = "tracing-chrome")]
+    chrome: ChromeCapture,
+}
+
+/// The slot of the Chrome layer, and the guard finishing the trace of its current capture.
+#[cfg(feature = "tracing-chrome")]
+struct ChromeCapture {
+    handle: reload::Handle<Option<ChromeLayer<Registry>>, Registry>,
+    guard: Option<SyncCell<FlushGuard>>,
+}
+
+impl TracingCapture {
+    /// Returns `true` if any of the backends is currently capturing.
+    pub fn is_capturing(&self) -> bool {
+        #[cfg(feature = "tracing-chrome")]
+        if self.is_capturing_chrome() {
+            return true;
+        }
+        #[cfg(feature = "tracing-tracy")]
+        if self.is_capturing_tracy() {
+            return true;
+        }
+        false
+    }
+
+    /// Returns `true` if the spans and events are currently written to a Chrome trace.
+    #[cfg(feature = "tracing-chrome")]
+    pub fn is_capturing_chrome(&self) -> bool {
+        self.chrome.guard.is_some()
+    }
+
+    /// Starts writing the spans and events to a new Chrome trace, in the file at `path`.
+    ///
+    /// Returns an error if a Chrome capture is already running, or if the file can't be created.
+    #[cfg(feature = "tracing-chrome")]
+    pub fn start_chrome(&mut self, path: impl AsRef<Path>) -> Result<(), TracingCaptureError> {
+        if self.is_capturing_chrome() {
+            return Err(TracingCaptureError::AlreadyCapturing);
+        }
+        let file = File::create(path).map_err(TracingCaptureError::Io)?;
+        let (layer, guard) = chrome_layer(ChromeLayerBuilder::new().writer(BufWriter::new(file)));
+        self.chrome
+            .handle
+            .reload(Some(layer))
+            .map_err(|_| TracingCaptureError::NotInstalled)?;
+        self.chrome.guard = Some(SyncCell::new(guard));
+        Ok(())
+    }
+
+    /// Returns `true` if the spans and events are currently sent to Tracy.
+    #[cfg(feature = "tracing-tracy")]
+    pub fn is_capturing_tracy(&self) -> bool {
+        TRACY_CAPTURING.load(Ordering::Relaxed)
+    }
+
+    /// Starts sending the spans and events to Tracy.
+    ///
+    /// Returns an error if they are already sent.
+    #[cfg(feature = "tracing-tracy")]
+    pub fn start_tracy(&mut self) -> Result<(), TracingCaptureError> {
+        if TRACY_CAPTURING.swap(true, Ordering::Relaxed) {
+            return Err(TracingCaptureError::AlreadyCapturing);
+        }
+        Ok(())
+    }
+
+    /// Stops all the captures, finishing the Chrome trace. Does nothing if nothing is captured.
+    pub fn stop(&mut self) {
+        #[cfg(feature = "tracing-chrome")]
+        if let Some(guard) = self.chrome.guard.take() {
+            // The layer is removed before the guard finishing the trace is dropped, so that
+            // nothing is written to it afterwards.
+            let _ = self.chrome.handle.reload(None);
+            drop(guard);
+        }
+        #[cfg(feature = "tracing-tracy")]
+        TRACY_CAPTURING.store(false, Ordering::Relaxed);
+    }
+}
+
+impl fmt::Debug for TracingCapture {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug = f.debug_struct("TracingCapture");
+        #[cfg(feature = "tracing-chrome")]
+        debug.field("chrome", &self.is_capturing_chrome());
+        #[cfg(feature = "tracing-tracy")]
+        debug.field("tracy", &self.is_capturing_tracy());
+        debug.finish()
+    }
+}
+
+/// An error starting a capture with the [`TracingCapture`].
+#[derive(Debug)]
+pub enum TracingCaptureError {
+    /// The backend is already capturing. Stop it before starting a new capture.
+    AlreadyCapturing,
+    /// The file of the capture couldn't be created.
+    Io(io::Error),
+    /// The [`LogPlugin`](crate::LogPlugin) couldn't set the global tracing subscriber, so the
+    /// capture can't be started.
+    NotInstalled,
+}
+
+impl fmt::Display for TracingCaptureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TracingCaptureError::AlreadyCapturing => write!(f, "a capture is already running"),
+            TracingCaptureError::Io(err) => write!(f, "could not create the capture file: {err}"),
+            TracingCaptureError::NotInstalled => {
+                write!(f, "the tracing subscriber of the LogPlugin isn't installed")
+            }
+        }
+    }
+}
+
+impl Error for TracingCaptureError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            TracingCaptureError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// Creates the layers of the tracing backends, capturing from the start of the app, and adds the
+/// [`TracingCapture`] controlling them to `app`.
+pub(crate) fn capture_layers(app: &mut App) -> Vec<BoxedLayer> {
+    let mut layers: Vec<BoxedLayer> = Vec::new();
+
+    // The Chrome layer is in a reloadable slot so that a new one can be created for each
+    // capture, with its own file.
+    #[cfg(feature = "tracing-chrome")]
+    let chrome = {
+        let mut builder = ChromeLayerBuilder::new();
+        if let Ok(path) = std::env::var("TRACE_CHROME") {
+            builder = builder.file(path);
+        }
+        let (layer, guard) = chrome_layer(builder);
+        let (layer, handle) = reload::Layer::new(Some(layer));
+        layers.push(Box::new(layer));
+        ChromeCapture {
+            handle,
+            guard: Some(SyncCell::new(guard)),
+        }
+    };
+
+    #[cfg(feature = "tracing-tracy")]
+    layers.push(Box::new(tracing_tracy::TracyLayer::default().with_filter(
+        DynFilterFn::new(|_, _| TRACY_CAPTURING.load(Ordering::Relaxed)),
+    )));
+
+    app.insert_resource(TracingCapture {
+        #[cfg(feature = "tracing-chrome")]
+        chrome,
+    });
+    layers
+}
+
+/// Builds a Chrome layer naming the spans with their fields.
+#[cfg(feature = "tracing-chrome")]
+fn chrome_layer(builder: ChromeLayerBuilder<Registry>) -> (ChromeLayer<Registry>, FlushGuard) {
+    builder
+        .name_fn(Box::new(|event_or_span| match event_or_span {
+            EventOrSpan::Event(event) => event.metadata().name().into(),
+            EventOrSpan::Span(span) => {
+                if let Some(fields) = span.extensions().get::<FormattedFields<DefaultFields>>() {
+                    format!("{}: {}", span.metadata().name(), fields.fields.as_str())
+                } else {
+                    span.metadata().name().into()
+                }
+            }
+        }))
+        .build()
+}
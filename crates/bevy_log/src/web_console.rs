@@ -0,0 +1,281 @@
+use crate::Level;
+use core::fmt::{self, Write};
+use std::sync::{Mutex, PoisonError};
+use tracing::{
+    field::{Field, Visit},
+    span::Id,
+    Event, Subscriber,
+};
+use tracing_subscriber::{layer::Context, registry::LookupSpan, Layer};
+use wasm_bindgen::JsValue;
+use web_sys::console;
+
+/// Writes the logs to the browser console, with the `console` method of their level, so that
+/// they can be filtered by severity in the devtools.
+///
+/// The layer is added after the filter of the [`LogPlugin`](crate::LogPlugin), so that the logs
+/// filtered out are never formatted.
+pub(crate) struct WebConsoleLayer<C = BrowserConsole> {
+    console: C,
+    /// The spans whose `console.group` is open, from the outermost to the innermost, if the logs
+    /// are grouped by span.
+    groups: Option<Mutex<Vec<Id>>>,
+}
+
+impl WebConsoleLayer {
+    pub(crate) fn new(group_spans: bool) -> Self {
+        Self::with_console(BrowserConsole, group_spans)
+    }
+}
+
+impl<C: Console> WebConsoleLayer<C> {
+    fn with_console(console: C, group_spans: bool) -> Self {
+        Self {
+            console,
+            groups: group_spans.then(Mutex::default),
+        }
+    }
+
+    /// Closes the groups of `id` and of the spans it contains.
+    fn close_group(&self, id: &Id) {
+        let Some(groups) = &self.groups else {
+            return;
+        };
+        let mut groups = groups.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(index) = groups.iter().position(|group| group == id) {
+            for _ in index..groups.len() {
+                self.console.group_end();
+            }
+            groups.truncate(index);
+        }
+    }
+}
+
+impl<C: Console, S: Subscriber + for<'a> LookupSpan<'a>> Layer<S> for WebConsoleLayer<C> {
+    fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+        if let Some(groups) = &self.groups {
+            let mut groups = groups.lock().unwrap_or_else(PoisonError::into_inner);
+            let scope: Vec<_> = ctx
+                .event_scope(event)
+                .map(|scope| scope.from_root().collect())
+                .unwrap_or_default();
+            // Closes the groups of the spans the event isn't in, then opens the missing ones.
+            let shared = groups
+                .iter()
+                .zip(&scope)
+                .take_while(|(group, span)| **group == span.id())
+                .count();
+            for _ in shared..groups.len() {
+                self.console.group_end();
+            }
+            groups.truncate(shared);
+            for span in &scope[shared..] {
+                self.console.group(span.name());
+                groups.push(span.id());
+            }
+        }
+
+        let metadata = event.metadata();
+        let mut visitor = MessageVisitor::default();
+        event.record(&mut visitor);
+        self.console.log(
+            ConsoleMethod::for_level(*metadata.level()),
+            metadata.level().as_str(),
+            metadata.target(),
+            &visitor.message,
+        );
+    }
+
+    fn on_exit(&self, id: &Id, _ctx: Context<'_, S>) {
+        self.close_group(id);
+    }
+
+    fn on_close(&self, id: Id, _ctx: Context<'_, S>) {
+        self.close_group(&id);
+    }
+}
+
+/// Where a [`WebConsoleLayer`] writes the logs, the [`BrowserConsole`] outside of the tests.
+pub(crate) trait Console: Send + Sync + 'static {
+    /// Opens a group named `name`, with `console.group`.
+    fn group(&self, name: &str);
+
+    /// Closes the innermost group, with `console.groupEnd`.
+    fn group_end(&self);
+
+    /// Logs `message` with `method`, after the level and target of the log.
+    fn log(&self, method: ConsoleMethod, level: &str, target: &str, message: &str);
+}
+
+/// The `console` of the browser.
+pub(crate) struct BrowserConsole;
+
+impl Console for BrowserConsole {
+    fn group(&self, name: &str) {
+        console::group_1(&JsValue::from_str(name));
+    }
+
+    fn group_end(&self) {
+        console::group_end();
+    }
+
+    fn log(&self, method: ConsoleMethod, level: &str, target: &str, message: &str) {
+        let log = match method {
+            ConsoleMethod::Error => console::error_5,
+            ConsoleMethod::Warn => console::warn_5,
+            ConsoleMethod::Info => console::info_5,
+            ConsoleMethod::Debug => console::debug_5,
+        };
+        // The message is passed as an argument, so that the `%` it may contain aren't replaced by
+        // the console.
+        log(
+            &JsValue::from_str(&format!("%c{level}%c {target}%c %s")),
+            &JsValue::from_str(method.level_style()),
+            &JsValue::from_str("color: gray"),
+            &JsValue::from_str(""),
+            &JsValue::from_str(message),
+        );
+    }
+}
+
+/// The method of the browser `console` a log is written with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum ConsoleMethod {
+    Error,
+    Warn,
+    Info,
+    Debug,
+}
+
+impl ConsoleMethod {
+    fn for_level(level: Level) -> Self {
+        match level {
+            Level::ERROR => ConsoleMethod::Error,
+            Level::WARN => ConsoleMethod::Warn,
+            Level::INFO => ConsoleMethod::Info,
+            // The browsers only show `console.debug` messages in their most verbose level.
+            _ => ConsoleMethod::Debug,
+        }
+    }
+
+    /// The CSS style of the level, in the prefix of the log.
+    fn level_style(self) -> &'static str {
+        match self {
+            ConsoleMethod::Error => "color: red; font-weight: bold",
+            ConsoleMethod::Warn => "color: orange; font-weight: bold",
+            ConsoleMethod::Info => "color: green; font-weight: bold",
+            ConsoleMethod::Debug => "color: blue; font-weight: bold",
+        }
+    }
+}
+
+/// Formats the message of a log, followed by its other fields.
+#[derive(Default)]
+struct MessageVisitor {
+    message: String,
+}
+
+impl Visit for MessageVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "message" {
+            // The message comes before the other fields.
+            self.message.insert_str(0, &format!("{value:?}"));
+        } else {
+            let _ = write!(self.message, " {}={value:?}", field.name());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+    use tracing::{debug, error, info_span, warn};
+    use tracing_subscriber::{prelude::*, Registry};
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    use super::{Console, ConsoleMethod, WebConsoleLayer};
+    use crate::Level;
+
+    #[derive(Debug, PartialEq)]
+    enum Call {
+        Group(String),
+        GroupEnd,
+        Log(ConsoleMethod, String),
+    }
+
+    /// Records the calls to the console.
+    #[derive(Clone, Default)]
+    struct MockConsole(Arc<Mutex<Vec<Call>>>);
+
+    impl Console for MockConsole {
+        fn group(&self, name: &str) {
+            self.0.lock().unwrap().push(Call::Group(name.to_string()));
+        }
+
+        fn group_end(&self) {
+            self.0.lock().unwrap().push(Call::GroupEnd);
+        }
+
+        fn log(&self, method: ConsoleMethod, level: &str, target: &str, message: &str) {
+            assert_eq!(target, module_path!());
+            let message = format!("{level} {message}");
+            self.0.lock().unwrap().push(Call::Log(method, message));
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn levels_are_mapped_to_console_methods() {
+        assert_eq!(ConsoleMethod::for_level(Level::ERROR), ConsoleMethod::Error);
+        assert_eq!(ConsoleMethod::for_level(Level::WARN), ConsoleMethod::Warn);
+        assert_eq!(ConsoleMethod::for_level(Level::INFO), ConsoleMethod::Info);
+        assert_eq!(ConsoleMethod::for_level(Level::DEBUG), ConsoleMethod::Debug);
+        assert_eq!(ConsoleMethod::for_level(Level::TRACE), ConsoleMethod::Debug);
+    }
+
+    fn write_logs(group_spans: bool) -> Vec<Call> {
+        let console = MockConsole::default();
+        let layer = WebConsoleLayer::with_console(console.clone(), group_spans);
+        let subscriber = Registry::default().with(layer);
+        tracing::subscriber::with_default(subscriber, || {
+            let frame = info_span!("frame").entered();
+            {
+                let _system = info_span!("system").entered();
+                warn!(answer = 42, "The answer is {}", 42);
+            }
+            error!("No more system");
+            drop(frame);
+            debug!("No more frame");
+        });
+        core::mem::take(&mut *console.0.lock().unwrap())
+    }
+
+    #[wasm_bindgen_test]
+    fn logs_are_written_in_span_groups() {
+        assert_eq!(
+            write_logs(true),
+            [
+                Call::Group("frame".to_string()),
+                Call::Group("system".to_string()),
+                Call::Log(
+                    ConsoleMethod::Warn,
+                    "WARN The answer is 42 answer=42".to_string()
+                ),
+                Call::GroupEnd,
+                Call::Log(ConsoleMethod::Error, "ERROR No more system".to_string()),
+                Call::GroupEnd,
+                Call::Log(ConsoleMethod::Debug, "DEBUG No more frame".to_string()),
+            ]
+        );
+        assert_eq!(
+            write_logs(false),
+            [
+                Call::Log(
+                    ConsoleMethod::Warn,
+                    "WARN The answer is 42 answer=42".to_string()
+                ),
+                Call::Log(ConsoleMethod::Error, "ERROR No more system".to_string()),
+                Call::Log(ConsoleMethod::Debug, "DEBUG No more frame".to_string()),
+            ]
+        );
+    }
+}
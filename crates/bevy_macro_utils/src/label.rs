@@ -58,6 +58,28 @@ pub fn derive_label(
     input: syn::DeriveInput,
     trait_name: &str,
     trait_path: &syn::Path,
+) -> TokenStream {
+    derive_label_with_methods(
+        input,
+        trait_name,
+        trait_path,
+        proc_macro2::TokenStream::new(),
+    )
+}
+
+/// Derive a label trait, like [`derive_label`], implementing additional methods of the trait.
+///
+/// # Args
+///
+/// - `input`: The [`syn::DeriveInput`] for struct that is deriving the label trait
+/// - `trait_name`: Name of the label trait
+/// - `trait_path`: The [path](`syn::Path`) to the label trait
+/// - `extra_methods`: The implementations of the additional methods
+pub fn derive_label_with_methods(
+    input: syn::DeriveInput,
+    trait_name: &str,
+    trait_path: &syn::Path,
+    extra_methods: proc_macro2::TokenStream,
 ) -> TokenStream {
     if let syn::Data::Union(_) = &input.data {
         let message = format!("Cannot derive {trait_name} for unions.");
@@ -85,6 +107,8 @@ pub fn derive_label(
             extern crate alloc;
 
             impl #impl_generics #trait_path for #ident #ty_generics #where_clause {
+                #extra_methods
+
                 fn dyn_clone(&self) -> alloc::boxed::Box<dyn #trait_path> {
                     alloc::boxed::Box::new(::core::clone::Clone::clone(self))
                 }
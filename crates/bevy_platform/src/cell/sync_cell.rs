@@ -4,7 +4,7 @@
 //!
 //! [`std::sync::Exclusive`]: https://doc.rust-lang.org/nightly/std/sync/struct.Exclusive.html
 
-use core::ptr;
+use core::{fmt, ptr};
 
 /// See [`Exclusive`](https://github.com/rust-lang/rust/issues/98407) for stdlib's upcoming implementation,
 /// which should replace this one entirely.
@@ -33,6 +33,13 @@ impl<T: ?Sized> SyncCell<T> {
         &mut self.inner
     }
 
+    /// Get a mutable reference to this `SyncCell`'s inner value, like [`get`](SyncCell::get).
+    ///
+    /// This mirrors the name of the stdlib's `Exclusive::get_mut`.
+    pub fn get_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+
     /// For types that implement [`Sync`], get shared access to this `SyncCell`'s inner value.
     pub fn read(&self) -> &T
     where
@@ -49,6 +56,25 @@ impl<T: ?Sized> SyncCell<T> {
     }
 }
 
+impl<T: Default> Default for SyncCell<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T> From<T> for SyncCell<T> {
+    fn from(inner: T) -> Self {
+        Self::new(inner)
+    }
+}
+
+impl<T: ?Sized> fmt::Debug for SyncCell<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The inner value can't be accessed through a shared reference.
+        f.debug_struct("SyncCell").finish_non_exhaustive()
+    }
+}
+
 // SAFETY: `Sync` only allows multithreaded access via immutable reference.
 // As `SyncCell` requires an exclusive reference to access the wrapped value for `!Sync` types,
 // marking this type as `Sync` does not actually allow unsynchronized access to the inner value.
@@ -1,13 +1,17 @@
-//! Provides `Instant` for all platforms.
+//! Provides `Instant` for all platforms, and `SystemTime` for the platforms with a system clock.
 
 pub use time::Instant;
 
 crate::cfg::switch! {
     crate::cfg::web => {
         use web_time as time;
+
+        pub use time::SystemTime;
     }
     crate::cfg::std => {
         use std::time;
+
+        pub use time::SystemTime;
     }
     _ => {
         mod fallback;
@@ -1,6 +1,8 @@
 use async_channel::{Receiver, Sender};
 
-use bevy_app::{App, AppExit, AppLabel, Plugin, SubApp};
+use bevy_app::{
+    spawn_app_thread, App, AppExit, AppLabel, AppShutdown, Plugin, ShutdownReason, SubApp,
+};
 use bevy_ecs::{
     resource::Resource,
     schedule::MainThreadExecutor,
@@ -146,7 +148,7 @@ impl Plugin for PipelinedRenderingPlugin {
             render_to_app_receiver,
         ));
 
-        std::thread::spawn(move || {
+        spawn_app_thread("bevy-render", move || {
             #[cfg(feature = "trace")]
             let _span = tracing::info_span!("render thread").entered();
 
@@ -197,6 +199,12 @@ fn renderer_extract(app_world: &mut World, _world: &mut World) {
                 render_channels.send_blocking(render_app);
             } else {
                 // Renderer thread panicked
+                if let Some(mut shutdown) = world.get_resource_mut::<AppShutdown>() {
+                    shutdown.request_with_message(
+                        ShutdownReason::PluginFailure,
+                        "The render thread panicked",
+                    );
+                }
                 world.write_event(AppExit::error());
             }
         });
@@ -0,0 +1,655 @@
+use crate::ron::{self, error::SpannedError, value::RawValue};
+use alloc::collections::BTreeMap;
+use bevy_app::{App, Plugin, PreStartup};
+use bevy_ecs::{
+    entity::Entity,
+    hierarchy::ChildOf,
+    reflect::{AppTypeRegistry, ReflectComponent, ReflectResource},
+    resource::Resource,
+    world::World,
+};
+use bevy_platform::collections::HashMap;
+use bevy_reflect::{
+    serde::TypedReflectDeserializer, PartialReflect, ReflectPath, TypeRegistration, TypeRegistry,
+};
+use serde::{de::DeserializeSeed, Deserialize};
+use std::path::PathBuf;
+use thiserror::Error;
+use tracing::error;
+
+/// Applies a list of operations read from a RON file to the [`World`] in [`PreStartup`].
+///
+/// This lets designers tweak the initial world, for example by spawning a few extra entities or
+/// changing resource fields, without touching code. The file contains a list of operations,
+/// which are resolved through the [`AppTypeRegistry`] and applied in order:
+///
+/// ```ron
+/// [
+///     // Spawns an entity with the given components, keyed by type path.
+///     // The optional label can be used by later operations of the same file.
+///     SpawnWithComponents(
+///         label: Some("player"),
+///         components: {
+///             "my_game::Health": (current: 10, max: 10),
+///         },
+///     ),
+///     // `child_of` refers to the label of an entity spawned earlier in the file.
+///     SpawnWithComponents(
+///         child_of: Some("player"),
+///         components: {
+///             "my_game::Weapon": (damage: 3),
+///         },
+///     ),
+///     // Inserts a resource, replacing any existing value.
+///     InsertResource(type: "my_game::Score", value: (points: 0)),
+///     // Sets a single field of an existing resource, using a reflection path.
+///     SetResourceField(type: "my_game::Difficulty", path: ".enemy_speed", value: 1.5),
+/// ]
+/// ```
+///
+/// Every type must be registered, and components and resources must respectively reflect
+/// [`ReflectComponent`] and [`ReflectResource`]. Labels of spawned entities are stored in the
+/// [`BootstrapLabels`] resource.
+///
+/// Failing operations are reported with their index and line in the file. By default they are
+/// logged and skipped; in [`strict`](Self::strict) mode, the first failure stops the bootstrap
+/// and panics.
+///
+/// The file is read synchronously from the file system, so this plugin is not available on the
+/// web.
+#[derive(Debug, Clone)]
+pub struct BootstrapPlugin {
+    /// The path of the RON file to apply.
+    pub path: PathBuf,
+    /// Whether to panic on the first failing operation instead of logging and skipping it.
+    pub strict: bool,
+}
+
+impl BootstrapPlugin {
+    /// Creates a lenient [`BootstrapPlugin`] applying the file at `path`.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            strict: false,
+        }
+    }
+
+    /// Sets whether to panic on the first failing operation.
+    pub fn with_strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+}
+
+impl Plugin for BootstrapPlugin {
+    fn build(&self, app: &mut App) {
+        let Self { path, strict } = self.clone();
+        app.init_resource::<BootstrapLabels>()
+            .add_systems(PreStartup, move |world: &mut World| {
+                let result = std::fs::read_to_string(&path)
+                    .map_err(BootstrapError::from)
+                    .and_then(|source| Bootstrap::from_ron(&source))
+                    .and_then(|bootstrap| bootstrap.apply(world, strict));
+                let Err(err) = result else {
+                    return;
+                };
+                if strict {
+                    panic!(
+                        "Failed to bootstrap the world from {}: {err}",
+                        path.display()
+                    );
+                }
+                match err {
+                    BootstrapError::Operations(errors) => {
+                        for err in errors {
+                            error!("Bootstrap operation from {} failed: {err}", path.display());
+                        }
+                    }
+                    err => error!(
+                        "Failed to bootstrap the world from {}: {err}",
+                        path.display()
+                    ),
+                }
+            });
+    }
+}
+
+/// The entities spawned by a [`Bootstrap`], keyed by their label.
+#[derive(Resource, Debug, Default)]
+pub struct BootstrapLabels(HashMap<String, Entity>);
+
+impl BootstrapLabels {
+    /// Returns the entity spawned with the given label, if any.
+    pub fn get(&self, label: &str) -> Option<Entity> {
+        self.0.get(label).copied()
+    }
+
+    /// Iterates over all labels and their entities.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, Entity)> {
+        self.0
+            .iter()
+            .map(|(label, entity)| (label.as_str(), *entity))
+    }
+}
+
+/// A parsed list of bootstrap operations, as read by [`BootstrapPlugin`].
+#[derive(Debug)]
+pub struct Bootstrap {
+    operations: Vec<ParsedOperation>,
+}
+
+#[derive(Debug)]
+struct ParsedOperation {
+    /// The line in the source where the operation starts.
+    line: usize,
+    /// The operation, or why it could not be parsed.
+    operation: Result<Operation, SpannedError>,
+}
+
+#[derive(Deserialize, Debug)]
+enum Operation {
+    SpawnWithComponents {
+        #[serde(default)]
+        label: Option<String>,
+        #[serde(default)]
+        child_of: Option<String>,
+        components: BTreeMap<String, Box<RawValue>>,
+    },
+    InsertResource {
+        #[serde(rename = "type")]
+        type_path: String,
+        value: Box<RawValue>,
+    },
+    SetResourceField {
+        #[serde(rename = "type")]
+        type_path: String,
+        path: String,
+        value: Box<RawValue>,
+    },
+}
+
+impl Operation {
+    fn name(&self) -> &'static str {
+        match self {
+            Operation::SpawnWithComponents { .. } => "SpawnWithComponents",
+            Operation::InsertResource { .. } => "InsertResource",
+            Operation::SetResourceField { .. } => "SetResourceField",
+        }
+    }
+}
+
+impl Bootstrap {
+    /// Parses a list of bootstrap operations from RON.
+    ///
+    /// Only the list itself must be valid RON: operations that are malformed are reported when
+    /// the bootstrap is [applied](Self::apply), along with their index and line.
+    pub fn from_ron(source: &str) -> Result<Self, BootstrapError> {
+        let entries = ron::from_str::<Vec<&RawValue>>(source)?;
+        let operations = entries
+            .into_iter()
+            .map(|entry| {
+                let entry = entry.trim();
+                let offset = entry.get_ron().as_ptr() as usize - source.as_ptr() as usize;
+                ParsedOperation {
+                    line: source[..offset].matches('\n').count() + 1,
+                    operation: entry.into_rust(),
+                }
+            })
+            .collect();
+        Ok(Self { operations })
+    }
+
+    /// Returns the number of operations.
+    pub fn len(&self) -> usize {
+        self.operations.len()
+    }
+
+    /// Returns `true` if there are no operations.
+    pub fn is_empty(&self) -> bool {
+        self.operations.is_empty()
+    }
+
+    /// Applies the operations to `world` in order.
+    ///
+    /// If `strict` is `false`, failing operations are skipped and every failure is returned once
+    /// all operations have been attempted. Otherwise, the first failure stops the bootstrap.
+    pub fn apply(&self, world: &mut World, strict: bool) -> Result<(), BootstrapError> {
+        let registry = world.resource::<AppTypeRegistry>().clone();
+        let registry = registry.read();
+        world.init_resource::<BootstrapLabels>();
+
+        let mut errors = Vec::new();
+        for (index, parsed) in self.operations.iter().enumerate() {
+            let result = match &parsed.operation {
+                Ok(operation) => apply_operation(world, &registry, operation),
+                Err(err) => Err(BootstrapErrorKind::Invalid(err.clone())),
+            };
+            if let Err(kind) = result {
+                errors.push(BootstrapOperationError {
+                    index,
+                    line: parsed.line,
+                    operation: parsed.operation.as_ref().map_or("unknown", Operation::name),
+                    kind,
+                });
+                if strict {
+                    break;
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(BootstrapError::Operations(errors))
+        }
+    }
+}
+
+fn apply_operation(
+    world: &mut World,
+    registry: &TypeRegistry,
+    operation: &Operation,
+) -> Result<(), BootstrapErrorKind> {
+    match operation {
+        Operation::SpawnWithComponents {
+            label,
+            child_of,
+            components,
+        } => {
+            if let Some(label) = label
+                && world.resource::<BootstrapLabels>().0.contains_key(label)
+            {
+                return Err(BootstrapErrorKind::DuplicateLabel(label.clone()));
+            }
+            let parent = child_of
+                .as_ref()
+                .map(|parent| {
+                    world
+                        .resource::<BootstrapLabels>()
+                        .get(parent)
+                        .ok_or_else(|| BootstrapErrorKind::UnknownLabel(parent.clone()))
+                })
+                .transpose()?;
+            // Resolve every component before spawning, so that a failing operation has no effect.
+            let components = components
+                .iter()
+                .map(|(type_path, value)| {
+                    let registration = get_registration(registry, type_path)?;
+                    let reflect_component =
+                        registration.data::<ReflectComponent>().ok_or_else(|| {
+                            BootstrapErrorKind::MissingReflectComponent(type_path.clone())
+                        })?;
+                    let value = deserialize(registration, registry, type_path, value)?;
+                    Ok((reflect_component, value))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            let mut entity = world.spawn_empty();
+            for (reflect_component, value) in components {
+                reflect_component.insert(&mut entity, value.as_ref(), registry);
+            }
+            if let Some(parent) = parent {
+                entity.insert(ChildOf(parent));
+            }
+            let entity = entity.id();
+            if let Some(label) = label {
+                world
+                    .resource_mut::<BootstrapLabels>()
+                    .0
+                    .insert(label.clone(), entity);
+            }
+        }
+        Operation::InsertResource { type_path, value } => {
+            let registration = get_registration(registry, type_path)?;
+            let reflect_resource = registration
+                .data::<ReflectResource>()
+                .ok_or_else(|| BootstrapErrorKind::MissingReflectResource(type_path.clone()))?;
+            let value = deserialize(registration, registry, type_path, value)?;
+            reflect_resource.insert(world, value.as_ref(), registry);
+        }
+        Operation::SetResourceField {
+            type_path,
+            path,
+            value,
+        } => {
+            let registration = get_registration(registry, type_path)?;
+            let reflect_resource = registration
+                .data::<ReflectResource>()
+                .ok_or_else(|| BootstrapErrorKind::MissingReflectResource(type_path.clone()))?;
+            let mut resource = reflect_resource
+                .reflect_mut(&mut *world)
+                .map_err(|_| BootstrapErrorKind::MissingResource(type_path.clone()))?;
+            let invalid_path = |reason: String| BootstrapErrorKind::InvalidPath {
+                type_path: type_path.clone(),
+                path: path.clone(),
+                reason,
+            };
+            let field = path
+                .as_str()
+                .reflect_element_mut(resource.as_partial_reflect_mut())
+                .map_err(|err| invalid_path(err.to_string()))?;
+            let field_registration = field
+                .get_represented_type_info()
+                .and_then(|info| registry.get(info.type_id()))
+                .ok_or_else(|| invalid_path("the field's type is not registered".into()))?;
+            let value = deserialize(field_registration, registry, type_path, value)?;
+            field
+                .try_apply(value.as_ref())
+                .map_err(|err| invalid_path(err.to_string()))?;
+        }
+    }
+    Ok(())
+}
+
+fn get_registration<'a>(
+    registry: &'a TypeRegistry,
+    type_path: &str,
+) -> Result<&'a TypeRegistration, BootstrapErrorKind> {
+    registry
+        .get_with_type_path(type_path)
+        .ok_or_else(|| BootstrapErrorKind::UnregisteredType(type_path.into()))
+}
+
+fn deserialize(
+    registration: &TypeRegistration,
+    registry: &TypeRegistry,
+    type_path: &str,
+    value: &RawValue,
+) -> Result<Box<dyn PartialReflect>, BootstrapErrorKind> {
+    let error = |error| BootstrapErrorKind::Deserialize {
+        type_path: type_path.into(),
+        error,
+    };
+    let mut deserializer = ron::Deserializer::from_str(value.get_ron()).map_err(error)?;
+    TypedReflectDeserializer::new(registration, registry)
+        .deserialize(&mut deserializer)
+        .map_err(|err| error(deserializer.span_error(err)))
+}
+
+/// An error that occurs when reading or applying a [`Bootstrap`].
+#[derive(Error, Debug)]
+pub enum BootstrapError {
+    /// The bootstrap file could not be read.
+    #[error("could not read the bootstrap file: {0}")]
+    Io(#[from] std::io::Error),
+    /// The bootstrap file is not a valid RON list.
+    #[error("could not parse the bootstrap file: {0}")]
+    Parse(#[from] SpannedError),
+    /// Some operations could not be applied.
+    #[error("{} bootstrap operation(s) failed", .0.len())]
+    Operations(Vec<BootstrapOperationError>),
+}
+
+/// A bootstrap operation that could not be applied.
+#[derive(Error, Debug)]
+#[error("operation {index} ({operation}) at line {line}: {kind}")]
+pub struct BootstrapOperationError {
+    /// The index of the operation in the file.
+    pub index: usize,
+    /// The line at which the operation starts in the file.
+    pub line: usize,
+    /// The name of the operation, or `"unknown"` if it could not be parsed.
+    pub operation: &'static str,
+    /// Why the operation failed.
+    pub kind: BootstrapErrorKind,
+}
+
+/// The reason a bootstrap operation could not be applied.
+#[derive(Error, Debug)]
+pub enum BootstrapErrorKind {
+    /// The operation is not valid RON or not a known operation.
+    #[error("invalid operation: {0}")]
+    Invalid(SpannedError),
+    /// A type path does not match any registered type.
+    #[error("the type `{0}` is not registered. consider registering it using `app.register_type::<T>()`")]
+    UnregisteredType(String),
+    /// A component type does not reflect [`ReflectComponent`].
+    #[error("the type `{0}` is not a reflected component. consider adding `#[reflect(Component)]` to it")]
+    MissingReflectComponent(String),
+    /// A resource type does not reflect [`ReflectResource`].
+    #[error(
+        "the type `{0}` is not a reflected resource. consider adding `#[reflect(Resource)]` to it"
+    )]
+    MissingReflectResource(String),
+    /// The resource whose field is set does not exist.
+    #[error("the resource `{0}` does not exist")]
+    MissingResource(String),
+    /// A value could not be deserialized.
+    #[error("could not deserialize a value for `{type_path}`: {error}")]
+    Deserialize {
+        /// The type path of the component or resource.
+        type_path: String,
+        /// The deserialization error, positioned relative to the value.
+        error: SpannedError,
+    },
+    /// A resource field could not be set.
+    #[error("could not set `{path}` of resource `{type_path}`: {reason}")]
+    InvalidPath {
+        /// The type path of the resource.
+        type_path: String,
+        /// The path of the field.
+        path: String,
+        /// Why the field could not be set.
+        reason: String,
+    },
+    /// `child_of` refers to a label that no earlier operation defined.
+    #[error("no entity is labeled `{0}`")]
+    UnknownLabel(String),
+    /// An entity was spawned with a label that is already used.
+    #[error("the label `{0}` is already used")]
+    DuplicateLabel(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_ecs::component::Component;
+    use bevy_reflect::Reflect;
+
+    #[derive(Component, Reflect, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Health {
+        current: u32,
+        max: u32,
+    }
+
+    #[derive(Resource, Reflect, Debug, PartialEq)]
+    #[reflect(Resource)]
+    struct Score {
+        points: u32,
+        multiplier: f32,
+    }
+
+    #[derive(Reflect)]
+    struct NotAComponent;
+
+    fn world() -> World {
+        let mut world = World::new();
+        let registry = AppTypeRegistry::default();
+        {
+            let mut registry = registry.write();
+            registry.register::<Health>();
+            registry.register::<Score>();
+            registry.register::<NotAComponent>();
+        }
+        world.insert_resource(registry);
+        world
+    }
+
+    fn operation_errors(result: Result<(), BootstrapError>) -> Vec<BootstrapOperationError> {
+        match result {
+            Err(BootstrapError::Operations(errors)) => errors,
+            other => panic!("expected operation errors, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn spawn_with_components() {
+        let mut world = world();
+        let bootstrap = Bootstrap::from_ron(
+            r#"[
+                SpawnWithComponents(
+                    label: Some("player"),
+                    components: {
+                        "bevy_scene::bootstrap::tests::Health": (current: 3, max: 10),
+                    },
+                ),
+            ]"#,
+        )
+        .unwrap();
+        bootstrap.apply(&mut world, true).unwrap();
+
+        let player = world.resource::<BootstrapLabels>().get("player").unwrap();
+        assert_eq!(
+            world.get::<Health>(player),
+            Some(&Health {
+                current: 3,
+                max: 10
+            })
+        );
+    }
+
+    #[test]
+    fn insert_resource_and_set_field() {
+        let mut world = world();
+        let bootstrap = Bootstrap::from_ron(
+            r#"[
+                InsertResource(
+                    type: "bevy_scene::bootstrap::tests::Score",
+                    value: (points: 5, multiplier: 1.0),
+                ),
+                SetResourceField(
+                    type: "bevy_scene::bootstrap::tests::Score",
+                    path: ".multiplier",
+                    value: 2.5,
+                ),
+            ]"#,
+        )
+        .unwrap();
+        bootstrap.apply(&mut world, true).unwrap();
+
+        assert_eq!(
+            world.resource::<Score>(),
+            &Score {
+                points: 5,
+                multiplier: 2.5
+            }
+        );
+    }
+
+    #[test]
+    fn labels_are_usable_by_later_operations() {
+        let mut world = world();
+        let bootstrap = Bootstrap::from_ron(
+            r#"[
+                SpawnWithComponents(label: Some("parent"), components: {}),
+                SpawnWithComponents(label: Some("child"), child_of: Some("parent"), components: {}),
+                SpawnWithComponents(child_of: Some("missing"), components: {}),
+                SpawnWithComponents(label: Some("parent"), components: {}),
+            ]"#,
+        )
+        .unwrap();
+        let errors = operation_errors(bootstrap.apply(&mut world, false));
+
+        let labels = world.resource::<BootstrapLabels>();
+        let (parent, child) = (labels.get("parent").unwrap(), labels.get("child").unwrap());
+        assert_eq!(world.get::<ChildOf>(child), Some(&ChildOf(parent)));
+        assert!(matches!(
+            errors.as_slice(),
+            [
+                BootstrapOperationError {
+                    index: 2,
+                    kind: BootstrapErrorKind::UnknownLabel(_),
+                    ..
+                },
+                BootstrapOperationError {
+                    index: 3,
+                    kind: BootstrapErrorKind::DuplicateLabel(_),
+                    ..
+                },
+            ]
+        ));
+    }
+
+    #[test]
+    fn errors_report_index_and_line() {
+        let mut world = world();
+        let bootstrap = Bootstrap::from_ron(
+            r#"[
+                SpawnWithComponents(components: {"unknown::Type": ()}),
+                InsertResource(type: "bevy_scene::bootstrap::tests::NotAComponent", value: ()),
+                SpawnWithComponents(components: {
+                    "bevy_scene::bootstrap::tests::Health": (current: "three", max: 10),
+                }),
+                SetResourceField(type: "bevy_scene::bootstrap::tests::Score", path: ".points", value: 1),
+                DoSomethingElse(),
+            ]"#,
+        )
+        .unwrap();
+        assert_eq!(bootstrap.len(), 5);
+        let errors = operation_errors(bootstrap.apply(&mut world, false));
+
+        let summary = errors
+            .iter()
+            .map(|err| (err.index, err.line, err.operation))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            summary,
+            [
+                (0, 2, "SpawnWithComponents"),
+                (1, 3, "InsertResource"),
+                (2, 4, "SpawnWithComponents"),
+                (3, 7, "SetResourceField"),
+                (4, 8, "unknown"),
+            ]
+        );
+        assert!(matches!(
+            errors[0].kind,
+            BootstrapErrorKind::UnregisteredType(_)
+        ));
+        assert!(matches!(
+            errors[1].kind,
+            BootstrapErrorKind::MissingReflectResource(_)
+        ));
+        assert!(matches!(
+            errors[2].kind,
+            BootstrapErrorKind::Deserialize { .. }
+        ));
+        assert!(matches!(
+            errors[3].kind,
+            BootstrapErrorKind::MissingResource(_)
+        ));
+        assert!(matches!(errors[4].kind, BootstrapErrorKind::Invalid(_)));
+        // Nothing was spawned by the failing operations.
+        assert_eq!(world.query::<&Health>().iter(&world).count(), 0);
+    }
+
+    #[test]
+    fn strict_stops_at_first_error() {
+        let source = r#"[
+            InsertResource(type: "bevy_scene::bootstrap::tests::Score", value: (points: 1, multiplier: 1.0)),
+            SetResourceField(type: "bevy_scene::bootstrap::tests::Score", path: ".missing", value: 2),
+            SetResourceField(type: "bevy_scene::bootstrap::tests::Score", path: ".points", value: 2),
+        ]"#;
+        let bootstrap = Bootstrap::from_ron(source).unwrap();
+
+        let mut strict = world();
+        let errors = operation_errors(bootstrap.apply(&mut strict, true));
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].index, 1);
+        assert_eq!(strict.resource::<Score>().points, 1);
+
+        let mut lenient = world();
+        let errors = operation_errors(bootstrap.apply(&mut lenient, false));
+        assert_eq!(errors.len(), 1);
+        assert_eq!(lenient.resource::<Score>().points, 2);
+    }
+
+    #[test]
+    fn invalid_list_is_a_parse_error() {
+        assert!(matches!(
+            Bootstrap::from_ron("[SpawnWithComponents(components: {})"),
+            Err(BootstrapError::Parse(_))
+        ));
+    }
+}
@@ -12,6 +12,8 @@
 
 extern crate alloc;
 
+#[cfg(feature = "serialize")]
+mod bootstrap;
 mod components;
 mod dynamic_scene;
 mod dynamic_scene_builder;
@@ -27,6 +29,8 @@ pub mod serde;
 /// Rusty Object Notation, a crate used to serialize and deserialize bevy scenes.
 pub use bevy_asset::ron;
 
+#[cfg(feature = "serialize")]
+pub use bootstrap::*;
 pub use components::*;
 pub use dynamic_scene::*;
 pub use dynamic_scene_builder::*;
@@ -1,12 +1,13 @@
-use bevy_app::{App, MainScheduleOrder, Plugin, PreStartup, PreUpdate, SubApp};
+use bevy_app::{App, MainScheduleOrder, Plugin, PreStartup, PreUpdate, RunSchedules, SubApp};
 use bevy_ecs::{event::Events, schedule::IntoScheduleConfigs, world::FromWorld};
 use bevy_utils::once;
 use log::warn;
 
 use crate::{
     state::{
-        setup_state_transitions_in_world, ComputedStates, FreelyMutableState, NextState, State,
-        StateTransition, StateTransitionEvent, StateTransitionSystems, States, SubStates,
+        setup_state_transitions_in_world, ComputedStates, FreelyMutableState, NextState, OnEnter,
+        OnExit, OnTransition, State, StateTransition, StateTransitionEvent, StateTransitionSystems,
+        States, SubStates,
     },
     state_scoped::{despawn_entities_on_enter_state, despawn_entities_on_exit_state},
 };
@@ -95,6 +96,15 @@ fn warn_if_no_states_plugin_installed(app: &SubApp) {
     }
 }
 
+/// Registers the schedules run by the transitions of `S`, so that adding systems to them isn't
+/// reported by the [`MissingScheduleBehavior`](bevy_app::MissingScheduleBehavior) checks.
+fn register_state_schedules<S: States>(app: &mut SubApp) {
+    let mut run_schedules = app.world_mut().get_resource_or_init::<RunSchedules>();
+    run_schedules.insert_type::<OnEnter<S>>();
+    run_schedules.insert_type::<OnExit<S>>();
+    run_schedules.insert_type::<OnTransition<S>>();
+}
+
 impl AppExtStates for SubApp {
     fn init_state<S: FreelyMutableState + FromWorld>(&mut self) -> &mut Self {
         warn_if_no_states_plugin_installed(self);
@@ -106,6 +116,7 @@ impl AppExtStates for SubApp {
                 "The `StateTransition` schedule is missing. Did you forget to add StatesPlugin or DefaultPlugins before calling init_state?"
             );
             S::register_state(schedule);
+            register_state_schedules::<S>(self);
             let state = self.world().resource::<State<S>>().get().clone();
             self.world_mut().write_event(StateTransitionEvent {
                 exited: None,
@@ -132,6 +143,7 @@ impl AppExtStates for SubApp {
                 "The `StateTransition` schedule is missing. Did you forget to add StatesPlugin or DefaultPlugins before calling insert_state?"
             );
             S::register_state(schedule);
+            register_state_schedules::<S>(self);
             self.world_mut().write_event(StateTransitionEvent {
                 exited: None,
                 entered: Some(state),
@@ -165,6 +177,7 @@ impl AppExtStates for SubApp {
                 "The `StateTransition` schedule is missing. Did you forget to add StatesPlugin or DefaultPlugins before calling add_computed_state?"
             );
             S::register_computed_state_systems(schedule);
+            register_state_schedules::<S>(self);
             let state = self
                 .world()
                 .get_resource::<State<S>>()
@@ -196,6 +209,7 @@ impl AppExtStates for SubApp {
                 "The `StateTransition` schedule is missing. Did you forget to add StatesPlugin or DefaultPlugins before calling add_sub_state?"
             );
             S::register_sub_state_systems(schedule);
+            register_state_schedules::<S>(self);
             let state = self
                 .world()
                 .get_resource::<State<S>>()
@@ -329,9 +343,9 @@ impl Plugin for StatesPlugin {
 mod tests {
     use crate::{
         app::StatesPlugin,
-        state::{State, StateTransition, StateTransitionEvent},
+        state::{OnEnter, OnExit, State, StateTransition, StateTransitionEvent},
     };
-    use bevy_app::App;
+    use bevy_app::{App, MissingScheduleBehavior};
     use bevy_ecs::event::Events;
     use bevy_state_macros::States;
 
@@ -384,4 +398,17 @@ mod tests {
         assert_eq!(last.exited, None);
         assert_eq!(last.entered, Some(TestState::C));
     }
+
+    #[test]
+    fn state_schedules_are_not_missing() {
+        let mut app = App::new();
+        app.add_plugins(StatesPlugin)
+            .set_missing_schedule_behavior(MissingScheduleBehavior::Error)
+            .init_state::<TestState>()
+            .add_systems(OnEnter(TestState::B), || {})
+            .add_systems(OnExit(TestState::C), || {});
+
+        assert!(app.unrun_schedules().is_empty());
+        app.finish();
+    }
 }
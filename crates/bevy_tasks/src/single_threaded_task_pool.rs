@@ -62,6 +62,11 @@ impl TaskPoolBuilder {
         self
     }
 
+    /// No op on the single threaded task pool
+    pub fn name(self, _name: String) -> Self {
+        self
+    }
+
     /// No op on the single threaded task pool
     pub fn on_thread_spawn(self, _f: impl Fn() + Send + Sync + 'static) -> Self {
         self
@@ -103,6 +108,31 @@ impl TaskPool {
         1
     }
 
+    /// The single threaded task pool has no name.
+    pub fn name(&self) -> Option<&str> {
+        None
+    }
+
+    /// The single threaded task pool doesn't own any thread.
+    pub fn thread_names(&self) -> impl Iterator<Item = &str> {
+        core::iter::empty()
+    }
+
+    /// The single threaded task pool doesn't track its tasks, so this is always `0`.
+    pub fn pending_tasks(&self) -> usize {
+        0
+    }
+
+    /// The single threaded task pool doesn't own any thread, so this is always `None`.
+    pub fn current_pool_name() -> Option<Arc<str>> {
+        None
+    }
+
+    /// The single threaded task pool doesn't track task names, so this is always `None`.
+    pub fn current_task_name() -> Option<Arc<str>> {
+        None
+    }
+
     /// Allows spawning non-`'static` futures on the thread pool. The function takes a callback,
     /// passing a scope object into it. The scope object provided to the callback can be used
     /// to spawn tasks. This function will await the completion of all tasks before returning.
@@ -215,6 +245,19 @@ impl TaskPool {
         }}
     }
 
+    /// Spawns a static future onto the thread pool. This is exactly the same as
+    /// [`TaskPool::spawn`], as the single threaded task pool doesn't track task names.
+    pub fn spawn_named<T>(
+        &self,
+        _name: impl Into<Arc<str>>,
+        future: impl Future<Output = T> + 'static + MaybeSend + MaybeSync,
+    ) -> Task<T>
+    where
+        T: 'static + MaybeSend + MaybeSync,
+    {
+        self.spawn(future)
+    }
+
     /// Spawns a static future on the JS event loop. This is exactly the same as [`TaskPool::spawn`].
     pub fn spawn_local<T>(
         &self,
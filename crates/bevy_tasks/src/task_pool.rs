@@ -1,5 +1,12 @@
 use alloc::{boxed::Box, format, string::String, vec::Vec};
-use core::{future::Future, marker::PhantomData, mem, panic::AssertUnwindSafe};
+use core::{
+    cell::RefCell,
+    future::{poll_fn, Future},
+    marker::PhantomData,
+    mem,
+    panic::AssertUnwindSafe,
+    sync::atomic::{AtomicUsize, Ordering},
+};
 use std::{
     thread::{self, JoinHandle},
     thread_local,
@@ -26,6 +33,37 @@ impl Drop for CallOnDrop {
     }
 }
 
+/// Counts a task spawned with [`TaskPool::spawn`] as pending until it is dropped.
+struct PendingTask(Arc<AtomicUsize>);
+
+impl PendingTask {
+    fn new(pending: &Arc<AtomicUsize>) -> Self {
+        pending.fetch_add(1, Ordering::Relaxed);
+        Self(Arc::clone(pending))
+    }
+}
+
+impl Drop for PendingTask {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+/// Sets the name of the task polled on this thread, and restores the previous one when dropped.
+struct CurrentTask(Option<Arc<str>>);
+
+impl CurrentTask {
+    fn enter(name: &Arc<str>) -> Self {
+        Self(TaskPool::CURRENT_TASK.with(|task| task.replace(Some(Arc::clone(name)))))
+    }
+}
+
+impl Drop for CurrentTask {
+    fn drop(&mut self) {
+        TaskPool::CURRENT_TASK.with(|task| *task.borrow_mut() = self.0.take());
+    }
+}
+
 /// Used to create a [`TaskPool`]
 #[derive(Default)]
 #[must_use]
@@ -38,6 +76,8 @@ pub struct TaskPoolBuilder {
     /// Allows customizing the name of the threads - helpful for debugging. If set, threads will
     /// be named `<thread_name> (<thread_index>)`, i.e. `"MyThreadPool (2)"`.
     thread_name: Option<String>,
+    /// The name of the pool, used to name the threads if `thread_name` isn't set.
+    name: Option<String>,
 
     on_thread_spawn: Option<Arc<dyn Fn() + Send + Sync + 'static>>,
     on_thread_destroy: Option<Arc<dyn Fn() + Send + Sync + 'static>>,
@@ -69,6 +109,13 @@ impl TaskPoolBuilder {
         self
     }
 
+    /// Names the pool, see [`TaskPool::name`]. Unless [`thread_name`](Self::thread_name) is set,
+    /// threads will be named `<name>-<thread_index>`, i.e. `my-pool-2`.
+    pub fn name(mut self, name: String) -> Self {
+        self.name = Some(name);
+        self
+    }
+
     /// Sets a callback that is invoked once for every created thread as it starts.
     ///
     /// This is called on the thread itself and has access to all thread-local storage.
@@ -139,12 +186,16 @@ pub struct TaskPool {
     // The inner state of the pool.
     threads: Vec<JoinHandle<()>>,
     shutdown_tx: async_channel::Sender<()>,
+    name: Option<Arc<str>>,
+    pending: Arc<AtomicUsize>,
 }
 
 impl TaskPool {
     thread_local! {
         static LOCAL_EXECUTOR: crate::executor::LocalExecutor<'static> = const { crate::executor::LocalExecutor::new() };
         static THREAD_EXECUTOR: Arc<ThreadExecutor<'static>> = Arc::new(ThreadExecutor::new());
+        static CURRENT_POOL: RefCell<Option<Arc<str>>> = const { RefCell::new(None) };
+        static CURRENT_TASK: RefCell<Option<Arc<str>>> = const { RefCell::new(None) };
     }
 
     /// Each thread should only create one `ThreadExecutor`, otherwise, there are good chances they will deadlock
@@ -152,6 +203,17 @@ impl TaskPool {
         Self::THREAD_EXECUTOR.with(Clone::clone)
     }
 
+    /// Returns the name of the pool owning the current thread, if it is a thread of a named pool.
+    pub fn current_pool_name() -> Option<Arc<str>> {
+        Self::CURRENT_POOL.with(|pool| pool.borrow().clone())
+    }
+
+    /// Returns the name of the task being polled on the current thread, if it was spawned with
+    /// [`TaskPool::spawn_named`].
+    pub fn current_task_name() -> Option<Arc<str>> {
+        Self::CURRENT_TASK.with(|task| task.borrow().clone())
+    }
+
     /// Create a `TaskPool` with the default configuration.
     pub fn new() -> Self {
         TaskPoolBuilder::new().build()
@@ -161,6 +223,7 @@ impl TaskPool {
         let (shutdown_tx, shutdown_rx) = async_channel::unbounded::<()>();
 
         let executor = Arc::new(crate::executor::Executor::new());
+        let name: Option<Arc<str>> = builder.name.as_deref().map(Arc::from);
 
         let num_threads = builder
             .num_threads
@@ -173,6 +236,8 @@ impl TaskPool {
 
                 let thread_name = if let Some(thread_name) = builder.thread_name.as_deref() {
                     format!("{thread_name} ({i})")
+                } else if let Some(name) = name.as_deref() {
+                    format!("{name}-{i}")
                 } else {
                     format!("TaskPool ({i})")
                 };
@@ -184,9 +249,11 @@ impl TaskPool {
 
                 let on_thread_spawn = builder.on_thread_spawn.clone();
                 let on_thread_destroy = builder.on_thread_destroy.clone();
+                let pool_name = name.clone();
 
                 thread_builder
                     .spawn(move || {
+                        TaskPool::CURRENT_POOL.with(|pool| *pool.borrow_mut() = pool_name);
                         TaskPool::LOCAL_EXECUTOR.with(|local_executor| {
                             if let Some(on_thread_spawn) = on_thread_spawn {
                                 on_thread_spawn();
@@ -218,6 +285,8 @@ impl TaskPool {
             executor,
             threads,
             shutdown_tx,
+            name,
+            pending: Arc::new(AtomicUsize::new(0)),
         }
     }
 
@@ -226,6 +295,24 @@ impl TaskPool {
         self.threads.len()
     }
 
+    /// Returns the name of the pool, if it was set with [`TaskPoolBuilder::name`].
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Returns the names of the threads owned by the task pool.
+    pub fn thread_names(&self) -> impl Iterator<Item = &str> {
+        self.threads
+            .iter()
+            .filter_map(|thread| thread.thread().name())
+    }
+
+    /// Returns the number of tasks spawned with [`TaskPool::spawn`] or [`TaskPool::spawn_named`]
+    /// that didn't complete yet, whether they are queued or running.
+    pub fn pending_tasks(&self) -> usize {
+        self.pending.load(Ordering::Relaxed)
+    }
+
     /// Allows spawning non-`'static` futures on the thread pool. The function takes a callback,
     /// passing a scope object into it. The scope object provided to the callback can be used
     /// to spawn tasks. This function will await the completion of all tasks before returning.
@@ -560,7 +647,34 @@ impl TaskPool {
     where
         T: Send + 'static,
     {
-        Task::new(self.executor.spawn(future))
+        let pending = PendingTask::new(&self.pending);
+        Task::new(self.executor.spawn(async move {
+            let _pending = pending;
+            future.await
+        }))
+    }
+
+    /// Spawns a static future onto the thread pool like [`TaskPool::spawn`], naming the task.
+    ///
+    /// While the task is polled, its name is returned by [`TaskPool::current_task_name`], so
+    /// panics and logs can be attributed to it.
+    pub fn spawn_named<T>(
+        &self,
+        name: impl Into<Arc<str>>,
+        future: impl Future<Output = T> + Send + 'static,
+    ) -> Task<T>
+    where
+        T: Send + 'static,
+    {
+        let name = name.into();
+        self.spawn(async move {
+            let mut future = core::pin::pin!(future);
+            poll_fn(|cx| {
+                let _task = CurrentTask::enter(&name);
+                future.as_mut().poll(cx)
+            })
+            .await
+        })
     }
 
     /// Spawns a static future on the thread-local async executor for the
@@ -964,4 +1078,49 @@ mod tests {
 
         assert_eq!(count.load(Ordering::Acquire), 1);
     }
+
+    #[test]
+    fn named_pool_threads_and_tasks() {
+        let pool = TaskPoolBuilder::new()
+            .num_threads(2)
+            .name("test-pool".into())
+            .build();
+        assert_eq!(pool.name(), Some("test-pool"));
+        assert_eq!(
+            pool.thread_names().collect::<Vec<_>>(),
+            ["test-pool-0", "test-pool-1"]
+        );
+
+        let (thread, pool_name, task_name) = block_on(pool.spawn_named("load-level", async {
+            (
+                thread::current().name().map(String::from),
+                TaskPool::current_pool_name(),
+                TaskPool::current_task_name(),
+            )
+        }));
+        assert!(thread.unwrap().starts_with("test-pool-"));
+        assert_eq!(pool_name.as_deref(), Some("test-pool"));
+        assert_eq!(task_name.as_deref(), Some("load-level"));
+
+        // The name is only set while the task is polled.
+        let task_name = block_on(pool.spawn(async { TaskPool::current_task_name() }));
+        assert_eq!(task_name, None);
+        assert_eq!(TaskPool::current_pool_name(), None);
+    }
+
+    #[test]
+    fn pending_tasks() {
+        let pool = TaskPoolBuilder::new().num_threads(1).build();
+        let (sender, receiver) = async_channel::bounded::<()>(1);
+
+        let task = pool.spawn(async move { receiver.recv().await });
+        assert_eq!(pool.pending_tasks(), 1);
+
+        sender.send_blocking(()).unwrap();
+        block_on(task).unwrap();
+        // The count is updated when the future is dropped, right after it completes.
+        while pool.pending_tasks() != 0 {
+            thread::yield_now();
+        }
+    }
 }
@@ -0,0 +1,437 @@
+use alloc::vec::Vec;
+use bevy_app::{App, Last, LoopWaitOverride, Plugin};
+use bevy_ecs::prelude::*;
+use core::time::Duration;
+
+use crate::{Real, Time};
+
+/// Detects when the app is idle, that is when no activity has been signaled for a
+/// [`threshold`](Self::threshold).
+///
+/// What counts as activity is configured by the [`IdleSignals`]. Calls to
+/// [`IdleActivity::ping`] always count as activity. The current state is available in the
+/// [`IdleState`] resource, and every change is announced by an [`IdleStateChanged`] event.
+///
+/// The time is measured with [`Time<Real>`], so the [`TimePlugin`](crate::TimePlugin) must be
+/// added, and pausing the virtual time doesn't affect the detection.
+///
+/// ```
+/// # use bevy_app::prelude::*;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_time::{IdleDetectionPlugin, IdleSignals, TimePlugin};
+/// # use core::time::Duration;
+/// #[derive(BufferedEvent)]
+/// struct KeyPressed;
+///
+/// #[derive(Component)]
+/// struct Camera;
+///
+/// App::new().add_plugins((
+///     TimePlugin,
+///     IdleDetectionPlugin {
+///         threshold: Duration::from_secs(30),
+///         signals: IdleSignals::default()
+///             .event::<KeyPressed>()
+///             .component::<Camera>(),
+///         // Run at 2 frames per second while idle.
+///         idle_wait: Some(Duration::from_millis(500)),
+///     },
+/// ));
+/// ```
+#[derive(Clone)]
+pub struct IdleDetectionPlugin {
+    /// How long the app must go without activity to become idle.
+    pub threshold: Duration,
+    /// The sources of activity, in addition to [`IdleActivity::ping`].
+    pub signals: IdleSignals,
+    /// The wait between updates while the app is idle.
+    ///
+    /// When set, entering the idle state inserts a [`LoopWaitOverride`], which slows down an app
+    /// run by the [`ScheduleRunnerPlugin`](bevy_app::ScheduleRunnerPlugin) in
+    /// [`RunMode::Loop`](bevy_app::RunMode::Loop). Leaving it restores the override that was
+    /// there before, if any, unless the override was replaced meanwhile.
+    pub idle_wait: Option<Duration>,
+}
+
+impl Default for IdleDetectionPlugin {
+    fn default() -> Self {
+        Self {
+            threshold: Duration::from_secs(60),
+            signals: IdleSignals::default(),
+            idle_wait: None,
+        }
+    }
+}
+
+impl Plugin for IdleDetectionPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(IdleState {
+            threshold: self.threshold,
+            idle_wait: self.idle_wait,
+            idle: false,
+            last_activity: None,
+            replaced_wait: None,
+        })
+        .init_resource::<IdleActivity>()
+        .add_event::<IdleStateChanged>()
+        .add_systems(Last, update_idle_state.in_set(IdleSystems));
+
+        for add_signal in &self.signals.signals {
+            add_signal(app);
+        }
+    }
+}
+
+/// The sources of activity of an [`IdleDetectionPlugin`].
+#[derive(Clone, Default)]
+pub struct IdleSignals {
+    signals: Vec<fn(&mut App)>,
+}
+
+impl IdleSignals {
+    /// Counts any event of type `E` as activity, for example an input event.
+    ///
+    /// This also [adds](App::add_event) the event if needed.
+    pub fn event<E: BufferedEvent>(mut self) -> Self {
+        self.signals.push(|app| {
+            app.add_event::<E>()
+                .add_systems(Last, ping_on_event::<E>.before(IdleSystems));
+        });
+        self
+    }
+
+    /// Counts any change to a component of type `C` as activity, including adding it.
+    pub fn component<C: Component>(mut self) -> Self {
+        self.signals.push(|app| {
+            app.add_systems(Last, ping_on_change::<C>.before(IdleSystems));
+        });
+        self
+    }
+}
+
+/// The system that updates the [`IdleState`], in [`Last`].
+///
+/// Activity signaled by systems running after this set is only taken into account on the next
+/// frame.
+#[derive(Debug, Hash, PartialEq, Eq, Copy, Clone, SystemSet)]
+pub struct IdleSystems;
+
+/// Signals activity to the [`IdleDetectionPlugin`].
+#[derive(Resource, Debug, Default)]
+pub struct IdleActivity {
+    pinged: bool,
+}
+
+impl IdleActivity {
+    /// Signals activity, which resets the idle timer and leaves the idle state if needed.
+    pub fn ping(&mut self) {
+        self.pinged = true;
+    }
+}
+
+/// Whether the app is idle, as detected by the [`IdleDetectionPlugin`].
+#[derive(Resource, Debug, Clone)]
+pub struct IdleState {
+    threshold: Duration,
+    idle_wait: Option<Duration>,
+    idle: bool,
+    last_activity: Option<Duration>,
+    /// The override inserted when the app became idle, and the one it replaced.
+    replaced_wait: Option<ReplacedWait>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct ReplacedWait {
+    inserted: LoopWaitOverride,
+    previous: Option<LoopWaitOverride>,
+}
+
+impl IdleState {
+    /// Returns `true` if no activity was signaled for the [threshold](Self::threshold).
+    pub fn is_idle(&self) -> bool {
+        self.idle
+    }
+
+    /// Returns the [elapsed real time](Time::elapsed) of the last activity.
+    ///
+    /// This is `None` until the first update.
+    pub fn last_activity(&self) -> Option<Duration> {
+        self.last_activity
+    }
+
+    /// Returns how long the app must go without activity to become idle.
+    pub fn threshold(&self) -> Duration {
+        self.threshold
+    }
+
+    /// Sets how long the app must go without activity to become idle.
+    pub fn set_threshold(&mut self, threshold: Duration) {
+        self.threshold = threshold;
+    }
+
+    /// Returns the wait between updates while the app is idle.
+    ///
+    /// See [`IdleDetectionPlugin::idle_wait`].
+    pub fn idle_wait(&self) -> Option<Duration> {
+        self.idle_wait
+    }
+
+    /// Sets the wait between updates while the app is idle.
+    ///
+    /// This takes effect the next time the app becomes idle.
+    pub fn set_idle_wait(&mut self, idle_wait: Option<Duration>) {
+        self.idle_wait = idle_wait;
+    }
+}
+
+/// Written when the app becomes idle or active again.
+#[derive(BufferedEvent, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IdleStateChanged {
+    /// Whether the app is now idle.
+    pub idle: bool,
+}
+
+fn ping_on_event<E: BufferedEvent>(mut events: EventReader<E>, mut activity: ResMut<IdleActivity>) {
+    if !events.is_empty() {
+        events.clear();
+        activity.ping();
+    }
+}
+
+fn ping_on_change<C: Component>(
+    changed: Query<(), Changed<C>>,
+    mut activity: ResMut<IdleActivity>,
+) {
+    if !changed.is_empty() {
+        activity.ping();
+    }
+}
+
+fn update_idle_state(
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    wait_override: Option<Res<LoopWaitOverride>>,
+    mut activity: ResMut<IdleActivity>,
+    mut state: ResMut<IdleState>,
+    mut changed: EventWriter<IdleStateChanged>,
+) {
+    let now = time.elapsed();
+    let pinged = core::mem::take(&mut activity.pinged);
+    let last_activity = match state.last_activity {
+        Some(last_activity) if !pinged => last_activity,
+        _ => now,
+    };
+    state.last_activity = Some(last_activity);
+    let idle = now.saturating_sub(last_activity) >= state.threshold;
+    if idle == state.idle {
+        return;
+    }
+
+    state.idle = idle;
+    changed.write(IdleStateChanged { idle });
+    let wait_override = wait_override.map(|wait_override| *wait_override);
+    if idle {
+        if let Some(idle_wait) = state.idle_wait {
+            let inserted = LoopWaitOverride(Some(idle_wait));
+            state.replaced_wait = Some(ReplacedWait {
+                inserted,
+                previous: wait_override,
+            });
+            commands.insert_resource(inserted);
+        }
+    } else if let Some(replaced) = state.replaced_wait.take()
+        && wait_override == Some(replaced.inserted)
+    {
+        match replaced.previous {
+            Some(previous) => commands.insert_resource(previous),
+            None => commands.remove_resource::<LoopWaitOverride>(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use bevy_app::{App, LoopWaitOverride, Update};
+    use bevy_ecs::prelude::*;
+    use core::time::Duration;
+
+    use crate::{
+        IdleActivity, IdleDetectionPlugin, IdleSignals, IdleState, IdleStateChanged, TimePlugin,
+        TimeUpdateStrategy,
+    };
+
+    #[derive(BufferedEvent)]
+    struct KeyPressed;
+
+    #[derive(Component)]
+    struct Position(u32);
+
+    /// The [`IdleStateChanged`] events read in each frame.
+    #[derive(Resource, Default)]
+    struct Changes(Vec<bool>);
+
+    fn record_changes(mut events: EventReader<IdleStateChanged>, mut changes: ResMut<Changes>) {
+        changes.0.extend(events.read().map(|event| event.idle));
+    }
+
+    /// An app whose clock advances by 100ms each frame, idle after 300ms without activity.
+    fn app() -> App {
+        let mut app = App::new();
+        app.add_plugins((
+            TimePlugin,
+            IdleDetectionPlugin {
+                threshold: Duration::from_millis(300),
+                signals: IdleSignals::default()
+                    .event::<KeyPressed>()
+                    .component::<Position>(),
+                idle_wait: Some(Duration::from_millis(500)),
+            },
+        ))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )))
+        .init_resource::<Changes>()
+        .add_systems(Update, record_changes);
+        app
+    }
+
+    fn is_idle(app: &App) -> bool {
+        app.world().resource::<IdleState>().is_idle()
+    }
+
+    /// Updates the app until it becomes idle, returning the number of updates.
+    fn updates_until_idle(app: &mut App) -> usize {
+        for updates in 1..=10 {
+            app.update();
+            if is_idle(app) {
+                return updates;
+            }
+        }
+        panic!("the app never became idle");
+    }
+
+    #[test]
+    fn threshold_crossing() {
+        let mut app = app();
+        // The clock starts at 0 on the first update, and the app is idle once it reaches 300ms.
+        assert_eq!(updates_until_idle(&mut app), 4);
+        assert_eq!(
+            app.world().resource::<IdleState>().last_activity(),
+            Some(Duration::ZERO)
+        );
+
+        app.update();
+        assert!(is_idle(&app));
+        // The change is read on the frame after it was written.
+        assert_eq!(app.world().resource::<Changes>().0, vec![true]);
+    }
+
+    #[test]
+    fn each_source_resets_the_timer() {
+        let mut app = app();
+        app.world_mut().spawn(Position(0));
+        updates_until_idle(&mut app);
+
+        // Input events.
+        app.world_mut().write_event(KeyPressed);
+        app.update();
+        assert!(!is_idle(&app));
+        assert_eq!(updates_until_idle(&mut app), 3);
+
+        // Component changes.
+        let mut query = app.world_mut().query::<&mut Position>();
+        query.single_mut(app.world_mut()).unwrap().0 += 1;
+        app.update();
+        assert!(!is_idle(&app));
+        assert_eq!(updates_until_idle(&mut app), 3);
+
+        // Explicit pings.
+        app.world_mut().resource_mut::<IdleActivity>().ping();
+        app.update();
+        assert!(!is_idle(&app));
+        assert_eq!(updates_until_idle(&mut app), 3);
+
+        // Activity before the threshold delays it.
+        app.world_mut().resource_mut::<IdleActivity>().ping();
+        app.update();
+        app.update();
+        app.world_mut().write_event(KeyPressed);
+        assert_eq!(updates_until_idle(&mut app), 4);
+
+        app.update();
+        assert_eq!(
+            app.world().resource::<Changes>().0,
+            [true, false, true, false, true, false, true, false, true]
+        );
+    }
+
+    #[test]
+    fn pacing_integration() {
+        let mut app = app();
+        app.update();
+        assert!(!app.world().contains_resource::<LoopWaitOverride>());
+
+        updates_until_idle(&mut app);
+        assert_eq!(
+            app.world().get_resource::<LoopWaitOverride>(),
+            Some(&LoopWaitOverride(Some(Duration::from_millis(500))))
+        );
+
+        app.world_mut().resource_mut::<IdleActivity>().ping();
+        app.update();
+        assert!(!app.world().contains_resource::<LoopWaitOverride>());
+
+        // Without an idle wait, the pacing is left alone.
+        app.world_mut()
+            .resource_mut::<IdleState>()
+            .set_idle_wait(None);
+        updates_until_idle(&mut app);
+        assert!(!app.world().contains_resource::<LoopWaitOverride>());
+    }
+
+    #[test]
+    fn other_overrides_are_kept() {
+        let previous = LoopWaitOverride(Some(Duration::from_millis(20)));
+        let mut app = app();
+        app.insert_resource(previous);
+        app.update();
+
+        // The override in place before the app became idle is restored.
+        updates_until_idle(&mut app);
+        assert_ne!(app.world().get_resource(), Some(&previous));
+        app.world_mut().resource_mut::<IdleActivity>().ping();
+        app.update();
+        assert_eq!(app.world().get_resource(), Some(&previous));
+
+        // An override inserted while the app is idle is left alone.
+        updates_until_idle(&mut app);
+        let replaced = LoopWaitOverride(None);
+        app.insert_resource(replaced);
+        app.world_mut().resource_mut::<IdleActivity>().ping();
+        app.update();
+        assert_eq!(app.world().get_resource(), Some(&replaced));
+    }
+
+    #[test]
+    fn restore_latency_is_one_frame() {
+        fn press_key_on_frame_6(mut frame: Local<u32>, mut keys: EventWriter<KeyPressed>) {
+            *frame += 1;
+            if *frame == 6 {
+                keys.write(KeyPressed);
+            }
+        }
+
+        let mut app = app();
+        app.add_systems(Update, press_key_on_frame_6);
+        assert_eq!(updates_until_idle(&mut app), 4);
+        app.update();
+        assert!(is_idle(&app));
+
+        // The frame in which the activity happens restores the normal pacing, so the runner
+        // doesn't wait the idle time after it.
+        app.update();
+        assert!(!is_idle(&app));
+        assert!(!app.world().contains_resource::<LoopWaitOverride>());
+    }
+}
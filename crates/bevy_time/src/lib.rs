@@ -15,6 +15,7 @@ extern crate alloc;
 /// Common run conditions
 pub mod common_conditions;
 mod fixed;
+mod idle;
 mod real;
 mod stopwatch;
 mod time;
@@ -22,6 +23,7 @@ mod timer;
 mod virt;
 
 pub use fixed::*;
+pub use idle::*;
 pub use real::*;
 pub use stopwatch::*;
 pub use time::*;
@@ -182,7 +184,7 @@ pub fn time_system(
 #[expect(clippy::print_stdout, reason = "Allowed in tests.")]
 mod tests {
     use crate::{Fixed, Time, TimePlugin, TimeUpdateStrategy, Virtual};
-    use bevy_app::{App, FixedUpdate, Startup, Update};
+    use bevy_app::{App, FixedUpdate, MainSchedulePause, Startup, Update};
     use bevy_ecs::{
         event::{
             BufferedEvent, EventReader, EventRegistry, EventWriter, Events, ShouldUpdateEvents,
@@ -292,6 +294,32 @@ mod tests {
         assert_eq!(counter.0, 2, "Fixed update should have run twice");
     }
 
+    #[test]
+    fn fixed_main_schedule_should_not_catch_up_after_pause() {
+        let mut app = App::new();
+        app.add_plugins(TimePlugin)
+            .add_systems(FixedUpdate, count_fixed_updates)
+            .init_resource::<FixedUpdateCounter>()
+            .insert_resource(TimeUpdateStrategy::ManualDuration(
+                Time::<Fixed>::default().timestep(),
+            ));
+
+        app.update();
+        app.update();
+        assert_eq!(app.world().resource::<FixedUpdateCounter>().0, 1);
+
+        app.world_mut().resource_mut::<MainSchedulePause>().pause();
+        for _ in 0..10 {
+            app.update();
+        }
+        assert_eq!(app.world().resource::<FixedUpdateCounter>().0, 1);
+
+        // Only the time of the frame after resuming is accumulated.
+        app.world_mut().resource_mut::<MainSchedulePause>().resume();
+        app.update();
+        assert_eq!(app.world().resource::<FixedUpdateCounter>().0, 2);
+    }
+
     #[test]
     fn events_get_dropped_regression_test_11528() -> Result<(), impl Error> {
         let (tx1, rx1) = std::sync::mpsc::channel();
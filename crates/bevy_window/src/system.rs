@@ -1,34 +1,36 @@
 use crate::{ClosingWindow, PrimaryWindow, Window, WindowCloseRequested};
 
-use bevy_app::AppExit;
+use bevy_app::{AppExit, AppExitWriter, ShutdownReason};
 use bevy_ecs::prelude::*;
 
-/// Exit the application when there are no open windows.
+/// Exit the application when there are no open windows, with the
+/// [`ShutdownReason::UserQuit`] reason.
 ///
 /// This system is added by the [`WindowPlugin`] in the default configuration.
 /// To disable this behavior, set `close_when_requested` (on the [`WindowPlugin`]) to `false`.
 /// Ensure that you read the caveats documented on that field if doing so.
 ///
 /// [`WindowPlugin`]: crate::WindowPlugin
-pub fn exit_on_all_closed(mut app_exit_events: EventWriter<AppExit>, windows: Query<&Window>) {
+pub fn exit_on_all_closed(mut app_exit: AppExitWriter, windows: Query<&Window>) {
     if windows.is_empty() {
         log::info!("No windows are open, exiting");
-        app_exit_events.write(AppExit::Success);
+        app_exit.write_with_reason(AppExit::Success, ShutdownReason::UserQuit);
     }
 }
 
-/// Exit the application when the primary window has been closed
+/// Exit the application when the primary window has been closed, with the
+/// [`ShutdownReason::UserQuit`] reason.
 ///
 /// This system is added by the [`WindowPlugin`]
 ///
 /// [`WindowPlugin`]: crate::WindowPlugin
 pub fn exit_on_primary_closed(
-    mut app_exit_events: EventWriter<AppExit>,
+    mut app_exit: AppExitWriter,
     windows: Query<(), (With<Window>, With<PrimaryWindow>)>,
 ) {
     if windows.is_empty() {
         log::info!("Primary window was closed, exiting");
-        app_exit_events.write(AppExit::Success);
+        app_exit.write_with_reason(AppExit::Success, ShutdownReason::UserQuit);
     }
 }
 
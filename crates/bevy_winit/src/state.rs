@@ -690,6 +690,7 @@ impl<T: BufferedEvent> WinitAppRunnerState<T> {
         }
 
         if let Some(app_exit) = self.app.should_exit() {
+            self.app.run_exit_hooks();
             self.app_exit = Some(app_exit);
 
             event_loop.exit();
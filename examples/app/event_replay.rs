@@ -0,0 +1,97 @@
+//! Demonstrates how to record events to a file and replay them, to reproduce a run exactly.
+//!
+//! A first app records the moves of a simulated player to a file. A second app replays them:
+//! its own, different moves are discarded while the replay runs, so the player ends at the same
+//! position, as if the same inputs were given again.
+
+use bevy::{
+    app::{EventRecorderPlugin, EventReplayPlugin, FrameLimitPlugin, FrameNumber},
+    prelude::*,
+};
+use serde::{Deserialize, Serialize};
+
+/// The number of frames each app runs.
+const FRAMES: u32 = 8;
+
+/// An input-like event, written by the player and read by the game logic.
+#[derive(BufferedEvent, Serialize, Deserialize, Debug, Clone, Copy)]
+enum Move {
+    Left,
+    Right,
+    Jump,
+}
+
+#[derive(Resource, Default, Debug)]
+struct Player {
+    x: i32,
+    jumps: u32,
+}
+
+fn main() {
+    let path = std::env::temp_dir().join("bevy_event_replay.rec");
+
+    // Record the moves of a player pressing keys in a pattern.
+    println!("Recording:");
+    game()
+        .add_plugins(EventRecorderPlugin::<Move>::new(&path))
+        .add_systems(
+            Update,
+            press_keys(|frame| match frame % 3 {
+                0 => Some(Move::Right),
+                1 => Some(Move::Jump),
+                _ => None,
+            }),
+        )
+        .run();
+
+    // Replay the recorded moves, while this player would only move left.
+    let replay = match EventReplayPlugin::<Move>::load(&path) {
+        Ok(replay) => replay,
+        Err(err) => {
+            eprintln!("Failed to load the recording: {err}");
+            return;
+        }
+    };
+    println!("Replaying:");
+    game()
+        .add_plugins(replay)
+        .add_systems(Update, press_keys(|_| Some(Move::Left)))
+        .run();
+}
+
+/// An app running the game logic headlessly for a few frames.
+fn game() -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, FrameLimitPlugin::new(FRAMES)))
+        .init_resource::<Player>()
+        .add_systems(PostUpdate, apply_moves)
+        .add_systems(Last, report);
+    app
+}
+
+/// Returns a system writing the move `pattern` returns for each frame, like a player would.
+fn press_keys(
+    pattern: impl Fn(u64) -> Option<Move> + Send + Sync + 'static,
+) -> impl FnMut(Res<FrameNumber>, EventWriter<Move>) {
+    move |frame, mut moves| {
+        if let Some(key) = pattern(frame.get()) {
+            moves.write(key);
+        }
+    }
+}
+
+fn apply_moves(mut moves: EventReader<Move>, mut player: ResMut<Player>) {
+    for key in moves.read() {
+        match key {
+            Move::Left => player.x -= 1,
+            Move::Right => player.x += 1,
+            Move::Jump => player.jumps += 1,
+        }
+    }
+}
+
+fn report(frame: Res<FrameNumber>, player: Res<Player>) {
+    if frame.get() + 1 == u64::from(FRAMES) {
+        println!("  after {FRAMES} frames: {:?}", *player);
+    }
+}
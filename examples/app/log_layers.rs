@@ -26,17 +26,17 @@ impl<S: Subscriber> Layer<S> for CustomLayer {
 
 // We don't need App for this example, as we are just printing log information.
 // For an example that uses App, see log_layers_ecs.
-fn custom_layer(_app: &mut App) -> Option<BoxedLayer> {
-    // You can provide multiple layers like this, since Vec<Layer> is also a layer:
-    Some(Box::new(vec![
+fn custom_layers(_app: &mut App) -> Vec<BoxedLayer> {
+    // Each layer is added to the subscriber, in order.
+    vec![
         bevy::log::tracing_subscriber::fmt::layer()
             .with_file(true)
             .boxed(),
         CustomLayer.boxed(),
-    ]))
+    ]
 }
 
-// While `custom_layer` allows you to add _additional_ layers, it won't allow you to override the
+// While `custom_layers` allows you to add _additional_ layers, it won't allow you to override the
 // default `tracing_subscriber::fmt::Layer` added by `LogPlugin`. To do that, you can use the
 // `fmt_layer` option.
 //
@@ -52,7 +52,7 @@ fn fmt_layer(_app: &mut App) -> Option<BoxedFmtLayer> {
 fn main() {
     App::new()
         .add_plugins(DefaultPlugins.set(bevy::log::LogPlugin {
-            custom_layer,
+            custom_layers,
             fmt_layer,
 
             ..default()
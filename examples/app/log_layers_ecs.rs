@@ -1,114 +1,88 @@
-//! This example illustrates how to transfer log events from the [`Layer`] to Bevy's ECS.
+//! This example illustrates how to read logs from Bevy's ECS, and how to transfer data from a
+//! custom log [`Layer`] to the ECS.
 //!
-//! The way we will do this is via a [`mpsc`] channel. [`mpsc`] channels allow 2 unrelated
-//! parts of the program to communicate (in this case, [`Layer`]s and Bevy's ECS).
+//! The [`LogPlugin`] captures the logs as [`LogMessage`] events when its `capture` is set. From
+//! there we can access the logs from our systems and use them. In this example we build a simple
+//! log viewer.
 //!
-//! Inside the `custom_layer` function we will create a [`mpsc::Sender`] and a [`mpsc::Receiver`] from a
-//! [`mpsc::channel`]. The [`Sender`](mpsc::Sender) will go into the `AdvancedLayer` and the [`Receiver`](mpsc::Receiver) will
-//! go into a non-send resource called `LogEvents` (It has to be non-send because [`Receiver`](mpsc::Receiver) is [`!Sync`](Sync)).
-//! From there we will use `transfer_log_events` to transfer log events from `LogEvents` to an ECS event called `LogEvent`.
+//! A custom layer is set up alongside the capture in `count_layer`: it counts the log events of
+//! each level in a resource shared with the ECS, which the log viewer displays in its header.
 //!
-//! Finally, after all that we can access the `LogEvent` event from our systems and use it.
-//! In this example we build a simple log viewer.
+//! The capture and the custom layer have their own filter, more verbose than the console: the
+//! log viewer shows the `debug` and `trace` logs of this example, which the terminal doesn't.
 
-use std::sync::mpsc;
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
 
 use bevy::{
     log::{
         tracing::{self, Subscriber},
         tracing_subscriber::{self, Layer},
-        BoxedLayer, Level,
+        BoxedLayer, Level, LogCaptureConfig, LogMessage, LogPlugin,
     },
     prelude::*,
 };
 
 fn main() {
     App::new()
-        .add_plugins(DefaultPlugins.set(bevy::log::LogPlugin {
-            // Show logs all the way up to the trace level, but only for logs
-            // produced by this example.
-            level: Level::TRACE,
-            filter: "warn,log_layers_ecs=trace".to_string(),
-            custom_layer,
+        .add_plugins(DefaultPlugins.set(LogPlugin {
+            // The console only shows the info logs of this example.
+            filter: "warn,log_layers_ecs=info".to_string(),
+            // Write the logs as `LogMessage` events, all the way up to the trace level, but only
+            // for logs produced by this example.
+            capture: Some(LogCaptureConfig::default()),
+            capture_filter: Some("warn,log_layers_ecs=trace".to_string()),
+            // The custom layer counts the logs of every level as well.
+            filtered_layers: |app| {
+                vec![(count_layer(app), "warn,log_layers_ecs=trace".to_string())]
+            },
             ..default()
         }))
         .add_systems(Startup, (log_system, setup))
-        .add_systems(Update, print_logs)
+        .add_systems(Update, (print_logs, print_log_counts))
         .run();
 }
 
-/// A basic message. This is what we will be sending from the [`CaptureLayer`] to [`CapturedLogEvents`] non-send resource.
-#[derive(Debug, BufferedEvent)]
-struct LogEvent {
-    message: String,
-    level: Level,
-}
-
-/// This non-send resource temporarily stores [`LogEvent`]s before they are
-/// written to [`Events<LogEvent>`] by [`transfer_log_events`].
-#[derive(Deref, DerefMut)]
-struct CapturedLogEvents(mpsc::Receiver<LogEvent>);
+/// The number of log events of each level, shared between the [`CountLayer`] and the ECS.
+#[derive(Resource, Clone, Default)]
+struct LogCounts(Arc<[AtomicUsize; 5]>);
+
+impl LogCounts {
+    fn index(level: &Level) -> usize {
+        match *level {
+            Level::ERROR => 0,
+            Level::WARN => 1,
+            Level::INFO => 2,
+            Level::DEBUG => 3,
+            Level::TRACE => 4,
+        }
+    }
 
-/// Transfers information from the `LogEvents` resource to [`Events<LogEvent>`](LogEvent).
-fn transfer_log_events(
-    receiver: NonSend<CapturedLogEvents>,
-    mut log_events: EventWriter<LogEvent>,
-) {
-    // Make sure to use `try_iter()` and not `iter()` to prevent blocking.
-    log_events.write_batch(receiver.try_iter());
+    fn get(&self, level: &Level) -> usize {
+        self.0[Self::index(level)].load(Ordering::Relaxed)
+    }
 }
 
-/// This is the [`Layer`] that we will use to capture log events and then send them to Bevy's
-/// ECS via its [`mpsc::Sender`].
-struct CaptureLayer {
-    sender: mpsc::Sender<LogEvent>,
-}
+/// This [`Layer`] counts the log events in [`LogCounts`], independently of the log capture.
+struct CountLayer(LogCounts);
 
-impl<S: Subscriber> Layer<S> for CaptureLayer {
+impl<S: Subscriber> Layer<S> for CountLayer {
     fn on_event(
         &self,
         event: &tracing::Event<'_>,
         _ctx: tracing_subscriber::layer::Context<'_, S>,
     ) {
-        // In order to obtain the log message, we have to create a struct that implements
-        // Visit and holds a reference to our string. Then we use the `record` method and
-        // the struct to modify the reference to hold the message string.
-        let mut message = None;
-        event.record(&mut CaptureLayerVisitor(&mut message));
-        if let Some(message) = message {
-            let metadata = event.metadata();
-
-            self.sender
-                .send(LogEvent {
-                    message,
-                    level: *metadata.level(),
-                })
-                .expect("LogEvents resource no longer exists!");
-        }
+        let index = LogCounts::index(event.metadata().level());
+        self.0 .0[index].fetch_add(1, Ordering::Relaxed);
     }
 }
 
-/// A [`Visit`](tracing::field::Visit)or that records log messages that are transferred to [`CaptureLayer`].
-struct CaptureLayerVisitor<'a>(&'a mut Option<String>);
-impl tracing::field::Visit for CaptureLayerVisitor<'_> {
-    fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
-        // This if statement filters out unneeded events sometimes show up
-        if field.name() == "message" {
-            *self.0 = Some(format!("{value:?}"));
-        }
-    }
-}
-fn custom_layer(app: &mut App) -> Option<BoxedLayer> {
-    let (sender, receiver) = mpsc::channel();
-
-    let layer = CaptureLayer { sender };
-    let resource = CapturedLogEvents(receiver);
-
-    app.insert_non_send_resource(resource);
-    app.add_event::<LogEvent>();
-    app.add_systems(Update, transfer_log_events);
-
-    Some(layer.boxed())
+fn count_layer(app: &mut App) -> BoxedLayer {
+    let counts = LogCounts::default();
+    app.insert_resource(counts.clone());
+    CountLayer(counts).boxed()
 }
 
 fn log_system() {
@@ -124,6 +98,9 @@ fn log_system() {
 #[derive(Component)]
 struct LogViewerRoot;
 
+#[derive(Component)]
+struct LogCountsText;
+
 fn setup(mut commands: Commands) {
     commands.spawn(Camera2d);
 
@@ -136,13 +113,24 @@ fn setup(mut commands: Commands) {
             ..default()
         },
         LogViewerRoot,
+        children![(Text::default(), LogCountsText)],
     ));
 }
 
-// This is how we can read our LogEvents.
-// In this example we are reading the LogEvents and inserting them as text into our log viewer.
+// This is how we can read the counts of the second layer.
+fn print_log_counts(counts: Res<LogCounts>, mut text: Single<&mut Text, With<LogCountsText>>) {
+    text.0 = format!(
+        "{} errors, {} warnings, {} other logs",
+        counts.get(&Level::ERROR),
+        counts.get(&Level::WARN),
+        counts.get(&Level::INFO) + counts.get(&Level::DEBUG) + counts.get(&Level::TRACE),
+    );
+}
+
+// This is how we can read the captured logs.
+// In this example we are reading the `LogMessage`s and inserting them as text into our log viewer.
 fn print_logs(
-    mut events: EventReader<LogEvent>,
+    mut events: EventReader<LogMessage>,
     mut commands: Commands,
     log_viewer_root: Single<Entity, With<LogViewerRoot>>,
 ) {
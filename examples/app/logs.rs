@@ -1,6 +1,9 @@
 //! This example illustrates how to use logs in bevy.
 
-use bevy::{log::once, prelude::*};
+use bevy::{
+    log::{once, Level, LogFilter},
+    prelude::*,
+};
 
 fn main() {
     App::new()
@@ -8,19 +11,24 @@ fn main() {
             // Uncomment this to override the default log settings:
             // level: bevy::log::Level::TRACE,
             // filter: "wgpu=warn,bevy_ecs=info".to_string(),
+            // On the web, the logs are written to the browser console with the method of their
+            // level, such as `console.warn`, so the devtools can filter them by level.
+            // Uncomment this to also group the logs of each span there:
+            // group_spans: true,
             ..default()
         }))
         .add_systems(Startup, setup)
         .add_systems(Update, log_system)
         .add_systems(Update, log_once_system)
         .add_systems(Update, panic_on_p)
+        .add_systems(Update, toggle_trace_on_t)
         .run();
 }
 
 fn setup(mut commands: Commands) {
     commands.spawn(Camera2d);
     commands.spawn((
-        Text::new("Press P to panic"),
+        Text::new("Press P to panic\nPress T to toggle trace logs"),
         Node {
             position_type: PositionType::Absolute,
             top: Val::Px(12.0),
@@ -36,6 +44,20 @@ fn panic_on_p(keys: Res<ButtonInput<KeyCode>>) {
     }
 }
 
+fn toggle_trace_on_t(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut filter: ResMut<LogFilter>,
+    mut trace: Local<bool>,
+) {
+    if keys.just_pressed(KeyCode::KeyT) {
+        // The log filter can be changed while the app is running, the change is applied at the
+        // end of the frame.
+        *trace = !*trace;
+        filter.set_global(if *trace { Level::TRACE } else { Level::INFO });
+        info!("Log filter set to {}", filter.filter());
+    }
+}
+
 fn log_system() {
     // here is how you write new logs at each "log level" (in "least important" to "most important"
     // order)
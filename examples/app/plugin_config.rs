@@ -0,0 +1,26 @@
+//! Demonstrates how to configure the plugins of `DefaultPlugins` from a data file.
+//!
+//! This lets the same binary run with differently tuned plugins, for example as a dedicated
+//! server or in CI. `assets/data/plugins.ron` disables the audio and changes the log filter.
+
+use bevy::{app::PluginGroupConfig, prelude::*};
+
+fn main() {
+    let config = std::fs::read_to_string("assets/data/plugins.ron")
+        .map_err(|error| error.to_string())
+        .and_then(|config| PluginGroupConfig::from_ron(&config).map_err(|error| error.to_string()))
+        .unwrap_or_else(|error| {
+            eprintln!("Failed to load the plugin configuration, using the defaults: {error}");
+            PluginGroupConfig::default()
+        });
+
+    App::new()
+        .add_plugins(DefaultPlugins.with_config(&config))
+        .add_systems(Startup, report)
+        .run();
+}
+
+fn report(audio_sources: Option<Res<Assets<AudioSource>>>) {
+    // This is only logged because the config enables debug logs for this example.
+    debug!("Audio enabled: {}", audio_sources.is_some());
+}
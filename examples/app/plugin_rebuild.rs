@@ -0,0 +1,105 @@
+//! Demonstrates how to rebuild a plugin while the app is running.
+//!
+//! This is useful while iterating on a plugin: the systems and resources it registered are
+//! removed, and a new instance of the plugin is built in its place, without restarting the app.
+//! Press space to rebuild the spinner plugin with different settings.
+
+use bevy::{app::PluginRebuilds, prelude::*};
+
+/// The settings the spinner plugin cycles through.
+const PRESETS: [SpinnerPlugin; 3] = [
+    SpinnerPlugin {
+        speed: 1.0,
+        color: Color::srgb(0.9, 0.3, 0.3),
+    },
+    SpinnerPlugin {
+        speed: 4.0,
+        color: Color::srgb(0.3, 0.9, 0.3),
+    },
+    SpinnerPlugin {
+        speed: -2.0,
+        color: Color::srgb(0.3, 0.3, 0.9),
+    },
+];
+
+fn main() {
+    App::new()
+        // This must be called before adding the plugins that will be rebuilt.
+        .enable_plugin_rebuilds()
+        .add_plugins((DefaultPlugins, PRESETS[0]))
+        .add_systems(Startup, setup)
+        .add_systems(Update, rebuild_on_space)
+        .run();
+}
+
+/// A plugin that spins every [`Spinning`] entity.
+#[derive(Clone, Copy)]
+struct SpinnerPlugin {
+    speed: f32,
+    color: Color,
+}
+
+impl Plugin for SpinnerPlugin {
+    fn build(&self, app: &mut App) {
+        info!("Building the spinner plugin with a speed of {}", self.speed);
+        app.insert_resource(SpinnerSettings {
+            speed: self.speed,
+            color: self.color,
+        })
+        .add_systems(Update, (spin, paint));
+    }
+}
+
+#[derive(Resource)]
+struct SpinnerSettings {
+    speed: f32,
+    color: Color,
+}
+
+#[derive(Component)]
+struct Spinning;
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+    commands.spawn((
+        Sprite::from_color(Color::WHITE, Vec2::splat(200.0)),
+        Spinning,
+    ));
+    commands.spawn((
+        Text::new("Press space to rebuild the spinner plugin"),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(12.0),
+            left: Val::Px(12.0),
+            ..default()
+        },
+    ));
+}
+
+fn spin(
+    time: Res<Time>,
+    settings: Res<SpinnerSettings>,
+    mut query: Query<&mut Transform, With<Spinning>>,
+) {
+    for mut transform in &mut query {
+        transform.rotate_z(settings.speed * time.delta_secs());
+    }
+}
+
+fn paint(settings: Res<SpinnerSettings>, mut query: Query<&mut Sprite, With<Spinning>>) {
+    for mut sprite in &mut query {
+        sprite.color = settings.color;
+    }
+}
+
+fn rebuild_on_space(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut rebuilds: ResMut<PluginRebuilds>,
+    mut preset: Local<usize>,
+) {
+    if keyboard.just_pressed(KeyCode::Space) {
+        *preset = (*preset + 1) % PRESETS.len();
+        // The plugin is rebuilt at the end of this update.
+        rebuilds.rebuild(PRESETS[*preset]);
+    }
+}
@@ -1,10 +1,9 @@
 //! How to use an external thread to run an infinite task and communicate with a channel.
 
-use bevy::prelude::*;
-// Using crossbeam_channel instead of std as std `Receiver` is `!Sync`
-use crossbeam_channel::{bounded, Receiver};
+use bevy::{ecs::resource::SyncCell, prelude::*};
 use rand::{Rng, SeedableRng};
 use rand_chacha::ChaCha8Rng;
+use std::sync::mpsc::{sync_channel, Receiver};
 
 fn main() {
     App::new()
@@ -17,8 +16,10 @@ fn main() {
         .run();
 }
 
-#[derive(Resource, Deref)]
-struct StreamReceiver(Receiver<u32>);
+// The std `Receiver` is `!Sync`, so it is wrapped in a `SyncCell`, which only gives mutable access
+// to it. This makes it a regular resource, and `read_stream` can run on any thread.
+#[derive(Resource, Deref, DerefMut)]
+struct StreamReceiver(SyncCell<Receiver<u32>>);
 
 #[derive(BufferedEvent)]
 struct StreamEvent(u32);
@@ -26,7 +27,7 @@ struct StreamEvent(u32);
 fn setup(mut commands: Commands) {
     commands.spawn(Camera2d);
 
-    let (tx, rx) = bounded::<u32>(1);
+    let (tx, rx) = sync_channel::<u32>(1);
     std::thread::spawn(move || {
         // We're seeding the PRNG here to make this example deterministic for testing purposes.
         // This isn't strictly required in practical use unless you need your app to be deterministic.
@@ -40,12 +41,12 @@ fn setup(mut commands: Commands) {
         }
     });
 
-    commands.insert_resource(StreamReceiver(rx));
+    commands.insert_resource(StreamReceiver(SyncCell::new(rx)));
 }
 
 // This system reads from the receiver and sends events to Bevy
-fn read_stream(receiver: Res<StreamReceiver>, mut events: EventWriter<StreamEvent>) {
-    for from_stream in receiver.try_iter() {
+fn read_stream(mut receiver: ResMut<StreamReceiver>, mut events: EventWriter<StreamEvent>) {
+    for from_stream in receiver.get_mut().try_iter() {
         events.write(StreamEvent(from_stream));
     }
 }
@@ -0,0 +1,74 @@
+//! Showcase how to use and configure the log overlay.
+
+use bevy::{
+    dev_tools::log_overlay::{LogOverlayConfig, LogOverlayPlugin},
+    log::{Level, LogCaptureConfig, LogPlugin},
+    prelude::*,
+};
+
+fn main() {
+    App::new()
+        .add_plugins((
+            DefaultPlugins.set(LogPlugin {
+                filter: "warn,log_overlay=trace".to_string(),
+                level: Level::TRACE,
+                // The overlay shows the logs captured as `LogMessage` events
+                capture: Some(LogCaptureConfig::default()),
+                ..default()
+            }),
+            LogOverlayPlugin {
+                config: LogOverlayConfig {
+                    // Only keep the 10 most recent logs on screen
+                    max_lines: 10,
+                    // Start with the logs of every level
+                    min_level: Level::TRACE,
+                    ..default()
+                },
+            },
+        ))
+        .add_systems(Startup, setup)
+        .add_systems(Update, (write_logs, customize_config))
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    // We need to spawn a camera (2d or 3d) to see the overlay
+    commands.spawn(Camera2d);
+
+    // Instruction text
+    commands.spawn((
+        Text::new(concat!(
+            "Press Space to write logs of every level.\n",
+            "Press 1 to only show the warnings and errors.\n",
+            "Press 2 to show the logs of every level.\n",
+            "Press F9 to toggle the overlay."
+        )),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(12.),
+            left: Val::Px(12.),
+            ..default()
+        },
+    ));
+}
+
+fn write_logs(input: Res<ButtonInput<KeyCode>>, mut count: Local<u32>) {
+    if input.just_pressed(KeyCode::Space) {
+        *count += 1;
+        error!(count = *count, "Something failed");
+        warn!(count = *count, "Something bad happened that isn't a failure");
+        info!(count = *count, "Helpful information");
+        debug!(count = *count, "Helpful for debugging");
+        trace!(count = *count, "Very noisy");
+    }
+}
+
+fn customize_config(input: Res<ButtonInput<KeyCode>>, mut overlay: ResMut<LogOverlayConfig>) {
+    // Changing the level only affects the logs written afterwards
+    if input.just_pressed(KeyCode::Digit1) {
+        overlay.min_level = Level::WARN;
+    }
+    if input.just_pressed(KeyCode::Digit2) {
+        overlay.min_level = Level::TRACE;
+    }
+}
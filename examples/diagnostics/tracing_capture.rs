@@ -0,0 +1,83 @@
+//! Shows how to capture a Chrome trace of a few seconds on demand, instead of a whole session.
+//!
+//! Run with `cargo run --example tracing_capture --features trace_chrome`, press Space when
+//! something interesting happens, and open the `capture-*.json` file written 10 seconds later in
+//! <https://ui.perfetto.dev>.
+
+use std::time::Duration;
+
+use bevy::{log::TracingCapture, prelude::*};
+
+/// How long each capture lasts.
+const CAPTURE_DURATION: Duration = Duration::from_secs(10);
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_systems(Startup, (setup, stop_initial_capture))
+        .add_systems(Update, capture_on_key_press)
+        .run();
+}
+
+/// The timer of the running capture, and the number of captures so far.
+#[derive(Default)]
+struct Capture {
+    timer: Option<Timer>,
+    count: u32,
+}
+
+#[derive(Component)]
+struct StatusText;
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+    commands.spawn((
+        Text::new("Press Space to capture 10 seconds of trace"),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(12.),
+            left: Val::Px(12.),
+            ..default()
+        },
+        StatusText,
+    ));
+}
+
+// The trace is captured from the start of the app, which isn't needed here.
+fn stop_initial_capture(mut capture: ResMut<TracingCapture>) {
+    capture.stop();
+}
+
+fn capture_on_key_press(
+    input: Res<ButtonInput<KeyCode>>,
+    mut tracing_capture: ResMut<TracingCapture>,
+    mut capture: Local<Capture>,
+    time: Res<Time>,
+    mut text: Single<&mut Text, With<StatusText>>,
+) {
+    if let Some(timer) = &mut capture.timer {
+        timer.tick(time.delta());
+        if timer.finished() {
+            tracing_capture.stop();
+            capture.timer = None;
+            text.0 = format!(
+                "Wrote capture-{}.json, press Space to capture again",
+                capture.count
+            );
+        }
+        return;
+    }
+
+    if input.just_pressed(KeyCode::Space) {
+        capture.count += 1;
+        let path = format!("capture-{}.json", capture.count);
+        // Starting a capture while one is running returns an error.
+        match tracing_capture.start_chrome(&path) {
+            Ok(()) => {
+                capture.timer = Some(Timer::new(CAPTURE_DURATION, TimerMode::Once));
+                text.0 = format!("Capturing to {path}...");
+            }
+            Err(err) => error!("Could not start the capture: {err}"),
+        }
+    }
+}
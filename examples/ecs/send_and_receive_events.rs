@@ -22,8 +22,10 @@
 use bevy::{diagnostic::FrameCount, ecs::event::EventCursor, prelude::*};
 
 fn main() {
-    let mut app = App::new();
-    app.add_plugins(MinimalPlugins)
+    App::new()
+        // We're just going to run a few frames, so we can see and understand the output.
+        // By running for longer than one frame, we can see that we're caching our cursor in the event queue properly.
+        .add_plugins(TestPlugins::with_frame_limit(2))
         .add_event::<DebugEvent>()
         .add_event::<A>()
         .add_event::<B>()
@@ -39,11 +41,8 @@ fn main() {
                 debug_events,
             )
                 .chain(),
-        );
-    // We're just going to run a few frames, so we can see and understand the output.
-    app.update();
-    // By running for longer than one frame, we can see that we're caching our cursor in the event queue properly.
-    app.update();
+        )
+        .run();
 }
 
 #[derive(BufferedEvent)]
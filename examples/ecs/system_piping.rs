@@ -10,11 +10,11 @@ fn main() {
     App::new()
         .insert_resource(Message("42".to_string()))
         .insert_resource(OptionalWarning(Err("Got to rusty?".to_string())))
-        .add_plugins(LogPlugin {
+        .add_plugins(TestPlugins.set(LogPlugin {
             level: Level::TRACE,
             filter: "".to_string(),
             ..default()
-        })
+        }))
         .add_systems(
             Update,
             (
@@ -0,0 +1,37 @@
+//! A test to confirm that the events derived with `#[buffered_event(auto_register)]` can be
+//! written and read without `App::add_event`.
+//!
+//! This relies on `inventory`, so it is run as an example, on the platforms it supports:
+//! `cargo run --example auto_register_events --features auto_register_events`.
+
+use bevy::{app::ScheduleRunnerPlugin, prelude::*};
+
+/// An event never added with `App::add_event`.
+#[derive(BufferedEvent)]
+#[buffered_event(auto_register)]
+struct LogEvent(String);
+
+#[derive(Resource, Default)]
+struct Logged(Vec<String>);
+
+fn main() {
+    App::new()
+        .add_plugins(ScheduleRunnerPlugin::run_once())
+        .init_resource::<Logged>()
+        .add_systems(Update, (write_log, read_log).chain())
+        .add_systems(Last, check_log)
+        .run();
+}
+
+fn write_log(mut log: EventWriter<LogEvent>) {
+    log.write(LogEvent("hello".to_string()));
+}
+
+fn read_log(mut log: EventReader<LogEvent>, mut logged: ResMut<Logged>) {
+    logged.0.extend(log.read().map(|event| event.0.clone()));
+}
+
+fn check_log(logged: Res<Logged>) {
+    assert_eq!(logged.0, ["hello"]);
+    println!("The auto-registered event was written and read");
+}
@@ -0,0 +1,51 @@
+//! Tests that [`HeadlessPlugins`] runs without a display or an audio device.
+//! This is run in CI.
+
+use bevy::{app::ScheduleRunnerPlugin, diagnostic::FrameCount, prelude::*};
+use core::time::Duration;
+
+fn exit_after_three_frames(frame_count: Res<FrameCount>, mut exit: EventWriter<AppExit>) {
+    if frame_count.0 >= 3 {
+        exit.write(AppExit::Success);
+    }
+}
+
+#[test]
+fn runs_frames_headlessly() {
+    let mut app = App::new();
+    app.add_plugins(HeadlessPlugins::with_tick_rate(1000.0))
+        .add_systems(Update, exit_after_three_frames);
+
+    assert_eq!(app.run(), AppExit::Success);
+}
+
+#[test]
+fn composes_like_other_groups() {
+    let mut app = App::new();
+    app.add_plugins(
+        HeadlessPlugins
+            .set(ScheduleRunnerPlugin::run_loop(Duration::from_millis(1)))
+            .disable::<TransformPlugin>(),
+    );
+    app.finish();
+    app.cleanup();
+    for _ in 0..3 {
+        app.update();
+    }
+
+    assert_eq!(app.world().resource::<FrameCount>().0, 3);
+    assert!(app.world().contains_resource::<Time>());
+    assert!(!app.is_plugin_added::<TransformPlugin>());
+}
+
+#[test]
+#[should_panic(expected = "invalid tick rate: 0 ticks per second")]
+fn rejects_a_zero_tick_rate() {
+    let _ = HeadlessPlugins::with_tick_rate(0.0);
+}
+
+#[test]
+#[should_panic(expected = "invalid tick rate: NaN ticks per second")]
+fn rejects_a_nan_tick_rate() {
+    let _ = HeadlessPlugins::with_tick_rate(f64::NAN);
+}